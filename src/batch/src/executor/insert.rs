@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::iter::once;
 
 use futures::future::try_join_all;
@@ -29,6 +30,7 @@ use risingwave_source::SourceManagerRef;
 
 use crate::executor::{
     BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+    UniqueIndexCheck,
 };
 use crate::task::BatchTaskContext;
 
@@ -41,6 +43,9 @@ pub struct InsertExecutor {
     child: BoxedExecutor,
     schema: Schema,
     identity: String,
+
+    /// Checks the inserted rows against the unique indexes on the table, if any.
+    unique_check: Option<UniqueIndexCheck>,
 }
 
 impl InsertExecutor {
@@ -53,8 +58,14 @@ impl InsertExecutor {
                 fields: vec![Field::unnamed(DataType::Int64)],
             },
             identity: "InsertExecutor".to_string(),
+            unique_check: None,
         }
     }
+
+    pub fn with_unique_check(mut self, unique_check: UniqueIndexCheck) -> Self {
+        self.unique_check = Some(unique_check);
+        self
+    }
 }
 
 impl Executor for InsertExecutor {
@@ -73,7 +84,7 @@ impl Executor for InsertExecutor {
 
 impl InsertExecutor {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
-    async fn do_execute(self: Box<Self>) {
+    async fn do_execute(mut self: Box<Self>) {
         let source_desc = self.source_manager.get_source(&self.table_id)?;
         let source = source_desc.source.as_table_v2().expect("not table source");
 
@@ -85,6 +96,10 @@ impl InsertExecutor {
             let len = data_chunk.cardinality();
             assert!(data_chunk.visibility().is_none());
 
+            if let Some(unique_check) = &mut self.unique_check {
+                unique_check.check(&data_chunk, &HashSet::new()).await?;
+            }
+
             // add row-id column as first column
             let mut builder = I64ArrayBuilder::new(len).unwrap();
             for _ in 0..len {
@@ -141,14 +156,21 @@ impl BoxedExecutorBuilder for InsertExecutor {
 
         let table_id = TableId::from(&insert_node.table_source_ref_id);
 
-        Ok(Box::new(Self::new(
+        let executor = Self::new(
             table_id,
             source
                 .context()
                 .source_manager_ref()
                 .ok_or_else(|| InternalError("Source manager not found".to_string()))?,
             inputs.remove(0),
-        )))
+        );
+
+        Ok(Box::new(
+            match UniqueIndexCheck::build(source, &insert_node.unique_indexes)? {
+                Some(unique_check) => executor.with_unique_check(unique_check),
+                None => executor,
+            },
+        ))
     }
 }
 
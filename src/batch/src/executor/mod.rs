@@ -14,7 +14,6 @@
 
 mod delete;
 mod filter;
-mod generate_series;
 mod generic_exchange;
 mod hash_agg;
 mod hop_window;
@@ -25,20 +24,26 @@ mod merge_sort_exchange;
 pub mod monitor;
 mod order_by;
 mod project;
+mod project_set;
+mod recursive_union;
 mod row_seq_scan;
 mod sort_agg;
+mod table_function;
 #[cfg(test)]
 pub mod test_utils;
 mod top_n;
 mod trace;
+mod union;
+mod unique_index_check;
 mod update;
 mod values;
+mod work_table_scan;
+mod write_changes;
 
 use async_recursion::async_recursion;
 pub use delete::*;
 pub use filter::*;
 use futures::stream::BoxStream;
-pub use generate_series::*;
 pub use generic_exchange::*;
 pub use hash_agg::*;
 pub use hop_window::*;
@@ -49,6 +54,8 @@ pub use merge_sort_exchange::*;
 pub use monitor::*;
 pub use order_by::*;
 pub use project::*;
+pub use project_set::*;
+pub use recursive_union::*;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::ErrorCode::InternalError;
@@ -57,10 +64,15 @@ use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::PlanNode;
 pub use row_seq_scan::*;
 pub use sort_agg::*;
+pub use table_function::*;
 pub use top_n::*;
 pub use trace::*;
+pub use union::*;
+pub use unique_index_check::*;
 pub use update::*;
 pub use values::*;
+pub use work_table_scan::*;
+pub use write_changes::*;
 
 use crate::task::{BatchTaskContext, TaskId};
 
@@ -103,6 +115,8 @@ pub struct ExecutorBuilder<'a, C> {
     pub task_id: &'a TaskId,
     context: C,
     epoch: u64,
+    /// The work tables of the enclosing [`RecursiveUnionExecutor`]s.
+    work_tables: WorkTables,
 }
 
 macro_rules! build_executor {
@@ -124,12 +138,20 @@ impl<'a, C: Clone> ExecutorBuilder<'a, C> {
             task_id,
             context,
             epoch,
+            work_tables: WorkTables::new(),
         }
     }
 
     #[must_use]
     pub fn clone_for_plan(&self, plan_node: &'a PlanNode) -> Self {
         ExecutorBuilder::new(plan_node, self.task_id, self.context.clone(), self.epoch)
+            .with_work_tables(self.work_tables.clone())
+    }
+
+    #[must_use]
+    pub fn with_work_tables(mut self, work_tables: WorkTables) -> Self {
+        self.work_tables = work_tables;
+        self
     }
 
     pub fn plan_node(&self) -> &PlanNode {
@@ -143,6 +165,10 @@ impl<'a, C: Clone> ExecutorBuilder<'a, C> {
     pub fn epoch(&self) -> u64 {
         self.epoch
     }
+
+    pub fn work_tables(&self) -> &WorkTables {
+        &self.work_tables
+    }
 }
 
 impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
@@ -159,8 +185,13 @@ impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
 
     #[async_recursion]
     async fn try_build(&self) -> Result<BoxedExecutor> {
-        let mut inputs = Vec::with_capacity(self.plan_node.children.len());
-        for input_node in &self.plan_node.children {
+        // The recursive term of a recursive union is built by the executor in each iteration.
+        let children = match self.plan_node.get_node_body() {
+            Ok(NodeBody::RecursiveUnion(_)) => &self.plan_node.children[..1],
+            _ => &self.plan_node.children[..],
+        };
+        let mut inputs = Vec::with_capacity(children.len());
+        for input_node in children {
             let input = self.clone_for_plan(input_node).build().await?;
             inputs.push(input);
         }
@@ -173,6 +204,7 @@ impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
             NodeBody::Update => UpdateExecutor,
             NodeBody::Filter => FilterExecutor,
             NodeBody::Project => ProjectExecutor,
+            NodeBody::ProjectSet => ProjectSetExecutor,
             NodeBody::SortAgg => SortAggExecutor,
             NodeBody::OrderBy => OrderByExecutor,
             NodeBody::TopN => TopNExecutor,
@@ -183,8 +215,12 @@ impl<'a, C: BatchTaskContext> ExecutorBuilder<'a, C> {
             NodeBody::SortMergeJoin => SortMergeJoinExecutor,
             NodeBody::HashAgg => HashAggExecutorBuilder,
             NodeBody::MergeSortExchange => MergeSortExchangeExecutorBuilder,
-            NodeBody::GenerateSeries => GenerateSeriesExecutorBuilder,
+            NodeBody::TableFunction => TableFunctionExecutorBuilder,
             NodeBody::HopWindow => HopWindowExecutor,
+            NodeBody::WriteChanges => WriteChangesExecutor,
+            NodeBody::Union => UnionExecutor,
+            NodeBody::RecursiveUnion => RecursiveUnionExecutorBuilder,
+            NodeBody::WorkTableScan => WorkTableScanExecutor,
         }
         .await?;
         let input_desc = real_executor.identity().to_string();
@@ -0,0 +1,175 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
+use risingwave_expr::table_function::{project_set, ProjectSetSelectItem};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::{
+    BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+};
+use crate::task::BatchTaskContext;
+
+/// `ProjectSetExecutor` expands each input row into a set of rows with set-returning functions
+/// like `unnest`. See [`project_set`] for the details.
+pub struct ProjectSetExecutor {
+    select_list: Vec<ProjectSetSelectItem>,
+    child: BoxedExecutor,
+    schema: Schema,
+    identity: String,
+}
+
+impl Executor for ProjectSetExecutor {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl ProjectSetExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let mut data_chunk_builder = DataChunkBuilder::with_default_size(self.schema.data_types());
+
+        #[for_await]
+        for data_chunk in self.child.execute() {
+            let data_chunk = data_chunk?;
+            for (_, row) in project_set(&self.select_list, &data_chunk)? {
+                if let Some(chunk) = data_chunk_builder.append_one_row_from_datums(row.values())? {
+                    yield chunk;
+                }
+            }
+        }
+
+        if let Some(chunk) = data_chunk_builder.consume_all()? {
+            yield chunk;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for ProjectSetExecutor {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        mut inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        ensure!(
+            inputs.len() == 1,
+            "ProjectSet executor should have only 1 child!"
+        );
+
+        let project_set_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::ProjectSet
+        )?;
+
+        let select_list = project_set_node
+            .get_select_list()
+            .iter()
+            .map(ProjectSetSelectItem::from_prost)
+            .collect::<Result<Vec<_>>>()?;
+
+        let fields = std::iter::once(Field::unnamed(DataType::Int64))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+
+        Ok(Box::new(Self {
+            select_list,
+            child: inputs.remove(0),
+            schema: Schema { fields },
+            identity: source.plan_node().get_identity().clone(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use risingwave_common::array::ListValue;
+    use risingwave_common::test_prelude::*;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_expr::expr::{Expression, InputRefExpression, LiteralExpression};
+    use risingwave_expr::table_function::{TableFunction, Unnest};
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    #[tokio::test]
+    async fn test_project_set_executor() {
+        let chunk = DataChunk::from_pretty(
+            "i
+             1
+             2",
+        );
+        let schema = schema_unnamed! { DataType::Int32 };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(chunk);
+
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let array = ListValue::new(vec![Some(10.into()), Some(20.into())]);
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(InputRefExpression::new(DataType::Int32, 0).boxed()),
+            ProjectSetSelectItem::TableFunction(
+                Unnest::new(
+                    LiteralExpression::new(list_type, Some(ScalarImpl::List(array))).boxed(),
+                    DataType::Int32,
+                )
+                .boxed(),
+            ),
+        ];
+        let fields = vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int32),
+            Field::unnamed(DataType::Int32),
+        ];
+        let executor = Box::new(ProjectSetExecutor {
+            select_list,
+            child: Box::new(mock_executor),
+            schema: Schema { fields },
+            identity: "ProjectSetExecutor".to_string(),
+        });
+
+        let mut stream = executor.execute();
+        let result_chunk = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            result_chunk,
+            DataChunk::from_pretty(
+                "I i i
+                 0 1 10
+                 1 1 20
+                 0 2 10
+                 1 2 20"
+            )
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
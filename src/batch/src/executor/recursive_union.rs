@@ -0,0 +1,280 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::PlanNode;
+
+use crate::executor::{
+    BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+};
+use crate::task::{BatchTaskContext, TaskId};
+
+/// The rows of the work tables visible to an executor, by the ids of the work tables.
+pub type WorkTables = HashMap<u32, Arc<[DataChunk]>>;
+
+/// [`RecursiveUnionExecutor`] implements `WITH RECURSIVE`. It returns the rows of the
+/// non-recursive term, and then evaluates the recursive term repeatedly over the rows produced by
+/// its previous evaluation, i.e. the work table, until no new rows are produced.
+///
+/// As the executors can only be executed once, the recursive term is built from the plan again in
+/// each iteration, with the new work table.
+pub struct RecursiveUnionExecutor<C> {
+    base: BoxedExecutor,
+    recursive: PlanNode,
+    /// Whether to keep the duplicate rows. Otherwise, a row is returned only the first time it is
+    /// produced, and is not added to the work table again.
+    all: bool,
+    work_table_id: u32,
+    /// 0 means unlimited.
+    max_iterations: u64,
+    /// 0 means unlimited.
+    max_memory_bytes: u64,
+
+    task_id: TaskId,
+    context: C,
+    epoch: u64,
+    /// The work tables of the enclosing recursive unions.
+    work_tables: WorkTables,
+
+    identity: String,
+}
+
+pub struct RecursiveUnionExecutorBuilder {}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for RecursiveUnionExecutorBuilder {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::RecursiveUnion
+        )?;
+        // Only the non-recursive term is built by the `ExecutorBuilder`.
+        ensure!(
+            inputs.len() == 1 && source.plan_node().children.len() == 2,
+            "RecursiveUnionExecutor should have 2 children!"
+        );
+
+        Ok(Box::new(RecursiveUnionExecutor {
+            base: inputs.into_iter().next().unwrap(),
+            recursive: source.plan_node().children[1].clone(),
+            all: node.all,
+            work_table_id: node.work_table_id,
+            max_iterations: node.max_iterations,
+            max_memory_bytes: node.max_memory_bytes,
+            task_id: source.task_id.clone(),
+            context: source.context().clone(),
+            epoch: source.epoch(),
+            work_tables: source.work_tables().clone(),
+            identity: source.plan_node().get_identity().clone(),
+        }))
+    }
+}
+
+impl<C: BatchTaskContext> RecursiveUnionExecutor<C> {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let Self {
+            base,
+            recursive,
+            all,
+            work_table_id,
+            max_iterations,
+            max_memory_bytes,
+            task_id,
+            context,
+            epoch,
+            work_tables,
+            ..
+        } = *self;
+
+        // The rows returned so far in the memcomparable format, to remove the duplicates.
+        let mut seen = HashSet::new();
+        let mut seen_bytes = 0;
+        let mut input = base;
+        let mut prev_work_table_bytes = 0;
+        let mut iterations = 0;
+        loop {
+            let mut work_table = vec![];
+            let mut work_table_bytes = 0;
+
+            #[for_await]
+            for chunk in input.execute() {
+                let chunk = chunk?.compact()?;
+                let mut visibility = Vec::with_capacity(chunk.cardinality());
+                for row in chunk.rows() {
+                    let key = Row::from(row).serialize()?;
+                    let bytes = key.len() as u64;
+                    let is_new = all || seen.insert(key);
+                    if is_new {
+                        work_table_bytes += bytes;
+                        if !all {
+                            seen_bytes += bytes;
+                        }
+                    }
+                    visibility.push(is_new);
+                }
+                // The work table of the previous iteration is still held by the recursive term.
+                let memory_bytes = seen_bytes + prev_work_table_bytes + work_table_bytes;
+                if max_memory_bytes > 0 && memory_bytes > max_memory_bytes {
+                    return Err(InternalError(format!(
+                        "recursive query exceeded the memory limit of {} bytes",
+                        max_memory_bytes
+                    ))
+                    .into());
+                }
+
+                let chunk = match all {
+                    true => chunk,
+                    false => chunk
+                        .with_visibility(Bitmap::try_from(visibility)?)
+                        .compact()?,
+                };
+                if chunk.cardinality() > 0 {
+                    work_table.push(chunk.clone());
+                    yield chunk;
+                }
+            }
+
+            if work_table.is_empty() {
+                break;
+            }
+            prev_work_table_bytes = work_table_bytes;
+            iterations += 1;
+            if max_iterations > 0 && iterations > max_iterations {
+                return Err(InternalError(format!(
+                    "recursive query exceeded the limit of {} iterations",
+                    max_iterations
+                ))
+                .into());
+            }
+
+            let mut work_tables = work_tables.clone();
+            work_tables.insert(work_table_id, work_table.into());
+            let builder = ExecutorBuilder::new(&recursive, &task_id, context.clone(), epoch)
+                .with_work_tables(work_tables);
+            input = builder.build().await?;
+        }
+    }
+}
+
+impl<C: BatchTaskContext> Executor for RecursiveUnionExecutor<C> {
+    fn schema(&self) -> &Schema {
+        self.base.schema()
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use risingwave_common::array::DataChunk;
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::test_prelude::DataChunkTestExt;
+    use risingwave_common::types::DataType;
+    use risingwave_pb::batch_plan::WorkTableScanNode;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+    use crate::task::ComputeNodeContext;
+
+    /// A recursive union whose recursive term returns the work table as is.
+    fn create_executor(
+        all: bool,
+        max_iterations: u64,
+        max_memory_bytes: u64,
+    ) -> Box<RecursiveUnionExecutor<ComputeNodeContext>> {
+        let field = Field::unnamed(DataType::Int32);
+        let base = MockExecutor::with_chunk(
+            DataChunk::from_pretty(
+                "i
+                 1
+                 2
+                 2",
+            ),
+            Schema {
+                fields: vec![field.clone()],
+            },
+        );
+        let recursive = PlanNode {
+            children: vec![],
+            node_body: Some(NodeBody::WorkTableScan(WorkTableScanNode {
+                work_table_id: 1,
+                fields: vec![field.to_prost()],
+            })),
+            identity: "WorkTableScanExecutor".to_string(),
+        };
+        Box::new(RecursiveUnionExecutor {
+            base: Box::new(base),
+            recursive,
+            all,
+            work_table_id: 1,
+            max_iterations,
+            max_memory_bytes,
+            task_id: TaskId::default(),
+            context: ComputeNodeContext::new_for_test(),
+            epoch: u64::MAX,
+            work_tables: WorkTables::new(),
+            identity: "RecursiveUnionExecutor".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_recursive_union_executor() {
+        let mut stream = create_executor(false, 0, 0).execute();
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            DataChunk::from_pretty(
+                "i
+                 1
+                 2",
+            )
+        );
+        // The rows of the second iteration have all been seen.
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_recursive_union_executor_limits() {
+        let mut stream = create_executor(true, 2, 0).execute();
+        for _ in 0..3 {
+            assert_eq!(stream.next().await.unwrap().unwrap().cardinality(), 3);
+        }
+        assert!(stream.next().await.unwrap().is_err());
+
+        // Each of the 3 rows takes 5 bytes in the memcomparable format, and the work tables of 2
+        // iterations are held in memory at the same time.
+        let mut stream = create_executor(true, 0, 20).execute();
+        assert_eq!(stream.next().await.unwrap().unwrap().cardinality(), 3);
+        assert!(stream.next().await.unwrap().is_err());
+    }
+}
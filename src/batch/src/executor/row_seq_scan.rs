@@ -1,3 +1,4 @@
+use std::ops::Bound::{self, *};
 use std::sync::Arc;
 
 use futures_async_stream::try_stream;
@@ -15,11 +16,15 @@ use futures_async_stream::try_stream;
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use itertools::Itertools;
-use risingwave_common::array::DataChunk;
-use risingwave_common::catalog::{ColumnDesc, Schema, TableId};
-use risingwave_common::error::{Result, RwError};
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{ColumnDesc, OrderedColumnDesc, Schema, TableId};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::Datum;
+use risingwave_common::util::ordered::OrderedRowSerializer;
+use risingwave_common::util::value_encoding::deserialize_datum;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
-use risingwave_storage::table::cell_based_table::{CellBasedTable, CellBasedTableRowIter};
+use risingwave_pb::batch_plan::{scan_range, ScanRange};
+use risingwave_storage::table::cell_based_table::CellBasedTable;
 use risingwave_storage::{dispatch_state_store, Keyspace, StateStore, StateStoreImpl};
 
 use crate::executor::monitor::BatchMetrics;
@@ -35,13 +40,27 @@ pub struct RowSeqScanExecutor<S: StateStore> {
     schema: Schema,
     identity: String,
     stats: Arc<BatchMetrics>,
-    row_iter: CellBasedTableRowIter<S>,
+    table: CellBasedTable<S>,
+    scans: Vec<ScanType>,
+    epoch: u64,
+}
+
+/// A part of the table to scan. The scans are done one by one when the executor is executed.
+pub enum ScanType {
+    /// Scans the whole table.
+    TableScan,
+    /// Scans the rows whose pk starts with the prefix, and whose next pk column is within the
+    /// bounds.
+    RangeScan(Row, (Bound<Datum>, Bound<Datum>)),
+    /// Gets the row by its pk, if any.
+    PointGet(Row),
 }
 
 impl<S: StateStore> RowSeqScanExecutor<S> {
     pub fn new(
-        schema: Schema,
-        row_iter: CellBasedTableRowIter<S>,
+        table: CellBasedTable<S>,
+        scans: Vec<ScanType>,
+        epoch: u64,
         chunk_size: usize,
         primary: bool,
         identity: String,
@@ -50,10 +69,12 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
         Self {
             primary,
             chunk_size,
-            schema,
+            schema: table.schema().clone(),
             identity,
             stats,
-            row_iter,
+            table,
+            scans,
+            epoch,
         }
     }
 
@@ -70,6 +91,56 @@ pub struct RowSeqScanExecutorBuilder {}
 impl RowSeqScanExecutorBuilder {
     // TODO: decide the chunk size for row seq scan
     pub const DEFAULT_CHUNK_SIZE: usize = 1024;
+
+    /// Decodes the value-encoded datums of `scan_range` with the types of the pk columns, into a
+    /// point get if it fixes the whole pk, or a range scan otherwise.
+    fn decode_scan_range(
+        scan_range: &ScanRange,
+        pk_descs: &[OrderedColumnDesc],
+    ) -> Result<ScanType> {
+        let num_bounded = scan_range.eq_conds.len()
+            + (scan_range.lower_bound.is_some() || scan_range.upper_bound.is_some()) as usize;
+        if num_bounded > pk_descs.len() {
+            return Err(ErrorCode::InternalError(format!(
+                "scan range on {} columns exceeds the pk of {} columns",
+                num_bounded,
+                pk_descs.len()
+            ))
+            .into());
+        }
+
+        let pk_prefix: Vec<_> = scan_range
+            .eq_conds
+            .iter()
+            .zip_eq(&pk_descs[..scan_range.eq_conds.len()])
+            .map(|(value, desc)| deserialize_datum(value.as_slice(), &desc.column_desc.data_type))
+            .try_collect()?;
+        // A range fixing the whole pk has at most one row.
+        if !pk_descs.is_empty() && pk_prefix.len() == pk_descs.len() {
+            return Ok(ScanType::PointGet(Row(pk_prefix)));
+        }
+
+        let decode_bound = |bound: &Option<scan_range::Bound>| -> Result<Bound<Datum>> {
+            Ok(match bound {
+                Some(bound) => {
+                    let data_type = &pk_descs[scan_range.eq_conds.len()].column_desc.data_type;
+                    let datum = deserialize_datum(bound.value.as_slice(), data_type)?;
+                    if bound.inclusive {
+                        Included(datum)
+                    } else {
+                        Excluded(datum)
+                    }
+                }
+                None => Unbounded,
+            })
+        };
+        let next_col_bounds = (
+            decode_bound(&scan_range.lower_bound)?,
+            decode_bound(&scan_range.upper_bound)?,
+        );
+
+        Ok(ScanType::RangeScan(Row(pk_prefix), next_col_bounds))
+    }
 }
 
 #[async_trait::async_trait]
@@ -95,15 +166,42 @@ impl BoxedExecutorBuilder for RowSeqScanExecutorBuilder {
             .iter()
             .map(|column_desc| ColumnDesc::from(column_desc.clone()))
             .collect_vec();
+        let pk_descs = seq_scan_node
+            .table_desc
+            .as_ref()
+            .unwrap()
+            .pk
+            .iter()
+            .map(|desc| OrderedColumnDesc::from(desc.clone()))
+            .collect_vec();
+        let scans = if seq_scan_node.scan_ranges.is_empty() {
+            vec![ScanType::TableScan]
+        } else {
+            seq_scan_node
+                .scan_ranges
+                .iter()
+                .map(|scan_range| Self::decode_scan_range(scan_range, &pk_descs))
+                .try_collect()?
+        };
+
         dispatch_state_store!(source.context().try_get_state_store()?, state_store, {
             let keyspace = Keyspace::table_root(state_store.clone(), &table_id);
             let storage_stats = state_store.stats();
             let batch_stats = source.context().stats();
-            let table = CellBasedTable::new_adhoc(keyspace, column_descs, storage_stats);
-            let iter = table.iter(source.epoch).await?;
+            let pk_serializer =
+                OrderedRowSerializer::new(pk_descs.iter().map(|desc| desc.order).collect());
+            let table = CellBasedTable::new(
+                keyspace,
+                column_descs,
+                Some(pk_serializer),
+                storage_stats,
+                None,
+            );
+
             Ok(Box::new(RowSeqScanExecutor::new(
-                table.schema().clone(),
-                iter,
+                table,
+                scans,
+                source.epoch,
                 RowSeqScanExecutorBuilder::DEFAULT_CHUNK_SIZE,
                 source.task_id.task_id == 0,
                 source.plan_node().get_identity().clone(),
@@ -131,22 +229,47 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
     #[try_stream(boxed, ok = DataChunk, error = RwError)]
     async fn do_execute(mut self: Box<Self>) {
         if !self.should_ignore() {
-            loop {
-                let timer = self.stats.row_seq_scan_next_duration.start_timer();
-
-                let chunk = self
-                    .row_iter
-                    .collect_data_chunk(&self.schema, Some(self.chunk_size))
-                    .await
-                    .map_err(RwError::from)?;
-                timer.observe_duration();
-
-                if let Some(chunk) = chunk {
-                    yield chunk
-                } else {
-                    break;
+            let data_types = self.schema.data_types();
+            // The rows of point gets are buffered until there are enough for a chunk.
+            let mut point_get_rows = Vec::with_capacity(self.chunk_size);
+
+            for scan in std::mem::take(&mut self.scans) {
+                let mut row_iter = match scan {
+                    ScanType::TableScan => self.table.iter(self.epoch).await?,
+                    ScanType::RangeScan(pk_prefix, next_col_bounds) => {
+                        self.table
+                            .iter_with_pk_bounds(self.epoch, &pk_prefix, next_col_bounds)
+                            .await?
+                    }
+                    ScanType::PointGet(pk) => {
+                        point_get_rows.extend(self.table.batch_get_row(&pk, self.epoch).await?);
+                        if point_get_rows.len() == self.chunk_size {
+                            yield DataChunk::from_rows(&point_get_rows, &data_types)?;
+                            point_get_rows.clear();
+                        }
+                        continue;
+                    }
+                };
+                loop {
+                    let timer = self.stats.row_seq_scan_next_duration.start_timer();
+
+                    let chunk = row_iter
+                        .collect_data_chunk(&self.schema, Some(self.chunk_size))
+                        .await
+                        .map_err(RwError::from)?;
+                    timer.observe_duration();
+
+                    if let Some(chunk) = chunk {
+                        yield chunk
+                    } else {
+                        break;
+                    }
                 }
             }
+
+            if !point_get_rows.is_empty() {
+                yield DataChunk::from_rows(&point_get_rows, &data_types)?;
+            }
         }
     }
 }
@@ -0,0 +1,140 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
+use risingwave_expr::table_function::{build_from_prost, BoxedTableFunction};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use super::{BoxedExecutor, BoxedExecutorBuilder};
+use crate::executor::{BoxedDataChunkStream, Executor, ExecutorBuilder};
+use crate::task::BatchTaskContext;
+
+/// `TableFunctionExecutor` evaluates a table function with constant arguments in the FROM clause.
+/// A set of structs, like the one of `jsonb_each`, is expanded into the fields of the structs.
+pub struct TableFunctionExecutor {
+    schema: Schema,
+    identity: String,
+    table_function: BoxedTableFunction,
+}
+
+impl TableFunctionExecutor {
+    pub fn new(table_function: BoxedTableFunction, identity: String) -> Self {
+        let fields = match table_function.return_type() {
+            DataType::Struct { fields } => fields.iter().cloned().map(Field::unnamed).collect(),
+            data_type => vec![Field::unnamed(data_type)],
+        };
+        Self {
+            schema: Schema { fields },
+            identity,
+            table_function,
+        }
+    }
+}
+
+impl Executor for TableFunctionExecutor {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl TableFunctionExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let is_struct = matches!(self.table_function.return_type(), DataType::Struct { .. });
+        let mut data_chunk_builder = DataChunkBuilder::with_default_size(self.schema.data_types());
+
+        for datum in self.table_function.eval_row(&Row::default())? {
+            let values: Vec<Datum> = match datum? {
+                Some(ScalarImpl::Struct(value)) if is_struct => value.fields().to_vec(),
+                None if is_struct => vec![None; self.schema.len()],
+                datum => vec![datum],
+            };
+            if let Some(chunk) = data_chunk_builder.append_one_row_from_datums(values.iter())? {
+                yield chunk;
+            }
+        }
+
+        if let Some(chunk) = data_chunk_builder.consume_all()? {
+            yield chunk;
+        }
+    }
+}
+
+pub struct TableFunctionExecutorBuilder {}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for TableFunctionExecutorBuilder {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        ensure!(
+            inputs.is_empty(),
+            "TableFunctionExecutor should not have child!"
+        );
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::TableFunction
+        )?;
+
+        let table_function = build_from_prost(node.get_table_function()?)?;
+        let identity = source.plan_node().get_identity().clone();
+        Ok(Box::new(TableFunctionExecutor::new(
+            table_function,
+            identity,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::util::chunk_coalesce::DEFAULT_CHUNK_BUFFER_SIZE;
+    use risingwave_expr::expr::{Expression, LiteralExpression};
+    use risingwave_expr::table_function::{GenerateSeries, TableFunction};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_table_function_executor() {
+        let literal = |v: i32| LiteralExpression::new(DataType::Int32, Some(v.into())).boxed();
+        let stop = (DEFAULT_CHUNK_BUFFER_SIZE * 2 + 3) as i32;
+        let generate_series = GenerateSeries::new(literal(1), literal(stop), literal(1));
+        let executor = Box::new(TableFunctionExecutor::new(
+            generate_series.boxed(),
+            "TableFunctionExecutor".to_string(),
+        ));
+        assert_eq!(executor.schema().data_types(), vec![DataType::Int32]);
+
+        let mut stream = executor.execute();
+        for expected_cardinality in [DEFAULT_CHUNK_BUFFER_SIZE, DEFAULT_CHUNK_BUFFER_SIZE, 3] {
+            let chunk = stream.next().await.unwrap().unwrap();
+            assert_eq!(chunk.cardinality(), expected_cardinality);
+        }
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::{Result, RwError};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::{
+    BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+};
+use crate::task::BatchTaskContext;
+
+/// Union executor, which returns the chunks of its children one after another, i.e. `UNION ALL`.
+pub struct UnionExecutor {
+    inputs: Vec<BoxedExecutor>,
+    /// Identity string of the executor
+    identity: String,
+}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for UnionExecutor {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        ensure!(
+            !inputs.is_empty(),
+            "UnionExecutor should have at least 1 child!"
+        );
+        try_match_expand!(source.plan_node().get_node_body().unwrap(), NodeBody::Union)?;
+
+        Ok(Box::new(Self {
+            inputs,
+            identity: source.plan_node().get_identity().clone(),
+        }))
+    }
+}
+
+impl UnionExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        for input in self.inputs {
+            #[for_await]
+            for data_chunk in input.execute() {
+                yield data_chunk?;
+            }
+        }
+    }
+}
+
+impl Executor for UnionExecutor {
+    fn schema(&self) -> &Schema {
+        self.inputs[0].schema()
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use risingwave_common::array::DataChunk;
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::test_prelude::DataChunkTestExt;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    #[tokio::test]
+    async fn test_union_executor() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ],
+        };
+        let left = MockExecutor::with_chunk(
+            DataChunk::from_pretty(
+                "i i
+                 1 2
+                 3 4",
+            ),
+            schema.clone(),
+        );
+        let right = MockExecutor::with_chunk(
+            DataChunk::from_pretty(
+                "i i
+                 1 2",
+            ),
+            schema,
+        );
+        let union_executor = Box::new(UnionExecutor {
+            inputs: vec![Box::new(left), Box::new(right)],
+            identity: "UnionExecutor".to_string(),
+        });
+        assert_eq!(union_executor.schema().len(), 2);
+
+        let mut stream = union_executor.execute();
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            DataChunk::from_pretty(
+                "i i
+                 1 2
+                 3 4",
+            )
+        );
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            DataChunk::from_pretty(
+                "i i
+                 1 2",
+            )
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::ops::Bound::Unbounded;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{ColumnDesc, TableId};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::ordered::OrderedRowSerializer;
+use risingwave_common::util::sort_util::OrderPair;
+use risingwave_pb::batch_plan::UniqueIndex as ProstUniqueIndex;
+use risingwave_storage::monitor::StateStoreMetrics;
+use risingwave_storage::table::cell_based_table::CellBasedTable;
+use risingwave_storage::table::TableIter;
+use risingwave_storage::{dispatch_state_store, Keyspace, StateStore, StateStoreImpl};
+
+use crate::executor::ExecutorBuilder;
+use crate::task::BatchTaskContext;
+
+/// A unique index on the target table of a DML.
+pub struct UniqueIndex {
+    pub index_id: TableId,
+    pub name: String,
+    /// The key columns of the index, referring to the columns of the table without the row id.
+    pub key: Vec<OrderPair>,
+    /// The column of the index that stores the row id of the table.
+    pub row_id_column: ColumnDesc,
+}
+
+impl UniqueIndex {
+    pub fn from_prost(index: &ProstUniqueIndex) -> Result<Self> {
+        Ok(Self {
+            index_id: TableId::new(index.index_id),
+            name: index.name.clone(),
+            key: index.key.iter().map(OrderPair::from_prost).collect(),
+            row_id_column: index.get_row_id_column()?.into(),
+        })
+    }
+}
+
+/// [`UniqueIndexCheck`] rejects the rows whose index keys already exist in the unique indexes, or
+/// appear more than once in the rows added by a DML. Rows with null keys never conflict.
+///
+/// The existing rows of the table that the DML removes don't conflict either, so that an `UPDATE`
+/// may keep the keys of the rows it updates.
+///
+/// The existing keys are read from the index tables at the epoch of the query. The frontend makes
+/// it see the writes of the DML before it by serializing the DML on the table and flushing each
+/// one, see `lock_unique_dml` in the frontend. The DML sent to the table by different frontends is
+/// not serialized though.
+pub struct UniqueIndexCheck {
+    state_store: StateStoreImpl,
+    epoch: u64,
+    indexes: Vec<UniqueIndex>,
+    /// The keys added so far, for each index.
+    added_keys: Vec<HashSet<Row>>,
+}
+
+impl UniqueIndexCheck {
+    pub fn new(state_store: StateStoreImpl, epoch: u64, indexes: Vec<UniqueIndex>) -> Self {
+        let added_keys = indexes.iter().map(|_| HashSet::new()).collect();
+        Self {
+            state_store,
+            epoch,
+            indexes,
+            added_keys,
+        }
+    }
+
+    /// Builds the check for the unique indexes of a DML node, or returns `None` if there's none.
+    pub fn build<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        indexes: &[ProstUniqueIndex],
+    ) -> Result<Option<Self>> {
+        if indexes.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self::new(
+            source.context().try_get_state_store()?,
+            source.epoch(),
+            indexes
+                .iter()
+                .map(UniqueIndex::from_prost)
+                .collect::<Result<_>>()?,
+        )))
+    }
+
+    /// Checks the rows of `added`, which consists of the columns of the table without the row id,
+    /// given the row ids of the existing rows removed by the DML.
+    pub async fn check(&mut self, added: &DataChunk, removed_row_ids: &HashSet<i64>) -> Result<()> {
+        for (index, added_keys) in self.indexes.iter().zip_eq(&mut self.added_keys) {
+            for row_idx in 0..added.cardinality() {
+                let key = Row(index
+                    .key
+                    .iter()
+                    .map(|pair| added.column_at(pair.column_idx).array().datum_at(row_idx))
+                    .collect());
+                if key.0.iter().any(|datum| datum.is_none()) {
+                    continue;
+                }
+                let duplicated = added_keys.contains(&key)
+                    || dispatch_state_store!(&self.state_store, state_store, {
+                        Self::index_contains(
+                            state_store.clone(),
+                            state_store.stats(),
+                            index,
+                            &key,
+                            removed_row_ids,
+                            self.epoch,
+                        )
+                        .await?
+                    });
+                if duplicated {
+                    return Err(ErrorCode::UniqueViolation(index.name.clone()).into());
+                }
+                added_keys.insert(key);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether the index contains `key` for a row that's not removed.
+    async fn index_contains<S: StateStore>(
+        state_store: S,
+        stats: Arc<StateStoreMetrics>,
+        index: &UniqueIndex,
+        key: &Row,
+        removed_row_ids: &HashSet<i64>,
+        epoch: u64,
+    ) -> Result<bool> {
+        let index_table = CellBasedTable::new(
+            Keyspace::table_root(state_store, &index.index_id),
+            vec![index.row_id_column.clone()],
+            Some(OrderedRowSerializer::new(
+                index.key.iter().map(|pair| pair.order_type).collect(),
+            )),
+            stats,
+            None,
+        );
+        let mut iter = index_table
+            .iter_with_pk_bounds(epoch, key, (Unbounded, Unbounded))
+            .await?;
+        while let Some(row) = iter.next().await? {
+            match &row.0[0] {
+                Some(ScalarImpl::Int64(row_id)) if removed_row_ids.contains(row_id) => {}
+                _ => return Ok(true),
+            }
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::{Array, I32Array};
+    use risingwave_common::catalog::ColumnId;
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::OrderType;
+    use risingwave_storage::memory::MemoryStateStore;
+    use risingwave_storage::table::state_table::StateTable;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unique_index_check() -> Result<()> {
+        let store = MemoryStateStore::new();
+        let index_id = TableId::new(1);
+        let row_id_column = ColumnDesc::unnamed(ColumnId::from(1), DataType::Int64);
+
+        // The index on `v` stores the key and the row id of the table.
+        let mut index_table = StateTable::new(
+            Keyspace::table_root(store.clone(), &index_id),
+            vec![
+                ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+                row_id_column.clone(),
+            ],
+            vec![OrderType::Descending, OrderType::Ascending],
+            None,
+            vec![0, 1],
+        );
+        index_table.insert(
+            &Row(vec![Some(1_i32.into()), Some(100_i64.into())]),
+            Row(vec![Some(1_i32.into()), Some(100_i64.into())]),
+        )?;
+        index_table.commit(0).await?;
+
+        let new_check = || {
+            UniqueIndexCheck::new(
+                StateStoreImpl::MemoryStateStore(
+                    store
+                        .clone()
+                        .monitored(Arc::new(StateStoreMetrics::unused())),
+                ),
+                u64::MAX,
+                vec![UniqueIndex {
+                    index_id,
+                    name: "idx".to_string(),
+                    key: vec![OrderPair::new(0, OrderType::Descending)],
+                    row_id_column: row_id_column.clone(),
+                }],
+            )
+        };
+        let chunk = |values: &[Option<i32>]| {
+            let array = I32Array::from_slice(values).unwrap();
+            DataChunk::new(vec![Column::new(Arc::new(array.into()))], values.len())
+        };
+        let no_removal = HashSet::new();
+
+        // New keys and null keys are accepted.
+        let mut check = new_check();
+        check
+            .check(&chunk(&[Some(2), None, None]), &no_removal)
+            .await?;
+        check.check(&chunk(&[Some(3)]), &no_removal).await?;
+
+        // Duplicated keys in the same DML are rejected.
+        let err = check
+            .check(&chunk(&[Some(2)]), &no_removal)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.inner(), ErrorCode::UniqueViolation(name) if name == "idx"));
+
+        // Existing keys are rejected.
+        let mut check = new_check();
+        let err = check
+            .check(&chunk(&[Some(1)]), &no_removal)
+            .await
+            .unwrap_err();
+        assert!(matches!(err.inner(), ErrorCode::UniqueViolation(name) if name == "idx"));
+
+        // Unless the rows holding them are removed by the DML.
+        let mut check = new_check();
+        check
+            .check(&chunk(&[Some(1)]), &HashSet::from([100]))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use futures::future::try_join_all;
 use futures_async_stream::try_stream;
 use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::{ArrayBuilder, DataChunk, Op, PrimitiveArrayBuilder, StreamChunk};
+use risingwave_common::array::{
+    Array, ArrayBuilder, DataChunk, Op, PrimitiveArrayBuilder, StreamChunk,
+};
 use risingwave_common::catalog::{Field, Schema, TableId};
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
@@ -26,6 +30,7 @@ use risingwave_source::SourceManagerRef;
 
 use crate::executor::{
     BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+    UniqueIndexCheck,
 };
 use crate::task::BatchTaskContext;
 
@@ -41,6 +46,9 @@ pub struct UpdateExecutor {
     exprs: Vec<BoxedExpression>,
     schema: Schema,
     identity: String,
+
+    /// Checks the updated rows against the unique indexes on the table, if any.
+    unique_check: Option<UniqueIndexCheck>,
 }
 
 impl UpdateExecutor {
@@ -66,8 +74,14 @@ impl UpdateExecutor {
                 fields: vec![Field::unnamed(DataType::Int64)],
             },
             identity: "UpdateExecutor".to_string(),
+            unique_check: None,
         }
     }
+
+    pub fn with_unique_check(mut self, unique_check: UniqueIndexCheck) -> Self {
+        self.unique_check = Some(unique_check);
+        self
+    }
 }
 
 impl Executor for UpdateExecutor {
@@ -91,8 +105,10 @@ impl UpdateExecutor {
         let source = source_desc.source.as_table_v2().expect("not table source");
 
         let schema = self.child.schema().clone();
-        let mut notifiers = Vec::new();
 
+        // Evaluate all the updates first, so that the unique indexes can be checked against the
+        // whole update before anything is written.
+        let mut updates = Vec::new();
         #[for_await]
         for data_chunk in self.child.execute() {
             let data_chunk = data_chunk?.compact()?;
@@ -107,6 +123,28 @@ impl UpdateExecutor {
 
                 DataChunk::new(columns, len)
             };
+            updates.push((data_chunk, updated_data_chunk));
+        }
+
+        if let Some(unique_check) = &mut self.unique_check {
+            // The first column is the row id, which is kept by the update.
+            let updated_row_ids = updates
+                .iter()
+                .flat_map(|(data_chunk, _)| data_chunk.column_at(0).array_ref().as_int64().iter())
+                .flatten()
+                .collect::<HashSet<_>>();
+            for (_, updated_data_chunk) in &updates {
+                let updated_rows = DataChunk::new(
+                    updated_data_chunk.columns()[1..].to_vec(),
+                    updated_data_chunk.cardinality(),
+                );
+                unique_check.check(&updated_rows, &updated_row_ids).await?;
+            }
+        }
+
+        let mut notifiers = Vec::new();
+        for (data_chunk, updated_data_chunk) in updates {
+            let len = data_chunk.cardinality();
 
             // Merge two data chunks into (U-, U+) pairs.
             // TODO: split chunks
@@ -182,12 +220,19 @@ impl BoxedExecutorBuilder for UpdateExecutor {
             .map(build_from_prost)
             .collect::<Result<Vec<BoxedExpression>>>()?;
 
-        Ok(Box::new(Self::new(
+        let executor = Self::new(
             table_id,
             source.context().try_get_source_manager_ref()?,
             inputs.remove(0),
             exprs,
-        )))
+        );
+
+        Ok(Box::new(
+            match UniqueIndexCheck::build(source, &update_node.unique_indexes)? {
+                Some(unique_check) => executor.with_unique_check(unique_check),
+                None => executor,
+            },
+        ))
     }
 }
 
@@ -196,7 +241,6 @@ mod tests {
     use std::sync::Arc;
 
     use futures::StreamExt;
-    use risingwave_common::array::Array;
     use risingwave_common::catalog::{schema_test_utils, ColumnDesc, ColumnId};
     use risingwave_common::test_prelude::DataChunkTestExt;
     use risingwave_expr::expr::InputRefExpression;
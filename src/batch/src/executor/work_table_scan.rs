@@ -0,0 +1,87 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::{
+    BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+};
+use crate::task::BatchTaskContext;
+
+/// [`WorkTableScanExecutor`] returns the rows of the work table of the enclosing
+/// [`super::RecursiveUnionExecutor`].
+pub struct WorkTableScanExecutor {
+    chunks: Arc<[DataChunk]>,
+    schema: Schema,
+    identity: String,
+}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for WorkTableScanExecutor {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        ensure!(
+            inputs.is_empty(),
+            "WorkTableScanExecutor should have no child!"
+        );
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::WorkTableScan
+        )?;
+        let chunks = source
+            .work_tables()
+            .get(&node.work_table_id)
+            .ok_or_else(|| InternalError(format!("work table {} not found", node.work_table_id)))?
+            .clone();
+        let fields = node.get_fields().iter().map(Field::from).collect();
+
+        Ok(Box::new(Self {
+            chunks,
+            schema: Schema { fields },
+            identity: source.plan_node().get_identity().clone(),
+        }))
+    }
+}
+
+impl WorkTableScanExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        for chunk in self.chunks.iter() {
+            yield chunk.clone();
+        }
+    }
+}
+
+impl Executor for WorkTableScanExecutor {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
@@ -0,0 +1,281 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{
+    Array, ArrayBuilder, DataChunk, I64ArrayBuilder, Op, PrimitiveArrayBuilder, StreamChunk,
+};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::catalog::{Field, Schema, TableId};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_source::SourceManagerRef;
+
+use crate::executor::{
+    BoxedDataChunkStream, BoxedExecutor, BoxedExecutorBuilder, Executor, ExecutorBuilder,
+    UniqueIndexCheck,
+};
+use crate::task::BatchTaskContext;
+
+/// [`WriteChangesExecutor`] writes the changes buffered by a transaction into a table as one chunk,
+/// so that the materialized view of the table sees all of them in the same epoch.
+pub struct WriteChangesExecutor {
+    /// Target table id.
+    table_id: TableId,
+    source_manager: SourceManagerRef,
+    /// The row id followed by the columns of the table. The row id is null for inserted rows.
+    changes: StreamChunk,
+    schema: Schema,
+    identity: String,
+
+    /// Checks the changes against the unique indexes on the table, if any.
+    unique_check: Option<UniqueIndexCheck>,
+}
+
+impl WriteChangesExecutor {
+    pub fn new(table_id: TableId, source_manager: SourceManagerRef, changes: StreamChunk) -> Self {
+        Self {
+            table_id,
+            source_manager,
+            changes,
+            schema: Schema {
+                fields: vec![Field::unnamed(DataType::Int64)],
+            },
+            identity: "WriteChangesExecutor".to_string(),
+            unique_check: None,
+        }
+    }
+
+    pub fn with_unique_check(mut self, unique_check: UniqueIndexCheck) -> Self {
+        self.unique_check = Some(unique_check);
+        self
+    }
+}
+
+impl Executor for WriteChangesExecutor {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl WriteChangesExecutor {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(mut self: Box<Self>) {
+        let source_desc = self.source_manager.get_source(&self.table_id)?;
+        let source = source_desc.source.as_table_v2().expect("not table source");
+
+        if let Some(unique_check) = &mut self.unique_check {
+            let (ops, columns) = (self.changes.ops(), self.changes.columns());
+            let removed_row_ids = ops
+                .iter()
+                .zip_eq(columns[0].array_ref().as_int64().iter())
+                .filter(|(op, _)| matches!(op, Op::Delete | Op::UpdateDelete))
+                .filter_map(|(_, row_id)| row_id)
+                .collect::<HashSet<_>>();
+            let added = DataChunk::new(
+                columns[1..].to_vec(),
+                Bitmap::try_from(
+                    ops.iter()
+                        .map(|op| matches!(op, Op::Insert | Op::UpdateInsert))
+                        .collect_vec(),
+                )?,
+            )
+            .compact()?;
+            unique_check.check(&added, &removed_row_ids).await?;
+        }
+
+        // Generate the row ids of the inserted rows.
+        let (ops, mut columns, _) = self.changes.into_inner();
+        let len = ops.len();
+        let mut builder = I64ArrayBuilder::new(len)?;
+        for row_id in columns[0].array_ref().as_int64().iter() {
+            builder.append(Some(row_id.unwrap_or_else(|| source_desc.next_row_id())))?;
+        }
+        columns[0] = Column::from(builder.finish()?);
+
+        let rows_written = source
+            .blocking_write_chunk(StreamChunk::new(ops, columns, None))
+            .await?;
+
+        // create ret value
+        {
+            let mut array_builder = PrimitiveArrayBuilder::<i64>::new(1)?;
+            array_builder.append(Some(rows_written as i64))?;
+
+            let array = array_builder.finish()?;
+            let ret_chunk = DataChunk::new(vec![array.into()], 1);
+
+            yield ret_chunk
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BoxedExecutorBuilder for WriteChangesExecutor {
+    async fn new_boxed_executor<C: BatchTaskContext>(
+        source: &ExecutorBuilder<C>,
+        inputs: Vec<BoxedExecutor>,
+    ) -> Result<BoxedExecutor> {
+        ensure!(
+            inputs.is_empty(),
+            "WriteChanges executor should not have input executor!"
+        );
+        let write_changes_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::WriteChanges
+        )?;
+
+        let table_id = TableId::from(&write_changes_node.table_source_ref_id);
+        let changes = StreamChunk::from_protobuf(write_changes_node.get_changes()?)?;
+
+        let executor = Self::new(
+            table_id,
+            source
+                .context()
+                .source_manager_ref()
+                .ok_or_else(|| InternalError("Source manager not found".to_string()))?,
+            changes,
+        );
+
+        Ok(Box::new(
+            match UniqueIndexCheck::build(source, &write_changes_node.unique_indexes)? {
+                Some(unique_check) => executor.with_unique_check(unique_check),
+                None => executor,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use futures::StreamExt;
+    use risingwave_common::catalog::{schema_test_utils, ColumnDesc, ColumnId};
+    use risingwave_common::test_prelude::StreamChunkTestExt;
+    use risingwave_source::{MemSourceManager, SourceManager, StreamSourceReader};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_changes_executor() -> Result<()> {
+        let source_manager = Arc::new(MemSourceManager::default());
+
+        // Schema of the table, with the row id first.
+        let schema = schema_test_utils::ii();
+        let table_columns: Vec<_> = [DataType::Int64]
+            .into_iter()
+            .chain(schema.fields.iter().map(|f| f.data_type.clone()))
+            .enumerate()
+            .map(|(i, data_type)| ColumnDesc {
+                data_type,
+                column_id: ColumnId::from(i as i32), // use column index as column id
+                name: "".to_string(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            })
+            .collect();
+
+        // Create the table.
+        let table_id = TableId::new(0);
+        source_manager.create_table_source(&table_id, table_columns)?;
+
+        // Create reader
+        let source_desc = source_manager.get_source(&table_id)?;
+        let source = source_desc.source.as_table_v2().unwrap();
+        let mut reader = source
+            .stream_reader(vec![0.into(), 1.into(), 2.into()])
+            .await?;
+
+        let changes = StreamChunk::from_pretty(
+            " I  i  i
+            +  .  1  2
+            -  7  3  4
+            U- 8  5  6
+            U+ 8  5  7
+            +  .  9 10",
+        );
+        let write_changes_executor = Box::new(WriteChangesExecutor::new(
+            table_id,
+            source_manager.clone(),
+            changes,
+        ));
+
+        let handle = tokio::spawn(async move {
+            let mut stream = write_changes_executor.execute();
+            let result = stream.next().await.unwrap().unwrap();
+
+            assert_eq!(
+                result
+                    .column_at(0)
+                    .array()
+                    .as_int64()
+                    .iter()
+                    .collect::<Vec<_>>(),
+                vec![Some(5)] // written rows
+            );
+        });
+
+        // All the changes are read as one chunk.
+        let chunk = reader.next().await?;
+
+        assert_eq!(
+            chunk.chunk.ops().to_vec(),
+            vec![
+                Op::Insert,
+                Op::Delete,
+                Op::UpdateDelete,
+                Op::UpdateInsert,
+                Op::Insert
+            ]
+        );
+
+        // Row ids are generated for the inserted rows only.
+        let row_ids = chunk.chunk.columns()[0]
+            .array()
+            .as_int64()
+            .iter()
+            .collect::<Vec<_>>();
+        assert!(row_ids.iter().all(Option::is_some));
+        assert_eq!(row_ids[1..4], [Some(7), Some(8), Some(8)]);
+        assert_ne!(row_ids[0], row_ids[4]);
+
+        assert_eq!(
+            chunk.chunk.columns()[2]
+                .array()
+                .as_int32()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(4), Some(6), Some(7), Some(10)]
+        );
+
+        handle.await.unwrap();
+
+        Ok(())
+    }
+}
@@ -175,13 +175,16 @@ impl Default for BatchManager {
 
 #[cfg(test)]
 mod tests {
+    use risingwave_common::types::DataType;
     use risingwave_expr::expr::make_i32_literal;
     use risingwave_pb::batch_plan::exchange_info::DistributionMode;
     use risingwave_pb::batch_plan::plan_node::NodeBody;
     use risingwave_pb::batch_plan::{
-        ExchangeInfo, GenerateSeriesNode, PlanFragment, PlanNode, TaskId as ProstTaskId,
+        ExchangeInfo, PlanFragment, PlanNode, TableFunctionNode, TaskId as ProstTaskId,
         TaskOutputId as ProstTaskOutputId, ValuesNode,
     };
+    use risingwave_pb::expr::table_function::Type as TableFunctionType;
+    use risingwave_pb::expr::TableFunction;
     use tonic::Code;
 
     use crate::task::{BatchManager, ComputeNodeContext, TaskId};
@@ -258,12 +261,18 @@ mod tests {
             root: Some(PlanNode {
                 children: vec![],
                 identity: "".to_string(),
-                node_body: Some(NodeBody::GenerateSeries(GenerateSeriesNode {
-                    start: Some(make_i32_literal(1)),
-                    // This is a bit hacky as we want to make sure the task lasts long enough
-                    // for us to abort it.
-                    stop: Some(make_i32_literal(i32::MAX)),
-                    step: Some(make_i32_literal(1)),
+                node_body: Some(NodeBody::TableFunction(TableFunctionNode {
+                    table_function: Some(TableFunction {
+                        function_type: TableFunctionType::GenerateSeries as i32,
+                        args: vec![
+                            make_i32_literal(1),
+                            // This is a bit hacky as we want to make sure the task lasts long
+                            // enough for us to abort it.
+                            make_i32_literal(i32::MAX),
+                            make_i32_literal(1),
+                        ],
+                        return_type: Some(DataType::Int32.to_protobuf()),
+                    }),
                 })),
             }),
             exchange_info: Some(ExchangeInfo {
@@ -0,0 +1,227 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::{Hash, Hasher};
+use std::iter;
+use std::mem::size_of;
+
+use itertools::Itertools;
+use risingwave_pb::data::buffer::CompressionType;
+use risingwave_pb::data::{Array as ProstArray, ArrayType, Buffer};
+
+use super::{Array, ArrayBuilder, ArrayIterator, ArrayMeta, NULL_VAL_FOR_HASH};
+use crate::array::ArrayBuilderImpl;
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::Result;
+use crate::types::{ByteaRef, ByteaVal};
+
+/// `ByteaArray` is a collection of byte strings, stored in the same layout as a `Utf8Array`.
+#[derive(Debug)]
+pub struct ByteaArray {
+    offset: Vec<usize>,
+    bitmap: Bitmap,
+    data: Vec<u8>,
+}
+
+impl Array for ByteaArray {
+    type Builder = ByteaArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = ByteaVal;
+    type RefItem<'a> = ByteaRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<ByteaRef<'_>> {
+        if !self.is_null(idx) {
+            Some(ByteaRef::from(
+                &self.data[self.offset[idx]..self.offset[idx + 1]],
+            ))
+        } else {
+            None
+        }
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<ByteaRef<'_>> {
+        if !self.is_null_unchecked(idx) {
+            Some(ByteaRef::from(self.data.get_unchecked(
+                *self.offset.get_unchecked(idx)..*self.offset.get_unchecked(idx + 1),
+            )))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.offset.len() - 1
+    }
+
+    fn iter(&self) -> ArrayIterator<'_, Self> {
+        ArrayIterator::new(self)
+    }
+
+    fn to_protobuf(&self) -> ProstArray {
+        let offset_buffer = self
+            .offset
+            .iter()
+            // the offset of the end is always pushed, see `Utf8Array::to_protobuf`
+            .zip_eq(self.null_bitmap().iter().chain(iter::once(true)))
+            .fold(
+                Vec::<u8>::with_capacity(self.offset.len() * size_of::<usize>()),
+                |mut buffer, (offset, not_null)| {
+                    if not_null {
+                        let offset = *offset as u64;
+                        buffer.extend_from_slice(&offset.to_be_bytes());
+                    }
+                    buffer
+                },
+            );
+
+        let values = vec![
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: offset_buffer,
+            },
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: self.data.clone(),
+            },
+        ];
+        let null_bitmap = self.null_bitmap().to_protobuf();
+        ProstArray {
+            null_bitmap: Some(null_bitmap),
+            values,
+            array_type: ArrayType::Bytea as i32,
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    #[inline(always)]
+    fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            state.write(&self.data[self.offset[idx]..self.offset[idx + 1]]);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = ByteaArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Bytea(array_builder))
+    }
+}
+
+impl ByteaArray {
+    pub fn from_slice(data: &[Option<&[u8]>]) -> Result<Self> {
+        let mut builder = <Self as Array>::Builder::new(data.len())?;
+        for i in data {
+            builder.append(i.map(ByteaRef::from))?;
+        }
+        builder.finish()
+    }
+}
+
+/// `ByteaArrayBuilder` use `ByteaRef` to build a `ByteaArray`.
+#[derive(Debug)]
+pub struct ByteaArrayBuilder {
+    offset: Vec<usize>,
+    bitmap: BitmapBuilder,
+    data: Vec<u8>,
+}
+
+impl ArrayBuilder for ByteaArrayBuilder {
+    type ArrayType = ByteaArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        let mut offset = Vec::with_capacity(capacity + 1);
+        offset.push(0);
+        Ok(Self {
+            offset,
+            data: Vec::with_capacity(capacity),
+            bitmap: BitmapBuilder::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<ByteaRef<'_>>) -> Result<()> {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.extend_from_slice(x.as_bytes());
+                self.offset.push(self.data.len())
+            }
+            None => {
+                self.bitmap.append(false);
+                self.offset.push(self.data.len())
+            }
+        }
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &ByteaArray) -> Result<()> {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+        let start = *self.offset.last().unwrap();
+        for other_offset in &other.offset[1..] {
+            self.offset.push(*other_offset + start);
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ByteaArray> {
+        Ok(ByteaArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+            offset: self.offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::ArrayImpl;
+    use crate::types::ScalarRef;
+
+    #[test]
+    fn test_bytea_array_protobuf() {
+        let input: Vec<Option<&[u8]>> = vec![
+            Some(&[0, 0xff][..]),
+            None,
+            Some(&b""[..]),
+            Some(&b"abc"[..]),
+        ];
+        let array = ByteaArray::from_slice(&input).unwrap();
+        assert_eq!(array.len(), 4);
+        assert_eq!(array.value_at(3).unwrap().as_bytes(), b"abc");
+
+        let prost = array.to_protobuf();
+        let decoded = ArrayImpl::from_protobuf(&prost, input.len()).unwrap();
+        let decoded = decoded.as_bytea();
+        assert_eq!(
+            decoded
+                .iter()
+                .map(|v| v.map(|v| v.to_owned_scalar()))
+                .collect_vec(),
+            input.iter().map(|v| v.map(ByteaVal::from)).collect_vec()
+        );
+    }
+}
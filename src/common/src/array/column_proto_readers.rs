@@ -22,13 +22,13 @@ use crate::array::value_reader::{PrimitiveValueReader, VarSizedValueReader};
 use crate::array::{
     Array, ArrayBuilder, ArrayImpl, ArrayMeta, BoolArray, IntervalArrayBuilder,
     NaiveDateArrayBuilder, NaiveDateTimeArrayBuilder, NaiveTimeArrayBuilder, PrimitiveArrayBuilder,
-    PrimitiveArrayItemType,
+    PrimitiveArrayItemType, UuidArrayBuilder,
 };
 use crate::buffer::Bitmap;
 use crate::error::ErrorCode::InternalError;
 use crate::error::{Result, RwError};
 use crate::types::interval::IntervalUnit;
-use crate::types::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper};
+use crate::types::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, Uuid};
 
 // TODO: Use techniques like apache arrow flight RPC to eliminate deserialization.
 // https://arrow.apache.org/docs/format/Flight.html
@@ -125,6 +125,17 @@ pub fn read_interval_unit(cursor: &mut Cursor<&[u8]>) -> Result<IntervalUnit> {
     })
 }
 
+fn read_uuid(cursor: &mut Cursor<&[u8]>) -> Result<Uuid> {
+    let mut bytes = [0; 16];
+    cursor.read_exact(&mut bytes).map_err(|e| {
+        RwError::from(InternalError(format!(
+            "Failed to read 16 bytes from Uuid buffer: {}",
+            e
+        )))
+    })?;
+    Ok(Uuid::from_bytes(bytes))
+}
+
 macro_rules! read_one_value_array {
     ($({ $type:ident, $builder:ty }),*) => {
         paste! {
@@ -159,7 +170,8 @@ read_one_value_array! {
     { IntervalUnit, IntervalArrayBuilder },
     { NaiveDate, NaiveDateArrayBuilder },
     { NaiveTime, NaiveTimeArrayBuilder },
-    { NaiveDateTime, NaiveDateTimeArrayBuilder }
+    { NaiveDateTime, NaiveDateTimeArrayBuilder },
+    { Uuid, UuidArrayBuilder }
 }
 
 fn read_offset(offset_cursor: &mut Cursor<&[u8]>) -> Result<i64> {
@@ -0,0 +1,220 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::{Hash, Hasher};
+use std::mem::size_of;
+use std::str::FromStr;
+
+use itertools::Itertools;
+use risingwave_pb::data::buffer::CompressionType;
+use risingwave_pb::data::{Array as ProstArray, ArrayType, Buffer};
+use serde_json::Value;
+
+use super::{Array, ArrayBuilder, ArrayIterator, NULL_VAL_FOR_HASH};
+use crate::array::value_reader::Utf8ValueReader;
+use crate::array::{read_string_array, ArrayBuilderImpl, ArrayImpl, ArrayMeta, Utf8ArrayBuilder};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::Result;
+use crate::types::{JsonbRef, JsonbVal, Scalar};
+
+#[derive(Debug)]
+pub struct JsonbArray {
+    bitmap: Bitmap,
+    data: Vec<Value>,
+}
+
+impl JsonbArray {
+    pub fn from_slice(data: &[Option<JsonbVal>]) -> Result<Self> {
+        let mut builder = <Self as Array>::Builder::new(data.len())?;
+        for i in data {
+            builder.append(i.as_ref().map(|v| v.as_scalar_ref()))?;
+        }
+        builder.finish()
+    }
+
+    /// Values are encoded with their compact text in the same layout as a string array.
+    pub fn from_protobuf(array: &ProstArray, cardinality: usize) -> Result<ArrayImpl> {
+        let texts = read_string_array::<Utf8ArrayBuilder, Utf8ValueReader>(array, cardinality)?;
+        let mut builder = JsonbArrayBuilder::new(cardinality)?;
+        for text in texts.as_utf8().iter() {
+            let value = text.map(JsonbVal::from_str).transpose()?;
+            builder.append(value.as_ref().map(|v| v.as_scalar_ref()))?;
+        }
+        Ok(builder.finish()?.into())
+    }
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = JsonbVal;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null(idx) {
+            Some(JsonbRef::from(&self.data[idx]))
+        } else {
+            None
+        }
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<JsonbRef<'_>> {
+        if !self.is_null_unchecked(idx) {
+            Some(JsonbRef::from(self.data.get_unchecked(idx)))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayIterator::new(self)
+    }
+
+    fn to_protobuf(&self) -> ProstArray {
+        let mut offset_buffer = Vec::<u8>::with_capacity(self.data.len() * size_of::<usize>());
+        let mut data_buffer = Vec::<u8>::new();
+        let mut offset = 0usize;
+        for (d, not_null) in self.data.iter().zip_eq(self.null_bitmap().iter()) {
+            if not_null {
+                let s = JsonbRef::from(d).to_compact_string();
+                let b = s.as_bytes();
+                offset_buffer.extend_from_slice(&offset.to_be_bytes());
+                data_buffer.extend_from_slice(b);
+                offset += b.len();
+            }
+        }
+        offset_buffer.extend_from_slice(&offset.to_be_bytes());
+
+        let values = vec![
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: offset_buffer,
+            },
+            Buffer {
+                compression: CompressionType::None as i32,
+                body: data_buffer,
+            },
+        ];
+        let null_bitmap = self.null_bitmap().to_protobuf();
+        ProstArray {
+            null_bitmap: Some(null_bitmap),
+            values,
+            array_type: ArrayType::Jsonb as i32,
+            struct_array_data: None,
+            list_array_data: None,
+        }
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    #[inline(always)]
+    fn hash_at<H: Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            JsonbRef::from(&self.data[idx]).hash(state);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = JsonbArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Jsonb(array_builder))
+    }
+}
+
+/// `JsonbArrayBuilder` constructs a `JsonbArray` from `Option<JsonbRef>`.
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Value>,
+}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        Ok(Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<JsonbRef<'_>>) -> Result<()> {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.push(x.value().clone());
+            }
+            None => {
+                self.bitmap.append(false);
+                self.data.push(Value::Null);
+            }
+        }
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &JsonbArray) -> Result<()> {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<JsonbArray> {
+        Ok(JsonbArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ScalarRef;
+
+    #[test]
+    fn test_jsonb_array_protobuf() {
+        let input = [
+            Some(JsonbVal::from_str(r#"{"a": [1, "x"]}"#).unwrap()),
+            None,
+            Some(JsonbVal::null()),
+            Some(JsonbVal::from_str(r#""s""#).unwrap()),
+        ];
+        let array = JsonbArray::from_slice(&input).unwrap();
+        let prost = array.to_protobuf();
+        assert_eq!(prost.values[1].get_body(), br#"{"a":[1,"x"]}null"s""#);
+
+        let decoded = ArrayImpl::from_protobuf(&prost, input.len()).unwrap();
+        let decoded = decoded.as_jsonb();
+        assert_eq!(
+            decoded
+                .iter()
+                .map(|v| v.map(|v| v.to_owned_scalar()))
+                .collect_vec(),
+            input
+        );
+    }
+}
@@ -15,6 +15,7 @@
 //! `Array` defines all in-memory representations of vectorized execution framework.
 
 mod bool_array;
+mod bytea_array;
 mod chrono_array;
 pub mod column;
 mod column_proto_readers;
@@ -23,6 +24,7 @@ pub mod data_chunk_iter;
 mod decimal_array;
 pub mod interval_array;
 mod iterator;
+mod jsonb_array;
 pub mod list_array;
 mod macros;
 mod primitive_array;
@@ -30,6 +32,7 @@ pub mod stream_chunk;
 mod stream_chunk_iter;
 pub mod struct_array;
 mod utf8_array;
+mod uuid_array;
 mod value_reader;
 
 use std::convert::From;
@@ -37,6 +40,7 @@ use std::hash::Hasher;
 use std::sync::Arc;
 
 pub use bool_array::{BoolArray, BoolArrayBuilder};
+pub use bytea_array::{ByteaArray, ByteaArrayBuilder};
 pub use chrono_array::{
     NaiveDateArray, NaiveDateArrayBuilder, NaiveDateTimeArray, NaiveDateTimeArrayBuilder,
     NaiveTimeArray, NaiveTimeArrayBuilder,
@@ -47,6 +51,7 @@ pub use data_chunk_iter::{Row, RowDeserializer, RowRef};
 pub use decimal_array::{DecimalArray, DecimalArrayBuilder};
 pub use interval_array::{IntervalArray, IntervalArrayBuilder};
 pub use iterator::ArrayIterator;
+pub use jsonb_array::{JsonbArray, JsonbArrayBuilder};
 pub use list_array::{ListArray, ListArrayBuilder, ListRef, ListValue};
 use paste::paste;
 pub use primitive_array::{PrimitiveArray, PrimitiveArrayBuilder, PrimitiveArrayItemType};
@@ -54,6 +59,7 @@ use risingwave_pb::data::{Array as ProstArray, ArrayType as ProstArrayType};
 pub use stream_chunk::{Op, StreamChunk, StreamChunkTestExt};
 pub use struct_array::{StructArray, StructArrayBuilder, StructRef, StructValue};
 pub use utf8_array::*;
+pub use uuid_array::{UuidArray, UuidArrayBuilder};
 
 use crate::array::iterator::ArrayImplIterator;
 use crate::buffer::Bitmap;
@@ -263,6 +269,9 @@ macro_rules! for_all_variants {
             { NaiveDate, naivedate, NaiveDateArray, NaiveDateArrayBuilder },
             { NaiveDateTime, naivedatetime, NaiveDateTimeArray, NaiveDateTimeArrayBuilder },
             { NaiveTime, naivetime, NaiveTimeArray, NaiveTimeArrayBuilder },
+            { Jsonb, jsonb, JsonbArray, JsonbArrayBuilder },
+            { Bytea, bytea, ByteaArray, ByteaArrayBuilder },
+            { Uuid, uuid, UuidArray, UuidArrayBuilder },
             { Struct, struct, StructArray, StructArrayBuilder },
             { List, list, ListArray, ListArrayBuilder }
         }
@@ -298,6 +307,18 @@ impl From<DecimalArray> for ArrayImpl {
     }
 }
 
+impl From<JsonbArray> for ArrayImpl {
+    fn from(arr: JsonbArray) -> Self {
+        Self::Jsonb(arr)
+    }
+}
+
+impl From<ByteaArray> for ArrayImpl {
+    fn from(arr: ByteaArray) -> Self {
+        Self::Bytea(arr)
+    }
+}
+
 impl From<Utf8Array> for ArrayImpl {
     fn from(arr: Utf8Array) -> Self {
         Self::Utf8(arr)
@@ -576,6 +597,11 @@ impl ArrayImpl {
             ProstArrayType::Time => read_naive_time_array(array, cardinality)?,
             ProstArrayType::Timestamp => read_naive_date_time_array(array, cardinality)?,
             ProstArrayType::Interval => read_interval_unit_array(array, cardinality)?,
+            ProstArrayType::Jsonb => JsonbArray::from_protobuf(array, cardinality)?,
+            ProstArrayType::Bytea => {
+                read_string_array::<ByteaArrayBuilder, ByteaValueReader>(array, cardinality)?
+            }
+            ProstArrayType::Uuid => read_uuid_array(array, cardinality)?,
             ProstArrayType::Struct => StructArray::from_protobuf(array)?,
             ProstArrayType::List => ListArray::from_protobuf(array)?,
         };
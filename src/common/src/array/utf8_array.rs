@@ -281,6 +281,13 @@ impl PartialBytesWriter {
     }
 }
 
+/// Allows formatting values like integers into the record without an intermediate `String`.
+impl std::fmt::Write for PartialBytesWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.write_ref(s).map_err(|_| std::fmt::Error)
+    }
+}
+
 /// `BytesGuard` guarded that exactly one record was appendded.
 /// `BytesGuard` will be produced iff the `BytesWriter` was consumed.
 pub struct BytesGuard {
@@ -0,0 +1,39 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{PrimitiveArray, PrimitiveArrayBuilder};
+use crate::types::Uuid;
+
+pub type UuidArray = PrimitiveArray<Uuid>;
+pub type UuidArrayBuilder = PrimitiveArrayBuilder<Uuid>;
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::array::{Array, ArrayImpl};
+
+    #[test]
+    fn test_uuid_array_protobuf() {
+        let uuid = Uuid::from_str("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11").unwrap();
+        let array = UuidArray::from_slice(&[Some(uuid), None, Some(Uuid::default())]).unwrap();
+        let prost = array.to_protobuf();
+        let decoded = ArrayImpl::from_protobuf(&prost, 3).unwrap();
+        assert_eq!(
+            decoded.as_uuid().iter().collect::<Vec<_>>(),
+            array.iter().collect::<Vec<_>>()
+        );
+    }
+}
@@ -18,11 +18,12 @@ use std::str::{from_utf8, FromStr};
 use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::array::{
-    Array, ArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType, Utf8ArrayBuilder,
+    Array, ArrayBuilder, ByteaArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType,
+    Utf8ArrayBuilder,
 };
 use crate::error::ErrorCode::InternalError;
 use crate::error::{ErrorCode, Result, RwError};
-use crate::types::{Decimal, OrderedF32, OrderedF64};
+use crate::types::{ByteaRef, Decimal, OrderedF32, OrderedF64};
 
 /// Reads an encoded buffer into a value.
 pub trait PrimitiveValueReader<T: PrimitiveArrayItemType> {
@@ -73,6 +74,14 @@ impl VarSizedValueReader<Utf8ArrayBuilder> for Utf8ValueReader {
     }
 }
 
+pub struct ByteaValueReader {}
+
+impl VarSizedValueReader<ByteaArrayBuilder> for ByteaValueReader {
+    fn read(buf: &[u8]) -> Result<ByteaRef<'_>> {
+        Ok(ByteaRef::from(buf))
+    }
+}
+
 pub struct DecimalValueReader {}
 
 impl VarSizedValueReader<DecimalArrayBuilder> for DecimalValueReader {
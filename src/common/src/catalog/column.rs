@@ -229,6 +229,15 @@ impl From<&ColumnDesc> for ProstColumnDesc {
     }
 }
 
+impl From<&OrderedColumnDesc> for ProstOrderedColumnDesc {
+    fn from(c: &OrderedColumnDesc) -> Self {
+        Self {
+            column_desc: Some((&c.column_desc).into()),
+            order: c.order.to_prost() as i32,
+        }
+    }
+}
+
 impl From<ProstOrderedColumnDesc> for OrderedColumnDesc {
     fn from(prost: ProstOrderedColumnDesc) -> Self {
         Self {
@@ -110,6 +110,8 @@ pub enum ErrorCode {
     CatalogError(BoxedError),
     #[error("Out of range")]
     NumericValueOutOfRange,
+    #[error("Division by zero")]
+    DivisionByZero,
     #[error("protocol error: {0}")]
     ProtocolError(String),
     #[error("Task not found")]
@@ -131,6 +133,12 @@ pub enum ErrorCode {
     },
     #[error("Invalid Parameter Value: {0}")]
     InvalidParameterValue(String),
+    /// An RPC that failed to reach the other node or get its response, e.g. because the node is
+    /// down, rather than an error returned by the node.
+    #[error("RPC error: {0}")]
+    RpcError(String),
+    #[error("duplicate key value violates unique index \"{0}\"")]
+    UniqueViolation(String),
 
     /// This error occurs when the meta node receives heartbeat from a previous removed worker
     /// node. Currently we don't support re-register, and the worker node need a full restart.
@@ -308,6 +316,9 @@ impl ErrorCode {
             ErrorCode::UnknownWorker => 24,
             ErrorCode::ConnectorError(_) => 25,
             ErrorCode::InvalidParameterValue(_) => 26,
+            ErrorCode::DivisionByZero => 27,
+            ErrorCode::RpcError(_) => 28,
+            ErrorCode::UniqueViolation(_) => 29,
             ErrorCode::UnknownError(_) => 101,
         }
     }
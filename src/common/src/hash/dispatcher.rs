@@ -118,6 +118,7 @@ mod tests {
             DataType::Decimal,   // 6
             DataType::Varchar,   // 7
             DataType::Timestamp, // 8
+            DataType::Uuid,      // 9
         ]
     }
 
@@ -142,6 +143,8 @@ mod tests {
         compare_key_kinds(&[8], HashKeyKind::Key128);
         compare_key_kinds(&[3, 4], HashKeyKind::Key128);
         compare_key_kinds(&[3, 4, 6], HashKeyKind::Key256);
+        compare_key_kinds(&[9], HashKeyKind::Key128);
+        compare_key_kinds(&[3, 9], HashKeyKind::Key256);
         compare_key_kinds(&[7], HashKeyKind::KeySerialized);
         compare_key_kinds(&[1, 7], HashKeyKind::KeySerialized);
     }
@@ -27,8 +27,8 @@ use crate::array::{
 };
 use crate::error::Result;
 use crate::types::{
-    DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum,
+    ByteaRef, DataType, Datum, Decimal, IntervalUnit, JsonbRef, NaiveDateTimeWrapper,
+    NaiveDateWrapper, NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum, Uuid,
 };
 use crate::util::hash_util::CRC32FastBuilder;
 
@@ -360,6 +360,34 @@ impl<'a> HashKeySerDe<'a> for &'a str {
     }
 }
 
+impl<'a> HashKeySerDe<'a> for JsonbRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize jsonb for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize jsonb for hash!")
+    }
+}
+
+impl<'a> HashKeySerDe<'a> for ByteaRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize bytea for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize bytea for hash!")
+    }
+}
+
 impl HashKeySerDe<'_> for NaiveDateWrapper {
     type S = [u8; 4];
 
@@ -415,6 +443,18 @@ impl HashKeySerDe<'_> for NaiveTimeWrapper {
     }
 }
 
+impl HashKeySerDe<'_> for Uuid {
+    type S = [u8; 16];
+
+    fn serialize(self) -> Self::S {
+        *self.as_bytes()
+    }
+
+    fn deserialize<R: Read>(source: &mut R) -> Self {
+        Uuid::from_bytes(Self::read_fixed_size_bytes::<R, 16>(source))
+    }
+}
+
 impl<'a> HashKeySerDe<'a> for StructRef<'a> {
     type S = Vec<u8>;
 
@@ -665,9 +705,9 @@ mod tests {
     use crate::array;
     use crate::array::column::Column;
     use crate::array::{
-        ArrayRef, BoolArray, DataChunk, DataChunkTestExt, DecimalArray, F32Array, F64Array,
-        I16Array, I32Array, I32ArrayBuilder, I64Array, NaiveDateArray, NaiveDateTimeArray,
-        NaiveTimeArray, Utf8Array,
+        ArrayRef, BoolArray, ByteaArray, DataChunk, DataChunkTestExt, DecimalArray, F32Array,
+        F64Array, I16Array, I32Array, I32ArrayBuilder, I64Array, NaiveDateArray,
+        NaiveDateTimeArray, NaiveTimeArray, Utf8Array, UuidArray,
     };
     use crate::hash::{
         HashKey, Key128, Key16, Key256, Key32, Key64, KeySerialized, PrecomputedBuildHasher,
@@ -696,6 +736,8 @@ mod tests {
                 capacity,
                 seed + 10,
             )),
+            Column::new(seed_rand_array_ref::<ByteaArray>(capacity, seed + 11)),
+            Column::new(seed_rand_array_ref::<UuidArray>(capacity, seed + 12)),
         ];
 
         DataChunk::new(columns, capacity)
@@ -821,17 +863,20 @@ mod tests {
     fn test_128_bits_hash_key() {
         do_test::<Key128, _>(vec![3, 5], generate_random_data_chunk);
         do_test::<Key128, _>(vec![6], generate_random_data_chunk);
+        do_test::<Key128, _>(vec![12], generate_random_data_chunk);
     }
 
     #[test]
     fn test_256_bits_hash_key() {
         do_test::<Key256, _>(vec![3, 5, 6], generate_random_data_chunk);
         do_test::<Key256, _>(vec![3, 6], generate_random_data_chunk);
+        do_test::<Key256, _>(vec![3, 12], generate_random_data_chunk);
     }
 
     #[test]
     fn test_var_length_hash_key() {
         do_test::<KeySerialized, _>(vec![0, 7], generate_random_data_chunk);
+        do_test::<KeySerialized, _>(vec![11], generate_random_data_chunk);
     }
 
     fn generate_decimal_test_data() -> DataChunk {
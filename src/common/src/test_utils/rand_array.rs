@@ -27,8 +27,8 @@ use rand::{Rng, SeedableRng};
 
 use crate::array::{Array, ArrayBuilder, ArrayRef, ListValue, StructValue};
 use crate::types::{
-    Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, NativeType,
-    Scalar,
+    ByteaVal, Decimal, IntervalUnit, JsonbVal, NaiveDateTimeWrapper, NaiveDateWrapper,
+    NaiveTimeWrapper, NativeType, Scalar, Uuid,
 };
 
 pub trait RandValue {
@@ -103,6 +103,28 @@ impl RandValue for bool {
     }
 }
 
+impl RandValue for JsonbVal {
+    fn rand_value<R: Rng>(rand: &mut R) -> Self {
+        JsonbVal::from_value(rand.gen::<i32>().into())
+    }
+}
+
+impl RandValue for ByteaVal {
+    fn rand_value<R: Rng>(rand: &mut R) -> Self {
+        let len = rand.gen_range(0..=10);
+        (0..len)
+            .map(|_| rand.gen::<u8>())
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl RandValue for Uuid {
+    fn rand_value<R: Rng>(rand: &mut R) -> Self {
+        Uuid::from_bytes(rand.gen())
+    }
+}
+
 impl RandValue for StructValue {
     fn rand_value<R: rand::Rng>(_rand: &mut R) -> Self {
         StructValue::new(vec![])
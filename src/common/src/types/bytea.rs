@@ -0,0 +1,192 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter, Write};
+use std::str::FromStr;
+
+use crate::error::{ErrorCode, Result, RwError};
+
+/// An owned BYTEA value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteaVal(Box<[u8]>);
+
+/// A reference to a BYTEA value, either owned by a [`ByteaVal`] or stored in a `ByteaArray`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteaRef<'a>(&'a [u8]);
+
+impl ByteaVal {
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_boxed_bytes(self) -> Box<[u8]> {
+        self.0
+    }
+
+    /// Parses the hex format without the leading `\x`, where whitespace between bytes is ignored.
+    pub fn from_hex_str(hex: &str) -> Result<Self> {
+        parse_hex(hex)
+            .map(Self::from)
+            .ok_or_else(|| invalid_input(hex))
+    }
+
+    /// Parses the escape format, where a backslash is written as `\\` and any byte can be
+    /// written as `\nnn` in octal.
+    pub fn from_escape_str(s: &str) -> Result<Self> {
+        parse_escape(s)
+            .map(Self::from)
+            .ok_or_else(|| invalid_input(s))
+    }
+}
+
+impl From<Vec<u8>> for ByteaVal {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes.into_boxed_slice())
+    }
+}
+
+impl From<&[u8]> for ByteaVal {
+    fn from(bytes: &[u8]) -> Self {
+        Self(bytes.into())
+    }
+}
+
+fn invalid_input(s: &str) -> RwError {
+    ErrorCode::InvalidInputSyntax(format!("invalid input syntax for type bytea: \"{}\"", s)).into()
+}
+
+/// Parses the text input of a bytea, in either the hex format, e.g. `\xdeadbeef`, or the escape
+/// format.
+impl FromStr for ByteaVal {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.strip_prefix("\\x") {
+            Some(hex) => parse_hex(hex),
+            None => parse_escape(s),
+        }
+        .map(Self::from)
+        .ok_or_else(|| invalid_input(s))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    let digits = hex
+        .bytes()
+        .filter(|b| !b.is_ascii_whitespace())
+        .collect::<Vec<_>>();
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+fn parse_escape(s: &str) -> Option<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            result.push(bytes[i]);
+            i += 1;
+        } else if bytes.get(i + 1) == Some(&b'\\') {
+            result.push(b'\\');
+            i += 2;
+        } else {
+            let octal = bytes.get(i + 1..i + 4)?;
+            if !octal.iter().all(|b| (b'0'..=b'7').contains(b)) || octal[0] > b'3' {
+                return None;
+            }
+            result.push(octal.iter().fold(0, |acc, b| acc * 8 + (b - b'0')));
+            i += 4;
+        }
+    }
+    Some(result)
+}
+
+impl Display for ByteaVal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        ByteaRef(&self.0).fmt(f)
+    }
+}
+
+impl<'a> ByteaRef<'a> {
+    pub fn as_bytes(self) -> &'a [u8] {
+        self.0
+    }
+
+    /// The text in the hex format, e.g. `\xdeadbeef`, which is also how a bytea is displayed.
+    pub fn to_hex_string(self) -> String {
+        self.to_string()
+    }
+
+    /// The text in the escape format, where printable ASCII characters are kept, a backslash is
+    /// doubled and the other bytes are written as `\nnn` in octal.
+    pub fn to_escape_string(self) -> String {
+        let mut s = String::with_capacity(self.0.len());
+        for &b in self.0 {
+            match b {
+                b'\\' => s.push_str("\\\\"),
+                0x20..=0x7e => s.push(b as char),
+                _ => write!(s, "\\{:03o}", b).unwrap(),
+            }
+        }
+        s
+    }
+}
+
+impl Display for ByteaRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("\\x")?;
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a [u8]> for ByteaRef<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Scalar;
+
+    #[test]
+    fn test_text_io() {
+        let v = ByteaVal::from(vec![0xde, 0xad, b'a', b'\\', 0]);
+        assert_eq!(v.to_string(), "\\xdead615c00");
+        assert_eq!(v.as_scalar_ref().to_escape_string(), "\\336\\255a\\\\\\000");
+
+        assert_eq!(ByteaVal::from_str("\\xDEAD615c00").unwrap(), v);
+        assert_eq!(ByteaVal::from_str("\\x de ad 61 5c 00").unwrap(), v);
+        assert_eq!(ByteaVal::from_str("\\336\\255a\\\\\\000").unwrap(), v);
+        assert_eq!(ByteaVal::from_str("").unwrap(), ByteaVal::default());
+
+        for invalid in ["\\xabc", "\\xzz", "\\", "\\9", "\\400", "a\\12"] {
+            assert!(ByteaVal::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -12,14 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Zero};
 pub use rust_decimal::prelude::{FromPrimitive, FromStr, ToPrimitive};
 use rust_decimal::{Decimal as RustDecimal, Error, RoundingStrategy};
 
-#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq, Ord, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
 pub enum Decimal {
     Normalized(RustDecimal),
     NaN,
@@ -165,10 +166,29 @@ impl_from!(u16, FromPrimitive::from_u16);
 impl_from!(u32, FromPrimitive::from_u32);
 impl_from!(u64, FromPrimitive::from_u64);
 
-checked_proxy!(CheckedRem, checked_rem, %);
+/// Like `checked_proxy`, but a division by zero fails unless the dividend is `NaN`, as in
+/// Postgres.
+macro_rules! checked_div_proxy {
+    ($trait:ty, $func:ident, $op: tt) => {
+        impl $trait for Decimal {
+            fn $func(&self, other: &Self) -> Option<Self> {
+                match (self, other) {
+                    (Self::Normalized(lhs), Self::Normalized(rhs)) => {
+                        lhs.$func(rhs).map(Decimal::Normalized)
+                    }
+                    (Self::NaN, _) => Some(Self::NaN),
+                    (_, rhs) if rhs.is_zero() => None,
+                    (lhs, rhs) => Some(*lhs $op *rhs),
+                }
+            }
+        }
+    }
+}
+
+checked_div_proxy!(CheckedRem, checked_rem, %);
 checked_proxy!(CheckedSub, checked_sub, -);
 checked_proxy!(CheckedAdd, checked_add, +);
-checked_proxy!(CheckedDiv, checked_div, /);
+checked_div_proxy!(CheckedDiv, checked_div, /);
 checked_proxy!(CheckedMul, checked_mul, *);
 
 impl Add for Decimal {
@@ -360,8 +380,8 @@ impl Display for Decimal {
         match self {
             Self::Normalized(d) => Display::fmt(&d, f),
             Self::NaN => Display::fmt("NaN", f),
-            Self::PositiveINF => Display::fmt("+Inf", f),
-            Self::NegativeINF => Display::fmt("-Inf", f),
+            Self::PositiveINF => Display::fmt("Infinity", f),
+            Self::NegativeINF => Display::fmt("-Infinity", f),
         }
     }
 }
@@ -421,6 +441,17 @@ impl Decimal {
         }
     }
 
+    /// Truncates to `dp` decimal places, i.e. rounds towards zero.
+    #[must_use]
+    pub fn trunc_dp(&self, dp: u32) -> Self {
+        match self {
+            Self::Normalized(d) => {
+                Self::Normalized(d.round_dp_with_strategy(dp, RoundingStrategy::ToZero))
+            }
+            d => *d,
+        }
+    }
+
     #[must_use]
     pub fn ceil(&self) -> Self {
         match self {
@@ -514,6 +545,44 @@ impl Decimal {
     }
 }
 
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Decimals are ordered as in Postgres, where `NaN` is greater than any other value:
+/// `-Infinity` < numbers < `Infinity` < `NaN`.
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn rank(decimal: &Decimal) -> u8 {
+            match decimal {
+                Decimal::NegativeINF => 0,
+                Decimal::Normalized(_) => 1,
+                Decimal::PositiveINF => 2,
+                Decimal::NaN => 3,
+            }
+        }
+        match (self, other) {
+            (Self::Normalized(lhs), Self::Normalized(rhs)) => lhs.cmp(rhs),
+            (lhs, rhs) => rank(lhs).cmp(&rank(rhs)),
+        }
+    }
+}
+
+impl Zero for Decimal {
+    fn zero() -> Self {
+        Self::Normalized(RustDecimal::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            Self::Normalized(d) => d.is_zero(),
+            _ => false,
+        }
+    }
+}
+
 impl Default for Decimal {
     fn default() -> Self {
         Self::Normalized(RustDecimal::default())
@@ -539,11 +608,11 @@ impl FromStr for Decimal {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "nan" | "NaN" | "NAN" => Ok(Decimal::NaN),
-            "inf" | "INF" | "+inf" | "+INF" => Ok(Decimal::PositiveINF),
-            "-inf" | "-INF" => Ok(Decimal::NegativeINF),
-            s => RustDecimal::from_str(s).map(Decimal::Normalized),
+        match s.to_ascii_lowercase().as_str() {
+            "nan" => Ok(Decimal::NaN),
+            "inf" | "+inf" | "infinity" | "+infinity" => Ok(Decimal::PositiveINF),
+            "-inf" | "-infinity" => Ok(Decimal::NegativeINF),
+            _ => RustDecimal::from_str(s).map(Decimal::Normalized),
         }
     }
 }
@@ -668,4 +737,25 @@ mod tests {
         assert_eq!(Decimal::to_u64(&Decimal::from_u64(1).unwrap()).unwrap(), 1,);
         assert_eq!(Decimal::to_i64(&Decimal::from_i64(1).unwrap()).unwrap(), 1,);
     }
+
+    #[test]
+    fn test_special_values() {
+        let one = Decimal::from(1);
+        assert!(Decimal::NegativeINF < one);
+        assert!(one < Decimal::PositiveINF);
+        assert!(Decimal::PositiveINF < Decimal::NaN);
+        assert_eq!(
+            Decimal::from_str("-Infinity").unwrap(),
+            Decimal::NegativeINF
+        );
+        assert_eq!(Decimal::PositiveINF.to_string(), "Infinity");
+
+        assert_eq!(one.checked_div(&Decimal::zero()), None);
+        assert_eq!(Decimal::PositiveINF.checked_div(&Decimal::zero()), None);
+        assert_eq!(
+            Decimal::NaN.checked_div(&Decimal::zero()),
+            Some(Decimal::NaN)
+        );
+        assert_eq!(Decimal::PositiveINF.checked_rem(&one), Some(Decimal::NaN));
+    }
 }
@@ -0,0 +1,669 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use super::{Scalar, ScalarImpl};
+use crate::error::{ErrorCode, Result, RwError};
+
+/// An owned JSONB value.
+///
+/// Values are compared and hashed structurally, so `1` equals `1.0`. The order follows postgres:
+/// `object > array > boolean > number > string > null`, with numbers compared by value.
+#[derive(Debug, Clone)]
+pub struct JsonbVal(Box<Value>);
+
+/// A reference to a JSONB value, either owned by a [`JsonbVal`] or stored in a `JsonbArray`.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonbRef<'a>(&'a Value);
+
+impl JsonbVal {
+    pub fn from_value(value: Value) -> Self {
+        Self(Box::new(value))
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.0
+    }
+
+    pub fn into_value(self) -> Value {
+        *self.0
+    }
+
+    /// Deserialize a value serialized by [`JsonbRef::memcmp_serialize`].
+    pub fn memcmp_deserialize(
+        de: &mut memcomparable::Deserializer<impl Buf>,
+    ) -> memcomparable::Result<Self> {
+        fn deserialize_value(
+            de: &mut memcomparable::Deserializer<impl Buf>,
+        ) -> memcomparable::Result<Value> {
+            Ok(match u8::deserialize(&mut *de)? {
+                0 => Value::Null,
+                1 => Value::String(String::deserialize(&mut *de)?),
+                2 => {
+                    let f = f64::deserialize(&mut *de)?;
+                    // Only integral numbers are followed by their exact value.
+                    let number = if f.fract() == 0.0 && f.abs() < 2f64.powi(127) {
+                        let high = i64::deserialize(&mut *de)?;
+                        let low = u64::deserialize(&mut *de)?;
+                        let i = ((high as i128) << 64) | low as i128;
+                        if let Ok(i) = i64::try_from(i) {
+                            Number::from(i)
+                        } else if let Ok(u) = u64::try_from(i) {
+                            Number::from(u)
+                        } else {
+                            Number::from_f64(f).unwrap()
+                        }
+                    } else {
+                        Number::from_f64(f).ok_or_else(|| {
+                            memcomparable::Error::Message(format!("invalid jsonb number: {}", f))
+                        })?
+                    };
+                    Value::Number(number)
+                }
+                3 => Value::Bool(bool::deserialize(&mut *de)?),
+                4 => {
+                    let len = u64::deserialize(&mut *de)?;
+                    Value::Array(
+                        (0..len)
+                            .map(|_| deserialize_value(de))
+                            .collect::<memcomparable::Result<_>>()?,
+                    )
+                }
+                5 => {
+                    let len = u64::deserialize(&mut *de)?;
+                    Value::Object(
+                        (0..len)
+                            .map(|_| Ok((String::deserialize(&mut *de)?, deserialize_value(de)?)))
+                            .collect::<memcomparable::Result<_>>()?,
+                    )
+                }
+                rank => {
+                    return Err(memcomparable::Error::Message(format!(
+                        "invalid jsonb type rank: {}",
+                        rank
+                    )))
+                }
+            })
+        }
+        Ok(Self::from_value(deserialize_value(de)?))
+    }
+
+    /// The JSON `null`, which is different from a SQL `NULL`.
+    pub fn null() -> Self {
+        Self::from_value(Value::Null)
+    }
+
+    /// Build a JSON object from its `(key, value)` pairs. A later value replaces an earlier one
+    /// with the same key.
+    pub fn object(pairs: impl IntoIterator<Item = (String, JsonbVal)>) -> Self {
+        Self::from_value(Value::Object(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (k, v.into_value()))
+                .collect(),
+        ))
+    }
+
+    /// Convert a SQL value into JSON. Numbers and booleans are kept, and other values become
+    /// JSON strings of their text.
+    pub fn from_scalar(scalar: &ScalarImpl) -> Self {
+        let value = match scalar {
+            ScalarImpl::Jsonb(v) => return v.clone(),
+            ScalarImpl::Bool(v) => Value::Bool(*v),
+            ScalarImpl::Int16(v) => Value::from(*v),
+            ScalarImpl::Int32(v) => Value::from(*v),
+            ScalarImpl::Int64(v) => Value::from(*v),
+            ScalarImpl::Float32(v) => Value::from(v.into_inner()),
+            ScalarImpl::Float64(v) => Value::from(v.into_inner()),
+            ScalarImpl::Decimal(v) => {
+                serde_json::from_str(&v.to_string()).unwrap_or_else(|_| Value::from(v.to_string()))
+            }
+            other => Value::String(other.to_string()),
+        };
+        Self::from_value(value)
+    }
+}
+
+impl FromStr for JsonbVal {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let value = serde_json::from_str(s).map_err(|e| {
+            ErrorCode::InvalidInputSyntax(format!("invalid input syntax for type jsonb: {}", e))
+        })?;
+        Ok(Self::from_value(value))
+    }
+}
+
+impl PartialEq for JsonbVal {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_scalar_ref() == other.as_scalar_ref()
+    }
+}
+
+impl Eq for JsonbVal {}
+
+impl PartialOrd for JsonbVal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbVal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_scalar_ref().cmp(&other.as_scalar_ref())
+    }
+}
+
+impl Hash for JsonbVal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_scalar_ref().hash(state)
+    }
+}
+
+impl Display for JsonbVal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_scalar_ref().fmt(f)
+    }
+}
+
+impl<'a> JsonbRef<'a> {
+    pub fn value(self) -> &'a Value {
+        self.0
+    }
+
+    /// The compact text of the value, e.g. `{"a":[1,2]}`.
+    pub fn to_compact_string(self) -> String {
+        self.0.to_string()
+    }
+
+    /// Serialize the value into the memcomparable format, in the same order as [`Ord`].
+    ///
+    /// The rank of the type is written first. Integral numbers are written as an `f64` followed
+    /// by their exact value, and other numbers as an `f64` only. Arrays and objects are written
+    /// as their length followed by their elements, or by their `(key, value)` pairs in key order.
+    pub fn memcmp_serialize(
+        self,
+        ser: &mut memcomparable::Serializer<impl BufMut>,
+    ) -> memcomparable::Result<()> {
+        type_rank(self.0).serialize(&mut *ser)?;
+        match self.0 {
+            Value::Null => {}
+            Value::String(s) => s.as_str().serialize(&mut *ser)?,
+            Value::Number(n) => match number_as_integer(n) {
+                Some(i) => {
+                    (i as f64).serialize(&mut *ser)?;
+                    ((i >> 64) as i64).serialize(&mut *ser)?;
+                    (i as u64).serialize(&mut *ser)?;
+                }
+                None => n.as_f64().unwrap_or(0.0).serialize(&mut *ser)?,
+            },
+            Value::Bool(b) => b.serialize(&mut *ser)?,
+            Value::Array(array) => {
+                (array.len() as u64).serialize(&mut *ser)?;
+                for v in array {
+                    Self(v).memcmp_serialize(ser)?;
+                }
+            }
+            Value::Object(object) => {
+                (object.len() as u64).serialize(&mut *ser)?;
+                for (k, v) in object {
+                    k.as_str().serialize(&mut *ser)?;
+                    Self(v).memcmp_serialize(ser)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The name of the type of the value, as returned by `jsonb_typeof`.
+    pub fn type_name(self) -> &'static str {
+        match self.0 {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    /// The field `key` of an object, as `->` with a text key.
+    pub fn access_object_field(self, key: &str) -> Option<Self> {
+        self.0.as_object()?.get(key).map(JsonbRef)
+    }
+
+    /// The `index`-th element of an array, as `->` with an integer. A negative index counts from
+    /// the end of the array.
+    pub fn access_array_element(self, index: i32) -> Option<Self> {
+        let array = self.0.as_array()?;
+        let index = if index < 0 {
+            array.len().checked_sub(index.unsigned_abs() as usize)?
+        } else {
+            index as usize
+        };
+        array.get(index).map(JsonbRef)
+    }
+
+    /// The value at `path`, as `#>`. Each step is an object key, or an array index if the value
+    /// at that step is an array.
+    pub fn access_path<'b>(self, path: impl IntoIterator<Item = &'b str>) -> Option<Self> {
+        path.into_iter()
+            .try_fold(self, |value, step| match value.0 {
+                Value::Array(_) => value.access_array_element(step.trim().parse().ok()?),
+                _ => value.access_object_field(step),
+            })
+    }
+
+    /// The text of the value, as `->>`. Strings are unquoted, and the JSON `null` becomes a SQL
+    /// `NULL`.
+    pub fn as_text(self) -> Option<String> {
+        match self.0 {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()),
+            _ => Some(self.to_string()),
+        }
+    }
+
+    /// Whether `other` is contained in this value, as `@>`.
+    ///
+    /// An object contains another if all the keys of the other are in it with values containing
+    /// the other's, and an array contains another if each element of the other is contained in
+    /// some of its elements. As a special case, an array also contains a primitive value that is
+    /// one of its elements.
+    pub fn contains(self, other: JsonbRef<'_>) -> bool {
+        match (self.0, other.0) {
+            (Value::Array(_), other_value)
+                if !other_value.is_array() && !other_value.is_object() =>
+            {
+                contains_value(self.0, &Value::Array(vec![other_value.clone()]))
+            }
+            (left, right) => contains_value(left, right),
+        }
+    }
+
+    /// Whether `key` is a key of an object, an element of an array or the string itself, as `?`.
+    pub fn exists(self, key: &str) -> bool {
+        match self.0 {
+            Value::Object(object) => object.contains_key(key),
+            Value::Array(array) => array.iter().any(|v| v.as_str() == Some(key)),
+            Value::String(s) => s == key,
+            _ => false,
+        }
+    }
+
+    /// The elements of an array, or `None` if the value is not an array.
+    pub fn array_elements(self) -> Option<impl Iterator<Item = JsonbRef<'a>>> {
+        Some(self.0.as_array()?.iter().map(JsonbRef))
+    }
+}
+
+fn contains_value(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => right
+            .iter()
+            .all(|(k, r)| left.get(k).map_or(false, |l| contains_value(l, r))),
+        (Value::Array(left), Value::Array(right)) => right
+            .iter()
+            .all(|r| left.iter().any(|l| contains_value(l, r))),
+        (left, right) => cmp_value(left, right).is_eq(),
+    }
+}
+
+/// The rank of the type of a value in the order `null < string < number < boolean < array <
+/// object`.
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::String(_) => 1,
+        Value::Number(_) => 2,
+        Value::Bool(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// The value of a number if it is integral, whether it is stored as an integer or a float.
+fn number_as_integer(n: &Number) -> Option<i128> {
+    if let Some(i) = n.as_i64() {
+        return Some(i as i128);
+    }
+    if let Some(u) = n.as_u64() {
+        return Some(u as i128);
+    }
+    let f = n.as_f64()?;
+    (f.fract() == 0.0 && f.abs() < 2f64.powi(127)).then(|| f as i128)
+}
+
+/// Compare two numbers by value. Integers are compared exactly, even if they don't fit in an
+/// `f64`.
+fn cmp_number(l: &Number, r: &Number) -> Ordering {
+    /// Compare an integer with a float that is not integral.
+    fn cmp_integer_float(i: i128, f: f64) -> Ordering {
+        if f >= 2f64.powi(127) {
+            Ordering::Less
+        } else if f < -(2f64.powi(127)) {
+            Ordering::Greater
+        } else {
+            // `f` has a fractional part, so it is never equal to `i`.
+            match i.cmp(&(f.floor() as i128)) {
+                Ordering::Greater => Ordering::Greater,
+                _ => Ordering::Less,
+            }
+        }
+    }
+    match (number_as_integer(l), number_as_integer(r)) {
+        (Some(l), Some(r)) => l.cmp(&r),
+        (Some(l), None) => cmp_integer_float(l, r.as_f64().unwrap_or(f64::NAN)),
+        (None, Some(r)) => cmp_integer_float(r, l.as_f64().unwrap_or(f64::NAN)).reverse(),
+        (None, None) => {
+            let (l, r) = (l.as_f64().unwrap_or(0.0), r.as_f64().unwrap_or(0.0));
+            l.partial_cmp(&r).unwrap_or(Ordering::Equal)
+        }
+    }
+}
+
+/// Compare two values structurally. As in postgres, arrays and objects with more elements are
+/// greater, and otherwise are compared element by element. Object keys are sorted, so objects
+/// are compared by their sorted `(key, value)` pairs.
+fn cmp_value(l: &Value, r: &Value) -> Ordering {
+    match (l, r) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::String(l), Value::String(r)) => l.cmp(r),
+        (Value::Number(l), Value::Number(r)) => cmp_number(l, r),
+        (Value::Bool(l), Value::Bool(r)) => l.cmp(r),
+        (Value::Array(l), Value::Array(r)) => l.len().cmp(&r.len()).then_with(|| {
+            l.iter()
+                .zip_eq(r.iter())
+                .map(|(l, r)| cmp_value(l, r))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        (Value::Object(l), Value::Object(r)) => l.len().cmp(&r.len()).then_with(|| {
+            l.iter()
+                .zip_eq(r.iter())
+                .map(|((lk, lv), (rk, rv))| lk.cmp(rk).then_with(|| cmp_value(lv, rv)))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        }),
+        (l, r) => type_rank(l).cmp(&type_rank(r)),
+    }
+}
+
+/// Hash a value consistently with [`cmp_value`]: integral numbers are hashed as integers, so
+/// `1` and `1.0` have the same hash.
+fn hash_value<H: Hasher>(value: &Value, state: &mut H) {
+    type_rank(value).hash(state);
+    match value {
+        Value::Null => {}
+        Value::String(s) => s.hash(state),
+        Value::Number(n) => match number_as_integer(n) {
+            Some(i) => i.hash(state),
+            None => n.as_f64().unwrap_or(0.0).to_bits().hash(state),
+        },
+        Value::Bool(b) => b.hash(state),
+        Value::Array(array) => {
+            array.len().hash(state);
+            array.iter().for_each(|v| hash_value(v, state));
+        }
+        Value::Object(object) => {
+            object.len().hash(state);
+            object.iter().for_each(|(k, v)| {
+                k.hash(state);
+                hash_value(v, state);
+            });
+        }
+    }
+}
+
+impl PartialEq for JsonbRef<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for JsonbRef<'_> {}
+
+impl PartialOrd for JsonbRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbRef<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_value(self.0, other.0)
+    }
+}
+
+impl Hash for JsonbRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_value(self.0, state)
+    }
+}
+
+/// Formats the value as postgres does, with a space after each `,` and `:`.
+impl Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn write_value(f: &mut Formatter<'_>, value: &Value) -> std::fmt::Result {
+            match value {
+                Value::Array(array) => {
+                    f.write_str("[")?;
+                    for (i, v) in array.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write_value(f, v)?;
+                    }
+                    f.write_str("]")
+                }
+                Value::Object(object) => {
+                    f.write_str("{")?;
+                    for (i, (k, v)) in object.iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write!(f, "{}: ", Value::String(k.clone()))?;
+                        write_value(f, v)?;
+                    }
+                    f.write_str("}")
+                }
+                primitive => write!(f, "{}", primitive),
+            }
+        }
+        write_value(f, self.0)
+    }
+}
+
+impl<'a> From<&'a Value> for JsonbRef<'a> {
+    fn from(value: &'a Value) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_display() {
+        let v = jsonb(r#"{"b": [1, "x", null], "a": {"c": true}}"#);
+        assert_eq!(v.to_string(), r#"{"a": {"c": true}, "b": [1, "x", null]}"#);
+        assert_eq!(
+            v.as_scalar_ref().to_compact_string(),
+            r#"{"a":{"c":true},"b":[1,"x",null]}"#
+        );
+        assert!("{".parse::<JsonbVal>().is_err());
+    }
+
+    #[test]
+    fn test_access() {
+        let v = jsonb(r#"{"a": [1, {"b": "x"}], "n": null}"#);
+        let v = v.as_scalar_ref();
+        let a = v.access_object_field("a").unwrap();
+        assert_eq!(a.type_name(), "array");
+        assert_eq!(a.access_array_element(-1), v.access_path(["a", "1"]));
+        assert_eq!(a.access_array_element(2), None);
+        assert_eq!(a.access_array_element(-3), None);
+        assert_eq!(
+            v.access_path(["a", "1", "b"]).unwrap().as_text().as_deref(),
+            Some("x")
+        );
+        assert_eq!(v.access_object_field("n").unwrap().as_text(), None);
+        assert_eq!(
+            v.access_object_field("a").unwrap().as_text().unwrap(),
+            "[1, {\"b\": \"x\"}]"
+        );
+    }
+
+    #[test]
+    fn test_contains_and_exists() {
+        let v = jsonb(r#"{"a": [1, 2, {"b": 3}], "c": "d"}"#);
+        let v = v.as_scalar_ref();
+        for (other, expected) in [
+            (r#"{"a": [2]}"#, true),
+            (r#"{"a": [{"b": 3}], "c": "d"}"#, true),
+            (r#"{"a": [4]}"#, false),
+            (r#"{"c": "e"}"#, false),
+            ("{}", true),
+        ] {
+            assert_eq!(
+                v.contains(jsonb(other).as_scalar_ref()),
+                expected,
+                "{}",
+                other
+            );
+        }
+        let array = jsonb("[1, [2, 3]]");
+        assert!(array.as_scalar_ref().contains(jsonb("1").as_scalar_ref()));
+        assert!(array
+            .as_scalar_ref()
+            .contains(jsonb("[[3]]").as_scalar_ref()));
+        assert!(!array.as_scalar_ref().contains(jsonb("3").as_scalar_ref()));
+
+        assert!(v.exists("a"));
+        assert!(!v.exists("d"));
+        assert!(jsonb(r#"["x", 1]"#).as_scalar_ref().exists("x"));
+    }
+
+    #[test]
+    fn test_cmp_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash(v: &JsonbVal) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        assert_eq!(jsonb("1"), jsonb("1.0"));
+        assert_eq!(hash(&jsonb("1")), hash(&jsonb("1.0")));
+        assert_eq!(jsonb(r#"{"a": [1, 2.0]}"#), jsonb(r#"{"a": [1.0, 2]}"#));
+        assert_eq!(
+            hash(&jsonb(r#"{"a": [1, 2.0]}"#)),
+            hash(&jsonb(r#"{"a": [1.0, 2]}"#))
+        );
+
+        for (smaller, larger) in [
+            ("9", "10"),
+            ("-1.5", "-1"),
+            ("1", "1.5"),
+            ("18446744073709551615", "1e20"),
+            ("null", r#""a""#),
+            (r#""z""#, "0"),
+            ("0", "false"),
+            ("true", "[]"),
+            ("[2]", "[1, 1]"),
+            ("[1, 2]", "[1, 10]"),
+            (r#"{"b": 1}"#, r#"{"a": 1, "b": 1}"#),
+            (r#"{"a": 2}"#, r#"{"b": 1}"#),
+            (r#"{"a": 9}"#, r#"{"a": 10}"#),
+        ] {
+            assert!(jsonb(smaller) < jsonb(larger), "{} < {}", smaller, larger);
+        }
+
+        assert!(jsonb(r#"{"a": 1}"#)
+            .as_scalar_ref()
+            .contains(jsonb(r#"{"a": 1.0}"#).as_scalar_ref()));
+    }
+
+    #[test]
+    fn test_memcmp_encoding() {
+        fn serialize(v: &JsonbVal) -> Vec<u8> {
+            let mut ser = memcomparable::Serializer::new(vec![]);
+            v.as_scalar_ref().memcmp_serialize(&mut ser).unwrap();
+            ser.into_inner()
+        }
+
+        let values = [
+            "null",
+            r#""""#,
+            r#""a""#,
+            r#""ab""#,
+            r#""b""#,
+            "-1e300",
+            "-9223372036854775808",
+            "-1.5",
+            "-1",
+            "-0.5",
+            "0",
+            "0.5",
+            "1",
+            "1.0",
+            "1.5",
+            "9",
+            "10",
+            "9007199254740992",
+            "9007199254740993",
+            "18446744073709551615",
+            "1e20",
+            "false",
+            "true",
+            "[]",
+            "[10]",
+            r#"["a"]"#,
+            "[1, 1]",
+            "[1, 2]",
+            "[1, [2]]",
+            "{}",
+            r#"{"a": 9}"#,
+            r#"{"a": 10}"#,
+            r#"{"b": 1}"#,
+            r#"{"a": 1, "b": 1}"#,
+            r#"{"a": [1, 2.0], "b": {"c": null}}"#,
+        ]
+        .map(jsonb);
+
+        for l in &values {
+            let mut de = memcomparable::Deserializer::new(&serialize(l)[..]);
+            assert_eq!(&JsonbVal::memcmp_deserialize(&mut de).unwrap(), l);
+            for r in &values {
+                assert_eq!(serialize(l).cmp(&serialize(r)), l.cmp(r), "{} <=> {}", l, r);
+            }
+        }
+    }
+}
@@ -32,12 +32,16 @@ pub use native_type::*;
 use risingwave_pb::data::data_type::IntervalType::*;
 use risingwave_pb::data::data_type::{IntervalType, TypeName};
 pub use scalar_impl::*;
+mod bytea;
 mod chrono_wrapper;
 mod decimal;
 pub mod interval;
+mod jsonb;
 
 mod ordered_float;
+mod uuid;
 
+pub use bytea::{ByteaRef, ByteaVal};
 use chrono::{Datelike, Timelike};
 pub use chrono_wrapper::{
     NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, UNIX_EPOCH_DAYS,
@@ -45,11 +49,13 @@ pub use chrono_wrapper::{
 pub use decimal::Decimal;
 pub use interval::*;
 use itertools::Itertools;
+pub use jsonb::{JsonbRef, JsonbVal};
 pub use ops::CheckedAdd;
 pub use ordered_float::IntoOrdered;
 use paste::paste;
 use prost::Message;
 use risingwave_pb::expr::{ListValue as ProstListValue, StructValue as ProstStructValue};
+pub use uuid::Uuid;
 
 use crate::array::{
     read_interval_unit, ArrayBuilderImpl, ListRef, ListValue, PrimitiveArrayItemType, StructRef,
@@ -75,6 +81,9 @@ pub enum DataType {
     Timestamp,
     Timestampz,
     Interval,
+    Jsonb,
+    Bytea,
+    Uuid,
     Struct { fields: Arc<[DataType]> },
     List { datatype: Box<DataType> },
 }
@@ -106,6 +115,9 @@ impl From<&ProstDataType> for DataType {
             TypeName::Timestampz => DataType::Timestampz,
             TypeName::Decimal => DataType::Decimal,
             TypeName::Interval => DataType::Interval,
+            TypeName::Jsonb => DataType::Jsonb,
+            TypeName::Bytea => DataType::Bytea,
+            TypeName::Uuid => DataType::Uuid,
             TypeName::Struct => {
                 let fields: Vec<DataType> = proto.field_type.iter().map(|f| f.into()).collect_vec();
                 DataType::Struct {
@@ -137,6 +149,9 @@ impl DataType {
             DataType::Timestamp => NaiveDateTimeArrayBuilder::new(capacity)?.into(),
             DataType::Timestampz => PrimitiveArrayBuilder::<i64>::new(capacity)?.into(),
             DataType::Interval => IntervalArrayBuilder::new(capacity)?.into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity)?.into(),
+            DataType::Bytea => ByteaArrayBuilder::new(capacity)?.into(),
+            DataType::Uuid => UuidArrayBuilder::new(capacity)?.into(),
             DataType::Struct { fields } => StructArrayBuilder::with_meta(
                 capacity,
                 ArrayMeta::Struct {
@@ -169,6 +184,9 @@ impl DataType {
             DataType::Timestampz => TypeName::Timestampz,
             DataType::Decimal => TypeName::Decimal,
             DataType::Interval => TypeName::Interval,
+            DataType::Jsonb => TypeName::Jsonb,
+            DataType::Bytea => TypeName::Bytea,
+            DataType::Uuid => TypeName::Uuid,
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
         }
@@ -204,6 +222,9 @@ impl DataType {
             DataType::Timestamp => DataSize::Fixed(size_of::<NaiveDateTimeWrapper>()),
             DataType::Timestampz => DataSize::Fixed(size_of::<NaiveDateTimeWrapper>()),
             DataType::Interval => DataSize::Variable,
+            DataType::Jsonb => DataSize::Variable,
+            DataType::Bytea => DataSize::Variable,
+            DataType::Uuid => DataSize::Fixed(size_of::<Uuid>()),
             DataType::Struct { .. } => DataSize::Variable,
             DataType::List { .. } => DataSize::Variable,
         }
@@ -295,6 +316,9 @@ macro_rules! for_all_scalar_variants {
             { NaiveDate, naivedate, NaiveDateWrapper, NaiveDateWrapper },
             { NaiveDateTime, naivedatetime, NaiveDateTimeWrapper, NaiveDateTimeWrapper },
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
+            { Jsonb, jsonb, JsonbVal, JsonbRef<'scalar> },
+            { Bytea, bytea, ByteaVal, ByteaRef<'scalar> },
+            { Uuid, uuid, Uuid, Uuid },
             { Struct, struct, StructValue, StructRef<'scalar> },
             { List, list, ListValue, ListRef<'scalar> }
         }
@@ -567,6 +591,9 @@ impl std::hash::Hash for ScalarImpl {
                     Self::NaiveDate(naivedate) => naivedate.hash(state),
                     Self::NaiveDateTime(naivedatetime) => naivedatetime.hash(state),
                     Self::NaiveTime(naivetime) => naivetime.hash(state),
+                    Self::Jsonb(v) => v.hash(state),
+                    Self::Bytea(v) => v.hash(state),
+                    Self::Uuid(v) => v.hash(state),
                     Self::Struct(v) => v.hash(state),
                     Self::List(v) => v.hash(state),
                 }
@@ -640,6 +667,9 @@ impl ScalarRefImpl<'_> {
             &Self::NaiveTime(v) => {
                 ser.serialize_naivetime(v.0.num_seconds_from_midnight(), v.0.nanosecond())?
             }
+            &Self::Jsonb(v) => v.memcmp_serialize(ser)?,
+            &Self::Bytea(v) => ser.serialize_bytea(v.as_bytes())?,
+            &Self::Uuid(v) => ser.serialize_uuid(v.as_bytes())?,
             _ => {
                 panic!("Type is unable to be serialized.")
             }
@@ -694,6 +724,9 @@ impl ScalarImpl {
                 let days = de.deserialize_naivedate()?;
                 NaiveDateWrapper::with_days(days)?
             }),
+            Ty::Jsonb => Self::Jsonb(JsonbVal::memcmp_deserialize(de)?),
+            Ty::Bytea => Self::Bytea(de.deserialize_bytea()?.into()),
+            Ty::Uuid => Self::Uuid(Uuid::from_bytes(de.deserialize_uuid()?)),
             _ => {
                 panic!("Type is unable to be deserialized.")
             }
@@ -714,6 +747,9 @@ impl ScalarImpl {
             ScalarImpl::NaiveDate(_) => todo!(),
             ScalarImpl::NaiveDateTime(_) => todo!(),
             ScalarImpl::NaiveTime(_) => todo!(),
+            ScalarImpl::Jsonb(v) => v.as_scalar_ref().to_compact_string().into_bytes(),
+            ScalarImpl::Bytea(v) => v.as_bytes().to_vec(),
+            ScalarImpl::Uuid(v) => v.as_bytes().to_vec(),
             ScalarImpl::Struct(v) => v.to_protobuf_owned(),
             ScalarImpl::List(v) => v.to_protobuf_owned(),
         };
@@ -766,6 +802,13 @@ impl ScalarImpl {
                     |e| InternalError(format!("Failed to deserialize decimal, reason: {:?}", e)),
                 )?)
             }
+            TypeName::Jsonb => {
+                ScalarImpl::Jsonb(JsonbVal::from_str(std::str::from_utf8(b).map_err(
+                    |e| InternalError(format!("Failed to deserialize jsonb, reason: {:?}", e)),
+                )?)?)
+            }
+            TypeName::Bytea => ScalarImpl::Bytea(b.as_slice().into()),
+            TypeName::Uuid => ScalarImpl::Uuid(Uuid::from_protobuf(b)?),
             TypeName::Interval => ScalarImpl::Interval(IntervalUnit::from_protobuf_bytes(
                 b,
                 data_type.get_interval_type()?,
@@ -380,12 +380,30 @@ impl_ordered_float_binop! {Mul, mul, MulAssign, mul_assign}
 impl_ordered_float_binop! {Div, div, DivAssign, div_assign}
 impl_ordered_float_binop! {Rem, rem, RemAssign, rem_assign}
 
+/// Checks the result of an arithmetic operation as Postgres does, where it is an error for the
+/// result to overflow to infinity unless an operand is infinite, or to underflow to zero unless an
+/// operand is zero.
+fn check_float_result<T: Float>(
+    result: T,
+    lhs: T,
+    rhs: T,
+    check_underflow: bool,
+) -> Option<OrderedFloat<T>> {
+    if result.is_infinite() && !lhs.is_infinite() && !rhs.is_infinite() {
+        return None;
+    }
+    if check_underflow && result.is_zero() && !lhs.is_zero() && !rhs.is_zero() {
+        return None;
+    }
+    Some(OrderedFloat(result))
+}
+
 impl<T> CheckedAdd for OrderedFloat<T>
 where
     T: Float,
 {
     fn checked_add(&self, v: &Self) -> Option<Self> {
-        Some(self.add(*v))
+        check_float_result(self.0 + v.0, self.0, v.0, false)
     }
 }
 
@@ -394,7 +412,7 @@ where
     T: Float,
 {
     fn checked_sub(&self, v: &Self) -> Option<Self> {
-        Some(self.sub(*v))
+        check_float_result(self.0 - v.0, self.0, v.0, false)
     }
 }
 
@@ -403,7 +421,7 @@ where
     T: Float,
 {
     fn checked_mul(&self, v: &Self) -> Option<Self> {
-        Some(self.mul(*v))
+        check_float_result(self.0 * v.0, self.0, v.0, true)
     }
 }
 
@@ -411,8 +429,13 @@ impl<T> CheckedDiv for OrderedFloat<T>
 where
     T: Float,
 {
+    /// Division by zero fails, even for a `NaN` dividend.
     fn checked_div(&self, v: &Self) -> Option<Self> {
-        Some(self.div(*v))
+        if v.0.is_zero() {
+            return None;
+        }
+        // Dividing by infinity is not an underflow.
+        check_float_result(self.0 / v.0, self.0, v.0, !v.0.is_infinite())
     }
 }
 
@@ -421,6 +444,9 @@ where
     T: Float,
 {
     fn checked_rem(&self, v: &Self) -> Option<Self> {
+        if v.0.is_zero() {
+            return None;
+        }
         Some(self.rem(*v))
     }
 }
@@ -1170,4 +1196,23 @@ mod tests {
         let ret: OrderedFloat<f64> = OrderedFloat::<f64>::from(crate::types::Decimal::from(5));
         assert_eq!(ret, OrderedFloat::<f64>::from(5_f64));
     }
+
+    #[test]
+    fn test_checked_ops() {
+        use num_traits::{CheckedAdd, CheckedDiv, CheckedMul};
+
+        let max = OrderedFloat(f64::MAX);
+        let inf = OrderedFloat(f64::INFINITY);
+        let zero = OrderedFloat(0_f64);
+        assert_eq!(max.checked_add(&max), None);
+        assert_eq!(max.checked_add(&inf), Some(inf));
+        assert_eq!(
+            OrderedFloat(1e-300).checked_mul(&OrderedFloat(1e-300)),
+            None
+        );
+        assert_eq!(max.checked_mul(&zero), Some(zero));
+        assert_eq!(OrderedFloat(1_f64).checked_div(&zero), None);
+        assert_eq!(OrderedFloat(f64::NAN).checked_div(&zero), None);
+        assert_eq!(max.checked_div(&inf), Some(zero));
+    }
 }
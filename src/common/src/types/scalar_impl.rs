@@ -173,6 +173,50 @@ impl<'a> ScalarRef<'a> for Decimal {
     }
 }
 
+/// Implement `Scalar` for `JsonbVal`.
+impl Scalar for JsonbVal {
+    type ScalarRefType<'a> = JsonbRef<'a>;
+
+    fn as_scalar_ref(&self) -> JsonbRef<'_> {
+        JsonbRef::from(self.value())
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Jsonb(self)
+    }
+}
+
+/// Implement `ScalarRef` for `JsonbRef`.
+impl<'a> ScalarRef<'a> for JsonbRef<'a> {
+    type ScalarType = JsonbVal;
+
+    fn to_owned_scalar(&self) -> JsonbVal {
+        JsonbVal::from_value(self.value().clone())
+    }
+}
+
+/// Implement `Scalar` for `ByteaVal`.
+impl Scalar for ByteaVal {
+    type ScalarRefType<'a> = ByteaRef<'a>;
+
+    fn as_scalar_ref(&self) -> ByteaRef<'_> {
+        ByteaRef::from(self.as_bytes())
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Bytea(self)
+    }
+}
+
+/// Implement `ScalarRef` for `ByteaRef`.
+impl<'a> ScalarRef<'a> for ByteaRef<'a> {
+    type ScalarType = ByteaVal;
+
+    fn to_owned_scalar(&self) -> ByteaVal {
+        ByteaVal::from(self.as_bytes())
+    }
+}
+
 /// Implement `Scalar` for `IntervalUnit`.
 impl Scalar for IntervalUnit {
     type ScalarRefType<'a> = IntervalUnit;
@@ -261,6 +305,28 @@ impl<'a> ScalarRef<'a> for NaiveTimeWrapper {
     }
 }
 
+/// Implement `Scalar` for `Uuid`.
+impl Scalar for Uuid {
+    type ScalarRefType<'a> = Uuid;
+
+    fn as_scalar_ref(&self) -> Uuid {
+        *self
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Uuid(self)
+    }
+}
+
+/// Implement `ScalarRef` for `Uuid`.
+impl<'a> ScalarRef<'a> for Uuid {
+    type ScalarType = Uuid;
+
+    fn to_owned_scalar(&self) -> Uuid {
+        *self
+    }
+}
+
 /// Implement `Scalar` for `StructValue`.
 impl<'a> ScalarRef<'a> for StructRef<'a> {
     type ScalarType = StructValue;
@@ -0,0 +1,149 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::str::FromStr;
+
+use crate::error::ErrorCode::IoError;
+use crate::error::{ErrorCode, Result, RwError};
+
+/// A UUID stored as its 16 bytes in network byte order, so that comparing two UUIDs compares
+/// their bytes as in Postgres.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid([u8; 16]);
+
+impl Uuid {
+    pub fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Builds a version 4 UUID from random bytes, by setting the version and variant bits.
+    pub fn new_v4(mut random_bytes: [u8; 16]) -> Self {
+        random_bytes[6] = (random_bytes[6] & 0x0f) | 0x40;
+        random_bytes[8] = (random_bytes[8] & 0x3f) | 0x80;
+        Self(random_bytes)
+    }
+
+    pub fn to_protobuf<T: Write>(self, output: &mut T) -> Result<usize> {
+        output.write(&self.0).map_err(|e| RwError::from(IoError(e)))
+    }
+
+    pub fn from_protobuf(bytes: &[u8]) -> Result<Self> {
+        bytes.try_into().map(Self).map_err(|_| {
+            ErrorCode::InternalError(format!("Failed to deserialize uuid from {:?}", bytes)).into()
+        })
+    }
+}
+
+/// Parses the text input of a UUID, which is 32 hex digits in either case, optionally surrounded
+/// by braces and with a hyphen allowed after any group of four digits, e.g.
+/// `a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11` or `{a0eebc999c0b4ef8bb6d6bb9bd380a11}`.
+impl FromStr for Uuid {
+    type Err = RwError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_uuid(s).map(Self).ok_or_else(|| {
+            ErrorCode::InvalidInputSyntax(format!("invalid input syntax for type uuid: \"{}\"", s))
+                .into()
+        })
+    }
+}
+
+fn parse_uuid(s: &str) -> Option<[u8; 16]> {
+    let s = match s.strip_prefix('{') {
+        Some(s) => s.strip_suffix('}')?,
+        None => s,
+    };
+    let mut bytes = [0; 16];
+    let mut digits = 0;
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '-' {
+            if digits == 0 || digits == 32 || digits % 4 != 0 || chars.peek() == Some(&'-') {
+                return None;
+            }
+            continue;
+        }
+        if digits == 32 {
+            return None;
+        }
+        let digit = c.to_digit(16)? as u8;
+        bytes[digits / 2] |= if digits % 2 == 0 { digit << 4 } else { digit };
+        digits += 1;
+    }
+    (digits == 32).then(|| bytes)
+}
+
+/// Displays the standard form, e.g. `a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11`.
+impl Display for Uuid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, b) in self.0.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_io() {
+        let uuid = Uuid::from_str("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11").unwrap();
+        assert_eq!(uuid.as_bytes()[0], 0xa0);
+        assert_eq!(uuid.as_bytes()[15], 0x11);
+        assert_eq!(uuid.to_string(), "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11");
+
+        for equivalent in [
+            "A0EEBC99-9C0B-4EF8-BB6D-6BB9BD380A11",
+            "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}",
+            "a0eebc999c0b4ef8bb6d6bb9bd380a11",
+            "a0ee-bc99-9c0b-4ef8-bb6d-6bb9-bd38-0a11",
+            "{a0eebc99-9c0b4ef8-bb6d6bb9-bd380a11}",
+        ] {
+            assert_eq!(Uuid::from_str(equivalent).unwrap(), uuid, "{}", equivalent);
+        }
+
+        for invalid in [
+            "",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a1",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a111",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a1g",
+            "-a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11-",
+            "a0eebc99--9c0b-4ef8-bb6d-6bb9bd380a11",
+            "a0eeb-c99-9c0b-4ef8-bb6d-6bb9bd380a11",
+            "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+        ] {
+            assert!(Uuid::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_new_v4() {
+        let uuid = Uuid::new_v4([0xff; 16]);
+        assert_eq!(uuid.to_string(), "ffffffff-ffff-4fff-bfff-ffffffffffff");
+        let uuid = Uuid::new_v4([0; 16]);
+        assert_eq!(uuid.to_string(), "00000000-0000-4000-8000-000000000000");
+    }
+}
@@ -75,6 +75,19 @@ impl OrderedRowSerializer {
         Self { order_types }
     }
 
+    pub fn order_types(&self) -> &[OrderType] {
+        &self.order_types
+    }
+
+    /// A serializer of the first `len` columns, whose output is a prefix of the output of this
+    /// serializer for the same row.
+    #[must_use]
+    pub fn prefix(&self, len: usize) -> Self {
+        Self {
+            order_types: self.order_types[..len].to_vec(),
+        }
+    }
+
     pub fn serialize(&self, row: &Row, append_to: &mut Vec<u8>) {
         self.serialize_datums(row.values(), append_to)
     }
@@ -266,6 +279,21 @@ mod tests {
         assert_eq!(&array[2][3..], [1, 1, b'a', b'b', b'd', 0, 0, 0, 0, 0, 3u8]);
     }
 
+    #[test]
+    fn test_ordered_row_serializer_prefix() {
+        let orders = vec![OrderType::Descending, OrderType::Ascending];
+        let serializer = OrderedRowSerializer::new(orders);
+        let row = Row(vec![Some(Int16(5)), Some(Utf8("abc".to_string()))]);
+        let mut row_bytes = vec![];
+        serializer.serialize(&row, &mut row_bytes);
+        let mut prefix_bytes = vec![];
+        serializer
+            .prefix(1)
+            .serialize(&Row(vec![Some(Int16(5))]), &mut prefix_bytes);
+        assert!(row_bytes.len() > prefix_bytes.len());
+        assert!(row_bytes.starts_with(&prefix_bytes));
+    }
+
     #[test]
     fn test_ordered_arrays_serializer() {
         let orders = vec![
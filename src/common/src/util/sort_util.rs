@@ -195,7 +195,8 @@ pub fn compare_two_row(
                 Interval,
                 NaiveDate,
                 NaiveDateTime,
-                NaiveTime
+                NaiveTime,
+                Uuid
             ]
         );
         if res != Ordering::Equal {
@@ -21,7 +21,7 @@ use chrono::{Datelike, Timelike};
 use crate::error::Result;
 use crate::types::{
     DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
-    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarImpl, ScalarRefImpl,
+    NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarImpl, ScalarRefImpl, Uuid,
 };
 
 pub mod error;
@@ -86,6 +86,9 @@ fn serialize_value(value: ScalarRefImpl, mut buf: impl BufMut) {
         ScalarRefImpl::NaiveTime(v) => {
             serialize_naivetime(v.0.num_seconds_from_midnight(), v.0.nanosecond(), buf)
         }
+        ScalarRefImpl::Jsonb(v) => serialize_str(v.to_compact_string().as_bytes(), buf),
+        ScalarRefImpl::Bytea(v) => serialize_str(v.as_bytes(), buf),
+        ScalarRefImpl::Uuid(v) => buf.put_slice(v.as_bytes()),
         ScalarRefImpl::Struct(StructRef::ValueRef { val }) => {
             serialize_struct_or_list(val.to_protobuf_owned(), buf);
         }
@@ -147,6 +150,9 @@ fn deserialize_value(ty: &DataType, mut data: impl Buf) -> Result<Datum> {
         DataType::Timestamp => ScalarImpl::NaiveDateTime(deserialize_naivedatetime(data)?),
         DataType::Timestampz => ScalarImpl::Int64(data.get_i64_le()),
         DataType::Date => ScalarImpl::NaiveDate(deserialize_naivedate(data)?),
+        DataType::Jsonb => ScalarImpl::Jsonb(deserialize_str(data)?.parse()?),
+        DataType::Bytea => ScalarImpl::Bytea(deserialize_bytea(data).into()),
+        DataType::Uuid => ScalarImpl::Uuid(deserialize_uuid(data)),
         DataType::Struct { fields: _ } => deserialize_struct_or_list(ty, data)?,
         DataType::List { datatype: _ } => deserialize_struct_or_list(ty, data)?,
     }))
@@ -159,6 +165,13 @@ fn deserialize_struct_or_list(data_type: &DataType, mut data: impl Buf) -> Resul
     ScalarImpl::bytes_to_scalar(&bytes, &data_type.to_protobuf())
 }
 
+fn deserialize_bytea(mut data: impl Buf) -> Vec<u8> {
+    let len = data.get_u32_le();
+    let mut bytes = vec![0; len as usize];
+    data.copy_to_slice(&mut bytes);
+    bytes
+}
+
 fn deserialize_str(mut data: impl Buf) -> Result<String> {
     let len = data.get_u32_le();
     let mut bytes = vec![0; len as usize];
@@ -198,6 +211,12 @@ fn deserialize_naivedate(mut data: impl Buf) -> Result<NaiveDateWrapper> {
     NaiveDateWrapper::new_with_days_value_encoding(days)
 }
 
+fn deserialize_uuid(mut data: impl Buf) -> Uuid {
+    let mut bytes = [0; 16];
+    data.copy_to_slice(&mut bytes);
+    Uuid::from_bytes(bytes)
+}
+
 fn deserialize_decimal(mut data: impl Buf) -> Result<Decimal> {
     let mut bytes = [0; 16];
     data.copy_to_slice(&mut bytes);
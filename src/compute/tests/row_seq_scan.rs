@@ -1,3 +1,4 @@
+use std::ops::Bound::*;
 use std::sync::Arc;
 
 // Copyright 2022 Singularity Data
@@ -15,7 +16,7 @@ use std::sync::Arc;
 // limitations under the License.
 use futures::StreamExt;
 use risingwave_batch::executor::monitor::BatchMetrics;
-use risingwave_batch::executor::{Executor, RowSeqScanExecutor};
+use risingwave_batch::executor::{Executor, RowSeqScanExecutor, ScanType};
 use risingwave_common::array::{Array, Row};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema};
 use risingwave_common::error::Result;
@@ -54,8 +55,8 @@ async fn test_row_seq_scan() -> Result<()> {
         vec![0_usize],
     );
     let table = CellBasedTable::new_adhoc(
-        keyspace,
-        column_descs,
+        keyspace.clone(),
+        column_descs.clone(),
         Arc::new(StateStoreMetrics::unused()),
     );
 
@@ -84,8 +85,9 @@ async fn test_row_seq_scan() -> Result<()> {
     state.commit(epoch).await.unwrap();
 
     let executor = Box::new(RowSeqScanExecutor::new(
-        table.schema().clone(),
-        table.iter(u64::MAX).await.unwrap(),
+        table,
+        vec![ScanType::TableScan],
+        u64::MAX,
         1,
         true,
         "RowSeqScanExecutor2".to_string(),
@@ -137,5 +139,38 @@ async fn test_row_seq_scan() -> Result<()> {
             .collect::<Vec<_>>(),
         vec![Some(5)]
     );
+
+    // Scan a range of the table and get rows by pk.
+    let table = CellBasedTable::new_for_test(keyspace, column_descs, vec![OrderType::Ascending]);
+    let executor = Box::new(RowSeqScanExecutor::new(
+        table,
+        vec![
+            ScanType::PointGet(Row(vec![Some(2_i32.into())])),
+            ScanType::PointGet(Row(vec![Some(3_i32.into())])),
+            ScanType::RangeScan(Row(vec![]), (Unbounded, Excluded(Some(2_i32.into())))),
+        ],
+        u64::MAX,
+        1,
+        true,
+        "RowSeqScanExecutor2".to_string(),
+        Arc::new(BatchMetrics::unused()),
+    ));
+
+    // The rows are yielded in the order of the scans, and the missing row is skipped.
+    let mut stream = executor.execute();
+    for pk in [2, 1] {
+        let chunk = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk
+                .column_at(0)
+                .array()
+                .as_int32()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(pk)]
+        );
+    }
+    assert!(stream.next().await.is_none());
+
     Ok(())
 }
@@ -22,7 +22,7 @@ use itertools::Itertools;
 use risingwave_batch::executor::monitor::BatchMetrics;
 use risingwave_batch::executor::{
     BoxedDataChunkStream, BoxedExecutor, DeleteExecutor, Executor as BatchExecutor, InsertExecutor,
-    RowSeqScanExecutor,
+    RowSeqScanExecutor, ScanType,
 };
 use risingwave_common::array::{Array, DataChunk, F64Array, I64Array};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema, TableId};
@@ -207,8 +207,9 @@ async fn test_table_v2_materialize() -> Result<()> {
     );
 
     let scan = Box::new(RowSeqScanExecutor::new(
-        table.schema().clone(),
-        table.iter(u64::MAX).await?,
+        table.clone(),
+        vec![ScanType::TableScan],
+        u64::MAX,
         1024,
         true,
         "RowSeqExecutor2".to_string(),
@@ -266,8 +267,9 @@ async fn test_table_v2_materialize() -> Result<()> {
 
     // Scan the table again, we are able to get the data now!
     let scan = Box::new(RowSeqScanExecutor::new(
-        table.schema().clone(),
-        table.iter(u64::MAX).await?,
+        table.clone(),
+        vec![ScanType::TableScan],
+        u64::MAX,
         1024,
         true,
         "RowSeqScanExecutor2".to_string(),
@@ -334,8 +336,9 @@ async fn test_table_v2_materialize() -> Result<()> {
 
     // Scan the table again, we are able to see the deletion now!
     let scan = Box::new(RowSeqScanExecutor::new(
-        table.schema().clone(),
-        table.iter(u64::MAX).await?,
+        table.clone(),
+        vec![ScanType::TableScan],
+        u64::MAX,
         1024,
         true,
         "RowSeqScanExecutor2".to_string(),
@@ -16,21 +16,27 @@ use risingwave_common::array::DataChunk;
 use risingwave_common::ensure;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::{DataType, ToOwnedDatum};
-use risingwave_pb::expr::expr_node::RexNode;
+use risingwave_pb::expr::expr_node::{RexNode, Type};
 use risingwave_pb::expr::ExprNode;
 
-use crate::expr::expr_binary_bytes::{new_substr_start, new_to_char};
-use crate::expr::expr_binary_nonnull::{new_binary_expr, new_like_default};
+use crate::expr::expr_binary_bytes::{new_binary_bytes_expr, new_substr_start, new_to_char};
+use crate::expr::expr_binary_nonnull::{
+    new_at_time_zone_expr, new_binary_expr, new_date_trunc_expr, new_ilike_default,
+    new_like_default,
+};
 use crate::expr::expr_binary_nullable::new_nullable_binary_expr;
 use crate::expr::expr_case::{CaseExpression, WhenClause};
 use crate::expr::expr_in::InExpression;
+use crate::expr::expr_quaternary_bytes::new_overlay_for_expr;
+use crate::expr::expr_regexp::RegexpExpression;
 use crate::expr::expr_ternary_bytes::{
-    new_replace_expr, new_split_part_expr, new_substr_start_end, new_translate_expr,
+    new_replace_expr, new_split_part_expr, new_substr_start_end, new_ternary_expr,
+    new_translate_expr,
 };
 use crate::expr::expr_unary::{
     new_length_default, new_ltrim_expr, new_rtrim_expr, new_trim_expr, new_unary_expr,
 };
-use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, LiteralExpression};
 
 fn get_children_and_return_type(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
     let ret_type = DataType::from(prost.get_return_type()?);
@@ -63,6 +69,23 @@ pub fn build_binary_expr_prost(prost: &ExprNode) -> Result<BoxedExpression> {
     ))
 }
 
+pub fn build_binary_bytes_expr_prost(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 2);
+    let left_expr = expr_build_from_prost(&children[0])?;
+    let right_expr = expr_build_from_prost(&children[1])?;
+    new_binary_bytes_expr(prost.get_expr_type()?, left_expr, right_expr, ret_type)
+}
+
+pub fn build_ternary_expr_prost(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 3);
+    let e1 = expr_build_from_prost(&children[0])?;
+    let e2 = expr_build_from_prost(&children[1])?;
+    let e3 = expr_build_from_prost(&children[2])?;
+    new_ternary_expr(prost.get_expr_type()?, ret_type, e1, e2, e3)
+}
+
 pub fn build_nullable_binary_expr_prost(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_children_and_return_type(prost)?;
     ensure!(children.len() == 2);
@@ -92,6 +115,41 @@ pub fn build_substr_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     }
 }
 
+pub fn build_overlay_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 3 || children.len() == 4);
+    if children.len() == 3 {
+        build_ternary_expr_prost(prost)
+    } else {
+        let s = expr_build_from_prost(&children[0])?;
+        let new_sub = expr_build_from_prost(&children[1])?;
+        let start = expr_build_from_prost(&children[2])?;
+        let count = expr_build_from_prost(&children[3])?;
+        Ok(new_overlay_for_expr(s, new_sub, start, count, ret_type))
+    }
+}
+
+/// `date_trunc` takes the time zone as the third argument for a timestamp with time zone.
+pub fn build_date_trunc_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 2 || children.len() == 3);
+    if children.len() == 2 {
+        let field = expr_build_from_prost(&children[0])?;
+        let source = expr_build_from_prost(&children[1])?;
+        new_date_trunc_expr(ret_type, field, source)
+    } else {
+        build_ternary_expr_prost(prost)
+    }
+}
+
+pub fn build_at_time_zone_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    ensure!(children.len() == 2);
+    let input = expr_build_from_prost(&children[0])?;
+    let time_zone = expr_build_from_prost(&children[1])?;
+    new_at_time_zone_expr(ret_type, input, time_zone)
+}
+
 pub fn build_trim_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_children_and_return_type(prost)?;
     // TODO: add expr with the delimiter parameter
@@ -133,12 +191,54 @@ pub fn build_length_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     Ok(new_length_default(child, ret_type))
 }
 
+pub fn build_regexp_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let func = prost.get_expr_type()?;
+    let (children, ret_type) = get_children_and_return_type(prost)?;
+    let flags_index = match func {
+        Type::RegexpReplace => 3,
+        _ => 2,
+    };
+    ensure!(children.len() == flags_index || children.len() == flags_index + 1);
+
+    // Compile the pattern only once if both the pattern and the flags are constants.
+    let pattern = build_const_str(&children[1])?;
+    let flags = match children.get(flags_index) {
+        Some(flags) => build_const_str(flags)?,
+        None => Some(Some(String::new())),
+    };
+    let compiled = match (pattern, flags) {
+        (Some(Some(pattern)), Some(Some(flags))) => {
+            Some(RegexpExpression::compile(func, &pattern, &flags)?)
+        }
+        _ => None,
+    };
+    let children = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(RegexpExpression::new(
+        func, ret_type, children, compiled,
+    )))
+}
+
+/// Returns the value of a constant string, or `None` if `prost` is not a constant.
+fn build_const_str(prost: &ExprNode) -> Result<Option<Option<String>>> {
+    if prost.get_expr_type()? != Type::ConstantValue {
+        return Ok(None);
+    }
+    let literal = LiteralExpression::try_from(prost)?.literal();
+    Ok(Some(literal.map(|v| v.into_utf8())))
+}
+
 pub fn build_like_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_children_and_return_type(prost)?;
     ensure!(children.len() == 2);
     let expr_ia1 = expr_build_from_prost(&children[0])?;
     let expr_ia2 = expr_build_from_prost(&children[1])?;
-    Ok(new_like_default(expr_ia1, expr_ia2, ret_type))
+    match prost.get_expr_type()? {
+        Type::Ilike => Ok(new_ilike_default(expr_ia1, expr_ia2, ret_type)),
+        _ => Ok(new_like_default(expr_ia1, expr_ia2, ret_type)),
+    }
 }
 
 pub fn build_in_expr(prost: &ExprNode) -> Result<BoxedExpression> {
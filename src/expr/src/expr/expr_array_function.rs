@@ -0,0 +1,238 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, ListValue, Row};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::try_match_expand;
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::array::*;
+
+/// `ArrayFunctionExpression` evaluates the functions and operators on arrays row by row, as the
+/// elements of a list are not laid out for vectorized evaluation.
+#[derive(Debug)]
+pub struct ArrayFunctionExpression {
+    func: Type,
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+}
+
+fn as_list(datum: &Datum) -> Option<&ListValue> {
+    datum.as_ref().map(ScalarImpl::as_list)
+}
+
+fn as_i32(datum: &Datum) -> Option<i32> {
+    datum.as_ref().map(|scalar| *scalar.as_int32())
+}
+
+fn as_str(datum: &Datum) -> Option<&str> {
+    datum.as_ref().map(|scalar| scalar.as_utf8().as_str())
+}
+
+impl ArrayFunctionExpression {
+    pub fn new(func: Type, return_type: DataType, children: Vec<BoxedExpression>) -> Self {
+        Self {
+            func,
+            return_type,
+            children,
+        }
+    }
+
+    fn eval_datums(&self, mut args: Vec<Datum>) -> Datum {
+        let list_datum = |list: ListValue| Some(ScalarImpl::List(list));
+        let bool_datum = |b: bool| Some(ScalarImpl::Bool(b));
+        match self.func {
+            Type::ArrayCat => array_cat(as_list(&args[0]), as_list(&args[1])).and_then(list_datum),
+            Type::ArrayAppend => {
+                let elem = args.pop().unwrap();
+                list_datum(array_append(as_list(&args[0]), elem))
+            }
+            Type::ArrayPrepend => {
+                let elem = args.swap_remove(0);
+                list_datum(array_prepend(elem, as_list(&args[0])))
+            }
+            Type::ArrayPosition => as_list(&args[0])
+                .and_then(|array| array_position(array, &args[1]))
+                .map(ScalarImpl::Int32),
+            Type::Cardinality => {
+                as_list(&args[0]).map(|array| ScalarImpl::Int32(cardinality(array)))
+            }
+            Type::ArrayLength => match (as_list(&args[0]), as_i32(&args[1])) {
+                (Some(array), Some(dimension)) => {
+                    array_length(array, dimension).map(ScalarImpl::Int32)
+                }
+                _ => None,
+            },
+            Type::ArrayToString => match (as_list(&args[0]), as_str(&args[1])) {
+                (Some(array), Some(delimiter)) => {
+                    let null_string = args.get(2).and_then(as_str);
+                    Some(ScalarImpl::Utf8(array_to_string(
+                        array,
+                        delimiter,
+                        null_string,
+                    )))
+                }
+                _ => None,
+            },
+            Type::ArrayContains | Type::ArrayContained | Type::ArrayOverlap => {
+                match (self.func, as_list(&args[0]), as_list(&args[1])) {
+                    (Type::ArrayContains, Some(left), Some(right)) => {
+                        bool_datum(array_contains(left, right))
+                    }
+                    (Type::ArrayContained, Some(left), Some(right)) => {
+                        bool_datum(array_contains(right, left))
+                    }
+                    (_, Some(left), Some(right)) => bool_datum(array_overlap(left, right)),
+                    _ => None,
+                }
+            }
+            Type::ArraySlice => as_list(&args[0])
+                .map(|array| array_slice(array, as_i32(&args[1]), as_i32(&args[2])))
+                .and_then(list_datum),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Expression for ArrayFunctionExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let children_array = self
+            .children
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let len = input.capacity();
+        let mut builder = self.return_type.create_array_builder(len)?;
+        for i in 0..len {
+            let args = children_array
+                .iter()
+                .map(|array| array.datum_at(i))
+                .collect();
+            builder.append_datum(&self.eval_datums(args))?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let args = self
+            .children
+            .iter()
+            .map(|c| c.eval_row(input))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.eval_datums(args))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for ArrayFunctionExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let func = prost.get_expr_type()?;
+        let return_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+        let children = func_call_node
+            .children
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<BoxedExpression>>>()?;
+        let arity_ok = match func {
+            Type::Cardinality => children.len() == 1,
+            Type::ArraySlice => children.len() == 3,
+            Type::ArrayToString => (2..=3).contains(&children.len()),
+            _ => children.len() == 2,
+        };
+        if !arity_ok {
+            return Err(ErrorCode::InternalError(format!(
+                "{:?} does not take {} arguments",
+                func,
+                children.len()
+            ))
+            .into());
+        }
+        Ok(Self::new(func, return_type, children))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    fn literal(data_type: DataType, datum: Datum) -> BoxedExpression {
+        LiteralExpression::new(data_type, datum).boxed()
+    }
+
+    fn int_list(values: &[i32]) -> ListValue {
+        ListValue::new(values.iter().map(|v| Some(ScalarImpl::Int32(*v))).collect())
+    }
+
+    #[test]
+    fn test_array_function_expression() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let array = || {
+            literal(
+                list_type.clone(),
+                Some(ScalarImpl::List(int_list(&[1, 2, 3]))),
+            )
+        };
+
+        let append = ArrayFunctionExpression::new(
+            Type::ArrayAppend,
+            list_type.clone(),
+            vec![
+                array(),
+                literal(DataType::Int32, Some(ScalarImpl::Int32(4))),
+            ],
+        );
+        let result = append.eval(&DataChunk::new_dummy(2)).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            result.datum_at(1),
+            Some(ScalarImpl::List(int_list(&[1, 2, 3, 4])))
+        );
+
+        let slice = ArrayFunctionExpression::new(
+            Type::ArraySlice,
+            list_type.clone(),
+            vec![
+                array(),
+                literal(DataType::Int32, Some(ScalarImpl::Int32(2))),
+                literal(DataType::Int32, None),
+            ],
+        );
+        assert_eq!(
+            slice.eval_row(&Row::new(vec![])).unwrap(),
+            Some(ScalarImpl::List(int_list(&[2, 3])))
+        );
+
+        let contains = ArrayFunctionExpression::new(
+            Type::ArrayContains,
+            DataType::Boolean,
+            vec![array(), literal(list_type.clone(), None)],
+        );
+        assert_eq!(contains.eval_row(&Row::new(vec![])).unwrap(), None);
+    }
+}
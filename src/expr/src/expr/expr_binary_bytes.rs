@@ -14,12 +14,20 @@
 
 //! For expression that only accept two arguments + 1 bytes writer as input.
 
-use risingwave_common::array::{I32Array, NaiveDateTimeArray, Utf8Array};
+use risingwave_common::array::{
+    ByteaArray, BytesGuard, BytesWriter, I32Array, NaiveDateTimeArray, Utf8Array,
+};
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
+use risingwave_pb::expr::expr_node::Type;
 
 use super::Expression;
-use crate::expr::template::BinaryBytesExpression;
+use crate::expr::template::{BinaryBytesExpression, BinaryExpression};
 use crate::expr::BoxedExpression;
+use crate::vector_op::bytea::bytea_substr_start;
+use crate::vector_op::concat_op::concat_op;
+use crate::vector_op::left_right::{left, right};
+use crate::vector_op::repeat::repeat;
 use crate::vector_op::substr::*;
 use crate::vector_op::to_char::to_char_timestamp;
 
@@ -28,13 +36,22 @@ pub fn new_substr_start(
     expr_ia2: BoxedExpression,
     return_type: DataType,
 ) -> BoxedExpression {
-    BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
-        expr_ia1,
-        expr_ia2,
-        return_type,
-        substr_start,
-    )
-    .boxed()
+    match expr_ia1.return_type() {
+        DataType::Bytea => BinaryExpression::<ByteaArray, I32Array, ByteaArray, _>::new(
+            expr_ia1,
+            expr_ia2,
+            return_type,
+            bytea_substr_start,
+        )
+        .boxed(),
+        _ => BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+            expr_ia1,
+            expr_ia2,
+            return_type,
+            substr_start,
+        )
+        .boxed(),
+    }
 }
 
 #[allow(dead_code)]
@@ -67,6 +84,45 @@ pub fn new_to_char(
     .boxed()
 }
 
+pub fn new_binary_bytes_expr(
+    expr_type: Type,
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> Result<BoxedExpression> {
+    let expr = match expr_type {
+        Type::ConcatOp => BinaryBytesExpression::<Utf8Array, Utf8Array, _>::new(
+            expr_ia1,
+            expr_ia2,
+            return_type,
+            concat_op,
+        )
+        .boxed(),
+        Type::Left | Type::Right | Type::Repeat => {
+            let func: fn(&str, i32, BytesWriter) -> Result<BytesGuard> = match expr_type {
+                Type::Left => left,
+                Type::Right => right,
+                _ => repeat,
+            };
+            BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+                expr_ia1,
+                expr_ia2,
+                return_type,
+                func,
+            )
+            .boxed()
+        }
+        _ => {
+            return Err(ErrorCode::NotImplemented(
+                format!("{:?} with two arguments is not supported yet", expr_type),
+                112.into(),
+            )
+            .into())
+        }
+    };
+    Ok(expr)
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::{DataChunk, Row};
@@ -134,4 +190,37 @@ mod tests {
             create_str_i32_binary_expr(new_substr_for, None, Some(ScalarImpl::Int32(for_pos)));
         test_evals_dummy(&substr_for_str_none, None);
     }
+
+    #[test]
+    fn test_binary_bytes_expr() {
+        let literal = |data_type, datum| LiteralExpression::new(data_type, datum).boxed();
+        let text = || literal(DataType::Varchar, Some(ScalarImpl::from("abc".to_string())));
+
+        let concat = new_binary_bytes_expr(
+            Type::ConcatOp,
+            text(),
+            literal(DataType::Varchar, Some(ScalarImpl::from("de".to_string()))),
+            DataType::Varchar,
+        )
+        .unwrap();
+        test_evals_dummy(&concat, Some(ScalarImpl::from("abcde".to_string())));
+
+        let concat_null = new_binary_bytes_expr(
+            Type::ConcatOp,
+            text(),
+            literal(DataType::Varchar, None),
+            DataType::Varchar,
+        )
+        .unwrap();
+        test_evals_dummy(&concat_null, None);
+
+        let repeat = new_binary_bytes_expr(
+            Type::Repeat,
+            text(),
+            literal(DataType::Int32, Some(ScalarImpl::Int32(2))),
+            DataType::Varchar,
+        )
+        .unwrap();
+        test_evals_dummy(&repeat, Some(ScalarImpl::from("abcabc".to_string())));
+    }
 }
@@ -13,22 +13,31 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, IntervalArray, ListArray, NaiveDateArray,
-    NaiveDateTimeArray, StructArray, Utf8Array,
+    Array, BoolArray, ByteaArray, DecimalArray, F64Array, I32Array, I64Array, IntervalArray,
+    JsonbArray, ListArray, NaiveDateArray, NaiveDateTimeArray, StructArray, Utf8Array,
 };
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::*;
 use risingwave_pb::expr::expr_node::Type;
 
 use crate::expr::template::BinaryExpression;
 use crate::expr::BoxedExpression;
 use crate::for_all_cmp_variants;
+use crate::vector_op::age::age;
 use crate::vector_op::arithmetic_op::*;
 use crate::vector_op::bitwise_op::*;
+use crate::vector_op::bytea::{bytea_decode, bytea_encode};
 use crate::vector_op::cmp::*;
-use crate::vector_op::extract::{extract_from_date, extract_from_timestamp};
-use crate::vector_op::like::like_default;
+use crate::vector_op::date_trunc::{date_trunc_interval, date_trunc_timestamp};
+use crate::vector_op::extract::{extract_from_date, extract_from_interval, extract_from_timestamp};
+use crate::vector_op::jsonb::{jsonb_contains, jsonb_exists};
+use crate::vector_op::like::{ilike_default, like_default};
+use crate::vector_op::math::{atan2_f64, pow_decimal, pow_f64};
 use crate::vector_op::position::position;
-use crate::vector_op::round::round_digits;
+use crate::vector_op::round::{round_digits, trunc_digits};
+use crate::vector_op::starts_with::starts_with;
+use crate::vector_op::timezone::{timestamp_at_time_zone, timestampz_at_time_zone};
+use crate::vector_op::to_timestamp::to_date;
 use crate::vector_op::tumble::{tumble_start_date, tumble_start_date_time};
 
 /// This macro helps create arithmetic expression.
@@ -154,6 +163,14 @@ macro_rules! gen_binary_expr_cmp {
                     gen_str_cmp($op),
                 ))
             }
+            (DataType::Bytea, DataType::Bytea) => Box::new(BinaryExpression::<
+                ByteaArray,
+                ByteaArray,
+                BoolArray,
+                _,
+            >::new(
+                $l, $r, $ret, gen_bytea_cmp($op)
+            )),
             (DataType::Struct { fields: _ }, DataType::Struct { fields: _ }) => Box::new(
                 BinaryExpression::<StructArray, StructArray, BoolArray, _>::new(
                     $l,
@@ -319,12 +336,78 @@ fn build_extract_expr(ret: DataType, l: BoxedExpression, r: BoxedExpression) ->
             DecimalArray,
             _,
         >::new(l, r, ret, extract_from_timestamp)),
+        DataType::Interval => Box::new(
+            BinaryExpression::<Utf8Array, IntervalArray, DecimalArray, _>::new(
+                l,
+                r,
+                ret,
+                extract_from_interval,
+            ),
+        ),
         _ => {
             unimplemented!("Extract ( {:?} ) is not supported yet!", r.return_type())
         }
     }
 }
 
+pub fn new_at_time_zone_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> Result<BoxedExpression> {
+    let expr: BoxedExpression = match l.return_type() {
+        DataType::Timestamp => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(l, r, ret, timestamp_at_time_zone)),
+        DataType::Timestampz => Box::new(BinaryExpression::<
+            I64Array,
+            Utf8Array,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, timestampz_at_time_zone)),
+        t => {
+            return Err(ErrorCode::NotImplemented(
+                format!("AtTimeZone({:?}) is not supported yet", t),
+                None.into(),
+            )
+            .into())
+        }
+    };
+    Ok(expr)
+}
+
+pub fn new_date_trunc_expr(
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> Result<BoxedExpression> {
+    let expr: BoxedExpression = match r.return_type() {
+        DataType::Timestamp => Box::new(BinaryExpression::<
+            Utf8Array,
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, date_trunc_timestamp)),
+        DataType::Interval => Box::new(BinaryExpression::<
+            Utf8Array,
+            IntervalArray,
+            IntervalArray,
+            _,
+        >::new(l, r, ret, date_trunc_interval)),
+        t => {
+            return Err(ErrorCode::NotImplemented(
+                format!("DateTrunc({:?}) is not supported yet", t),
+                None.into(),
+            )
+            .into())
+        }
+    };
+    Ok(expr)
+}
+
 pub fn new_binary_expr(
     expr_type: Type,
     ret: DataType,
@@ -461,6 +544,15 @@ pub fn new_binary_expr(
             }
         }
         Type::Extract => build_extract_expr(ret, l, r),
+        Type::ToDate => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, NaiveDateArray, _>::new(l, r, ret, to_date),
+        ),
+        Type::Age => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            IntervalArray,
+            _,
+        >::new(l, r, ret, age)),
         Type::RoundDigit => Box::new(
             BinaryExpression::<DecimalArray, I32Array, DecimalArray, _>::new(
                 l,
@@ -469,10 +561,55 @@ pub fn new_binary_expr(
                 round_digits,
             ),
         ),
+        Type::TruncDigit => Box::new(
+            BinaryExpression::<DecimalArray, I32Array, DecimalArray, _>::new(
+                l,
+                r,
+                ret,
+                trunc_digits,
+            ),
+        ),
+        Type::Pow => match ret {
+            DataType::Decimal => Box::new(BinaryExpression::<
+                DecimalArray,
+                DecimalArray,
+                DecimalArray,
+                _,
+            >::new(l, r, ret, pow_decimal)),
+            _ => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+                l, r, ret, pow_f64,
+            )),
+        },
+        Type::Atan2 => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, atan2_f64,
+        )),
         Type::Position => Box::new(BinaryExpression::<Utf8Array, Utf8Array, I32Array, _>::new(
             l, r, ret, position,
         )),
+        Type::StartsWith => Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+            l,
+            r,
+            ret,
+            starts_with,
+        )),
         Type::TumbleStart => new_tumble_start(l, r, ret),
+        Type::JsonbContains => Box::new(
+            BinaryExpression::<JsonbArray, JsonbArray, BoolArray, _>::new(
+                l,
+                r,
+                ret,
+                jsonb_contains,
+            ),
+        ),
+        Type::JsonbExists => Box::new(
+            BinaryExpression::<JsonbArray, Utf8Array, BoolArray, _>::new(l, r, ret, jsonb_exists),
+        ),
+        Type::Encode => Box::new(
+            BinaryExpression::<ByteaArray, Utf8Array, Utf8Array, _>::new(l, r, ret, bytea_encode),
+        ),
+        Type::Decode => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, ByteaArray, _>::new(l, r, ret, bytea_decode),
+        ),
 
         tp => {
             unimplemented!(
@@ -525,6 +662,19 @@ pub fn new_like_default(
     ))
 }
 
+pub fn new_ilike_default(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        ilike_default,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
@@ -22,8 +22,11 @@ use super::BoxedExpression;
 use crate::expr::template::BinaryNullableExpression;
 use crate::for_all_cmp_variants;
 use crate::vector_op::array_access::array_access;
-use crate::vector_op::cmp::{general_is_distinct_from, str_is_distinct_from};
+use crate::vector_op::cmp::{
+    bytea_is_distinct_from, general_is_distinct_from, str_is_distinct_from,
+};
 use crate::vector_op::conjunction::{and, or};
+use crate::vector_op::jsonb::*;
 
 macro_rules! gen_nullable_cmp_impl {
     ([$l:expr, $r:expr, $ret:expr], $( { $i1:ident, $i2:ident, $cast:ident, $func:ident} ),*) => {
@@ -71,6 +74,10 @@ pub fn new_nullable_binary_expr(
             BinaryNullableExpression::<BoolArray, BoolArray, BoolArray, _>::new(l, r, ret, or),
         ),
         Type::IsDistinctFrom => new_distinct_from_expr(l, r, ret),
+        Type::JsonbAccessInner
+        | Type::JsonbAccessStr
+        | Type::JsonbAccessPath
+        | Type::JsonbAccessPathStr => build_jsonb_access_expr(expr_type, ret, l, r),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
@@ -112,11 +119,48 @@ fn build_array_access_expr(
         DataType::Timestamp => array_access_expression!(NaiveDateTimeArray),
         DataType::Timestampz => array_access_expression!(PrimitiveArray::<i64>),
         DataType::Interval => array_access_expression!(IntervalArray),
+        DataType::Jsonb => array_access_expression!(JsonbArray),
+        DataType::Bytea => array_access_expression!(ByteaArray),
+        DataType::Uuid => array_access_expression!(UuidArray),
         DataType::Struct { .. } => array_access_expression!(StructArray),
         DataType::List { .. } => array_access_expression!(ListArray),
     }
 }
 
+fn build_jsonb_access_expr(
+    expr_type: Type,
+    ret: DataType,
+    l: BoxedExpression,
+    r: BoxedExpression,
+) -> BoxedExpression {
+    macro_rules! jsonb_access_expression {
+        ($key:ty, $output:ty, $func:ident) => {
+            Box::new(
+                BinaryNullableExpression::<JsonbArray, $key, $output, _>::new(l, r, ret, $func),
+            )
+        };
+    }
+
+    match (expr_type, r.return_type()) {
+        (Type::JsonbAccessInner, DataType::Int32) => {
+            jsonb_access_expression!(I32Array, JsonbArray, jsonb_access_element)
+        }
+        (Type::JsonbAccessInner, _) => {
+            jsonb_access_expression!(Utf8Array, JsonbArray, jsonb_access_field)
+        }
+        (Type::JsonbAccessStr, DataType::Int32) => {
+            jsonb_access_expression!(I32Array, Utf8Array, jsonb_access_element_str)
+        }
+        (Type::JsonbAccessStr, _) => {
+            jsonb_access_expression!(Utf8Array, Utf8Array, jsonb_access_field_str)
+        }
+        (Type::JsonbAccessPath, _) => {
+            jsonb_access_expression!(Utf8Array, JsonbArray, jsonb_access_path)
+        }
+        (_, _) => jsonb_access_expression!(Utf8Array, Utf8Array, jsonb_access_path_str),
+    }
+}
+
 pub fn new_distinct_from_expr(
     l: BoxedExpression,
     r: BoxedExpression,
@@ -133,6 +177,14 @@ pub fn new_distinct_from_expr(
         >::new(
             l, r, ret, str_is_distinct_from
         )),
+        (DataType::Bytea, DataType::Bytea) => {
+            Box::new(BinaryNullableExpression::<
+                ByteaArray,
+                ByteaArray,
+                BoolArray,
+                _,
+            >::new(l, r, ret, bytea_is_distinct_from))
+        }
         _ => {
             for_all_cmp_variants! {gen_nullable_cmp_impl, l, r, ret, general_is_distinct_from}
         }
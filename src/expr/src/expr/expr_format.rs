@@ -0,0 +1,204 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, Row, Utf8ArrayBuilder,
+};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, Scalar};
+use risingwave_common::{ensure, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::format::format;
+
+/// `format(formatstr, args...)`, where the arguments have been cast to strings. See
+/// [`format`] for the format specifiers.
+#[derive(Debug)]
+pub struct FormatExpression {
+    return_type: DataType,
+    fmt_expr: BoxedExpression,
+    arg_exprs: Vec<BoxedExpression>,
+}
+
+impl Expression for FormatExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let fmt_column = self.fmt_expr.eval(input)?;
+        let fmt_column = fmt_column.as_utf8();
+
+        let arg_columns = self
+            .arg_exprs
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let arg_columns_ref = arg_columns.iter().map(|c| c.as_utf8()).collect::<Vec<_>>();
+
+        let row_len = input.cardinality();
+        let mut builder = Utf8ArrayBuilder::new(row_len)?;
+        let mut args = Vec::with_capacity(arg_columns_ref.len());
+
+        for row_idx in 0..row_len {
+            let fmt = match fmt_column.value_at(row_idx) {
+                Some(fmt) => fmt,
+                None => {
+                    builder.append(None)?;
+                    continue;
+                }
+            };
+
+            args.clear();
+            args.extend(arg_columns_ref.iter().map(|c| c.value_at(row_idx)));
+
+            let mut writer = builder.writer().begin();
+            format(fmt, &args, &mut writer)?;
+            builder = writer.finish()?.into_inner();
+        }
+        Ok(Arc::new(ArrayImpl::from(builder.finish()?)))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let fmt = match self.fmt_expr.eval_row(input)? {
+            Some(fmt) => fmt,
+            None => return Ok(None),
+        };
+        let args = self
+            .arg_exprs
+            .iter()
+            .map(|c| c.eval_row(input))
+            .collect::<Result<Vec<_>>>()?;
+        let args = args
+            .iter()
+            .map(|arg| arg.as_ref().map(|arg| arg.as_utf8().as_str()))
+            .collect::<Vec<_>>();
+
+        let mut writer = Utf8ArrayBuilder::new(1)?.writer().begin();
+        format(fmt.as_utf8(), &args, &mut writer)?;
+        let array = writer.finish()?.into_inner().finish()?;
+        Ok(array.value_at(0).map(|s| s.to_owned().to_scalar_value()))
+    }
+}
+
+impl FormatExpression {
+    pub fn new(
+        return_type: DataType,
+        fmt_expr: BoxedExpression,
+        arg_exprs: Vec<BoxedExpression>,
+    ) -> Self {
+        FormatExpression {
+            return_type,
+            fmt_expr,
+            arg_exprs,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for FormatExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type()? == Type::Format);
+
+        let ret_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+
+        let children = &func_call_node.children;
+        ensure!(!children.is_empty());
+        let fmt_expr = expr_build_from_prost(&children[0])?;
+
+        let arg_exprs = children[1..]
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(FormatExpression::new(ret_type, fmt_expr, arg_exprs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::{DataChunk, DataChunkTestExt, Row};
+    use risingwave_common::types::{Datum, Scalar};
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::RexNode;
+    use risingwave_pb::expr::expr_node::Type::Format;
+    use risingwave_pb::expr::{ExprNode, FunctionCall};
+
+    use crate::expr::expr_format::FormatExpression;
+    use crate::expr::test_utils::make_input_ref;
+    use crate::expr::Expression;
+
+    fn make_format_expr() -> FormatExpression {
+        let children = (0..3)
+            .map(|i| make_input_ref(i, TypeName::Varchar))
+            .collect();
+        let prost = ExprNode {
+            expr_type: Format as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Varchar as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall { children })),
+        };
+        FormatExpression::try_from(&prost).unwrap()
+    }
+
+    #[test]
+    fn test_eval_format_expr() {
+        let format_expr = make_format_expr();
+        let chunk = DataChunk::from_pretty(
+            "
+            T       T T
+            %s-%I   a B
+            .       a b
+            %L,%s%% . .",
+        );
+
+        let actual = format_expr.eval(&chunk).unwrap();
+        let actual = actual
+            .iter()
+            .map(|r| r.map(|s| s.into_utf8()))
+            .collect_vec();
+        let expected = vec![Some("a-\"B\""), None, Some("NULL,%")];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_eval_row_format_expr() {
+        let format_expr = make_format_expr();
+        let row_inputs = vec![
+            (vec![Some("%s-%I"), Some("a"), Some("B")], Some("a-\"B\"")),
+            (vec![None, Some("a"), Some("b")], None),
+            (vec![Some("%L,%s%%"), None, None], Some("NULL,%")),
+        ];
+
+        for (row_input, expected) in row_inputs {
+            let datum_vec: Vec<Datum> = row_input
+                .iter()
+                .map(|e| e.map(|s| s.to_string().to_scalar_value()))
+                .collect();
+            let result = format_expr.eval_row(&Row::new(datum_vec)).unwrap();
+            let expected = expected.map(|s| s.to_string().to_scalar_value());
+            assert_eq!(result, expected);
+        }
+    }
+}
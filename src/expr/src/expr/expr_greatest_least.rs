@@ -0,0 +1,162 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, Row};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::try_match_expand;
+use risingwave_common::types::{DataType, Datum};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+
+/// `GREATEST` or `LEAST` of the children, which ignores nulls and is null only if all the children
+/// are.
+#[derive(Debug)]
+pub struct GreatestLeastExpression {
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+    /// `Greater` for `GREATEST`, and `Less` for `LEAST`.
+    ordering: Ordering,
+}
+
+impl GreatestLeastExpression {
+    pub fn new(return_type: DataType, children: Vec<BoxedExpression>, ordering: Ordering) -> Self {
+        GreatestLeastExpression {
+            return_type,
+            children,
+            ordering,
+        }
+    }
+
+    fn pick(&self, datums: impl Iterator<Item = Datum>) -> Datum {
+        datums
+            .flatten()
+            .reduce(|picked, datum| match datum.cmp(&picked) == self.ordering {
+                true => datum,
+                false => picked,
+            })
+    }
+}
+
+impl Expression for GreatestLeastExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let children_array = self
+            .children
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+
+        let len = children_array[0].len();
+        for i in 0..len {
+            let datum = self.pick(children_array.iter().map(|array| array.datum_at(i)));
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let datums = self
+            .children
+            .iter()
+            .map(|c| c.eval_row(input))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.pick(datums.into_iter()))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for GreatestLeastExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        let ordering = match prost.get_expr_type()? {
+            Type::Greatest => Ordering::Greater,
+            Type::Least => Ordering::Less,
+            _ => unreachable!(),
+        };
+        let ret_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+
+        let children = func_call_node
+            .children
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(GreatestLeastExpression::new(ret_type, children, ordering))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunk;
+    use risingwave_common::test_prelude::DataChunkTestExt;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::{RexNode, Type};
+    use risingwave_pb::expr::{ExprNode, FunctionCall};
+
+    use super::*;
+    use crate::expr::test_utils::make_input_ref;
+
+    fn make_function(kind: Type) -> ExprNode {
+        ExprNode {
+            expr_type: kind as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Int32 as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall {
+                children: vec![
+                    make_input_ref(0, TypeName::Int32),
+                    make_input_ref(1, TypeName::Int32),
+                ],
+            })),
+        }
+    }
+
+    #[test]
+    fn test_greatest_least() {
+        let data_chunk = DataChunk::from_pretty(
+            "i i
+             1 2
+             3 .
+             . .",
+        );
+
+        let greatest = GreatestLeastExpression::try_from(&make_function(Type::Greatest)).unwrap();
+        let res = greatest.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Int32(2)));
+        assert_eq!(res.datum_at(1), Some(ScalarImpl::Int32(3)));
+        assert_eq!(res.datum_at(2), None);
+
+        let least = GreatestLeastExpression::try_from(&make_function(Type::Least)).unwrap();
+        let res = least.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Int32(1)));
+        assert_eq!(res.datum_at(1), Some(ScalarImpl::Int32(3)));
+        assert_eq!(res.datum_at(2), None);
+
+        let row = Row::new(vec![Some(ScalarImpl::Int32(5)), Some(ScalarImpl::Int32(4))]);
+        assert_eq!(least.eval_row(&row).unwrap(), Some(ScalarImpl::Int32(4)));
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{
+    ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, JsonbArrayBuilder, Row,
+};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{DataType, Datum, JsonbVal, Scalar};
+use risingwave_common::{ensure, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+
+/// `jsonb_build_object(key1, value1, key2, value2, ...)` builds a JSON object from alternating
+/// keys and values. Keys are strings and must not be null, and a null value becomes JSON `null`.
+#[derive(Debug)]
+pub struct JsonbBuildObjectExpression {
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+}
+
+impl Expression for JsonbBuildObjectExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let children_array = self
+            .children
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let len = children_array
+            .first()
+            .map_or(input.capacity(), |array| array.len());
+        let mut builder = JsonbArrayBuilder::new(len)?;
+        for i in 0..len {
+            let object = build_object(children_array.iter().map(|array| array.datum_at(i)))?;
+            builder.append(Some(object.as_scalar_ref()))?;
+        }
+        Ok(Arc::new(ArrayImpl::from(builder.finish()?)))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let datums = self
+            .children
+            .iter()
+            .map(|c| c.eval_row(input))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(build_object(datums.into_iter())?.to_scalar_value()))
+    }
+}
+
+fn build_object(datums: impl Iterator<Item = Datum>) -> Result<JsonbVal> {
+    let pairs = datums
+        .tuples()
+        .enumerate()
+        .map(|(i, (key, value))| {
+            let key = key.ok_or_else(|| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "argument {}: key must not be null",
+                    i * 2 + 1
+                ))
+            })?;
+            let value = value.map_or_else(JsonbVal::null, |v| JsonbVal::from_scalar(&v));
+            Ok((key.into_utf8(), value))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(JsonbVal::object(pairs))
+}
+
+impl JsonbBuildObjectExpression {
+    pub fn new(return_type: DataType, children: Vec<BoxedExpression>) -> Self {
+        JsonbBuildObjectExpression {
+            return_type,
+            children,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for JsonbBuildObjectExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type()? == Type::JsonbBuildObject);
+
+        let ret_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+
+        let children = func_call_node
+            .children
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(children.len() % 2 == 0);
+        Ok(JsonbBuildObjectExpression::new(ret_type, children))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::RexNode;
+    use risingwave_pb::expr::expr_node::Type::JsonbBuildObject;
+    use risingwave_pb::expr::{ExprNode, FunctionCall};
+
+    use super::*;
+    use crate::expr::test_utils::make_input_ref;
+
+    fn make_build_object(children: Vec<ExprNode>) -> JsonbBuildObjectExpression {
+        JsonbBuildObjectExpression::try_from(&ExprNode {
+            expr_type: JsonbBuildObject as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Jsonb as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall { children })),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_eval_jsonb_build_object() {
+        let expr = make_build_object(vec![
+            make_input_ref(0, TypeName::Varchar),
+            make_input_ref(1, TypeName::Int32),
+            make_input_ref(2, TypeName::Varchar),
+            make_input_ref(3, TypeName::Varchar),
+        ]);
+
+        let chunk = DataChunk::from_pretty(
+            "
+            T i T T
+            a 1 b x
+            a . b .",
+        );
+        let actual = expr.eval(&chunk).unwrap();
+        let actual = actual
+            .iter()
+            .map(|v| v.map(|v| v.to_string()))
+            .collect_vec();
+        assert_eq!(
+            actual,
+            vec![
+                Some(r#"{"a": 1, "b": "x"}"#.to_string()),
+                Some(r#"{"a": null, "b": null}"#.to_string()),
+            ]
+        );
+
+        let row = Row::new(vec![
+            None,
+            Some(ScalarImpl::Int32(1)),
+            Some(ScalarImpl::Utf8("b".into())),
+            None,
+        ]);
+        assert!(expr.eval_row(&row).is_err());
+
+        let empty = make_build_object(vec![]);
+        assert_eq!(
+            empty.eval_row(&Row::new(vec![])).unwrap(),
+            Some(ScalarImpl::Jsonb(JsonbVal::object(vec![])))
+        );
+    }
+}
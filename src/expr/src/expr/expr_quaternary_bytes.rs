@@ -0,0 +1,68 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! For expression that accept 4 arguments + 1 bytes writer as input.
+
+use risingwave_common::array::{I32Array, Utf8Array};
+use risingwave_common::types::DataType;
+
+use crate::expr::template::QuaternaryBytesExpression;
+use crate::expr::BoxedExpression;
+use crate::vector_op::overlay::overlay_for;
+
+pub fn new_overlay_for_expr(
+    s: BoxedExpression,
+    new_sub: BoxedExpression,
+    start: BoxedExpression,
+    count: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(QuaternaryBytesExpression::<
+        Utf8Array,
+        Utf8Array,
+        I32Array,
+        I32Array,
+        _,
+    >::new(
+        s, new_sub, start, count, return_type, overlay_for
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{DataChunk, Row};
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    #[test]
+    fn test_overlay_for() {
+        let text = |s: &str| LiteralExpression::new(DataType::Varchar, Some(s.to_string().into()));
+        let int = |v: i32| LiteralExpression::new(DataType::Int32, Some(ScalarImpl::Int32(v)));
+        let expr = new_overlay_for_expr(
+            Box::new(text("Txxxxas")),
+            Box::new(text("hom")),
+            Box::new(int(2)),
+            Box::new(int(4)),
+            DataType::Varchar,
+        );
+        let expected = Some(ScalarImpl::from("Thomas".to_string()));
+
+        let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+        assert_eq!(res.to_datum(), expected);
+        let res = expr.eval_row(&Row::new(vec![])).unwrap();
+        assert_eq!(res, expected);
+    }
+}
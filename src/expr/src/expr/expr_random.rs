@@ -0,0 +1,122 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{
+    ArrayBuilder, ArrayRef, DataChunk, F64ArrayBuilder, Row, UuidArrayBuilder,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, OrderedF64, Scalar, Uuid};
+
+use crate::expr::Expression;
+
+/// `random()`, a random value in the range 0.0 <= x < 1.0 for each row.
+#[derive(Debug)]
+pub struct RandomExpression;
+
+impl RandomExpression {
+    fn next() -> OrderedF64 {
+        rand::random::<f64>().into()
+    }
+}
+
+impl Expression for RandomExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Float64
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut builder = F64ArrayBuilder::new(input.cardinality())?;
+        for _ in 0..input.cardinality() {
+            builder.append(Some(Self::next()))?;
+        }
+        Ok(Arc::new(builder.finish()?.into()))
+    }
+
+    fn eval_row(&self, _input: &Row) -> Result<Datum> {
+        Ok(Some(Self::next().to_scalar_value()))
+    }
+}
+
+/// `gen_random_uuid()`, a random version 4 UUID for each row.
+#[derive(Debug)]
+pub struct GenRandomUuidExpression;
+
+impl GenRandomUuidExpression {
+    fn next() -> Uuid {
+        Uuid::new_v4(rand::random())
+    }
+}
+
+impl Expression for GenRandomUuidExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Uuid
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let mut builder = UuidArrayBuilder::new(input.cardinality())?;
+        for _ in 0..input.cardinality() {
+            builder.append(Some(Self::next()))?;
+        }
+        Ok(Arc::new(builder.finish()?.into()))
+    }
+
+    fn eval_row(&self, _input: &Row) -> Result<Datum> {
+        Ok(Some(Self::next().to_scalar_value()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::Array;
+    use risingwave_common::test_prelude::DataChunkTestExt;
+
+    use super::*;
+
+    #[test]
+    fn test_random() {
+        let data_chunk = DataChunk::from_pretty(
+            "i
+             1
+             2
+             3",
+        );
+        let res = RandomExpression.eval(&data_chunk).unwrap();
+        let values = res
+            .as_float64()
+            .iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(values.len(), 3);
+        assert!(values.iter().all(|v| (0.0..1.0).contains(&v.0)));
+    }
+
+    #[test]
+    fn test_gen_random_uuid() {
+        let data_chunk = DataChunk::from_pretty(
+            "i
+             1
+             2",
+        );
+        let res = GenRandomUuidExpression.eval(&data_chunk).unwrap();
+        let values = res.as_uuid().iter().map(Option::unwrap).collect::<Vec<_>>();
+        assert_eq!(values.len(), 2);
+        assert_ne!(values[0], values[1]);
+        for value in values {
+            assert_eq!(value.as_bytes()[6] >> 4, 4);
+            assert_eq!(value.as_bytes()[8] >> 6, 0b10);
+        }
+    }
+}
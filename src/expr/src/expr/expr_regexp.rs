@@ -0,0 +1,292 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{Array, ArrayRef, DataChunk, Row};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_pb::expr::expr_node::Type;
+
+use crate::expr::{BoxedExpression, Expression};
+use crate::vector_op::regexp::*;
+
+/// `RegexpExpression` evaluates the regular expression functions. The children are the source
+/// string, the pattern, the replacement of `regexp_replace` and the optional flags, in this order.
+#[derive(Debug)]
+pub struct RegexpExpression {
+    func: Type,
+    return_type: DataType,
+    children: Vec<BoxedExpression>,
+    /// The pattern compiled at build time, if both the pattern and the flags are constants.
+    compiled: Option<RegexpContext>,
+}
+
+impl RegexpExpression {
+    pub fn new(
+        func: Type,
+        return_type: DataType,
+        children: Vec<BoxedExpression>,
+        compiled: Option<RegexpContext>,
+    ) -> Self {
+        Self {
+            func,
+            return_type,
+            children,
+            compiled,
+        }
+    }
+
+    /// Compiles the pattern with the flags, checking that the function accepts these flags.
+    pub fn compile(func: Type, pattern: &str, flags: &str) -> Result<RegexpContext> {
+        let ctx = match func {
+            Type::SimilarTo => RegexpContext::from_similar_to(pattern)?,
+            _ => RegexpContext::new(pattern, flags)?,
+        };
+        if ctx.global && func != Type::RegexpReplace {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "{:?} does not support the \"global\" option",
+                func
+            ))
+            .into());
+        }
+        Ok(ctx)
+    }
+
+    fn flags_index(&self) -> usize {
+        match self.func {
+            Type::RegexpReplace => 3,
+            _ => 2,
+        }
+    }
+
+    /// Evaluates a row where none of the inputs is null.
+    fn eval_strs(&self, args: &[&str]) -> Result<Datum> {
+        let compiled;
+        let ctx = match &self.compiled {
+            Some(ctx) => ctx,
+            None => {
+                let flags = args.get(self.flags_index()).copied().unwrap_or_default();
+                compiled = Self::compile(self.func, args[1], flags)?;
+                &compiled
+            }
+        };
+        let text = args[0];
+        let datum = match self.func {
+            Type::RegexpLike | Type::SimilarTo => Some(ScalarImpl::Bool(regexp_like(text, ctx))),
+            Type::RegexpMatch => regexp_match(text, ctx).map(ScalarImpl::List),
+            Type::RegexpReplace => Some(ScalarImpl::Utf8(regexp_replace(text, args[2], ctx))),
+            Type::RegexpSplitToArray => Some(ScalarImpl::List(regexp_split_to_array(text, ctx))),
+            _ => unreachable!(),
+        };
+        Ok(datum)
+    }
+}
+
+impl Expression for RegexpExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let children_array = self
+            .children
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let children_array = children_array
+            .iter()
+            .map(|array| array.as_utf8())
+            .collect::<Vec<_>>();
+        let len = children_array[0].len();
+        let mut builder = self.return_type.create_array_builder(len)?;
+        for i in 0..len {
+            let args = children_array
+                .iter()
+                .map(|array| array.value_at(i))
+                .collect::<Option<Vec<_>>>();
+            let datum = match args {
+                Some(args) => self.eval_strs(&args)?,
+                None => None,
+            };
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        let datums = self
+            .children
+            .iter()
+            .map(|c| c.eval_row(input))
+            .collect::<Result<Vec<_>>>()?;
+        let args = datums
+            .iter()
+            .map(|datum| match datum {
+                Some(ScalarImpl::Utf8(s)) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        match args {
+            Some(args) => self.eval_strs(&args),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::RexNode;
+    use risingwave_pb::expr::{ConstantValue, ExprNode, FunctionCall};
+
+    use super::*;
+    use crate::expr::build_from_prost;
+    use crate::expr::test_utils::make_input_ref;
+
+    fn make_string_literal(s: &str) -> ExprNode {
+        ExprNode {
+            expr_type: Type::ConstantValue as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Varchar as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::Constant(ConstantValue {
+                body: s.as_bytes().to_vec(),
+            })),
+        }
+    }
+
+    fn make_regexp_expr(func: Type, ret: TypeName, children: Vec<ExprNode>) -> BoxedExpression {
+        build_from_prost(&ExprNode {
+            expr_type: func as i32,
+            return_type: Some(ProstDataType {
+                type_name: ret as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall { children })),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_regexp_like() {
+        let chunk = DataChunk::from_pretty(
+            "
+            T     T
+            abc   ^A
+            xbc   b
+            .     b
+            ABC   (",
+        );
+        let constant = make_regexp_expr(
+            Type::RegexpLike,
+            TypeName::Boolean,
+            vec![
+                make_input_ref(0, TypeName::Varchar),
+                make_string_literal("^A"),
+                make_string_literal("i"),
+            ],
+        );
+        let actual = constant.eval(&chunk).unwrap();
+        assert_eq!(
+            actual.iter().collect_vec(),
+            vec![
+                Some(true.into()),
+                Some(false.into()),
+                None,
+                Some(true.into())
+            ]
+        );
+
+        // The pattern is compiled per row, and an invalid one is an error.
+        let non_constant = make_regexp_expr(
+            Type::RegexpLike,
+            TypeName::Boolean,
+            vec![
+                make_input_ref(0, TypeName::Varchar),
+                make_input_ref(1, TypeName::Varchar),
+            ],
+        );
+        assert!(non_constant.eval(&chunk).is_err());
+        let chunk = DataChunk::from_pretty(
+            "
+            T     T
+            abc   ^A
+            xbc   b
+            .     b",
+        );
+        let actual = non_constant.eval(&chunk).unwrap();
+        assert_eq!(
+            actual.iter().collect_vec(),
+            vec![Some(false.into()), Some(true.into()), None]
+        );
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let expr = make_regexp_expr(
+            Type::RegexpReplace,
+            TypeName::Varchar,
+            vec![
+                make_input_ref(0, TypeName::Varchar),
+                make_string_literal("(\\d)"),
+                make_string_literal("<\\1>"),
+                make_input_ref(1, TypeName::Varchar),
+            ],
+        );
+        let row = Row::new(vec![
+            Some(ScalarImpl::Utf8("a1b2".into())),
+            Some(ScalarImpl::Utf8("g".into())),
+        ]);
+        assert_eq!(
+            expr.eval_row(&row).unwrap(),
+            Some(ScalarImpl::Utf8("a<1>b<2>".into()))
+        );
+        let row = Row::new(vec![
+            Some(ScalarImpl::Utf8("a1b2".into())),
+            Some(ScalarImpl::Utf8("".into())),
+        ]);
+        assert_eq!(
+            expr.eval_row(&row).unwrap(),
+            Some(ScalarImpl::Utf8("a<1>b2".into()))
+        );
+    }
+
+    #[test]
+    fn test_global_not_supported() {
+        let prost = ExprNode {
+            expr_type: Type::RegexpMatch as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::List as i32,
+                field_type: vec![ProstDataType {
+                    type_name: TypeName::Varchar as i32,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall {
+                children: vec![
+                    make_input_ref(0, TypeName::Varchar),
+                    make_string_literal("a"),
+                    make_string_literal("g"),
+                ],
+            })),
+        };
+        assert!(build_from_prost(&prost).is_err());
+    }
+}
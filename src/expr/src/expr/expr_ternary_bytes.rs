@@ -14,14 +14,24 @@
 
 //! For expression that accept 3 arguments + 1 bytes writer as input.
 
-use risingwave_common::array::{I32Array, Utf8Array};
+use risingwave_common::array::{
+    ByteaArray, BytesGuard, BytesWriter, I32Array, I64Array, NaiveDateArray, Utf8Array,
+};
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
+use risingwave_pb::expr::expr_node::Type;
 
-use crate::expr::template::TernaryBytesExpression;
+use crate::expr::template::{TernaryBytesExpression, TernaryExpression};
 use crate::expr::BoxedExpression;
+use crate::vector_op::bytea::bytea_substr_start_for;
+use crate::vector_op::date_trunc::date_trunc_timestampz;
+use crate::vector_op::make_date::make_date;
+use crate::vector_op::overlay::overlay;
+use crate::vector_op::pad::{lpad, rpad};
 use crate::vector_op::replace::replace;
 use crate::vector_op::split_part::split_part;
 use crate::vector_op::substr::substr_start_for;
+use crate::vector_op::to_timestamp::to_timestamp1;
 use crate::vector_op::translate::translate;
 
 pub fn new_substr_start_end(
@@ -30,15 +40,26 @@ pub fn new_substr_start_end(
     len: BoxedExpression,
     return_type: DataType,
 ) -> BoxedExpression {
-    Box::new(
-        TernaryBytesExpression::<Utf8Array, I32Array, I32Array, _>::new(
-            items,
-            off,
-            len,
-            return_type,
-            substr_start_for,
+    match items.return_type() {
+        DataType::Bytea => Box::new(TernaryExpression::<
+            ByteaArray,
+            I32Array,
+            I32Array,
+            ByteaArray,
+            _,
+        >::new(
+            items, off, len, return_type, bytea_substr_start_for
+        )),
+        _ => Box::new(
+            TernaryBytesExpression::<Utf8Array, I32Array, I32Array, _>::new(
+                items,
+                off,
+                len,
+                return_type,
+                substr_start_for,
+            ),
         ),
-    )
+    }
 }
 
 pub fn new_replace_expr(
@@ -92,6 +113,72 @@ pub fn new_split_part_expr(
     )
 }
 
+pub fn new_ternary_expr(
+    expr_type: Type,
+    return_type: DataType,
+    e1: BoxedExpression,
+    e2: BoxedExpression,
+    e3: BoxedExpression,
+) -> Result<BoxedExpression> {
+    let expr: BoxedExpression = match expr_type {
+        Type::DateTrunc => Box::new(TernaryExpression::<
+            Utf8Array,
+            I64Array,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(
+            e1, e2, e3, return_type, date_trunc_timestampz
+        )),
+        Type::ToTimestamp1 => Box::new(TernaryExpression::<
+            Utf8Array,
+            Utf8Array,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(e1, e2, e3, return_type, to_timestamp1)),
+        Type::Lpad | Type::Rpad => {
+            let func: fn(&str, i32, &str, BytesWriter) -> Result<BytesGuard> = match expr_type {
+                Type::Lpad => lpad,
+                _ => rpad,
+            };
+            Box::new(
+                TernaryBytesExpression::<Utf8Array, I32Array, Utf8Array, _>::new(
+                    e1,
+                    e2,
+                    e3,
+                    return_type,
+                    func,
+                ),
+            )
+        }
+        Type::Overlay => Box::new(
+            TernaryBytesExpression::<Utf8Array, Utf8Array, I32Array, _>::new(
+                e1,
+                e2,
+                e3,
+                return_type,
+                overlay,
+            ),
+        ),
+        Type::MakeDate => Box::new(TernaryExpression::<
+            I32Array,
+            I32Array,
+            I32Array,
+            NaiveDateArray,
+            _,
+        >::new(e1, e2, e3, return_type, make_date)),
+        _ => {
+            return Err(ErrorCode::NotImplemented(
+                format!("{:?} with three arguments is not supported yet", expr_type),
+                112.into(),
+            )
+            .into())
+        }
+    };
+    Ok(expr)
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::{DataChunk, Row};
@@ -26,15 +26,28 @@ use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::{decimal_abs, general_abs, general_neg};
 use crate::vector_op::ascii::ascii;
 use crate::vector_op::bitwise_op::general_bitnot;
+use crate::vector_op::bytea::{bytea_length, bytea_sha256};
 use crate::vector_op::cast::*;
+use crate::vector_op::chr::chr;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
+use crate::vector_op::initcap::initcap;
+use crate::vector_op::jsonb::jsonb_typeof;
 use crate::vector_op::length::length_default;
 use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
+use crate::vector_op::math::{
+    acos_f64, asin_f64, atan_f64, cbrt_f64, cos_f64, cot_f64, degrees_f64, exp_decimal, exp_f64,
+    ln_decimal, ln_f64, log10_decimal, log10_f64, radians_f64, sign_decimal, sign_f64, sin_f64,
+    sqrt_decimal, sqrt_f64, tan_f64,
+};
 use crate::vector_op::md5::md5;
+use crate::vector_op::quote::{quote_ident, quote_literal};
+use crate::vector_op::reverse::reverse;
 use crate::vector_op::round::*;
 use crate::vector_op::rtrim::rtrim;
+use crate::vector_op::to_hex::to_hex;
+use crate::vector_op::to_timestamp::to_timestamp;
 use crate::vector_op::trim::trim;
 use crate::vector_op::upper::upper;
 
@@ -90,6 +103,7 @@ macro_rules! gen_cast {
             { time, time, |x| Ok(x) },
             { boolean, boolean, |x| Ok(x) },
             { varchar, varchar, |x| Ok(x.into()) },
+            { uuid, uuid, |x| Ok(x) },
 
             { varchar, date, str_to_date },
             { varchar, time, str_to_time },
@@ -102,6 +116,9 @@ macro_rules! gen_cast {
             { varchar, float64, str_parse },
             { varchar, decimal, str_parse },
             { varchar, boolean, str_to_bool },
+            { varchar, jsonb, str_parse },
+            { varchar, bytea, str_parse },
+            { varchar, uuid, str_parse },
 
             { boolean, varchar, general_to_string },
             { int16, varchar, general_to_string },
@@ -110,6 +127,9 @@ macro_rules! gen_cast {
             { float32, varchar, general_to_string },
             { float64, varchar, general_to_string },
             { decimal, varchar, general_to_string },
+            { jsonb, varchar, |x| general_to_string(x) },
+            { bytea, varchar, |x| general_to_string(x) },
+            { uuid, varchar, general_to_string },
 
             { boolean, int32, general_cast },
             { int32, boolean, int32_to_bool },
@@ -147,7 +167,9 @@ macro_rules! gen_cast {
             { decimal, float32, to_f32 },
             { decimal, float64, to_f64 },
 
-            { date, timestamp, date_to_timestamp }
+            { date, timestamp, date_to_timestamp },
+            { timestamp, date, timestamp_to_date },
+            { timestamp, time, timestamp_to_time }
         }
     };
 }
@@ -207,18 +229,12 @@ macro_rules! gen_unary_atm_expr  {
     };
 }
 
-macro_rules! gen_round_expr {
-    (
-        $expr_name:literal,
-        $child:expr,
-        $ret:expr,
-        $float64_round_func:ident,
-        $decimal_round_func:ident
-    ) => {
+macro_rules! gen_float64_or_decimal_expr {
+    ($expr_name:literal, $child:expr, $ret:expr, $float64_func:ident, $decimal_func:ident) => {
         gen_unary_impl! {
             [$expr_name, $child, $ret],
-            { float64, float64, $float64_round_func },
-            { decimal, decimal, $decimal_round_func },
+            { float64, float64, $float64_func },
+            { decimal, decimal, $decimal_func },
         }
     };
 }
@@ -284,11 +300,57 @@ pub fn new_unary_expr(
             return_type,
             md5,
         )),
+        (
+            ProstType::Reverse
+            | ProstType::Initcap
+            | ProstType::QuoteIdent
+            | ProstType::QuoteLiteral,
+            _,
+            _,
+        ) => {
+            let func: fn(&str, BytesWriter) -> Result<BytesGuard> = match expr_type {
+                ProstType::Reverse => reverse,
+                ProstType::Initcap => initcap,
+                ProstType::QuoteIdent => quote_ident,
+                _ => quote_literal,
+            };
+            Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+                child_expr,
+                return_type,
+                func,
+            ))
+        }
+        (ProstType::Chr, _, _) => Box::new(UnaryBytesExpression::<I32Array, _>::new(
+            child_expr,
+            return_type,
+            chr,
+        )),
+        (ProstType::ToHex, _, DataType::Int64) => Box::new(
+            UnaryBytesExpression::<I64Array, _>::new(child_expr, return_type, to_hex::<i64>),
+        ),
+        (ProstType::ToHex, _, _) => Box::new(UnaryBytesExpression::<I32Array, _>::new(
+            child_expr,
+            return_type,
+            to_hex::<i32>,
+        )),
+        (ProstType::Sha256, _, _) => Box::new(UnaryExpression::<ByteaArray, ByteaArray, _>::new(
+            child_expr,
+            return_type,
+            bytea_sha256,
+        )),
         (ProstType::Ascii, _, _) => Box::new(UnaryExpression::<Utf8Array, I32Array, _>::new(
             child_expr,
             return_type,
             ascii,
         )),
+        (ProstType::ToTimestamp, _, _) => Box::new(UnaryExpression::<F64Array, I64Array, _>::new(
+            child_expr,
+            return_type,
+            to_timestamp,
+        )),
+        (ProstType::JsonbTypeof, _, _) => Box::new(
+            UnaryExpression::<JsonbArray, Utf8Array, _>::new(child_expr, return_type, jsonb_typeof),
+        ),
         (ProstType::Neg, _, _) => {
             gen_unary_atm_expr! { "Neg", child_expr, return_type, general_neg,
                 {
@@ -313,13 +375,64 @@ pub fn new_unary_expr(
             }
         }
         (ProstType::Ceil, _, _) => {
-            gen_round_expr! {"Ceil", child_expr, return_type, ceil_f64, ceil_decimal}
+            gen_float64_or_decimal_expr! {"Ceil", child_expr, return_type, ceil_f64, ceil_decimal}
         }
         (ProstType::Floor, _, _) => {
-            gen_round_expr! {"Floor", child_expr, return_type, floor_f64, floor_decimal}
+            gen_float64_or_decimal_expr! {"Floor", child_expr, return_type, floor_f64, floor_decimal}
         }
         (ProstType::Round, _, _) => {
-            gen_round_expr! {"Ceil", child_expr, return_type, round_f64, round_decimal}
+            gen_float64_or_decimal_expr! {"Round", child_expr, return_type, round_f64, round_decimal}
+        }
+        (ProstType::Trunc, _, _) => {
+            gen_float64_or_decimal_expr! {"Trunc", child_expr, return_type, trunc_f64, trunc_decimal}
+        }
+        (ProstType::Sqrt, _, _) => {
+            gen_float64_or_decimal_expr! {"Sqrt", child_expr, return_type, sqrt_f64, sqrt_decimal}
+        }
+        (ProstType::Exp, _, _) => {
+            gen_float64_or_decimal_expr! {"Exp", child_expr, return_type, exp_f64, exp_decimal}
+        }
+        (ProstType::Ln, _, _) => {
+            gen_float64_or_decimal_expr! {"Ln", child_expr, return_type, ln_f64, ln_decimal}
+        }
+        (ProstType::Log10, _, _) => {
+            gen_float64_or_decimal_expr! {"Log10", child_expr, return_type, log10_f64, log10_decimal}
+        }
+        (ProstType::Sign, _, _) => {
+            gen_float64_or_decimal_expr! {"Sign", child_expr, return_type, sign_f64, sign_decimal}
+        }
+        (
+            ProstType::Cbrt
+            | ProstType::Sin
+            | ProstType::Cos
+            | ProstType::Tan
+            | ProstType::Cot
+            | ProstType::Asin
+            | ProstType::Acos
+            | ProstType::Atan
+            | ProstType::Degrees
+            | ProstType::Radians,
+            _,
+            _,
+        ) => {
+            // These are only defined on `double precision`.
+            let func: fn(OrderedF64) -> Result<OrderedF64> = match expr_type {
+                ProstType::Cbrt => cbrt_f64,
+                ProstType::Sin => sin_f64,
+                ProstType::Cos => cos_f64,
+                ProstType::Tan => tan_f64,
+                ProstType::Cot => cot_f64,
+                ProstType::Asin => asin_f64,
+                ProstType::Acos => acos_f64,
+                ProstType::Atan => atan_f64,
+                ProstType::Degrees => degrees_f64,
+                _ => radians_f64,
+            };
+            Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+                child_expr,
+                return_type,
+                func,
+            ))
         }
         (expr, ret, child) => {
             return Err(ErrorCode::NotImplemented(format!(
@@ -334,11 +447,18 @@ pub fn new_unary_expr(
 }
 
 pub fn new_length_default(expr_ia1: BoxedExpression, return_type: DataType) -> BoxedExpression {
-    Box::new(UnaryExpression::<Utf8Array, I64Array, _>::new(
-        expr_ia1,
-        return_type,
-        length_default,
-    ))
+    match expr_ia1.return_type() {
+        DataType::Bytea => Box::new(UnaryExpression::<ByteaArray, I32Array, _>::new(
+            expr_ia1,
+            return_type,
+            bytea_length,
+        )),
+        _ => Box::new(UnaryExpression::<Utf8Array, I64Array, _>::new(
+            expr_ia1,
+            return_type,
+            length_default,
+        )),
+    }
 }
 
 pub fn new_trim_expr(expr_ia1: BoxedExpression, return_type: DataType) -> BoxedExpression {
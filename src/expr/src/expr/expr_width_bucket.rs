@@ -0,0 +1,193 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use risingwave_common::array::{ArrayRef, DataChunk, Row};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_common::{ensure, try_match_expand};
+use risingwave_pb::expr::expr_node::{RexNode, Type};
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, Expression};
+use crate::vector_op::math::{width_bucket_decimal, width_bucket_f64};
+
+/// `width_bucket(operand, low, high, count)`, which takes operand and bounds of either `double
+/// precision` or `numeric`.
+#[derive(Debug)]
+pub struct WidthBucketExpression {
+    return_type: DataType,
+    operand: BoxedExpression,
+    low: BoxedExpression,
+    high: BoxedExpression,
+    count: BoxedExpression,
+}
+
+impl WidthBucketExpression {
+    pub fn new(
+        return_type: DataType,
+        operand: BoxedExpression,
+        low: BoxedExpression,
+        high: BoxedExpression,
+        count: BoxedExpression,
+    ) -> Self {
+        WidthBucketExpression {
+            return_type,
+            operand,
+            low,
+            high,
+            count,
+        }
+    }
+
+    fn eval_datums(operand: Datum, low: Datum, high: Datum, count: Datum) -> Result<Datum> {
+        let bucket = match (operand, low, high, count) {
+            (
+                Some(ScalarImpl::Float64(operand)),
+                Some(ScalarImpl::Float64(low)),
+                Some(ScalarImpl::Float64(high)),
+                Some(ScalarImpl::Int32(count)),
+            ) => width_bucket_f64(operand, low, high, count)?,
+            (
+                Some(ScalarImpl::Decimal(operand)),
+                Some(ScalarImpl::Decimal(low)),
+                Some(ScalarImpl::Decimal(high)),
+                Some(ScalarImpl::Int32(count)),
+            ) => width_bucket_decimal(operand, low, high, count)?,
+            (None, _, _, _) | (_, None, _, _) | (_, _, None, _) | (_, _, _, None) => {
+                return Ok(None)
+            }
+            args => {
+                return Err(
+                    InternalError(format!("width_bucket does not support {:?}", args)).into(),
+                )
+            }
+        };
+        Ok(Some(ScalarImpl::Int32(bucket)))
+    }
+}
+
+impl Expression for WidthBucketExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let operand = self.operand.eval(input)?;
+        let low = self.low.eval(input)?;
+        let high = self.high.eval(input)?;
+        let count = self.count.eval(input)?;
+        let mut builder = self.return_type.create_array_builder(input.cardinality())?;
+
+        for i in 0..operand.len() {
+            let datum = Self::eval_datums(
+                operand.datum_at(i),
+                low.datum_at(i),
+                high.datum_at(i),
+                count.datum_at(i),
+            )?;
+            builder.append_datum(&datum)?;
+        }
+        Ok(Arc::new(builder.finish()?))
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<Datum> {
+        Self::eval_datums(
+            self.operand.eval_row(input)?,
+            self.low.eval_row(input)?,
+            self.high.eval_row(input)?,
+            self.count.eval_row(input)?,
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for WidthBucketExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type()? == Type::WidthBucket);
+
+        let ret_type = DataType::from(prost.get_return_type()?);
+        let func_call_node = try_match_expand!(prost.get_rex_node().unwrap(), RexNode::FuncCall)?;
+        ensure!(func_call_node.children.len() == 4);
+
+        let mut children = func_call_node
+            .children
+            .iter()
+            .map(expr_build_from_prost)
+            .collect::<Result<Vec<_>>>()?
+            .into_iter();
+        let mut child = || children.next().unwrap();
+        Ok(WidthBucketExpression::new(
+            ret_type,
+            child(),
+            child(),
+            child(),
+            child(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::test_prelude::DataChunkTestExt;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::FunctionCall;
+
+    use super::*;
+    use crate::expr::test_utils::make_input_ref;
+
+    #[test]
+    fn test_width_bucket() {
+        let prost = ExprNode {
+            expr_type: Type::WidthBucket as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Int32 as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::FuncCall(FunctionCall {
+                children: vec![
+                    make_input_ref(0, TypeName::Double),
+                    make_input_ref(1, TypeName::Double),
+                    make_input_ref(2, TypeName::Double),
+                    make_input_ref(3, TypeName::Int32),
+                ],
+            })),
+        };
+        let expr = WidthBucketExpression::try_from(&prost).unwrap();
+
+        let data_chunk = DataChunk::from_pretty(
+            "F    F     F     i
+             5.35 0.024 10.06 5
+             -1   0     10    5
+             10   0     10    5
+             .    0     10    5",
+        );
+        let res = expr.eval(&data_chunk).unwrap();
+        assert_eq!(res.datum_at(0), Some(ScalarImpl::Int32(3)));
+        assert_eq!(res.datum_at(1), Some(ScalarImpl::Int32(0)));
+        assert_eq!(res.datum_at(2), Some(ScalarImpl::Int32(6)));
+        assert_eq!(res.datum_at(3), None);
+
+        let data_chunk = DataChunk::from_pretty(
+            "F F F i
+             1 0 10 0",
+        );
+        assert!(expr.eval(&data_chunk).is_err());
+    }
+}
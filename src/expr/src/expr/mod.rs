@@ -16,6 +16,7 @@ mod agg;
 pub mod build_expr_from_prost;
 pub mod data_types;
 mod expr_array;
+mod expr_array_function;
 mod expr_binary_bytes;
 pub mod expr_binary_nonnull;
 pub mod expr_binary_nullable;
@@ -23,12 +24,19 @@ mod expr_case;
 mod expr_coalesce;
 mod expr_concat_ws;
 mod expr_field;
+mod expr_format;
+mod expr_greatest_least;
 mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
+mod expr_jsonb_build_object;
 mod expr_literal;
+mod expr_quaternary_bytes;
+mod expr_random;
+mod expr_regexp;
 mod expr_ternary_bytes;
 pub mod expr_unary;
+mod expr_width_bucket;
 mod template;
 
 use std::convert::TryFrom;
@@ -46,9 +54,15 @@ use risingwave_pb::expr::ExprNode;
 
 use crate::expr::build_expr_from_prost::*;
 use crate::expr::expr_array::ArrayExpression;
+use crate::expr::expr_array_function::ArrayFunctionExpression;
 use crate::expr::expr_coalesce::CoalesceExpression;
 use crate::expr::expr_concat_ws::ConcatWsExpression;
 use crate::expr::expr_field::FieldExpression;
+use crate::expr::expr_format::FormatExpression;
+use crate::expr::expr_greatest_least::GreatestLeastExpression;
+use crate::expr::expr_jsonb_build_object::JsonbBuildObjectExpression;
+use crate::expr::expr_random::{GenRandomUuidExpression, RandomExpression};
+use crate::expr::expr_width_bucket::WidthBucketExpression;
 
 pub type ExpressionRef = Arc<dyn Expression>;
 
@@ -79,26 +93,39 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
     use risingwave_pb::expr::expr_node::Type::*;
 
     match prost.get_expr_type()? {
-        Cast | Upper | Lower | Md5 | Not | IsTrue | IsNotTrue | IsFalse | IsNotFalse | IsNull
-        | IsNotNull | Neg | Ascii | Abs | Ceil | Floor | Round | BitwiseNot => {
-            build_unary_expr_prost(prost)
-        }
+        Cast | Upper | Lower | Md5 | Sha256 | Not | IsTrue | IsNotTrue | IsFalse | IsNotFalse
+        | IsNull | IsNotNull | Neg | Ascii | Abs | Ceil | Floor | Round | BitwiseNot
+        | JsonbTypeof | ToTimestamp | Sqrt | Cbrt | Exp | Ln | Log10 | Sign | Trunc | Sin | Cos
+        | Tan | Cot | Asin | Acos | Atan | Degrees | Radians | Reverse | Initcap | QuoteIdent
+        | QuoteLiteral | Chr | ToHex => build_unary_expr_prost(prost),
         Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual | Add
         | Subtract | Multiply | Divide | Modulus | Extract | RoundDigit | TumbleStart
-        | Position | BitwiseShiftLeft | BitwiseShiftRight | BitwiseAnd | BitwiseOr | BitwiseXor => {
-            build_binary_expr_prost(prost)
-        }
-        And | Or | IsDistinctFrom | ArrayAccess => build_nullable_binary_expr_prost(prost),
+        | Position | BitwiseShiftLeft | BitwiseShiftRight | BitwiseAnd | BitwiseOr | BitwiseXor
+        | JsonbContains | JsonbExists | Encode | Decode | ToDate | Age | Pow | Atan2
+        | TruncDigit | StartsWith => build_binary_expr_prost(prost),
+        ConcatOp | Left | Right | Repeat => build_binary_bytes_expr_prost(prost),
+        ToTimestamp1 | MakeDate | Lpad | Rpad => build_ternary_expr_prost(prost),
+        Overlay => build_overlay_expr(prost),
+        DateTrunc => build_date_trunc_expr(prost),
+        AtTimeZone => build_at_time_zone_expr(prost),
+        And | Or | IsDistinctFrom | ArrayAccess | JsonbAccessInner | JsonbAccessStr
+        | JsonbAccessPath | JsonbAccessPathStr => build_nullable_binary_expr_prost(prost),
         ToChar => build_to_char_expr(prost),
         Coalesce => CoalesceExpression::try_from(prost).map(Expression::boxed),
+        Greatest | Least => GreatestLeastExpression::try_from(prost).map(Expression::boxed),
+        WidthBucket => WidthBucketExpression::try_from(prost).map(Expression::boxed),
+        Random => Ok(RandomExpression.boxed()),
+        GenRandomUuid => Ok(GenRandomUuidExpression.boxed()),
         Substr => build_substr_expr(prost),
         Length => build_length_expr(prost),
         Replace => build_replace_expr(prost),
-        Like => build_like_expr(prost),
+        Like | Ilike => build_like_expr(prost),
         Trim => build_trim_expr(prost),
         Ltrim => build_ltrim_expr(prost),
         Rtrim => build_rtrim_expr(prost),
         ConcatWs => ConcatWsExpression::try_from(prost).map(Expression::boxed),
+        Format => FormatExpression::try_from(prost).map(Expression::boxed),
+        JsonbBuildObject => JsonbBuildObjectExpression::try_from(prost).map(Expression::boxed),
         SplitPart => build_split_part_expr(prost),
         ConstantValue => LiteralExpression::try_from(prost).map(Expression::boxed),
         InputRef => InputRefExpression::try_from(prost).map(Expression::boxed),
@@ -107,6 +134,13 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         In => build_in_expr(prost),
         Field => FieldExpression::try_from(prost).map(Expression::boxed),
         Array => ArrayExpression::try_from(prost).map(Expression::boxed),
+        ArrayCat | ArrayAppend | ArrayPrepend | ArrayLength | Cardinality | ArrayPosition
+        | ArrayToString | ArrayContains | ArrayContained | ArrayOverlap | ArraySlice => {
+            ArrayFunctionExpression::try_from(prost).map(Expression::boxed)
+        }
+        RegexpLike | SimilarTo | RegexpMatch | RegexpReplace | RegexpSplitToArray => {
+            build_regexp_expr(prost)
+        }
         _ => Err(InternalError(format!(
             "Unsupported expression type: {:?}",
             prost.get_expr_type()
@@ -33,4 +33,5 @@
 #![feature(assert_matches)]
 
 pub mod expr;
+pub mod table_function;
 pub mod vector_op;
@@ -0,0 +1,154 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::ErrorCode::{InternalError, InvalidParameterValue};
+use risingwave_common::error::Result;
+use risingwave_common::types::{CheckedAdd, DataType, Scalar, ScalarImpl};
+
+use super::{DatumIter, TableFunction};
+use crate::expr::BoxedExpression;
+
+/// `generate_series(start, stop, step)`, which generates the values from `start` to `stop` by
+/// `step`, over integers, or over timestamps with an interval step. A negative step counts down.
+#[derive(Debug)]
+pub struct GenerateSeries {
+    start: BoxedExpression,
+    stop: BoxedExpression,
+    step: BoxedExpression,
+}
+
+impl GenerateSeries {
+    pub fn new(start: BoxedExpression, stop: BoxedExpression, step: BoxedExpression) -> Self {
+        Self { start, stop, step }
+    }
+}
+
+impl TableFunction for GenerateSeries {
+    fn return_type(&self) -> DataType {
+        self.start.return_type()
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<DatumIter> {
+        let start = self.start.eval_row(input)?;
+        let stop = self.stop.eval_row(input)?;
+        let step = self.step.eval_row(input)?;
+        match (start, stop, step) {
+            (
+                Some(ScalarImpl::Int32(start)),
+                Some(ScalarImpl::Int32(stop)),
+                Some(ScalarImpl::Int32(step)),
+            ) => series(start, stop, step),
+            (
+                Some(ScalarImpl::NaiveDateTime(start)),
+                Some(ScalarImpl::NaiveDateTime(stop)),
+                Some(ScalarImpl::Interval(step)),
+            ) => series(start, stop, step),
+            (Some(_), Some(_), Some(_)) => Err(InternalError(format!(
+                "generate_series does not support {:?}",
+                self.start.return_type()
+            ))
+            .into()),
+            _ => Ok(Box::new(std::iter::empty())),
+        }
+    }
+}
+
+fn series<T, S>(start: T, stop: T, step: S) -> Result<DatumIter>
+where
+    T: Scalar + PartialOrd + CheckedAdd<S>,
+    S: Copy + Default + PartialOrd + Send + 'static,
+{
+    if step == S::default() {
+        return Err(InvalidParameterValue("step size cannot equal zero".to_string()).into());
+    }
+    let ascending = step > S::default();
+    let mut next = Some(start);
+    Ok(Box::new(std::iter::from_fn(move || {
+        let current = next.take()?;
+        if (ascending && current > stop) || (!ascending && current < stop) {
+            return None;
+        }
+        // An overflow can only happen past `stop`, where the series ends anyway.
+        next = current.checked_add(step).ok();
+        Some(Ok(Some(current.to_scalar_value())))
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::{Datum, IntervalUnit};
+
+    use super::*;
+    use crate::expr::{Expression, LiteralExpression};
+    use crate::vector_op::cast::str_to_timestamp;
+
+    fn literal(data_type: DataType, value: impl Into<ScalarImpl>) -> BoxedExpression {
+        LiteralExpression::new(data_type, Some(value.into())).boxed()
+    }
+
+    fn eval(function: &GenerateSeries) -> Vec<Datum> {
+        function
+            .eval_row(&Row::default())
+            .unwrap()
+            .collect::<Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_generate_i32_series() {
+        let series = |start: i32, stop: i32, step: i32| {
+            eval(&GenerateSeries::new(
+                literal(DataType::Int32, start),
+                literal(DataType::Int32, stop),
+                literal(DataType::Int32, step),
+            ))
+            .into_iter()
+            .map(|datum| *datum.unwrap().as_int32())
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(series(2, 4, 1), vec![2, 3, 4]);
+        assert_eq!(series(0, 9, 4), vec![0, 4, 8]);
+        assert_eq!(series(3, 1, -1), vec![3, 2, 1]);
+        assert_eq!(series(1, 0, 1), Vec::<i32>::new());
+        assert_eq!(
+            series(i32::MAX - 1, i32::MAX, 1),
+            vec![i32::MAX - 1, i32::MAX]
+        );
+
+        let zero_step = GenerateSeries::new(
+            literal(DataType::Int32, 1),
+            literal(DataType::Int32, 2),
+            literal(DataType::Int32, 0),
+        );
+        assert!(zero_step.eval_row(&Row::default()).is_err());
+    }
+
+    #[test]
+    fn test_generate_time_series() {
+        let start = str_to_timestamp("2008-03-01 00:00:00").unwrap();
+        let stop = str_to_timestamp("2008-03-09 00:00:00").unwrap();
+        let count = |step: IntervalUnit| {
+            eval(&GenerateSeries::new(
+                literal(DataType::Timestamp, start),
+                literal(DataType::Timestamp, stop),
+                literal(DataType::Interval, step),
+            ))
+            .len()
+        };
+        assert_eq!(count(IntervalUnit::from_minutes(1)), 60 * 24 * 8 + 1);
+        assert_eq!(count(IntervalUnit::from_minutes(60)), 24 * 8 + 1);
+        assert_eq!(count(IntervalUnit::from_days(1)), 8 + 1);
+    }
+}
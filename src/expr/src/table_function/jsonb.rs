@@ -0,0 +1,147 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::array::{Row, StructValue};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, JsonbVal, Scalar, ScalarImpl, ScalarRef};
+
+use super::{DatumIter, TableFunction};
+use crate::expr::BoxedExpression;
+
+/// `jsonb_array_elements(jsonb)`, which expands a JSON array into the set of its elements.
+#[derive(Debug)]
+pub struct JsonbArrayElements {
+    jsonb: BoxedExpression,
+}
+
+impl JsonbArrayElements {
+    pub fn new(jsonb: BoxedExpression) -> Self {
+        Self { jsonb }
+    }
+}
+
+impl TableFunction for JsonbArrayElements {
+    fn return_type(&self) -> DataType {
+        DataType::Jsonb
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<DatumIter> {
+        let elements = match self.jsonb.eval_row(input)? {
+            Some(ScalarImpl::Jsonb(value)) => value
+                .as_scalar_ref()
+                .array_elements()
+                .ok_or_else(|| {
+                    InvalidInputSyntax("cannot extract elements from a non-array".to_string())
+                })?
+                .map(|element| Ok(Some(ScalarImpl::Jsonb(element.to_owned_scalar()))))
+                .collect_vec(),
+            _ => vec![],
+        };
+        Ok(Box::new(elements.into_iter()))
+    }
+}
+
+/// `jsonb_each(jsonb)`, which expands a JSON object into the set of its `(key, value)` pairs, as
+/// structs of `varchar` and `jsonb`.
+#[derive(Debug)]
+pub struct JsonbEach {
+    jsonb: BoxedExpression,
+    return_type: DataType,
+}
+
+impl JsonbEach {
+    pub fn new(jsonb: BoxedExpression, return_type: DataType) -> Self {
+        Self { jsonb, return_type }
+    }
+}
+
+impl TableFunction for JsonbEach {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<DatumIter> {
+        let pairs = match self.jsonb.eval_row(input)? {
+            Some(ScalarImpl::Jsonb(value)) => value
+                .value()
+                .as_object()
+                .ok_or_else(|| {
+                    InvalidInputSyntax("cannot call jsonb_each on a non-object".to_string())
+                })?
+                .iter()
+                .map(|(key, value)| {
+                    let fields = vec![
+                        Some(ScalarImpl::Utf8(key.clone())),
+                        Some(ScalarImpl::Jsonb(JsonbVal::from_value(value.clone()))),
+                    ];
+                    Ok(Some(ScalarImpl::Struct(StructValue::new(fields))))
+                })
+                .collect_vec(),
+            _ => vec![],
+        };
+        Ok(Box::new(pairs.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::expr::{Expression, LiteralExpression};
+
+    fn jsonb(text: &str) -> BoxedExpression {
+        let value = JsonbVal::from_str(text).unwrap();
+        LiteralExpression::new(DataType::Jsonb, Some(ScalarImpl::Jsonb(value))).boxed()
+    }
+
+    #[test]
+    fn test_jsonb_each() {
+        let return_type = DataType::Struct {
+            fields: vec![DataType::Varchar, DataType::Jsonb].into(),
+        };
+        let each = JsonbEach::new(jsonb(r#"{"b": [1], "a": null}"#), return_type.clone());
+        let pairs = each
+            .eval_row(&Row::default())
+            .unwrap()
+            .map(|datum| match datum.unwrap() {
+                Some(ScalarImpl::Struct(pair)) => match pair.fields() {
+                    [Some(ScalarImpl::Utf8(key)), Some(ScalarImpl::Jsonb(value))] => {
+                        (key.clone(), value.as_scalar_ref().to_compact_string())
+                    }
+                    fields => panic!("unexpected fields {:?}", fields),
+                },
+                datum => panic!("unexpected datum {:?}", datum),
+            })
+            .collect_vec();
+        assert_eq!(
+            pairs,
+            vec![
+                ("a".to_string(), "null".to_string()),
+                ("b".to_string(), "[1]".to_string())
+            ]
+        );
+
+        let non_object = JsonbEach::new(jsonb("[1]"), return_type);
+        assert!(non_object.eval_row(&Row::default()).is_err());
+
+        let elements = JsonbArrayElements::new(jsonb("[1, {}]"))
+            .eval_row(&Row::default())
+            .unwrap()
+            .count();
+        assert_eq!(elements, 2);
+    }
+}
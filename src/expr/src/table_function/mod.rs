@@ -0,0 +1,99 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Table functions, or set-returning functions, which expand a row into a set of values. They are
+//! evaluated either as a relation in the FROM clause by the `TableFunctionExecutor`, or in the
+//! select list of a `ProjectSet`.
+
+mod generate_series;
+mod jsonb;
+mod project_set;
+mod regexp_matches;
+mod unnest;
+
+pub use generate_series::GenerateSeries;
+pub use jsonb::{JsonbArrayElements, JsonbEach};
+pub use project_set::{project_set, ProjectSetSelectItem};
+pub use regexp_matches::RegexpMatches;
+use risingwave_common::array::Row;
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum};
+use risingwave_pb::expr::table_function::Type;
+use risingwave_pb::expr::TableFunction as TableFunctionProst;
+pub use unnest::Unnest;
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+
+/// A lazily produced set of values, as the set can be large, e.g. for `generate_series`.
+pub type DatumIter = Box<dyn Iterator<Item = Result<Datum>> + Send>;
+
+/// Instance of a table function.
+pub trait TableFunction: std::fmt::Debug + Sync + Send {
+    /// The type of the values in the set.
+    fn return_type(&self) -> DataType;
+
+    /// Evaluate the arguments on a row, and expand them into a set of values. A null argument
+    /// makes an empty set.
+    fn eval_row(&self, input: &Row) -> Result<DatumIter>;
+
+    fn boxed(self) -> BoxedTableFunction
+    where
+        Self: Sized + Send + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+pub type BoxedTableFunction = Box<dyn TableFunction>;
+
+pub fn build_from_prost(prost: &TableFunctionProst) -> Result<BoxedTableFunction> {
+    let function_type = prost.get_function_type()?;
+    let return_type = DataType::from(prost.get_return_type()?);
+    let arity_ok = match function_type {
+        Type::GenerateSeries => prost.args.len() == 3,
+        Type::RegexpMatches => (2..=3).contains(&prost.args.len()),
+        _ => prost.args.len() == 1,
+    };
+    if !arity_ok {
+        return Err(InternalError(format!(
+            "{:?} does not take {} arguments",
+            function_type,
+            prost.args.len()
+        ))
+        .into());
+    }
+    let mut args = prost
+        .args
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<BoxedExpression>>>()?
+        .into_iter();
+    let mut arg = || args.next().unwrap();
+
+    let table_function = match function_type {
+        Type::GenerateSeries => GenerateSeries::new(arg(), arg(), arg()).boxed(),
+        Type::Unnest => Unnest::new(arg(), return_type).boxed(),
+        Type::RegexpMatches => {
+            let (text, pattern) = (arg(), arg());
+            RegexpMatches::new(text, pattern, args.next()).boxed()
+        }
+        Type::JsonbArrayElements => JsonbArrayElements::new(arg()).boxed(),
+        Type::JsonbEach => JsonbEach::new(arg(), return_type).boxed(),
+        Type::Unspecified => {
+            return Err(InternalError("unspecified table function".to_string()).into())
+        }
+    };
+    Ok(table_function)
+}
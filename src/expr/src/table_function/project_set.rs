@@ -0,0 +1,189 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_pb::expr::project_set_select_item::SelectItem;
+use risingwave_pb::expr::ProjectSetSelectItem as ProjectSetSelectItemProst;
+
+use super::{build_from_prost, BoxedTableFunction, DatumIter};
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+
+/// An item of the select list of a `ProjectSet`.
+#[derive(Debug)]
+pub enum ProjectSetSelectItem {
+    /// An expression, whose value is repeated in all the rows expanded from an input row.
+    Expr(BoxedExpression),
+    /// A table function, whose set expands the input row.
+    TableFunction(BoxedTableFunction),
+}
+
+impl ProjectSetSelectItem {
+    pub fn from_prost(prost: &ProjectSetSelectItemProst) -> Result<Self> {
+        match prost.get_select_item()? {
+            SelectItem::Expr(expr) => Ok(Self::Expr(expr_build_from_prost(expr)?)),
+            SelectItem::TableFunction(table_function) => {
+                Ok(Self::TableFunction(build_from_prost(table_function)?))
+            }
+        }
+    }
+
+    pub fn return_type(&self) -> DataType {
+        match self {
+            Self::Expr(expr) => expr.return_type(),
+            Self::TableFunction(table_function) => table_function.return_type(),
+        }
+    }
+}
+
+/// Expands the visible rows of `input` with `select_list`, returning each output row along with
+/// the index of the input row it is expanded from.
+///
+/// An input row is expanded into as many rows as the largest set of the table functions, where
+/// the smaller sets are padded with nulls, so it is dropped if all the sets are empty. The first
+/// column of an output row is `projected_row_id`, the index of the row among those expanded from
+/// the same input row.
+pub fn project_set(
+    select_list: &[ProjectSetSelectItem],
+    input: &DataChunk,
+) -> Result<Vec<(usize, Row)>> {
+    let arrays = select_list
+        .iter()
+        .map(|item| match item {
+            ProjectSetSelectItem::Expr(expr) => expr.eval(input).map(Some),
+            ProjectSetSelectItem::TableFunction(_) => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut rows = vec![];
+    for row_idx in 0..input.capacity() {
+        let (row, visible) = input.row_at(row_idx)?;
+        if !visible {
+            continue;
+        }
+        let row = row.to_owned_row();
+        let mut sets = select_list
+            .iter()
+            .map(|item| match item {
+                ProjectSetSelectItem::TableFunction(table_function) => {
+                    table_function.eval_row(&row).map(Some)
+                }
+                ProjectSetSelectItem::Expr(_) => Ok(None),
+            })
+            .collect::<Result<Vec<Option<DatumIter>>>>()?;
+        for projected_row_id in 0.. {
+            let mut exhausted = true;
+            let mut values = vec![Some(ScalarImpl::Int64(projected_row_id))];
+            for (set, array) in sets.iter_mut().zip_eq(&arrays) {
+                let datum: Datum = match (set, array) {
+                    (Some(set), _) => match set.next().transpose()? {
+                        Some(datum) => {
+                            exhausted = false;
+                            datum
+                        }
+                        None => None,
+                    },
+                    (None, Some(array)) => array.datum_at(row_idx),
+                    (None, None) => unreachable!(),
+                };
+                values.push(datum);
+            }
+            if exhausted {
+                break;
+            }
+            rows.push((row_idx, Row::new(values)));
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+    use risingwave_common::array::ListValue;
+
+    use super::*;
+    use crate::expr::{Expression, InputRefExpression};
+    use crate::table_function::{TableFunction, Unnest};
+
+    fn column(data_type: &DataType, datums: &[Datum]) -> Column {
+        let mut builder = data_type.create_array_builder(datums.len()).unwrap();
+        for datum in datums {
+            builder.append_datum(datum).unwrap();
+        }
+        Column::new(Arc::new(builder.finish().unwrap()))
+    }
+
+    #[test]
+    fn test_project_set() {
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let int_list = |values: &[i32]| {
+            Some(ScalarImpl::List(ListValue::new(
+                values.iter().map(|v| Some(ScalarImpl::Int32(*v))).collect(),
+            )))
+        };
+        let chunk = DataChunk::new(
+            vec![
+                column(
+                    &DataType::Int32,
+                    &[Some(7.into()), Some(8.into()), Some(9.into())],
+                ),
+                column(&list_type, &[int_list(&[1, 2, 3]), int_list(&[]), None]),
+                column(&list_type, &[int_list(&[4]), int_list(&[]), int_list(&[5])]),
+            ],
+            3,
+        );
+
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(InputRefExpression::new(DataType::Int32, 0).boxed()),
+            ProjectSetSelectItem::TableFunction(
+                Unnest::new(
+                    InputRefExpression::new(list_type.clone(), 1).boxed(),
+                    DataType::Int32,
+                )
+                .boxed(),
+            ),
+            ProjectSetSelectItem::TableFunction(
+                Unnest::new(
+                    InputRefExpression::new(list_type, 2).boxed(),
+                    DataType::Int32,
+                )
+                .boxed(),
+            ),
+        ];
+        let rows = project_set(&select_list, &chunk).unwrap();
+        let row = |id: i64, values: [Option<i32>; 3]| {
+            Row::new(
+                std::iter::once(Some(ScalarImpl::Int64(id)))
+                    .chain(values.into_iter().map(|v| v.map(ScalarImpl::Int32)))
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            rows,
+            vec![
+                (0, row(0, [Some(7), Some(1), Some(4)])),
+                (0, row(1, [Some(7), Some(2), None])),
+                (0, row(2, [Some(7), Some(3), None])),
+                (2, row(0, [Some(9), None, Some(5)])),
+            ]
+        );
+    }
+}
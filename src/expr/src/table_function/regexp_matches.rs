@@ -0,0 +1,73 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::{DatumIter, TableFunction};
+use crate::expr::BoxedExpression;
+use crate::vector_op::regexp::{regexp_matches, RegexpContext};
+
+/// `regexp_matches(string, pattern [, flags])`, which returns the captured substrings of the first
+/// match, or of every match if the `g` flag is given, as a set of `varchar[]`.
+#[derive(Debug)]
+pub struct RegexpMatches {
+    text: BoxedExpression,
+    pattern: BoxedExpression,
+    flags: Option<BoxedExpression>,
+}
+
+impl RegexpMatches {
+    pub fn new(
+        text: BoxedExpression,
+        pattern: BoxedExpression,
+        flags: Option<BoxedExpression>,
+    ) -> Self {
+        Self {
+            text,
+            pattern,
+            flags,
+        }
+    }
+}
+
+impl TableFunction for RegexpMatches {
+    fn return_type(&self) -> DataType {
+        DataType::List {
+            datatype: Box::new(DataType::Varchar),
+        }
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<DatumIter> {
+        let text = self.text.eval_row(input)?;
+        let pattern = self.pattern.eval_row(input)?;
+        let flags = match &self.flags {
+            Some(flags) => flags.eval_row(input)?,
+            None => Some(ScalarImpl::Utf8(String::new())),
+        };
+        let matches = match (text, pattern, flags) {
+            (Some(text), Some(pattern), Some(flags)) => {
+                let ctx = RegexpContext::new(pattern.as_utf8(), flags.as_utf8())?;
+                regexp_matches(text.as_utf8(), &ctx)
+                    .into_iter()
+                    .map(|matches| Ok(Some(ScalarImpl::List(matches))))
+                    .collect_vec()
+            }
+            _ => vec![],
+        };
+        Ok(Box::new(matches.into_iter()))
+    }
+}
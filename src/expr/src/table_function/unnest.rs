@@ -0,0 +1,49 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::{DatumIter, TableFunction};
+use crate::expr::BoxedExpression;
+use crate::vector_op::array::unnest;
+
+/// `unnest(array)`, which expands an array into the set of its elements.
+#[derive(Debug)]
+pub struct Unnest {
+    array: BoxedExpression,
+    return_type: DataType,
+}
+
+impl Unnest {
+    pub fn new(array: BoxedExpression, return_type: DataType) -> Self {
+        Self { array, return_type }
+    }
+}
+
+impl TableFunction for Unnest {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval_row(&self, input: &Row) -> Result<DatumIter> {
+        let elements = match self.array.eval_row(input)? {
+            Some(ScalarImpl::List(array)) => unnest(&array).cloned().map(Ok).collect_vec(),
+            _ => vec![],
+        };
+        Ok(Box::new(elements.into_iter()))
+    }
+}
@@ -0,0 +1,96 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, NaiveDate, Timelike};
+use risingwave_common::error::Result;
+use risingwave_common::types::{IntervalUnit, NaiveDateTimeWrapper};
+
+const MS_PER_DAY: i64 = 24 * 3600 * 1000;
+
+fn days_in_month(year: i32, month: u32) -> i64 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    NaiveDate::from_ymd(next_year, next_month, 1)
+        .signed_duration_since(NaiveDate::from_ymd(year, month, 1))
+        .num_days()
+}
+
+/// `age(a, b)` subtracts the timestamps symbolically, giving years and months rather than only
+/// days, e.g. `age('2022-03-01', '2022-01-31')` is `1 mon 1 day`.
+#[inline(always)]
+pub fn age(a: NaiveDateTimeWrapper, b: NaiveDateTimeWrapper) -> Result<IntervalUnit> {
+    if a < b {
+        return age(b, a).map(|interval| interval.negative());
+    }
+    let (a, b) = (a.0, b.0);
+    let time_ms = |t: &chrono::NaiveDateTime| {
+        t.num_seconds_from_midnight() as i64 * 1000 + (t.nanosecond() / 1_000_000) as i64
+    };
+    let mut ms = time_ms(&a) - time_ms(&b);
+    let mut days = a.day() as i64 - b.day() as i64;
+    let mut months = (a.year() as i64 - b.year() as i64) * 12 + a.month() as i64 - b.month() as i64;
+    if ms < 0 {
+        ms += MS_PER_DAY;
+        days -= 1;
+    }
+    // As in PG, a day is borrowed from the month of the earlier timestamp.
+    if days < 0 {
+        days += days_in_month(b.year(), b.month());
+        months -= 1;
+    }
+    Ok(IntervalUnit::new(months as i32, days as i32, ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    fn timestamp(s: &str) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+    }
+
+    #[test]
+    fn test_age() {
+        assert_eq!(
+            age(
+                timestamp("2001-04-10 00:00:00"),
+                timestamp("1957-06-13 00:00:00")
+            )
+            .unwrap(),
+            // 43 years 9 mons 27 days
+            IntervalUnit::new(43 * 12 + 9, 27, 0)
+        );
+        assert_eq!(
+            age(
+                timestamp("2022-03-01 00:00:00"),
+                timestamp("2022-01-31 12:00:00")
+            )
+            .unwrap(),
+            IntervalUnit::new(1, 0, 12 * 3600 * 1000)
+        );
+        assert_eq!(
+            age(
+                timestamp("2022-01-31 12:00:00"),
+                timestamp("2022-03-01 00:00:00")
+            )
+            .unwrap(),
+            IntervalUnit::new(-1, 0, -12 * 3600 * 1000)
+        );
+    }
+}
@@ -179,6 +179,7 @@ pub fn create_agg_state_unary(
         (Count, count, decimal, int64, Some(0)),
         (Count, count_str, varchar, int64, Some(0)),
         (Count, count, boolean, int64, Some(0)),
+        (Count, count, uuid, int64, Some(0)),
         (Sum, sum, int16, int64, None),
         (Sum, sum, int32, int64, None),
         (Sum, sum, int64, decimal, None),
@@ -16,8 +16,10 @@ use std::any::type_name;
 use std::convert::TryInto;
 use std::fmt::Debug;
 
-use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Signed};
-use risingwave_common::error::ErrorCode::{InternalError, NumericValueOutOfRange};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Signed, Zero,
+};
+use risingwave_common::error::ErrorCode::{DivisionByZero, InternalError, NumericValueOutOfRange};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{
     CheckedAdd as NaiveDateTimeCheckedAdd, Decimal, IntervalUnit, NaiveDateTimeWrapper,
@@ -70,11 +72,10 @@ pub fn general_div<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
 where
     T1: TryInto<T3> + Debug,
     T2: TryInto<T3> + Debug,
-    T3: CheckedDiv,
+    T3: CheckedDiv + Zero,
 {
     general_atm(l, r, |a, b| {
-        a.checked_div(&b)
-            .ok_or_else(|| RwError::from(NumericValueOutOfRange))
+        a.checked_div(&b).ok_or_else(|| division_error(&b))
     })
 }
 
@@ -83,14 +84,21 @@ pub fn general_mod<T1, T2, T3>(l: T1, r: T2) -> Result<T3>
 where
     T1: TryInto<T3> + Debug,
     T2: TryInto<T3> + Debug,
-    T3: CheckedRem,
+    T3: CheckedRem + Zero,
 {
     general_atm(l, r, |a, b| {
-        a.checked_rem(&b)
-            .ok_or_else(|| RwError::from(NumericValueOutOfRange))
+        a.checked_rem(&b).ok_or_else(|| division_error(&b))
     })
 }
 
+/// A failed division is either by zero or out of range.
+fn division_error<T: Zero>(divisor: &T) -> RwError {
+    match divisor.is_zero() {
+        true => DivisionByZero.into(),
+        false => NumericValueOutOfRange.into(),
+    }
+}
+
 #[inline(always)]
 pub fn general_neg<T1: CheckedNeg>(expr: T1) -> Result<T1> {
     expr.checked_neg()
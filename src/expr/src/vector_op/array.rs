@@ -0,0 +1,235 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions on arrays. An array of arrays plays the role of a multidimensional array of PG, so
+//! the dimensions of `array_length` and `cardinality` are the levels of nesting.
+
+use itertools::Itertools;
+use risingwave_common::array::ListValue;
+use risingwave_common::types::{Datum, ScalarImpl};
+
+/// `array_cat(left, right)`, where a null array is treated as empty unless both are null.
+pub fn array_cat(left: Option<&ListValue>, right: Option<&ListValue>) -> Option<ListValue> {
+    match (left, right) {
+        (None, None) => None,
+        (Some(array), None) | (None, Some(array)) => Some(array.clone()),
+        (Some(left), Some(right)) => Some(ListValue::new(
+            left.values()
+                .iter()
+                .chain(right.values())
+                .cloned()
+                .collect(),
+        )),
+    }
+}
+
+/// `array_append(array, elem)`, where a null array is treated as empty.
+pub fn array_append(array: Option<&ListValue>, elem: Datum) -> ListValue {
+    let mut values = array
+        .map(|array| array.values().to_vec())
+        .unwrap_or_default();
+    values.push(elem);
+    ListValue::new(values)
+}
+
+/// `array_prepend(elem, array)`, where a null array is treated as empty.
+pub fn array_prepend(elem: Datum, array: Option<&ListValue>) -> ListValue {
+    let values = std::iter::once(elem)
+        .chain(
+            array
+                .into_iter()
+                .flat_map(|array| array.values().iter().cloned()),
+        )
+        .collect();
+    ListValue::new(values)
+}
+
+/// `array_length(array, dimension)`, which is null for an empty array or a dimension that does not
+/// exist. The length of an inner dimension is that of the first element.
+pub fn array_length(array: &ListValue, dimension: i32) -> Option<i32> {
+    if dimension < 1 || array.values().is_empty() {
+        return None;
+    }
+    if dimension == 1 {
+        return Some(array.values().len() as i32);
+    }
+    match &array.values()[0] {
+        Some(ScalarImpl::List(inner)) => array_length(inner, dimension - 1),
+        _ => None,
+    }
+}
+
+/// `cardinality(array)` is the total number of elements in all dimensions.
+pub fn cardinality(array: &ListValue) -> i32 {
+    array
+        .values()
+        .iter()
+        .map(|value| match value {
+            Some(ScalarImpl::List(inner)) => cardinality(inner),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// `array_position(array, elem)` is the 1-based index of the first element not distinct from
+/// `elem`, so a null can be found as well.
+pub fn array_position(array: &ListValue, elem: &Datum) -> Option<i32> {
+    array
+        .values()
+        .iter()
+        .position(|value| value == elem)
+        .map(|index| index as i32 + 1)
+}
+
+/// `array_to_string(array, delimiter [, null_string])` joins the text of the elements. Nulls are
+/// skipped unless `null_string` is given.
+pub fn array_to_string(array: &ListValue, delimiter: &str, null_string: Option<&str>) -> String {
+    fn flatten<'a>(array: &'a ListValue, out: &mut Vec<&'a Datum>) {
+        for value in array.values() {
+            match value {
+                Some(ScalarImpl::List(inner)) => flatten(inner, out),
+                _ => out.push(value),
+            }
+        }
+    }
+    let mut values = vec![];
+    flatten(array, &mut values);
+    values
+        .into_iter()
+        .filter_map(|value| match value {
+            Some(scalar) => Some(scalar.to_string()),
+            None => null_string.map(str::to_string),
+        })
+        .join(delimiter)
+}
+
+/// `left @> right`, i.e. every element of `right` is in `left`. A null element is never found,
+/// as in PG.
+pub fn array_contains(left: &ListValue, right: &ListValue) -> bool {
+    right
+        .values()
+        .iter()
+        .all(|value| value.is_some() && left.values().contains(value))
+}
+
+/// `left && right`, i.e. the arrays have a non-null element in common.
+pub fn array_overlap(left: &ListValue, right: &ListValue) -> bool {
+    right
+        .values()
+        .iter()
+        .any(|value| value.is_some() && left.values().contains(value))
+}
+
+/// `array[lower:upper]` with 1-based inclusive bounds, where a missing bound is the first or the
+/// last element. Bounds out of range are clamped.
+pub fn array_slice(array: &ListValue, lower: Option<i32>, upper: Option<i32>) -> ListValue {
+    let len = array.values().len() as i64;
+    let lower = lower.map_or(1, i64::from).max(1);
+    let upper = upper.map_or(len, i64::from).min(len);
+    if lower > upper {
+        return ListValue::new(vec![]);
+    }
+    ListValue::new(array.values()[(lower - 1) as usize..upper as usize].to_vec())
+}
+
+/// `unnest(array)` expands the elements of the outermost dimension into rows.
+pub fn unnest(array: &ListValue) -> impl Iterator<Item = &Datum> {
+    array.values().iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_array(values: &[Option<i32>]) -> ListValue {
+        ListValue::new(values.iter().map(|v| v.map(ScalarImpl::Int32)).collect())
+    }
+
+    #[test]
+    fn test_array_cat_append_prepend() {
+        let a = int_array(&[Some(1), Some(2)]);
+        let b = int_array(&[Some(3)]);
+        assert_eq!(
+            array_cat(Some(&a), Some(&b)),
+            Some(int_array(&[Some(1), Some(2), Some(3)]))
+        );
+        assert_eq!(array_cat(None, Some(&b)), Some(b.clone()));
+        assert_eq!(array_cat(None, None), None);
+        assert_eq!(
+            array_append(Some(&a), None),
+            int_array(&[Some(1), Some(2), None])
+        );
+        assert_eq!(array_append(None, Some(3.into())), b);
+        assert_eq!(
+            array_prepend(Some(0.into()), Some(&a)),
+            int_array(&[Some(0), Some(1), Some(2)])
+        );
+    }
+
+    #[test]
+    fn test_array_length_and_cardinality() {
+        let a = int_array(&[Some(1), None, Some(3)]);
+        assert_eq!(array_length(&a, 1), Some(3));
+        assert_eq!(array_length(&a, 2), None);
+        assert_eq!(array_length(&int_array(&[]), 1), None);
+        assert_eq!(cardinality(&a), 3);
+
+        let nested = ListValue::new(vec![
+            Some(ScalarImpl::List(int_array(&[Some(1), Some(2)]))),
+            Some(ScalarImpl::List(int_array(&[Some(3), Some(4)]))),
+        ]);
+        assert_eq!(array_length(&nested, 2), Some(2));
+        assert_eq!(cardinality(&nested), 4);
+    }
+
+    #[test]
+    fn test_array_position_and_to_string() {
+        let a = int_array(&[Some(1), None, Some(3), Some(1)]);
+        assert_eq!(array_position(&a, &Some(1.into())), Some(1));
+        assert_eq!(array_position(&a, &None), Some(2));
+        assert_eq!(array_position(&a, &Some(2.into())), None);
+        assert_eq!(array_to_string(&a, ",", None), "1,3,1");
+        assert_eq!(array_to_string(&a, ", ", Some("*")), "1, *, 3, 1");
+    }
+
+    #[test]
+    fn test_array_contains_and_overlap() {
+        let a = int_array(&[Some(1), Some(2), None]);
+        assert!(array_contains(&a, &int_array(&[Some(2), Some(1)])));
+        assert!(array_contains(&a, &int_array(&[])));
+        assert!(!array_contains(&a, &int_array(&[None])));
+        assert!(!array_contains(&a, &int_array(&[Some(3)])));
+        assert!(array_overlap(&a, &int_array(&[Some(3), Some(2)])));
+        assert!(!array_overlap(&a, &int_array(&[None, Some(4)])));
+    }
+
+    #[test]
+    fn test_array_slice() {
+        let a = int_array(&[Some(1), Some(2), Some(3), Some(4)]);
+        assert_eq!(
+            array_slice(&a, Some(2), Some(3)),
+            int_array(&[Some(2), Some(3)])
+        );
+        assert_eq!(
+            array_slice(&a, None, Some(2)),
+            int_array(&[Some(1), Some(2)])
+        );
+        assert_eq!(
+            array_slice(&a, Some(3), None),
+            int_array(&[Some(3), Some(4)])
+        );
+        assert_eq!(array_slice(&a, Some(-5), Some(1)), int_array(&[Some(1)]));
+        assert_eq!(array_slice(&a, Some(3), Some(2)), int_array(&[]));
+    }
+}
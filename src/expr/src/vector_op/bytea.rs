@@ -0,0 +1,147 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::{max, min};
+use std::fmt::Write;
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{ByteaRef, ByteaVal};
+use sha2::{Digest, Sha256};
+
+fn unrecognized_encoding(format: &str) -> ErrorCode {
+    ErrorCode::InvalidInputSyntax(format!("unrecognized encoding: \"{}\"", format))
+}
+
+/// `encode(bytes, format)` converts binary data into text, where `format` is one of `hex`,
+/// `escape` and `base64`.
+#[inline(always)]
+pub fn bytea_encode(data: ByteaRef, format: &str) -> Result<String> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => Ok(data.as_bytes().iter().fold(
+            String::with_capacity(data.as_bytes().len() * 2),
+            |mut s, b| {
+                write!(s, "{:02x}", b).unwrap();
+                s
+            },
+        )),
+        "escape" => Ok(data.to_escape_string()),
+        "base64" => Ok(base64::encode(data.as_bytes())),
+        _ => Err(unrecognized_encoding(format).into()),
+    }
+}
+
+/// `decode(text, format)` is the inverse of [`bytea_encode`].
+#[inline(always)]
+pub fn bytea_decode(text: &str, format: &str) -> Result<ByteaVal> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => ByteaVal::from_hex_str(text),
+        "escape" => ByteaVal::from_escape_str(text),
+        "base64" => base64::decode(text.trim())
+            .map(ByteaVal::from)
+            .map_err(|e| {
+                ErrorCode::InvalidInputSyntax(format!("invalid base64 input: {}", e)).into()
+            }),
+        _ => Err(unrecognized_encoding(format).into()),
+    }
+}
+
+#[inline(always)]
+pub fn bytea_sha256(data: ByteaRef) -> Result<ByteaVal> {
+    Ok(Sha256::digest(data.as_bytes()).to_vec().into())
+}
+
+#[inline(always)]
+pub fn bytea_length(data: ByteaRef) -> Result<i32> {
+    Ok(data.as_bytes().len() as i32)
+}
+
+#[inline(always)]
+pub fn bytea_substr_start(data: ByteaRef, start: i32) -> Result<ByteaVal> {
+    let bytes = data.as_bytes();
+    let start = min(max(start - 1, 0) as usize, bytes.len());
+    Ok(bytes[start..].into())
+}
+
+#[inline(always)]
+pub fn bytea_substr_start_for(data: ByteaRef, start: i32, count: i32) -> Result<ByteaVal> {
+    if count < 0 {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "length in substr should be non-negative: {}",
+            count
+        ))
+        .into());
+    }
+    let bytes = data.as_bytes();
+    let begin = min(max(start - 1, 0) as usize, bytes.len());
+    let end = min(
+        max(start.saturating_add(count) - 1, 0) as usize,
+        bytes.len(),
+    );
+    Ok(bytes[begin..max(begin, end)].into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        let v = ByteaVal::from(&b"\x00ab\\\xff"[..]);
+        let data = ByteaRef::from(v.as_bytes());
+        for (format, text) in [
+            ("hex", "0061625cff"),
+            ("escape", "\\000ab\\\\\\377"),
+            ("base64", "AGFiXP8="),
+        ] {
+            assert_eq!(bytea_encode(data, format).unwrap(), text);
+            assert_eq!(bytea_decode(text, format).unwrap(), v);
+        }
+        assert!(bytea_encode(data, "utf8").is_err());
+        assert!(bytea_decode("0g", "hex").is_err());
+        assert!(bytea_decode("a=b", "base64").is_err());
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            bytea_encode(
+                ByteaRef::from(
+                    bytea_sha256(ByteaRef::from(&b"abc"[..]))
+                        .unwrap()
+                        .as_bytes()
+                ),
+                "hex"
+            )
+            .unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_length_and_substr() {
+        let data = ByteaRef::from(&b"abcdef"[..]);
+        assert_eq!(bytea_length(data).unwrap(), 6);
+        assert_eq!(bytea_substr_start(data, 3).unwrap().as_bytes(), b"cdef");
+        assert_eq!(bytea_substr_start(data, 10).unwrap().as_bytes(), b"");
+        assert_eq!(
+            bytea_substr_start_for(data, 2, 3).unwrap().as_bytes(),
+            b"bcd"
+        );
+        assert_eq!(
+            bytea_substr_start_for(data, -1, 3).unwrap().as_bytes(),
+            b"a"
+        );
+        assert!(bytea_substr_start_for(data, 1, -1).is_err());
+    }
+}
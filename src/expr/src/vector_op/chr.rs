@@ -0,0 +1,64 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+/// Returns the character with the given Unicode code point.
+#[inline(always)]
+pub fn chr(code: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    if code == 0 {
+        return Err(ErrorCode::InvalidParameterValue("null character not permitted".into()).into());
+    }
+    // Negative codes wrap around to large unsigned values, as in PostgreSQL.
+    let c = char::from_u32(code as u32).ok_or_else(|| {
+        let reason = match code as u32 > char::MAX as u32 {
+            true => "too large",
+            false => "not valid",
+        };
+        ErrorCode::InvalidParameterValue(format!(
+            "requested character {} for encoding: {}",
+            reason, code
+        ))
+    })?;
+    let mut buf = [0; 4];
+    writer.write_ref(c.encode_utf8(&mut buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_chr() -> Result<()> {
+        let cases = [(65, "A"), (20154, "人"), (0x1F600, "😀")];
+
+        for (code, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = chr(code, writer)?;
+            let array = guard.into_inner().finish()?;
+            let v = array.value_at(0).unwrap();
+            assert_eq!(v, expected);
+        }
+
+        for code in [0, -1, 0xD800, 0x110000] {
+            let writer = Utf8ArrayBuilder::new(1)?.writer();
+            assert!(chr(code, writer).is_err());
+        }
+        Ok(())
+    }
+}
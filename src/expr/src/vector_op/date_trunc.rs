@@ -0,0 +1,171 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{IntervalUnit, NaiveDateTimeWrapper};
+
+use super::timezone::{timestamp_at_time_zone, timestampz_at_time_zone};
+
+fn invalid_unit(kind: &str, field: &str) -> RwError {
+    ErrorCode::InvalidInputSyntax(format!("{} units \"{}\" not supported", kind, field)).into()
+}
+
+/// The first year of the century or millennium of `year`, e.g. 1901 for 2000 and 2001 for 2022.
+fn first_year_of(year: i32, years: i32) -> i32 {
+    (year - 1).div_euclid(years) * years + 1
+}
+
+fn truncate(field: &str, ts: NaiveDateTime) -> Option<NaiveDateTime> {
+    let date = ts.date();
+    let nanos = ts.nanosecond();
+    let truncated = match field.to_ascii_lowercase().as_str() {
+        "microseconds" => ts.with_nanosecond(nanos / 1000 * 1000)?,
+        "milliseconds" => ts.with_nanosecond(nanos / 1_000_000 * 1_000_000)?,
+        "second" => ts.with_nanosecond(0)?,
+        "minute" => date.and_hms(ts.hour(), ts.minute(), 0),
+        "hour" => date.and_hms(ts.hour(), 0, 0),
+        "day" => date.and_hms(0, 0, 0),
+        // Weeks start on Monday, as in ISO 8601.
+        "week" => {
+            (date - Duration::days(date.weekday().num_days_from_monday() as i64)).and_hms(0, 0, 0)
+        }
+        "month" => NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?.and_hms(0, 0, 0),
+        "quarter" => NaiveDate::from_ymd_opt(date.year(), (date.month() - 1) / 3 * 3 + 1, 1)?
+            .and_hms(0, 0, 0),
+        "year" => NaiveDate::from_ymd_opt(date.year(), 1, 1)?.and_hms(0, 0, 0),
+        "decade" => {
+            NaiveDate::from_ymd_opt(date.year().div_euclid(10) * 10, 1, 1)?.and_hms(0, 0, 0)
+        }
+        "century" => {
+            NaiveDate::from_ymd_opt(first_year_of(date.year(), 100), 1, 1)?.and_hms(0, 0, 0)
+        }
+        "millennium" => {
+            NaiveDate::from_ymd_opt(first_year_of(date.year(), 1000), 1, 1)?.and_hms(0, 0, 0)
+        }
+        _ => return None,
+    };
+    Some(truncated)
+}
+
+#[inline(always)]
+pub fn date_trunc_timestamp(field: &str, ts: NaiveDateTimeWrapper) -> Result<NaiveDateTimeWrapper> {
+    truncate(field, ts.0)
+        .map(NaiveDateTimeWrapper::new)
+        .ok_or_else(|| invalid_unit("timestamp", field))
+}
+
+/// Truncates the local time in `zone`.
+#[inline(always)]
+pub fn date_trunc_timestampz(field: &str, tsz: i64, zone: &str) -> Result<i64> {
+    let local = timestampz_at_time_zone(tsz, zone)?;
+    timestamp_at_time_zone(date_trunc_timestamp(field, local)?, zone)
+}
+
+#[inline(always)]
+pub fn date_trunc_interval(field: &str, interval: IntervalUnit) -> Result<IntervalUnit> {
+    let months = interval.get_months();
+    let days = interval.get_days();
+    let ms = interval.get_ms();
+    let truncated = match field.to_ascii_lowercase().as_str() {
+        // An interval is precise to milliseconds.
+        "microseconds" | "milliseconds" => interval,
+        "second" => IntervalUnit::new(months, days, ms / 1000 * 1000),
+        "minute" => IntervalUnit::new(months, days, ms / 60_000 * 60_000),
+        "hour" => IntervalUnit::new(months, days, ms / 3_600_000 * 3_600_000),
+        "day" => IntervalUnit::new(months, days, 0),
+        "month" => IntervalUnit::from_month(months),
+        "quarter" => IntervalUnit::from_month(months / 3 * 3),
+        "year" => IntervalUnit::from_month(months / 12 * 12),
+        "decade" => IntervalUnit::from_month(months / 120 * 120),
+        "century" => IntervalUnit::from_month(months / 1200 * 1200),
+        "millennium" => IntervalUnit::from_month(months / 12000 * 12000),
+        _ => return Err(invalid_unit("interval", field)),
+    };
+    Ok(truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp(s: &str) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap())
+    }
+
+    #[test]
+    fn test_date_trunc_timestamp() {
+        let ts = timestamp("2022-08-17 13:45:21.123456");
+        for (field, expected) in [
+            ("microseconds", "2022-08-17 13:45:21.123456"),
+            ("milliseconds", "2022-08-17 13:45:21.123"),
+            ("SECOND", "2022-08-17 13:45:21"),
+            ("minute", "2022-08-17 13:45:00"),
+            ("hour", "2022-08-17 13:00:00"),
+            ("day", "2022-08-17 00:00:00"),
+            ("week", "2022-08-15 00:00:00"),
+            ("month", "2022-08-01 00:00:00"),
+            ("quarter", "2022-07-01 00:00:00"),
+            ("year", "2022-01-01 00:00:00"),
+            ("decade", "2020-01-01 00:00:00"),
+            ("century", "2001-01-01 00:00:00"),
+            ("millennium", "2001-01-01 00:00:00"),
+        ] {
+            assert_eq!(
+                date_trunc_timestamp(field, ts).unwrap(),
+                timestamp(expected),
+                "{}",
+                field
+            );
+        }
+        assert_eq!(
+            date_trunc_timestamp("century", timestamp("2000-12-31 00:00:00")).unwrap(),
+            timestamp("1901-01-01 00:00:00")
+        );
+        assert!(date_trunc_timestamp("fortnight", ts).is_err());
+    }
+
+    #[test]
+    fn test_date_trunc_timestampz() {
+        let tsz = timestamp_at_time_zone(timestamp("2022-08-17 01:30:00"), "UTC").unwrap();
+        // It is still the 16th in New York.
+        let truncated = date_trunc_timestampz("day", tsz, "America/New_York").unwrap();
+        assert_eq!(
+            timestampz_at_time_zone(truncated, "UTC").unwrap(),
+            timestamp("2022-08-16 04:00:00")
+        );
+    }
+
+    #[test]
+    fn test_date_trunc_interval() {
+        let interval = IntervalUnit::new(27, 12, 3_723_456);
+        assert_eq!(
+            date_trunc_interval("minute", interval).unwrap(),
+            IntervalUnit::new(27, 12, 3_720_000)
+        );
+        assert_eq!(
+            date_trunc_interval("day", interval).unwrap(),
+            IntervalUnit::new(27, 12, 0)
+        );
+        assert_eq!(
+            date_trunc_interval("quarter", interval).unwrap(),
+            IntervalUnit::from_month(27)
+        );
+        assert_eq!(
+            date_trunc_interval("year", interval).unwrap(),
+            IntervalUnit::from_month(24)
+        );
+        assert!(date_trunc_interval("week", interval).is_err());
+    }
+}
@@ -12,23 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use chrono::{Datelike, Timelike};
+use chrono::{Datelike, NaiveDateTime, Timelike};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::{Decimal, NaiveDateTimeWrapper, NaiveDateWrapper};
+use risingwave_common::types::{Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper};
+
+fn unsupported_unit(time_unit: &str) -> RwError {
+    RwError::from(InternalError(format!(
+        "Unsupported time unit {} in extract function",
+        time_unit
+    )))
+}
 
 fn extract_time<T>(time: T, time_unit: &str) -> Result<Decimal>
 where
     T: Timelike,
 {
+    // Seconds and their fractional part, in microseconds.
+    let micros = time.second() as i64 * 1_000_000 + (time.nanosecond() / 1000) as i64;
     match time_unit {
         "HOUR" => Ok(time.hour().into()),
         "MINUTE" => Ok(time.minute().into()),
-        "SECOND" => Ok(time.second().into()),
-        _ => Err(RwError::from(InternalError(format!(
-            "Unsupported time unit {} in extract function",
-            time_unit
-        )))),
+        "SECOND" => Ok(Decimal::new(micros, 6)),
+        "MILLISECONDS" => Ok(Decimal::new(micros, 3)),
+        "MICROSECONDS" => Ok(micros.into()),
+        _ => Err(unsupported_unit(time_unit)),
     }
 }
 
@@ -40,22 +48,50 @@ where
         "DAY" => Ok(date.day().into()),
         "MONTH" => Ok(date.month().into()),
         "YEAR" => Ok(date.year().into()),
+        "QUARTER" => Ok(((date.month() - 1) / 3 + 1).into()),
+        "WEEK" => Ok(date.iso_week().week().into()),
+        "DECADE" => Ok(date.year().div_euclid(10).into()),
+        "CENTURY" => Ok(first_of(date.year(), 100).into()),
+        "MILLENNIUM" => Ok(first_of(date.year(), 1000).into()),
         // Sun = 0 and Sat = 6
         "DOW" => Ok(date.weekday().num_days_from_sunday().into()),
+        // Mon = 1 and Sun = 7
+        "ISODOW" => Ok(date.weekday().number_from_monday().into()),
         "DOY" => Ok(date.ordinal().into()),
-        _ => Err(RwError::from(InternalError(format!(
-            "Unsupported time unit {} in extract function",
-            time_unit
-        )))),
+        _ => Err(unsupported_unit(time_unit)),
+    }
+}
+
+/// The century or millennium of `year`, where the first one begins with the year 1.
+fn first_of(year: i32, years: i32) -> i32 {
+    if year > 0 {
+        (year - 1) / years + 1
+    } else {
+        -((-year) / years + 1)
     }
 }
 
+fn epoch(time: NaiveDateTime) -> Decimal {
+    Decimal::new(
+        time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64,
+        6,
+    )
+}
+
 pub fn extract_from_date(time_unit: &str, date: NaiveDateWrapper) -> Result<Decimal> {
-    extract_date(date.0, time_unit)
+    match time_unit {
+        "EPOCH" => Ok(epoch(date.0.and_hms(0, 0, 0))),
+        _ => extract_date(date.0, time_unit),
+    }
 }
 
+/// For a timestamp with time zone, the frontend extracts from its local time in the session time
+/// zone, or from its UTC time for `EPOCH`.
 pub fn extract_from_timestamp(time_unit: &str, timestamp: NaiveDateTimeWrapper) -> Result<Decimal> {
     let time = timestamp.0;
+    if time_unit == "EPOCH" {
+        return Ok(epoch(time));
+    }
     let mut res = extract_date(time, time_unit);
     if res.is_err() {
         res = extract_time(time, time_unit);
@@ -63,6 +99,33 @@ pub fn extract_from_timestamp(time_unit: &str, timestamp: NaiveDateTimeWrapper)
     res
 }
 
+pub fn extract_from_interval(time_unit: &str, interval: IntervalUnit) -> Result<Decimal> {
+    let months = interval.get_months() as i64;
+    let ms = interval.get_ms();
+    match time_unit {
+        "MILLENNIUM" => Ok((months / 12000).into()),
+        "CENTURY" => Ok((months / 1200).into()),
+        "DECADE" => Ok((months / 120).into()),
+        "YEAR" => Ok((months / 12).into()),
+        "QUARTER" => Ok((months % 12 / 3 + 1).into()),
+        "MONTH" => Ok((months % 12).into()),
+        "DAY" => Ok(interval.get_days().into()),
+        "HOUR" => Ok((ms / 3_600_000).into()),
+        "MINUTE" => Ok((ms / 60_000 % 60).into()),
+        "SECOND" => Ok(Decimal::new(ms % 60_000, 3)),
+        "MILLISECONDS" => Ok((ms % 60_000).into()),
+        "MICROSECONDS" => Ok((ms % 60_000 * 1000).into()),
+        // As in PG, a year has 365.25 days and a month has 30 days.
+        "EPOCH" => {
+            let ms = (months / 12) as i128 * 36525 * 864_000
+                + ((months % 12) * 30 + interval.get_days() as i64) as i128 * 86_400_000
+                + ms as i128;
+            Ok(Decimal::from_i128_with_scale(ms, 3))
+        }
+        _ => Err(unsupported_unit(time_unit)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{NaiveDate, NaiveDateTime};
@@ -88,5 +151,32 @@ mod tests {
         assert_eq!(extract_from_timestamp("HOUR", time).unwrap(), 12.into());
         assert_eq!(extract_from_timestamp("MINUTE", time).unwrap(), 4.into());
         assert_eq!(extract_from_timestamp("SECOND", time).unwrap(), 2.into());
+        assert_eq!(
+            extract_from_timestamp("EPOCH", time).unwrap(),
+            1637582642.into()
+        );
+        assert_eq!(extract_from_timestamp("QUARTER", time).unwrap(), 4.into());
+        assert_eq!(extract_from_timestamp("ISODOW", time).unwrap(), 1.into());
+        assert_eq!(extract_from_timestamp("CENTURY", time).unwrap(), 21.into());
+    }
+
+    #[test]
+    fn test_interval() {
+        // 1 year 2 mons 3 days 04:05:06.789
+        let interval = IntervalUnit::new(14, 3, 14_706_789);
+        assert_eq!(extract_from_interval("YEAR", interval).unwrap(), 1.into());
+        assert_eq!(extract_from_interval("MONTH", interval).unwrap(), 2.into());
+        assert_eq!(extract_from_interval("DAY", interval).unwrap(), 3.into());
+        assert_eq!(extract_from_interval("HOUR", interval).unwrap(), 4.into());
+        assert_eq!(extract_from_interval("MINUTE", interval).unwrap(), 5.into());
+        assert_eq!(
+            extract_from_interval("SECOND", interval).unwrap(),
+            Decimal::new(6789, 3)
+        );
+        assert_eq!(
+            extract_from_interval("EPOCH", interval).unwrap(),
+            Decimal::new(37_015_506_789, 3)
+        );
+        assert!(extract_from_interval("DOW", interval).is_err());
     }
 }
@@ -0,0 +1,242 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+
+use risingwave_common::array::PartialBytesWriter;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+use crate::vector_op::quote::{write_quoted_ident, write_quoted_literal};
+
+/// Formats the arguments like `format()` in Postgres, where each format specifier has the form
+/// `%[position$][-][width]type`:
+/// - `position` is the 1-based index of the argument, or the one after the previous argument if
+///   omitted.
+/// - `-` left-justifies the value, which is otherwise right-justified if it's shorter than `width`.
+/// - `width` is either a number, or `*` or `*n$` to take it from an argument.
+/// - `type` is `s` for the value as a string, `I` for a quoted identifier, or `L` for a quoted
+///   literal. `%%` outputs a literal `%`.
+pub fn format(fmt: &str, args: &[Option<&str>], writer: &mut PartialBytesWriter) -> Result<()> {
+    let mut cursor = Cursor(fmt);
+    let mut next_arg = 0;
+
+    while let Some(pos) = cursor.0.find('%') {
+        writer.write_ref(&cursor.0[..pos])?;
+        cursor.0 = &cursor.0[pos + 1..];
+        if cursor.eat('%') {
+            writer.write_ref("%")?;
+            continue;
+        }
+
+        let mut position = None;
+        let mut width = None;
+        let mut left_justify = false;
+        if let Some(n) = cursor.number()? {
+            if cursor.eat('$') {
+                position = Some(n);
+            } else {
+                width = Some(n as i64);
+            }
+        }
+        if width.is_none() {
+            while cursor.eat('-') {
+                left_justify = true;
+            }
+            if cursor.eat('*') {
+                let index = match cursor.number()? {
+                    Some(n) if cursor.eat('$') => arg_index(n)?,
+                    Some(_) => return Err(unterminated()),
+                    None => next_arg,
+                };
+                next_arg = index + 1;
+                // A null width is ignored.
+                if let Some(w) = get_arg(args, index)? {
+                    let w: i64 = w.trim().parse().map_err(|_| {
+                        invalid(format!("invalid input syntax for type integer: \"{}\"", w))
+                    })?;
+                    width = Some(w);
+                }
+            } else if let Some(n) = cursor.number()? {
+                width = Some(n as i64);
+            }
+        }
+
+        let spec = cursor.bump().ok_or_else(unterminated)?;
+        let index = match position {
+            Some(n) => arg_index(n)?,
+            None => next_arg,
+        };
+        next_arg = index + 1;
+        let arg = get_arg(args, index)?;
+        let value: Cow<'_, str> = match (spec, arg) {
+            ('s', Some(arg)) => arg.into(),
+            ('s', None) => "".into(),
+            ('I', Some(arg)) => {
+                let mut quoted = String::new();
+                write_quoted_ident(arg, &mut quoted).unwrap();
+                quoted.into()
+            }
+            ('I', None) => {
+                return Err(invalid(
+                    "null values cannot be formatted as an SQL identifier".into(),
+                ))
+            }
+            ('L', Some(arg)) => {
+                let mut quoted = String::new();
+                write_quoted_literal(arg, &mut quoted).unwrap();
+                quoted.into()
+            }
+            ('L', None) => "NULL".into(),
+            (spec, _) => {
+                return Err(invalid(format!(
+                    "unrecognized format() type specifier \"{}\"",
+                    spec
+                )))
+            }
+        };
+
+        let width = width.unwrap_or(0);
+        let padding = (width.unsigned_abs() as usize).saturating_sub(value.chars().count());
+        if left_justify || width < 0 {
+            writer.write_ref(&value)?;
+            write_spaces(padding, writer)?;
+        } else {
+            write_spaces(padding, writer)?;
+            writer.write_ref(&value)?;
+        }
+    }
+    writer.write_ref(cursor.0)
+}
+
+/// The rest of the format string to parse.
+struct Cursor<'a>(&'a str);
+
+impl Cursor<'_> {
+    fn bump(&mut self) -> Option<char> {
+        let c = self.0.chars().next()?;
+        self.0 = &self.0[c.len_utf8()..];
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        match self.0.starts_with(c) {
+            true => {
+                self.0 = &self.0[c.len_utf8()..];
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn number(&mut self) -> Result<Option<usize>> {
+        let len = self
+            .0
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.0.len());
+        if len == 0 {
+            return Ok(None);
+        }
+        let n = self.0[..len]
+            .parse::<i32>()
+            .map_err(|_| invalid("number is out of range".into()))?;
+        self.0 = &self.0[len..];
+        Ok(Some(n as usize))
+    }
+}
+
+fn arg_index(position: usize) -> Result<usize> {
+    match position {
+        0 => Err(invalid(
+            "format specifies argument 0, but arguments are numbered from 1".into(),
+        )),
+        n => Ok(n - 1),
+    }
+}
+
+fn get_arg<'a>(args: &[Option<&'a str>], index: usize) -> Result<Option<&'a str>> {
+    args.get(index)
+        .copied()
+        .ok_or_else(|| invalid("too few arguments for format()".into()))
+}
+
+fn write_spaces(count: usize, writer: &mut PartialBytesWriter) -> Result<()> {
+    const SPACES: &str = "                ";
+    let mut count = count;
+    while count > 0 {
+        let n = count.min(SPACES.len());
+        writer.write_ref(&SPACES[..n])?;
+        count -= n;
+    }
+    Ok(())
+}
+
+fn unterminated() -> RwError {
+    invalid("unterminated format() type specifier".into())
+}
+
+fn invalid(msg: String) -> RwError {
+    ErrorCode::InvalidParameterValue(msg).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    fn format_string(fmt: &str, args: &[Option<&str>]) -> Result<String> {
+        let builder = Utf8ArrayBuilder::new(1)?;
+        let mut writer = builder.writer().begin();
+        format(fmt, args, &mut writer)?;
+        let array = writer.finish()?.into_inner().finish()?;
+        Ok(array.value_at(0).unwrap().to_string())
+    }
+
+    #[test]
+    fn test_format() {
+        let cases: &[(&str, &[Option<&str>], &str)] = &[
+            ("Hello %s", &[Some("World")], "Hello World"),
+            ("Hello %s%%", &[None], "Hello %"),
+            (
+                "INSERT INTO %I VALUES(%L)",
+                &[Some("Foo bar"), Some("O'Reilly")],
+                "INSERT INTO \"Foo bar\" VALUES('O''Reilly')",
+            ),
+            ("%L", &[None], "NULL"),
+            (
+                "|%10s|%-5s|",
+                &[Some("foo"), Some("bar")],
+                "|       foo|bar  |",
+            ),
+            ("|%*s|", &[Some("-5"), Some("foo")], "|foo  |"),
+            ("|%*2$s|", &[Some("foo"), Some("5"), Some("bar")], "|  bar|"),
+            ("%2$s %1$s %s", &[Some("a"), Some("b")], "b a b"),
+            ("奇点%s", &[Some("无限")], "奇点无限"),
+        ];
+        for (fmt, args, expected) in cases {
+            assert_eq!(format_string(fmt, args).unwrap(), *expected, "{}", fmt);
+        }
+
+        let errors: &[(&str, &[Option<&str>])] = &[
+            ("%s %s", &[Some("a")]),
+            ("%", &[Some("a")]),
+            ("%x", &[Some("a")]),
+            ("%0$s", &[Some("a")]),
+            ("%I", &[None]),
+        ];
+        for (fmt, args) in errors {
+            assert!(format_string(fmt, args).is_err(), "{}", fmt);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{JsonbRef, JsonbVal, ScalarRef};
+
+#[inline(always)]
+pub fn jsonb_access_field(l: Option<JsonbRef>, r: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(l.zip(r)
+        .and_then(|(l, r)| l.access_object_field(r))
+        .map(|v| v.to_owned_scalar()))
+}
+
+#[inline(always)]
+pub fn jsonb_access_element(l: Option<JsonbRef>, r: Option<i32>) -> Result<Option<JsonbVal>> {
+    Ok(l.zip(r)
+        .and_then(|(l, r)| l.access_array_element(r))
+        .map(|v| v.to_owned_scalar()))
+}
+
+#[inline(always)]
+pub fn jsonb_access_field_str(l: Option<JsonbRef>, r: Option<&str>) -> Result<Option<String>> {
+    Ok(l.zip(r)
+        .and_then(|(l, r)| l.access_object_field(r))
+        .and_then(|v| v.as_text()))
+}
+
+#[inline(always)]
+pub fn jsonb_access_element_str(l: Option<JsonbRef>, r: Option<i32>) -> Result<Option<String>> {
+    Ok(l.zip(r)
+        .and_then(|(l, r)| l.access_array_element(r))
+        .and_then(|v| v.as_text()))
+}
+
+#[inline(always)]
+pub fn jsonb_access_path(l: Option<JsonbRef>, r: Option<&str>) -> Result<Option<JsonbVal>> {
+    match (l, r) {
+        (Some(l), Some(r)) => Ok(l.access_path(parse_path(r)?).map(|v| v.to_owned_scalar())),
+        _ => Ok(None),
+    }
+}
+
+#[inline(always)]
+pub fn jsonb_access_path_str(l: Option<JsonbRef>, r: Option<&str>) -> Result<Option<String>> {
+    match (l, r) {
+        (Some(l), Some(r)) => Ok(l.access_path(parse_path(r)?).and_then(|v| v.as_text())),
+        _ => Ok(None),
+    }
+}
+
+#[inline(always)]
+pub fn jsonb_contains(l: JsonbRef, r: JsonbRef) -> Result<bool> {
+    Ok(l.contains(r))
+}
+
+#[inline(always)]
+pub fn jsonb_exists(l: JsonbRef, r: &str) -> Result<bool> {
+    Ok(l.exists(r))
+}
+
+#[inline(always)]
+pub fn jsonb_typeof(v: JsonbRef) -> Result<String> {
+    Ok(v.type_name().to_string())
+}
+
+/// Parses a path written as a text array literal, e.g. `{a,0,"b c"}`.
+fn parse_path(path: &str) -> Result<Vec<&str>> {
+    let inner = path
+        .trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| {
+            ErrorCode::InvalidInputSyntax(format!("malformed array literal: \"{}\"", path))
+        })?;
+    if inner.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(inner
+        .split(',')
+        .map(|step| {
+            let step = step.trim();
+            step.strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .unwrap_or(step)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn test_jsonb_access() {
+        let v: JsonbVal = r#"{"a": [1, {"b c": "x"}], "n": null}"#.parse().unwrap();
+        let v = v.as_scalar_ref();
+
+        assert_eq!(
+            jsonb_access_field(Some(v), Some("a")).unwrap(),
+            Some("[1, {\"b c\": \"x\"}]".parse().unwrap())
+        );
+        assert_eq!(jsonb_access_field(Some(v), Some("z")).unwrap(), None);
+        assert_eq!(jsonb_access_field(Some(v), None).unwrap(), None);
+        assert_eq!(jsonb_access_field_str(Some(v), Some("n")).unwrap(), None);
+        assert_eq!(jsonb_access_element(Some(v), Some(0)).unwrap(), None);
+        assert_eq!(
+            jsonb_access_path_str(Some(v), Some(r#"{a, 1, "b c"}"#)).unwrap(),
+            Some("x".to_string())
+        );
+        assert_eq!(
+            jsonb_access_path(Some(v), Some("{a,-2}")).unwrap(),
+            Some("1".parse().unwrap())
+        );
+        assert_eq!(
+            jsonb_access_path(Some(v), Some("{}")).unwrap(),
+            Some(v.to_owned_scalar())
+        );
+        assert!(jsonb_access_path(Some(v), Some("a")).is_err());
+    }
+}
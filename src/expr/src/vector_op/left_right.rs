@@ -0,0 +1,87 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::Result;
+
+/// Returns the first `n` characters of the string, or all but the last `|n|` characters if `n` is
+/// negative.
+#[inline(always)]
+pub fn left(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let end = if n >= 0 {
+        s.char_indices().nth(n as usize).map_or(s.len(), |(i, _)| i)
+    } else {
+        s.char_indices()
+            .rev()
+            .nth(n.unsigned_abs() as usize - 1)
+            .map_or(0, |(i, _)| i)
+    };
+    writer.write_ref(&s[..end])
+}
+
+/// Returns the last `n` characters of the string, or all but the first `|n|` characters if `n` is
+/// negative.
+#[inline(always)]
+pub fn right(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let start = match n.cmp(&0) {
+        Ordering::Greater => s
+            .char_indices()
+            .rev()
+            .nth(n as usize - 1)
+            .map_or(0, |(i, _)| i),
+        Ordering::Equal => s.len(),
+        Ordering::Less => s
+            .char_indices()
+            .nth(n.unsigned_abs() as usize)
+            .map_or(s.len(), |(i, _)| i),
+    };
+    writer.write_ref(&s[start..])
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_left_right() -> Result<()> {
+        let cases = [
+            ("abcde", 2, "ab", "de"),
+            ("abcde", -2, "abc", "cde"),
+            ("abcde", 0, "", ""),
+            ("abcde", 10, "abcde", "abcde"),
+            ("abcde", -10, "", ""),
+            ("奇点无限", 1, "奇", "限"),
+            ("奇点无限", -1, "奇点无", "点无限"),
+        ];
+
+        for (s, n, expected_left, expected_right) in cases {
+            for (f, expected) in [
+                (left as fn(_, _, _) -> _, expected_left),
+                (right, expected_right),
+            ] {
+                let builder = Utf8ArrayBuilder::new(1)?;
+                let writer = builder.writer();
+                let guard = f(s, n, writer)?;
+                let array = guard.into_inner().finish()?;
+                let v = array.value_at(0).unwrap();
+                assert_eq!(v, expected);
+            }
+        }
+        Ok(())
+    }
+}
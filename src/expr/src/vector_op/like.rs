@@ -16,6 +16,17 @@ use risingwave_common::error::Result;
 
 #[inline(always)]
 pub fn like_default(s: &str, p: &str) -> Result<bool> {
+    like_impl::<false>(s, p)
+}
+
+/// `ILIKE`, which matches ASCII letters case-insensitively.
+#[inline(always)]
+pub fn ilike_default(s: &str, p: &str) -> Result<bool> {
+    like_impl::<true>(s, p)
+}
+
+#[inline(always)]
+fn like_impl<const CASE_INSENSITIVE: bool>(s: &str, p: &str) -> Result<bool> {
     let (mut px, mut sx) = (0, 0);
     let (mut next_px, mut next_sx) = (0, 0);
     let (pbytes, sbytes) = (p.as_bytes(), s.as_bytes());
@@ -37,7 +48,12 @@ pub fn like_default(s: &str, p: &str) -> Result<bool> {
                     continue;
                 }
                 pc => {
-                    if sx < sbytes.len() && sbytes[sx] == pc {
+                    let matched = sx < sbytes.len()
+                        && match CASE_INSENSITIVE {
+                            true => sbytes[sx].eq_ignore_ascii_case(&pc),
+                            false => sbytes[sx] == pc,
+                        };
+                    if matched {
                         px += 1;
                         sx += 1;
                         continue;
@@ -57,7 +73,7 @@ pub fn like_default(s: &str, p: &str) -> Result<bool> {
 
 #[cfg(test)]
 mod tests {
-    use super::like_default;
+    use super::{ilike_default, like_default};
 
     static CASES: &[(&str, &str, std::option::Option<bool>)] = &[
         (r#"ABCDE"#, r#"%abcde%"#, Some(false)),
@@ -94,4 +110,17 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_ilike() {
+        let cases = [
+            ("ABCDE", "%abcde%", true),
+            ("Like, Expression", "like, %", true),
+            ("like", "L_KE", true),
+            ("like", "LIKES", false),
+        ];
+        for (target, pattern, expected) in cases {
+            assert_eq!(ilike_default(target, pattern).unwrap(), expected);
+        }
+    }
 }
@@ -0,0 +1,56 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::NaiveDate;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::NaiveDateWrapper;
+
+/// `make_date(year, month, day)`, where a negative year is BC as in PG.
+#[inline(always)]
+pub fn make_date(year: i32, month: i32, day: i32) -> Result<NaiveDateWrapper> {
+    // 1 BC is the year 0 in chrono.
+    let chrono_year = if year < 0 { year + 1 } else { year };
+    let date = if year == 0 || month < 1 || day < 1 {
+        None
+    } else {
+        NaiveDate::from_ymd_opt(chrono_year, month as u32, day as u32)
+    };
+    date.map(NaiveDateWrapper::new).ok_or_else(|| {
+        ErrorCode::InvalidInputSyntax(format!(
+            "date field value out of range: {}-{:02}-{:02}",
+            year, month, day
+        ))
+        .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_date() {
+        assert_eq!(
+            make_date(2022, 2, 28).unwrap(),
+            NaiveDateWrapper::new(NaiveDate::from_ymd(2022, 2, 28))
+        );
+        assert_eq!(
+            make_date(-44, 3, 15).unwrap(),
+            NaiveDateWrapper::new(NaiveDate::from_ymd(-43, 3, 15))
+        );
+        assert!(make_date(2022, 2, 29).is_err());
+        assert!(make_date(0, 1, 1).is_err());
+        assert!(make_date(2022, 13, 1).is_err());
+    }
+}
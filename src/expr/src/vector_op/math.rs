@@ -0,0 +1,475 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mathematical functions, which follow Postgres on special values: `NaN` propagates, and
+//! infinite inputs are allowed where the result is well defined. An infinite result from finite
+//! inputs is an overflow, and a zero result from non-zero inputs is an underflow.
+//!
+//! Transcendental functions on decimals are computed in `f64`, which keeps about 15 significant
+//! digits.
+
+use num_traits::{CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, ToPrimitive, Zero};
+use risingwave_common::error::ErrorCode::{InvalidParameterValue, NumericValueOutOfRange};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{Decimal, OrderedF64};
+
+fn invalid_argument(message: &str) -> RwError {
+    InvalidParameterValue(message.to_string()).into()
+}
+
+/// Checks the result of a function on finite inputs for overflow, and for underflow if
+/// `check_underflow` is set.
+fn check_float_result(
+    result: f64,
+    inputs_finite: bool,
+    check_underflow: bool,
+) -> Result<OrderedF64> {
+    if inputs_finite && (result.is_infinite() || (check_underflow && result == 0.0)) {
+        return Err(NumericValueOutOfRange.into());
+    }
+    Ok(result.into())
+}
+
+/// Converts a result computed in `f64` to a decimal, which is zero if it is too small.
+fn f64_to_decimal(result: f64) -> Result<Decimal> {
+    match Decimal::from_f64(result) {
+        Some(decimal) => Ok(decimal),
+        None if result.abs() < 1.0 => Ok(Decimal::zero()),
+        None => Err(NumericValueOutOfRange.into()),
+    }
+}
+
+#[inline(always)]
+pub fn pow_f64(base: OrderedF64, exponent: OrderedF64) -> Result<OrderedF64> {
+    let (x, y) = (base.0, exponent.0);
+    if x == 0.0 && y < 0.0 {
+        return Err(invalid_argument(
+            "zero raised to a negative power is undefined",
+        ));
+    }
+    if x < 0.0 && y.is_finite() && y.fract() != 0.0 {
+        return Err(invalid_argument(
+            "a negative number raised to a non-integer power yields a complex result",
+        ));
+    }
+    check_float_result(x.powf(y), x.is_finite() && y.is_finite(), x != 0.0)
+}
+
+/// An integral exponent is computed exactly by repeated squaring, and others in `f64`.
+#[inline(always)]
+pub fn pow_decimal(base: Decimal, exponent: Decimal) -> Result<Decimal> {
+    let integral_exponent = match exponent {
+        Decimal::Normalized(_) if exponent.trunc_dp(0) == exponent => exponent.to_i64(),
+        _ => None,
+    };
+    let n = match (base, integral_exponent) {
+        (Decimal::Normalized(_), Some(n)) => n,
+        _ => return f64_to_decimal(pow_f64(base.into(), exponent.into())?.0),
+    };
+    if base.is_zero() && n < 0 {
+        return Err(invalid_argument(
+            "zero raised to a negative power is undefined",
+        ));
+    }
+    let mut result = Decimal::from(1);
+    let mut square = base;
+    let mut remaining = n.unsigned_abs();
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = result.checked_mul(&square).ok_or(NumericValueOutOfRange)?;
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            square = square.checked_mul(&square).ok_or(NumericValueOutOfRange)?;
+        }
+    }
+    if n < 0 {
+        result = Decimal::from(1)
+            .checked_div(&result)
+            .ok_or(NumericValueOutOfRange)?;
+    }
+    Ok(result)
+}
+
+#[inline(always)]
+pub fn sqrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if input.0 < 0.0 {
+        return Err(invalid_argument(
+            "cannot take square root of a negative number",
+        ));
+    }
+    Ok(input.0.sqrt().into())
+}
+
+#[inline(always)]
+pub fn sqrt_decimal(input: Decimal) -> Result<Decimal> {
+    f64_to_decimal(sqrt_f64(input.into())?.0)
+}
+
+#[inline(always)]
+pub fn cbrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.cbrt().into())
+}
+
+#[inline(always)]
+pub fn exp_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_float_result(input.0.exp(), input.0.is_finite(), true)
+}
+
+/// Unlike `exp_f64`, a result too small to be represented is zero rather than an underflow.
+#[inline(always)]
+pub fn exp_decimal(input: Decimal) -> Result<Decimal> {
+    let result = OrderedF64::from(input).0.exp();
+    if result.is_infinite() && matches!(input, Decimal::Normalized(_)) {
+        return Err(NumericValueOutOfRange.into());
+    }
+    f64_to_decimal(result)
+}
+
+fn check_log_input(input: f64) -> Result<()> {
+    if input == 0.0 {
+        Err(invalid_argument("cannot take logarithm of zero"))
+    } else if input < 0.0 {
+        Err(invalid_argument(
+            "cannot take logarithm of a negative number",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[inline(always)]
+pub fn ln_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_log_input(input.0)?;
+    Ok(input.0.ln().into())
+}
+
+#[inline(always)]
+pub fn ln_decimal(input: Decimal) -> Result<Decimal> {
+    f64_to_decimal(ln_f64(input.into())?.0)
+}
+
+#[inline(always)]
+pub fn log10_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_log_input(input.0)?;
+    Ok(input.0.log10().into())
+}
+
+#[inline(always)]
+pub fn log10_decimal(input: Decimal) -> Result<Decimal> {
+    f64_to_decimal(log10_f64(input.into())?.0)
+}
+
+/// The sign of `NaN` is zero, as in Postgres.
+#[inline(always)]
+pub fn sign_f64(input: OrderedF64) -> Result<OrderedF64> {
+    let sign = if input.0 > 0.0 {
+        1.0
+    } else if input.0 < 0.0 {
+        -1.0
+    } else {
+        0.0
+    };
+    Ok(sign.into())
+}
+
+/// The sign of `NaN` is `NaN`, as in Postgres.
+#[inline(always)]
+pub fn sign_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(match input {
+        Decimal::NaN => Decimal::NaN,
+        _ if input > Decimal::zero() => Decimal::from(1),
+        _ if input < Decimal::zero() => Decimal::from(-1),
+        _ => Decimal::zero(),
+    })
+}
+
+fn check_trigonometric_input(input: f64) -> Result<()> {
+    if input.is_infinite() {
+        return Err(invalid_argument("input is out of range"));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub fn sin_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_input(input.0)?;
+    Ok(input.0.sin().into())
+}
+
+#[inline(always)]
+pub fn cos_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_input(input.0)?;
+    Ok(input.0.cos().into())
+}
+
+#[inline(always)]
+pub fn tan_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_input(input.0)?;
+    Ok(input.0.tan().into())
+}
+
+/// The cotangent of zero is infinity.
+#[inline(always)]
+pub fn cot_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_trigonometric_input(input.0)?;
+    Ok((1.0 / input.0.tan()).into())
+}
+
+fn check_inverse_trigonometric_input(input: f64) -> Result<()> {
+    if !(-1.0..=1.0).contains(&input) && !input.is_nan() {
+        return Err(invalid_argument("input is out of range"));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+pub fn asin_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_inverse_trigonometric_input(input.0)?;
+    Ok(input.0.asin().into())
+}
+
+#[inline(always)]
+pub fn acos_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_inverse_trigonometric_input(input.0)?;
+    Ok(input.0.acos().into())
+}
+
+#[inline(always)]
+pub fn atan_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.atan().into())
+}
+
+#[inline(always)]
+pub fn atan2_f64(y: OrderedF64, x: OrderedF64) -> Result<OrderedF64> {
+    Ok(y.0.atan2(x.0).into())
+}
+
+#[inline(always)]
+pub fn degrees_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_float_result(input.0.to_degrees(), input.0.is_finite(), false)
+}
+
+#[inline(always)]
+pub fn radians_f64(input: OrderedF64) -> Result<OrderedF64> {
+    check_float_result(input.0.to_radians(), input.0.is_finite(), input.0 != 0.0)
+}
+
+/// Returns the number of the bucket `operand` falls in, among `count` buckets of the same width
+/// between `low` (inclusive) and `high` (exclusive). The buckets are numbered from 1, and values
+/// below or above the range fall in the bucket 0 or `count + 1`. `high` may be less than `low`
+/// for descending buckets. `fraction` gives how far `operand` is into the range from `low`.
+fn width_bucket<T: PartialOrd>(
+    operand: T,
+    low: T,
+    high: T,
+    count: i32,
+    fraction: impl FnOnce(T, T, T) -> Result<f64>,
+) -> Result<i32> {
+    if count <= 0 {
+        return Err(invalid_argument("count must be greater than zero"));
+    }
+    if low == high {
+        return Err(invalid_argument("lower bound cannot equal upper bound"));
+    }
+    let (below, above) = match low < high {
+        true => (operand < low, operand >= high),
+        false => (operand > low, operand <= high),
+    };
+    if below {
+        Ok(0)
+    } else if above {
+        count
+            .checked_add(1)
+            .ok_or_else(|| NumericValueOutOfRange.into())
+    } else {
+        // Rounding may put an operand just below `high` into the bucket above.
+        let bucket = (fraction(operand, low, high)? * count as f64).floor() as i32;
+        Ok(bucket.min(count - 1) + 1)
+    }
+}
+
+#[inline(always)]
+pub fn width_bucket_f64(
+    operand: OrderedF64,
+    low: OrderedF64,
+    high: OrderedF64,
+    count: i32,
+) -> Result<i32> {
+    if operand.0.is_nan() || low.0.is_nan() || high.0.is_nan() {
+        return Err(invalid_argument(
+            "operand, lower bound, and upper bound cannot be NaN",
+        ));
+    }
+    if low.0.is_infinite() || high.0.is_infinite() {
+        return Err(invalid_argument("lower and upper bounds must be finite"));
+    }
+    width_bucket(operand, low, high, count, |operand, low, high| {
+        let (operand, low, high) = (operand.0, low.0, high.0);
+        // Halve the values if the width of the range overflows.
+        Ok(match (high - low).is_finite() {
+            true => (operand - low) / (high - low),
+            false => (operand / 2.0 - low / 2.0) / (high / 2.0 - low / 2.0),
+        })
+    })
+}
+
+#[inline(always)]
+pub fn width_bucket_decimal(
+    operand: Decimal,
+    low: Decimal,
+    high: Decimal,
+    count: i32,
+) -> Result<i32> {
+    if [operand, low, high].contains(&Decimal::NaN) {
+        return Err(invalid_argument(
+            "operand, lower bound, and upper bound cannot be NaN",
+        ));
+    }
+    if !matches!(
+        (low, high),
+        (Decimal::Normalized(_), Decimal::Normalized(_))
+    ) {
+        return Err(invalid_argument("lower and upper bounds must be finite"));
+    }
+    width_bucket(operand, low, high, count, |operand, low, high| {
+        operand
+            .checked_sub(&low)
+            .zip(high.checked_sub(&low))
+            .and_then(|(offset, width)| offset.checked_div(&width))
+            .and_then(|fraction| fraction.to_f64())
+            .ok_or_else(|| NumericValueOutOfRange.into())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn f64(v: f64) -> OrderedF64 {
+        v.into()
+    }
+
+    fn decimal(v: &str) -> Decimal {
+        Decimal::from_str(v).unwrap()
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(pow_f64(f64(2.0), f64(10.0)).unwrap(), f64(1024.0));
+        assert_eq!(pow_f64(f64(-8.0), f64(3.0)).unwrap(), f64(-512.0));
+        assert_eq!(pow_f64(f64(f64::NAN), f64(0.0)).unwrap(), f64(1.0));
+        assert!(pow_f64(f64(0.0), f64(-1.0)).is_err());
+        assert!(pow_f64(f64(-8.0), f64(0.5)).is_err());
+        assert!(pow_f64(f64(10.0), f64(400.0)).is_err());
+        assert!(pow_f64(f64(10.0), f64(-400.0)).is_err());
+
+        assert_eq!(
+            pow_decimal(decimal("1.1"), decimal("2")).unwrap(),
+            decimal("1.21")
+        );
+        assert_eq!(
+            pow_decimal(decimal("2"), decimal("-2")).unwrap(),
+            decimal("0.25")
+        );
+        assert_eq!(
+            pow_decimal(decimal("4"), decimal("0.5")).unwrap(),
+            decimal("2")
+        );
+        assert!(pow_decimal(decimal("10"), decimal("100")).is_err());
+        assert!(pow_decimal(decimal("0"), decimal("-1")).is_err());
+    }
+
+    #[test]
+    fn test_exp_and_log() {
+        assert_eq!(sqrt_f64(f64(f64::INFINITY)).unwrap(), f64(f64::INFINITY));
+        assert!(sqrt_f64(f64(-1.0)).is_err());
+        assert_eq!(sqrt_decimal(decimal("6.25")).unwrap(), decimal("2.5"));
+        assert_eq!(sqrt_decimal(Decimal::NaN).unwrap(), Decimal::NaN);
+        assert!(sqrt_decimal(Decimal::NegativeINF).is_err());
+
+        assert_eq!(exp_f64(f64(f64::NEG_INFINITY)).unwrap(), f64(0.0));
+        assert!(exp_f64(f64(1000.0)).is_err());
+        assert!(exp_f64(f64(-1000.0)).is_err());
+        assert_eq!(exp_decimal(decimal("-1000")).unwrap(), Decimal::zero());
+        assert!(exp_decimal(decimal("1000")).is_err());
+
+        assert_eq!(ln_f64(f64(1.0)).unwrap(), f64(0.0));
+        assert!(ln_f64(f64(0.0)).is_err());
+        assert!(ln_decimal(decimal("-1")).is_err());
+        assert_eq!(log10_decimal(decimal("1000")).unwrap(), decimal("3"));
+        assert_eq!(
+            log10_decimal(Decimal::PositiveINF).unwrap(),
+            Decimal::PositiveINF
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(sign_f64(f64(-0.5)).unwrap(), f64(-1.0));
+        assert_eq!(sign_f64(f64(f64::NAN)).unwrap(), f64(0.0));
+        assert_eq!(sign_decimal(decimal("0.5")).unwrap(), decimal("1"));
+        assert_eq!(sign_decimal(Decimal::NegativeINF).unwrap(), decimal("-1"));
+        assert_eq!(sign_decimal(Decimal::NaN).unwrap(), Decimal::NaN);
+    }
+
+    #[test]
+    fn test_trigonometric() {
+        assert_eq!(sin_f64(f64(0.0)).unwrap(), f64(0.0));
+        assert!(sin_f64(f64(f64::INFINITY)).is_err());
+        assert_eq!(cot_f64(f64(0.0)).unwrap(), f64(f64::INFINITY));
+        assert_eq!(
+            asin_f64(f64(1.0)).unwrap(),
+            f64(std::f64::consts::FRAC_PI_2)
+        );
+        assert!(acos_f64(f64(1.5)).is_err());
+        assert!(acos_f64(f64(f64::NAN)).unwrap().0.is_nan());
+        assert_eq!(
+            atan2_f64(f64(1.0), f64(0.0)).unwrap(),
+            f64(std::f64::consts::FRAC_PI_2)
+        );
+        assert!((degrees_f64(f64(std::f64::consts::PI)).unwrap().0 - 180.0).abs() < 1e-9);
+        assert!((radians_f64(f64(180.0)).unwrap().0 - std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_width_bucket() {
+        let bucket = |operand: f64, low: f64, high: f64, count: i32| {
+            width_bucket_f64(f64(operand), f64(low), f64(high), count)
+        };
+        assert_eq!(bucket(5.35, 0.024, 10.06, 5).unwrap(), 3);
+        assert_eq!(bucket(-1.0, 0.0, 10.0, 5).unwrap(), 0);
+        assert_eq!(bucket(10.0, 0.0, 10.0, 5).unwrap(), 6);
+        assert_eq!(bucket(9.0, 10.0, 0.0, 5).unwrap(), 1);
+        assert_eq!(bucket(0.0, 10.0, 0.0, 5).unwrap(), 6);
+        assert_eq!(bucket(0.0, -f64::MAX, f64::MAX, 2).unwrap(), 2);
+        assert!(bucket(1.0, 0.0, 10.0, 0).is_err());
+        assert!(bucket(1.0, 1.0, 1.0, 5).is_err());
+        assert!(bucket(f64::NAN, 0.0, 10.0, 5).is_err());
+        assert!(bucket(1.0, 0.0, f64::INFINITY, 5).is_err());
+        assert!(bucket(10.0, 0.0, 1.0, i32::MAX).is_err());
+
+        assert_eq!(
+            width_bucket_decimal(decimal("5.35"), decimal("0.024"), decimal("10.06"), 5).unwrap(),
+            3
+        );
+        assert_eq!(
+            width_bucket_decimal(Decimal::PositiveINF, decimal("0"), decimal("10"), 5).unwrap(),
+            6
+        );
+        assert!(width_bucket_decimal(decimal("1"), decimal("0"), Decimal::PositiveINF, 5).is_err());
+    }
+}
@@ -12,27 +12,49 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod age;
 pub mod agg;
 pub mod arithmetic_op;
+pub mod array;
 pub mod array_access;
 pub mod ascii;
 pub mod bitwise_op;
+pub mod bytea;
 pub mod cast;
+pub mod chr;
 pub mod cmp;
+pub mod concat_op;
 pub mod conjunction;
+pub mod date_trunc;
 pub mod extract;
+pub mod format;
+pub mod initcap;
+pub mod jsonb;
+pub mod left_right;
 pub mod length;
 pub mod like;
 pub mod lower;
 pub mod ltrim;
+pub mod make_date;
+pub mod math;
 pub mod md5;
+pub mod overlay;
+pub mod pad;
 pub mod position;
+pub mod quote;
+pub mod regexp;
+pub mod repeat;
 pub mod replace;
+pub mod reverse;
 pub mod round;
 pub mod rtrim;
 pub mod split_part;
+pub mod starts_with;
 pub mod substr;
+pub mod timezone;
 pub mod to_char;
+pub mod to_hex;
+pub mod to_timestamp;
 pub mod translate;
 pub mod trim;
 pub mod tumble;
@@ -0,0 +1,93 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+/// `OVERLAY(s PLACING new_sub FROM start)`, which replaces as many characters as `new_sub` has.
+#[inline(always)]
+pub fn overlay(s: &str, new_sub: &str, start: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let count = new_sub.chars().count() as i32;
+    overlay_for(s, new_sub, start, count, writer)
+}
+
+/// `OVERLAY(s PLACING new_sub FROM start FOR count)`, which is equivalent to
+/// `substr(s, 1, start - 1) || new_sub || substr(s, start + count)`, so a negative `count` repeats
+/// characters before `start`.
+#[inline(always)]
+pub fn overlay_for(
+    s: &str,
+    new_sub: &str,
+    start: i32,
+    count: i32,
+    writer: BytesWriter,
+) -> Result<BytesGuard> {
+    if start <= 0 {
+        return Err(ErrorCode::InvalidParameterValue(
+            "negative substring length not allowed".into(),
+        )
+        .into());
+    }
+    let prefix_end = s
+        .char_indices()
+        .nth(start as usize - 1)
+        .map_or(s.len(), |(i, _)| i);
+    // 1-based position of the first character after the replaced ones.
+    let suffix_start = (start as i64 + count as i64).max(1) as usize;
+    let suffix_start = s
+        .char_indices()
+        .nth(suffix_start - 1)
+        .map_or(s.len(), |(i, _)| i);
+
+    let mut writer = writer.begin();
+    writer.write_ref(&s[..prefix_end])?;
+    writer.write_ref(new_sub)?;
+    writer.write_ref(&s[suffix_start..])?;
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_overlay() -> Result<()> {
+        let cases = [
+            ("Txxxxas", "hom", 2, None, "Thomxas"),
+            ("Txxxxas", "hom", 2, Some(4), "Thomas"),
+            ("abcdef", "x", 3, Some(-1), "abxbcdef"),
+            ("abcdef", "xy", 10, None, "abcdefxy"),
+            ("abcdef", "", 2, Some(2), "adef"),
+            ("奇点无限", "零", 2, Some(2), "奇零限"),
+        ];
+
+        for (s, new_sub, start, count, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = match count {
+                Some(count) => overlay_for(s, new_sub, start, count, writer)?,
+                None => overlay(s, new_sub, start, writer)?,
+            };
+            let array = guard.into_inner().finish()?;
+            let v = array.value_at(0).unwrap();
+            assert_eq!(v, expected);
+        }
+
+        let writer = Utf8ArrayBuilder::new(1)?.writer();
+        assert!(overlay("abc", "x", 0, writer).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::iter;
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+use super::repeat::MAX_LENGTH;
+
+/// Convert `length` to a count of characters, and reject it if the result could exceed
+/// [`MAX_LENGTH`] bytes, assuming the longest UTF-8 characters as Postgres does.
+fn checked_length(length: i32) -> Result<usize> {
+    let length = length.max(0) as usize;
+    if length.saturating_mul(4) > MAX_LENGTH {
+        return Err(ErrorCode::InvalidParameterValue("requested length too large".into()).into());
+    }
+    Ok(length)
+}
+
+/// Fill up the string to `length` characters by prepending the characters of `fill`, or truncate
+/// it on the right if it's already longer.
+#[inline(always)]
+pub fn lpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let length = checked_length(length)?;
+    let s_len = s.chars().count();
+    if s_len >= length || fill.is_empty() {
+        return writer.write_from_char_iter(s.chars().take(length));
+    }
+    let padding = iter::repeat(fill.chars()).flatten().take(length - s_len);
+    writer.write_from_char_iter(padding.chain(s.chars()))
+}
+
+/// Fill up the string to `length` characters by appending the characters of `fill`, or truncate
+/// it on the right if it's already longer.
+#[inline(always)]
+pub fn rpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let length = checked_length(length)?;
+    let s_len = s.chars().count();
+    if s_len >= length || fill.is_empty() {
+        return writer.write_from_char_iter(s.chars().take(length));
+    }
+    let padding = iter::repeat(fill.chars()).flatten().take(length - s_len);
+    writer.write_from_char_iter(s.chars().chain(padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_pad() -> Result<()> {
+        let cases = [
+            ("hi", 5, "xy", "xyxhi", "hixyx"),
+            ("hi", 5, " ", "   hi", "hi   "),
+            ("hello", 2, "xy", "he", "he"),
+            ("hi", 5, "", "hi", "hi"),
+            ("hi", -1, "xy", "", ""),
+            ("奇点", 4, "无限", "无限奇点", "奇点无限"),
+        ];
+
+        for (s, length, fill, expected_lpad, expected_rpad) in cases {
+            for (f, expected) in [
+                (lpad as fn(_, _, _, _) -> _, expected_lpad),
+                (rpad, expected_rpad),
+            ] {
+                let builder = Utf8ArrayBuilder::new(1)?;
+                let writer = builder.writer();
+                let guard = f(s, length, fill, writer)?;
+                let array = guard.into_inner().finish()?;
+                let v = array.value_at(0).unwrap();
+                assert_eq!(v, expected);
+            }
+        }
+
+        for f in [
+            lpad as fn(&str, i32, &str, BytesWriter) -> Result<BytesGuard>,
+            rpad,
+        ] {
+            let writer = Utf8ArrayBuilder::new(1)?.writer();
+            assert!(f("hi", i32::MAX, "xy", writer).is_err());
+        }
+        Ok(())
+    }
+}
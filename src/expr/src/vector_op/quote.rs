@@ -0,0 +1,110 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Result as FmtResult, Write};
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+/// Quotes the string to be used as an identifier in SQL, unless it only consists of lower case
+/// letters, digits and underscores and doesn't start with a digit. Unlike Postgres, key words
+/// are not quoted.
+#[inline(always)]
+pub fn quote_ident(s: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let mut writer = writer.begin();
+    write_quoted_ident(s, &mut writer).map_err(write_error)?;
+    writer.finish()
+}
+
+/// Quotes the string to be used as a string literal in SQL, with single quotes and backslashes
+/// doubled, and an `E` prefix if there are backslashes.
+#[inline(always)]
+pub fn quote_literal(s: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let mut writer = writer.begin();
+    write_quoted_literal(s, &mut writer).map_err(write_error)?;
+    writer.finish()
+}
+
+pub fn write_quoted_ident(s: &str, w: &mut impl Write) -> FmtResult {
+    let safe = s.starts_with(|c: char| c.is_ascii_lowercase() || c == '_')
+        && s.chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if safe {
+        return w.write_str(s);
+    }
+    w.write_char('"')?;
+    write_escaped(s, '"', w)?;
+    w.write_char('"')
+}
+
+pub fn write_quoted_literal(s: &str, w: &mut impl Write) -> FmtResult {
+    if s.contains('\\') {
+        w.write_char('E')?;
+    }
+    w.write_char('\'')?;
+    write_escaped(s, '\'', w)?;
+    w.write_char('\'')
+}
+
+/// Writes the string with every occurrence of `quote` and backslash doubled.
+fn write_escaped(s: &str, quote: char, w: &mut impl Write) -> FmtResult {
+    let escape_backslash = quote == '\'';
+    let mut start = 0;
+    for (i, c) in s.match_indices(|c: char| c == quote || (escape_backslash && c == '\\')) {
+        w.write_str(&s[start..i])?;
+        w.write_str(c)?;
+        w.write_str(c)?;
+        start = i + c.len();
+    }
+    w.write_str(&s[start..])
+}
+
+fn write_error(_: std::fmt::Error) -> RwError {
+    ErrorCode::InternalError("failed to write the quoted string".into()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_quote() -> Result<()> {
+        let cases = [
+            ("foo_bar1", "foo_bar1", "'foo_bar1'"),
+            ("Foo bar", "\"Foo bar\"", "'Foo bar'"),
+            ("1abc", "\"1abc\"", "'1abc'"),
+            ("a\"b", "\"a\"\"b\"", "'a\"b'"),
+            ("O'Reilly", "\"O'Reilly\"", "'O''Reilly'"),
+            ("C:\\dir", "\"C:\\dir\"", "E'C:\\\\dir'"),
+            ("", "\"\"", "''"),
+        ];
+
+        for (s, expected_ident, expected_literal) in cases {
+            for (f, expected) in [
+                (quote_ident as fn(_, _) -> _, expected_ident),
+                (quote_literal, expected_literal),
+            ] {
+                let builder = Utf8ArrayBuilder::new(1)?;
+                let writer = builder.writer();
+                let guard = f(s, writer)?;
+                let array = guard.into_inner().finish()?;
+                let v = array.value_at(0).unwrap();
+                assert_eq!(v, expected);
+            }
+        }
+        Ok(())
+    }
+}
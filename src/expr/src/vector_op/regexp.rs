@@ -0,0 +1,301 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::{Captures, Regex, RegexBuilder};
+use risingwave_common::array::ListValue;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::ScalarImpl;
+
+/// A compiled pattern together with the options given in its flags.
+#[derive(Debug, Clone)]
+pub struct RegexpContext {
+    pub regex: Regex,
+    /// Whether the `g` flag is given, i.e. all matches rather than only the first one are used.
+    pub global: bool,
+}
+
+impl RegexpContext {
+    /// Compiles `pattern` with the Postgres-style `flags`, e.g. `i` for case-insensitive
+    /// matching and `g` for global matching.
+    pub fn new(pattern: &str, flags: &str) -> Result<Self> {
+        let mut builder = RegexBuilder::new(pattern);
+        // Unlike the `regex` crate, `.` matches a newline in PG unless `n` is given.
+        builder.dot_matches_new_line(true);
+        let mut global = false;
+        for flag in flags.chars() {
+            match flag {
+                'i' => builder.case_insensitive(true),
+                'c' => builder.case_insensitive(false),
+                'n' | 'm' => builder.dot_matches_new_line(false).multi_line(true),
+                's' => builder.dot_matches_new_line(true).multi_line(false),
+                'x' => builder.ignore_whitespace(true),
+                'g' => {
+                    global = true;
+                    &mut builder
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidInputSyntax(format!(
+                        "invalid regular expression option: \"{}\"",
+                        flag
+                    ))
+                    .into())
+                }
+            };
+        }
+        let regex = builder.build().map_err(|e| {
+            ErrorCode::InvalidInputSyntax(format!("invalid regular expression: {}", e))
+        })?;
+        Ok(Self { regex, global })
+    }
+
+    /// Compiles the pattern of `SIMILAR TO`, see [`similar_to_regex`].
+    pub fn from_similar_to(pattern: &str) -> Result<Self> {
+        Self::new(&similar_to_regex(pattern), "")
+    }
+}
+
+/// Translates a `SIMILAR TO` pattern into a regular expression matching the entire string. `%`
+/// and `_` are the wildcards of `LIKE`, `.`, `^` and `$` are literal characters, and a backslash
+/// escapes the next character. The other metacharacters keep their meaning in regular
+/// expressions.
+pub fn similar_to_regex(pattern: &str) -> String {
+    let mut translated = String::with_capacity(pattern.len() + 6);
+    translated.push_str("^(?:");
+    let mut in_bracket = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) => {
+                    translated.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])))
+                }
+                None => translated.push_str("\\\\"),
+            },
+            _ if in_bracket => {
+                in_bracket = c != ']';
+                translated.push(c);
+            }
+            '[' => {
+                in_bracket = true;
+                translated.push(c);
+            }
+            '%' => translated.push_str(".*"),
+            '_' => translated.push('.'),
+            '.' | '^' | '$' => {
+                translated.push('\\');
+                translated.push(c);
+            }
+            _ => translated.push(c),
+        }
+    }
+    translated.push_str(")$");
+    translated
+}
+
+#[inline(always)]
+pub fn regexp_like(text: &str, ctx: &RegexpContext) -> bool {
+    ctx.regex.is_match(text)
+}
+
+/// Returns the captured substrings of the first match, or the whole match if the pattern has no
+/// parenthesized subexpressions.
+pub fn regexp_match(text: &str, ctx: &RegexpContext) -> Option<ListValue> {
+    ctx.regex
+        .captures(text)
+        .map(|captures| captures_to_list(&captures))
+}
+
+/// Returns the captured substrings of the first match, or of every match if `g` is given.
+pub fn regexp_matches(text: &str, ctx: &RegexpContext) -> Vec<ListValue> {
+    let captures = ctx.regex.captures_iter(text).map(|c| captures_to_list(&c));
+    if ctx.global {
+        captures.collect()
+    } else {
+        captures.take(1).collect()
+    }
+}
+
+fn captures_to_list(captures: &Captures<'_>) -> ListValue {
+    let values = if captures.len() == 1 {
+        vec![captures.get(0)]
+    } else {
+        captures.iter().skip(1).collect()
+    };
+    ListValue::new(
+        values
+            .into_iter()
+            .map(|m| m.map(|m| ScalarImpl::Utf8(m.as_str().to_string())))
+            .collect(),
+    )
+}
+
+/// Replaces the first match, or every match if `g` is given. In `replacement`, `\n` refers to
+/// the `n`-th captured substring and `\&` to the whole match.
+pub fn regexp_replace(text: &str, replacement: &str, ctx: &RegexpContext) -> String {
+    let limit = if ctx.global { 0 } else { 1 };
+    ctx.regex
+        .replacen(text, limit, |captures: &Captures<'_>| {
+            expand_replacement(captures, replacement)
+        })
+        .into_owned()
+}
+
+fn expand_replacement(captures: &Captures<'_>, replacement: &str) -> String {
+    let mut expanded = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            expanded.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('&') => expanded.push_str(&captures[0]),
+            Some(d @ '1'..='9') => {
+                let group = d.to_digit(10).unwrap() as usize;
+                if let Some(m) = captures.get(group) {
+                    expanded.push_str(m.as_str());
+                }
+            }
+            Some(other) => expanded.push(other),
+            None => expanded.push('\\'),
+        }
+    }
+    expanded
+}
+
+/// Splits `text` using the pattern as the delimiter. As in PG, an empty match at the beginning
+/// or the end of the string, or right after the previous match, does not split.
+pub fn regexp_split_to_array(text: &str, ctx: &RegexpContext) -> ListValue {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut prev_end = None;
+    for m in ctx.regex.find_iter(text) {
+        if m.start() == m.end()
+            && (m.start() == 0 || m.start() == text.len() || prev_end == Some(m.start()))
+        {
+            continue;
+        }
+        parts.push(&text[start..m.start()]);
+        start = m.end();
+        prev_end = Some(m.end());
+    }
+    parts.push(&text[start..]);
+    ListValue::new(
+        parts
+            .into_iter()
+            .map(|part| Some(ScalarImpl::Utf8(part.to_string())))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| ScalarImpl::Utf8(v.to_string())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_regexp_like() {
+        let ctx = RegexpContext::new("^a.c$", "").unwrap();
+        assert!(regexp_like("abc", &ctx));
+        assert!(regexp_like("a\nc", &ctx));
+        assert!(!regexp_like("ABC", &ctx));
+        assert!(regexp_like(
+            "ABC",
+            &RegexpContext::new("^a.c$", "i").unwrap()
+        ));
+        assert!(!regexp_like(
+            "a\nc",
+            &RegexpContext::new("^a.c$", "n").unwrap()
+        ));
+        assert!(RegexpContext::new("a", "q").is_err());
+        assert!(RegexpContext::new("(a", "").is_err());
+    }
+
+    #[test]
+    fn test_similar_to() {
+        for (pattern, text, expected) in [
+            ("abc", "abc", true),
+            ("a", "abc", false),
+            ("%(b|d)%", "abc", true),
+            ("(b|c)%", "abc", false),
+            ("a_c", "abc", true),
+            ("a.c", "abc", false),
+            ("a.c", "a.c", true),
+            ("a[b-d]+", "abdc", true),
+            ("a\\%", "a%", true),
+            ("a\\%", "ab", false),
+        ] {
+            let ctx = RegexpContext::from_similar_to(pattern).unwrap();
+            assert_eq!(regexp_like(text, &ctx), expected, "{} {}", pattern, text);
+        }
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let ctx = RegexpContext::new("(\\d+)-(x)?", "").unwrap();
+        assert_eq!(
+            regexp_match("a 12- b 3-x", &ctx),
+            Some(list(&[Some("12"), None]))
+        );
+        assert_eq!(regexp_match("no digits", &ctx), None);
+        let ctx = RegexpContext::new("b+", "").unwrap();
+        assert_eq!(regexp_match("abbc", &ctx), Some(list(&[Some("bb")])));
+
+        let ctx = RegexpContext::new("(\\d)", "g").unwrap();
+        assert_eq!(
+            regexp_matches("1a2", &ctx),
+            vec![list(&[Some("1")]), list(&[Some("2")])]
+        );
+        let ctx = RegexpContext::new("(\\d)", "").unwrap();
+        assert_eq!(regexp_matches("1a2", &ctx), vec![list(&[Some("1")])]);
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let ctx = RegexpContext::new("(o)(\\w)", "").unwrap();
+        assert_eq!(
+            regexp_replace("foobar obo", "[\\2\\1\\&]$", &ctx),
+            "f[oooo]$bar obo"
+        );
+        let ctx = RegexpContext::new("o", "g").unwrap();
+        assert_eq!(regexp_replace("foo bob", "0", &ctx), "f00 b0b");
+    }
+
+    #[test]
+    fn test_regexp_split_to_array() {
+        let ctx = RegexpContext::new("\\s+", "").unwrap();
+        assert_eq!(
+            regexp_split_to_array("hello  world x", &ctx),
+            list(&[Some("hello"), Some("world"), Some("x")])
+        );
+        let ctx = RegexpContext::new("", "").unwrap();
+        assert_eq!(
+            regexp_split_to_array("abc", &ctx),
+            list(&[Some("a"), Some("b"), Some("c")])
+        );
+        let ctx = RegexpContext::new("x*", "").unwrap();
+        assert_eq!(
+            regexp_split_to_array("axbc", &ctx),
+            list(&[Some("a"), Some("b"), Some("c")])
+        );
+    }
+}
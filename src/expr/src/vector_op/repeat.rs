@@ -0,0 +1,63 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+/// The maximum length in bytes of a string produced by `repeat`, `lpad` and `rpad`, which is 1 GB
+/// as in Postgres.
+pub(crate) const MAX_LENGTH: usize = (1 << 30) - 1;
+
+#[inline(always)]
+pub fn repeat(s: &str, count: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let count = count.max(0) as usize;
+    if s.len().saturating_mul(count) > MAX_LENGTH {
+        return Err(ErrorCode::InvalidParameterValue("requested length too large".into()).into());
+    }
+    let mut writer = writer.begin();
+    for _ in 0..count {
+        writer.write_ref(s)?;
+    }
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_repeat() -> Result<()> {
+        let cases = [
+            ("ab", 3, "ababab"),
+            ("ab", 0, ""),
+            ("ab", -1, ""),
+            ("", 5, ""),
+        ];
+
+        for (s, count, expected) in cases {
+            let builder = Utf8ArrayBuilder::new(1)?;
+            let writer = builder.writer();
+            let guard = repeat(s, count, writer)?;
+            let array = guard.into_inner().finish()?;
+            let v = array.value_at(0).unwrap();
+            assert_eq!(v, expected);
+        }
+
+        let writer = Utf8ArrayBuilder::new(1)?.writer();
+        assert!(repeat("abc", i32::MAX, writer).is_err());
+        Ok(())
+    }
+}
@@ -12,17 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use num_traits::{CheckedDiv, CheckedMul};
+use risingwave_common::error::ErrorCode::NumericValueOutOfRange;
 use risingwave_common::error::Result;
 use risingwave_common::types::{Decimal, OrderedF64};
 
+/// The largest power of ten a `Decimal` can hold.
+const MAX_DECIMAL_DIGITS: u32 = 28;
+
 #[inline(always)]
 pub fn round_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal> {
-    let digits = digits.into();
-    if digits < 0 {
-        Ok(Decimal::zero())
-    } else {
-        Ok(input.round_dp(digits as u32))
+    round_to_digits(input, digits.into(), Decimal::round_dp)
+}
+
+#[inline(always)]
+pub fn trunc_digits<D: Into<i32>>(input: Decimal, digits: D) -> Result<Decimal> {
+    round_to_digits(input, digits.into(), Decimal::trunc_dp)
+}
+
+/// Rounds `input` to `digits` places after the decimal point, or to the left of it if `digits`
+/// is negative, e.g. `round(1250.0, -2)` is `1300`.
+fn round_to_digits(
+    input: Decimal,
+    digits: i32,
+    round_dp: fn(&Decimal, u32) -> Decimal,
+) -> Result<Decimal> {
+    if digits >= 0 {
+        return Ok(round_dp(&input, digits as u32));
     }
+    if !matches!(input, Decimal::Normalized(_)) {
+        return Ok(input);
+    }
+    let shift = digits.unsigned_abs();
+    if shift > MAX_DECIMAL_DIGITS {
+        return Ok(Decimal::zero());
+    }
+    let factor = Decimal::from_i128_with_scale(10i128.pow(shift), 0);
+    input
+        .checked_div(&factor)
+        .map(|shifted| round_dp(&shifted, 0))
+        .and_then(|rounded| rounded.checked_mul(&factor))
+        .ok_or_else(|| NumericValueOutOfRange.into())
 }
 
 #[inline(always)]
@@ -56,6 +86,17 @@ pub fn round_f64(input: OrderedF64) -> Result<OrderedF64> {
 pub fn round_decimal(input: Decimal) -> Result<Decimal> {
     Ok(input.round_dp(0))
 }
+
+#[inline(always)]
+pub fn trunc_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(f64::trunc(input.0).into())
+}
+
+#[inline(always)]
+pub fn trunc_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.trunc_dp(0))
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -77,7 +118,30 @@ mod tests {
         do_test("21.666666666666666666666666667", 4, "21.6667");
         do_test("84818.33333333333333333333333", 4, "84818.3333");
         do_test("84818.15", 1, "84818.2");
-        do_test("21.372736", -1, "0");
+        do_test("21.372736", -1, "20");
+        do_test("1250.5", -2, "1300");
+        do_test("-1250.5", -2, "-1300");
+        do_test("1250.5", -30, "0");
+    }
+
+    #[test]
+    fn test_trunc() {
+        let trunc = |input: &str, digits: i32| {
+            trunc_digits(Decimal::from_str(input).unwrap(), digits)
+                .unwrap()
+                .to_string()
+        };
+        assert_eq!(trunc("42.4382", 2), "42.43");
+        assert_eq!(trunc("-42.8", 0), "-42");
+        assert_eq!(trunc("1299.9", -2), "1200");
+        assert_eq!(
+            trunc_decimal(Decimal::from_str("-42.8").unwrap()).unwrap(),
+            Decimal::from(-42)
+        );
+        assert_eq!(
+            trunc_f64(OrderedF64::from(-42.8)).unwrap(),
+            OrderedF64::from(-42.0)
+        );
     }
 
     #[test]
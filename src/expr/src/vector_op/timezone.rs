@@ -0,0 +1,169 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Duration, FixedOffset, LocalResult, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// A time zone given by its name, e.g. `Asia/Shanghai` or `UTC`, or by its offset from UTC, e.g.
+/// `+08:00`. Unlike the POSIX-style offsets of PG, a positive offset is east of Greenwich.
+enum Zone {
+    Named(Tz),
+    Fixed(FixedOffset),
+}
+
+impl Zone {
+    fn lookup(name: &str) -> Result<Self> {
+        if let Some(offset) = parse_utc_offset(name) {
+            return Ok(Self::Fixed(offset));
+        }
+        Tz::from_str_insensitive(name)
+            .map(Self::Named)
+            .map_err(|_| {
+                ErrorCode::InvalidInputSyntax(format!("time zone \"{}\" not recognized", name))
+                    .into()
+            })
+    }
+
+    fn offset_at_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Named(tz) => tz.offset_from_utc_datetime(utc).fix(),
+            Self::Fixed(offset) => *offset,
+        }
+    }
+
+    /// As in PG, an ambiguous local time takes the earlier offset, and a local time skipped by a
+    /// transition takes the offset before the transition.
+    fn offset_at_local(&self, local: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Named(tz) => match tz.offset_from_local_datetime(local) {
+                LocalResult::Single(offset) | LocalResult::Ambiguous(offset, _) => offset.fix(),
+                LocalResult::None => tz
+                    .offset_from_utc_datetime(&(*local - Duration::days(1)))
+                    .fix(),
+            },
+            Self::Fixed(offset) => *offset,
+        }
+    }
+}
+
+/// Parses `+HH`, `-HH`, `+HH:MM` or `-HH:MM`.
+fn parse_utc_offset(s: &str) -> Option<FixedOffset> {
+    let sign = match s.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let (hours, minutes) = match s[1..].split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None => (&s[1..], "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if !(0..60).contains(&minutes) {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Converts a `timestamp with time zone`, i.e. microseconds since the Unix epoch, to the UTC time.
+pub fn timestampz_to_utc(micros: i64) -> Result<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(
+        micros.div_euclid(1_000_000),
+        (micros.rem_euclid(1_000_000) * 1000) as u32,
+    )
+    .ok_or_else(|| ErrorCode::InvalidInputSyntax("timestamp out of range".to_string()).into())
+}
+
+/// Converts the UTC time to a `timestamp with time zone`.
+pub fn utc_to_timestampz(utc: NaiveDateTime) -> i64 {
+    utc.timestamp() * 1_000_000 + utc.timestamp_subsec_micros() as i64
+}
+
+/// `timestamp AT TIME ZONE zone` interprets the timestamp as the local time in the zone.
+#[inline(always)]
+pub fn timestamp_at_time_zone(input: NaiveDateTimeWrapper, zone: &str) -> Result<i64> {
+    let offset = Zone::lookup(zone)?.offset_at_local(&input.0);
+    let utc = input.0 - Duration::seconds(offset.local_minus_utc() as i64);
+    Ok(utc_to_timestampz(utc))
+}
+
+/// `timestamptz AT TIME ZONE zone` returns the local time in the zone.
+#[inline(always)]
+pub fn timestampz_at_time_zone(input: i64, zone: &str) -> Result<NaiveDateTimeWrapper> {
+    let utc = timestampz_to_utc(input)?;
+    let offset = Zone::lookup(zone)?.offset_at_utc(&utc);
+    Ok(NaiveDateTimeWrapper::new(
+        utc + Duration::seconds(offset.local_minus_utc() as i64),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn timestamp(s: &str) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap())
+    }
+
+    #[test]
+    fn test_fixed_offset() {
+        let local = timestamp("2022-01-01 08:00:00");
+        let micros = timestamp_at_time_zone(local, "+08:00").unwrap();
+        let utc = NaiveDate::from_ymd(2022, 1, 1).and_hms(0, 0, 0);
+        assert_eq!(micros, utc_to_timestampz(utc));
+        assert_eq!(timestampz_at_time_zone(micros, "+08").unwrap(), local);
+        assert_eq!(
+            timestampz_at_time_zone(micros, "-05:30").unwrap(),
+            timestamp("2021-12-31 18:30:00")
+        );
+        assert!(timestamp_at_time_zone(local, "+08:60").is_err());
+    }
+
+    #[test]
+    fn test_named_zone() {
+        let micros = timestamp_at_time_zone(timestamp("2022-07-01 12:00:00"), "utc").unwrap();
+        assert_eq!(
+            timestampz_at_time_zone(micros, "America/New_York").unwrap(),
+            timestamp("2022-07-01 08:00:00")
+        );
+        assert_eq!(
+            timestampz_at_time_zone(micros, "asia/shanghai").unwrap(),
+            timestamp("2022-07-01 20:00:00")
+        );
+        assert!(timestampz_at_time_zone(micros, "Mars/Olympus").is_err());
+
+        // 02:30 does not exist on the day daylight saving time starts, so the standard time is
+        // used, which is 03:30 in daylight saving time.
+        let micros =
+            timestamp_at_time_zone(timestamp("2022-03-13 02:30:00"), "America/New_York").unwrap();
+        assert_eq!(
+            timestampz_at_time_zone(micros, "America/New_York").unwrap(),
+            timestamp("2022-03-13 03:30:00")
+        );
+    }
+
+    #[test]
+    fn test_negative_micros() {
+        let utc = timestampz_to_utc(-1).unwrap();
+        assert_eq!(
+            utc,
+            NaiveDate::from_ymd(1969, 12, 31).and_hms_micro(23, 59, 59, 999_999)
+        );
+        assert_eq!(utc_to_timestampz(utc), -1);
+    }
+}
@@ -19,7 +19,7 @@ use risingwave_common::types::NaiveDateTimeWrapper;
 
 /// Compile the pg pattern to chrono pattern.
 // TODO: Chrono can not fully support the pg format, so consider using other implementations later.
-fn compile_pattern_to_chrono(tmpl: &str) -> String {
+pub(super) fn compile_pattern_to_chrono(tmpl: &str) -> String {
     // https://www.postgresql.org/docs/current/functions-formatting.html
     static PG_PATTERNS: &[&str] = &[
         "HH24", "HH12", "HH", "MI", "SS", "YYYY", "YY", "IYYY", "IY", "MM", "DD",
@@ -0,0 +1,51 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{LowerHex, Write};
+
+use risingwave_common::array::{BytesGuard, BytesWriter};
+use risingwave_common::error::{ErrorCode, Result};
+
+/// Converts the integer to its hexadecimal representation, where negative numbers are in two's
+/// complement as in Postgres, e.g. `to_hex(-1)` is `ffffffff`.
+#[inline(always)]
+pub fn to_hex<T: LowerHex>(n: T, writer: BytesWriter) -> Result<BytesGuard> {
+    let mut writer = writer.begin();
+    write!(writer, "{:x}", n).map_err(|e| ErrorCode::InternalError(e.to_string()))?;
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    fn to_hex_string<T: LowerHex>(n: T) -> Result<String> {
+        let builder = Utf8ArrayBuilder::new(1)?;
+        let guard = to_hex(n, builder.writer())?;
+        let array = guard.into_inner().finish()?;
+        Ok(array.value_at(0).unwrap().to_string())
+    }
+
+    #[test]
+    fn test_to_hex() -> Result<()> {
+        assert_eq!(to_hex_string(255i32)?, "ff");
+        assert_eq!(to_hex_string(0i32)?, "0");
+        assert_eq!(to_hex_string(-1i32)?, "ffffffff");
+        assert_eq!(to_hex_string(i64::MAX)?, "7fffffffffffffff");
+        assert_eq!(to_hex_string(-1i64)?, "ffffffffffffffff");
+        Ok(())
+    }
+}
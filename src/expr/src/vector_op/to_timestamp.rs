@@ -0,0 +1,110 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{NaiveDateTimeWrapper, NaiveDateWrapper, OrderedF64};
+
+use super::timezone::timestamp_at_time_zone;
+use super::to_char::compile_pattern_to_chrono;
+
+fn invalid_value(text: &str, tmpl: &str) -> RwError {
+    ErrorCode::InvalidInputSyntax(format!(
+        "invalid value \"{}\" for the format \"{}\"",
+        text, tmpl
+    ))
+    .into()
+}
+
+/// Parses `text` with a format of `to_char`. The time is midnight if the format has no time
+/// fields.
+fn parse_timestamp(text: &str, tmpl: &str) -> Result<NaiveDateTime> {
+    let chrono_tmpl = compile_pattern_to_chrono(tmpl);
+    NaiveDateTime::parse_from_str(text, &chrono_tmpl)
+        .or_else(|_| NaiveDate::parse_from_str(text, &chrono_tmpl).map(|d| d.and_hms(0, 0, 0)))
+        .map_err(|_| invalid_value(text, tmpl))
+}
+
+/// `to_timestamp(double precision)` converts seconds since the Unix epoch to a timestamp with
+/// time zone.
+#[inline(always)]
+pub fn to_timestamp(seconds: OrderedF64) -> Result<i64> {
+    let micros = (seconds.0 * 1e6).round();
+    if !micros.is_finite() || micros.abs() >= i64::MAX as f64 {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "timestamp out of range: \"{}\"",
+            seconds
+        ))
+        .into());
+    }
+    Ok(micros as i64)
+}
+
+/// `to_timestamp(text, format)`, where the local time parsed is in `zone`.
+#[inline(always)]
+pub fn to_timestamp1(text: &str, tmpl: &str, zone: &str) -> Result<i64> {
+    let local = parse_timestamp(text, tmpl)?;
+    timestamp_at_time_zone(NaiveDateTimeWrapper::new(local), zone)
+}
+
+#[inline(always)]
+pub fn to_date(text: &str, tmpl: &str) -> Result<NaiveDateWrapper> {
+    let chrono_tmpl = compile_pattern_to_chrono(tmpl);
+    NaiveDate::parse_from_str(text, &chrono_tmpl)
+        .or_else(|_| NaiveDateTime::parse_from_str(text, &chrono_tmpl).map(|ts| ts.date()))
+        .map(NaiveDateWrapper::new)
+        .map_err(|_| invalid_value(text, tmpl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::timezone::utc_to_timestampz;
+
+    #[test]
+    fn test_to_timestamp() {
+        assert_eq!(to_timestamp(1.5.into()).unwrap(), 1_500_000);
+        assert_eq!(to_timestamp((-0.25).into()).unwrap(), -250_000);
+        assert!(to_timestamp(f64::NAN.into()).is_err());
+        assert!(to_timestamp(f64::INFINITY.into()).is_err());
+    }
+
+    #[test]
+    fn test_to_timestamp1() {
+        let expected = utc_to_timestampz(NaiveDate::from_ymd(2022, 8, 17).and_hms(5, 30, 0));
+        assert_eq!(
+            to_timestamp1("2022-08-17 13:30:00", "YYYY-MM-DD HH24:MI:SS", "+08:00").unwrap(),
+            expected
+        );
+        let expected = utc_to_timestampz(NaiveDate::from_ymd(2022, 8, 17).and_hms(0, 0, 0));
+        assert_eq!(
+            to_timestamp1("17/08/2022", "DD/MM/YYYY", "UTC").unwrap(),
+            expected
+        );
+        assert!(to_timestamp1("2022-13-17", "YYYY-MM-DD", "UTC").is_err());
+    }
+
+    #[test]
+    fn test_to_date() {
+        assert_eq!(
+            to_date("2022/08/17", "YYYY/MM/DD").unwrap(),
+            NaiveDateWrapper::new(NaiveDate::from_ymd(2022, 8, 17))
+        );
+        assert_eq!(
+            to_date("2022-08-17 10:00", "YYYY-MM-DD HH24:MI").unwrap(),
+            NaiveDateWrapper::new(NaiveDate::from_ymd(2022, 8, 17))
+        );
+        assert!(to_date("17", "DD").is_err());
+    }
+}
@@ -15,9 +15,12 @@
 use std::collections::HashMap;
 use std::fmt::Display;
 
+use itertools::{Either, Itertools};
 use risingwave_common::catalog::Field;
 use risingwave_common::error::{ErrorCode, Result};
 
+use crate::expr::{CorrelatedInputRef, ExprImpl, ExprType, FunctionCall, InputRef};
+
 #[derive(Debug, Clone)]
 pub struct ColumnBinding {
     pub table_name: String,
@@ -37,6 +40,22 @@ impl ColumnBinding {
     }
 }
 
+/// A column merged from the columns of the same name on both sides of a `NATURAL` or `USING`
+/// join. Unqualified references to the name resolve to it, and it replaces the merged columns in
+/// the output of `*`.
+#[derive(Debug, Clone)]
+pub struct UsingColumn {
+    pub name: String,
+    /// Indices in `columns` of the columns merged into it.
+    pub merged: Vec<usize>,
+    /// Indices in `columns` of the columns whose first non-null value is its value. There is more
+    /// than one only if it's merged by a `FULL` join.
+    pub values: Vec<usize>,
+    /// Index in `columns` of the first column of the join, before which it's placed in the output
+    /// of `*`.
+    pub begin: usize,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum Clause {
     Where,
@@ -60,6 +79,8 @@ pub struct BindContext {
     pub indexs_of: HashMap<String, Vec<usize>>,
     // Mapping table name to [begin, end) of its columns.
     pub range_of: HashMap<String, (usize, usize)>,
+    // Columns merged by `NATURAL` or `USING` joins, where those of an outer join come first.
+    pub using_columns: Vec<UsingColumn>,
     // `clause` identifies in what clause we are binding.
     pub clause: Option<Clause>,
 }
@@ -111,6 +132,116 @@ impl BindContext {
     }
 }
 
+impl BindContext {
+    /// Finds the merged column named `column_name`. It's ambiguous if any other column of the name
+    /// is visible.
+    pub fn get_using_column(&self, column_name: &String) -> Result<Option<&UsingColumn>> {
+        let mut using_columns = self
+            .using_columns
+            .iter()
+            .filter(|using_column| &using_column.name == column_name);
+        let Some(using_column) = using_columns.next() else {
+            return Ok(None);
+        };
+        let is_ambiguous = using_columns.next().is_some()
+            || self.indexs_of[column_name]
+                .iter()
+                .any(|index| !using_column.merged.contains(index));
+        if is_ambiguous {
+            return Err(ErrorCode::InternalError("Ambiguous column name".into()).into());
+        }
+        Ok(Some(using_column))
+    }
+
+    /// Binds the first non-null value of the columns at `values`, in the context `depth` levels
+    /// above the current one.
+    pub fn bind_merged_column(&self, values: &[usize], depth: usize) -> Result<ExprImpl> {
+        let mut inputs = values
+            .iter()
+            .map(|&index| {
+                let column = &self.columns[index];
+                let data_type = column.field.data_type.clone();
+                match depth {
+                    0 => InputRef::new(column.index, data_type).into(),
+                    _ => CorrelatedInputRef::new(column.index, data_type, depth).into(),
+                }
+            })
+            .collect_vec();
+        match inputs.len() {
+            1 => Ok(inputs.pop().unwrap()),
+            _ => Ok(FunctionCall::new(ExprType::Coalesce, inputs)?.into()),
+        }
+    }
+
+    /// Lists the columns output by `*` over the columns in `[begin, end)`. The columns merged by
+    /// `NATURAL` or `USING` joins are replaced by the merged columns, which are placed before the
+    /// other columns of the joins.
+    pub fn wildcard_columns(
+        &self,
+        begin: usize,
+        end: usize,
+    ) -> Vec<Either<&ColumnBinding, &UsingColumn>> {
+        let mut output = vec![];
+        for (index, column) in self.columns.iter().enumerate().take(end).skip(begin) {
+            output.extend(
+                self.using_columns
+                    .iter()
+                    .filter(|using_column| using_column.begin == index)
+                    .map(Either::Right),
+            );
+            let is_merged = self
+                .using_columns
+                .iter()
+                .any(|using_column| using_column.merged.contains(&index));
+            if !column.is_hidden && !is_merged {
+                output.push(Either::Left(column));
+            }
+        }
+        output
+    }
+
+    /// Creates the context that a `LATERAL` item is bound in as an outer query. It has the columns
+    /// from `begin`, i.e. those of the preceding items it can refer to, indexed from 0 as they
+    /// are output by the left side of the join.
+    pub fn lateral_context(&self, begin: usize) -> BindContext {
+        let mut context = BindContext::new();
+        for column in &self.columns[begin..] {
+            context
+                .indexs_of
+                .entry(column.field.name.clone())
+                .or_default()
+                .push(context.columns.len());
+            context.columns.push(ColumnBinding {
+                index: column.index - begin,
+                ..column.clone()
+            });
+        }
+        context.range_of = self
+            .range_of
+            .iter()
+            .filter(|(_, (table_begin, _))| *table_begin >= begin)
+            .map(|(name, (table_begin, table_end))| {
+                (name.clone(), (table_begin - begin, table_end - begin))
+            })
+            .collect();
+        context.using_columns = self
+            .using_columns
+            .iter()
+            .filter(|using_column| using_column.begin >= begin)
+            .map(|using_column| {
+                let shift = |indices: &[usize]| indices.iter().map(|i| i - begin).collect();
+                UsingColumn {
+                    name: using_column.name.clone(),
+                    merged: shift(&using_column.merged),
+                    values: shift(&using_column.values),
+                    begin: using_column.begin - begin,
+                }
+            })
+            .collect();
+        context
+    }
+}
+
 impl BindContext {
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
@@ -118,6 +249,7 @@ impl BindContext {
             columns: Vec::new(),
             indexs_of: HashMap::new(),
             range_of: HashMap::new(),
+            using_columns: Vec::new(),
             clause: None,
         }
     }
@@ -0,0 +1,273 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_sqlparser::ast::Expr as AstExpr;
+
+use super::{Binder, BoundQuery, BoundSetExpr, BoundStatement, Relation};
+use crate::expr::{Expr, ExprImpl, ExprRewriter, Parameter, Subquery};
+
+/// Replaces each [`Parameter`] with the corresponding value, cast to the type of the parameter.
+struct ParamRewriter {
+    params: Vec<ExprImpl>,
+    error: Option<RwError>,
+}
+
+impl ExprRewriter for ParamRewriter {
+    fn rewrite_parameter(&mut self, parameter: Parameter) -> ExprImpl {
+        let value = match self.params.get(parameter.index() as usize - 1) {
+            Some(value) => value.clone(),
+            None => {
+                self.error.get_or_insert_with(|| {
+                    ErrorCode::BindError(format!(
+                        "no value supplied for parameter ${}",
+                        parameter.index()
+                    ))
+                    .into()
+                });
+                return parameter.into();
+            }
+        };
+        match value.cast_assign(parameter.return_type()) {
+            Ok(expr) => expr,
+            Err(err) => {
+                self.error.get_or_insert(err);
+                parameter.into()
+            }
+        }
+    }
+
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.query.rewrite_exprs(self);
+        subquery.into()
+    }
+}
+
+/// Collects the largest parameter index in the expressions, leaving them unchanged.
+#[derive(Default)]
+struct MaxParamIndex(u64);
+
+impl ExprRewriter for MaxParamIndex {
+    fn rewrite_parameter(&mut self, parameter: Parameter) -> ExprImpl {
+        self.0 = self.0.max(parameter.index());
+        parameter.into()
+    }
+
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.query.rewrite_exprs(self);
+        subquery.into()
+    }
+}
+
+impl Binder {
+    /// Bind the values given in `EXECUTE`, which must be constant.
+    pub fn bind_execute_params(&mut self, params: Vec<AstExpr>) -> Result<Vec<ExprImpl>> {
+        params
+            .into_iter()
+            .map(|param| {
+                let expr = self.bind_expr(param)?;
+                if !expr.is_const() {
+                    return Err(ErrorCode::BindError(format!(
+                        "parameter of EXECUTE must be a constant: {:?}",
+                        expr
+                    ))
+                    .into());
+                }
+                Ok(expr)
+            })
+            .collect()
+    }
+}
+
+impl BoundStatement {
+    /// Number of parameters the statement takes, i.e. the largest `n` of all `$n`.
+    pub fn param_count(&mut self) -> usize {
+        let mut visitor = MaxParamIndex::default();
+        self.rewrite_exprs(&mut visitor);
+        visitor.0 as usize
+    }
+
+    /// Substitute the parameters with `params`, where `$n` is replaced by the `n - 1`-th of them.
+    pub fn bind_parameters(mut self, params: Vec<ExprImpl>) -> Result<BoundStatement> {
+        let mut rewriter = ParamRewriter {
+            params,
+            error: None,
+        };
+        self.rewrite_exprs(&mut rewriter);
+        match rewriter.error {
+            Some(err) => Err(err),
+            None => Ok(self),
+        }
+    }
+
+    pub(super) fn rewrite_exprs(&mut self, rewriter: &mut impl ExprRewriter) {
+        match self {
+            BoundStatement::Insert(insert) => {
+                insert.source.rewrite_exprs(rewriter);
+                rewrite_all(&mut insert.cast_exprs, rewriter);
+            }
+            BoundStatement::Delete(delete) => {
+                rewrite_opt(&mut delete.selection, rewriter);
+            }
+            BoundStatement::Update(update) => {
+                update.table.rewrite_exprs(rewriter);
+                rewrite_opt(&mut update.selection, rewriter);
+                rewrite_all(&mut update.exprs, rewriter);
+            }
+            BoundStatement::Query(query) => query.rewrite_exprs(rewriter),
+        }
+    }
+}
+
+impl BoundQuery {
+    pub(super) fn rewrite_exprs(&mut self, rewriter: &mut impl ExprRewriter) {
+        self.body.rewrite_exprs(rewriter);
+        rewrite_all(&mut self.extra_order_exprs, rewriter);
+    }
+}
+
+impl BoundSetExpr {
+    fn rewrite_exprs(&mut self, rewriter: &mut impl ExprRewriter) {
+        match self {
+            BoundSetExpr::Select(select) => {
+                if let Some(from) = &mut select.from {
+                    from.rewrite_exprs(rewriter);
+                }
+                rewrite_all(&mut select.select_items, rewriter);
+                rewrite_opt(&mut select.where_clause, rewriter);
+                rewrite_all(&mut select.group_by, rewriter);
+                rewrite_opt(&mut select.having, rewriter);
+            }
+            BoundSetExpr::Values(values) => {
+                for row in &mut values.rows {
+                    rewrite_all(row, rewriter);
+                }
+            }
+            BoundSetExpr::SetOperation { left, right, .. } => {
+                left.rewrite_exprs(rewriter);
+                right.rewrite_exprs(rewriter);
+            }
+            BoundSetExpr::RecursiveUnion {
+                base, recursive, ..
+            } => {
+                base.rewrite_exprs(rewriter);
+                recursive.rewrite_exprs(rewriter);
+            }
+        }
+    }
+}
+
+impl Relation {
+    pub(super) fn rewrite_exprs(&mut self, rewriter: &mut impl ExprRewriter) {
+        match self {
+            Relation::Source(_)
+            | Relation::BaseTable(_)
+            | Relation::SystemTable(_)
+            | Relation::WorkTable(_) => {}
+            Relation::Subquery(subquery) => subquery.query.rewrite_exprs(rewriter),
+            Relation::Join(join) => {
+                join.left.rewrite_exprs(rewriter);
+                join.right.rewrite_exprs(rewriter);
+                rewrite(&mut join.cond, rewriter);
+            }
+            Relation::WindowTableFunction(window) => {
+                window.input.rewrite_exprs(rewriter);
+                rewrite_all(&mut window.args, rewriter);
+            }
+            Relation::TableFunction(table_function) => {
+                rewrite_all(&mut table_function.args, rewriter);
+            }
+        }
+    }
+}
+
+fn rewrite(expr: &mut ExprImpl, rewriter: &mut impl ExprRewriter) {
+    let dummy = ExprImpl::literal_bool(false);
+    *expr = rewriter.rewrite_expr(std::mem::replace(expr, dummy));
+}
+
+fn rewrite_opt(expr: &mut Option<ExprImpl>, rewriter: &mut impl ExprRewriter) {
+    if let Some(expr) = expr {
+        rewrite(expr, rewriter);
+    }
+}
+
+fn rewrite_all(exprs: &mut [ExprImpl], rewriter: &mut impl ExprRewriter) {
+    for expr in exprs {
+        rewrite(expr, rewriter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::DataType;
+    use risingwave_sqlparser::parser::Parser;
+
+    use super::*;
+    use crate::binder::test_utils::mock_binder;
+
+    fn bind(sql: &str, param_types: Vec<DataType>) -> BoundStatement {
+        let stmt = Parser::parse_sql(sql).unwrap().remove(0);
+        let mut binder = mock_binder();
+        binder.param_types = Some(param_types);
+        Binder::bind(&mut binder, stmt).unwrap()
+    }
+
+    fn select_items(stmt: BoundStatement) -> Vec<ExprImpl> {
+        match stmt {
+            BoundStatement::Query(query) => match query.body {
+                BoundSetExpr::Select(select) => select.select_items,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_parameter_type_inference() {
+        let mut stmt = bind("select $1 + 1, $2, $3::int", vec![DataType::Int64]);
+        assert_eq!(stmt.param_count(), 3);
+        let types = select_items(stmt)
+            .iter()
+            .map(|expr| expr.return_type())
+            .collect::<Vec<_>>();
+        // `$1` is declared, `$2` has no context to infer its type, and `$3` takes the cast type.
+        assert_eq!(
+            types,
+            vec![DataType::Int64, DataType::Varchar, DataType::Int32]
+        );
+
+        let stmt = bind("select 1 = $1", vec![]);
+        match &select_items(stmt)[0] {
+            ExprImpl::FunctionCall(func_call) => {
+                assert_eq!(func_call.inputs()[1].return_type(), DataType::Int32)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_bind_parameters() {
+        let stmt = bind("select $1 + 1", vec![]);
+        let stmt = stmt
+            .bind_parameters(vec![ExprImpl::literal_int(41)])
+            .unwrap();
+        assert!(!select_items(stmt)[0].has_parameter());
+
+        let stmt = bind("select $2", vec![]);
+        assert!(stmt
+            .bind_parameters(vec![ExprImpl::literal_int(1)])
+            .is_err());
+    }
+}
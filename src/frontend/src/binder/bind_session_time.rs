@@ -0,0 +1,194 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::BoundStatement;
+use crate::expr::{Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, Literal, Subquery};
+
+/// Resolves the functions that depend on the current time or the session time zone, so that the
+/// plan gives the same result wherever it is evaluated:
+/// - `now()` becomes a `timestamp with time zone` constant, unless `now` is `None`.
+/// - A conversion between `timestamp with time zone` and a local time, explicit or not, is done in
+///   the session time zone, e.g. `ts::timestamptz` becomes `ts AT TIME ZONE 'UTC'`.
+struct SessionTimeRewriter {
+    now: Option<i64>,
+    time_zone: String,
+}
+
+impl SessionTimeRewriter {
+    fn time_zone(&self) -> ExprImpl {
+        Literal::new(
+            Some(ScalarImpl::Utf8(self.time_zone.clone())),
+            DataType::Varchar,
+        )
+        .into()
+    }
+
+    fn at_time_zone(input: ExprImpl, time_zone: ExprImpl) -> ExprImpl {
+        let return_type = match input.return_type() {
+            DataType::Timestampz => DataType::Timestamp,
+            _ => DataType::Timestampz,
+        };
+        FunctionCall::new_unchecked(ExprType::AtTimeZone, vec![input, time_zone], return_type)
+            .into()
+    }
+
+    fn cast(input: ExprImpl, target: DataType) -> ExprImpl {
+        FunctionCall::new_unchecked(ExprType::Cast, vec![input], target).into()
+    }
+
+    fn rewrite_cast(&self, input: ExprImpl, target: DataType) -> ExprImpl {
+        match (input.return_type(), &target) {
+            (DataType::Timestamp, DataType::Timestampz)
+            | (DataType::Timestampz, DataType::Timestamp) => {
+                Self::at_time_zone(input, self.time_zone())
+            }
+            (DataType::Date, DataType::Timestampz) => {
+                Self::at_time_zone(Self::cast(input, DataType::Timestamp), self.time_zone())
+            }
+            (DataType::Timestampz, DataType::Date | DataType::Time) => {
+                Self::cast(Self::at_time_zone(input, self.time_zone()), target)
+            }
+            _ => Self::cast(input, target),
+        }
+    }
+}
+
+fn is_epoch(field: &ExprImpl) -> bool {
+    matches!(field, ExprImpl::Literal(literal)
+        if matches!(literal.get_data(), Some(ScalarImpl::Utf8(field)) if field == "EPOCH"))
+}
+
+impl ExprRewriter for SessionTimeRewriter {
+    fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        let (func_type, inputs, ret) = func_call.decompose();
+        let mut inputs: Vec<ExprImpl> = inputs
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        let is_timestampz = |expr: &ExprImpl| expr.return_type() == DataType::Timestampz;
+        match func_type {
+            ExprType::Now if let Some(now) = self.now => {
+                return Literal::new(Some(ScalarImpl::Int64(now)), DataType::Timestampz).into()
+            }
+            ExprType::Cast => return self.rewrite_cast(inputs.pop().unwrap(), ret),
+            ExprType::DateTrunc if inputs.len() == 2 && is_timestampz(&inputs[1]) => {
+                inputs.push(self.time_zone())
+            }
+            ExprType::ToTimestamp1 if inputs.len() == 2 => inputs.push(self.time_zone()),
+            ExprType::Extract if is_timestampz(&inputs[1]) => {
+                // The epoch is the same in every time zone, so the UTC time is used.
+                let time_zone = if is_epoch(&inputs[0]) {
+                    Literal::new(Some(ScalarImpl::Utf8("UTC".into())), DataType::Varchar).into()
+                } else {
+                    self.time_zone()
+                };
+                let input = inputs.pop().unwrap();
+                inputs.push(Self::at_time_zone(input, time_zone));
+            }
+            ExprType::Age | ExprType::ToChar if is_timestampz(&inputs[0]) => {
+                let count = if func_type == ExprType::Age { 2 } else { 1 };
+                for input in &mut inputs[..count] {
+                    let dummy = ExprImpl::literal_bool(false);
+                    let timestampz = std::mem::replace(input, dummy);
+                    *input = Self::at_time_zone(timestampz, self.time_zone());
+                }
+            }
+            _ => {}
+        }
+        FunctionCall::new_unchecked(func_type, inputs, ret).into()
+    }
+
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.query.rewrite_exprs(self);
+        subquery.into()
+    }
+}
+
+impl BoundStatement {
+    /// Resolve `now()` to `now`, in microseconds since the Unix epoch, and the conversions of
+    /// `timestamp with time zone` to `time_zone`.
+    ///
+    /// `now()` is kept if `now` is `None`, which is the case for streaming queries, where fixing it
+    /// at creation would make the view wrong as time passes. The planner compares the input with
+    /// the value of a [`crate::optimizer::plan_node::LogicalNow`] instead.
+    pub fn bind_session_time(mut self, now: Option<i64>, time_zone: &str) -> Self {
+        let mut rewriter = SessionTimeRewriter {
+            now,
+            time_zone: time_zone.to_string(),
+        };
+        self.rewrite_exprs(&mut rewriter);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::InputRef;
+
+    fn rewrite(expr: ExprImpl) -> ExprImpl {
+        let mut rewriter = SessionTimeRewriter {
+            now: Some(1_000_000),
+            time_zone: "Asia/Shanghai".to_string(),
+        };
+        rewriter.rewrite_expr(expr)
+    }
+
+    #[test]
+    fn test_rewrite_now() {
+        let now = ExprImpl::from(FunctionCall::new(ExprType::Now, vec![]).unwrap());
+        assert_eq!(
+            rewrite(now.clone()),
+            ExprImpl::from(Literal::new(
+                Some(ScalarImpl::Int64(1_000_000)),
+                DataType::Timestampz
+            ))
+        );
+
+        let mut rewriter = SessionTimeRewriter {
+            now: None,
+            time_zone: "UTC".to_string(),
+        };
+        assert_eq!(rewriter.rewrite_expr(now.clone()), now);
+    }
+
+    #[test]
+    fn test_rewrite_cast() {
+        let time_zone: ExprImpl = Literal::new(
+            Some(ScalarImpl::Utf8("Asia/Shanghai".into())),
+            DataType::Varchar,
+        )
+        .into();
+        let timestampz: ExprImpl = InputRef::new(0, DataType::Timestampz).into();
+        let date = timestampz.clone().cast_explicit(DataType::Date).unwrap();
+        assert_eq!(
+            rewrite(date),
+            ExprImpl::from(FunctionCall::new_unchecked(
+                ExprType::Cast,
+                vec![
+                    FunctionCall::new(ExprType::AtTimeZone, vec![timestampz, time_zone])
+                        .unwrap()
+                        .into()
+                ],
+                DataType::Date,
+            ))
+        );
+
+        let varchar = ExprImpl::from(InputRef::new(1, DataType::Varchar));
+        let cast = varchar.cast_explicit(DataType::Int32).unwrap();
+        assert_eq!(rewrite(cast.clone()), cast);
+    }
+}
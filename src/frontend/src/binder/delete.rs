@@ -18,7 +18,7 @@ use risingwave_sqlparser::ast::{Expr, ObjectName};
 use super::{Binder, BoundBaseTable, BoundTableSource};
 use crate::expr::ExprImpl;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundDelete {
     /// Used for injecting deletion chunks to the source.
     pub table_source: BoundTableSource,
@@ -35,7 +35,7 @@ impl Binder {
         source_name: ObjectName,
         selection: Option<Expr>,
     ) -> Result<BoundDelete> {
-        let (schema_name, table_name) = Self::resolve_table_name(source_name.clone())?;
+        let (schema_name, table_name) = self.resolve_relation_name(source_name.clone())?;
         let table_source = self.bind_table_source(source_name)?;
         let table = self.bind_table(&schema_name, &table_name, None)?;
         let delete = BoundDelete {
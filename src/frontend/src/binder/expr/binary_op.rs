@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{BinaryOperator, Expr};
 
 use crate::binder::Binder;
-use crate::expr::{ExprImpl, ExprType, FunctionCall};
+use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall};
 
 impl Binder {
     pub(super) fn bind_binary_op(
@@ -25,6 +26,15 @@ impl Binder {
         op: BinaryOperator,
         right: Expr,
     ) -> Result<ExprImpl> {
+        let right = match right {
+            Expr::AnySubquery(query) => {
+                return self.bind_quantified_subquery(left, op, *query, false)
+            }
+            Expr::AllSubquery(query) => {
+                return self.bind_quantified_subquery(left, op, *query, true)
+            }
+            right => right,
+        };
         let bound_left = self.bind_expr(left)?;
         let bound_right = self.bind_expr(right)?;
         let func_type = match op {
@@ -42,23 +52,101 @@ impl Binder {
             BinaryOperator::And => ExprType::And,
             BinaryOperator::Or => ExprType::Or,
             BinaryOperator::Like => ExprType::Like,
-            BinaryOperator::NotLike => return self.bind_not_like(bound_left, bound_right),
+            BinaryOperator::NotLike => {
+                return self.bind_negated(ExprType::Like, vec![bound_left, bound_right])
+            }
+            BinaryOperator::ILike => ExprType::Ilike,
+            BinaryOperator::NotILike => {
+                return self.bind_negated(ExprType::Ilike, vec![bound_left, bound_right])
+            }
+            BinaryOperator::StringConcat => return Self::bind_concat_op(bound_left, bound_right),
+            BinaryOperator::SimilarTo => ExprType::SimilarTo,
+            BinaryOperator::NotSimilarTo => {
+                return self.bind_negated(ExprType::SimilarTo, vec![bound_left, bound_right])
+            }
+            BinaryOperator::PGRegexMatch => ExprType::RegexpLike,
+            BinaryOperator::PGRegexIMatch => {
+                return Ok(FunctionCall::new(
+                    ExprType::RegexpLike,
+                    vec![
+                        bound_left,
+                        bound_right,
+                        ExprImpl::literal_varchar("i".into()),
+                    ],
+                )?
+                .into())
+            }
+            BinaryOperator::PGRegexNotMatch => {
+                return self.bind_negated(ExprType::RegexpLike, vec![bound_left, bound_right])
+            }
+            BinaryOperator::PGRegexNotIMatch => {
+                return self.bind_negated(
+                    ExprType::RegexpLike,
+                    vec![
+                        bound_left,
+                        bound_right,
+                        ExprImpl::literal_varchar("i".into()),
+                    ],
+                )
+            }
             BinaryOperator::BitwiseOr => ExprType::BitwiseOr,
             BinaryOperator::BitwiseAnd => ExprType::BitwiseAnd,
             BinaryOperator::PGBitwiseXor => ExprType::BitwiseXor,
             BinaryOperator::PGBitwiseShiftLeft => ExprType::BitwiseShiftLeft,
             BinaryOperator::PGBitwiseShiftRight => ExprType::BitwiseShiftRight,
+            BinaryOperator::Arrow => ExprType::JsonbAccessInner,
+            BinaryOperator::LongArrow => ExprType::JsonbAccessStr,
+            BinaryOperator::HashArrow => ExprType::JsonbAccessPath,
+            BinaryOperator::HashLongArrow => ExprType::JsonbAccessPathStr,
+            BinaryOperator::AtArrow
+                if matches!(bound_left.return_type(), DataType::List { .. }) =>
+            {
+                ExprType::ArrayContains
+            }
+            BinaryOperator::ArrowAt => ExprType::ArrayContained,
+            BinaryOperator::PGOverlap => ExprType::ArrayOverlap,
+            BinaryOperator::AtArrow => {
+                // Like a string literal of unknown type in PG, the right side takes `jsonb`.
+                let bound_right = bound_right.cast_assign(DataType::Jsonb)?;
+                return Ok(FunctionCall::new(
+                    ExprType::JsonbContains,
+                    vec![bound_left, bound_right],
+                )?
+                .into());
+            }
+            BinaryOperator::Question => ExprType::JsonbExists,
 
             _ => return Err(ErrorCode::NotImplemented(format!("{:?}", op), 112.into()).into()),
         };
         Ok(FunctionCall::new(func_type, vec![bound_left, bound_right])?.into())
     }
 
-    /// Apply a NOT on top of LIKE.
-    fn bind_not_like(&mut self, left: ExprImpl, right: ExprImpl) -> Result<ExprImpl> {
+    /// `||` concatenates arrays, an array with an element, or strings. A string can also be
+    /// concatenated with a value of any other type, which is then cast to a string.
+    fn bind_concat_op(left: ExprImpl, right: ExprImpl) -> Result<ExprImpl> {
+        let is_array = |e: &ExprImpl| matches!(e.return_type(), DataType::List { .. });
+        let (func_type, inputs) = match (is_array(&left), is_array(&right)) {
+            (true, true) => (ExprType::ArrayCat, vec![left, right]),
+            (true, false) => (ExprType::ArrayAppend, vec![left, right]),
+            (false, true) => (ExprType::ArrayPrepend, vec![left, right]),
+            (false, false) => {
+                let inputs = match (left.return_type(), right.return_type()) {
+                    (DataType::Varchar, DataType::Varchar) => vec![left, right],
+                    (DataType::Varchar, _) => vec![left, right.cast_explicit(DataType::Varchar)?],
+                    (_, DataType::Varchar) => vec![left.cast_explicit(DataType::Varchar)?, right],
+                    _ => vec![left, right],
+                };
+                (ExprType::ConcatOp, inputs)
+            }
+        };
+        Ok(FunctionCall::new(func_type, inputs)?.into())
+    }
+
+    /// Apply a NOT on top of a predicate like LIKE or `~`.
+    fn bind_negated(&mut self, func_type: ExprType, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
         Ok(FunctionCall::new(
             ExprType::Not,
-            vec![FunctionCall::new(ExprType::Like, vec![left, right])?.into()],
+            vec![FunctionCall::new(func_type, inputs)?.into()],
         )?
         .into())
     }
@@ -32,6 +32,10 @@ impl Binder {
             }
         };
 
+        // An unqualified name may refer to a column merged by a `NATURAL` or `USING` join.
+        if table_name.is_none() && let Ok(Some(using_column)) = self.context.get_using_column(column_name) {
+            return self.context.bind_merged_column(&using_column.values, 0);
+        }
         if let Ok(index) = self
             .context
             .get_column_binding_index(table_name, column_name)
@@ -45,6 +49,9 @@ impl Binder {
         for (i, context) in self.upper_contexts.iter().rev().enumerate() {
             // `depth` starts from 1.
             let depth = i + 1;
+            if table_name.is_none() && let Ok(Some(using_column)) = context.get_using_column(column_name) {
+                return context.bind_merged_column(&using_column.values, depth);
+            }
             match context.get_column_binding_index(table_name, column_name) {
                 Ok(index) => {
                     let column = &context.columns[index];
@@ -13,17 +13,30 @@
 // limitations under the License.
 
 use itertools::Itertools;
-use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_expr::expr::AggKind;
-use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr};
+use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr, WindowSpec};
 
 use crate::binder::bind_context::Clause;
 use crate::binder::Binder;
-use crate::expr::{AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal};
+use crate::catalog::system_catalog::pg_catalog::{
+    type_oids_with_format_names, PG_CATALOG_SCHEMA_NAME,
+};
+use crate::expr::{
+    AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal, TableFunction, TableFunctionType,
+    WindowFunction, WindowFunctionType,
+};
+use crate::optimizer::property::Direction;
 
 impl Binder {
-    pub(super) fn bind_function(&mut self, f: Function) -> Result<ExprImpl> {
+    pub(super) fn bind_function(&mut self, mut f: Function) -> Result<ExprImpl> {
+        // Built-in functions can be qualified with `pg_catalog`, as psql does.
+        if let [schema_name, _] = f.name.0.as_slice()
+            && schema_name.value.eq_ignore_ascii_case(PG_CATALOG_SCHEMA_NAME)
+        {
+            f.name.0.remove(0);
+        }
         let mut inputs = f
             .args
             .into_iter()
@@ -34,6 +47,9 @@ impl Binder {
         if f.name.0.len() == 1 {
             let function_name = f.name.0.get(0).unwrap().value.as_str();
             let function_name = function_name.to_lowercase();
+            if let Some(window_spec) = f.over {
+                return self.bind_window_function(&function_name, inputs, window_spec);
+            }
             let agg_kind = match function_name.as_str() {
                 "count" => Some(AggKind::Count),
                 "sum" => Some(AggKind::Sum),
@@ -46,10 +62,27 @@ impl Binder {
             };
             if let Some(kind) = agg_kind {
                 self.ensure_aggregate_allowed()?;
+                if inputs.iter().any(ExprImpl::has_table_function) {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "aggregate function calls cannot contain set-returning function calls"
+                            .to_string(),
+                    )
+                    .into());
+                }
+                if inputs.iter().any(ExprImpl::has_window_function) {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "aggregate function calls cannot contain window function calls".to_string(),
+                    )
+                    .into());
+                }
                 return Ok(ExprImpl::AggCall(Box::new(AggCall::new(
                     kind, inputs, f.distinct,
                 )?)));
             }
+            if let Some(function_type) = TableFunctionType::from_name(&function_name) {
+                self.ensure_set_returning_function_allowed(&function_name)?;
+                return Ok(TableFunction::new(function_type, inputs)?.into());
+            }
             let function_type = match function_name.as_str() {
                 "substr" => ExprType::Substr,
                 "length" => ExprType::Length,
@@ -61,14 +94,87 @@ impl Binder {
                 "ltrim" => ExprType::Ltrim,
                 "rtrim" => ExprType::Rtrim,
                 "md5" => ExprType::Md5,
+                "ascii" => ExprType::Ascii,
+                "translate" => ExprType::Translate,
+                "concat" => {
+                    inputs = Self::rewrite_concat_to_concat_ws(inputs)?;
+                    ExprType::ConcatWs
+                }
+                "lpad" | "rpad" => {
+                    if inputs.len() == 2 {
+                        inputs.push(ExprImpl::literal_varchar(" ".into()));
+                    }
+                    match function_name.as_str() {
+                        "lpad" => ExprType::Lpad,
+                        _ => ExprType::Rpad,
+                    }
+                }
+                "left" => ExprType::Left,
+                "right" => ExprType::Right,
+                "repeat" => ExprType::Repeat,
+                "reverse" => ExprType::Reverse,
+                "initcap" => ExprType::Initcap,
+                "strpos" => ExprType::Position,
+                "starts_with" => ExprType::StartsWith,
+                "chr" => ExprType::Chr,
+                "format" => ExprType::Format,
+                "quote_ident" => ExprType::QuoteIdent,
+                "quote_literal" => {
+                    inputs = inputs
+                        .into_iter()
+                        .map(|input| input.cast_explicit(DataType::Varchar))
+                        .try_collect()?;
+                    ExprType::QuoteLiteral
+                }
+                "to_hex" => ExprType::ToHex,
+                "encode" => ExprType::Encode,
+                "decode" => ExprType::Decode,
+                "sha256" => ExprType::Sha256,
                 "to_char" => ExprType::ToChar,
+                "date_trunc" => ExprType::DateTrunc,
+                "date_part" => return Self::bind_date_part(inputs),
+                "to_timestamp" => {
+                    if inputs.len() == 1 {
+                        inputs = vec![inputs.pop().unwrap().cast_implicit(DataType::Float64)?];
+                        ExprType::ToTimestamp
+                    } else {
+                        ExprType::ToTimestamp1
+                    }
+                }
+                "to_date" => ExprType::ToDate,
+                "make_date" => ExprType::MakeDate,
+                "age" => {
+                    inputs = Self::rewrite_age_args(inputs)?;
+                    ExprType::Age
+                }
+                "now" | "current_timestamp" | "transaction_timestamp" | "statement_timestamp" => {
+                    ExprType::Now
+                }
+                "current_date" => return Self::bind_current_time(inputs, DataType::Date),
+                "localtimestamp" => return Self::bind_current_time(inputs, DataType::Timestamp),
+                "current_time" | "localtime" => {
+                    return Self::bind_current_time(inputs, DataType::Time)
+                }
+                "jsonb_typeof" => ExprType::JsonbTypeof,
+                "jsonb_build_object" => ExprType::JsonbBuildObject,
+                "gen_random_uuid" => ExprType::GenRandomUuid,
                 "nullif" => {
                     inputs = Self::rewrite_nullif_to_case_when(inputs)?;
                     ExprType::Case
                 }
                 "concat_ws" => ExprType::ConcatWs,
                 "split_part" => ExprType::SplitPart,
+                "regexp_match" => ExprType::RegexpMatch,
+                "regexp_replace" => ExprType::RegexpReplace,
+                "regexp_split_to_array" => ExprType::RegexpSplitToArray,
                 "coalesce" => ExprType::Coalesce,
+                "array_cat" => ExprType::ArrayCat,
+                "array_append" => ExprType::ArrayAppend,
+                "array_prepend" => ExprType::ArrayPrepend,
+                "array_length" => ExprType::ArrayLength,
+                "cardinality" => ExprType::Cardinality,
+                "array_position" => ExprType::ArrayPosition,
+                "array_to_string" => ExprType::ArrayToString,
                 "round" => {
                     inputs = Self::rewrite_round_args(inputs);
                     if inputs.len() >= 2 {
@@ -77,7 +183,7 @@ impl Binder {
                         ExprType::Round
                     }
                 }
-                "ceil" => {
+                "ceil" | "ceiling" => {
                     inputs = Self::rewrite_round_args(inputs);
                     ExprType::Ceil
                 }
@@ -85,7 +191,60 @@ impl Binder {
                     inputs = Self::rewrite_round_args(inputs);
                     ExprType::Floor
                 }
+                "trunc" => {
+                    inputs = Self::rewrite_round_args(inputs);
+                    if inputs.len() >= 2 {
+                        ExprType::TruncDigit
+                    } else {
+                        ExprType::Trunc
+                    }
+                }
                 "abs" => ExprType::Abs,
+                "mod" => ExprType::Modulus,
+                "power" | "pow" => {
+                    inputs = Self::rewrite_float_or_decimal_args(inputs)?;
+                    ExprType::Pow
+                }
+                "sqrt" | "exp" | "ln" | "log10" | "sign" => {
+                    inputs = Self::rewrite_float_or_decimal_args(inputs)?;
+                    match function_name.as_str() {
+                        "sqrt" => ExprType::Sqrt,
+                        "exp" => ExprType::Exp,
+                        "ln" => ExprType::Ln,
+                        "log10" => ExprType::Log10,
+                        _ => ExprType::Sign,
+                    }
+                }
+                "log" => return Self::bind_log(inputs),
+                "cbrt" | "sin" | "cos" | "tan" | "cot" | "asin" | "acos" | "atan" | "atan2"
+                | "degrees" | "radians" => {
+                    inputs = inputs
+                        .into_iter()
+                        .map(|input| input.cast_implicit(DataType::Float64))
+                        .try_collect()?;
+                    match function_name.as_str() {
+                        "cbrt" => ExprType::Cbrt,
+                        "sin" => ExprType::Sin,
+                        "cos" => ExprType::Cos,
+                        "tan" => ExprType::Tan,
+                        "cot" => ExprType::Cot,
+                        "asin" => ExprType::Asin,
+                        "acos" => ExprType::Acos,
+                        "atan" => ExprType::Atan,
+                        "atan2" => ExprType::Atan2,
+                        "degrees" => ExprType::Degrees,
+                        _ => ExprType::Radians,
+                    }
+                }
+                "pi" => return Self::bind_pi(inputs),
+                "format_type" => return Self::bind_format_type(inputs),
+                "random" => ExprType::Random,
+                "width_bucket" => {
+                    inputs = Self::rewrite_width_bucket_args(inputs)?;
+                    ExprType::WidthBucket
+                }
+                "greatest" => ExprType::Greatest,
+                "least" => ExprType::Least,
                 "booleq" => {
                     inputs = Self::rewrite_two_bool_inputs(inputs)?;
                     ExprType::Equal
@@ -171,6 +330,181 @@ impl Binder {
         }
     }
 
+    /// `concat(a, b, ...)` is the same as `concat_ws('', a, b, ...)`, as both ignore null
+    /// arguments.
+    fn rewrite_concat_to_concat_ws(mut inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
+        if inputs.is_empty() {
+            return Err(ErrorCode::BindError(
+                "Function `concat` takes at least 1 arguments (0 given)".to_string(),
+            )
+            .into());
+        }
+        inputs.insert(0, ExprImpl::literal_varchar("".into()));
+        Ok(inputs)
+    }
+
+    /// Rewrite the arguments of functions defined on both `double precision` and `numeric`, like
+    /// `sqrt` and `power`. The arguments are all cast to `numeric` if one of them is `numeric`
+    /// and none is a float, and are otherwise cast to `double precision`, e.g. `sqrt(2)` is
+    /// `sqrt(2::double precision)` as in Postgres.
+    fn rewrite_float_or_decimal_args(inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
+        let types = inputs.iter().map(|input| input.return_type()).collect_vec();
+        let target = if types.contains(&DataType::Decimal)
+            && !types.contains(&DataType::Float32)
+            && !types.contains(&DataType::Float64)
+        {
+            DataType::Decimal
+        } else {
+            DataType::Float64
+        };
+        inputs
+            .into_iter()
+            .map(|input| input.cast_implicit(target.clone()))
+            .collect()
+    }
+
+    /// `width_bucket(operand, low, high, count)` takes the operand and bounds like `power`, and
+    /// an integer count.
+    fn rewrite_width_bucket_args(mut inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
+        if inputs.len() != 4 {
+            return Err(ErrorCode::BindError(format!(
+                "Function `width_bucket` takes 4 arguments ({} given)",
+                inputs.len()
+            ))
+            .into());
+        }
+        let count = inputs.pop().unwrap().cast_implicit(DataType::Int32)?;
+        let mut inputs = Self::rewrite_float_or_decimal_args(inputs)?;
+        inputs.push(count);
+        Ok(inputs)
+    }
+
+    /// `log(x)` is the base 10 logarithm, and `log(b, x)` the logarithm to base `b`, which is
+    /// rewritten to `ln(x) / ln(b)` on `numeric` as in Postgres.
+    fn bind_log(mut inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        match inputs.len() {
+            1 => Ok(FunctionCall::new(
+                ExprType::Log10,
+                Self::rewrite_float_or_decimal_args(inputs)?,
+            )?
+            .into()),
+            2 => {
+                let x = inputs.pop().unwrap().cast_implicit(DataType::Decimal)?;
+                let base = inputs.pop().unwrap().cast_implicit(DataType::Decimal)?;
+                Ok(FunctionCall::new(
+                    ExprType::Divide,
+                    vec![
+                        FunctionCall::new(ExprType::Ln, vec![x])?.into(),
+                        FunctionCall::new(ExprType::Ln, vec![base])?.into(),
+                    ],
+                )?
+                .into())
+            }
+            _ => Err(ErrorCode::BindError(format!(
+                "Function `log` takes 1 or 2 arguments ({} given)",
+                inputs.len()
+            ))
+            .into()),
+        }
+    }
+
+    /// `format_type(type_oid, typemod)` is the SQL name of a type in `pg_type`, or `???` if there
+    /// is no such type. Type modifiers are ignored since no type has any.
+    fn bind_format_type(inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let type_oid = match <[ExprImpl; 2]>::try_from(inputs) {
+            Ok([type_oid, _typemod]) => type_oid.cast_implicit(DataType::Int32)?,
+            Err(_) => {
+                return Err(ErrorCode::BindError(
+                    "Function `format_type` takes 2 arguments".to_string(),
+                )
+                .into())
+            }
+        };
+
+        let mut inputs = vec![
+            FunctionCall::new(ExprType::IsNull, vec![type_oid.clone()])?.into(),
+            Literal::new(None, DataType::Varchar).into(),
+        ];
+        for (oid, format_name) in type_oids_with_format_names() {
+            inputs.push(
+                FunctionCall::new(
+                    ExprType::Equal,
+                    vec![type_oid.clone(), ExprImpl::literal_int(oid)],
+                )?
+                .into(),
+            );
+            inputs.push(ExprImpl::literal_varchar(format_name.to_string()));
+        }
+        inputs.push(ExprImpl::literal_varchar("???".to_string()));
+        Ok(FunctionCall::new(ExprType::Case, inputs)?.into())
+    }
+
+    fn bind_pi(inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        if !inputs.is_empty() {
+            return Err(
+                ErrorCode::BindError("Function `pi` takes no arguments".to_string()).into(),
+            );
+        }
+        Ok(Literal::new(
+            Some(ScalarImpl::Float64(std::f64::consts::PI.into())),
+            DataType::Float64,
+        )
+        .into())
+    }
+
+    /// `age(timestamp)` is the age since midnight of the current date:
+    /// age(ts) -> age(current_date::timestamp, ts).
+    fn rewrite_age_args(mut inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
+        if inputs.len() == 1 {
+            let input = inputs.pop().unwrap();
+            let today = Self::bind_current_time(vec![], DataType::Date)?
+                .cast_explicit(input.return_type())?;
+            inputs = vec![today, input];
+        }
+        Ok(inputs)
+    }
+
+    /// `current_date`, `current_time` and `localtimestamp` are the current timestamp cast to
+    /// the local date or time, which is done in the session time zone during planning.
+    fn bind_current_time(inputs: Vec<ExprImpl>, target: DataType) -> Result<ExprImpl> {
+        if !inputs.is_empty() {
+            return Err(ErrorCode::BindError(
+                "current date/time functions take no arguments".to_string(),
+            )
+            .into());
+        }
+        ExprImpl::from(FunctionCall::new(ExprType::Now, inputs)?).cast_explicit(target)
+    }
+
+    /// date_part(field, source) -> extract(FIELD from source)::double precision, where the field
+    /// must be a constant.
+    fn bind_date_part(mut inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        if inputs.len() != 2 {
+            return Err(
+                ErrorCode::BindError("Function `date_part` takes 2 arguments".to_string()).into(),
+            );
+        }
+        let source = inputs.pop().unwrap();
+        let field = match &inputs.pop().unwrap() {
+            ExprImpl::Literal(literal) if let Some(ScalarImpl::Utf8(field)) = literal.get_data() => {
+                field.to_uppercase()
+            }
+            _ => {
+                return Err(ErrorCode::NotImplemented(
+                    "date_part with a non-constant field".to_string(),
+                    None.into(),
+                )
+                .into())
+            }
+        };
+        let field = Literal::new(Some(ScalarImpl::Utf8(field)), DataType::Varchar);
+        ExprImpl::from(FunctionCall::new(
+            ExprType::Extract,
+            vec![field.into(), source],
+        )?)
+        .cast_explicit(DataType::Float64)
+    }
+
     fn rewrite_two_bool_inputs(mut inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
         if inputs.len() != 2 {
             return Err(
@@ -185,6 +519,50 @@ impl Binder {
         ])
     }
 
+    fn bind_window_function(
+        &mut self,
+        function_name: &str,
+        inputs: Vec<ExprImpl>,
+        WindowSpec {
+            partition_by,
+            order_by,
+            window_frame,
+        }: WindowSpec,
+    ) -> Result<ExprImpl> {
+        let function_type = WindowFunctionType::from_name(function_name).ok_or_else(|| {
+            ErrorCode::NotImplemented(format!("window function `{}`", function_name), None.into())
+        })?;
+        if let Some(clause) = self.context.clause {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "window functions are not allowed in {}",
+                clause
+            ))
+            .into());
+        }
+        if window_frame.is_some() {
+            return Err(ErrorCode::NotImplemented(
+                "window frame in OVER clause".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        let partition_by = partition_by
+            .into_iter()
+            .map(|expr| self.bind_expr(expr))
+            .try_collect()?;
+        let order_by = order_by
+            .into_iter()
+            .map(|order_by_expr| {
+                let direct = match order_by_expr.asc {
+                    None | Some(true) => Direction::Asc,
+                    Some(false) => Direction::Desc,
+                };
+                Ok((self.bind_expr(order_by_expr.expr)?, direct))
+            })
+            .try_collect::<_, _, RwError>()?;
+        Ok(WindowFunction::new(function_type, inputs, partition_by, order_by)?.into())
+    }
+
     fn ensure_aggregate_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             if clause == Clause::Values || clause == Clause::Where {
@@ -198,6 +576,17 @@ impl Binder {
         Ok(())
     }
 
+    fn ensure_set_returning_function_allowed(&self, function_name: &str) -> Result<()> {
+        if let Some(clause) = self.context.clause {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "set-returning function `{}` is not allowed in {}",
+                function_name, clause
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     pub(in crate::binder) fn bind_function_expr_arg(
         &mut self,
         arg_expr: FunctionArgExpr,
@@ -15,14 +15,15 @@
 use itertools::zip_eq;
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_sqlparser::ast::{
-    BinaryOperator, DataType as AstDataType, DateTimeField, Expr, Query, StructField,
-    TrimWhereField, UnaryOperator,
+    BinaryOperator, DataType as AstDataType, DateTimeField, Expr, Ident, ObjectName, Query,
+    StructField, TrimWhereField, UnaryOperator,
 };
 
 use crate::binder::Binder;
-use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall, SubqueryKind};
+use crate::catalog::CatalogError;
+use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall, Literal, Parameter, SubqueryKind};
 
 mod binary_op;
 mod column;
@@ -40,6 +41,7 @@ impl Binder {
                 s.cast_explicit(bind_data_type(&data_type)?)
             }
             Expr::Row(exprs) => Ok(ExprImpl::Literal(Box::new(self.bind_row(&exprs)?))),
+            Expr::Parameter { index } => self.bind_parameter(index),
             // input ref
             Expr::Identifier(ident) => self.bind_column(&[ident]),
             Expr::CompoundIdentifier(idents) => self.bind_column(&idents),
@@ -52,6 +54,7 @@ impl Binder {
             Expr::Nested(expr) => self.bind_expr(*expr),
             Expr::Array(exprs) => self.bind_array(exprs),
             Expr::ArrayIndex { obj, indexs } => self.bind_array_index(*obj, indexs),
+            Expr::ArraySlice { obj, start, end } => self.bind_array_slice(*obj, start, end),
             Expr::Function(f) => self.bind_function(f),
             // subquery
             Expr::Subquery(q) => self.bind_subquery_expr(*q, SubqueryKind::Scalar),
@@ -90,6 +93,10 @@ impl Binder {
             } => self.bind_in_list(*expr, list, negated),
             // special syntax for date/time
             Expr::Extract { field, expr } => self.bind_extract(field, *expr),
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => self.bind_at_time_zone(*timestamp, *time_zone),
             // special syntaxt for string
             Expr::Trim { expr, trim_where } => self.bind_trim(*expr, trim_where),
             Expr::Substring {
@@ -97,6 +104,12 @@ impl Binder {
                 substring_from,
                 substring_for,
             } => self.bind_substring(*expr, substring_from, substring_for),
+            Expr::Overlay {
+                expr,
+                new_substring,
+                start,
+                count,
+            } => self.bind_overlay(*expr, *new_substring, *start, count),
             _ => Err(ErrorCode::NotImplemented(
                 format!("unsupported expression {:?}", expr),
                 112.into(),
@@ -105,6 +118,17 @@ impl Binder {
         }
     }
 
+    fn bind_parameter(&mut self, index: u64) -> Result<ExprImpl> {
+        let param_types = self.param_types.as_ref().ok_or_else(|| {
+            ErrorCode::BindError(format!(
+                "parameter ${} is only allowed in a prepared statement",
+                index
+            ))
+        })?;
+        let data_type = param_types.get(index as usize - 1).cloned();
+        Ok(Parameter::new(index, data_type).into())
+    }
+
     pub(super) fn bind_extract(&mut self, field: DateTimeField, expr: Expr) -> Result<ExprImpl> {
         let arg = self.bind_expr(expr)?;
         let arg_type = arg.return_type();
@@ -124,6 +148,18 @@ impl Binder {
         .into())
     }
 
+    pub(super) fn bind_at_time_zone(
+        &mut self,
+        timestamp: Expr,
+        time_zone: Expr,
+    ) -> Result<ExprImpl> {
+        let timestamp = self.bind_expr(timestamp)?;
+        let time_zone = self
+            .bind_expr(time_zone)?
+            .cast_implicit(DataType::Varchar)?;
+        Ok(FunctionCall::new(ExprType::AtTimeZone, vec![timestamp, time_zone])?.into())
+    }
+
     pub(super) fn bind_in_list(
         &mut self,
         expr: Expr,
@@ -135,7 +171,8 @@ impl Binder {
         let mut non_const_exprs = vec![];
         for elem in list {
             let expr = self.bind_expr(elem)?;
-            match expr.is_const() {
+            // `now()` is not a constant in streaming queries, so it's not kept in the list.
+            match expr.is_const() && !expr.has_now() {
                 true => bound_expr_list.push(expr),
                 false => non_const_exprs.push(expr),
             }
@@ -247,6 +284,24 @@ impl Binder {
         FunctionCall::new(ExprType::Substr, args).map(|f| f.into())
     }
 
+    fn bind_overlay(
+        &mut self,
+        expr: Expr,
+        new_substring: Expr,
+        start: Expr,
+        count: Option<Box<Expr>>,
+    ) -> Result<ExprImpl> {
+        let mut args = vec![
+            self.bind_expr(expr)?,
+            self.bind_expr(new_substring)?,
+            self.bind_expr(start)?,
+        ];
+        if let Some(count) = count {
+            args.push(self.bind_expr(*count)?);
+        }
+        FunctionCall::new(ExprType::Overlay, args).map(|f| f.into())
+    }
+
     /// Bind `expr (not) between low and high`
     pub(super) fn bind_between(
         &mut self,
@@ -341,9 +396,57 @@ impl Binder {
     }
 
     pub(super) fn bind_cast(&mut self, expr: Expr, data_type: AstDataType) -> Result<ExprImpl> {
+        if data_type == AstDataType::Regclass {
+            return self.bind_cast_to_regclass(expr);
+        }
         self.bind_expr(expr)?
             .cast_explicit(bind_data_type(&data_type)?)
     }
+
+    /// Bind `expr::regclass` to the OID of a relation, i.e. its `oid` in `pg_class`, like
+    /// `'t'::regclass` in the queries of psql. A name is resolved with the `search_path`, and an
+    /// integer is taken as an OID.
+    fn bind_cast_to_regclass(&mut self, expr: Expr) -> Result<ExprImpl> {
+        let expr = self.bind_expr(expr)?;
+        let name = match &expr {
+            ExprImpl::Literal(literal) if literal.return_type() == DataType::Varchar => {
+                match literal.get_data() {
+                    Some(ScalarImpl::Utf8(name)) => name.clone(),
+                    _ => return Ok(Literal::new(None, DataType::Int32).into()),
+                }
+            }
+            _ if matches!(
+                expr.return_type(),
+                DataType::Int16 | DataType::Int32 | DataType::Int64
+            ) =>
+            {
+                return expr.cast_explicit(DataType::Int32)
+            }
+            _ => {
+                return Err(ErrorCode::BindError(format!(
+                    "cannot cast type {:?} to regclass",
+                    expr.return_type()
+                ))
+                .into())
+            }
+        };
+
+        let idents = name.split('.').map(Ident::new).collect();
+        let (schema_name, relation_name) = self.resolve_relation_name(ObjectName(idents))?;
+        let schema = self
+            .catalog
+            .get_schema_by_name(&self.db_name, &schema_name)?;
+        let oid = schema
+            .get_table_by_name(&relation_name)
+            .map(|table| table.id().table_id)
+            .or_else(|| {
+                schema
+                    .get_source_by_name(&relation_name)
+                    .map(|source| source.id)
+            })
+            .ok_or_else(|| CatalogError::NotFound("relation", name))?;
+        Ok(ExprImpl::literal_int(oid as i32))
+    }
 }
 
 /// Given a type `STRUCT<v1 int>`, this function binds the field `v1 int`.
@@ -388,6 +491,9 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
         AstDataType::Timestamp(false) => DataType::Timestamp,
         AstDataType::Timestamp(true) => DataType::Timestampz,
         AstDataType::Interval => DataType::Interval,
+        AstDataType::Jsonb => DataType::Jsonb,
+        AstDataType::Bytea => DataType::Bytea,
+        AstDataType::Uuid => DataType::Uuid,
         AstDataType::Array(datatype) => DataType::List {
             datatype: Box::new(bind_data_type(datatype)?),
         },
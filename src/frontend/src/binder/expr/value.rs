@@ -100,6 +100,7 @@ impl Binder {
                 let ms = seconds.checked_mul(1000)?;
                 Some(IntervalUnit::from_millis(ms))
             }
+            _ => None,
         })()
         .ok_or_else(|| {
             RwError::from(ErrorCode::InvalidInputSyntax(format!(
@@ -151,6 +152,23 @@ impl Binder {
             _ => panic!("Should be a List"),
         }
     }
+
+    /// Bind `obj[start:end]`, where a missing bound is passed as null.
+    pub(super) fn bind_array_slice(
+        &mut self,
+        obj: Expr,
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
+    ) -> Result<ExprImpl> {
+        let obj = self.bind_expr(obj)?;
+        let mut bind_bound = |bound: Option<Box<Expr>>| match bound {
+            Some(bound) => self.bind_expr(*bound),
+            None => Ok(Literal::new(None, DataType::Int32).into()),
+        };
+        let start = bind_bound(start)?;
+        let end = bind_bound(end)?;
+        Ok(FunctionCall::new(ExprType::ArraySlice, vec![obj, start, end])?.into())
+    }
 }
 
 #[cfg(test)]
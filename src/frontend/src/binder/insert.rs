@@ -22,7 +22,7 @@ use super::{BoundQuery, BoundSetExpr};
 use crate::binder::{Binder, BoundTableSource};
 use crate::expr::{Expr, ExprImpl, InputRef, Literal};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundInsert {
     /// Used for injecting deletion chunks to the source.
     pub table_source: BoundTableSource,
@@ -13,11 +13,17 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
+use parking_lot::RwLock;
+use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
 use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{Statement, TableAlias};
 
 pub mod bind_context;
+mod bind_param;
+mod bind_session_time;
 mod delete;
 pub(crate) mod expr;
 mod insert;
@@ -35,8 +41,8 @@ pub use delete::BoundDelete;
 pub use insert::BoundInsert;
 pub use query::BoundQuery;
 pub use relation::{
-    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundTableSource,
-    BoundWindowTableFunction, Relation, WindowTableFunctionKind,
+    BoundBaseTable, BoundJoin, BoundSource, BoundSystemTable, BoundTableSource, BoundUniqueIndex,
+    BoundWindowTableFunction, BoundWorkTable, Relation, WindowTableFunctionKind,
 };
 pub use select::BoundSelect;
 pub use set_expr::BoundSetExpr;
@@ -44,7 +50,10 @@ pub use statement::BoundStatement;
 pub use update::BoundUpdate;
 pub use values::BoundValues;
 
+use self::query::RecursiveCte;
 use crate::catalog::catalog_service::CatalogReadGuard;
+use crate::session::SessionImpl;
+use crate::user::user_service::UserInfoReader;
 
 /// `Binder` binds the identifiers in AST to columns in relations
 pub struct Binder {
@@ -60,6 +69,17 @@ pub struct Binder {
     next_subquery_id: usize,
     /// Map the cte's name to its Relation::Subquery.
     cte_to_relation: HashMap<String, (BoundQuery, TableAlias)>,
+    /// Map the name of the recursive cte whose recursive term is being bound to its work table.
+    recursive_ctes: HashMap<String, RecursiveCte>,
+    next_work_table_id: u32,
+
+    /// Types of the parameters declared in `PREPARE`, where the `i`-th type is for `$(i + 1)`.
+    /// Parameters are only allowed in prepared statements, i.e. if this is not `None`.
+    param_types: Option<Vec<DataType>>,
+    /// Schemas searched in order for relation names that are not schema-qualified.
+    search_path: Vec<String>,
+    /// Read by system catalogs such as `pg_user`.
+    user_info: UserInfoReader,
 }
 
 impl Binder {
@@ -71,9 +91,33 @@ impl Binder {
             upper_contexts: vec![],
             next_subquery_id: 0,
             cte_to_relation: HashMap::new(),
+            recursive_ctes: HashMap::new(),
+            next_work_table_id: 0,
+            param_types: None,
+            search_path: vec![DEFAULT_SCHEMA_NAME.to_string()],
+            user_info: UserInfoReader::new(Arc::new(RwLock::new(Default::default()))),
         }
     }
 
+    /// Create a binder on the catalog and database of the session, resolving relation names with
+    /// its `search_path`.
+    pub fn new_for_session(session: &SessionImpl) -> Binder {
+        let mut binder = Self::new(
+            session.env().catalog_reader().read_guard(),
+            session.database().to_string(),
+        );
+        binder.search_path = session.config().search_path().to_vec();
+        binder.user_info = session.env().user_info_reader().clone();
+        binder
+    }
+
+    /// Create a binder for a prepared statement with the declared parameter types.
+    pub fn new_with_param_types(session: &SessionImpl, param_types: Vec<DataType>) -> Binder {
+        let mut binder = Self::new_for_session(session);
+        binder.param_types = Some(param_types);
+        binder
+    }
+
     /// Bind a [`Statement`].
     pub fn bind(&mut self, stmt: Statement) -> Result<BoundStatement> {
         self.bind_statement(stmt)
@@ -94,6 +138,12 @@ impl Binder {
         self.next_subquery_id += 1;
         id
     }
+
+    fn next_work_table_id(&mut self) -> u32 {
+        let id = self.next_work_table_id;
+        self.next_work_table_id += 1;
+        id
+    }
 }
 
 #[cfg(test)]
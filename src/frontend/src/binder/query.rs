@@ -14,13 +14,16 @@
 
 use std::collections::HashMap;
 
+use itertools::Itertools;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
-use risingwave_sqlparser::ast::{Cte, Expr, OrderByExpr, Query, Value, With};
+use risingwave_sqlparser::ast::{
+    Cte, Expr, OrderByExpr, Query, SetExpr, SetOperator, TableAlias, Value, With,
+};
 
-use crate::binder::{Binder, BoundSetExpr};
-use crate::expr::ExprImpl;
+use crate::binder::{BindContext, Binder, BoundSetExpr, BoundWorkTable};
+use crate::expr::{cast_ok, CastContext, ExprImpl};
 use crate::optimizer::property::{Direction, FieldOrder};
 
 /// A validated sql query, including order and union.
@@ -34,6 +37,15 @@ pub struct BoundQuery {
     pub extra_order_exprs: Vec<ExprImpl>,
 }
 
+/// A recursive cte whose recursive term is being bound, which may refer to the cte as its work
+/// table.
+pub(super) struct RecursiveCte {
+    pub work_table: BoundWorkTable,
+    pub alias: TableAlias,
+    /// A recursive cte can only be referred to once in its recursive term.
+    pub referenced: bool,
+}
+
 impl BoundQuery {
     /// The schema returned by this [`BoundQuery`].
     pub fn schema(&self) -> &Schema {
@@ -100,6 +112,14 @@ impl Binder {
                 )
             })
             .collect::<Result<_>>()?;
+        if matches!(body, BoundSetExpr::SetOperation { .. }) && !extra_order_exprs.is_empty() {
+            return Err(ErrorCode::BindError(
+                "invalid UNION/INTERSECT/EXCEPT ORDER BY clause: only result column names or \
+                 positions can be used"
+                    .into(),
+            )
+            .into());
+        }
         Ok(BoundQuery {
             body,
             order,
@@ -144,17 +164,119 @@ impl Binder {
     }
 
     fn bind_with(&mut self, with: With) -> Result<()> {
-        if with.recursive {
-            Err(ErrorCode::NotImplemented("recursive cte".into(), None.into()).into())
-        } else {
-            for cte_table in with.cte_tables {
-                let Cte { alias, query, .. } = cte_table;
-                let table_name = alias.name.value.clone();
-                let bound_query = self.bind_query(query)?;
-                self.cte_to_relation
-                    .insert(table_name, (bound_query, alias));
+        for cte_table in with.cte_tables {
+            let Cte { alias, query, .. } = cte_table;
+            let table_name = alias.name.value.clone();
+            let bound_query = match with.recursive {
+                true => self.bind_recursive_cte(&table_name, &alias, query)?,
+                false => self.bind_query(query)?,
+            };
+            self.cte_to_relation
+                .insert(table_name, (bound_query, alias));
+        }
+        Ok(())
+    }
+
+    /// Binds a cte in `WITH RECURSIVE`. It is bound as a [`BoundSetExpr::RecursiveUnion`] if it is
+    /// of the form `base UNION [ALL] recursive`, where `recursive` refers to the cte itself.
+    /// Otherwise, it is bound as a normal cte.
+    fn bind_recursive_cte(
+        &mut self,
+        name: &str,
+        alias: &TableAlias,
+        query: Query,
+    ) -> Result<BoundQuery> {
+        let (all, left, right) = match query.body {
+            SetExpr::SetOperation {
+                op: SetOperator::Union,
+                all,
+                left,
+                right,
+            } if query.with.is_none()
+                && query.order_by.is_empty()
+                && query.limit.is_none()
+                && query.offset.is_none()
+                && query.fetch.is_none() =>
+            {
+                (all, left, right)
+            }
+            _ => return self.bind_query(query),
+        };
+
+        self.push_context();
+        let result = self.bind_recursive_union(name, alias, all, *left, *right);
+        self.pop_context();
+        Ok(BoundQuery {
+            body: result?,
+            order: vec![],
+            limit: None,
+            offset: None,
+            extra_order_exprs: vec![],
+        })
+    }
+
+    fn bind_recursive_union(
+        &mut self,
+        name: &str,
+        alias: &TableAlias,
+        all: bool,
+        left: SetExpr,
+        right: SetExpr,
+    ) -> Result<BoundSetExpr> {
+        let base = self.bind_set_expr(left)?;
+
+        // The recursive term refers to the cte as a work table with the columns of `base`.
+        let work_table = BoundWorkTable {
+            id: self.next_work_table_id(),
+            schema: base.schema().clone(),
+        };
+        let work_table_id = work_table.id;
+        self.recursive_ctes.insert(
+            name.to_string(),
+            RecursiveCte {
+                work_table,
+                alias: alias.clone(),
+                referenced: false,
+            },
+        );
+        self.context = BindContext::new();
+        let recursive = self.bind_set_expr(right);
+        let referenced = self.recursive_ctes.remove(name).unwrap().referenced;
+        let recursive = recursive?;
+
+        if !referenced {
+            return Self::align_set_operation(SetOperator::Union, all, base, recursive);
+        }
+        let base_types = base.schema().data_types();
+        let recursive_types = recursive.schema().data_types();
+        if base_types.len() != recursive_types.len() {
+            return Err(ErrorCode::BindError(
+                "each UNION query must have the same number of columns".into(),
+            )
+            .into());
+        }
+        for (i, (base_type, recursive_type)) in
+            base_types.iter().zip_eq(&recursive_types).enumerate()
+        {
+            if recursive_type != base_type
+                && !cast_ok(recursive_type, base_type, &CastContext::Implicit)
+            {
+                return Err(ErrorCode::BindError(format!(
+                    "recursive query \"{}\" column {} has type {:?} in non-recursive term but \
+                     type {:?} in recursive term",
+                    name,
+                    i + 1,
+                    base_type,
+                    recursive_type
+                ))
+                .into());
             }
-            Ok(())
         }
+        Ok(BoundSetExpr::RecursiveUnion {
+            all,
+            base: Box::new(base),
+            recursive: Box::new(recursive),
+            work_table_id,
+        })
     }
 }
@@ -12,15 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
+use itertools::{Either, Itertools};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 use risingwave_pb::plan_common::JoinType;
-use risingwave_sqlparser::ast::{
-    BinaryOperator, Expr, Ident, JoinConstraint, JoinOperator, TableFactor, TableWithJoins, Value,
-};
+use risingwave_sqlparser::ast::{Ident, JoinConstraint, JoinOperator, TableWithJoins};
 
+use crate::binder::bind_context::{ColumnBinding, UsingColumn};
 use crate::binder::{Binder, Relation};
-use crate::expr::{Expr as _, ExprImpl};
+use crate::expr::{merge_expr_by_binary, Expr as _, ExprImpl, ExprType, FunctionCall};
 
 #[derive(Debug, Clone)]
 pub struct BoundJoin {
@@ -54,7 +56,16 @@ impl Binder {
     }
 
     fn bind_table_with_joins(&mut self, table: TableWithJoins) -> Result<Relation> {
-        let mut root = self.bind_table_factor(table.relation)?;
+        let begin = self.context.columns.len();
+        // The first relation can refer to all the items before it in the FROM clause.
+        let mut root = self.bind_table_factor(table.relation, 0)?;
+        if root.is_lateral() && !table.joins.is_empty() {
+            return Err(ErrorCode::NotImplemented(
+                "LATERAL item followed by JOIN".into(),
+                None.into(),
+            )
+            .into());
+        }
         for join in table.joins {
             let (constraint, join_type) = match join.join_operator {
                 JoinOperator::Inner(constraint) => (constraint, JoinType::Inner),
@@ -64,16 +75,30 @@ impl Binder {
                 // Cross join equals to inner join with with no constraint.
                 JoinOperator::CrossJoin => (JoinConstraint::None, JoinType::Inner),
             };
-            let right: Relation;
-            let cond: ExprImpl;
-            if let JoinConstraint::Using(_col) = constraint.clone() {
-                let option_rel: Option<Relation>;
-                (cond, option_rel) = self.bind_join_constraint(constraint, Some(join.relation))?;
-                right = option_rel.unwrap();
-            } else {
-                right = self.bind_table_factor(join.relation)?;
-                (cond, _) = self.bind_join_constraint(constraint, None)?;
+            let right_begin = self.context.columns.len();
+            // The joined relation can only refer to the left side of the join.
+            let right = self.bind_table_factor(join.relation, begin)?;
+            if right.is_lateral() {
+                match (join_type, &right) {
+                    (JoinType::Inner, _) | (JoinType::LeftOuter, Relation::Subquery(_)) => {}
+                    (JoinType::LeftOuter, _) => {
+                        return Err(ErrorCode::NotImplemented(
+                            "LEFT JOIN with a table function referring to its left side".into(),
+                            None.into(),
+                        )
+                        .into())
+                    }
+                    _ => {
+                        return Err(ErrorCode::BindError(
+                            "The combining JOIN type must be INNER or LEFT for a LATERAL \
+                             reference"
+                                .into(),
+                        )
+                        .into())
+                    }
+                }
             }
+            let cond = self.bind_join_constraint(constraint, join_type, begin, right_begin)?;
             let join = BoundJoin {
                 join_type,
                 left: root,
@@ -86,15 +111,35 @@ impl Binder {
         Ok(root)
     }
 
+    /// Binds the join condition, where the columns of the left side start from `left_begin`, and
+    /// those of the right side start from `right_begin`.
     fn bind_join_constraint(
         &mut self,
         constraint: JoinConstraint,
-        table_factor: Option<TableFactor>,
-    ) -> Result<(ExprImpl, Option<Relation>)> {
+        join_type: JoinType,
+        left_begin: usize,
+        right_begin: usize,
+    ) -> Result<ExprImpl> {
         Ok(match constraint {
-            JoinConstraint::None => (ExprImpl::literal_bool(true), None),
+            JoinConstraint::None => ExprImpl::literal_bool(true),
             JoinConstraint::Natural => {
-                return Err(ErrorCode::NotImplemented("Natural join".into(), 1633.into()).into())
+                // The columns of the same names on both sides, in the order of the left side.
+                let end = self.context.columns.len();
+                let right_names: HashSet<_> = self
+                    .context
+                    .wildcard_columns(right_begin, end)
+                    .into_iter()
+                    .map(|column| column_name(column).to_string())
+                    .collect();
+                let names = self
+                    .context
+                    .wildcard_columns(left_begin, right_begin)
+                    .into_iter()
+                    .map(column_name)
+                    .filter(|name| right_names.contains(*name))
+                    .map(|name| name.to_string())
+                    .collect_vec();
+                self.bind_using(names, join_type, left_begin, right_begin)?
             }
             JoinConstraint::On(expr) => {
                 let bound_expr = self.bind_expr(expr)?;
@@ -105,56 +150,127 @@ impl Binder {
                     ))
                     .into());
                 }
-                (bound_expr, None)
+                bound_expr
             }
             JoinConstraint::Using(columns) => {
-                let table_factor = table_factor.unwrap();
-                let right_table = get_table_name(&table_factor);
-                let mut binary_expr = Expr::Value(Value::Boolean(true));
-                for column in columns {
-                    let left_table = self.context.columns[self.context.get_index(&column.value)?]
-                        .table_name
-                        .clone();
-                    binary_expr = Expr::BinaryOp {
-                        left: Box::new(binary_expr),
-                        op: BinaryOperator::And,
-                        right: Box::new(Expr::BinaryOp {
-                            left: Box::new(Expr::CompoundIdentifier(vec![
-                                Ident::new(left_table.clone()),
-                                column.clone(),
-                            ])),
-                            op: BinaryOperator::Eq,
-                            right: Box::new(Expr::CompoundIdentifier({
-                                let mut right_table_clone = right_table.clone().unwrap();
-                                right_table_clone.push(column.clone());
-                                right_table_clone
-                            })),
-                        }),
+                let mut names = vec![];
+                for Ident { value, .. } in columns {
+                    if names.contains(&value) {
+                        return Err(ErrorCode::BindError(format!(
+                            "column name \"{}\" appears more than once in USING clause",
+                            value
+                        ))
+                        .into());
                     }
+                    names.push(value);
                 }
-                // We cannot move this into ret expression since it should be done before bind_expr
-                let relation = self.bind_table_factor(table_factor)?;
-                (self.bind_expr(binary_expr)?, Some(relation))
+                self.bind_using(names, join_type, left_begin, right_begin)?
             }
         })
     }
-}
 
-fn get_table_name(table_factor: &TableFactor) -> Option<Vec<Ident>> {
-    match table_factor {
-        TableFactor::Table { name, alias, .. } => {
-            if let Some(table_alias) = alias {
-                Some(vec![table_alias.name.clone()])
-            } else {
-                Some(name.0.clone())
-            }
+    /// Binds the condition of a `USING` join, which requires the columns named `names` to be
+    /// equal on both sides, and merges each pair of the columns into a [`UsingColumn`].
+    ///
+    /// The merged column takes the value of the left side for `INNER` and `LEFT` joins, and that
+    /// of the right side for `RIGHT` joins, which can only be null if both are. For `FULL` joins,
+    /// it takes the first non-null one.
+    fn bind_using(
+        &mut self,
+        names: Vec<String>,
+        join_type: JoinType,
+        left_begin: usize,
+        right_begin: usize,
+    ) -> Result<ExprImpl> {
+        let end = self.context.columns.len();
+        let mut conjunctions = vec![];
+        let mut using_columns = vec![];
+        for name in names {
+            let left = self.take_join_column(&name, left_begin, right_begin, "left")?;
+            let right = self.take_join_column(&name, right_begin, end, "right")?;
+            conjunctions.push(
+                FunctionCall::new(
+                    ExprType::Equal,
+                    vec![
+                        self.context.bind_merged_column(&left.values, 0)?,
+                        self.context.bind_merged_column(&right.values, 0)?,
+                    ],
+                )?
+                .into(),
+            );
+            let values = match join_type {
+                JoinType::RightOuter => right.values,
+                JoinType::FullOuter => left.values.into_iter().chain(right.values).collect(),
+                _ => left.values,
+            };
+            using_columns.push(UsingColumn {
+                name,
+                merged: left.merged.into_iter().chain(right.merged).collect(),
+                values,
+                begin: left_begin,
+            });
         }
-        TableFactor::Derived { alias, .. } => alias
-            .as_ref()
-            .map(|table_alias| vec![table_alias.name.clone()]),
-        TableFactor::TableFunction { expr: _, alias } => alias
-            .as_ref()
-            .map(|table_alias| vec![table_alias.name.clone()]),
-        TableFactor::NestedJoin(table_with_joins) => get_table_name(&table_with_joins.relation),
+        // The columns merged by this join are placed before those merged by the inner joins.
+        self.context.using_columns.splice(0..0, using_columns);
+        Ok(merge_expr_by_binary(
+            conjunctions.into_iter(),
+            ExprType::And,
+            ExprImpl::literal_bool(true),
+        ))
+    }
+
+    /// Finds the column `name` among the columns in `[begin, end)` on the `side` of a `USING`
+    /// join. If it's merged by an inner join, it's removed from the context, as it's going to be
+    /// merged again.
+    fn take_join_column(
+        &mut self,
+        name: &str,
+        begin: usize,
+        end: usize,
+        side: &str,
+    ) -> Result<UsingColumn> {
+        let mut columns = self
+            .context
+            .wildcard_columns(begin, end)
+            .into_iter()
+            .filter(|column| column_name(*column) == name)
+            .map(|column| match column {
+                Either::Left(column) => UsingColumn {
+                    name: name.to_string(),
+                    merged: vec![column.index],
+                    values: vec![column.index],
+                    begin: column.index,
+                },
+                Either::Right(using_column) => using_column.clone(),
+            })
+            .collect_vec();
+        let column = match columns.len() {
+            0 => {
+                return Err(ErrorCode::BindError(format!(
+                    "column \"{}\" specified in USING clause does not exist in {} table",
+                    name, side
+                ))
+                .into())
+            }
+            1 => columns.pop().unwrap(),
+            _ => {
+                return Err(ErrorCode::BindError(format!(
+                    "common column name \"{}\" appears more than once in {} table",
+                    name, side
+                ))
+                .into())
+            }
+        };
+        self.context
+            .using_columns
+            .retain(|using_column| using_column.merged != column.merged);
+        Ok(column)
+    }
+}
+
+fn column_name<'a>(column: Either<&'a ColumnBinding, &'a UsingColumn>) -> &'a str {
+    match column {
+        Either::Left(column) => &column.field.name,
+        Either::Right(using_column) => &using_column.name,
     }
 }
@@ -21,17 +21,25 @@ use risingwave_sqlparser::ast::{Ident, ObjectName, TableAlias, TableFactor};
 
 use super::bind_context::ColumnBinding;
 use crate::binder::Binder;
+use crate::catalog::root_catalog::Catalog;
+use crate::catalog::system_catalog::get_system_catalog;
+use crate::catalog::system_catalog::pg_catalog::PG_CATALOG_SCHEMA_NAME;
+use crate::expr::{TableFunction, TableFunctionType};
+use crate::session::SessionImpl;
 
-mod generate_series;
 mod join;
 mod subquery;
+mod table_function;
 mod table_or_source;
 mod window_table_function;
-pub use generate_series::BoundGenerateSeriesFunction;
+mod work_table;
 pub use join::BoundJoin;
 pub use subquery::BoundSubquery;
-pub use table_or_source::{BoundBaseTable, BoundSource, BoundTableSource};
+pub use table_or_source::{
+    BoundBaseTable, BoundSource, BoundSystemTable, BoundTableSource, BoundUniqueIndex,
+};
 pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKind};
+pub use work_table::BoundWorkTable;
 
 /// A validated item that refers to a table-like entity, including base table, subquery, join, etc.
 /// It is usually part of the `from` clause.
@@ -42,7 +50,25 @@ pub enum Relation {
     Subquery(Box<BoundSubquery>),
     Join(Box<BoundJoin>),
     WindowTableFunction(Box<BoundWindowTableFunction>),
-    GenerateSeriesFunction(Box<BoundGenerateSeriesFunction>),
+    TableFunction(Box<TableFunction>),
+    SystemTable(Box<BoundSystemTable>),
+    WorkTable(Box<BoundWorkTable>),
+}
+
+impl Relation {
+    /// Whether it refers to the items on its left in the FROM clause, as a `LATERAL` subquery or a
+    /// table function, so it must be joined by a
+    /// [`LogicalApply`](crate::optimizer::plan_node::LogicalApply).
+    pub fn is_lateral(&self) -> bool {
+        match self {
+            Relation::Subquery(subquery) => subquery.lateral && subquery.query.is_correlated(),
+            Relation::TableFunction(table_function) => table_function
+                .args
+                .iter()
+                .any(|arg| arg.has_correlated_input_ref()),
+            _ => false,
+        }
+    }
 }
 
 impl Binder {
@@ -82,11 +108,87 @@ impl Binder {
         Ok(name)
     }
 
-    /// return the (`schema_name`, `table_name`)
-    pub fn resolve_table_name(name: ObjectName) -> Result<(String, String)> {
+    /// return the (`schema_name`, `table_name`) of a qualified name, or of an unqualified one in
+    /// the default schema.
+    fn resolve_qualified_name(name: ObjectName) -> Result<(String, String)> {
         Self::resolve_double_name(name.0, "empty table name", DEFAULT_SCHEMA_NAME)
     }
 
+    /// return the (`schema_name`, `table_name`) of an existing relation, e.g. to drop or describe,
+    /// resolved with the `search_path` of the session in the same way as [`Binder`] resolves the
+    /// relations to read from.
+    pub fn resolve_table_name(session: &SessionImpl, name: ObjectName) -> Result<(String, String)> {
+        let catalog = session.env().catalog_reader().read_guard();
+        Self::resolve_name_in_search_path(
+            &catalog,
+            session.database(),
+            session.config().search_path(),
+            name,
+        )
+    }
+
+    /// return the (`schema_name`, `relation_name`) of a relation to create. As in Postgres, an
+    /// unqualified name is created in the first schema of the `search_path` that exists.
+    pub fn resolve_create_name(
+        session: &SessionImpl,
+        name: ObjectName,
+    ) -> Result<(String, String)> {
+        if name.0.len() != 1 {
+            return Self::resolve_qualified_name(name);
+        }
+        let relation_name = Self::resolve_single_name(name.0, "relation name")?;
+        let config = session.config();
+        let catalog = session.env().catalog_reader().read_guard();
+        let schema_name = config
+            .search_path()
+            .iter()
+            .find(|schema_name| {
+                catalog
+                    .get_schema_by_name(session.database(), schema_name)
+                    .is_ok()
+            })
+            .map_or(DEFAULT_SCHEMA_NAME, |s| s.as_str())
+            .to_string();
+        Ok((schema_name, relation_name))
+    }
+
+    /// return the (`schema_name`, `table_name`) of a relation to read from. An unqualified name is
+    /// looked up in `pg_catalog` and then each schema of the `search_path` in order, falling back
+    /// to the first one.
+    pub(super) fn resolve_relation_name(&self, name: ObjectName) -> Result<(String, String)> {
+        Self::resolve_name_in_search_path(&self.catalog, &self.db_name, &self.search_path, name)
+    }
+
+    fn resolve_name_in_search_path(
+        catalog: &Catalog,
+        db_name: &str,
+        search_path: &[String],
+        name: ObjectName,
+    ) -> Result<(String, String)> {
+        if name.0.len() != 1 {
+            return Self::resolve_qualified_name(name);
+        }
+        let table_name = Self::resolve_single_name(name.0, "table name")?;
+        if get_system_catalog(PG_CATALOG_SCHEMA_NAME, &table_name).is_some() {
+            return Ok((PG_CATALOG_SCHEMA_NAME.to_string(), table_name));
+        }
+        let schema_name = search_path
+            .iter()
+            .find(|schema_name| {
+                catalog
+                    .get_schema_by_name(db_name, schema_name)
+                    .map(|schema| {
+                        schema.get_table_by_name(&table_name).is_some()
+                            || schema.get_source_by_name(&table_name).is_some()
+                    })
+                    .unwrap_or(false)
+            })
+            .or_else(|| search_path.first())
+            .map_or(DEFAULT_SCHEMA_NAME, |s| s.as_str())
+            .to_string();
+        Ok((schema_name, table_name))
+    }
+
     /// return the ( `database_name`, `schema_name`)
     pub fn resolve_schema_name(
         default_db_name: &str,
@@ -165,12 +267,20 @@ impl Binder {
         }
     }
 
-    pub(super) fn bind_table_factor(&mut self, table_factor: TableFactor) -> Result<Relation> {
+    /// Binds a relation in the FROM clause. If it's a `LATERAL` subquery or a table function, it
+    /// can refer to the columns of the items on its left from `lateral_begin`.
+    pub(super) fn bind_table_factor(
+        &mut self,
+        table_factor: TableFactor,
+        lateral_begin: usize,
+    ) -> Result<Relation> {
         match table_factor {
             TableFactor::Table { name, alias, args } => {
                 if args.is_empty() {
-                    let (schema_name, table_name) = Self::resolve_table_name(name)?;
-                    if let Some(bound_query) = self.cte_to_relation.get(&table_name) {
+                    let (schema_name, table_name) = self.resolve_relation_name(name)?;
+                    if let Some(work_table) = self.try_bind_work_table(&table_name)? {
+                        Ok(Relation::WorkTable(Box::new(work_table)))
+                    } else if let Some(bound_query) = self.cte_to_relation.get(&table_name) {
                         let (query, alias) = bound_query.clone();
                         self.bind_context(
                             query
@@ -182,15 +292,20 @@ impl Binder {
                             table_name,
                             Some(alias),
                         )?;
-                        Ok(Relation::Subquery(Box::new(BoundSubquery { query })))
+                        Ok(Relation::Subquery(Box::new(BoundSubquery {
+                            query,
+                            lateral: false,
+                        })))
                     } else {
                         self.bind_table_or_source(&schema_name, &table_name, alias)
                     }
                 } else {
                     let func_name = &name.0[0].value;
-                    if func_name.eq_ignore_ascii_case("generate_series") {
-                        return Ok(Relation::GenerateSeriesFunction(Box::new(
-                            self.bind_generate_series_function(args)?,
+                    if let Some(function_type) =
+                        TableFunctionType::from_name(&func_name.to_lowercase())
+                    {
+                        return Ok(Relation::TableFunction(Box::new(
+                            self.bind_table_function(function_type, alias, args, lateral_begin)?,
                         )));
                     }
                     let kind = WindowTableFunctionKind::from_str(func_name).map_err(|_| {
@@ -209,13 +324,12 @@ impl Binder {
                 subquery,
                 alias,
             } => {
-                if lateral {
-                    Err(ErrorCode::NotImplemented("unsupported lateral".into(), None.into()).into())
-                } else {
-                    Ok(Relation::Subquery(Box::new(
-                        self.bind_subquery_relation(*subquery, alias)?,
-                    )))
-                }
+                let lateral_begin = if lateral { Some(lateral_begin) } else { None };
+                Ok(Relation::Subquery(Box::new(self.bind_subquery_relation(
+                    *subquery,
+                    alias,
+                    lateral_begin,
+                )?)))
             }
             _ => Err(ErrorCode::NotImplemented(
                 format!("unsupported table factor {:?}", table_factor),
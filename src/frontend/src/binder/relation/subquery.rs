@@ -20,19 +20,30 @@ use crate::binder::{Binder, BoundQuery, UNNAMED_SUBQUERY};
 #[derive(Debug, Clone)]
 pub struct BoundSubquery {
     pub query: BoundQuery,
+    /// Whether it's a `LATERAL` subquery, which can refer to the items on its left.
+    pub lateral: bool,
 }
 
 impl Binder {
     /// Binds a subquery using [`bind_query`](Self::bind_query), which will use a new empty
     /// [`BindContext`](crate::binder::BindContext) for it.
     ///
+    /// A `LATERAL` subquery sees the columns of the items on its left, from `lateral_begin`, as
+    /// those of an outer query. Otherwise it sees none of them.
+    ///
     /// After finishing binding, we update the current context with the output of the subquery.
     pub(super) fn bind_subquery_relation(
         &mut self,
         query: Query,
         alias: Option<TableAlias>,
+        lateral_begin: Option<usize>,
     ) -> Result<BoundSubquery> {
-        let query = self.bind_query(query)?;
+        let begin = lateral_begin.unwrap_or(self.context.columns.len());
+        let lateral_context = self.context.lateral_context(begin);
+        let context = std::mem::replace(&mut self.context, lateral_context);
+        let query = self.bind_query(query);
+        self.context = context;
+        let query = query?;
         let sub_query_id = self.next_subquery_id();
 
         self.bind_context(
@@ -45,6 +56,9 @@ impl Binder {
             format!("{}_{}", UNNAMED_SUBQUERY, sub_query_id),
             alias,
         )?;
-        Ok(BoundSubquery { query })
+        Ok(BoundSubquery {
+            query,
+            lateral: lateral_begin.is_some(),
+        })
     }
 }
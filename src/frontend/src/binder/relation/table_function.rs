@@ -0,0 +1,92 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_sqlparser::ast::{FunctionArg, TableAlias};
+
+use super::Binder;
+use crate::expr::{CorrelatedInputRef, ExprImpl, ExprVisitor, TableFunction, TableFunctionType};
+
+impl Binder {
+    /// Binds a table function in the FROM clause, like `generate_series` or `unnest`, whose
+    /// columns are named after the function.
+    ///
+    /// As in `LATERAL` subqueries, its arguments see the columns of the items on its left, from
+    /// `lateral_begin`, as those of an outer query.
+    pub(super) fn bind_table_function(
+        &mut self,
+        function_type: TableFunctionType,
+        alias: Option<TableAlias>,
+        args: Vec<FunctionArg>,
+        lateral_begin: usize,
+    ) -> Result<TableFunction> {
+        let lateral_context = self.context.lateral_context(lateral_begin);
+        let context = std::mem::replace(&mut self.context, lateral_context);
+        self.push_context();
+        let args: Result<Vec<ExprImpl>> = args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect();
+        self.pop_context();
+        self.context = context;
+        let table_function = TableFunction::new(function_type, args?)?;
+
+        // The arguments can refer to the items on the left, but not to the outer queries.
+        let mut max_depth = MaxCorrelatedDepth { depth: 0 };
+        table_function
+            .args
+            .iter()
+            .for_each(|arg| max_depth.visit_expr(arg));
+        let is_valid = |arg: &ExprImpl| {
+            !(arg.has_input_ref()
+                || arg.has_agg_call()
+                || arg.has_subquery()
+                || arg.has_table_function())
+        };
+        if max_depth.depth > 1 || !table_function.args.iter().all(is_valid) {
+            return Err(ErrorCode::NotImplemented(
+                format!(
+                    "{} in the FROM clause with arguments other than constants and columns on \
+                     its left",
+                    function_type.name()
+                ),
+                None.into(),
+            )
+            .into());
+        }
+
+        self.bind_context(
+            table_function
+                .output_fields()
+                .into_iter()
+                .map(|field| (false, field)),
+            function_type.name().to_string(),
+            alias,
+        )?;
+        Ok(table_function)
+    }
+}
+
+/// Finds the maximum depth of the correlated input refs in an expression.
+struct MaxCorrelatedDepth {
+    depth: usize,
+}
+
+impl ExprVisitor for MaxCorrelatedDepth {
+    fn visit_correlated_input_ref(&mut self, correlated_input_ref: &CorrelatedInputRef) {
+        self.depth = self.depth.max(correlated_input_ref.depth());
+    }
+}
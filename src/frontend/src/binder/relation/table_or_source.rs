@@ -14,14 +14,20 @@
 
 use std::sync::Arc;
 
-use risingwave_common::catalog::ColumnDesc;
+use risingwave_common::array::Row;
+use risingwave_common::catalog::{ColumnDesc, Field, Schema};
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_pb::batch_plan::UniqueIndex as ProstUniqueIndex;
 use risingwave_sqlparser::ast::{ObjectName, TableAlias};
 
 use crate::binder::{Binder, Relation};
 use crate::catalog::source_catalog::SourceCatalog;
+use crate::catalog::system_catalog::{
+    get_system_catalog, is_system_schema, SystemCatalog, SystemCatalogContext,
+};
 use crate::catalog::table_catalog::TableCatalog;
 use crate::catalog::{CatalogError, TableId};
+use crate::optimizer::property::{Direction, FieldOrder, Order};
 
 #[derive(Debug, Clone)]
 pub struct BoundBaseTable {
@@ -32,11 +38,37 @@ pub struct BoundBaseTable {
 }
 
 /// `BoundTableSource` is used by DML statement on table source like insert, update.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundTableSource {
     pub name: String,       // explain-only
     pub source_id: TableId, // TODO: refactor to source id
     pub columns: Vec<ColumnDesc>,
+    /// The unique indexes on the table, if the source is associated with one.
+    pub unique_indexes: Vec<BoundUniqueIndex>,
+}
+
+/// A unique index on the table of a [`BoundTableSource`].
+#[derive(Debug, Clone)]
+pub struct BoundUniqueIndex {
+    pub index_id: TableId,
+    pub name: String,
+    /// The key columns of the index, referring to the `columns` of the table source.
+    pub key: Order,
+    /// The column of the index that stores the row id of the table.
+    pub row_id_column: ColumnDesc,
+}
+
+impl BoundUniqueIndex {
+    /// Serializes the index for the batch DML nodes, where `schema` is the schema of the `columns`
+    /// of the table source.
+    pub fn to_protobuf(&self, schema: &Schema) -> ProstUniqueIndex {
+        ProstUniqueIndex {
+            index_id: self.index_id.table_id,
+            name: self.name.clone(),
+            key: self.key.to_protobuf(schema),
+            row_id_column: Some((&self.row_id_column).into()),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -44,6 +76,14 @@ pub struct BoundSource {
     pub catalog: SourceCatalog,
 }
 
+/// A table in a system schema, with all its rows generated when it's bound.
+#[derive(Debug, Clone)]
+pub struct BoundSystemTable {
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub rows: Vec<Row>,
+}
+
 impl From<&SourceCatalog> for BoundSource {
     fn from(s: &SourceCatalog) -> Self {
         Self { catalog: s.clone() }
@@ -57,20 +97,11 @@ impl Binder {
         table_name: &str,
         alias: Option<TableAlias>,
     ) -> Result<Relation> {
-        if schema_name == "pg_catalog" {
-            // TODO: support pg_catalog.
-            return Err(ErrorCode::NotImplemented(
-                // TODO: We can ref the document of `SHOW` commands here if ready.
-                r###"pg_catalog is not supported, please use `SHOW` commands for now.
-`SHOW TABLES`,
-`SHOW MATERIALIZED VIEWS`,
-`DESCRIBE <table>`,
-`SHOW COLUMNS FROM [table]`
-"###
-                .into(),
-                1695.into(),
-            )
-            .into());
+        if let Some(system_catalog) = get_system_catalog(schema_name, table_name) {
+            return self.bind_system_table(system_catalog, alias);
+        }
+        if is_system_schema(schema_name) {
+            return Err(CatalogError::NotFound("table or source", table_name.to_string()).into());
         }
 
         let (ret, columns) = {
@@ -112,6 +143,29 @@ impl Binder {
         Ok(ret)
     }
 
+    fn bind_system_table(
+        &mut self,
+        system_catalog: &SystemCatalog,
+        alias: Option<TableAlias>,
+    ) -> Result<Relation> {
+        let rows = (system_catalog.rows)(&SystemCatalogContext {
+            catalog: &self.catalog,
+            db_name: &self.db_name,
+            users: &self.user_info.read_guard(),
+        })?;
+        let fields = system_catalog.fields();
+        self.bind_context(
+            fields.iter().map(|f| (false, f.clone())),
+            system_catalog.name.to_string(),
+            alias,
+        )?;
+        Ok(Relation::SystemTable(Box::new(BoundSystemTable {
+            name: system_catalog.name.to_string(),
+            fields,
+            rows,
+        })))
+    }
+
     fn resolve_table_indexes(
         &mut self,
         schema_name: &str,
@@ -159,7 +213,7 @@ impl Binder {
     }
 
     pub(crate) fn bind_table_source(&mut self, name: ObjectName) -> Result<BoundTableSource> {
-        let (schema_name, source_name) = Self::resolve_table_name(name)?;
+        let (schema_name, source_name) = self.resolve_relation_name(name)?;
         let source = self
             .catalog
             .get_source_by_name(&self.db_name, &schema_name, &source_name)?;
@@ -173,12 +227,69 @@ impl Binder {
             .map(|c| c.column_desc.clone())
             .collect();
 
+        let unique_indexes =
+            self.resolve_unique_indexes(&schema_name, &source_name, source_id, &columns)?;
+
         // Note(bugen): do not bind context here.
 
         Ok(BoundTableSource {
             name: source_name,
             source_id,
             columns,
+            unique_indexes,
         })
     }
+
+    /// Resolves the unique indexes on the table associated with the source `source_id`, with
+    /// their key columns mapped to `columns` by name.
+    fn resolve_unique_indexes(
+        &mut self,
+        schema_name: &str,
+        table_name: &str,
+        source_id: TableId,
+        columns: &[ColumnDesc],
+    ) -> Result<Vec<BoundUniqueIndex>> {
+        let table_id = match self
+            .catalog
+            .get_table_by_name(&self.db_name, schema_name, table_name)
+        {
+            Ok(table) if table.associated_source_id == Some(source_id) => table.id(),
+            _ => return Ok(vec![]),
+        };
+
+        self.resolve_table_indexes(schema_name, table_id)?
+            .into_iter()
+            .filter(|index| index.is_unique_index)
+            .map(|index| {
+                // The index is ordered by its key columns first, followed by the table pk, i.e.
+                // the row id.
+                let field_order = index.order_desc[..index.distribution_keys.len()]
+                    .iter()
+                    .map(|desc| {
+                        let column_idx = columns
+                            .iter()
+                            .position(|c| c.name == desc.column_desc.name)
+                            .ok_or_else(|| {
+                                ErrorCode::InternalError(format!(
+                                    "key column {} of index {} not found in the table",
+                                    desc.column_desc.name, index.name
+                                ))
+                            })?;
+                        Ok(FieldOrder {
+                            index: column_idx,
+                            direct: Direction::from(desc.order),
+                        })
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(BoundUniqueIndex {
+                    index_id: index.id(),
+                    name: index.name.clone(),
+                    key: Order::new(field_order),
+                    row_id_column: index.order_desc[index.distribution_keys.len()]
+                        .column_desc
+                        .clone(),
+                })
+            })
+            .collect()
+    }
 }
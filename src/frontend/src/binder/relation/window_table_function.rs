@@ -76,7 +76,7 @@ impl Binder {
             )
             .into()),
         }?;
-        let (schema_name, table_name) = Self::resolve_table_name(table_name)?;
+        let (schema_name, table_name) = self.resolve_relation_name(table_name)?;
 
         let base = self.bind_table_or_source(&schema_name, &table_name, None)?;
 
@@ -0,0 +1,56 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result};
+
+use crate::binder::Binder;
+
+/// A reference to a recursive cte in its recursive term, which reads the rows produced by the
+/// previous evaluation of the recursive term.
+#[derive(Debug, Clone)]
+pub struct BoundWorkTable {
+    pub id: u32,
+    pub schema: Schema,
+}
+
+impl Binder {
+    /// Binds `table_name` as a work table if it is a recursive cte whose recursive term is being
+    /// bound.
+    pub(super) fn try_bind_work_table(
+        &mut self,
+        table_name: &str,
+    ) -> Result<Option<BoundWorkTable>> {
+        let Some(cte) = self.recursive_ctes.get_mut(table_name) else {
+            return Ok(None);
+        };
+        if cte.referenced {
+            return Err(ErrorCode::BindError(format!(
+                "recursive reference to query \"{}\" must not appear more than once",
+                table_name
+            ))
+            .into());
+        }
+        cte.referenced = true;
+        let work_table = cte.work_table.clone();
+        let alias = cte.alias.clone();
+
+        self.bind_context(
+            work_table.schema.fields.iter().map(|f| (false, f.clone())),
+            table_name.to_string(),
+            Some(alias),
+        )?;
+        Ok(Some(work_table))
+    }
+}
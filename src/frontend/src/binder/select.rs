@@ -14,7 +14,7 @@
 
 use std::fmt::Debug;
 
-use itertools::Itertools;
+use itertools::{Either, Itertools};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
@@ -49,6 +49,7 @@ impl BoundSelect {
             .iter()
             .chain(self.group_by.iter())
             .chain(self.where_clause.iter())
+            .chain(self.having.iter())
             .any(|expr| expr.has_correlated_input_ref())
     }
 }
@@ -153,11 +154,21 @@ impl Binder {
                     aliases.extend(names);
                 }
                 SelectItem::Wildcard => {
-                    let (exprs, names) = Self::iter_bound_columns(
-                        self.context.columns[..].iter().filter(|c| !c.is_hidden),
-                    );
-                    select_list.extend(exprs);
-                    aliases.extend(names);
+                    let columns = self.context.wildcard_columns(0, self.context.columns.len());
+                    for column in columns {
+                        let (expr, name) = match column {
+                            Either::Left(c) => (
+                                InputRef::new(c.index, c.field.data_type.clone()).into(),
+                                c.field.name.clone(),
+                            ),
+                            Either::Right(c) => (
+                                self.context.bind_merged_column(&c.values, 0)?,
+                                c.name.clone(),
+                            ),
+                        };
+                        select_list.push(expr);
+                        aliases.push(Some(name));
+                    }
                 }
             }
         }
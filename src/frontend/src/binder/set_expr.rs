@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::catalog::Schema;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_sqlparser::ast::SetExpr;
+use risingwave_sqlparser::ast::{SetExpr, SetOperator};
 
 use crate::binder::{Binder, BoundSelect, BoundValues};
+use crate::expr::{align_types, ExprImpl, InputRef};
 
 /// Part of a validated query, without order or limit clause. It may be composed of smaller
 /// `BoundSetExpr`s via set operators (e.g. union).
@@ -24,6 +26,24 @@ use crate::binder::{Binder, BoundSelect, BoundValues};
 pub enum BoundSetExpr {
     Select(Box<BoundSelect>),
     Values(Box<BoundValues>),
+    /// `UNION`, `INTERSECT` or `EXCEPT`, with or without `ALL`. The output columns are named after
+    /// the left side, and their types are aligned over both sides.
+    SetOperation {
+        op: SetOperator,
+        all: bool,
+        left: Box<BoundSetExpr>,
+        right: Box<BoundSetExpr>,
+        schema: Schema,
+    },
+    /// `base UNION [ALL] recursive` in `WITH RECURSIVE`, where `recursive` refers to the rows
+    /// produced by its previous evaluation as the work table `work_table_id`, until no new rows
+    /// are produced. The output columns are those of `base`.
+    RecursiveUnion {
+        all: bool,
+        base: Box<BoundSetExpr>,
+        recursive: Box<BoundSetExpr>,
+        work_table_id: u32,
+    },
 }
 
 impl BoundSetExpr {
@@ -33,6 +53,8 @@ impl BoundSetExpr {
         match self {
             BoundSetExpr::Select(s) => s.schema(),
             BoundSetExpr::Values(v) => v.schema(),
+            BoundSetExpr::SetOperation { schema, .. } => schema,
+            BoundSetExpr::RecursiveUnion { base, .. } => base.schema(),
         }
     }
 
@@ -40,6 +62,12 @@ impl BoundSetExpr {
         match self {
             BoundSetExpr::Select(s) => s.is_correlated(),
             BoundSetExpr::Values(_) => false,
+            BoundSetExpr::SetOperation { left, right, .. } => {
+                left.is_correlated() || right.is_correlated()
+            }
+            BoundSetExpr::RecursiveUnion {
+                base, recursive, ..
+            } => base.is_correlated() || recursive.is_correlated(),
         }
     }
 }
@@ -49,7 +77,91 @@ impl Binder {
         match set_expr {
             SetExpr::Select(s) => Ok(BoundSetExpr::Select(Box::new(self.bind_select(*s)?))),
             SetExpr::Values(v) => Ok(BoundSetExpr::Values(Box::new(self.bind_values(v, None)?))),
+            // A parenthesized query is only a set expression if it has no other clauses.
+            SetExpr::Query(q)
+                if q.with.is_none()
+                    && q.order_by.is_empty()
+                    && q.limit.is_none()
+                    && q.offset.is_none()
+                    && q.fetch.is_none() =>
+            {
+                self.bind_set_expr(q.body)
+            }
+            SetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+            } => self.bind_set_operation(op, all, *left, *right),
             _ => Err(ErrorCode::NotImplemented(format!("{:?}", set_expr), None.into()).into()),
         }
     }
+
+    fn bind_set_operation(
+        &mut self,
+        op: SetOperator,
+        all: bool,
+        left: SetExpr,
+        right: SetExpr,
+    ) -> Result<BoundSetExpr> {
+        let left = self.bind_set_expr(left)?;
+        // The right side is bound in a fresh context, so that it doesn't see the relations of the
+        // left side. The outer queries stay visible to both sides.
+        let left_context = std::mem::take(&mut self.context);
+        let right = self.bind_set_expr(right);
+        self.context = left_context;
+        let right = right?;
+        Self::align_set_operation(op, all, left, right)
+    }
+
+    /// Builds a set operation over the bound sides, whose columns are of the aligned types.
+    pub(super) fn align_set_operation(
+        op: SetOperator,
+        all: bool,
+        left: BoundSetExpr,
+        right: BoundSetExpr,
+    ) -> Result<BoundSetExpr> {
+        let left_types = left.schema().data_types();
+        let right_types = right.schema().data_types();
+        if left_types.len() != right_types.len() {
+            return Err(ErrorCode::BindError(format!(
+                "each {} query must have the same number of columns",
+                op
+            ))
+            .into());
+        }
+        let fields = left
+            .schema()
+            .fields()
+            .iter()
+            .zip_eq(right_types)
+            .enumerate()
+            .map(|(i, (field, right_type))| {
+                let mut exprs: [ExprImpl; 2] = [
+                    InputRef::new(i, field.data_type()).into(),
+                    InputRef::new(i, right_type.clone()).into(),
+                ];
+                let data_type = align_types(exprs.iter_mut()).map_err(|_| {
+                    ErrorCode::BindError(format!(
+                        "{} types {:?} and {:?} cannot be matched",
+                        op,
+                        field.data_type(),
+                        right_type
+                    ))
+                })?;
+                Ok(Field {
+                    data_type,
+                    ..field.clone()
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(BoundSetExpr::SetOperation {
+            op,
+            all,
+            left: Box::new(left),
+            right: Box::new(right),
+            schema: Schema { fields },
+        })
+    }
 }
@@ -197,6 +197,15 @@ impl Binder {
     /// `name` is the alias of the expr.
     pub fn expr_to_field(&self, item: &ExprImpl, name: String) -> Result<Field> {
         if let DataType::Struct { .. } = item.return_type() {
+            // The structs of a table function like `jsonb_each` are named as its columns.
+            if let ExprImpl::TableFunction(table_function) = item {
+                return Ok(Field::with_struct(
+                    item.return_type(),
+                    name,
+                    table_function.output_fields(),
+                    String::new(),
+                ));
+            }
             // Derive the schema of a struct including its fields name.
             // NOTE: The implementation assumes that only Min/Max/Field/InputRef
             // will return a STRUCT. Because we assumes the first argument (if in a function form)
@@ -24,7 +24,7 @@ use risingwave_sqlparser::ast::{Assignment, Expr, TableFactor, TableWithJoins};
 use super::{Binder, BoundTableSource, Relation};
 use crate::expr::{Expr as _, ExprImpl};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BoundUpdate {
     /// Used for injecting new chunks to the source.
     pub table_source: BoundTableSource,
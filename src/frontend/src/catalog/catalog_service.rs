@@ -21,7 +21,9 @@ use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    TableStatistics as ProstTableStatistics,
 };
+use risingwave_pb::ddl_service::comment_on_request;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_rpc_client::MetaClient;
 use tokio::sync::watch::Receiver;
@@ -77,6 +79,19 @@ pub trait CatalogWriter: Send + Sync {
     async fn drop_database(&self, database_id: u32) -> Result<()>;
 
     async fn drop_schema(&self, schema_id: u32) -> Result<()>;
+
+    async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<()>;
+
+    async fn comment_on(
+        &self,
+        object: comment_on_request::Object,
+        column_name: String,
+        comment: String,
+    ) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -168,6 +183,31 @@ impl CatalogWriter for CatalogWriterImpl {
         let version = self.meta_client.drop_database(database_id).await?;
         self.wait_version(version).await
     }
+
+    async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .update_table_statistics(table_id, statistics)
+            .await?;
+        self.wait_version(version).await
+    }
+
+    async fn comment_on(
+        &self,
+        object: comment_on_request::Object,
+        column_name: String,
+        comment: String,
+    ) -> Result<()> {
+        let version = self
+            .meta_client
+            .comment_on(object, column_name, comment)
+            .await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
@@ -24,6 +24,8 @@ use super::row_id_column_desc;
 pub struct ColumnCatalog {
     pub column_desc: ColumnDesc,
     pub is_hidden: bool,
+    /// Set by `COMMENT ON COLUMN`.
+    pub description: Option<String>,
 }
 
 impl ColumnCatalog {
@@ -52,6 +54,7 @@ impl ColumnCatalog {
         ProstColumnCatalog {
             column_desc: Some(self.column_desc.to_protobuf()),
             is_hidden: self.is_hidden,
+            description: self.description.clone().unwrap_or_default(),
         }
     }
 
@@ -60,6 +63,7 @@ impl ColumnCatalog {
         Self {
             column_desc: row_id_column_desc(),
             is_hidden: true,
+            description: None,
         }
     }
 
@@ -77,6 +81,7 @@ impl From<ProstColumnCatalog> for ColumnCatalog {
         Self {
             column_desc: prost.column_desc.unwrap().into(),
             is_hidden: prost.is_hidden,
+            description: Some(prost.description).filter(|d| !d.is_empty()),
         }
     }
 }
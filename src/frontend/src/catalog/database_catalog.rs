@@ -22,7 +22,6 @@ use crate::catalog::{DatabaseId, SchemaId};
 #[derive(Clone, Debug)]
 pub struct DatabaseCatalog {
     id: DatabaseId,
-    #[allow(dead_code)]
     name: String,
     schema_by_name: HashMap<String, SchemaCatalog>,
     schema_name_by_id: HashMap<SchemaId, String>,
@@ -48,10 +47,19 @@ impl DatabaseCatalog {
         self.schema_by_name.keys().cloned().collect_vec()
     }
 
+    pub fn iter_schemas(&self) -> impl Iterator<Item = &SchemaCatalog> {
+        self.schema_by_name.values()
+    }
+
     pub fn get_schema_by_name(&self, name: &str) -> Option<&SchemaCatalog> {
         self.schema_by_name.get(name)
     }
 
+    pub fn get_schema_by_id(&self, schema_id: SchemaId) -> Option<&SchemaCatalog> {
+        self.schema_by_name
+            .get(self.schema_name_by_id.get(&schema_id)?)
+    }
+
     pub fn get_schema_mut(&mut self, schema_id: SchemaId) -> Option<&mut SchemaCatalog> {
         let name = self.schema_name_by_id.get(&schema_id).unwrap();
         self.schema_by_name.get_mut(name)
@@ -64,6 +72,10 @@ impl DatabaseCatalog {
     pub fn id(&self) -> DatabaseId {
         self.id
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 impl From<&ProstDatabase> for DatabaseCatalog {
     fn from(db: &ProstDatabase) -> Self {
@@ -90,6 +90,14 @@ impl Catalog {
             .create_table(proto);
     }
 
+    pub fn update_table(&mut self, proto: &ProstTable) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .update_table(proto);
+    }
+
     pub fn create_source(&mut self, proto: ProstSource) {
         self.get_database_mut(proto.database_id)
             .unwrap()
@@ -98,6 +106,14 @@ impl Catalog {
             .create_source(proto);
     }
 
+    pub fn update_source(&mut self, proto: &ProstSource) {
+        self.get_database_mut(proto.database_id)
+            .unwrap()
+            .get_schema_mut(proto.schema_id)
+            .unwrap()
+            .update_source(proto);
+    }
+
     pub fn drop_database(&mut self, db_id: DatabaseId) {
         let name = self.db_name_by_id.remove(&db_id).unwrap();
         let _database = self.database_by_name.remove(&name).unwrap();
@@ -123,6 +139,18 @@ impl Catalog {
             .drop_source(source_id);
     }
 
+    pub fn get_table_by_id(
+        &self,
+        db_id: DatabaseId,
+        schema_id: SchemaId,
+        table_id: &TableId,
+    ) -> Option<&TableCatalog> {
+        self.database_by_name
+            .get(self.db_name_by_id.get(&db_id)?)?
+            .get_schema_by_id(schema_id)?
+            .get_table_by_id(table_id)
+    }
+
     pub fn get_database_by_name(&self, db_name: &str) -> Result<&DatabaseCatalog> {
         self.database_by_name
             .get(db_name)
@@ -137,6 +165,10 @@ impl Catalog {
         self.database_by_name.keys().cloned().collect_vec()
     }
 
+    pub fn iter_databases(&self) -> impl Iterator<Item = &DatabaseCatalog> {
+        self.database_by_name.values()
+    }
+
     pub fn get_schema_by_name(&self, db_name: &str, schema_name: &str) -> Result<&SchemaCatalog> {
         self.get_database_by_name(db_name)?
             .get_schema_by_name(schema_name)
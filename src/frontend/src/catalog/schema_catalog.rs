@@ -27,7 +27,6 @@ pub type SourceId = u32;
 #[derive(Clone, Debug)]
 pub struct SchemaCatalog {
     id: SchemaId,
-    #[allow(dead_code)]
     name: String,
     table_by_name: HashMap<String, TableCatalog>,
     table_name_by_id: HashMap<TableId, String>,
@@ -50,6 +49,12 @@ impl SchemaCatalog {
         self.table_by_name.remove(&name).unwrap();
     }
 
+    /// Replace the catalog of an existing table, e.g. after its statistics are updated.
+    pub fn update_table(&mut self, prost: &ProstTable) {
+        let name = self.table_name_by_id.get(&prost.id.into()).unwrap();
+        *self.table_by_name.get_mut(name).unwrap() = prost.into();
+    }
+
     pub fn create_source(&mut self, prost: ProstSource) {
         let name = prost.name.clone();
         let id = prost.id;
@@ -60,6 +65,12 @@ impl SchemaCatalog {
         self.source_name_by_id.try_insert(id, name).unwrap();
     }
 
+    /// Replace the catalog of an existing source, e.g. after its comment is changed.
+    pub fn update_source(&mut self, prost: &ProstSource) {
+        let name = self.source_name_by_id.get(&prost.id).unwrap();
+        *self.source_by_name.get_mut(name).unwrap() = SourceCatalog::from(prost);
+    }
+
     pub fn drop_source(&mut self, id: SourceId) {
         let name = self.source_name_by_id.remove(&id).unwrap();
         self.source_by_name.remove(&name).unwrap();
@@ -120,9 +131,17 @@ impl SchemaCatalog {
         self.source_by_name.get(source_name)
     }
 
+    pub fn get_table_by_id(&self, table_id: &TableId) -> Option<&TableCatalog> {
+        self.table_by_name.get(self.table_name_by_id.get(table_id)?)
+    }
+
     pub fn id(&self) -> SchemaId {
         self.id
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 impl From<&ProstSchema> for SchemaCatalog {
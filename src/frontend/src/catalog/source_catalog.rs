@@ -27,6 +27,8 @@ pub struct SourceCatalog {
     pub columns: Vec<ColumnCatalog>,
     pub pk_col_ids: Vec<ColumnId>,
     pub source_type: SourceType,
+    /// Set by `COMMENT ON SOURCE`.
+    pub description: Option<String>,
 }
 
 impl SourceCatalog {
@@ -40,9 +42,17 @@ impl SourceCatalog {
                     .column_desc
                     .flatten()
                     .into_iter()
-                    .map(|c| ColumnCatalog {
-                        column_desc: c,
-                        is_hidden: col.is_hidden,
+                    .map(|c| {
+                        // Nested fields don't have comments of their own.
+                        let description = col
+                            .description
+                            .clone()
+                            .filter(|_| c.column_id == col.column_id());
+                        ColumnCatalog {
+                            column_desc: c,
+                            is_hidden: col.is_hidden,
+                            description,
+                        }
                     })
                     .collect_vec(),
             )
@@ -80,6 +90,7 @@ impl From<&ProstSource> for SourceCatalog {
             columns,
             pk_col_ids,
             source_type,
+            description: Some(prost.description.clone()).filter(|d| !d.is_empty()),
         }
     }
 }
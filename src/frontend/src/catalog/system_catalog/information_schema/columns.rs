@@ -0,0 +1,87 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{
+    iter_relations, numbered_columns, RelationKind, SystemCatalog, SystemCatalogContext,
+};
+
+/// The visible columns of the tables, materialized views and sources in the current database.
+/// See <https://www.postgresql.org/docs/current/infoschema-columns.html>.
+pub static COLUMNS: SystemCatalog = SystemCatalog {
+    name: "columns",
+    columns: &[
+        (DataType::Varchar, "table_catalog"),
+        (DataType::Varchar, "table_schema"),
+        (DataType::Varchar, "table_name"),
+        (DataType::Varchar, "column_name"),
+        (DataType::Int32, "ordinal_position"),
+        (DataType::Varchar, "is_nullable"),
+        (DataType::Varchar, "data_type"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut rows = vec![];
+    for schema in ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+    {
+        for relation in iter_relations(schema).filter(|r| r.kind != RelationKind::Index) {
+            rows.extend(numbered_columns(relation.columns).map(|(i, column)| {
+                Row(vec![
+                    Some(ScalarImpl::Utf8(ctx.db_name.to_string())),
+                    Some(ScalarImpl::Utf8(schema.name().to_string())),
+                    Some(ScalarImpl::Utf8(relation.name.to_string())),
+                    Some(ScalarImpl::Utf8(column.name().to_string())),
+                    Some(ScalarImpl::Int32(i as i32)),
+                    Some(ScalarImpl::Utf8("YES".to_string())),
+                    Some(ScalarImpl::Utf8(
+                        sql_type_name(column.data_type()).to_string(),
+                    )),
+                ])
+            }));
+        }
+    }
+    Ok(rows)
+}
+
+/// The name of the type in the SQL standard, which is what `data_type` shows.
+fn sql_type_name(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int16 => "smallint",
+        DataType::Int32 => "integer",
+        DataType::Int64 => "bigint",
+        DataType::Float32 => "real",
+        DataType::Float64 => "double precision",
+        DataType::Decimal => "numeric",
+        DataType::Date => "date",
+        DataType::Varchar => "character varying",
+        DataType::Time => "time without time zone",
+        DataType::Timestamp => "timestamp without time zone",
+        DataType::Timestampz => "timestamp with time zone",
+        DataType::Interval => "interval",
+        DataType::Jsonb => "jsonb",
+        DataType::Bytea => "bytea",
+        DataType::Uuid => "uuid",
+        DataType::Struct { .. } => "USER-DEFINED",
+        DataType::List { .. } => "ARRAY",
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod columns;
+mod schemata;
+mod tables;
+
+use super::SystemCatalog;
+
+pub const INFORMATION_SCHEMA_SCHEMA_NAME: &str = "information_schema";
+/// The OID of `information_schema` in `pg_namespace`.
+pub const INFORMATION_SCHEMA_NAMESPACE_OID: i32 = 13000;
+
+/// All views in `information_schema`.
+pub static INFORMATION_SCHEMA_TABLES: &[&SystemCatalog] =
+    &[&columns::COLUMNS, &schemata::SCHEMATA, &tables::TABLES];
@@ -0,0 +1,41 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{iter_schemas, SystemCatalog, SystemCatalogContext};
+
+/// The schemas in the current database, including the system schemas.
+/// See <https://www.postgresql.org/docs/current/infoschema-schemata.html>.
+pub static SCHEMATA: SystemCatalog = SystemCatalog {
+    name: "schemata",
+    columns: &[
+        (DataType::Varchar, "catalog_name"),
+        (DataType::Varchar, "schema_name"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    Ok(iter_schemas(ctx)?
+        .map(|(_, name)| {
+            Row(vec![
+                Some(ScalarImpl::Utf8(ctx.db_name.to_string())),
+                Some(ScalarImpl::Utf8(name.to_string())),
+            ])
+        })
+        .collect())
+}
@@ -0,0 +1,60 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{
+    iter_relations, RelationKind, SystemCatalog, SystemCatalogContext,
+};
+
+/// The tables, materialized views and sources in the current database. Materialized views are
+/// listed as views, and sources as foreign tables.
+/// See <https://www.postgresql.org/docs/current/infoschema-tables.html>.
+pub static TABLES: SystemCatalog = SystemCatalog {
+    name: "tables",
+    columns: &[
+        (DataType::Varchar, "table_catalog"),
+        (DataType::Varchar, "table_schema"),
+        (DataType::Varchar, "table_name"),
+        (DataType::Varchar, "table_type"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut rows = vec![];
+    for schema in ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+    {
+        for relation in iter_relations(schema) {
+            let table_type = match relation.kind {
+                RelationKind::Table => "BASE TABLE",
+                RelationKind::MaterializedView => "VIEW",
+                RelationKind::Source => "FOREIGN",
+                RelationKind::Index => continue,
+            };
+            rows.push(Row(vec![
+                Some(ScalarImpl::Utf8(ctx.db_name.to_string())),
+                Some(ScalarImpl::Utf8(schema.name().to_string())),
+                Some(ScalarImpl::Utf8(relation.name.to_string())),
+                Some(ScalarImpl::Utf8(table_type.to_string())),
+            ]));
+        }
+    }
+    Ok(rows)
+}
@@ -0,0 +1,289 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! System catalogs, i.e. the read-only tables in system schemas like `pg_catalog`. They are not
+//! stored anywhere, and their rows are generated from the frontend [`Catalog`] when they are read.
+
+pub mod information_schema;
+pub mod pg_catalog;
+
+use risingwave_common::array::Row;
+use risingwave_common::catalog::Field;
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use crate::catalog::column_catalog::ColumnCatalog;
+use crate::catalog::root_catalog::Catalog;
+use crate::catalog::schema_catalog::SchemaCatalog;
+use crate::user::user_manager::UserInfoManager;
+
+/// The OID of `pg_class` in postgres, i.e. the `classoid` of objects listed in `pg_class`.
+pub const PG_CLASS_OID: i32 = 1259;
+
+/// What the rows of a system catalog are generated from.
+pub struct SystemCatalogContext<'a> {
+    pub catalog: &'a Catalog,
+    /// The name of the current database.
+    pub db_name: &'a str,
+    pub users: &'a UserInfoManager,
+}
+
+/// A table in a system schema.
+pub struct SystemCatalog {
+    pub name: &'static str,
+    pub columns: &'static [(DataType, &'static str)],
+    pub rows: fn(&SystemCatalogContext<'_>) -> Result<Vec<Row>>,
+}
+
+impl SystemCatalog {
+    pub fn fields(&self) -> Vec<Field> {
+        self.columns
+            .iter()
+            .map(|(data_type, name)| Field::with_name(data_type.clone(), *name))
+            .collect()
+    }
+}
+
+/// Get the system catalog `schema_name.table_name`, or `None` if it isn't a system catalog.
+pub fn get_system_catalog(schema_name: &str, table_name: &str) -> Option<&'static SystemCatalog> {
+    let tables = match schema_name {
+        pg_catalog::PG_CATALOG_SCHEMA_NAME => pg_catalog::PG_CATALOG_TABLES,
+        information_schema::INFORMATION_SCHEMA_SCHEMA_NAME => {
+            information_schema::INFORMATION_SCHEMA_TABLES
+        }
+        _ => return None,
+    };
+    tables
+        .iter()
+        .find(|table| table.name == table_name)
+        .copied()
+}
+
+/// Whether `schema_name` is a system schema, whose tables are all system catalogs.
+pub fn is_system_schema(schema_name: &str) -> bool {
+    schema_name == pg_catalog::PG_CATALOG_SCHEMA_NAME
+        || schema_name == information_schema::INFORMATION_SCHEMA_SCHEMA_NAME
+}
+
+/// The kinds of relations listed in the system catalogs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RelationKind {
+    Table,
+    MaterializedView,
+    Index,
+    Source,
+}
+
+/// A relation in a schema, as listed in the system catalogs.
+struct RelationInfo<'a> {
+    id: u32,
+    name: &'a str,
+    kind: RelationKind,
+    columns: &'a [ColumnCatalog],
+    description: Option<&'a str>,
+}
+
+/// All relations in the schema, sorted by their ids.
+fn iter_relations(schema: &SchemaCatalog) -> impl Iterator<Item = RelationInfo<'_>> {
+    let tables = schema
+        .iter_table()
+        .map(|t| (t, RelationKind::Table))
+        .chain(
+            schema
+                .iter_mv()
+                .map(|t| (t, RelationKind::MaterializedView)),
+        )
+        .chain(schema.iter_index().map(|t| (t, RelationKind::Index)))
+        .map(|(t, kind)| RelationInfo {
+            id: t.id().table_id,
+            name: t.name(),
+            kind,
+            columns: t.columns(),
+            description: t.description.as_deref(),
+        });
+    let sources = schema.iter_source().map(|s| RelationInfo {
+        id: s.id,
+        name: &s.name,
+        kind: RelationKind::Source,
+        columns: &s.columns,
+        description: s.description.as_deref(),
+    });
+    let mut relations = tables.chain(sources).collect::<Vec<_>>();
+    relations.sort_by_key(|r| r.id);
+    relations.into_iter()
+}
+
+/// The visible columns with their numbers, counted from 1 as `attnum` in postgres.
+fn numbered_columns(columns: &[ColumnCatalog]) -> impl Iterator<Item = (i16, &ColumnCatalog)> {
+    columns
+        .iter()
+        .filter(|c| !c.is_hidden)
+        .enumerate()
+        .map(|(i, c)| (i as i16 + 1, c))
+}
+
+/// All schemas in the current database, including the system schemas, with their OIDs. The
+/// system schemas come first.
+fn iter_schemas<'a>(
+    ctx: &'a SystemCatalogContext<'_>,
+) -> Result<impl Iterator<Item = (i32, &'a str)>> {
+    let mut schemas = ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+        .map(|s| (s.id() as i32, s.name()))
+        .collect::<Vec<_>>();
+    schemas.sort();
+    Ok([
+        (
+            pg_catalog::PG_CATALOG_NAMESPACE_OID,
+            pg_catalog::PG_CATALOG_SCHEMA_NAME,
+        ),
+        (
+            information_schema::INFORMATION_SCHEMA_NAMESPACE_OID,
+            information_schema::INFORMATION_SCHEMA_SCHEMA_NAME,
+        ),
+    ]
+    .into_iter()
+    .chain(schemas))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::DEFAULT_DATABASE_NAME;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    async fn read(frontend: &LocalFrontend, schema_name: &str, table_name: &str) -> Vec<Row> {
+        let session = frontend.session_ref();
+        let table = get_system_catalog(schema_name, table_name).unwrap();
+        (table.rows)(&SystemCatalogContext {
+            catalog: &session.env().catalog_reader().read_guard(),
+            db_name: DEFAULT_DATABASE_NAME,
+            users: &session.env().user_info_reader().read_guard(),
+        })
+        .unwrap()
+    }
+
+    fn utf8(s: &str) -> Option<ScalarImpl> {
+        Some(ScalarImpl::Utf8(s.to_string()))
+    }
+
+    #[tokio::test]
+    async fn test_system_catalogs() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (v1 int, v2 varchar)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("create index idx on t (v2)")
+            .await
+            .unwrap();
+
+        let pg_class = read(&frontend, "pg_catalog", "pg_class").await;
+        let relations = pg_class
+            .iter()
+            .map(|row| (row.0[1].clone(), row.0[3].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            relations,
+            vec![(utf8("t"), utf8("r")), (utf8("idx"), utf8("i"))]
+        );
+        let t_oid = pg_class[0].0[0].clone();
+        let idx_oid = pg_class[1].0[0].clone();
+
+        let attributes = read(&frontend, "pg_catalog", "pg_attribute")
+            .await
+            .into_iter()
+            .filter(|row| row.0[0] == t_oid)
+            .map(|row| (row.0[1].clone(), row.0[2].clone(), row.0[3].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            attributes,
+            vec![
+                (
+                    utf8("v1"),
+                    Some(ScalarImpl::Int32(23)),
+                    Some(ScalarImpl::Int16(1))
+                ),
+                (
+                    utf8("v2"),
+                    Some(ScalarImpl::Int32(1043)),
+                    Some(ScalarImpl::Int16(2))
+                ),
+            ]
+        );
+
+        let pg_index = read(&frontend, "pg_catalog", "pg_index").await;
+        assert_eq!(pg_index.len(), 1);
+        assert_eq!(pg_index[0].0[0], idx_oid);
+        assert_eq!(pg_index[0].0[1], t_oid);
+        assert_eq!(pg_index[0].0[5], utf8("2"));
+
+        let tables = read(&frontend, "information_schema", "tables").await;
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].0[2..], [utf8("t"), utf8("BASE TABLE")]);
+
+        let columns = read(&frontend, "information_schema", "columns")
+            .await
+            .into_iter()
+            .map(|row| (row.0[3].clone(), row.0[6].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            columns,
+            vec![
+                (utf8("v1"), utf8("integer")),
+                (utf8("v2"), utf8("character varying"))
+            ]
+        );
+
+        let schemas = read(&frontend, "information_schema", "schemata")
+            .await
+            .into_iter()
+            .map(|row| row.0[1].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            schemas[..2],
+            [utf8("pg_catalog"), utf8("information_schema")]
+        );
+        assert!(schemas.contains(&utf8("dev")));
+
+        frontend
+            .run_sql("explain select relname from pg_class where relkind = 'r'")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("explain select * from information_schema.columns")
+            .await
+            .unwrap();
+        assert!(frontend
+            .run_sql("explain select * from pg_catalog.pg_foo")
+            .await
+            .is_err());
+
+        // The query of psql `\d t` for the columns of `t`.
+        let describe_columns = "explain select a.attname, pg_catalog.format_type(a.atttypid, \
+                                a.atttypmod), a.attnotnull from pg_catalog.pg_attribute a \
+                                where a.attrelid = 't'::regclass and a.attnum > 0 and not \
+                                a.attisdropped order by a.attnum";
+        frontend.run_sql(describe_columns).await.unwrap();
+        assert!(frontend
+            .run_sql("explain select 'no_such_table'::regclass")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod pg_attribute;
+mod pg_class;
+mod pg_database;
+mod pg_description;
+mod pg_index;
+mod pg_namespace;
+mod pg_type;
+mod pg_user;
+
+pub use pg_type::{type_oid, type_oids_with_format_names};
+
+use super::SystemCatalog;
+
+pub const PG_CATALOG_SCHEMA_NAME: &str = "pg_catalog";
+/// The OID of `pg_catalog` in `pg_namespace`, as in postgres.
+pub const PG_CATALOG_NAMESPACE_OID: i32 = 11;
+
+/// All tables in `pg_catalog`.
+pub static PG_CATALOG_TABLES: &[&SystemCatalog] = &[
+    &pg_attribute::PG_ATTRIBUTE,
+    &pg_class::PG_CLASS,
+    &pg_database::PG_DATABASE,
+    &pg_description::PG_DESCRIPTION,
+    &pg_index::PG_INDEX,
+    &pg_namespace::PG_NAMESPACE,
+    &pg_type::PG_TYPE,
+    &pg_user::PG_USER,
+];
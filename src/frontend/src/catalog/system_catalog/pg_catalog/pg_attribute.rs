@@ -0,0 +1,64 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::type_oid;
+use crate::catalog::system_catalog::{
+    iter_relations, numbered_columns, SystemCatalog, SystemCatalogContext,
+};
+
+/// The visible columns of all relations in the current database. Hidden columns such as the row
+/// id are not listed.
+/// See <https://www.postgresql.org/docs/current/catalog-pg-attribute.html>.
+pub static PG_ATTRIBUTE: SystemCatalog = SystemCatalog {
+    name: "pg_attribute",
+    columns: &[
+        (DataType::Int32, "attrelid"),
+        (DataType::Varchar, "attname"),
+        (DataType::Int32, "atttypid"),
+        (DataType::Int16, "attnum"),
+        (DataType::Int32, "atttypmod"),
+        (DataType::Boolean, "attnotnull"),
+        (DataType::Boolean, "attisdropped"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut rows = vec![];
+    for schema in ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+    {
+        for relation in iter_relations(schema) {
+            rows.extend(numbered_columns(relation.columns).map(|(attnum, column)| {
+                Row(vec![
+                    Some(ScalarImpl::Int32(relation.id as i32)),
+                    Some(ScalarImpl::Utf8(column.name().to_string())),
+                    Some(ScalarImpl::Int32(type_oid(column.data_type()))),
+                    Some(ScalarImpl::Int16(attnum)),
+                    // No type has any modifier.
+                    Some(ScalarImpl::Int32(-1)),
+                    Some(ScalarImpl::Bool(false)),
+                    Some(ScalarImpl::Bool(false)),
+                ])
+            }));
+        }
+    }
+    Ok(rows)
+}
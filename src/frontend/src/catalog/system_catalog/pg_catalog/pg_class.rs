@@ -0,0 +1,75 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{
+    iter_relations, numbered_columns, RelationKind, SystemCatalog, SystemCatalogContext,
+};
+
+/// The tables, materialized views, indexes and sources in the current database.
+/// See <https://www.postgresql.org/docs/current/catalog-pg-class.html>.
+pub static PG_CLASS: SystemCatalog = SystemCatalog {
+    name: "pg_class",
+    columns: &[
+        (DataType::Int32, "oid"),
+        (DataType::Varchar, "relname"),
+        (DataType::Int32, "relnamespace"),
+        (DataType::Varchar, "relkind"),
+        (DataType::Boolean, "relhasindex"),
+        (DataType::Int16, "relnatts"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut rows = vec![];
+    for schema in ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+    {
+        let indexed = schema
+            .iter_index()
+            .filter_map(|index| index.is_index_on)
+            .collect::<Vec<_>>();
+        for relation in iter_relations(schema) {
+            rows.push(Row(vec![
+                Some(ScalarImpl::Int32(relation.id as i32)),
+                Some(ScalarImpl::Utf8(relation.name.to_string())),
+                Some(ScalarImpl::Int32(schema.id() as i32)),
+                Some(ScalarImpl::Utf8(relkind(relation.kind).to_string())),
+                Some(ScalarImpl::Bool(
+                    indexed.iter().any(|id| id.table_id == relation.id),
+                )),
+                Some(ScalarImpl::Int16(
+                    numbered_columns(relation.columns).count() as i16,
+                )),
+            ]));
+        }
+    }
+    Ok(rows)
+}
+
+/// Sources are shown as foreign tables, since they can't be queried unless materialized.
+fn relkind(kind: RelationKind) -> &'static str {
+    match kind {
+        RelationKind::Table => "r",
+        RelationKind::MaterializedView => "m",
+        RelationKind::Index => "i",
+        RelationKind::Source => "f",
+    }
+}
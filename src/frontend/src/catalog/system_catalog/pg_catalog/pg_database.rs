@@ -0,0 +1,61 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{SystemCatalog, SystemCatalogContext};
+
+/// The encoding of all databases, which is `UTF8` in postgres.
+const UTF8_ENCODING: i32 = 6;
+
+/// All databases.
+/// See <https://www.postgresql.org/docs/current/catalog-pg-database.html>.
+pub static PG_DATABASE: SystemCatalog = SystemCatalog {
+    name: "pg_database",
+    columns: &[
+        (DataType::Int32, "oid"),
+        (DataType::Varchar, "datname"),
+        (DataType::Int32, "encoding"),
+        (DataType::Varchar, "datcollate"),
+        (DataType::Varchar, "datctype"),
+        (DataType::Boolean, "datistemplate"),
+        (DataType::Boolean, "datallowconn"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut databases = ctx
+        .catalog
+        .iter_databases()
+        .map(|db| (db.id() as i32, db.name()))
+        .collect::<Vec<_>>();
+    databases.sort();
+    Ok(databases
+        .into_iter()
+        .map(|(oid, name)| {
+            Row(vec![
+                Some(ScalarImpl::Int32(oid)),
+                Some(ScalarImpl::Utf8(name.to_string())),
+                Some(ScalarImpl::Int32(UTF8_ENCODING)),
+                Some(ScalarImpl::Utf8("C".to_string())),
+                Some(ScalarImpl::Utf8("C".to_string())),
+                Some(ScalarImpl::Bool(false)),
+                Some(ScalarImpl::Bool(true)),
+            ])
+        })
+        .collect())
+}
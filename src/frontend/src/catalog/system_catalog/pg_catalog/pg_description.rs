@@ -0,0 +1,66 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{
+    iter_relations, numbered_columns, SystemCatalog, SystemCatalogContext, PG_CLASS_OID,
+};
+
+/// The comments set by `COMMENT ON`, one row for each commented relation or column.
+/// See <https://www.postgresql.org/docs/current/catalog-pg-description.html>.
+pub static PG_DESCRIPTION: SystemCatalog = SystemCatalog {
+    name: "pg_description",
+    columns: &[
+        (DataType::Int32, "objoid"),
+        (DataType::Int32, "classoid"),
+        (DataType::Int32, "objsubid"),
+        (DataType::Varchar, "description"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut descriptions = vec![];
+    for schema in ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+    {
+        for relation in iter_relations(schema) {
+            if let Some(description) = relation.description {
+                descriptions.push((relation.id, 0, description.to_string()));
+            }
+            descriptions.extend(
+                numbered_columns(relation.columns)
+                    .filter_map(|(i, c)| Some((relation.id, i as i32, c.description.clone()?))),
+            );
+        }
+    }
+    descriptions.sort();
+
+    Ok(descriptions
+        .into_iter()
+        .map(|(id, objsubid, description)| {
+            Row(vec![
+                Some(ScalarImpl::Int32(id as i32)),
+                Some(ScalarImpl::Int32(PG_CLASS_OID)),
+                Some(ScalarImpl::Int32(objsubid)),
+                Some(ScalarImpl::Utf8(description)),
+            ])
+        })
+        .collect())
+}
@@ -0,0 +1,74 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{numbered_columns, SystemCatalog, SystemCatalogContext};
+
+/// The indexes in the current database, one row for each index.
+/// See <https://www.postgresql.org/docs/current/catalog-pg-index.html>.
+pub static PG_INDEX: SystemCatalog = SystemCatalog {
+    name: "pg_index",
+    columns: &[
+        (DataType::Int32, "indexrelid"),
+        (DataType::Int32, "indrelid"),
+        (DataType::Int16, "indnatts"),
+        (DataType::Boolean, "indisunique"),
+        (DataType::Boolean, "indisprimary"),
+        (DataType::Varchar, "indkey"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut rows = vec![];
+    for schema in ctx
+        .catalog
+        .get_database_by_name(ctx.db_name)?
+        .iter_schemas()
+    {
+        for index in schema
+            .iter_index()
+            .sorted_by_key(|index| index.id().table_id)
+        {
+            let table = match index.is_index_on.and_then(|id| schema.get_table_by_id(&id)) {
+                Some(table) => table,
+                None => continue,
+            };
+            // `indkey` is the `attnum`s of the indexed columns of the table, separated by spaces.
+            let keys = index
+                .distribution_keys
+                .iter()
+                .map(|k| {
+                    let name = index.columns[*k].name();
+                    numbered_columns(table.columns())
+                        .find(|(_, c)| c.name() == name)
+                        .map_or(0, |(attnum, _)| attnum)
+                })
+                .collect_vec();
+            rows.push(Row(vec![
+                Some(ScalarImpl::Int32(index.id().table_id as i32)),
+                Some(ScalarImpl::Int32(table.id().table_id as i32)),
+                Some(ScalarImpl::Int16(keys.len() as i16)),
+                Some(ScalarImpl::Bool(index.is_unique_index)),
+                Some(ScalarImpl::Bool(false)),
+                Some(ScalarImpl::Utf8(keys.iter().join(" "))),
+            ]));
+        }
+    }
+    Ok(rows)
+}
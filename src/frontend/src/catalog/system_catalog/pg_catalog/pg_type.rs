@@ -0,0 +1,92 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_field_descriptor::TypeOid;
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use super::PG_CATALOG_NAMESPACE_OID;
+use crate::catalog::system_catalog::{SystemCatalog, SystemCatalogContext};
+use crate::handler::util::data_type_to_type_oid;
+
+/// The types that values are sent to clients as, with their names, lengths (-1 for variable
+/// length types) and the names shown by `format_type`.
+const TYPES: &[(TypeOid, &str, i16, &str)] = &[
+    (TypeOid::Boolean, "bool", 1, "boolean"),
+    (TypeOid::BigInt, "int8", 8, "bigint"),
+    (TypeOid::SmallInt, "int2", 2, "smallint"),
+    (TypeOid::Int, "int4", 4, "integer"),
+    (TypeOid::Float4, "float4", 4, "real"),
+    (TypeOid::Float8, "float8", 8, "double precision"),
+    (TypeOid::CharArray, "_char", -1, "\"char\"[]"),
+    (TypeOid::Varchar, "varchar", -1, "character varying"),
+    (TypeOid::Date, "date", 4, "date"),
+    (TypeOid::Time, "time", 8, "time without time zone"),
+    (
+        TypeOid::Timestamp,
+        "timestamp",
+        8,
+        "timestamp without time zone",
+    ),
+    (
+        TypeOid::Timestampz,
+        "timestamptz",
+        8,
+        "timestamp with time zone",
+    ),
+    (TypeOid::Decimal, "numeric", -1, "numeric"),
+    (TypeOid::Jsonb, "jsonb", -1, "jsonb"),
+    (TypeOid::Bytea, "bytea", -1, "bytea"),
+    (TypeOid::Uuid, "uuid", 16, "uuid"),
+];
+
+/// The data types.
+/// See <https://www.postgresql.org/docs/current/catalog-pg-type.html>.
+pub static PG_TYPE: SystemCatalog = SystemCatalog {
+    name: "pg_type",
+    columns: &[
+        (DataType::Int32, "oid"),
+        (DataType::Varchar, "typname"),
+        (DataType::Int32, "typnamespace"),
+        (DataType::Int16, "typlen"),
+    ],
+    rows,
+};
+
+fn rows(_ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    Ok(TYPES
+        .iter()
+        .map(|(oid, name, len, _)| {
+            Row(vec![
+                Some(ScalarImpl::Int32(oid.as_number())),
+                Some(ScalarImpl::Utf8(name.to_string())),
+                Some(ScalarImpl::Int32(PG_CATALOG_NAMESPACE_OID)),
+                Some(ScalarImpl::Int16(*len)),
+            ])
+        })
+        .collect())
+}
+
+/// The OIDs of all types in `pg_type` with their names in SQL, which `format_type` returns.
+pub fn type_oids_with_format_names() -> impl Iterator<Item = (i32, &'static str)> {
+    TYPES
+        .iter()
+        .map(|(oid, _, _, format_name)| (oid.as_number(), *format_name))
+}
+
+/// The OID in `pg_type` of the type that values of `data_type` are sent to clients as.
+pub fn type_oid(data_type: &DataType) -> i32 {
+    data_type_to_type_oid(data_type.clone()).as_number()
+}
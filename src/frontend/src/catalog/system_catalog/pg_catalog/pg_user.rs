@@ -0,0 +1,52 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::Row;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+
+use crate::catalog::system_catalog::{SystemCatalog, SystemCatalogContext};
+
+/// All users, with their passwords masked.
+/// See <https://www.postgresql.org/docs/current/view-pg-user.html>.
+pub static PG_USER: SystemCatalog = SystemCatalog {
+    name: "pg_user",
+    columns: &[
+        (DataType::Varchar, "usename"),
+        (DataType::Int32, "usesysid"),
+        (DataType::Boolean, "usecreatedb"),
+        (DataType::Boolean, "usesuper"),
+        (DataType::Varchar, "passwd"),
+    ],
+    rows,
+};
+
+fn rows(ctx: &SystemCatalogContext<'_>) -> Result<Vec<Row>> {
+    let mut users = ctx.users.iter_users().collect::<Vec<_>>();
+    users.sort_by(|a, b| a.name.cmp(&b.name));
+    // Users don't have ids, so they are numbered by their names instead.
+    Ok(users
+        .into_iter()
+        .enumerate()
+        .map(|(i, user)| {
+            Row(vec![
+                Some(ScalarImpl::Utf8(user.name.clone())),
+                Some(ScalarImpl::Int32(i as i32 + 1)),
+                Some(ScalarImpl::Bool(user.can_create_db)),
+                Some(ScalarImpl::Bool(user.is_supper)),
+                Some(ScalarImpl::Utf8("********".to_string())),
+            ])
+        })
+        .collect())
+}
@@ -16,12 +16,14 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 use risingwave_common::catalog::{ColumnDesc, OrderedColumnDesc, TableDesc};
+use risingwave_common::error::Result;
 use risingwave_common::util::sort_util::OrderType;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::Table as ProstTable;
 use risingwave_pb::plan_common::OrderType as ProstOrderType;
 
 use super::column_catalog::ColumnCatalog;
+use super::table_statistics::TableStatistics;
 use super::{DatabaseId, SchemaId};
 use crate::catalog::TableId;
 
@@ -47,6 +49,16 @@ pub struct TableCatalog {
 
     /// If set to Some(TableId), then this table is an index on another table.
     pub is_index_on: Option<TableId>,
+
+    /// Whether this table is a unique index. The key columns of an index are its distribution
+    /// keys, which are also the prefix of its `order_desc`.
+    pub is_unique_index: bool,
+
+    /// Statistics collected by the latest `ANALYZE`, or `None` if never analyzed.
+    pub statistics: Option<TableStatistics>,
+
+    /// Set by `COMMENT ON`.
+    pub description: Option<String>,
 }
 
 impl TableCatalog {
@@ -91,7 +103,12 @@ impl TableCatalog {
         self.distribution_keys.as_ref()
     }
 
-    pub fn to_prost(&self, schema_id: SchemaId, database_id: DatabaseId) -> ProstTable {
+    /// Get the statistics collected by the latest `ANALYZE` on this table.
+    pub fn statistics(&self) -> Option<&TableStatistics> {
+        self.statistics.as_ref()
+    }
+
+    pub fn to_prost(&self, schema_id: SchemaId, database_id: DatabaseId) -> Result<ProstTable> {
         let (order_column_ids, orders) = self
             .order_desc()
             .iter()
@@ -103,7 +120,13 @@ impl TableCatalog {
             })
             .unzip();
 
-        ProstTable {
+        let statistics = self
+            .statistics
+            .as_ref()
+            .map(TableStatistics::to_prost)
+            .transpose()?;
+
+        Ok(ProstTable {
             id: self.id.table_id as u32,
             schema_id,
             database_id,
@@ -118,12 +141,15 @@ impl TableCatalog {
                 .map(|source_id| OptionalAssociatedSourceId::AssociatedSourceId(source_id.into())),
             is_index: self.is_index_on.is_some(),
             index_on_id: self.is_index_on.unwrap_or_default().table_id(),
+            is_unique_index: self.is_unique_index,
             distribution_keys: self
                 .distribution_keys
                 .iter()
                 .map(|k| *k as i32)
                 .collect_vec(),
-        }
+            statistics,
+            description: self.description.clone().unwrap_or_default(),
+        })
     }
 }
 
@@ -174,12 +200,18 @@ impl From<ProstTable> for TableCatalog {
             } else {
                 None
             },
+            is_unique_index: tb.is_unique_index,
             distribution_keys: tb
                 .distribution_keys
                 .iter()
                 .map(|k| *k as usize)
                 .collect_vec(),
             pks: tb.pk.iter().map(|x| *x as _).collect(),
+            statistics: tb
+                .statistics
+                .as_ref()
+                .map(|stats| TableStatistics::from_prost(stats, &col_descs)),
+            description: Some(tb.description.clone()).filter(|d| !d.is_empty()),
         }
     }
 }
@@ -211,6 +243,7 @@ mod tests {
         let table: TableCatalog = ProstTable {
             is_index: false,
             index_on_id: 0,
+            is_unique_index: false,
             id: 0,
             schema_id: 0,
             database_id: 0,
@@ -219,6 +252,7 @@ mod tests {
                 ProstColumnCatalog {
                     column_desc: Some((&row_id_column_desc()).into()),
                     is_hidden: true,
+                    description: String::new(),
                 },
                 ProstColumnCatalog {
                     column_desc: Some(ProstColumnDesc::new_struct(
@@ -239,6 +273,7 @@ mod tests {
                         ],
                     )),
                     is_hidden: false,
+                    description: "a country".to_string(),
                 },
             ],
             order_column_ids: vec![0],
@@ -248,6 +283,8 @@ mod tests {
             distribution_keys: vec![],
             optional_associated_source_id: OptionalAssociatedSourceId::AssociatedSourceId(233)
                 .into(),
+            statistics: None,
+            description: String::new(),
         }
         .into();
 
@@ -255,6 +292,7 @@ mod tests {
             table,
             TableCatalog {
                 is_index_on: None,
+                is_unique_index: false,
                 id: TableId::new(0),
                 associated_source_id: Some(TableId::new(233)),
                 name: "test".to_string(),
@@ -285,7 +323,8 @@ mod tests {
                             ],
                             type_name: ".test.Country".to_string()
                         },
-                        is_hidden: false
+                        is_hidden: false,
+                        description: Some("a country".to_string()),
                     }
                 ],
                 pks: vec![0],
@@ -294,6 +333,8 @@ mod tests {
                     order: OrderType::Ascending
                 }],
                 distribution_keys: vec![],
+                statistics: None,
+                description: None,
             }
         );
     }
@@ -0,0 +1,176 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use risingwave_common::catalog::ColumnDesc;
+use risingwave_common::error::Result;
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::value_encoding::{deserialize_datum, serialize_datum};
+use risingwave_pb::catalog::{
+    ColumnStatistics as ProstColumnStatistics, TableStatistics as ProstTableStatistics,
+};
+
+use crate::catalog::ColumnId;
+
+/// Statistics of a table collected by `ANALYZE`. They are only used by the optimizer for cost
+/// estimation, so stale statistics never affect the correctness of a plan.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableStatistics {
+    pub row_count: u64,
+    pub columns: HashMap<ColumnId, ColumnStatistics>,
+}
+
+/// Statistics of a single column collected by `ANALYZE`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnStatistics {
+    /// Fraction of rows whose value is null.
+    pub null_fraction: f64,
+    /// Estimated number of distinct non-null values.
+    pub ndv: u64,
+    /// Bounds of an equi-depth histogram in ascending order, i.e. each pair of adjacent bounds
+    /// covers roughly the same number of non-null rows.
+    pub histogram_bounds: Vec<ScalarImpl>,
+}
+
+impl ColumnStatistics {
+    /// Estimated selectivity of `column = <constant>`.
+    pub fn eq_selectivity(&self) -> f64 {
+        if self.ndv == 0 {
+            return 0.0;
+        }
+        (1.0 - self.null_fraction) / self.ndv as f64
+    }
+
+    /// Estimated fraction of rows whose value is less than `value`, interpolated from the
+    /// histogram. Returns `None` if there is no histogram for the column.
+    pub fn lt_selectivity(&self, value: &ScalarImpl) -> Option<f64> {
+        if self.histogram_bounds.len() < 2 {
+            return None;
+        }
+        let buckets = (self.histogram_bounds.len() - 1) as f64;
+        let below = self
+            .histogram_bounds
+            .iter()
+            .take_while(|bound| *bound < value)
+            .count();
+        let fraction = match below {
+            0 => 0.0,
+            n if n == self.histogram_bounds.len() => 1.0,
+            // Assume the value lies in the middle of the bucket it falls into.
+            n => (n as f64 - 0.5) / buckets,
+        };
+        Some(fraction * (1.0 - self.null_fraction))
+    }
+}
+
+impl TableStatistics {
+    pub fn column(&self, column_id: ColumnId) -> Option<&ColumnStatistics> {
+        self.columns.get(&column_id)
+    }
+
+    pub fn to_prost(&self) -> Result<ProstTableStatistics> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|(column_id, stats)| {
+                Ok(ProstColumnStatistics {
+                    column_id: column_id.get_id(),
+                    null_fraction: stats.null_fraction,
+                    ndv: stats.ndv,
+                    histogram_bounds: stats
+                        .histogram_bounds
+                        .iter()
+                        .map(|bound| serialize_datum(&Some(bound.clone())))
+                        .collect::<Result<_>>()?,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(ProstTableStatistics {
+            row_count: self.row_count,
+            columns,
+        })
+    }
+
+    /// Restore the statistics from protobuf. Column statistics of unknown columns or with
+    /// undecodable histograms are dropped silently since statistics are only a hint.
+    pub fn from_prost(
+        prost: &ProstTableStatistics,
+        column_descs: &HashMap<i32, ColumnDesc>,
+    ) -> Self {
+        let columns = prost
+            .columns
+            .iter()
+            .filter_map(|stats| {
+                let desc = column_descs.get(&stats.column_id)?;
+                let histogram_bounds = stats
+                    .histogram_bounds
+                    .iter()
+                    .map(|bound| deserialize_datum(bound.as_slice(), &desc.data_type))
+                    .collect::<Result<Option<Vec<_>>>>()
+                    .ok()
+                    .flatten()
+                    .unwrap_or_default();
+                Some((
+                    desc.column_id,
+                    ColumnStatistics {
+                        null_fraction: stats.null_fraction,
+                        ndv: stats.ndv,
+                        histogram_bounds,
+                    },
+                ))
+            })
+            .collect();
+        Self {
+            row_count: prost.row_count,
+            columns,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::DataType;
+
+    use super::*;
+
+    fn int_column_stats() -> ColumnStatistics {
+        ColumnStatistics {
+            null_fraction: 0.0,
+            ndv: 100,
+            histogram_bounds: (0..=4).map(|i| ScalarImpl::Int32(i * 25)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_selectivity() {
+        let stats = int_column_stats();
+        assert!((stats.eq_selectivity() - 0.01).abs() < f64::EPSILON);
+        assert_eq!(stats.lt_selectivity(&ScalarImpl::Int32(-1)), Some(0.0));
+        assert_eq!(stats.lt_selectivity(&ScalarImpl::Int32(1000)), Some(1.0));
+        assert_eq!(stats.lt_selectivity(&ScalarImpl::Int32(30)), Some(0.375));
+    }
+
+    #[test]
+    fn test_prost_round_trip() {
+        let column_desc = ColumnDesc::unnamed(ColumnId::new(1), DataType::Int32);
+        let stats = TableStatistics {
+            row_count: 100,
+            columns: HashMap::from([(ColumnId::new(1), int_column_stats())]),
+        };
+        let prost = stats.to_prost().unwrap();
+        let column_descs = HashMap::from([(1, column_desc)]);
+        assert_eq!(TableStatistics::from_prost(&prost, &column_descs), stats);
+    }
+}
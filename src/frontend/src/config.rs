@@ -14,14 +14,19 @@
 
 //! Contains configurations that could be accessed via "set" command.
 
-use risingwave_common::error::ErrorCode::InvalidConfigValue;
-use risingwave_common::error::RwError;
+use std::fmt;
+
+use chrono_tz::Tz;
+use itertools::Itertools;
+use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
+use risingwave_common::error::ErrorCode::{self, InvalidConfigValue};
+use risingwave_common::error::{Result, RwError};
 
 use crate::config::QueryMode::{Distributed, Local};
 
 pub static QUERY_MODE: &str = "query_mode";
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QueryMode {
     Local,
     Distributed,
@@ -37,7 +42,7 @@ impl Default for QueryMode {
 impl<'a> TryFrom<&'a str> for QueryMode {
     type Error = RwError;
 
-    fn try_from(s: &'a str) -> Result<Self, RwError> {
+    fn try_from(s: &'a str) -> Result<Self> {
         if s.eq_ignore_ascii_case("local") {
             Ok(Local)
         } else if s.eq_ignore_ascii_case("distributed") {
@@ -51,11 +56,388 @@ impl<'a> TryFrom<&'a str> for QueryMode {
     }
 }
 
+impl fmt::Display for QueryMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Local => write!(f, "local"),
+            Distributed => write!(f, "distributed"),
+        }
+    }
+}
+
+/// The configurations of a session, which can be changed by `SET`, inspected by `SHOW` and
+/// restored by `RESET`.
+///
+/// All variables are listed in [`CONFIG_VARIABLES`], which validates the values set by users.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionConfig {
+    /// Schemas to look up unqualified relation names in, in order.
+    search_path: Vec<String>,
+    timezone: String,
+    query_mode: QueryMode,
+    /// Parallelism of non-root stages of distributed batch queries. 0 means one task per worker
+    /// node.
+    batch_parallelism: u32,
+    /// Parallelism of the fragments of new streaming jobs. 0 means one actor per parallel unit.
+    streaming_parallelism: u32,
+    /// If on, every INSERT/UPDATE/DELETE statement will block until the entire dataflow is
+    /// refreshed. In other words, every related table & MV will be able to see the write.
+    implicit_flush: bool,
+    /// If on, create delta joins instead of hash joins for streaming joins when possible.
+    force_delta_join: bool,
+    /// Adjusts the number of digits of floating-point values in the output, as in Postgres.
+    extra_float_digits: i32,
+    application_name: String,
+    /// The maximum number of iterations of a recursive query. 0 means unlimited.
+    max_recursive_iterations: u64,
+    /// The maximum memory in megabytes a recursive query can use for its intermediate rows. 0
+    /// means unlimited.
+    max_recursive_memory_mb: u64,
+    /// How many milliseconds the time of a row may fall behind the largest time seen on its side
+    /// of a streaming interval join before the row is dropped as late.
+    interval_join_allowed_lateness_ms: u64,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            search_path: vec![DEFAULT_SCHEMA_NAME.to_string()],
+            timezone: "UTC".to_string(),
+            query_mode: QueryMode::default(),
+            batch_parallelism: 0,
+            streaming_parallelism: 0,
+            implicit_flush: false,
+            force_delta_join: false,
+            extra_float_digits: 1,
+            application_name: String::new(),
+            max_recursive_iterations: 10000,
+            max_recursive_memory_mb: 1024,
+            interval_join_allowed_lateness_ms: 60000,
+        }
+    }
+}
+
+/// A variable in [`SessionConfig`].
+pub struct ConfigVariable {
+    pub name: &'static str,
+    /// Other names the variable can be referred to by.
+    aliases: &'static [&'static str],
+    pub description: &'static str,
+    /// Whether changes of the variable are reported to the client with `ParameterStatus`.
+    pub report: bool,
+    get: fn(&SessionConfig) -> String,
+    set: fn(&mut SessionConfig, &str) -> Result<()>,
+}
+
+impl ConfigVariable {
+    fn matches(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+}
+
+/// All variables of [`SessionConfig`].
+pub static CONFIG_VARIABLES: &[ConfigVariable] = &[
+    ConfigVariable {
+        name: "search_path",
+        aliases: &[],
+        description: "Sets the schema search order for names that are not schema-qualified.",
+        report: false,
+        get: |c| c.search_path.join(", "),
+        set: |c, v| {
+            let schemas = v
+                .split(',')
+                .map(|s| s.trim().trim_matches('"').to_string())
+                .collect_vec();
+            if schemas.iter().any(|s| s.is_empty()) {
+                return Err(invalid_value("search_path", v));
+            }
+            c.search_path = schemas;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "TimeZone",
+        aliases: &["time zone"],
+        description: "Sets the time zone for displaying and interpreting time stamps.",
+        report: true,
+        get: |c| c.timezone.clone(),
+        set: |c, v| {
+            if !is_valid_timezone(v) {
+                return Err(invalid_value("TimeZone", v));
+            }
+            c.timezone = v.to_string();
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: QUERY_MODE,
+        aliases: &[],
+        description: "Sets whether batch queries are executed locally on the frontend or \
+                      distributed to the compute nodes.",
+        report: false,
+        get: |c| c.query_mode.to_string(),
+        set: |c, v| {
+            c.query_mode = v.try_into()?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "batch_parallelism",
+        aliases: &[],
+        description: "Sets the parallelism of distributed batch queries. 0 means one task per \
+                      worker node.",
+        report: false,
+        get: |c| c.batch_parallelism.to_string(),
+        set: |c, v| {
+            c.batch_parallelism = parse("batch_parallelism", v)?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "streaming_parallelism",
+        aliases: &[],
+        description: "Sets the parallelism of new streaming jobs. 0 means one actor per \
+                      parallel unit.",
+        report: false,
+        get: |c| c.streaming_parallelism.to_string(),
+        set: |c, v| {
+            c.streaming_parallelism = parse("streaming_parallelism", v)?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "implicit_flush",
+        aliases: &["rw_implicit_flush"],
+        description: "If on, every INSERT/UPDATE/DELETE statement blocks until its changes are \
+                      visible in all related tables and materialized views.",
+        report: false,
+        get: |c| on_off(c.implicit_flush),
+        set: |c, v| {
+            c.implicit_flush = parse_bool("implicit_flush", v)?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "rw_force_delta_join",
+        aliases: &[],
+        description: "If on, streaming joins are planned as delta joins when possible.",
+        report: false,
+        get: |c| on_off(c.force_delta_join),
+        set: |c, v| {
+            c.force_delta_join = parse_bool("rw_force_delta_join", v)?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "extra_float_digits",
+        aliases: &[],
+        description: "Sets the number of digits displayed for floating-point values.",
+        report: false,
+        get: |c| c.extra_float_digits.to_string(),
+        set: |c, v| {
+            let digits: i32 = parse("extra_float_digits", v)?;
+            if !(-15..=3).contains(&digits) {
+                return Err(invalid_value("extra_float_digits", v));
+            }
+            c.extra_float_digits = digits;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "application_name",
+        aliases: &[],
+        description: "Sets the application name to be reported in statistics and logs.",
+        report: true,
+        get: |c| c.application_name.clone(),
+        set: |c, v| {
+            c.application_name = v.to_string();
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "rw_max_recursive_iterations",
+        aliases: &[],
+        description: "Sets the maximum number of iterations of a recursive query. 0 means \
+                      unlimited.",
+        report: false,
+        get: |c| c.max_recursive_iterations.to_string(),
+        set: |c, v| {
+            c.max_recursive_iterations = parse("rw_max_recursive_iterations", v)?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "rw_max_recursive_memory_mb",
+        aliases: &[],
+        description: "Sets the maximum memory in megabytes a recursive query can use for its \
+                      intermediate rows. 0 means unlimited.",
+        report: false,
+        get: |c| c.max_recursive_memory_mb.to_string(),
+        set: |c, v| {
+            c.max_recursive_memory_mb = parse("rw_max_recursive_memory_mb", v)?;
+            Ok(())
+        },
+    },
+    ConfigVariable {
+        name: "rw_interval_join_allowed_lateness_ms",
+        aliases: &[],
+        description: "Sets how many milliseconds a row of a streaming interval join may fall \
+                      behind the other rows of its side before it's dropped as late.",
+        report: false,
+        get: |c| c.interval_join_allowed_lateness_ms.to_string(),
+        set: |c, v| {
+            c.interval_join_allowed_lateness_ms = parse("rw_interval_join_allowed_lateness_ms", v)?;
+            Ok(())
+        },
+    },
+];
+
+impl SessionConfig {
+    /// Find the variable named `name`, case-insensitively.
+    pub fn variable(name: &str) -> Result<&'static ConfigVariable> {
+        CONFIG_VARIABLES
+            .iter()
+            .find(|var| var.matches(name))
+            .ok_or_else(|| {
+                ErrorCode::InvalidInputSyntax(format!(
+                    "unrecognized configuration parameter \"{}\"",
+                    name
+                ))
+                .into()
+            })
+    }
+
+    pub fn get(&self, name: &str) -> Result<String> {
+        Ok((Self::variable(name)?.get)(self))
+    }
+
+    /// Set the variable `name` to `value` if the value is valid for it.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        (Self::variable(name)?.set)(self, value)
+    }
+
+    /// Restore the variable `name` to its default value.
+    pub fn reset(&mut self, name: &str) -> Result<()> {
+        let var = Self::variable(name)?;
+        (var.set)(self, &(var.get)(&Self::default()))
+    }
+
+    /// Name, value and description of all variables.
+    pub fn show_all(&self) -> Vec<(&'static str, String, &'static str)> {
+        CONFIG_VARIABLES
+            .iter()
+            .map(|var| (var.name, (var.get)(self), var.description))
+            .collect()
+    }
+
+    pub fn search_path(&self) -> &[String] {
+        &self.search_path
+    }
+
+    pub fn timezone(&self) -> &str {
+        &self.timezone
+    }
+
+    pub fn query_mode(&self) -> QueryMode {
+        self.query_mode
+    }
+
+    /// The parallelism of distributed batch queries, or `None` to decide by the cluster size.
+    pub fn batch_parallelism(&self) -> Option<u32> {
+        match self.batch_parallelism {
+            0 => None,
+            parallelism => Some(parallelism),
+        }
+    }
+
+    /// The parallelism of new streaming jobs, or `None` to decide by the cluster size.
+    pub fn streaming_parallelism(&self) -> Option<u32> {
+        match self.streaming_parallelism {
+            0 => None,
+            parallelism => Some(parallelism),
+        }
+    }
+
+    pub fn implicit_flush(&self) -> bool {
+        self.implicit_flush
+    }
+
+    pub fn force_delta_join(&self) -> bool {
+        self.force_delta_join
+    }
+
+    pub fn extra_float_digits(&self) -> i32 {
+        self.extra_float_digits
+    }
+
+    pub fn application_name(&self) -> &str {
+        &self.application_name
+    }
+
+    /// The maximum number of iterations of a recursive query, where 0 means unlimited.
+    pub fn max_recursive_iterations(&self) -> u64 {
+        self.max_recursive_iterations
+    }
+
+    /// The maximum memory in bytes a recursive query can use, where 0 means unlimited.
+    pub fn max_recursive_memory_bytes(&self) -> u64 {
+        self.max_recursive_memory_mb.saturating_mul(1 << 20)
+    }
+
+    pub fn interval_join_allowed_lateness_ms(&self) -> u64 {
+        self.interval_join_allowed_lateness_ms
+    }
+}
+
+fn invalid_value(name: &str, value: &str) -> RwError {
+    InvalidConfigValue {
+        config_entry: name.to_string(),
+        config_value: value.to_string(),
+    }
+    .into()
+}
+
+fn parse<T: std::str::FromStr>(name: &str, value: &str) -> Result<T> {
+    value.parse().map_err(|_| invalid_value(name, value))
+}
+
+/// Parse a boolean in any of the forms Postgres accepts.
+fn parse_bool(name: &str, value: &str) -> Result<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "on" | "yes" | "1" | "t" => Ok(true),
+        "false" | "off" | "no" | "0" | "f" => Ok(false),
+        _ => Err(invalid_value(name, value)),
+    }
+}
+
+fn on_off(b: bool) -> String {
+    if b { "on" } else { "off" }.to_string()
+}
+
+/// Accepts the names of the tz database like `UTC` and `Asia/Shanghai`, in any case, and offsets
+/// like `+08:00`.
+fn is_valid_timezone(value: &str) -> bool {
+    let offset = value
+        .strip_prefix(&['+', '-'][..])
+        .map(|offset| offset.split(':').collect_vec());
+    match offset.as_deref() {
+        Some([hours]) | Some([hours, "00" | "30" | "45"]) => {
+            matches!(hours.parse::<u32>(), Ok(0..=14)) && hours.len() <= 2
+        }
+        Some(_) => false,
+        None => Tz::from_str_insensitive(value).is_ok(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::assert_matches::assert_matches;
 
-    use crate::config::QueryMode;
+    use super::*;
 
     #[test]
     fn parse_query_mode() {
@@ -65,4 +447,40 @@ mod tests {
         assert_matches!("diStributed".try_into().unwrap(), QueryMode::Distributed);
         assert!(QueryMode::try_from("ab").is_err());
     }
+
+    #[test]
+    fn test_session_config() {
+        let mut config = SessionConfig::default();
+        config.set("QUERY_MODE", "local").unwrap();
+        assert_eq!(config.query_mode(), QueryMode::Local);
+        config.set("RW_IMPLICIT_FLUSH", "true").unwrap();
+        assert!(config.implicit_flush());
+        assert_eq!(config.get("implicit_flush").unwrap(), "on");
+        config.set("search_path", "s1, \"s2\"").unwrap();
+        assert_eq!(config.search_path(), ["s1", "s2"]);
+        config.set("timezone", "Asia/Shanghai").unwrap();
+        config.set("timezone", "america/new_york").unwrap();
+        config.set("timezone", "+08:00").unwrap();
+        config.set("rw_max_recursive_memory_mb", "2").unwrap();
+        assert_eq!(config.max_recursive_memory_bytes(), 2 << 20);
+
+        // Invalid values are rejected and leave the variable unchanged.
+        assert!(config.set("extra_float_digits", "4").is_err());
+        assert!(config.set("batch_parallelism", "-1").is_err());
+        assert!(config
+            .set("rw_max_recursive_iterations", "unlimited")
+            .is_err());
+        assert!(config.set("implicit_flush", "maybe").is_err());
+        assert!(config.set("timezone", "+25").is_err());
+        assert!(config.set("timezone", "Foo/Bar").is_err());
+        assert!(config.set("no_such_variable", "1").is_err());
+        assert_eq!(config.timezone(), "+08:00");
+
+        config.reset("timezone").unwrap();
+        assert_eq!(config.timezone(), "UTC");
+        for (name, _, _) in config.clone().show_all() {
+            config.reset(name).unwrap();
+        }
+        assert_eq!(config, SessionConfig::default());
+    }
 }
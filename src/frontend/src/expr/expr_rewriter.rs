@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{AggCall, CorrelatedInputRef, ExprImpl, FunctionCall, InputRef, Literal, Subquery};
+use super::{
+    AggCall, CorrelatedInputRef, ExprImpl, FunctionCall, InputRef, Literal, Parameter, Subquery,
+    TableFunction, WindowFunction,
+};
 
 /// By default, `ExprRewriter` simply traverses the expression tree and leaves nodes unchanged.
 /// Implementations can override a subset of methods and perform transformation on some particular
@@ -26,6 +29,9 @@ pub trait ExprRewriter {
             ExprImpl::AggCall(inner) => self.rewrite_agg_call(*inner),
             ExprImpl::Subquery(inner) => self.rewrite_subquery(*inner),
             ExprImpl::CorrelatedInputRef(inner) => self.rewrite_correlated_input_ref(*inner),
+            ExprImpl::Parameter(inner) => self.rewrite_parameter(*inner),
+            ExprImpl::TableFunction(inner) => self.rewrite_table_function(*inner),
+            ExprImpl::WindowFunction(inner) => self.rewrite_window_function(*inner),
         }
     }
     fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
@@ -44,6 +50,46 @@ pub trait ExprRewriter {
             .collect();
         AggCall::new(func_type, inputs, distinct).unwrap().into()
     }
+    fn rewrite_table_function(&mut self, table_function: TableFunction) -> ExprImpl {
+        let TableFunction {
+            args,
+            return_type,
+            function_type,
+        } = table_function;
+        let args = args
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        TableFunction {
+            args,
+            return_type,
+            function_type,
+        }
+        .into()
+    }
+    fn rewrite_window_function(&mut self, window_function: WindowFunction) -> ExprImpl {
+        let WindowFunction {
+            function_type,
+            return_type,
+            partition_by,
+            order_by,
+        } = window_function;
+        let partition_by = partition_by
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        let order_by = order_by
+            .into_iter()
+            .map(|(expr, direct)| (self.rewrite_expr(expr), direct))
+            .collect();
+        WindowFunction {
+            function_type,
+            return_type,
+            partition_by,
+            order_by,
+        }
+        .into()
+    }
     fn rewrite_literal(&mut self, literal: Literal) -> ExprImpl {
         literal.into()
     }
@@ -56,4 +102,7 @@ pub trait ExprRewriter {
     fn rewrite_correlated_input_ref(&mut self, input_ref: CorrelatedInputRef) -> ExprImpl {
         input_ref.into()
     }
+    fn rewrite_parameter(&mut self, parameter: Parameter) -> ExprImpl {
+        parameter.into()
+    }
 }
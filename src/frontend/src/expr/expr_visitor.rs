@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{AggCall, CorrelatedInputRef, ExprImpl, FunctionCall, InputRef, Literal, Subquery};
+use super::{
+    AggCall, CorrelatedInputRef, ExprImpl, FunctionCall, InputRef, Literal, Parameter, Subquery,
+    TableFunction, WindowFunction,
+};
 
 /// Traverse an expression tree.
 ///
@@ -31,6 +34,9 @@ pub trait ExprVisitor {
             ExprImpl::AggCall(inner) => self.visit_agg_call(inner),
             ExprImpl::Subquery(inner) => self.visit_subquery(inner),
             ExprImpl::CorrelatedInputRef(inner) => self.visit_correlated_input_ref(inner),
+            ExprImpl::Parameter(inner) => self.visit_parameter(inner),
+            ExprImpl::TableFunction(inner) => self.visit_table_function(inner),
+            ExprImpl::WindowFunction(inner) => self.visit_window_function(inner),
         }
     }
     fn visit_function_call(&mut self, func_call: &FunctionCall) {
@@ -45,8 +51,22 @@ pub trait ExprVisitor {
             .iter()
             .for_each(|expr| self.visit_expr(expr))
     }
+    fn visit_table_function(&mut self, table_function: &TableFunction) {
+        table_function
+            .args
+            .iter()
+            .for_each(|expr| self.visit_expr(expr))
+    }
+    fn visit_window_function(&mut self, window_function: &WindowFunction) {
+        window_function
+            .partition_by
+            .iter()
+            .chain(window_function.order_by.iter().map(|(expr, _)| expr))
+            .for_each(|expr| self.visit_expr(expr))
+    }
     fn visit_literal(&mut self, _: &Literal) {}
     fn visit_input_ref(&mut self, _: &InputRef) {}
     fn visit_subquery(&mut self, _: &Subquery) {}
     fn visit_correlated_input_ref(&mut self, _: &CorrelatedInputRef) {}
+    fn visit_parameter(&mut self, _: &Parameter) {}
 }
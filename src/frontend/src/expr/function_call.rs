@@ -13,10 +13,10 @@
 // limitations under the License.
 
 use num_integer::Integer as _;
-use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 
-use super::{align_types, cast_ok, infer_type, CastContext, Expr, ExprImpl, Literal};
+use super::{align_types, cast_ok, infer_type, CastContext, Expr, ExprImpl, Literal, Parameter};
 use crate::expr::ExprType;
 
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -77,6 +77,7 @@ impl std::fmt::Debug for FunctionCall {
                 ExprType::BitwiseAnd => debug_binary_op(f, "&", &self.inputs),
                 ExprType::BitwiseOr => debug_binary_op(f, "|", &self.inputs),
                 ExprType::BitwiseXor => debug_binary_op(f, "#", &self.inputs),
+                ExprType::ConcatOp => debug_binary_op(f, "||", &self.inputs),
                 _ => {
                     let func_name = format!("{:?}", self.func_type);
                     let mut builder = f.debug_tuple(&func_name);
@@ -114,23 +115,26 @@ impl FunctionCall {
                 align_types(inputs.iter_mut())?;
                 Ok(DataType::Boolean)
             }
-            ExprType::Coalesce => {
+            ExprType::Coalesce | ExprType::Greatest | ExprType::Least => {
                 if inputs.is_empty() {
                     return Err(ErrorCode::BindError(format!(
-                        "Function `Coalesce` takes at least {} arguments ({} given)",
-                        1, 0
+                        "Function `{:?}` takes at least {} arguments ({} given)",
+                        func_type, 1, 0
                     ))
                     .into());
                 }
                 align_types(inputs.iter_mut())
             }
-            ExprType::ConcatWs => {
-                let expected = 2;
+            ExprType::ConcatWs | ExprType::Format => {
+                let expected = match func_type {
+                    ExprType::ConcatWs => 2,
+                    _ => 1,
+                };
                 let actual = inputs.len();
                 if actual < expected {
                     return Err(ErrorCode::BindError(format!(
-                        "Function `ConcatWs` takes at least {} arguments ({} given)",
-                        expected, actual
+                        "Function `{:?}` takes at least {} arguments ({} given)",
+                        func_type, expected, actual
                     ))
                     .into());
                 }
@@ -147,11 +151,78 @@ impl FunctionCall {
                     .collect::<Result<Vec<_>>>()?;
                 Ok(DataType::Varchar)
             }
+            ExprType::JsonbBuildObject => {
+                if inputs.len().is_odd() {
+                    return Err(ErrorCode::BindError(
+                        "Function `JsonbBuildObject` takes an even number of arguments".into(),
+                    )
+                    .into());
+                }
 
-            _ => infer_type(
-                func_type,
-                inputs.iter().map(|expr| expr.return_type()).collect(),
-            ),
+                inputs = inputs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, input)| match i.is_even() {
+                        // keys are converted to string, and values are kept as is
+                        true => input.cast_explicit(DataType::Varchar),
+                        false => Ok(input),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::Jsonb)
+            }
+            ExprType::RegexpMatch | ExprType::RegexpSplitToArray => {
+                // These take a string, a pattern and optional flags, and return a string array.
+                if !(2..=3).contains(&inputs.len()) {
+                    return Err(ErrorCode::BindError(format!(
+                        "Function `{:?}` takes 2 or 3 arguments ({} given)",
+                        func_type,
+                        inputs.len()
+                    ))
+                    .into());
+                }
+                inputs = inputs
+                    .into_iter()
+                    .map(|input| input.cast_implicit(DataType::Varchar))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::List {
+                    datatype: Box::new(DataType::Varchar),
+                })
+            }
+            ExprType::ArrayCat
+            | ExprType::ArrayAppend
+            | ExprType::ArrayPrepend
+            | ExprType::ArrayLength
+            | ExprType::Cardinality
+            | ExprType::ArrayPosition
+            | ExprType::ArrayToString
+            | ExprType::ArrayContains
+            | ExprType::ArrayContained
+            | ExprType::ArrayOverlap
+            | ExprType::ArraySlice => infer_array_function_type(func_type, &mut inputs),
+
+            _ => {
+                if matches!(
+                    func_type,
+                    ExprType::Equal
+                        | ExprType::NotEqual
+                        | ExprType::LessThan
+                        | ExprType::LessThanOrEqual
+                        | ExprType::GreaterThan
+                        | ExprType::GreaterThanOrEqual
+                        | ExprType::IsDistinctFrom
+                        | ExprType::Add
+                        | ExprType::Subtract
+                        | ExprType::Multiply
+                        | ExprType::Divide
+                        | ExprType::Modulus
+                ) {
+                    infer_parameter_types(&mut inputs);
+                }
+                infer_type(
+                    func_type,
+                    inputs.iter().map(|expr| expr.return_type()).collect(),
+                )
+            }
         }?;
         Ok(Self {
             func_type,
@@ -163,7 +234,10 @@ impl FunctionCall {
     /// Create a cast expr over `child` to `target` type in `allows` context.
     pub fn new_cast(child: ExprImpl, target: DataType, allows: CastContext) -> Result<ExprImpl> {
         let source = child.return_type();
-        if child.is_null() {
+        if let ExprImpl::Parameter(parameter) = &child && parameter.is_untyped() {
+            // The parameter takes the target type, like a string literal of unknown type in PG.
+            Ok(Parameter::new(parameter.index(), Some(target)).into())
+        } else if child.is_null() {
             Ok(Literal::new(None, target).into())
         } else if source == target {
             Ok(child)
@@ -225,6 +299,108 @@ impl FunctionCall {
         self.inputs.as_ref()
     }
 }
+
+/// Assign untyped parameters of an operator the type of the first typed operand, e.g. `$1` in
+/// `v1 = $1` takes the type of `v1`. Parameters stay untyped if all operands are untyped.
+fn infer_parameter_types(inputs: &mut [ExprImpl]) {
+    let Some(data_type) = inputs
+        .iter()
+        .find(|input| !input.is_untyped_parameter() && !input.is_null())
+        .map(|input| input.return_type()) else {
+        return;
+    };
+    for input in inputs.iter_mut() {
+        if let ExprImpl::Parameter(parameter) = input && parameter.is_untyped() {
+            *input = Parameter::new(parameter.index(), Some(data_type.clone())).into();
+        }
+    }
+}
+
+/// Type a function on arrays, whose signature depends on the element type and so cannot be
+/// listed [with the others](crate::expr::type_inference::build_type_derive_map). Elements are cast
+/// implicitly to the element type of the array, and two arrays must be of the same type.
+fn infer_array_function_type(func_type: ExprType, inputs: &mut Vec<ExprImpl>) -> Result<DataType> {
+    let arity = match func_type {
+        ExprType::Cardinality => 1..=1,
+        ExprType::ArraySlice => 3..=3,
+        ExprType::ArrayToString => 2..=3,
+        _ => 2..=2,
+    };
+    if !arity.contains(&inputs.len()) {
+        return Err(ErrorCode::BindError(format!(
+            "Function `{:?}` takes {} arguments ({} given)",
+            func_type,
+            if arity.start() == arity.end() {
+                arity.start().to_string()
+            } else {
+                format!("{} or {}", arity.start(), arity.end())
+            },
+            inputs.len()
+        ))
+        .into());
+    }
+    let array_index = match func_type {
+        ExprType::ArrayPrepend => 1,
+        _ => 0,
+    };
+    if matches!(
+        func_type,
+        ExprType::ArrayCat
+            | ExprType::ArrayContains
+            | ExprType::ArrayContained
+            | ExprType::ArrayOverlap
+    ) {
+        align_types(inputs.iter_mut())?;
+    }
+    let array_type = inputs[array_index].return_type();
+    let DataType::List { datatype: elem_type } = array_type.clone() else {
+        return Err(ErrorCode::BindError(format!(
+            "Function `{:?}` takes an array, not {:?}",
+            func_type, array_type
+        ))
+        .into());
+    };
+    let cast_args = |inputs: &mut Vec<ExprImpl>, indices: &[usize], target: &DataType| {
+        for &i in indices {
+            let input = std::mem::replace(&mut inputs[i], ExprImpl::literal_bool(false));
+            inputs[i] = input.cast_implicit(target.clone())?;
+        }
+        Ok::<_, RwError>(())
+    };
+    match func_type {
+        ExprType::ArrayCat => Ok(array_type),
+        ExprType::ArrayAppend | ExprType::ArrayPosition => {
+            cast_args(inputs, &[1], &elem_type)?;
+            match func_type {
+                ExprType::ArrayAppend => Ok(array_type),
+                _ => Ok(DataType::Int32),
+            }
+        }
+        ExprType::ArrayPrepend => {
+            cast_args(inputs, &[0], &elem_type)?;
+            Ok(array_type)
+        }
+        ExprType::ArrayLength => {
+            cast_args(inputs, &[1], &DataType::Int32)?;
+            Ok(DataType::Int32)
+        }
+        ExprType::Cardinality => Ok(DataType::Int32),
+        ExprType::ArrayToString => {
+            let indices = (1..inputs.len()).collect::<Vec<_>>();
+            cast_args(inputs, &indices, &DataType::Varchar)?;
+            Ok(DataType::Varchar)
+        }
+        ExprType::ArrayContains | ExprType::ArrayContained | ExprType::ArrayOverlap => {
+            Ok(DataType::Boolean)
+        }
+        ExprType::ArraySlice => {
+            cast_args(inputs, &[1, 2], &DataType::Int32)?;
+            Ok(array_type)
+        }
+        _ => unreachable!(),
+    }
+}
+
 impl Expr for FunctionCall {
     fn return_type(&self) -> DataType {
         self.return_type.clone()
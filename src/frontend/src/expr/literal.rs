@@ -33,9 +33,12 @@ impl std::fmt::Debug for Literal {
         } else {
             match &self.data {
                 None => write!(f, "null"),
-                // Add single quotation marks for string and interval literals
+                // Add single quotation marks for string, interval, jsonb, bytea and uuid literals
                 Some(ScalarImpl::Utf8(v)) => write!(f, "'{}'", v),
                 Some(ScalarImpl::Interval(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Jsonb(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Bytea(v)) => write!(f, "'{}'", v),
+                Some(ScalarImpl::Uuid(v)) => write!(f, "'{}'", v),
                 Some(v) => write!(f, "{}", v),
             }?;
             write!(f, ":{:?}", self.data_type)
@@ -25,7 +25,10 @@ mod correlated_input_ref;
 mod function_call;
 mod input_ref;
 mod literal;
+mod parameter;
 mod subquery;
+mod table_function;
+mod window_function;
 
 mod expr_rewriter;
 mod expr_visitor;
@@ -37,7 +40,10 @@ pub use correlated_input_ref::CorrelatedInputRef;
 pub use function_call::FunctionCall;
 pub use input_ref::{as_alias_display, input_ref_to_column_indices, InputRef, InputRefDisplay};
 pub use literal::Literal;
+pub use parameter::Parameter;
 pub use subquery::{Subquery, SubqueryKind};
+pub use table_function::{TableFunction, TableFunctionType};
+pub use window_function::{WindowFunction, WindowFunctionType};
 
 pub type ExprType = risingwave_pb::expr::expr_node::Type;
 
@@ -64,6 +70,9 @@ pub enum ExprImpl {
     FunctionCall(Box<FunctionCall>),
     AggCall(Box<AggCall>),
     Subquery(Box<Subquery>),
+    Parameter(Box<Parameter>),
+    TableFunction(Box<TableFunction>),
+    WindowFunction(Box<WindowFunction>),
 }
 
 impl ExprImpl {
@@ -73,12 +82,24 @@ impl ExprImpl {
         Literal::new(Some(v.to_scalar_value()), DataType::Int32).into()
     }
 
+    /// A literal bigint value.
+    #[inline(always)]
+    pub fn literal_bigint(v: i64) -> Self {
+        Literal::new(Some(v.to_scalar_value()), DataType::Int64).into()
+    }
+
     /// A literal boolean value.
     #[inline(always)]
     pub fn literal_bool(v: bool) -> Self {
         Literal::new(Some(v.to_scalar_value()), DataType::Boolean).into()
     }
 
+    /// A literal varchar value.
+    #[inline(always)]
+    pub fn literal_varchar(v: String) -> Self {
+        Literal::new(Some(v.to_scalar_value()), DataType::Varchar).into()
+    }
+
     /// A `count(*)` aggregate function.
     #[inline(always)]
     pub fn count_star() -> Self {
@@ -100,6 +121,11 @@ impl ExprImpl {
         matches!(self, ExprImpl::Literal(literal) if literal.get_data().is_none())
     }
 
+    /// Check whether self is a parameter whose type is not determined yet.
+    pub fn is_untyped_parameter(&self) -> bool {
+        matches!(self, ExprImpl::Parameter(parameter) if parameter.is_untyped())
+    }
+
     /// Shorthand to create cast expr to `target` type in implicit context.
     pub fn cast_implicit(self, target: DataType) -> Result<ExprImpl> {
         FunctionCall::new_cast(self, target, CastContext::Implicit)
@@ -148,7 +174,7 @@ macro_rules! impl_has_variant {
     };
 }
 
-impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery}
+impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery, Parameter, TableFunction, WindowFunction}
 
 impl ExprImpl {
     // We need to traverse inside subqueries.
@@ -166,19 +192,43 @@ impl ExprImpl {
             }
 
             fn visit_subquery(&mut self, subquery: &Subquery) {
+                // The left operand of `IN`, `ANY` and `ALL` belongs to the enclosing query.
+                if let SubqueryKind::In(expr)
+                | SubqueryKind::Some(expr, _)
+                | SubqueryKind::All(expr, _) = &subquery.kind
+                {
+                    self.visit_expr(expr);
+                }
+                self.depth += 1;
+                self.visit_set_expr(&subquery.query.body);
+                self.depth -= 1;
+            }
+        }
+
+        impl Has {
+            fn visit_set_expr(&mut self, set_expr: &crate::binder::BoundSetExpr) {
                 use crate::binder::BoundSetExpr;
 
-                self.depth += 1;
-                match &subquery.query.body {
+                match set_expr {
                     BoundSetExpr::Select(select) => select
                         .select_items
                         .iter()
                         .chain(select.group_by.iter())
                         .chain(select.where_clause.iter())
+                        .chain(select.having.iter())
                         .for_each(|expr| self.visit_expr(expr)),
                     BoundSetExpr::Values(_) => {}
+                    BoundSetExpr::SetOperation { left, right, .. } => {
+                        self.visit_set_expr(left);
+                        self.visit_set_expr(right);
+                    }
+                    BoundSetExpr::RecursiveUnion {
+                        base, recursive, ..
+                    } => {
+                        self.visit_set_expr(base);
+                        self.visit_set_expr(recursive);
+                    }
                 }
-                self.depth -= 1;
             }
         }
 
@@ -192,7 +242,8 @@ impl ExprImpl {
 
     /// Checks whether this is a constant expr that can be evaluated over a dummy chunk.
     /// Equivalent to `!has_input_ref && !has_agg_call && !has_subquery &&
-    /// !has_correlated_input_ref` but checks them in one pass.
+    /// !has_correlated_input_ref` but checks them in one pass. `random()` and `gen_random_uuid()`
+    /// are not constant, as they take a different value for each row.
     pub fn is_const(&self) -> bool {
         struct Has {
             has: bool,
@@ -201,6 +252,14 @@ impl ExprImpl {
             fn visit_expr(&mut self, expr: &ExprImpl) {
                 match expr {
                     ExprImpl::Literal(_inner) => {}
+                    ExprImpl::FunctionCall(inner)
+                        if matches!(
+                            inner.get_expr_type(),
+                            ExprType::Random | ExprType::GenRandomUuid
+                        ) =>
+                    {
+                        self.has = true
+                    }
                     ExprImpl::FunctionCall(inner) => self.visit_function_call(inner),
                     _ => self.has = true,
                 }
@@ -210,6 +269,30 @@ impl ExprImpl {
         visitor.visit_expr(self);
         !visitor.has
     }
+
+    /// Checks whether the expr calls `now()`, which is left in streaming queries to be planned as
+    /// a [`crate::optimizer::plan_node::LogicalDynamicFilter`].
+    ///
+    /// It will not traverse inside subqueries.
+    pub fn has_now(&self) -> bool {
+        struct Has {
+            has: bool,
+        }
+        impl ExprVisitor for Has {
+            fn visit_function_call(&mut self, func_call: &FunctionCall) {
+                if func_call.get_expr_type() == ExprType::Now {
+                    self.has = true;
+                }
+                func_call
+                    .inputs()
+                    .iter()
+                    .for_each(|expr| self.visit_expr(expr));
+            }
+        }
+        let mut visitor = Has { has: false };
+        visitor.visit_expr(self);
+        visitor.has
+    }
 }
 
 impl Expr for ExprImpl {
@@ -221,6 +304,9 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(expr) => expr.return_type(),
             ExprImpl::Subquery(expr) => expr.return_type(),
             ExprImpl::CorrelatedInputRef(expr) => expr.return_type(),
+            ExprImpl::Parameter(expr) => expr.return_type(),
+            ExprImpl::TableFunction(expr) => expr.return_type(),
+            ExprImpl::WindowFunction(expr) => expr.return_type(),
         }
     }
 
@@ -232,6 +318,9 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(e) => e.to_expr_proto(),
             ExprImpl::Subquery(e) => e.to_expr_proto(),
             ExprImpl::CorrelatedInputRef(e) => e.to_expr_proto(),
+            ExprImpl::Parameter(e) => e.to_expr_proto(),
+            ExprImpl::TableFunction(e) => e.to_expr_proto(),
+            ExprImpl::WindowFunction(e) => e.to_expr_proto(),
         }
     }
 }
@@ -272,6 +361,24 @@ impl From<CorrelatedInputRef> for ExprImpl {
     }
 }
 
+impl From<Parameter> for ExprImpl {
+    fn from(parameter: Parameter) -> Self {
+        ExprImpl::Parameter(Box::new(parameter))
+    }
+}
+
+impl From<TableFunction> for ExprImpl {
+    fn from(table_function: TableFunction) -> Self {
+        ExprImpl::TableFunction(Box::new(table_function))
+    }
+}
+
+impl From<WindowFunction> for ExprImpl {
+    fn from(window_function: WindowFunction) -> Self {
+        ExprImpl::WindowFunction(Box::new(window_function))
+    }
+}
+
 impl From<Condition> for ExprImpl {
     fn from(c: Condition) -> Self {
         merge_expr_by_binary(
@@ -297,6 +404,9 @@ impl std::fmt::Debug for ExprImpl {
                 Self::CorrelatedInputRef(arg0) => {
                     f.debug_tuple("CorrelatedInputRef").field(arg0).finish()
                 }
+                Self::Parameter(arg0) => f.debug_tuple("Parameter").field(arg0).finish(),
+                Self::TableFunction(arg0) => f.debug_tuple("TableFunction").field(arg0).finish(),
+                Self::WindowFunction(arg0) => f.debug_tuple("WindowFunction").field(arg0).finish(),
             };
         }
         match self {
@@ -306,6 +416,9 @@ impl std::fmt::Debug for ExprImpl {
             Self::AggCall(x) => write!(f, "{:?}", x),
             Self::Subquery(x) => write!(f, "{:?}", x),
             Self::CorrelatedInputRef(x) => write!(f, "{:?}", x),
+            Self::Parameter(x) => write!(f, "{:?}", x),
+            Self::TableFunction(x) => write!(f, "{:?}", x),
+            Self::WindowFunction(x) => write!(f, "{:?}", x),
         }
     }
 }
@@ -0,0 +1,71 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+
+use risingwave_common::types::DataType;
+
+use super::Expr;
+
+/// A positional parameter `$n` of a prepared statement, which is replaced by the value given in
+/// `EXECUTE` before planning.
+///
+/// The type of a parameter is either declared in `PREPARE`, or inferred from the context it is
+/// used in, e.g. the type of the other operand of a comparison. A parameter whose type can't be
+/// inferred is treated as `varchar`.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Parameter {
+    index: u64,
+    data_type: Option<DataType>,
+}
+
+impl Parameter {
+    pub fn new(index: u64, data_type: Option<DataType>) -> Self {
+        Parameter { index, data_type }
+    }
+
+    /// The 1-based index of the parameter, i.e. `n` in `$n`.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Whether the type of the parameter is neither declared nor inferred yet.
+    pub fn is_untyped(&self) -> bool {
+        self.data_type.is_none()
+    }
+}
+
+impl Expr for Parameter {
+    fn return_type(&self) -> DataType {
+        self.data_type.clone().unwrap_or(DataType::Varchar)
+    }
+
+    fn to_expr_proto(&self) -> risingwave_pb::expr::ExprNode {
+        // The planner rejects statements with parameters that are not substituted.
+        unreachable!("Parameter {:?} has not been substituted", self)
+    }
+}
+
+impl fmt::Debug for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Parameter")
+                .field("index", &self.index)
+                .field("data_type", &self.data_type)
+                .finish()
+        } else {
+            write!(f, "${}", self.index)
+        }
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::Field;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::table_function::Type;
+use risingwave_pb::expr::TableFunction as TableFunctionProst;
+
+use super::{Expr, ExprImpl};
+
+/// The set-returning functions, or table functions, which expand a row into a set of values.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum TableFunctionType {
+    GenerateSeries,
+    Unnest,
+    RegexpMatches,
+    JsonbArrayElements,
+    JsonbEach,
+}
+
+impl TableFunctionType {
+    /// The table function of a (lowercase) function name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "generate_series" => Some(Self::GenerateSeries),
+            "unnest" => Some(Self::Unnest),
+            "regexp_matches" => Some(Self::RegexpMatches),
+            "jsonb_array_elements" => Some(Self::JsonbArrayElements),
+            "jsonb_each" => Some(Self::JsonbEach),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::GenerateSeries => "generate_series",
+            Self::Unnest => "unnest",
+            Self::RegexpMatches => "regexp_matches",
+            Self::JsonbArrayElements => "jsonb_array_elements",
+            Self::JsonbEach => "jsonb_each",
+        }
+    }
+
+    fn to_protobuf(self) -> Type {
+        match self {
+            Self::GenerateSeries => Type::GenerateSeries,
+            Self::Unnest => Type::Unnest,
+            Self::RegexpMatches => Type::RegexpMatches,
+            Self::JsonbArrayElements => Type::JsonbArrayElements,
+            Self::JsonbEach => Type::JsonbEach,
+        }
+    }
+}
+
+/// A call to a table function. It is planned as a
+/// [`LogicalTableFunction`](crate::optimizer::plan_node::LogicalTableFunction) in the FROM clause,
+/// or in the select list of a
+/// [`LogicalProjectSet`](crate::optimizer::plan_node::LogicalProjectSet).
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TableFunction {
+    pub args: Vec<ExprImpl>,
+    pub return_type: DataType,
+    pub function_type: TableFunctionType,
+}
+
+impl TableFunction {
+    /// Type checks the arguments, casting them if needed, and infers the type of the values in the
+    /// set.
+    pub fn new(function_type: TableFunctionType, mut args: Vec<ExprImpl>) -> Result<Self> {
+        let name = function_type.name();
+        let arity = match function_type {
+            TableFunctionType::GenerateSeries => 2..=3,
+            TableFunctionType::RegexpMatches => 2..=3,
+            _ => 1..=1,
+        };
+        if !arity.contains(&args.len()) {
+            return Err(ErrorCode::BindError(format!(
+                "Function `{}` takes {} arguments ({} given)",
+                name,
+                if arity.start() == arity.end() {
+                    arity.start().to_string()
+                } else {
+                    format!("{} or {}", arity.start(), arity.end())
+                },
+                args.len()
+            ))
+            .into());
+        }
+
+        let return_type = match function_type {
+            TableFunctionType::GenerateSeries => {
+                if args.len() == 2 {
+                    args.push(ExprImpl::literal_int(1));
+                }
+                let types = args.iter().map(ExprImpl::return_type).collect_vec();
+                match types.as_slice() {
+                    [DataType::Int32, DataType::Int32, DataType::Int32] => DataType::Int32,
+                    [DataType::Timestamp, DataType::Timestamp, DataType::Interval] => {
+                        DataType::Timestamp
+                    }
+                    _ => {
+                        return Err(ErrorCode::BindError(format!(
+                            "Function `generate_series` does not support {:?}",
+                            types
+                        ))
+                        .into())
+                    }
+                }
+            }
+            TableFunctionType::Unnest => match args[0].return_type() {
+                DataType::List { datatype } => *datatype,
+                data_type => {
+                    return Err(ErrorCode::BindError(format!(
+                        "Function `unnest` takes an array, not {:?}",
+                        data_type
+                    ))
+                    .into())
+                }
+            },
+            TableFunctionType::RegexpMatches => {
+                args = args
+                    .into_iter()
+                    .map(|arg| arg.cast_implicit(DataType::Varchar))
+                    .try_collect()?;
+                DataType::List {
+                    datatype: Box::new(DataType::Varchar),
+                }
+            }
+            TableFunctionType::JsonbArrayElements | TableFunctionType::JsonbEach => {
+                let arg = args.pop().unwrap();
+                args.push(arg.cast_assign(DataType::Jsonb)?);
+                match function_type {
+                    TableFunctionType::JsonbEach => DataType::Struct {
+                        fields: vec![DataType::Varchar, DataType::Jsonb].into(),
+                    },
+                    _ => DataType::Jsonb,
+                }
+            }
+        };
+
+        Ok(Self {
+            args,
+            return_type,
+            function_type,
+        })
+    }
+
+    /// The columns of the function as a relation in the FROM clause, where a set of structs is
+    /// expanded into the fields of the structs.
+    pub fn output_fields(&self) -> Vec<Field> {
+        match self.function_type {
+            TableFunctionType::JsonbArrayElements => {
+                vec![Field::with_name(DataType::Jsonb, "value")]
+            }
+            TableFunctionType::JsonbEach => vec![
+                Field::with_name(DataType::Varchar, "key"),
+                Field::with_name(DataType::Jsonb, "value"),
+            ],
+            _ => vec![Field::with_name(
+                self.return_type.clone(),
+                self.function_type.name(),
+            )],
+        }
+    }
+
+    pub fn to_protobuf(&self) -> TableFunctionProst {
+        TableFunctionProst {
+            function_type: self.function_type.to_protobuf() as i32,
+            args: self.args.iter().map(Expr::to_expr_proto).collect(),
+            return_type: Some(self.return_type.to_protobuf()),
+        }
+    }
+}
+
+impl std::fmt::Debug for TableFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("TableFunction")
+                .field("function_type", &self.function_type)
+                .field("return_type", &self.return_type)
+                .field("args", &self.args)
+                .finish()
+        } else {
+            let mut builder = f.debug_tuple(&format!("{:?}", self.function_type));
+            self.args.iter().for_each(|arg| {
+                builder.field(arg);
+            });
+            builder.finish()
+        }
+    }
+}
+
+impl Expr for TableFunction {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn to_expr_proto(&self) -> risingwave_pb::expr::ExprNode {
+        // A table function is always planned as a `LogicalTableFunction` or extracted into a
+        // `LogicalProjectSet`, which serialize it with `to_protobuf`.
+        unreachable!("TableFunction {:?} is not a scalar expression", self)
+    }
+}
@@ -40,6 +40,9 @@ enum DataTypeName {
     Timestampz,
     Time,
     Interval,
+    Jsonb,
+    Bytea,
+    Uuid,
     Struct,
     List,
 }
@@ -59,6 +62,9 @@ fn name_of(ty: &DataType) -> DataTypeName {
         DataType::Timestampz => DataTypeName::Timestampz,
         DataType::Time => DataTypeName::Time,
         DataType::Interval => DataTypeName::Interval,
+        DataType::Jsonb => DataTypeName::Jsonb,
+        DataType::Bytea => DataTypeName::Bytea,
+        DataType::Uuid => DataTypeName::Uuid,
         DataType::Struct { .. } => DataTypeName::Struct,
         DataType::List { .. } => DataTypeName::List,
     }
@@ -84,6 +90,9 @@ pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Result<Dat
         DataTypeName::Timestampz => DataType::Timestampz,
         DataTypeName::Time => DataType::Time,
         DataTypeName::Interval => DataType::Interval,
+        DataTypeName::Jsonb => DataType::Jsonb,
+        DataTypeName::Bytea => DataType::Bytea,
+        DataTypeName::Uuid => DataType::Uuid,
         DataTypeName::Struct | DataTypeName::List => {
             panic!("Functions returning struct or list can not be inferred. Please use `FunctionCall::new_unchecked`.")
         }
@@ -195,6 +204,9 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::Uuid,
     ];
     let num_types = [
         T::Int16,
@@ -233,7 +245,7 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Date, T::Timestamp, T::Timestampz]);
     build_binary_cmp_funcs(&mut map, cmp_exprs, &[T::Time, T::Interval]);
     for e in cmp_exprs {
-        for t in [T::Boolean, T::Varchar] {
+        for t in [T::Boolean, T::Varchar, T::Bytea, T::Uuid] {
             map.insert(FuncSign::new(*e, vec![t, t]), T::Boolean);
         }
     }
@@ -282,6 +294,42 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
     build_round_funcs(&mut map, E::Round);
     build_round_funcs(&mut map, E::Ceil);
     build_round_funcs(&mut map, E::Floor);
+    build_round_funcs(&mut map, E::Trunc);
+    map.insert(
+        FuncSign::new(E::TruncDigit, vec![T::Decimal, T::Int32]),
+        T::Decimal,
+    );
+
+    // math functions
+    for e in [E::Sqrt, E::Exp, E::Ln, E::Log10, E::Sign] {
+        build_round_funcs(&mut map, e);
+    }
+    for e in [
+        E::Cbrt,
+        E::Sin,
+        E::Cos,
+        E::Tan,
+        E::Cot,
+        E::Asin,
+        E::Acos,
+        E::Atan,
+        E::Degrees,
+        E::Radians,
+    ] {
+        map.insert(FuncSign::new(e, vec![T::Float64]), T::Float64);
+    }
+    for t in [T::Float64, T::Decimal] {
+        map.insert(FuncSign::new(E::Pow, vec![t, t]), t);
+        map.insert(
+            FuncSign::new(E::WidthBucket, vec![t, t, t, T::Int32]),
+            T::Int32,
+        );
+    }
+    map.insert(
+        FuncSign::new(E::Atan2, vec![T::Float64, T::Float64]),
+        T::Float64,
+    );
+    map.insert(FuncSign::new(E::Random, vec![]), T::Float64);
 
     // temporal expressions
     for (base, delta) in [
@@ -310,7 +358,7 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         map.insert(FuncSign::new(E::Divide, vec![T::Interval, t]), T::Interval);
     }
 
-    for t in [T::Timestamp, T::Time, T::Date] {
+    for t in [T::Timestamp, T::Timestampz, T::Time, T::Date, T::Interval] {
         map.insert(FuncSign::new(E::Extract, vec![T::Varchar, t]), T::Decimal);
     }
     for t in [T::Timestamp, T::Date] {
@@ -320,6 +368,47 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         );
     }
 
+    // date/time functions
+    map.insert(
+        FuncSign::new(E::AtTimeZone, vec![T::Timestamp, T::Varchar]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::AtTimeZone, vec![T::Timestampz, T::Varchar]),
+        T::Timestamp,
+    );
+    for t in [T::Timestamp, T::Timestampz, T::Interval] {
+        map.insert(FuncSign::new(E::DateTrunc, vec![T::Varchar, t]), t);
+    }
+    map.insert(
+        FuncSign::new(E::DateTrunc, vec![T::Varchar, T::Timestampz, T::Varchar]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::ToTimestamp, vec![T::Float64]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::ToTimestamp1, vec![T::Varchar, T::Varchar]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::ToTimestamp1, vec![T::Varchar, T::Varchar, T::Varchar]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::ToDate, vec![T::Varchar, T::Varchar]),
+        T::Date,
+    );
+    map.insert(
+        FuncSign::new(E::MakeDate, vec![T::Int32, T::Int32, T::Int32]),
+        T::Date,
+    );
+    for t in [T::Timestamp, T::Timestampz] {
+        map.insert(FuncSign::new(E::Age, vec![t, t]), T::Interval);
+    }
+    map.insert(FuncSign::new(E::Now, vec![]), T::Timestampz);
+
     // string expressions
     for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper, E::Md5] {
         map.insert(FuncSign::new(e, vec![T::Varchar]), T::Varchar);
@@ -356,11 +445,101 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::SplitPart, vec![T::Varchar, T::Varchar, T::Int32]),
         T::Varchar,
     );
-    // TODO: Support more `to_char` types.
+    for e in [E::Reverse, E::Initcap, E::QuoteIdent, E::QuoteLiteral] {
+        map.insert(FuncSign::new(e, vec![T::Varchar]), T::Varchar);
+    }
+    map.insert(
+        FuncSign::new(E::ConcatOp, vec![T::Varchar, T::Varchar]),
+        T::Varchar,
+    );
+    for e in [E::Left, E::Right, E::Repeat] {
+        map.insert(FuncSign::new(e, vec![T::Varchar, T::Int32]), T::Varchar);
+    }
+    for e in [E::Lpad, E::Rpad] {
+        map.insert(
+            FuncSign::new(e, vec![T::Varchar, T::Int32, T::Varchar]),
+            T::Varchar,
+        );
+    }
+    for args in [
+        vec![T::Varchar, T::Varchar, T::Int32],
+        vec![T::Varchar, T::Varchar, T::Int32, T::Int32],
+    ] {
+        map.insert(FuncSign::new(E::Overlay, args), T::Varchar);
+    }
+    for e in [E::StartsWith, E::Ilike] {
+        map.insert(FuncSign::new(e, vec![T::Varchar, T::Varchar]), T::Boolean);
+    }
+    map.insert(FuncSign::new(E::Chr, vec![T::Int32]), T::Varchar);
+    for t in [T::Int32, T::Int64] {
+        map.insert(FuncSign::new(E::ToHex, vec![t]), T::Varchar);
+    }
+    // regular expressions, whose optional last argument is the flags
     map.insert(
-        FuncSign::new(E::ToChar, vec![T::Timestamp, T::Varchar]),
+        FuncSign::new(E::SimilarTo, vec![T::Varchar, T::Varchar]),
+        T::Boolean,
+    );
+    for args in [
+        vec![T::Varchar, T::Varchar],
+        vec![T::Varchar, T::Varchar, T::Varchar],
+    ] {
+        map.insert(FuncSign::new(E::RegexpLike, args), T::Boolean);
+    }
+    for args in [
+        vec![T::Varchar, T::Varchar, T::Varchar],
+        vec![T::Varchar, T::Varchar, T::Varchar, T::Varchar],
+    ] {
+        map.insert(FuncSign::new(E::RegexpReplace, args), T::Varchar);
+    }
+    // bytea expressions
+    map.insert(
+        FuncSign::new(E::Encode, vec![T::Bytea, T::Varchar]),
         T::Varchar,
     );
+    map.insert(
+        FuncSign::new(E::Decode, vec![T::Varchar, T::Varchar]),
+        T::Bytea,
+    );
+    map.insert(FuncSign::new(E::Sha256, vec![T::Bytea]), T::Bytea);
+    map.insert(FuncSign::new(E::Length, vec![T::Bytea]), T::Int32);
+    map.insert(FuncSign::new(E::Substr, vec![T::Bytea, T::Int32]), T::Bytea);
+    map.insert(
+        FuncSign::new(E::Substr, vec![T::Bytea, T::Int32, T::Int32]),
+        T::Bytea,
+    );
+    // jsonb expressions
+    for key in [T::Varchar, T::Int32] {
+        map.insert(
+            FuncSign::new(E::JsonbAccessInner, vec![T::Jsonb, key]),
+            T::Jsonb,
+        );
+        map.insert(
+            FuncSign::new(E::JsonbAccessStr, vec![T::Jsonb, key]),
+            T::Varchar,
+        );
+    }
+    map.insert(
+        FuncSign::new(E::JsonbAccessPath, vec![T::Jsonb, T::Varchar]),
+        T::Jsonb,
+    );
+    map.insert(
+        FuncSign::new(E::JsonbAccessPathStr, vec![T::Jsonb, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::JsonbContains, vec![T::Jsonb, T::Jsonb]),
+        T::Boolean,
+    );
+    map.insert(
+        FuncSign::new(E::JsonbExists, vec![T::Jsonb, T::Varchar]),
+        T::Boolean,
+    );
+    map.insert(FuncSign::new(E::JsonbTypeof, vec![T::Jsonb]), T::Varchar);
+    map.insert(FuncSign::new(E::GenRandomUuid, vec![]), T::Uuid);
+    // TODO: Support more `to_char` types.
+    for t in [T::Timestamp, T::Timestampz] {
+        map.insert(FuncSign::new(E::ToChar, vec![t, T::Varchar]), T::Varchar);
+    }
 
     map
 }
@@ -398,7 +577,7 @@ pub fn align_types<'a>(exprs: impl Iterator<Item = &'a mut ExprImpl>) -> Result<
     // Essentially a filter_map followed by a try_reduce, which is unstable.
     let mut ret_type = None;
     for e in &exprs {
-        if e.is_null() {
+        if e.is_null() || e.is_untyped_parameter() {
             continue;
         }
         ret_type = match ret_type {
@@ -463,6 +642,7 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
     // As we do not support size parameters in types, there are no sizing casts.
     m.insert((T::Boolean, T::Boolean), CastContext::Explicit);
     m.insert((T::Varchar, T::Varchar), CastContext::Explicit);
+    m.insert((T::Uuid, T::Uuid), CastContext::Explicit);
 
     // Casting to and from string type.
     for t in [
@@ -478,6 +658,9 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::Uuid,
     ] {
         m.insert((t, T::Varchar), CastContext::Assign);
         // Casting from string is explicit-only in PG.
@@ -0,0 +1,118 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+
+use super::{Expr, ExprImpl};
+use crate::optimizer::property::Direction;
+
+/// The window functions, which compute a value for each row from the rows of its partition.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WindowFunctionType {
+    RowNumber,
+}
+
+impl WindowFunctionType {
+    /// The window function of a (lowercase) function name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "row_number" => Some(Self::RowNumber),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::RowNumber => "row_number",
+        }
+    }
+}
+
+/// A call to a window function with its `OVER` clause. It is planned as a
+/// [`LogicalOverAgg`](crate::optimizer::plan_node::LogicalOverAgg) in the select list.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct WindowFunction {
+    pub function_type: WindowFunctionType,
+    pub return_type: DataType,
+    pub partition_by: Vec<ExprImpl>,
+    pub order_by: Vec<(ExprImpl, Direction)>,
+}
+
+impl WindowFunction {
+    /// Type checks the arguments and infers the return type of the window function.
+    pub fn new(
+        function_type: WindowFunctionType,
+        args: Vec<ExprImpl>,
+        partition_by: Vec<ExprImpl>,
+        order_by: Vec<(ExprImpl, Direction)>,
+    ) -> Result<Self> {
+        let return_type = match function_type {
+            WindowFunctionType::RowNumber => {
+                if !args.is_empty() {
+                    return Err(ErrorCode::BindError(format!(
+                        "Function `{}` takes 0 arguments ({} given)",
+                        function_type.name(),
+                        args.len()
+                    ))
+                    .into());
+                }
+                DataType::Int64
+            }
+        };
+        Ok(Self {
+            function_type,
+            return_type,
+            partition_by,
+            order_by,
+        })
+    }
+}
+
+impl std::fmt::Debug for WindowFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("WindowFunction")
+                .field("function_type", &self.function_type)
+                .field("return_type", &self.return_type)
+                .field("partition_by", &self.partition_by)
+                .field("order_by", &self.order_by)
+                .finish()
+        } else {
+            write!(f, "{:?}() OVER(", self.function_type)?;
+            if !self.partition_by.is_empty() {
+                write!(f, "PARTITION BY {:?} ", self.partition_by)?;
+            }
+            write!(f, "ORDER BY [")?;
+            for (i, (expr, direct)) in self.order_by.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{:?} {}", expr, direct)?;
+            }
+            write!(f, "])")
+        }
+    }
+}
+
+impl Expr for WindowFunction {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn to_expr_proto(&self) -> risingwave_pb::expr::ExprNode {
+        // A window function is always extracted into a `LogicalOverAgg`.
+        unreachable!("WindowFunction {:?} is not a scalar expression", self)
+    }
+}
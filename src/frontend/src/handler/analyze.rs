@@ -0,0 +1,247 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::for_await;
+use itertools::Itertools;
+use pgwire::pg_response::{PgResponse, StatementType};
+use rand::Rng;
+use risingwave_common::array::DataChunk;
+use risingwave_common::error::Result;
+use risingwave_common::types::ScalarImpl;
+use risingwave_sqlparser::ast::{
+    ObjectName, Query, Select, SelectItem, SetExpr, Statement, TableFactor, TableWithJoins,
+};
+
+use crate::binder::Binder;
+use crate::catalog::table_statistics::{ColumnStatistics, TableStatistics};
+use crate::catalog::ColumnId;
+use crate::handler::query::execute_query;
+use crate::session::OptimizerContext;
+use crate::utils::HyperLogLog;
+
+/// Maximum number of values sampled per column to build the histogram.
+const SAMPLE_SIZE: usize = 10000;
+/// Number of buckets of the equi-depth histogram.
+const HISTOGRAM_BUCKETS: usize = 100;
+
+pub(super) async fn handle_analyze(
+    context: OptimizerContext,
+    table_name: ObjectName,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+
+    let (table_id, column_ids) = {
+        let (schema_name, table_name) = Binder::resolve_table_name(&session, table_name.clone())?;
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let table =
+            catalog_reader.get_table_by_name(session.database(), &schema_name, &table_name)?;
+        let column_ids = table
+            .columns()
+            .iter()
+            .filter(|c| !c.is_hidden)
+            .map(|c| c.column_id())
+            .collect_vec();
+        (table.id(), column_ids)
+    };
+
+    // Scan the whole table with `SELECT * FROM <table>`, whose output columns are exactly the
+    // visible columns of the table in order.
+    let bound = {
+        let mut binder = Binder::new_for_session(&session);
+        binder.bind(scan_table_statement(table_name))?
+    };
+    let (data_stream, _) = execute_query(context, bound).await?;
+
+    let mut collector = StatisticsCollector::new(column_ids);
+    #[for_await]
+    for chunk in data_stream {
+        collector.add_chunk(&chunk?);
+    }
+
+    let statistics = collector.finish().to_prost()?;
+    session
+        .env()
+        .catalog_writer()
+        .update_table_statistics(table_id, statistics)
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::ANALYZE))
+}
+
+fn scan_table_statement(table_name: ObjectName) -> Statement {
+    Statement::Query(Box::new(Query {
+        with: None,
+        body: SetExpr::Select(Box::new(Select {
+            distinct: false,
+            projection: vec![SelectItem::Wildcard],
+            from: vec![TableWithJoins {
+                relation: TableFactor::Table {
+                    name: table_name,
+                    alias: None,
+                    args: vec![],
+                },
+                joins: vec![],
+            }],
+            lateral_views: vec![],
+            selection: None,
+            group_by: vec![],
+            having: None,
+        })),
+        order_by: vec![],
+        limit: None,
+        offset: None,
+        fetch: None,
+    }))
+}
+
+/// Collects the statistics of each column from the scanned data chunks in a single pass: nulls
+/// are counted exactly, distinct values are estimated with a [`HyperLogLog`], and the histogram
+/// is built from a reservoir sample of the non-null values.
+struct StatisticsCollector {
+    row_count: u64,
+    columns: Vec<ColumnCollector>,
+}
+
+struct ColumnCollector {
+    column_id: ColumnId,
+    null_count: u64,
+    non_null_count: u64,
+    distinct: HyperLogLog,
+    sample: Vec<ScalarImpl>,
+}
+
+impl StatisticsCollector {
+    fn new(column_ids: Vec<ColumnId>) -> Self {
+        Self {
+            row_count: 0,
+            columns: column_ids
+                .into_iter()
+                .map(|column_id| ColumnCollector {
+                    column_id,
+                    null_count: 0,
+                    non_null_count: 0,
+                    distinct: HyperLogLog::new(),
+                    sample: vec![],
+                })
+                .collect(),
+        }
+    }
+
+    fn add_chunk(&mut self, chunk: &DataChunk) {
+        let mut rng = rand::thread_rng();
+        for row in chunk.rows() {
+            self.row_count += 1;
+            for (idx, column) in self.columns.iter_mut().enumerate() {
+                let scalar = match row.value_at(idx) {
+                    Some(scalar) => scalar.into_scalar_impl(),
+                    None => {
+                        column.null_count += 1;
+                        continue;
+                    }
+                };
+                column.distinct.add(&scalar);
+                column.non_null_count += 1;
+                // Reservoir sampling keeps each value with the same probability.
+                if column.sample.len() < SAMPLE_SIZE {
+                    column.sample.push(scalar);
+                } else {
+                    let pos = rng.gen_range(0..column.non_null_count) as usize;
+                    if pos < SAMPLE_SIZE {
+                        column.sample[pos] = scalar;
+                    }
+                }
+            }
+        }
+    }
+
+    fn finish(self) -> TableStatistics {
+        let row_count = self.row_count;
+        let columns = self
+            .columns
+            .into_iter()
+            .map(|mut column| {
+                let null_fraction = if row_count == 0 {
+                    0.0
+                } else {
+                    column.null_count as f64 / row_count as f64
+                };
+                // The sketch may overestimate slightly, but never beyond the number of values.
+                let ndv = column.distinct.estimate().min(column.non_null_count);
+                column.sample.sort();
+                let histogram_bounds = build_histogram(&column.sample);
+                (
+                    column.column_id,
+                    ColumnStatistics {
+                        null_fraction,
+                        ndv,
+                        histogram_bounds,
+                    },
+                )
+            })
+            .collect();
+        TableStatistics { row_count, columns }
+    }
+}
+
+/// Picks the bounds of an equi-depth histogram from the sorted sample.
+fn build_histogram(sorted_sample: &[ScalarImpl]) -> Vec<ScalarImpl> {
+    if sorted_sample.is_empty() {
+        return vec![];
+    }
+    let buckets = HISTOGRAM_BUCKETS.min(sorted_sample.len());
+    (0..=buckets)
+        .map(|i| sorted_sample[(i * (sorted_sample.len() - 1)) / buckets].clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunk;
+    use risingwave_common::test_prelude::DataChunkTestExt;
+
+    use super::*;
+
+    #[test]
+    fn test_statistics_collector() {
+        let mut collector = StatisticsCollector::new(vec![ColumnId::new(1), ColumnId::new(2)]);
+        collector.add_chunk(&DataChunk::from_pretty(
+            "i I
+             1 .
+             2 .
+             2 .
+             3 .",
+        ));
+        collector.add_chunk(&DataChunk::from_pretty(
+            "i I
+             . .
+             4 .
+             4 .
+             4 .",
+        ));
+
+        let stats = collector.finish();
+        assert_eq!(stats.row_count, 8);
+
+        let column = stats.column(ColumnId::new(1)).unwrap();
+        assert_eq!(column.ndv, 4);
+        assert!((column.null_fraction - 0.125).abs() < f64::EPSILON);
+        assert_eq!(column.histogram_bounds.first(), Some(&ScalarImpl::Int32(1)));
+        assert_eq!(column.histogram_bounds.last(), Some(&ScalarImpl::Int32(4)));
+
+        let column = stats.column(ColumnId::new(2)).unwrap();
+        assert_eq!(column.ndv, 0);
+        assert!((column.null_fraction - 1.0).abs() < f64::EPSILON);
+        assert!(column.histogram_bounds.is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_pb::ddl_service::comment_on_request::Object;
+use risingwave_pb::stream_plan::source_node::SourceType;
+use risingwave_sqlparser::ast::{CommentObject, ObjectName};
+
+use crate::binder::Binder;
+use crate::catalog::CatalogError;
+use crate::session::OptimizerContext;
+
+pub async fn handle_comment(
+    context: OptimizerContext,
+    object_type: CommentObject,
+    object_name: ObjectName,
+    comment: Option<String>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+
+    // The column name is split off, so that the relation name is resolved before the catalog is
+    // read.
+    let (relation_name, column_name) = match object_type {
+        CommentObject::Column => {
+            let mut idents = object_name.0;
+            match idents.pop() {
+                Some(ident) if !idents.is_empty() => (ObjectName(idents), ident.value),
+                _ => {
+                    return Err(ErrorCode::InvalidInputSyntax(
+                        "column name must be qualified by the table name".to_string(),
+                    )
+                    .into())
+                }
+            }
+        }
+        _ => (object_name, String::new()),
+    };
+    let (schema_name, relation_name) = Binder::resolve_table_name(&session, relation_name)?;
+
+    let object = {
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let not_a = |name: &str| {
+            RwError::from(ErrorCode::InvalidInputSyntax(format!(
+                "\"{}\" is not a {}",
+                name,
+                object_type.to_string().to_lowercase()
+            )))
+        };
+
+        match object_type {
+            CommentObject::Column => {
+                let (object, columns) = match catalog_reader
+                    .get_schema_by_name(session.database(), &schema_name)?
+                    .get_table_by_name(&relation_name)
+                {
+                    Some(table) => (Object::TableId(table.id().table_id), &table.columns),
+                    None => {
+                        let source = catalog_reader.get_source_by_name(
+                            session.database(),
+                            &schema_name,
+                            &relation_name,
+                        )?;
+                        (Object::SourceId(source.id), &source.columns)
+                    }
+                };
+                if !columns
+                    .iter()
+                    .any(|c| !c.is_hidden && c.name() == column_name)
+                {
+                    return Err(CatalogError::NotFound("column", column_name).into());
+                }
+                object
+            }
+            CommentObject::Table | CommentObject::MaterializedView => {
+                let table = catalog_reader.get_table_by_name(
+                    session.database(),
+                    &schema_name,
+                    &relation_name,
+                )?;
+                // If associated source is `Some`, then it is a table rather than a materialized
+                // view.
+                let is_table = table.associated_source_id().is_some();
+                if table.is_index_on.is_some() || is_table != (object_type == CommentObject::Table)
+                {
+                    return Err(not_a(&relation_name));
+                }
+                Object::TableId(table.id().table_id)
+            }
+            CommentObject::Source => {
+                let source = catalog_reader.get_source_by_name(
+                    session.database(),
+                    &schema_name,
+                    &relation_name,
+                )?;
+                if source.source_type != SourceType::Source {
+                    return Err(not_a(&relation_name));
+                }
+                Object::SourceId(source.id)
+            }
+        }
+    };
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer
+        .comment_on(object, column_name, comment.unwrap_or_default())
+        .await?;
+
+    Ok(PgResponse::empty_result(StatementType::COMMENT))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+    use risingwave_common::types::ScalarImpl;
+
+    use crate::catalog::system_catalog::{get_system_catalog, SystemCatalogContext};
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_comment_on() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql("create table t (v1 int)").await.unwrap();
+
+        frontend
+            .run_sql("comment on table t is 'a table'")
+            .await
+            .unwrap();
+        frontend
+            .run_sql("comment on column t.v1 is 'a column'")
+            .await
+            .unwrap();
+        assert!(frontend
+            .run_sql("comment on materialized view t is 'not a mv'")
+            .await
+            .is_err());
+        assert!(frontend
+            .run_sql("comment on column t.v2 is 'no such column'")
+            .await
+            .is_err());
+
+        let session = frontend.session_ref();
+        let get_table = || {
+            session
+                .env()
+                .catalog_reader()
+                .read_guard()
+                .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t")
+                .unwrap()
+                .clone()
+        };
+        let table = get_table();
+        assert_eq!(table.description.as_deref(), Some("a table"));
+        let column = table.columns.iter().find(|c| c.name() == "v1").unwrap();
+        assert_eq!(column.description.as_deref(), Some("a column"));
+
+        let rows = frontend.run_sql("describe t").await.unwrap();
+        let descriptions = rows
+            .iter()
+            .map(|row| row.values()[2].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            descriptions,
+            vec![Some("a column".to_string()), Some("a table".to_string())]
+        );
+
+        let pg_description = get_system_catalog("pg_catalog", "pg_description").unwrap();
+        let rows = (pg_description.rows)(&SystemCatalogContext {
+            catalog: &session.env().catalog_reader().read_guard(),
+            db_name: DEFAULT_DATABASE_NAME,
+            users: &session.env().user_info_reader().read_guard(),
+        })
+        .unwrap();
+        let rows = rows
+            .into_iter()
+            .map(|row| (row.0[2].clone(), row.0[3].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                (
+                    Some(ScalarImpl::Int32(0)),
+                    Some(ScalarImpl::Utf8("a table".to_string()))
+                ),
+                (
+                    Some(ScalarImpl::Int32(1)),
+                    Some(ScalarImpl::Utf8("a column".to_string()))
+                ),
+            ]
+        );
+
+        frontend
+            .run_sql("comment on table t is null")
+            .await
+            .unwrap();
+        assert_eq!(get_table().description, None);
+    }
+}
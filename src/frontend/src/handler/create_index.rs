@@ -12,17 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::rc::Rc;
+use std::sync::Arc;
 
 use fixedbitset::FixedBitSet;
+use futures_async_stream::for_await;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::catalog::TableId;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::catalog::Table as ProstTable;
-use risingwave_sqlparser::ast::{ObjectName, OrderByExpr};
+use risingwave_sqlparser::ast::{Expr, Ident, ObjectName, OrderByExpr};
+use risingwave_sqlparser::parser::Parser;
 
 use crate::binder::Binder;
+use crate::handler::dml::flush_unique_dml;
+use crate::handler::query::execute_query;
 use crate::optimizer::plan_node::{LogicalScan, StreamTableScan};
 use crate::optimizer::property::{FieldOrder, Order, RequiredDist};
 use crate::optimizer::{PlanRef, PlanRoot};
@@ -35,16 +41,12 @@ pub(crate) fn gen_create_index_plan(
     index_name: ObjectName,
     table_name: ObjectName,
     columns: Vec<OrderByExpr>,
+    include: Vec<Ident>,
+    unique: bool,
 ) -> Result<(PlanRef, ProstTable)> {
     let columns = columns
         .iter()
         .map(|column| {
-            if column.asc.is_some() {
-                return Err(
-                    ErrorCode::NotImplemented("asc not supported".into(), None.into()).into(),
-                );
-            }
-
             if column.nulls_first.is_some() {
                 return Err(ErrorCode::NotImplemented(
                     "nulls_first not supported".into(),
@@ -53,10 +55,9 @@ pub(crate) fn gen_create_index_plan(
                 .into());
             }
 
-            use risingwave_sqlparser::ast::Expr;
-
             if let Expr::Identifier(ref ident) = column.expr {
-                Ok::<_, RwError>(ident)
+                // Index columns are ascending by default.
+                Ok::<_, RwError>((ident, column.asc.unwrap_or(true)))
             } else {
                 Err(ErrorCode::NotImplemented(
                     "only identifier is supported for create index".into(),
@@ -67,13 +68,25 @@ pub(crate) fn gen_create_index_plan(
         })
         .try_collect::<_, Vec<_>, _>()?;
 
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = Binder::resolve_table_name(session, table_name)?;
     let catalog_reader = session.env().catalog_reader();
     let table = catalog_reader
         .read_guard()
         .get_table_by_name(session.database(), &schema_name, &table_name)?
         .clone();
 
+    // As in Postgres, an index is always created in the schema of its table.
+    let index_table_name = match &index_name.0[..] {
+        [ident] => ident.value.clone(),
+        _ => {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "index name \"{}\" can't be qualified by a schema",
+                index_name
+            ))
+            .into())
+        }
+    };
+
     let table_desc = Rc::new(table.table_desc());
     let table_desc_map = table_desc
         .columns
@@ -81,32 +94,62 @@ pub(crate) fn gen_create_index_plan(
         .enumerate()
         .map(|(x, y)| (y.name.clone(), x))
         .collect::<HashMap<_, _>>();
+    let resolve_column = |ident: &Ident| {
+        let x = ident.to_string();
+        table_desc_map
+            .get(&x)
+            .cloned()
+            .ok_or_else(|| RwError::from(ErrorCode::ItemNotFound(x)))
+    };
     let arrange_keys = columns
         .iter()
-        .map(|x| {
-            let x = x.to_string();
-            table_desc_map
-                .get(&x)
-                .cloned()
-                .ok_or_else(|| ErrorCode::ItemNotFound(x).into())
-        })
-        .try_collect::<_, Vec<_>, RwError>()?;
+        .map(|(ident, asc)| Ok::<_, RwError>((resolve_column(ident)?, *asc)))
+        .try_collect::<_, Vec<_>, _>()?;
+    let include_columns = include
+        .iter()
+        .map(resolve_column)
+        .try_collect::<_, Vec<_>, _>()?;
+
+    // Without `INCLUDE`, an index stores all columns of the table, so that it covers any query on
+    // the table. Otherwise, it only stores its key columns, the included columns and the primary
+    // key of the table, in the order of the table.
+    let index_columns = if include.is_empty() {
+        (0..table_desc.columns.len()).collect_vec()
+    } else {
+        arrange_keys
+            .iter()
+            .map(|(idx, _)| *idx)
+            .chain(include_columns)
+            .chain(table_desc.pks.iter().copied())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect_vec()
+    };
+    let index_column_positions = index_columns
+        .iter()
+        .enumerate()
+        .map(|(position, idx)| (*idx, position))
+        .collect::<HashMap<_, _>>();
 
     // Manually assemble the materialization plan for the index MV.
     let materialize = {
-        let mut required_cols = FixedBitSet::with_capacity(table_desc.columns.len());
-        required_cols.toggle_range(..);
-        required_cols.toggle(0);
-        let mut out_names: Vec<String> =
-            table_desc.columns.iter().map(|c| c.name.clone()).collect();
-        out_names.remove(0);
+        let mut required_cols = FixedBitSet::with_capacity(index_columns.len());
+        let mut out_names = vec![];
+        for (position, idx) in index_columns.iter().enumerate() {
+            let column = &table.columns()[*idx];
+            if !column.is_hidden {
+                required_cols.insert(position);
+                out_names.push(column.name().to_string());
+            }
+        }
 
         let scan_node = StreamTableScan::new(LogicalScan::new(
             table_name,
-            (0..table_desc.columns.len()).into_iter().collect(),
+            index_columns,
             table_desc,
             // indexes are only used by DeltaJoin rule, and we don't need to provide them here.
             vec![],
+            None,
             context,
         ));
 
@@ -116,29 +159,32 @@ pub(crate) fn gen_create_index_plan(
             Order::new(
                 arrange_keys
                     .iter()
-                    .map(|id| FieldOrder::ascending(*id))
+                    .map(|(idx, asc)| {
+                        let position = index_column_positions[idx];
+                        if *asc {
+                            FieldOrder::ascending(position)
+                        } else {
+                            FieldOrder::descending(position)
+                        }
+                    })
                     .collect(),
             ),
             required_cols,
             out_names,
         )
-        .gen_create_index_plan(index_name.to_string(), table.id())?
+        .gen_create_index_plan(index_table_name.clone(), table.id())?
     };
 
-    let (index_schema_name, index_table_name) = Binder::resolve_table_name(index_name)?;
     let (index_database_id, index_schema_id) = session
         .env()
         .catalog_reader()
         .read_guard()
-        .check_relation_name_duplicated(
-            session.database(),
-            &index_schema_name,
-            &index_table_name,
-        )?;
+        .check_relation_name_duplicated(session.database(), &schema_name, &index_table_name)?;
 
-    let index_table = materialize
+    let mut index_table = materialize
         .table()
-        .to_prost(index_schema_id, index_database_id);
+        .to_prost(index_schema_id, index_database_id)?;
+    index_table.is_unique_index = unique;
 
     Ok((materialize.into(), index_table))
 }
@@ -148,8 +194,14 @@ pub async fn handle_create_index(
     name: ObjectName,
     table_name: ObjectName,
     columns: Vec<OrderByExpr>,
+    include: Vec<Ident>,
+    unique: bool,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
+    let key = columns
+        .iter()
+        .map(|column| column.expr.clone())
+        .collect_vec();
 
     let (graph, table) = {
         let (plan, table) = gen_create_index_plan(
@@ -158,9 +210,12 @@ pub async fn handle_create_index(
             name.clone(),
             table_name.clone(),
             columns,
+            include,
+            unique,
         )?;
         let plan = plan.to_stream_prost();
-        let graph = StreamFragmenter::build_graph(plan);
+        let mut graph = StreamFragmenter::build_graph(plan);
+        graph.parallelism = session.config().streaming_parallelism().unwrap_or(0);
 
         (graph, table)
     };
@@ -171,10 +226,71 @@ pub async fn handle_create_index(
         serde_json::to_string_pretty(&graph).unwrap()
     );
 
+    // The DML on the table checks the new index as soon as it's created, so holding the lock of the
+    // table until the existing rows are checked makes sure that none of them is left unchecked.
+    let _unique_guard = if unique {
+        Some(
+            session
+                .env()
+                .lock_unique_dml(TableId::new(table.index_on_id))
+                .await,
+        )
+    } else {
+        None
+    };
+
+    let index_name = table.name.clone();
     let catalog_writer = session.env().catalog_writer();
     catalog_writer
         .create_materialized_view(table, graph)
         .await?;
 
+    if unique && has_duplicated_keys(session.clone(), &table_name, &key).await? {
+        let (schema_name, _) = Binder::resolve_table_name(&session, table_name)?;
+        let index_id = session
+            .env()
+            .catalog_reader()
+            .read_guard()
+            .get_table_by_name(session.database(), &schema_name, &index_name)?
+            .id();
+        catalog_writer.drop_materialized_view(index_id).await?;
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "could not create unique index \"{}\": the table has duplicated keys",
+            index_name
+        ))
+        .into());
+    }
+
     Ok(PgResponse::empty_result(StatementType::CREATE_TABLE))
 }
+
+/// Returns whether the existing rows of a table have duplicated non-null values of `key`, after
+/// flushing the writes before it.
+async fn has_duplicated_keys(
+    session: Arc<SessionImpl>,
+    table_name: &ObjectName,
+    key: &[Expr],
+) -> Result<bool> {
+    flush_unique_dml(&session).await?;
+
+    let sql = format!(
+        "SELECT 1 FROM {} WHERE {} GROUP BY {} HAVING count(*) > 1 LIMIT 1",
+        table_name,
+        key.iter()
+            .map(|expr| format!("{} IS NOT NULL", expr))
+            .join(" AND "),
+        key.iter().join(", ")
+    );
+    let stmt = Parser::parse_sql(&sql)
+        .map_err(|e| ErrorCode::InternalError(e.to_string()))?
+        .remove(0);
+    let bound = Binder::new_for_session(&session).bind(stmt)?;
+    let (data_stream, _) = execute_query(OptimizerContext::new(session), bound).await?;
+
+    let mut duplicated = false;
+    #[for_await]
+    for chunk in data_stream {
+        duplicated |= chunk?.cardinality() > 0;
+    }
+    Ok(duplicated)
+}
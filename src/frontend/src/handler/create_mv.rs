@@ -31,7 +31,7 @@ pub fn gen_create_mv_plan(
     query: Box<Query>,
     name: ObjectName,
 ) -> Result<(PlanRef, ProstTable)> {
-    let (schema_name, table_name) = Binder::resolve_table_name(name)?;
+    let (schema_name, table_name) = Binder::resolve_create_name(session, name)?;
     let (database_id, schema_id) = session
         .env()
         .catalog_reader()
@@ -39,14 +39,18 @@ pub fn gen_create_mv_plan(
         .check_relation_name_duplicated(session.database(), &schema_name, &table_name)?;
 
     let bound = {
-        let mut binder = Binder::new(
-            session.env().catalog_reader().read_guard(),
-            session.database().to_string(),
-        );
+        let mut binder = Binder::new_for_session(session);
         binder.bind_query(*query)?
     };
 
-    if let BoundSetExpr::Select(select) = &bound.body {
+    // The output columns of a set operation are named after its leftmost side.
+    let mut body = &bound.body;
+    while let BoundSetExpr::SetOperation { left, .. }
+    | BoundSetExpr::RecursiveUnion { base: left, .. } = body
+    {
+        body = left;
+    }
+    if let BoundSetExpr::Select(select) = body {
         // `InputRef`'s alias will be implicitly assigned in `bind_project`.
         // For other expressions, we require the user to explicitly assign an alias.
         if select.aliases.iter().any(Option::is_none) {
@@ -57,10 +61,10 @@ pub fn gen_create_mv_plan(
         }
     }
 
-    let mut plan_root = Planner::new(context).plan_query(bound)?;
+    let mut plan_root = Planner::new(context).plan_streaming_query(bound)?;
     plan_root.set_required_dist(RequiredDist::Any);
     let materialize = plan_root.gen_create_mv_plan(table_name)?;
-    let table = materialize.table().to_prost(schema_id, database_id);
+    let table = materialize.table().to_prost(schema_id, database_id)?;
     let plan: PlanRef = materialize.into();
 
     Ok((plan, table))
@@ -76,7 +80,8 @@ pub async fn handle_create_mv(
     let (table, graph) = {
         let (plan, table) = gen_create_mv_plan(&session, context.into(), query, name)?;
         let stream_plan = plan.to_stream_prost();
-        let graph = StreamFragmenter::build_graph(stream_plan);
+        let mut graph = StreamFragmenter::build_graph(stream_plan);
+        graph.parallelism = session.config().streaming_parallelism().unwrap_or(0);
 
         (table, graph)
     };
@@ -98,7 +103,10 @@ pub mod tests {
     use itertools::Itertools;
     use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
     use risingwave_common::types::DataType;
+    use risingwave_sqlparser::ast::{Ident, Statement};
+    use risingwave_sqlparser::parser::Parser;
 
+    use super::*;
     use crate::catalog::row_id_column_name;
     use crate::test_utils::{create_proto_file, LocalFrontend, PROTO_FILE_DATA};
 
@@ -196,4 +204,43 @@ pub mod tests {
             "Bind error: An alias must be specified for an expression"
         );
     }
+
+    #[tokio::test]
+    async fn test_now() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+
+        let sql = "create table t(x timestamp with time zone)";
+        frontend.run_sql(sql).await.unwrap();
+
+        let sql = "create materialized view mv1 as select x from t where x > now()";
+        frontend.run_sql(sql).await.unwrap();
+
+        // The table is filtered by the current time, which is updated on every barrier.
+        let sql = "select x from t where x > now() - interval '1' hour";
+        let query = match Parser::parse_sql(sql).unwrap().remove(0) {
+            Statement::Query(query) => query,
+            _ => unreachable!(),
+        };
+        let session = frontend.session_ref();
+        let context = OptimizerContext::new(session.clone()).into();
+        let name = ObjectName(vec![Ident::new("mv2")]);
+        let (plan, _) = gen_create_mv_plan(&session, context, query, name).unwrap();
+        let plan = plan.explain_to_string().unwrap();
+        assert!(plan.contains("StreamDynamicFilter"));
+        assert!(plan.contains("StreamNow"));
+        assert!(!plan.contains("StreamNestedLoopJoin"));
+
+        // Other uses of `now()` would have to update the whole view as time passes.
+        let sql = "create materialized view mv3 as select x, now() from t";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert!(err.to_string().contains("now() in a streaming query"));
+        let sql = "create materialized view mv3 as select x from t where x + interval '1' hour \
+                   > now() or x is null";
+        let err = frontend.run_sql(sql).await.unwrap_err();
+        assert!(err.to_string().contains("now() in a streaming query"));
+
+        // `now()` is a constant in batch queries.
+        let plan = frontend.to_batch_plan(sql).await.unwrap();
+        assert!(!plan.explain_to_string().unwrap().contains("Now"));
+    }
 }
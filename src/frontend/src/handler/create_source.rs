@@ -16,12 +16,13 @@ use std::collections::HashMap;
 
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::ErrorCode::ProtocolError;
+use risingwave_common::error::ErrorCode::{self, ProtocolError};
 use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo};
 use risingwave_pb::plan_common::{ColumnCatalog as ProstColumnCatalog, RowFormatType};
-use risingwave_source::ProtobufParser;
+use risingwave_source::{ProtobufParser, JSON_PAYLOAD_COLUMN_KEY};
 use risingwave_sqlparser::ast::{
     CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema, SqlOption, Value,
 };
@@ -37,7 +38,7 @@ pub(crate) fn make_prost_source(
     name: ObjectName,
     source_info: Info,
 ) -> Result<ProstSource> {
-    let (schema_name, name) = Binder::resolve_table_name(name)?;
+    let (schema_name, name) = Binder::resolve_create_name(session, name)?;
 
     let (database_id, schema_id) = session
         .env()
@@ -51,6 +52,7 @@ pub(crate) fn make_prost_source(
         database_id,
         name,
         info: Some(source_info),
+        description: String::new(),
     })
 }
 
@@ -64,6 +66,7 @@ fn extract_protobuf_table_schema(schema: &ProtobufSchema) -> Result<Vec<ProstCol
         .map(|col| ProstColumnCatalog {
             column_desc: Some(col),
             is_hidden: false,
+            description: String::new(),
         })
         .collect_vec())
 }
@@ -80,6 +83,35 @@ fn handle_source_with_properties(options: Vec<SqlOption>) -> Result<HashMap<Stri
         .collect()
 }
 
+/// Check that the column taking the whole message, if there is one, is a `jsonb` column.
+fn check_json_payload_column(
+    properties: &HashMap<String, String>,
+    columns: &[ProstColumnCatalog],
+) -> Result<()> {
+    let Some(name) = properties.get(JSON_PAYLOAD_COLUMN_KEY) else {
+        return Ok(());
+    };
+    let column_type = columns
+        .iter()
+        .filter_map(|c| c.column_desc.as_ref())
+        .find(|desc| &desc.name == name)
+        .and_then(|desc| desc.column_type.as_ref())
+        .map(DataType::from);
+    match column_type {
+        Some(DataType::Jsonb) => Ok(()),
+        Some(data_type) => Err(ErrorCode::InvalidInputSyntax(format!(
+            "column \"{}\" in option \"{}\" must be of type jsonb, but is {:?}",
+            name, JSON_PAYLOAD_COLUMN_KEY, data_type
+        ))
+        .into()),
+        None => Err(ErrorCode::InvalidInputSyntax(format!(
+            "column \"{}\" in option \"{}\" does not exist",
+            name, JSON_PAYLOAD_COLUMN_KEY
+        ))
+        .into()),
+    }
+}
+
 pub async fn handle_create_source(
     context: OptimizerContext,
     is_materialized: bool,
@@ -98,14 +130,19 @@ pub async fn handle_create_source(
                 pk_column_ids: vec![0],
             }
         }
-        SourceSchema::Json => StreamSourceInfo {
-            properties: handle_source_with_properties(stmt.with_properties.0)?,
-            row_format: RowFormatType::Json as i32,
-            row_schema_location: "".to_string(),
-            row_id_index: 0,
-            columns: bind_sql_columns(stmt.columns)?,
-            pk_column_ids: vec![0],
-        },
+        SourceSchema::Json => {
+            let properties = handle_source_with_properties(stmt.with_properties.0)?;
+            let columns = bind_sql_columns(stmt.columns)?;
+            check_json_payload_column(&properties, &columns)?;
+            StreamSourceInfo {
+                properties,
+                row_format: RowFormatType::Json as i32,
+                row_schema_location: "".to_string(),
+                row_id_index: 0,
+                columns,
+                pk_column_ids: vec![0],
+            }
+        }
     };
 
     let session = context.session_ctx.clone();
@@ -115,7 +152,8 @@ pub async fn handle_create_source(
         let (graph, table) = {
             let (plan, table) = gen_materialized_source_plan(context.into(), source.clone())?;
             let plan = plan.to_stream_prost();
-            let graph = StreamFragmenter::build_graph(plan);
+            let mut graph = StreamFragmenter::build_graph(plan);
+            graph.parallelism = session.config().streaming_parallelism().unwrap_or(0);
 
             (graph, table)
         };
@@ -193,4 +231,25 @@ pub mod tests {
         };
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_json_source_with_payload_column() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql(
+                "CREATE SOURCE s (id INT, payload JSONB) \
+                 WITH ('kafka.topic' = 'abc', 'json.payload_column' = 'payload') ROW FORMAT JSON",
+            )
+            .await
+            .unwrap();
+
+        let err = frontend
+            .run_sql(
+                "CREATE SOURCE s2 (id INT) \
+                 WITH ('kafka.topic' = 'abc', 'json.payload_column' = 'id') ROW FORMAT JSON",
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("must be of type jsonb"), "{}", err);
+    }
 }
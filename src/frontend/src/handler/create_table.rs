@@ -69,6 +69,7 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<Vec<ColumnCatalog>> {
         .map(|(i, c)| ColumnCatalog {
             column_desc: c.to_protobuf().into(),
             is_hidden: i == 0, // the row id column is hidden
+            description: String::new(),
         })
         .collect_vec();
     Ok(columns_catalog)
@@ -118,7 +119,7 @@ pub(crate) fn gen_materialized_source_plan(
     };
     let table = materialize
         .table()
-        .to_prost(source.schema_id, source.database_id);
+        .to_prost(source.schema_id, source.database_id)?;
 
     Ok((materialize.into(), table))
 }
@@ -134,7 +135,8 @@ pub async fn handle_create_table(
         let (plan, source, table) =
             gen_create_table_plan(&session, context.into(), table_name.clone(), columns)?;
         let plan = plan.to_stream_prost();
-        let graph = StreamFragmenter::build_graph(plan);
+        let mut graph = StreamFragmenter::build_graph(plan);
+        graph.parallelism = session.config().streaming_parallelism().unwrap_or(0);
 
         (graph, source, table)
     };
@@ -16,11 +16,11 @@ use itertools::Itertools;
 use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
 use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::types::Row;
-use risingwave_common::catalog::ColumnDesc;
 use risingwave_common::error::Result;
 use risingwave_sqlparser::ast::{display_comma_separated, ObjectName};
 
 use crate::binder::Binder;
+use crate::catalog::column_catalog::ColumnCatalog;
 use crate::catalog::table_catalog::TableCatalog;
 use crate::handler::util::col_descs_to_rows;
 use crate::session::OptimizerContext;
@@ -30,13 +30,13 @@ pub async fn handle_describe(
     table_name: ObjectName,
 ) -> Result<PgResponse> {
     let session = context.session_ctx;
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = Binder::resolve_table_name(&session, table_name)?;
 
     let catalog_reader = session.env().catalog_reader().read_guard();
 
     // For Source, it doesn't have table catalog so use get source to get column descs.
-    let (columns, indices): (Vec<ColumnDesc>, Vec<TableCatalog>) = {
-        let (catalogs, indices) = match catalog_reader
+    let (columns, indices, description): (Vec<ColumnCatalog>, Vec<TableCatalog>, _) = {
+        let (catalogs, indices, description) = match catalog_reader
             .get_schema_by_name(session.database(), &schema_name)?
             .get_table_by_name(&table_name)
         {
@@ -48,26 +48,39 @@ pub async fn handle_describe(
                     .filter(|x| x.is_index_on == Some(table.id))
                     .cloned()
                     .collect_vec(),
+                table.description.clone(),
             ),
-            None => (
-                &catalog_reader
-                    .get_source_by_name(session.database(), &schema_name, &table_name)?
-                    .columns,
-                vec![],
-            ),
+            None => {
+                let source = catalog_reader.get_source_by_name(
+                    session.database(),
+                    &schema_name,
+                    &table_name,
+                )?;
+                (&source.columns, vec![], source.description.clone())
+            }
         };
         (
-            catalogs
-                .iter()
-                .filter(|c| !c.is_hidden)
-                .map(|c| c.column_desc.clone())
-                .collect(),
+            catalogs.iter().filter(|c| !c.is_hidden).cloned().collect(),
             indices,
+            description,
         )
     };
 
-    // Convert all column descs to rows
-    let mut rows = col_descs_to_rows(columns);
+    // Convert all column descs to rows, with the comment on the column following its type. Nested
+    // fields of a struct column don't have comments.
+    let mut rows = columns
+        .into_iter()
+        .flat_map(|c| {
+            col_descs_to_rows(vec![c.column_desc])
+                .into_iter()
+                .enumerate()
+                .map(move |(i, row)| {
+                    let mut values = row.values().to_vec();
+                    values.push(if i == 0 { c.description.clone() } else { None });
+                    Row::new(values)
+                })
+        })
+        .collect_vec();
 
     // Convert all indexs to rows
     rows.extend(indices.iter().map(|i| {
@@ -79,9 +92,19 @@ pub async fn handle_describe(
         Row::new(vec![
             Some(i.name.clone()),
             Some(format!("index({})", display_comma_separated(&s))),
+            i.description.clone(),
         ])
     }));
 
+    // The comment on the table itself, if any, comes last.
+    if let Some(description) = description {
+        rows.push(Row::new(vec![
+            Some(table_name),
+            Some("table description".to_string()),
+            Some(description),
+        ]));
+    }
+
     // TODO: recover the original user statement
     Ok(PgResponse::new(
         StatementType::DESCRIBE_TABLE,
@@ -90,6 +113,7 @@ pub async fn handle_describe(
         vec![
             PgFieldDescriptor::new("Name".to_owned(), TypeOid::Varchar),
             PgFieldDescriptor::new("Type".to_owned(), TypeOid::Varchar),
+            PgFieldDescriptor::new("Description".to_owned(), TypeOid::Varchar),
         ],
     ))
 }
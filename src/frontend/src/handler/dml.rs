@@ -12,34 +12,47 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::iter::once;
+
 use futures_async_stream::for_await;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::Result;
+use risingwave_common::array::{Op, Row};
+use risingwave_common::catalog::TableId;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::Statement;
 
-use crate::binder::Binder;
+use crate::binder::{Binder, BoundSetExpr, BoundStatement};
 use crate::handler::util::{to_pg_field, to_pg_rows};
 use crate::planner::Planner;
 use crate::scheduler::{ExecutionContext, ExecutionContextRef};
 use crate::session::{OptimizerContext, SessionImpl};
 
-/// If `RW_IMPLICIT_FLUSH` is on, then every INSERT/UPDATE/DELETE statement will block
-/// until the entire dataflow is refreshed. In other words, every related table & MV will
-/// be able to see the write.
-pub static IMPLICIT_FLUSH: &str = "RW_IMPLICIT_FLUSH";
-
 pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<PgResponse> {
     let stmt_type = to_statement_type(&stmt);
     let session = context.session_ctx.clone();
 
     let bound = {
-        let mut binder = Binder::new(
-            session.env().catalog_reader().read_guard(),
-            session.database().to_string(),
-        );
+        let mut binder = Binder::new_for_session(&session);
         binder.bind(stmt)?
     };
 
+    handle_bound_dml(context, stmt_type, bound).await
+}
+
+/// Executes a bound INSERT/UPDATE/DELETE and reports the number of affected rows. In a
+/// transaction, the rows it writes are buffered until `COMMIT` instead.
+pub(super) async fn handle_bound_dml(
+    context: OptimizerContext,
+    stmt_type: StatementType,
+    bound: BoundStatement,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+    if session.in_transaction() {
+        return buffer_dml(context, stmt_type, bound).await;
+    }
+    let unique_table_id = unique_checked_table(&bound);
+
     let (plan, pg_descs) = {
         // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
         let root = Planner::new(context.into()).plan(bound)?;
@@ -49,16 +62,22 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
         (plan.to_batch_prost(), pg_descs)
     };
 
+    let _unique_guard = match unique_table_id {
+        Some(table_id) => Some(session.env().lock_unique_dml(table_id).await),
+        None => None,
+    };
+
     let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
     let query_manager = execution_context.session().env().query_manager().clone();
 
+    let extra_float_digits = session.config().extra_float_digits();
     let mut rows = vec![];
     #[for_await]
     for chunk in query_manager
         .schedule_single(execution_context, plan)
         .await?
     {
-        rows.extend(to_pg_rows(chunk?));
+        rows.extend(to_pg_rows(chunk?, extra_float_digits));
     }
 
     let rows_count = match stmt_type {
@@ -75,15 +94,143 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
     };
 
     // Implicitly flush the writes.
-    if let Some(flag) = session.get_config(IMPLICIT_FLUSH) {
-        if flag.is_set(false) {
-            flush_for_write(&session, stmt_type).await?;
-        }
+    let implicit_flush = session.config().implicit_flush();
+    if unique_table_id.is_some() {
+        flush_unique_dml(&session).await?;
+    } else if implicit_flush {
+        flush_for_write(&session, stmt_type).await?;
     }
 
     Ok(PgResponse::new(stmt_type, rows_count, rows, pg_descs))
 }
 
+/// Computes the rows a bound INSERT/UPDATE/DELETE writes on the snapshot of the transaction, and
+/// buffers them in the transaction. They are not visible to any read until `COMMIT`.
+async fn buffer_dml(
+    context: OptimizerContext,
+    stmt_type: StatementType,
+    bound: BoundStatement,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+    let table_source = match &bound {
+        BoundStatement::Insert(insert) => &insert.table_source,
+        BoundStatement::Delete(delete) => &delete.table_source,
+        BoundStatement::Update(update) => &update.table_source,
+        BoundStatement::Query(_) => unreachable!(),
+    };
+    let (table_id, unique_indexes) = (table_source.source_id, table_source.unique_indexes.clone());
+
+    // The writes to each table are applied by a separate task at `COMMIT`, so the writes to more
+    // than one table wouldn't be atomic.
+    let buffered_table_ids = session.buffered_table_ids();
+    if buffered_table_ids.iter().any(|id| *id != table_id) {
+        return Err(ErrorCode::NotImplemented(
+            "writing to more than one table in a transaction".to_string(),
+            None.into(),
+        )
+        .into());
+    }
+    // A statement reads the snapshot of the transaction, which doesn't have the rows buffered
+    // before it. So only the statements that don't read the table can follow a write to it.
+    let reads_table = match &bound {
+        BoundStatement::Insert(insert) => match &insert.source.body {
+            BoundSetExpr::Values(values) => {
+                values.rows.iter().flatten().any(|expr| expr.has_subquery())
+            }
+            _ => true,
+        },
+        _ => true,
+    };
+    if reads_table && buffered_table_ids.contains(&table_id) {
+        return Err(ErrorCode::NotImplemented(
+            "reading a table with writes buffered in the same transaction. Only INSERT ... \
+             VALUES can follow other writes to the table before COMMIT"
+                .to_string(),
+            None.into(),
+        )
+        .into());
+    }
+
+    let (plan, data_types) = {
+        // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
+        let root = Planner::new(context.into()).plan_changes(bound)?;
+        let data_types = root.schema().data_types();
+        let plan = root.gen_batch_query_plan()?;
+
+        (plan.to_batch_prost(), data_types)
+    };
+
+    let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
+    let query_manager = execution_context.session().env().query_manager().clone();
+
+    let mut rows = vec![];
+    #[for_await]
+    for chunk in query_manager
+        .schedule_single(execution_context, plan)
+        .await?
+    {
+        rows.extend(chunk?.rows().map(|row| row.to_owned_row()));
+    }
+    let rows_count = rows.len() as i32;
+
+    // Convert the rows to the layout of the table: the row id followed by the columns.
+    let (data_types, writes): (Vec<DataType>, Vec<(Op, Row)>) = match stmt_type {
+        StatementType::INSERT => {
+            let data_types = once(DataType::Int64).chain(data_types).collect();
+            let writes = rows
+                .into_iter()
+                .map(|row| (Op::Insert, Row(once(None).chain(row.0).collect())))
+                .collect();
+            (data_types, writes)
+        }
+        StatementType::DELETE => {
+            let writes = rows.into_iter().map(|row| (Op::Delete, row)).collect();
+            (data_types, writes)
+        }
+        StatementType::UPDATE => {
+            let len = data_types.len() / 2;
+            let writes = rows
+                .into_iter()
+                .flat_map(|Row(mut old)| {
+                    let new = old.split_off(len);
+                    [(Op::UpdateDelete, Row(old)), (Op::UpdateInsert, Row(new))]
+                })
+                .collect();
+            (data_types[..len].to_vec(), writes)
+        }
+        _ => unreachable!(),
+    };
+    session.buffer_writes(table_id, data_types, writes, unique_indexes)?;
+
+    Ok(PgResponse::new(stmt_type, rows_count, vec![], vec![]))
+}
+
+/// Returns the table of an INSERT/UPDATE if it has unique indexes.
+///
+/// The keys a DML adds are checked against the unique indexes at the epoch it reads, which doesn't
+/// have the writes of the DML running at the same time. So the DML that may add keys to a table
+/// with unique indexes holds the lock of the table in the frontend until its writes are flushed.
+fn unique_checked_table(bound: &BoundStatement) -> Option<TableId> {
+    let table_source = match bound {
+        BoundStatement::Insert(insert) => &insert.table_source,
+        BoundStatement::Update(update) => &update.table_source,
+        _ => return None,
+    };
+    (!table_source.unique_indexes.is_empty()).then(|| table_source.source_id)
+}
+
+/// Flushes the writes of a DML that holds the lock of a table with unique indexes, and makes the
+/// next query read the epoch that has them.
+pub(super) async fn flush_unique_dml(session: &SessionImpl) -> Result<()> {
+    session.env().meta_client().flush().await?;
+    session
+        .env()
+        .hummock_snapshot_manager()
+        .mark_outdated()
+        .await;
+    Ok(())
+}
+
 async fn flush_for_write(session: &SessionImpl, stmt_type: StatementType) -> Result<()> {
     match stmt_type {
         StatementType::INSERT | StatementType::DELETE | StatementType::UPDATE => {
@@ -15,12 +15,13 @@
 use std::sync::Arc;
 
 use pgwire::pg_response::PgResponse;
-use pgwire::pg_response::StatementType::{ABORT, START_TRANSACTION};
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_sqlparser::ast::{DropStatement, ObjectType, Statement};
 
 use crate::session::{OptimizerContext, SessionImpl};
 
+mod analyze;
+mod comment;
 mod create_database;
 pub mod create_index;
 pub mod create_mv;
@@ -39,10 +40,12 @@ pub mod drop_table;
 pub mod drop_user;
 mod explain;
 mod flush;
+mod prepared_statement;
 #[allow(dead_code)]
 pub mod query;
 mod set;
 mod show;
+mod transaction;
 pub mod util;
 
 pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result<PgResponse> {
@@ -70,6 +73,11 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
         } => create_schema::handle_create_schema(context, schema_name, if_not_exists).await,
         Statement::CreateUser(stmt) => create_user::handle_create_user(context, stmt).await,
         Statement::Describe { name } => describe::handle_describe(context, name).await,
+        Statement::Comment {
+            object_type,
+            object_name,
+            comment,
+        } => comment::handle_comment(context, object_type, object_name, comment).await,
         Statement::ShowObjects(show_object) => show::handle_show_object(context, show_object).await,
         Statement::Drop(DropStatement {
             object_type,
@@ -114,23 +122,31 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             ..
         } => create_mv::handle_create_mv(context, name, query).await,
         Statement::Flush => flush::handle_flush(context).await,
+        Statement::Analyze { table_name } => analyze::handle_analyze(context, table_name).await,
+        Statement::Prepare {
+            name,
+            data_types,
+            statement,
+        } => prepared_statement::handle_prepare(context, name, data_types, *statement),
+        Statement::Execute { name, parameters } => {
+            prepared_statement::handle_execute(context, name, parameters).await
+        }
+        Statement::Deallocate { name, .. } => prepared_statement::handle_deallocate(context, name),
         Statement::SetVariable {
             local: _,
             variable,
             value,
         } => set::handle_set(context, variable, value),
+        Statement::ShowVariable { variable } => set::handle_show(context, variable),
+        Statement::ResetVariable { variable } => set::handle_reset(context, variable),
         Statement::CreateIndex {
             name,
             table_name,
             columns,
+            include,
             unique,
             if_not_exists,
         } => {
-            if unique {
-                return Err(
-                    ErrorCode::NotImplemented("create unique index".into(), None.into()).into(),
-                );
-            }
             if if_not_exists {
                 return Err(ErrorCode::NotImplemented(
                     "create if_not_exists index".into(),
@@ -138,20 +154,13 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
                 )
                 .into());
             }
-            create_index::handle_create_index(context, name, table_name, columns).await
+            create_index::handle_create_index(context, name, table_name, columns, include, unique)
+                .await
         }
-        // Ignore `StartTransaction` and `Abort` temporarily.Its not final implementation.
-        // 1. Fully support transaction is too hard and gives few benefits to us.
-        // 2. Some client e.g. psycopg2 will use this statement.
-        // TODO: Track issues #2595 #2541
-        Statement::StartTransaction { .. } => Ok(PgResponse::empty_result_with_notice(
-            START_TRANSACTION,
-            "Ignored temporarily.See detail in issue#2541".to_string(),
-        )),
-        Statement::Abort { .. } => Ok(PgResponse::empty_result_with_notice(
-            ABORT,
-            "Ignored temporarily.See detail in issue#2541".to_string(),
-        )),
+        Statement::StartTransaction { .. } => transaction::handle_begin(context).await,
+        Statement::Commit { chain } => transaction::handle_commit(context, chain).await,
+        Statement::Rollback { chain } => transaction::handle_rollback(context, chain).await,
+        Statement::Abort => transaction::handle_rollback(context, false).await,
         _ => {
             Err(ErrorCode::NotImplemented(format!("Unhandled ast: {:?}", stmt), None.into()).into())
         }
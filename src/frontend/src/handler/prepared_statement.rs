@@ -0,0 +1,229 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_sqlparser::ast::{DataType as AstDataType, Expr, Ident, Statement};
+
+use crate::binder::expr::bind_data_type;
+use crate::binder::{Binder, BoundStatement};
+use crate::handler::dml::handle_bound_dml;
+use crate::handler::query::handle_bound_query;
+use crate::session::{BoundPreparedStatement, OptimizerContext, PreparedStatement, SessionImpl};
+
+pub(super) fn handle_prepare(
+    context: OptimizerContext,
+    name: Ident,
+    data_types: Vec<AstDataType>,
+    statement: Statement,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+
+    let stmt_type = match &statement {
+        Statement::Query(_) => StatementType::SELECT,
+        Statement::Insert { .. } => StatementType::INSERT,
+        Statement::Delete { .. } => StatementType::DELETE,
+        Statement::Update { .. } => StatementType::UPDATE,
+        _ => {
+            return Err(ErrorCode::NotImplemented(
+                format!("PREPARE of statement: {}", statement),
+                None.into(),
+            )
+            .into())
+        }
+    };
+    let param_types = data_types
+        .iter()
+        .map(bind_data_type)
+        .collect::<Result<Vec<_>>>()?;
+
+    // Bind the statement to validate it and count its parameters. `EXECUTE` reuses it until the
+    // catalog changes.
+    let mut bound = bind_prepared_statement(&session, &statement, &param_types)?;
+    let param_count = bound.statement.param_count().max(param_types.len());
+
+    session.add_prepared_statement(
+        &name.value,
+        PreparedStatement {
+            statement,
+            param_types,
+            stmt_type,
+            param_count,
+            bound,
+        },
+    )?;
+    Ok(PgResponse::empty_result(StatementType::PREPARE))
+}
+
+pub(super) async fn handle_execute(
+    context: OptimizerContext,
+    name: Ident,
+    parameters: Vec<Expr>,
+) -> Result<PgResponse> {
+    let session = context.session_ctx.clone();
+    let prepared = session.get_prepared_statement(&name.value)?;
+    if parameters.len() != prepared.param_count {
+        return Err(ErrorCode::BindError(format!(
+            "wrong number of parameters for prepared statement \"{}\": expected {}, got {}",
+            name.value,
+            prepared.param_count,
+            parameters.len()
+        ))
+        .into());
+    }
+
+    let bound = {
+        let params = Binder::new_for_session(&session).bind_execute_params(parameters)?;
+        get_bound_statement(&session, &name.value, &prepared)?.bind_parameters(params)?
+    };
+
+    match &bound {
+        BoundStatement::Query(_) => handle_bound_query(context, prepared.stmt_type, bound).await,
+        BoundStatement::Insert(_) | BoundStatement::Delete(_) | BoundStatement::Update(_) => {
+            handle_bound_dml(context, prepared.stmt_type, bound).await
+        }
+    }
+}
+
+/// Bind `statement` with its parameters left as `$n`, recording the catalog version and the
+/// `search_path` it is bound against.
+fn bind_prepared_statement(
+    session: &SessionImpl,
+    statement: &Statement,
+    param_types: &[DataType],
+) -> Result<BoundPreparedStatement> {
+    // Read the version before binding, so that a concurrent catalog change can only make the
+    // version older than the catalog the statement is bound against, and cause another bind.
+    let catalog_version = session.env().catalog_reader().read_guard().version();
+    let search_path = session.config().search_path().to_vec();
+    let mut binder = Binder::new_with_param_types(session, param_types.to_vec());
+    let statement = binder.bind(statement.clone())?;
+    Ok(BoundPreparedStatement {
+        catalog_version,
+        search_path,
+        statement,
+    })
+}
+
+/// Get the bound statement of the prepared statement `name`, reusing the cached one unless the
+/// catalog or the `search_path` has changed since it was bound.
+fn get_bound_statement(
+    session: &SessionImpl,
+    name: &str,
+    prepared: &PreparedStatement,
+) -> Result<BoundStatement> {
+    let catalog_version = session.env().catalog_reader().read_guard().version();
+    if prepared.bound.catalog_version == catalog_version
+        && prepared.bound.search_path == session.config().search_path()
+    {
+        return Ok(prepared.bound.statement.clone());
+    }
+    let bound = bind_prepared_statement(session, &prepared.statement, &prepared.param_types)?;
+    let statement = bound.statement.clone();
+    session.update_bound_prepared_statement(name, bound);
+    Ok(statement)
+}
+
+pub(super) fn handle_deallocate(context: OptimizerContext, name: Ident) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    if name.value.eq_ignore_ascii_case("all") && name.quote_style.is_none() {
+        session.clear_prepared_statements();
+    } else {
+        session.remove_prepared_statement(&name.value)?;
+    }
+    Ok(PgResponse::empty_result(StatementType::DEALLOCATE))
+}
+
+#[cfg(test)]
+mod tests {
+    use pgwire::pg_server::Session;
+    use risingwave_sqlparser::parser::Parser;
+
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_prepare_and_deallocate() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        // Prepared statements live in a session, so run all statements in the same one.
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| {
+            let session = session.clone();
+            async move { session.run_statement(sql).await }
+        };
+
+        run_sql("prepare p1 (int) as select $1 + 1").await.unwrap();
+        // Names of prepared statements are unique within a session.
+        assert!(run_sql("prepare p1 as select 1").await.is_err());
+        // Wrong number of parameters.
+        assert!(run_sql("execute p1").await.is_err());
+        assert!(run_sql("execute p1 (1, 2)").await.is_err());
+
+        run_sql("deallocate p1").await.unwrap();
+        assert!(run_sql("execute p1 (1)").await.is_err());
+        assert!(run_sql("deallocate p1").await.is_err());
+
+        run_sql("prepare p2 as select $1 = 1").await.unwrap();
+        run_sql("deallocate all").await.unwrap();
+        assert!(run_sql("execute p2 (1)").await.is_err());
+
+        // Parameters are only allowed in prepared statements.
+        assert!(run_sql("select $1").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_after_drop() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| session.clone().run_statement(sql);
+
+        run_sql("create table t (v int)").await.unwrap();
+        run_sql("prepare p as insert into t values ($1)")
+            .await
+            .unwrap();
+        run_sql("drop table t").await.unwrap();
+        // The statement is bound again, instead of inserting into the dropped table.
+        let err = run_sql("execute p (1)").await.unwrap_err();
+        assert!(err.to_string().contains("not found"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_reuse_bound_statement() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| session.clone().run_statement(sql);
+
+        run_sql("create table t (v int)").await.unwrap();
+        run_sql("prepare p as select v + $1 from t").await.unwrap();
+
+        // Make the saved statement fail to bind, so that binding it again would be an error.
+        let mut prepared = session.get_prepared_statement("p").unwrap();
+        prepared.statement = Parser::parse_sql("select v from not_exist")
+            .unwrap()
+            .remove(0);
+        session.remove_prepared_statement("p").unwrap();
+        session.add_prepared_statement("p", prepared).unwrap();
+
+        // The statement bound by `PREPARE` is reused.
+        let prepared = session.get_prepared_statement("p").unwrap();
+        get_bound_statement(&session, "p", &prepared).unwrap();
+        get_bound_statement(&session, "p", &prepared).unwrap();
+
+        // It is bound again after the catalog changes.
+        run_sql("create table t2 (v int)").await.unwrap();
+        let err = get_bound_statement(&session, "p", &prepared).unwrap_err();
+        assert!(err.to_string().contains("not found"), "{}", err);
+    }
+}
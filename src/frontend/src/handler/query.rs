@@ -29,34 +29,31 @@ use crate::scheduler::{
 };
 use crate::session::OptimizerContext;
 
-pub static QUERY_MODE: &str = "query_mode";
-
 pub async fn handle_query(context: OptimizerContext, stmt: Statement) -> Result<PgResponse> {
     let stmt_type = to_statement_type(&stmt);
     let session = context.session_ctx.clone();
 
     let bound = {
-        let mut binder = Binder::new(
-            session.env().catalog_reader().read_guard(),
-            session.database().to_string(),
-        );
+        let mut binder = Binder::new_for_session(&session);
         binder.bind(stmt)?
     };
 
-    let query_mode = session
-        .get_config(QUERY_MODE)
-        .map(|entry| entry.get_val(QueryMode::default()))
-        .unwrap_or_default();
+    handle_bound_query(context, stmt_type, bound).await
+}
 
-    let (data_stream, pg_descs) = match query_mode {
-        QueryMode::Local => local_execute(context, bound).await?,
-        QueryMode::Distributed => distribute_execute(context, bound).await?,
-    };
+/// Executes a bound query and collects all rows of its result.
+pub(super) async fn handle_bound_query(
+    context: OptimizerContext,
+    stmt_type: StatementType,
+    bound: BoundStatement,
+) -> Result<PgResponse> {
+    let extra_float_digits = context.session_ctx.config().extra_float_digits();
+    let (data_stream, pg_descs) = execute_query(context, bound).await?;
 
     let mut rows = vec![];
     #[for_await]
     for chunk in data_stream {
-        rows.extend(to_pg_rows(chunk?));
+        rows.extend(to_pg_rows(chunk?, extra_float_digits));
     }
 
     let rows_count = match stmt_type {
@@ -67,6 +64,19 @@ pub async fn handle_query(context: OptimizerContext, stmt: Statement) -> Result<
     Ok(PgResponse::new(stmt_type, rows_count, rows, pg_descs))
 }
 
+/// Plans and executes a bound query in the query mode configured for the session.
+pub(super) async fn execute_query(
+    context: OptimizerContext,
+    stmt: BoundStatement,
+) -> Result<(BoxedDataChunkStream, Vec<PgFieldDescriptor>)> {
+    let query_mode = context.session_ctx.config().query_mode();
+
+    match query_mode {
+        QueryMode::Local => local_execute(context, stmt).await,
+        QueryMode::Distributed => distribute_execute(context, stmt).await,
+    }
+}
+
 fn to_statement_type(stmt: &Statement) -> StatementType {
     use StatementType::*;
 
@@ -99,7 +109,10 @@ async fn distribute_execute(
             plan.explain_to_string()?
         );
 
-        let plan_fragmenter = BatchPlanFragmenter::new(session.env().worker_node_manager_ref());
+        let plan_fragmenter = BatchPlanFragmenter::new(
+            session.env().worker_node_manager_ref(),
+            session.config().batch_parallelism(),
+        );
         let query = plan_fragmenter.split(plan)?;
         info!("Generated query after plan fragmenter: {:?}", &query);
         (query, pg_descs)
@@ -137,7 +150,10 @@ async fn local_execute(
             plan.explain_to_string()?
         );
 
-        let plan_fragmenter = BatchPlanFragmenter::new(session.env().worker_node_manager_ref());
+        let plan_fragmenter = BatchPlanFragmenter::new(
+            session.env().worker_node_manager_ref(),
+            session.config().batch_parallelism(),
+        );
         let query = plan_fragmenter.split(plan)?;
         info!("Generated query after plan fragmenter: {:?}", &query);
         (query, pg_descs)
@@ -146,6 +162,7 @@ async fn local_execute(
     let hummock_snapshot_manager = session.env().hummock_snapshot_manager().clone();
 
     // TODO: Passing sql here
-    let execution = LocalQueryExecution::new(query, hummock_snapshot_manager, "");
+    let execution =
+        LocalQueryExecution::new(query, hummock_snapshot_manager, session.txn_epoch(), "");
     Ok((Box::pin(execution.run()), pg_descs))
 }
@@ -12,27 +12,236 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
+use pgwire::pg_field_descriptor::{PgFieldDescriptor, TypeOid};
 use pgwire::pg_response::{PgResponse, StatementType};
+use pgwire::types::Row;
 use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::{Ident, SetVariableValue};
+use risingwave_sqlparser::ast::{Ident, SetVariableValue, Value};
 
-use crate::session::OptimizerContext;
+use crate::config::SessionConfig;
+use crate::session::{OptimizerContext, SessionImpl};
 
 pub(super) fn handle_set(
     context: OptimizerContext,
     name: Ident,
     value: Vec<SetVariableValue>,
 ) -> Result<PgResponse> {
-    let string_val = to_string(&value[0]);
-    // Currently store the config variable simply as String -> ConfigEntry(String).
-    // In future we can add converter/parser to make the API more robust.
-    context.session_ctx.set_config(&name.value, &string_val);
+    let session = context.session_ctx;
+    // Clients like JDBC drivers set variables such as `client_encoding` that we don't support.
+    if SessionConfig::variable(&name.value).is_err() {
+        return Ok(PgResponse::empty_result_with_notice(
+            StatementType::SET_OPTION,
+            format!(
+                "unrecognized configuration parameter \"{}\" is ignored",
+                name.value
+            ),
+        ));
+    }
+    let is_default = matches!(
+        value.as_slice(),
+        [SetVariableValue::Ident(ident)]
+            if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("default")
+    );
+    if is_default {
+        session.reset_config(&name.value)?;
+    } else {
+        // A list of values is stored as a comma-separated string, e.g. for `search_path`.
+        let string_val = value.iter().map(to_string).join(", ");
+        session.set_config(&name.value, &string_val)?;
+    }
 
-    Ok(PgResponse::empty_result(StatementType::SET_OPTION))
+    with_parameter_status(
+        PgResponse::empty_result(StatementType::SET_OPTION),
+        &session,
+        &name.value,
+    )
+}
+
+pub(super) fn handle_reset(context: OptimizerContext, name: Ident) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let response = PgResponse::empty_result(StatementType::SET_OPTION);
+    if name.value.eq_ignore_ascii_case("all") && name.quote_style.is_none() {
+        session.reset_all_config();
+        return Ok(session
+            .config()
+            .show_all()
+            .into_iter()
+            .filter(|(name, _, _)| SessionConfig::variable(name).unwrap().report)
+            .fold(response, |response, (name, value, _)| {
+                response.with_parameter_status(name.to_string(), value)
+            }));
+    }
+    session.reset_config(&name.value)?;
+    with_parameter_status(response, &session, &name.value)
+}
+
+pub(super) fn handle_show(context: OptimizerContext, variable: Vec<Ident>) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let name = variable.iter().map(|ident| ident.value.as_str()).join(" ");
+    let config = session.config();
+
+    if name.eq_ignore_ascii_case("all") {
+        let rows = config
+            .show_all()
+            .into_iter()
+            .map(|(name, value, description)| {
+                Row::new(vec![
+                    Some(name.to_string()),
+                    Some(value),
+                    Some(description.to_string()),
+                ])
+            })
+            .collect_vec();
+        return Ok(PgResponse::new(
+            StatementType::SHOW_COMMAND,
+            rows.len() as i32,
+            rows,
+            vec![
+                PgFieldDescriptor::new("Name".to_string(), TypeOid::Varchar),
+                PgFieldDescriptor::new("Setting".to_string(), TypeOid::Varchar),
+                PgFieldDescriptor::new("Description".to_string(), TypeOid::Varchar),
+            ],
+        ));
+    }
+
+    let var = SessionConfig::variable(&name)?;
+    Ok(PgResponse::new(
+        StatementType::SHOW_COMMAND,
+        1,
+        vec![Row::new(vec![Some(config.get(&name)?)])],
+        vec![PgFieldDescriptor::new(
+            var.name.to_string(),
+            TypeOid::Varchar,
+        )],
+    ))
+}
+
+/// Report the new value of the variable to the client if it's one of those reported.
+fn with_parameter_status(
+    response: PgResponse,
+    session: &SessionImpl,
+    name: &str,
+) -> Result<PgResponse> {
+    let var = SessionConfig::variable(name)?;
+    if !var.report {
+        return Ok(response);
+    }
+    let value = session.config().get(name)?;
+    Ok(response.with_parameter_status(var.name.to_string(), value))
 }
 
 /// Convert any set variable to String.
-/// For example, TRUE -> "TRUE", 1 -> "1".
+/// For example, TRUE -> "TRUE", 1 -> "1", 'utc' -> "utc".
 fn to_string(value: &SetVariableValue) -> String {
-    format!("{}", value)
+    match value {
+        SetVariableValue::Ident(ident) => ident.value.clone(),
+        SetVariableValue::Literal(Value::SingleQuotedString(s)) => s.clone(),
+        SetVariableValue::Literal(literal) => literal.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pgwire::pg_server::Session;
+    use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
+
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_set_show_reset() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        // Configurations live in a session, so run all statements in the same one.
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| {
+            let session = session.clone();
+            async move { session.run_statement(sql).await }
+        };
+        let show = |sql: &'static str| {
+            let session = session.clone();
+            async move {
+                let response = session.run_statement(sql).await.unwrap();
+                let value = response.iter().next().unwrap().values()[0].clone().unwrap();
+                value
+            }
+        };
+
+        run_sql("set extra_float_digits = 0").await.unwrap();
+        assert_eq!(show("show extra_float_digits").await, "0");
+        assert!(run_sql("set extra_float_digits = 4").await.is_err());
+        let response = run_sql("set no_such_variable = 1").await.unwrap();
+        assert!(response.get_notice().is_some());
+        assert!(run_sql("show no_such_variable").await.is_err());
+
+        let response = run_sql("set application_name = 'psql'").await.unwrap();
+        assert_eq!(
+            response.get_parameter_status(),
+            [("application_name".to_string(), "psql".to_string())]
+        );
+        run_sql("set search_path to s1, public").await.unwrap();
+        assert_eq!(show("show search_path").await, "s1, public");
+        run_sql("set search_path to default").await.unwrap();
+        assert_eq!(show("show search_path").await, DEFAULT_SCHEMA_NAME);
+
+        run_sql("reset extra_float_digits").await.unwrap();
+        assert_eq!(show("show extra_float_digits").await, "1");
+        run_sql("reset all").await.unwrap();
+        assert_eq!(show("show application_name").await, "");
+
+        let response = run_sql("show all").await.unwrap();
+        assert_eq!(response.get_row_desc().len(), 3);
+        assert!(response.iter().count() > 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_path() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| session.clone().run_statement(sql);
+
+        run_sql("create schema s1").await.unwrap();
+        run_sql("create table s1.t (v1 int)").await.unwrap();
+        assert!(run_sql("explain select * from t").await.is_err());
+
+        run_sql("set search_path to s1, public").await.unwrap();
+        run_sql("explain select * from t").await.unwrap();
+
+        // Relations are created in the first existing schema of the `search_path`.
+        run_sql("set search_path to no_such_schema, s1, public")
+            .await
+            .unwrap();
+        run_sql("create table t2 (v1 int)").await.unwrap();
+        run_sql("explain select * from s1.t2").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_search_path_in_ddl() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| session.clone().run_statement(sql);
+
+        run_sql("create schema s1").await.unwrap();
+        run_sql("create table s1.t (v1 int)").await.unwrap();
+        run_sql("create table public.t (v1 int, v2 int)")
+            .await
+            .unwrap();
+        run_sql("set search_path to s1, public").await.unwrap();
+
+        // `s1.t` is found before `public.t`.
+        let response = run_sql("describe t").await.unwrap();
+        assert_eq!(response.iter().count(), 1);
+
+        run_sql("create materialized view mv as select * from t")
+            .await
+            .unwrap();
+        run_sql("describe mv").await.unwrap();
+        run_sql("drop materialized view mv").await.unwrap();
+        assert!(run_sql("describe s1.mv").await.is_err());
+
+        run_sql("drop table t").await.unwrap();
+        assert!(run_sql("describe s1.t").await.is_err());
+        // Then `public.t` is found.
+        let response = run_sql("describe t").await.unwrap();
+        assert_eq!(response.iter().count(), 2);
+    }
 }
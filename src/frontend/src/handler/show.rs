@@ -28,7 +28,7 @@ pub fn get_columns_from_table(
     session: &SessionImpl,
     table_name: ObjectName,
 ) -> Result<Vec<ColumnDesc>> {
-    let (schema_name, table_name) = Binder::resolve_table_name(table_name)?;
+    let (schema_name, table_name) = Binder::resolve_table_name(session, table_name)?;
 
     let catalog_reader = session.env().catalog_reader().read_guard();
     let catalogs = match catalog_reader
@@ -60,29 +60,30 @@ pub async fn handle_show_object(
     command: ShowObject,
 ) -> Result<PgResponse> {
     let session = context.session_ctx;
-    let catalog_reader = session.env().catalog_reader().read_guard();
+    // Not held by `SHOW COLUMNS`, which resolves the table name with the catalog.
+    let catalog_reader = || session.env().catalog_reader().read_guard();
 
     let names = match command {
         // If not include schema name, use default schema name
-        ShowObject::Table { schema } => catalog_reader
+        ShowObject::Table { schema } => catalog_reader()
             .get_schema_by_name(session.database(), schema_or_default(&schema))?
             .iter_table()
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::Database => catalog_reader.get_all_database_names(),
-        ShowObject::Schema => catalog_reader.get_all_schema_names(session.database())?,
+        ShowObject::Database => catalog_reader().get_all_database_names(),
+        ShowObject::Schema => catalog_reader().get_all_schema_names(session.database())?,
         // If not include schema name, use default schema name
-        ShowObject::MaterializedView { schema } => catalog_reader
+        ShowObject::MaterializedView { schema } => catalog_reader()
             .get_schema_by_name(session.database(), schema_or_default(&schema))?
             .iter_mv()
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::Source { schema } => catalog_reader
+        ShowObject::Source { schema } => catalog_reader()
             .get_schema_by_name(session.database(), schema_or_default(&schema))?
             .iter_source()
             .map(|t| t.name.clone())
             .collect(),
-        ShowObject::MaterializedSource { schema } => catalog_reader
+        ShowObject::MaterializedSource { schema } => catalog_reader()
             .get_schema_by_name(session.database(), schema_or_default(&schema))?
             .iter_materialized_source()
             .map(|t| t.name.clone())
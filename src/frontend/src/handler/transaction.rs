@@ -0,0 +1,216 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures_async_stream::for_await;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::array::StreamChunk;
+use risingwave_common::catalog::{Field, Schema, TableId};
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::{PlanNode, WriteChangesNode};
+use risingwave_pb::plan_common::TableRefId;
+
+use crate::handler::dml::flush_unique_dml;
+use crate::scheduler::{ExecutionContext, ExecutionContextRef, QueryId};
+use crate::session::{BufferedWrites, OptimizerContext, SessionImpl, Transaction};
+
+pub(super) async fn handle_begin(context: OptimizerContext) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    if session.in_transaction() {
+        return Ok(PgResponse::empty_result_with_notice(
+            StatementType::START_TRANSACTION,
+            "there is already a transaction in progress".to_string(),
+        ));
+    }
+    start_transaction(&session).await?;
+    Ok(PgResponse::empty_result(StatementType::START_TRANSACTION))
+}
+
+pub(super) async fn handle_commit(context: OptimizerContext, chain: bool) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let writes = match session.take_buffered_writes() {
+        Some(writes) => writes,
+        None => {
+            return Ok(PgResponse::empty_result_with_notice(
+                StatementType::COMMIT,
+                "there is no transaction in progress".to_string(),
+            ))
+        }
+    };
+
+    // Apply the writes while the snapshot is still pinned, and unpin it even if they fail.
+    let result = apply_writes(&session, writes).await;
+    end_transaction(&session).await?;
+    result?;
+
+    if chain {
+        start_transaction(&session).await?;
+    }
+    Ok(PgResponse::empty_result(StatementType::COMMIT))
+}
+
+pub(super) async fn handle_rollback(context: OptimizerContext, chain: bool) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    if !session.in_transaction() {
+        return Ok(PgResponse::empty_result_with_notice(
+            StatementType::ROLLBACK,
+            "there is no transaction in progress".to_string(),
+        ));
+    }
+    end_transaction(&session).await?;
+
+    if chain {
+        start_transaction(&session).await?;
+    }
+    Ok(PgResponse::empty_result(StatementType::ROLLBACK))
+}
+
+/// Apply the writes buffered by a transaction. A transaction writes to one table at most, and its
+/// writes are sent to the table source as one chunk, so that they are materialized in the same
+/// epoch. The writes to a table with unique indexes are serialized with the other DML on it, like
+/// an INSERT or UPDATE outside of a transaction.
+async fn apply_writes(
+    session: &Arc<SessionImpl>,
+    writes: HashMap<TableId, BufferedWrites>,
+) -> Result<()> {
+    let query_manager = session.env().query_manager().clone();
+    let mut unique_guard = None;
+    for (table_id, writes) in writes {
+        let changes = StreamChunk::from_rows(&writes.rows, &writes.data_types)?;
+        // The keys of the unique indexes refer to the columns without the row id.
+        let schema = Schema::new(
+            writes.data_types[1..]
+                .iter()
+                .cloned()
+                .map(Field::unnamed)
+                .collect(),
+        );
+        let plan = PlanNode {
+            children: vec![],
+            identity: "WriteChanges".to_string(),
+            node_body: Some(NodeBody::WriteChanges(WriteChangesNode {
+                table_source_ref_id: TableRefId {
+                    table_id: table_id.table_id() as i32,
+                    ..Default::default()
+                }
+                .into(),
+                changes: Some(changes.to_protobuf()),
+                unique_indexes: writes
+                    .unique_indexes
+                    .iter()
+                    .map(|index| index.to_protobuf(&schema))
+                    .collect(),
+            })),
+        };
+
+        if !writes.unique_indexes.is_empty() {
+            unique_guard = Some(session.env().lock_unique_dml(table_id).await);
+        }
+        let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
+        #[for_await]
+        for chunk in query_manager
+            .schedule_single(execution_context, plan)
+            .await?
+        {
+            chunk?;
+        }
+    }
+
+    if unique_guard.is_some() {
+        flush_unique_dml(session).await?;
+    } else if session.config().implicit_flush() {
+        session.env().meta_client().flush().await?;
+    }
+    Ok(())
+}
+
+/// Pin a snapshot for all reads in the new transaction.
+async fn start_transaction(session: &SessionImpl) -> Result<()> {
+    let id = QueryId::default();
+    let epoch = session
+        .env()
+        .hummock_snapshot_manager()
+        .get_epoch(id.clone())
+        .await?;
+    session.start_transaction(Transaction {
+        id,
+        epoch,
+        writes: HashMap::new(),
+    });
+    Ok(())
+}
+
+/// Discard the buffered writes of the transaction, if any are left, and unpin its snapshot.
+async fn end_transaction(session: &SessionImpl) -> Result<()> {
+    if let Some(txn) = session.end_transaction() {
+        session
+            .env()
+            .hummock_snapshot_manager()
+            .unpin_snapshot(txn.epoch, &txn.id)
+            .await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pgwire::pg_server::Session;
+    use risingwave_common::array::{Op, Row};
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_transaction_buffers_writes() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let session = frontend.session_ref();
+        let run_sql = |sql: &'static str| session.clone().run_statement(sql);
+
+        let write = |v: i32| {
+            session.buffer_writes(
+                TableId::new(1),
+                vec![DataType::Int64, DataType::Int32],
+                vec![(Op::Insert, Row(vec![None, Some(v.into())]))],
+                vec![],
+            )
+        };
+        // Writes can only be buffered in a transaction.
+        assert!(write(1).is_err());
+
+        run_sql("begin").await.unwrap();
+        assert!(session.in_transaction());
+        let epoch = session.txn_epoch();
+        assert!(epoch.is_some());
+
+        write(1).unwrap();
+        write(2).unwrap();
+        assert_eq!(session.txn_epoch(), epoch);
+
+        // `ROLLBACK` discards the writes.
+        run_sql("rollback").await.unwrap();
+        assert!(!session.in_transaction());
+        assert!(session.txn_epoch().is_none());
+        assert!(session.take_buffered_writes().is_none());
+
+        // `COMMIT` without writes only unpins the snapshot.
+        run_sql("begin").await.unwrap();
+        run_sql("commit").await.unwrap();
+        assert!(!session.in_transaction());
+        assert!(session.txn_epoch().is_none());
+    }
+}
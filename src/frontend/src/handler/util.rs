@@ -21,16 +21,25 @@ use risingwave_common::catalog::{ColumnDesc, Field};
 use risingwave_common::types::{DataType, ScalarRefImpl};
 
 /// Format scalars according to postgres convention.
-fn pg_value_format(d: ScalarRefImpl) -> String {
+///
+/// As in postgres, floats are printed in the shortest form that reads back to the same value when
+/// `extra_float_digits` is positive, and are otherwise rounded to `6 + extra_float_digits` or
+/// `15 + extra_float_digits` significant digits.
+fn pg_value_format(d: ScalarRefImpl, extra_float_digits: i32) -> String {
+    let precision = |digits: i32| match extra_float_digits > 0 {
+        true => None,
+        false => Some((digits + extra_float_digits) as usize),
+    };
     match d {
         ScalarRefImpl::Bool(b) => if b { "t" } else { "f" }.to_string(),
-        ScalarRefImpl::Float32(v) => pg_float_format(v),
-        ScalarRefImpl::Float64(v) => pg_float_format(v),
+        ScalarRefImpl::Float32(v) => pg_float_format(v, precision(6)),
+        ScalarRefImpl::Float64(v) => pg_float_format(v, precision(15)),
         _ => d.to_string(),
     }
 }
 
-fn pg_float_format<T: Float + ToString>(v: T) -> String {
+/// Format a float, rounded to `precision` significant digits if it's given.
+fn pg_float_format<T: Float + ToString>(v: T, precision: Option<usize>) -> String {
     if v.is_infinite() {
         if v.is_sign_positive() {
             "Infinity"
@@ -40,18 +49,22 @@ fn pg_float_format<T: Float + ToString>(v: T) -> String {
         .to_string()
     } else if v.is_nan() {
         "NaN".to_string()
+    } else if let Some(precision) = precision {
+        // Round in the decimal representation, then print the rounded value in the shortest form.
+        let rounded = format!("{:.*e}", precision.max(1) - 1, v.to_f64().unwrap());
+        rounded.parse::<f64>().unwrap().to_string()
     } else {
         v.to_string()
     }
 }
 
-pub fn to_pg_rows(chunk: DataChunk) -> Vec<Row> {
+pub fn to_pg_rows(chunk: DataChunk, extra_float_digits: i32) -> Vec<Row> {
     chunk
         .rows()
         .map(|r| {
             Row::new(
                 r.values()
-                    .map(|data| data.map(pg_value_format))
+                    .map(|data| data.map(|d| pg_value_format(d, extra_float_digits)))
                     .collect_vec(),
             )
         })
@@ -98,6 +111,9 @@ pub fn data_type_to_type_oid(data_type: DataType) -> TypeOid {
         DataType::Timestampz => TypeOid::Timestampz,
         DataType::Decimal => TypeOid::Decimal,
         DataType::Interval => TypeOid::Varchar,
+        DataType::Jsonb => TypeOid::Jsonb,
+        DataType::Bytea => TypeOid::Bytea,
+        DataType::Uuid => TypeOid::Uuid,
         DataType::Struct { .. } => TypeOid::Varchar,
         DataType::List { .. } => TypeOid::Varchar,
     }
@@ -129,7 +145,7 @@ mod tests {
              3 7 7.01 vvv
              4 . .    .  ",
         );
-        let rows = to_pg_rows(chunk);
+        let rows = to_pg_rows(chunk, 1);
         let expected = vec![
             vec![
                 Some("1".to_string()),
@@ -158,7 +174,7 @@ mod tests {
     fn test_value_format() {
         use ScalarRefImpl as S;
 
-        let f = pg_value_format;
+        let f = |d| pg_value_format(d, 1);
         assert_eq!(&f(S::Float32(1_f32.into())), "1");
         assert_eq!(&f(S::Float32(f32::NAN.into())), "NaN");
         assert_eq!(&f(S::Float64(f64::NAN.into())), "NaN");
@@ -168,5 +184,11 @@ mod tests {
         assert_eq!(&f(S::Float64(f64::NEG_INFINITY.into())), "-Infinity");
         assert_eq!(&f(S::Bool(true)), "t");
         assert_eq!(&f(S::Bool(false)), "f");
+
+        let v = S::Float64((0.1_f64 + 0.2).into());
+        assert_eq!(&pg_value_format(v, 1), "0.30000000000000004");
+        assert_eq!(&pg_value_format(v, 0), "0.3");
+        assert_eq!(&pg_value_format(S::Float32(6.01_f32.into()), 0), "6.01");
+        assert_eq!(&pg_value_format(S::Float64(1234.5678.into()), -12), "1230");
     }
 }
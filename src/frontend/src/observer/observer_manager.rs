@@ -137,6 +137,7 @@ impl ObserverManager {
                 Operation::Delete => {
                     catalog_guard.drop_table(table.database_id, table.schema_id, table.id.into())
                 }
+                Operation::Update => catalog_guard.update_table(table),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
             Info::Source(source) => match resp.operation() {
@@ -144,6 +145,7 @@ impl ObserverManager {
                 Operation::Delete => {
                     catalog_guard.drop_source(source.database_id, source.schema_id, source.id)
                 }
+                Operation::Update => catalog_guard.update_source(source),
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
             _ => unreachable!(),
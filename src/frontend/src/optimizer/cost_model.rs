@@ -0,0 +1,617 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A simple cost model estimating the output row count of plan nodes from the table statistics
+//! collected by `ANALYZE`. An estimation is `None` if any table below the node has never been
+//! analyzed, in which case callers should fall back to their rule-based decisions.
+//!
+//! Alternative plans are compared by a [`CostModel`], which differs between batch queries, where
+//! latency matters, and streaming queries, where the size of the states kept by operators does.
+
+use risingwave_common::catalog::Schema;
+use risingwave_common::types::{DataSize, DataType, Datum};
+use risingwave_pb::plan_common::JoinType;
+
+use super::plan_node::*;
+use super::plan_visitor::PlanVisitor;
+use crate::catalog::table_statistics::ColumnStatistics;
+use crate::expr::{ExprImpl, ExprType};
+use crate::session::OptimizerContextRef;
+use crate::utils::Condition;
+
+/// Selectivity of `column = <constant>` without column statistics.
+const DEFAULT_EQ_SELECTIVITY: f64 = 0.005;
+/// Selectivity of a range comparison without column statistics.
+const DEFAULT_RANGE_SELECTIVITY: f64 = 1.0 / 3.0;
+/// Selectivity of any other predicate.
+const DEFAULT_SELECTIVITY: f64 = 0.5;
+/// Fraction of input rows left after grouping, if the number of groups can't be derived.
+const DEFAULT_GROUP_FRACTION: f64 = 0.1;
+/// Assumed size in bytes of a value of a variable-length type.
+const DEFAULT_VARIABLE_WIDTH: f64 = 32.0;
+
+/// Cost of inserting a row into the hash table of a hash join, relative to probing it.
+const HASH_BUILD_COST: f64 = 2.0;
+/// Cost of sending a byte to another node, relative to processing a row.
+const NETWORK_COST_PER_BYTE: f64 = 0.01;
+/// The maximum estimated row count of the right side of a batch hash join to be broadcast.
+const BROADCAST_ROW_COUNT_THRESHOLD: f64 = 10000.0;
+/// Cost of evaluating the condition of a streaming nested loop join on a pair of rows, relative to
+/// keeping a byte in the state.
+const NESTED_LOOP_COST_PER_PAIR: f64 = 0.01;
+
+/// Estimate the number of rows `plan` outputs, or `None` if it can't be estimated.
+pub fn estimate_row_count(plan: PlanRef) -> Option<f64> {
+    RowCountEstimator.visit(plan)
+}
+
+/// Estimate the average size in bytes of a row of `schema`.
+pub fn estimate_row_width(schema: &Schema) -> f64 {
+    schema
+        .fields()
+        .iter()
+        .map(
+            |field| match (&field.data_type, field.data_type.data_size()) {
+                (DataType::Boolean, _) => 1.0,
+                (_, DataSize::Fixed(size)) => size as f64,
+                (_, DataSize::Variable) => DEFAULT_VARIABLE_WIDTH,
+            },
+        )
+        .sum()
+}
+
+/// Estimate the number of rows of a join between `left` and `right` rows, which is an equi-join
+/// if `equi`.
+pub fn estimate_join_row_count(left: f64, right: f64, equi: bool) -> f64 {
+    // Assume an equi-join is a foreign key join, so each row matches at most one row of the
+    // other side.
+    if equi {
+        left.max(right)
+    } else {
+        left * right * DEFAULT_SELECTIVITY
+    }
+}
+
+/// Estimated size of the output of a plan.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RelationSize {
+    pub row_count: f64,
+    /// Average size of a row in bytes.
+    pub row_width: f64,
+}
+
+impl RelationSize {
+    /// Estimate the output size of `plan`, or `None` if its row count can't be estimated.
+    pub fn of(plan: &PlanRef) -> Option<Self> {
+        Some(Self {
+            row_count: estimate_row_count(plan.clone())?,
+            row_width: plan.plan_base().row_width(),
+        })
+    }
+
+    pub fn bytes(&self) -> f64 {
+        self.row_count * self.row_width
+    }
+}
+
+/// Cost functions comparing alternative plans of a query. The costs are only comparable under
+/// the same model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CostModel {
+    /// Batch queries minimize latency, measured by the rows processed by each operator and the
+    /// bytes sent over the network, for a query running on `parallelism` partitions.
+    Batch { parallelism: usize },
+    /// Streaming queries minimize the size of the states kept by the operators, which are
+    /// maintained for as long as the materialized view exists, for a query running on
+    /// `parallelism` parallel units.
+    Stream { parallelism: usize },
+}
+
+/// The ways to join two streams.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamJoinStrategy {
+    /// Shuffle both sides by the join keys, and keep both of them in the state of the join.
+    Hash,
+    /// Look up the arrangements of both sides, which are shared with other queries if they are
+    /// indexes, so the join keeps no state of its own.
+    Delta,
+    /// Broadcast the right side to a nested loop join on every parallel unit, where each keeps a
+    /// copy of it and its share of the left side.
+    Broadcast,
+}
+
+impl CostModel {
+    /// The batch cost model for the queries planned in `ctx`.
+    pub fn batch(ctx: &OptimizerContextRef) -> Self {
+        let session = &ctx.inner().session_ctx;
+        let parallelism = match session.config().batch_parallelism() {
+            Some(parallelism) => parallelism as usize,
+            None => session.env().worker_node_manager().worker_node_count(),
+        };
+        Self::Batch {
+            parallelism: parallelism.max(1),
+        }
+    }
+
+    /// The streaming cost model for the queries planned in `ctx`.
+    pub fn stream(ctx: &OptimizerContextRef) -> Self {
+        let session = &ctx.inner().session_ctx;
+        let parallelism = match session.config().streaming_parallelism() {
+            Some(parallelism) => parallelism as usize,
+            None => session.env().worker_node_manager().worker_node_count(),
+        };
+        Self::Stream {
+            parallelism: parallelism.max(1),
+        }
+    }
+
+    /// Cost of a hash join between `left` and `right` producing `output`, excluding the costs of
+    /// the inputs.
+    pub fn join_cost(&self, left: RelationSize, right: RelationSize, output: RelationSize) -> f64 {
+        match self {
+            Self::Batch { .. } => {
+                // The hash table is built from the right side.
+                let cpu = left.row_count + right.row_count * HASH_BUILD_COST + output.row_count;
+                let network = self
+                    .shuffle_cost(left, right)
+                    .min(self.broadcast_cost(right));
+                cpu + network * NETWORK_COST_PER_BYTE
+            }
+            // Neither side is arranged before the join order is decided.
+            Self::Stream { .. } => {
+                let strategy = self.choose_stream_join(left, right, (false, false));
+                self.stream_join_cost(strategy, left, right, (false, false))
+            }
+        }
+    }
+
+    /// Choose the cheapest way to join the streams `left` and `right`. `arranged` tells whether
+    /// each side is already arranged by the join keys, e.g. by an index, without which a delta
+    /// join is not considered.
+    pub fn choose_stream_join(
+        &self,
+        left: RelationSize,
+        right: RelationSize,
+        arranged: (bool, bool),
+    ) -> StreamJoinStrategy {
+        let mut strategies = vec![StreamJoinStrategy::Hash, StreamJoinStrategy::Broadcast];
+        if arranged.0 && arranged.1 {
+            strategies.push(StreamJoinStrategy::Delta);
+        }
+        // Take the first of the cheapest, so a hash join is kept on ties.
+        strategies
+            .into_iter()
+            .map(|strategy| {
+                (
+                    strategy,
+                    self.stream_join_cost(strategy, left, right, arranged),
+                )
+            })
+            .reduce(|best, next| if next.1 < best.1 { next } else { best })
+            .unwrap()
+            .0
+    }
+
+    /// Cost of joining the streams `left` and `right` with `strategy`, excluding the costs of the
+    /// inputs. It's the size of the states kept for the join, plus the bytes sent over the network
+    /// and the pairs of rows checked by a nested loop join for each change of the inputs.
+    pub fn stream_join_cost(
+        &self,
+        strategy: StreamJoinStrategy,
+        left: RelationSize,
+        right: RelationSize,
+        arranged: (bool, bool),
+    ) -> f64 {
+        let parallelism = match self {
+            Self::Stream { parallelism } => *parallelism as f64,
+            Self::Batch { .. } => unreachable!("stream join cost under the batch cost model"),
+        };
+        let (state, network, pairs) = match strategy {
+            StreamJoinStrategy::Hash => (
+                left.bytes() + right.bytes(),
+                self.shuffle_cost(left, right),
+                0.0,
+            ),
+            // The sides not arranged yet have to be arranged for the join.
+            StreamJoinStrategy::Delta => {
+                let arrangement = |size: RelationSize, arranged: bool| {
+                    if arranged {
+                        0.0
+                    } else {
+                        size.bytes()
+                    }
+                };
+                (
+                    arrangement(left, arranged.0) + arrangement(right, arranged.1),
+                    self.shuffle_cost(left, right),
+                    0.0,
+                )
+            }
+            StreamJoinStrategy::Broadcast => (
+                left.bytes() + right.bytes() * parallelism,
+                self.broadcast_cost(right),
+                left.row_count * right.row_count,
+            ),
+        };
+        state + network * NETWORK_COST_PER_BYTE + pairs * NESTED_LOOP_COST_PER_PAIR
+    }
+
+    /// Whether to broadcast the right side of a batch hash join, instead of shuffling both sides
+    /// by the join keys. `left` is `None` if its size is unknown, in which case it's assumed to be
+    /// large.
+    pub fn should_broadcast_right(&self, left: Option<RelationSize>, right: RelationSize) -> bool {
+        match self {
+            Self::Batch { .. } => {
+                right.row_count <= BROADCAST_ROW_COUNT_THRESHOLD
+                    && left.map_or(true, |left| {
+                        self.broadcast_cost(right) < self.shuffle_cost(left, right)
+                    })
+            }
+            // A streaming hash join keeps its state in tables shared by all the parallel units,
+            // so its inputs can't be broadcast.
+            Self::Stream { .. } => false,
+        }
+    }
+
+    /// Bytes sent to shuffle both sides of a join by the join keys.
+    fn shuffle_cost(&self, left: RelationSize, right: RelationSize) -> f64 {
+        left.bytes() + right.bytes()
+    }
+
+    /// Bytes sent to broadcast `right` to every partition.
+    fn broadcast_cost(&self, right: RelationSize) -> f64 {
+        match self {
+            Self::Batch { parallelism } | Self::Stream { parallelism } => {
+                right.bytes() * *parallelism as f64
+            }
+        }
+    }
+}
+
+struct RowCountEstimator;
+
+impl RowCountEstimator {
+    fn filter(&mut self, input: PlanRef, predicate: &Condition) -> Option<f64> {
+        let row_count = self.visit(input.clone())?;
+        Some(row_count * selectivity(&input, predicate))
+    }
+
+    fn join(
+        &mut self,
+        join_type: JoinType,
+        left: PlanRef,
+        right: PlanRef,
+        equi: bool,
+    ) -> Option<f64> {
+        let left = self.visit(left)?;
+        let right = self.visit(right)?;
+        let inner = estimate_join_row_count(left, right, equi);
+        Some(match join_type {
+            JoinType::Inner => inner,
+            JoinType::LeftOuter => inner.max(left),
+            JoinType::RightOuter => inner.max(right),
+            JoinType::FullOuter => inner.max(left).max(right),
+            JoinType::LeftSemi | JoinType::LeftAnti => left,
+            JoinType::RightSemi | JoinType::RightAnti => right,
+        })
+    }
+
+    fn agg(&mut self, input: PlanRef, group_keys: &[usize]) -> Option<f64> {
+        if group_keys.is_empty() {
+            return Some(1.0);
+        }
+        let row_count = self.visit(input.clone())?;
+        let groups = match group_keys {
+            [key] => column_statistics(&input, *key).map(|stats| stats.ndv as f64 + 1.0),
+            _ => None,
+        };
+        Some(
+            groups
+                .unwrap_or(row_count * DEFAULT_GROUP_FRACTION)
+                .min(row_count),
+        )
+    }
+}
+
+impl PlanVisitor<Option<f64>> for RowCountEstimator {
+    fn visit_logical_scan(&mut self, plan: &LogicalScan) -> Option<f64> {
+        plan.statistics().map(|stats| stats.row_count as f64)
+    }
+
+    fn visit_batch_seq_scan(&mut self, plan: &BatchSeqScan) -> Option<f64> {
+        self.visit_logical_scan(plan.logical())
+    }
+
+    fn visit_logical_values(&mut self, plan: &LogicalValues) -> Option<f64> {
+        Some(plan.rows().len() as f64)
+    }
+
+    fn visit_batch_values(&mut self, plan: &BatchValues) -> Option<f64> {
+        self.visit_logical_values(plan.logical())
+    }
+
+    fn visit_logical_filter(&mut self, plan: &LogicalFilter) -> Option<f64> {
+        self.filter(plan.input(), plan.predicate())
+    }
+
+    fn visit_batch_filter(&mut self, plan: &BatchFilter) -> Option<f64> {
+        self.filter(plan.input(), plan.predicate())
+    }
+
+    fn visit_logical_join(&mut self, plan: &LogicalJoin) -> Option<f64> {
+        let equi = EqJoinPredicate::create(
+            plan.left().schema().len(),
+            plan.right().schema().len(),
+            plan.on().clone(),
+        )
+        .has_eq();
+        self.join(plan.join_type(), plan.left(), plan.right(), equi)
+    }
+
+    fn visit_batch_hash_join(&mut self, plan: &BatchHashJoin) -> Option<f64> {
+        self.join(plan.logical().join_type(), plan.left(), plan.right(), true)
+    }
+
+    fn visit_batch_nested_loop_join(&mut self, plan: &BatchNestedLoopJoin) -> Option<f64> {
+        self.join(plan.logical().join_type(), plan.left(), plan.right(), false)
+    }
+
+    fn visit_logical_agg(&mut self, plan: &LogicalAgg) -> Option<f64> {
+        self.agg(plan.input(), plan.group_keys())
+    }
+
+    fn visit_batch_hash_agg(&mut self, plan: &BatchHashAgg) -> Option<f64> {
+        self.agg(plan.input(), plan.group_keys())
+    }
+
+    fn visit_batch_simple_agg(&mut self, _plan: &BatchSimpleAgg) -> Option<f64> {
+        Some(1.0)
+    }
+
+    fn visit_logical_union(&mut self, plan: &LogicalUnion) -> Option<f64> {
+        plan.inputs()
+            .into_iter()
+            .map(|input| self.visit(input))
+            .sum()
+    }
+
+    fn visit_batch_union(&mut self, plan: &BatchUnion) -> Option<f64> {
+        plan.inputs()
+            .into_iter()
+            .map(|input| self.visit(input))
+            .sum()
+    }
+
+    fn visit_logical_limit(&mut self, plan: &LogicalLimit) -> Option<f64> {
+        let row_count = self.visit(plan.input())?;
+        Some(row_count.min(plan.limit() as f64))
+    }
+
+    fn visit_logical_top_n(&mut self, plan: &LogicalTopN) -> Option<f64> {
+        let row_count = self.visit(plan.input())?;
+        if plan.group_key().is_empty() {
+            Some(row_count.min(plan.limit() as f64))
+        } else {
+            // The limit applies to each group.
+            Some(row_count)
+        }
+    }
+}
+
+/// Get the statistics of the `col_idx`-th output column of `plan` if it is a table scan.
+fn column_statistics(plan: &PlanRef, col_idx: usize) -> Option<&ColumnStatistics> {
+    if let Some(scan) = plan.as_logical_scan() {
+        scan.column_statistics(col_idx)
+    } else if let Some(scan) = plan.as_batch_seq_scan() {
+        scan.logical().column_statistics(col_idx)
+    } else {
+        None
+    }
+}
+
+/// Estimate the fraction of rows of `input` satisfying `predicate`, assuming the conjunctions are
+/// independent.
+fn selectivity(input: &PlanRef, predicate: &Condition) -> f64 {
+    predicate
+        .conjunctions
+        .iter()
+        .map(|expr| conjunction_selectivity(input, expr))
+        .product()
+}
+
+fn conjunction_selectivity(input: &PlanRef, expr: &ExprImpl) -> f64 {
+    let func_call = match expr {
+        ExprImpl::FunctionCall(func_call) => func_call,
+        _ => return DEFAULT_SELECTIVITY,
+    };
+    match (func_call.get_expr_type(), func_call.inputs()) {
+        (ExprType::IsNull, [ExprImpl::InputRef(input_ref)]) => {
+            column_statistics(input, input_ref.index())
+                .map_or(DEFAULT_EQ_SELECTIVITY, |stats| stats.null_fraction)
+        }
+        (ExprType::IsNotNull, [ExprImpl::InputRef(input_ref)]) => {
+            column_statistics(input, input_ref.index())
+                .map_or(1.0 - DEFAULT_EQ_SELECTIVITY, |stats| {
+                    1.0 - stats.null_fraction
+                })
+        }
+        (expr_type, [ExprImpl::InputRef(input_ref), ExprImpl::Literal(literal)]) => {
+            comparison_selectivity(input, expr_type, input_ref.index(), literal.get_data())
+        }
+        (expr_type, [ExprImpl::Literal(literal), ExprImpl::InputRef(input_ref)]) => {
+            let expr_type = match expr_type {
+                ExprType::LessThan => ExprType::GreaterThan,
+                ExprType::LessThanOrEqual => ExprType::GreaterThanOrEqual,
+                ExprType::GreaterThan => ExprType::LessThan,
+                ExprType::GreaterThanOrEqual => ExprType::LessThanOrEqual,
+                expr_type => expr_type,
+            };
+            comparison_selectivity(input, expr_type, input_ref.index(), literal.get_data())
+        }
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+/// Estimate the selectivity of `column <op> value`.
+fn comparison_selectivity(
+    input: &PlanRef,
+    expr_type: ExprType,
+    col_idx: usize,
+    value: &Datum,
+) -> f64 {
+    let value = match value {
+        Some(value) => value,
+        // Comparing with null never evaluates to true.
+        None => return 0.0,
+    };
+    let stats = column_statistics(input, col_idx);
+    let eq = stats.map_or(DEFAULT_EQ_SELECTIVITY, ColumnStatistics::eq_selectivity);
+    let non_null = stats.map_or(1.0, |stats| 1.0 - stats.null_fraction);
+    let lt = stats.and_then(|stats| stats.lt_selectivity(value));
+    match (expr_type, lt) {
+        (ExprType::Equal, _) => eq,
+        (ExprType::NotEqual, _) => (non_null - eq).max(0.0),
+        (ExprType::LessThan, Some(lt)) => lt,
+        (ExprType::LessThanOrEqual, Some(lt)) => (lt + eq).min(non_null),
+        (ExprType::GreaterThan, Some(lt)) => (non_null - lt - eq).max(0.0),
+        (ExprType::GreaterThanOrEqual, Some(lt)) => (non_null - lt).max(0.0),
+        (
+            ExprType::LessThan
+            | ExprType::LessThanOrEqual
+            | ExprType::GreaterThan
+            | ExprType::GreaterThanOrEqual,
+            None,
+        ) => DEFAULT_RANGE_SELECTIVITY,
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    use risingwave_common::catalog::{ColumnDesc, TableDesc};
+    use risingwave_common::types::{DataType, ScalarImpl};
+
+    use super::*;
+    use crate::catalog::table_statistics::TableStatistics;
+    use crate::catalog::ColumnId;
+    use crate::expr::{FunctionCall, InputRef, Literal};
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    async fn test_estimate_filter_row_count() {
+        let ctx = OptimizerContext::mock().await;
+        let table_desc = TableDesc {
+            columns: vec![ColumnDesc::unnamed(ColumnId::new(1), DataType::Int32)],
+            ..Default::default()
+        };
+        let statistics = TableStatistics {
+            row_count: 1000,
+            columns: HashMap::from([(
+                ColumnId::new(1),
+                ColumnStatistics {
+                    null_fraction: 0.0,
+                    ndv: 100,
+                    histogram_bounds: (0..=4).map(|i| ScalarImpl::Int32(i * 25)).collect(),
+                },
+            )]),
+        };
+        let scan: PlanRef = LogicalScan::create(
+            "t".to_string(),
+            Rc::new(table_desc),
+            vec![],
+            Some(Rc::new(statistics)),
+            ctx,
+        )
+        .unwrap();
+        assert_eq!(estimate_row_count(scan.clone()), Some(1000.0));
+
+        let filter = |expr_type, value| {
+            let cond = FunctionCall::new(
+                expr_type,
+                vec![
+                    InputRef::new(0, DataType::Int32).into(),
+                    Literal::new(Some(ScalarImpl::Int32(value)), DataType::Int32).into(),
+                ],
+            )
+            .unwrap();
+            LogicalFilter::create_with_expr(scan.clone(), cond.into())
+        };
+        assert_eq!(
+            estimate_row_count(filter(ExprType::Equal, 30)).map(f64::round),
+            Some(10.0)
+        );
+        assert_eq!(
+            estimate_row_count(filter(ExprType::LessThan, 30)).map(f64::round),
+            Some(375.0)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_parallelism() {
+        let ctx = OptimizerContext::mock().await;
+        // The mock cluster has no worker nodes.
+        assert_eq!(
+            CostModel::stream(&ctx),
+            CostModel::Stream { parallelism: 1 }
+        );
+        ctx.inner()
+            .session_ctx
+            .set_config("streaming_parallelism", "4")
+            .unwrap();
+        assert_eq!(
+            CostModel::stream(&ctx),
+            CostModel::Stream { parallelism: 4 }
+        );
+    }
+
+    #[test]
+    fn test_choose_stream_join() {
+        let cost_model = CostModel::Stream { parallelism: 4 };
+        let size = |row_count, row_width| RelationSize {
+            row_count,
+            row_width,
+        };
+        let large = size(1000.0, 16.0);
+        let single = size(1.0, 8.0);
+
+        // Broadcasting a large side multiplies its state by the parallelism.
+        assert_eq!(
+            cost_model.choose_stream_join(large, large, (false, false)),
+            StreamJoinStrategy::Hash
+        );
+        // Broadcasting a single row keeps the state of the join smaller than a hash join.
+        assert_eq!(
+            cost_model.choose_stream_join(large, single, (false, false)),
+            StreamJoinStrategy::Broadcast
+        );
+        // A delta join keeps no state when both sides are arranged.
+        assert_eq!(
+            cost_model.choose_stream_join(large, large, (true, true)),
+            StreamJoinStrategy::Delta
+        );
+        assert_eq!(
+            cost_model.choose_stream_join(large, single, (true, true)),
+            StreamJoinStrategy::Delta
+        );
+        // The arrangement of a side not arranged yet adds to the state of a delta join.
+        let delta_cost = |arranged| {
+            cost_model.stream_join_cost(StreamJoinStrategy::Delta, large, large, arranged)
+        };
+        assert!(delta_cost((true, true)) < delta_cost((true, false)));
+        assert!(delta_cost((true, false)) < delta_cost((false, false)));
+    }
+}
@@ -16,6 +16,7 @@ pub mod plan_node;
 pub use plan_node::PlanRef;
 pub mod property;
 
+mod cost_model;
 mod delta_join_solver;
 mod heuristic;
 mod plan_rewriter;
@@ -28,6 +29,7 @@ use property::Order;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 
+use self::cost_model::CostModel;
 use self::heuristic::{ApplyOrder, HeuristicOptimizer};
 use self::plan_node::{BatchProject, Convention, LogicalProject, StreamMaterialize};
 use self::property::RequiredDist;
@@ -110,8 +112,17 @@ impl PlanRoot {
         LogicalProject::create(self.plan, exprs)
     }
 
-    /// Apply logical optimization to the plan.
+    /// Apply logical optimization to the plan, with joins reordered for batch execution.
     pub fn gen_optimized_logical_plan(&self) -> PlanRef {
+        self.gen_optimized_logical_plan_with_cost_model(CostModel::batch(&self.plan.ctx()))
+    }
+
+    /// Apply logical optimization to the plan, with joins reordered for streaming execution.
+    pub fn gen_optimized_logical_plan_for_stream(&self) -> PlanRef {
+        self.gen_optimized_logical_plan_with_cost_model(CostModel::stream(&self.plan.ctx()))
+    }
+
+    fn gen_optimized_logical_plan_with_cost_model(&self, cost_model: CostModel) -> PlanRef {
         let mut plan = self.plan.clone();
 
         // Subquery Unnesting.
@@ -120,6 +131,8 @@ impl PlanRoot {
                 // This rule should be applied first to pull up LogicalAgg.
                 UnnestAggForLOJ::create(),
                 PullUpCorrelatedPredicate::create(),
+                PullUpCorrelatedProject::create(),
+                PullUpCorrelatedTableFunction::create(),
             ];
             let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::TopDown, rules);
             heuristic_optimizer.optimize(plan)
@@ -137,9 +150,9 @@ impl PlanRoot {
             heuristic_optimizer.optimize(plan)
         };
 
-        // Reorder multijoin into left-deep join tree.
+        // Reorder multijoin into a join tree, by cost if the sizes of all inputs can be estimated.
         plan = {
-            let rules = vec![ReorderMultiJoinRule::create()];
+            let rules = vec![ReorderMultiJoinRule::create(cost_model)];
             let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::TopDown, rules);
             heuristic_optimizer.optimize(plan)
         };
@@ -167,6 +180,15 @@ impl PlanRoot {
             heuristic_optimizer.optimize(plan)
         };
 
+        // Convert the ranking window functions filtered by constants into group Top-N. This
+        // should be applied after the projects are merged, so that it can tell whether the rank
+        // is used above the filter.
+        plan = {
+            let rules = vec![OverAggToTopNRule::create()];
+            let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::TopDown, rules);
+            heuristic_optimizer.optimize(plan)
+        };
+
         plan
     }
 
@@ -224,7 +246,7 @@ impl PlanRoot {
     fn gen_stream_plan(&mut self) -> Result<PlanRef> {
         let plan = match self.plan.convention() {
             Convention::Logical => {
-                let plan = self.gen_optimized_logical_plan();
+                let plan = self.gen_optimized_logical_plan_for_stream();
                 let (plan, out_col_change) = plan.logical_rewrite_for_stream()?;
                 self.required_dist =
                     out_col_change.rewrite_required_distribution(&self.required_dist);
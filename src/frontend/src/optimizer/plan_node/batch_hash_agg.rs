@@ -42,6 +42,8 @@ impl BatchHashAgg {
                 .i2o_col_mapping()
                 .rewrite_provided_distribution(input_dist),
             Distribution::SomeShard => Distribution::SomeShard,
+            // Every instance aggregates all the rows, so the results are the same.
+            Distribution::Broadcast => Distribution::Broadcast,
         };
         let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
         BatchHashAgg { base, logical }
@@ -17,12 +17,14 @@ use std::fmt;
 use risingwave_common::error::Result;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::HashJoinNode;
+use risingwave_pb::plan_common::JoinType;
 
 use super::{
     EqJoinPredicate, LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, ToBatchProst,
     ToDistributedBatch,
 };
 use crate::expr::Expr;
+use crate::optimizer::cost_model::{CostModel, RelationSize};
 use crate::optimizer::plan_node::ToLocalBatch;
 use crate::optimizer::property::{Distribution, Order, RequiredDist};
 use crate::utils::ColIndexMapping;
@@ -69,10 +71,33 @@ impl BatchHashJoin {
             (Distribution::HashShard(_), Distribution::HashShard(_)) => {
                 l2o_mapping.rewrite_provided_distribution(left)
             }
+            // Each partition of the left side joins with the full right side.
+            (_, Distribution::Broadcast) => l2o_mapping.rewrite_provided_distribution(left),
             (_, _) => unreachable!(),
         }
     }
 
+    /// Whether to broadcast the right side to every partition of the left side instead of
+    /// shuffling both sides by the join keys, which is cheaper if the right side is known to be
+    /// small. Join types emitting unmatched right rows can't be executed this way, as each
+    /// partition only sees part of the left rows.
+    fn should_broadcast_right(&self) -> bool {
+        let join_type_allowed = matches!(
+            self.logical.join_type(),
+            JoinType::Inner | JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti
+        );
+        join_type_allowed
+            && RelationSize::of(&self.right()).map_or(false, |right| {
+                CostModel::batch(&self.base.ctx)
+                    .should_broadcast_right(RelationSize::of(&self.left()), right)
+            })
+    }
+
+    /// Get a reference to the batch hash join's logical.
+    pub fn logical(&self) -> &LogicalJoin {
+        &self.logical
+    }
+
     /// Get a reference to the batch hash join's eq join predicate.
     pub fn eq_join_predicate(&self) -> &EqJoinPredicate {
         &self.eq_join_predicate
@@ -111,6 +136,17 @@ impl_plan_tree_node_for_binary! { BatchHashJoin }
 
 impl ToDistributedBatch for BatchHashJoin {
     fn to_distributed(&self) -> Result<PlanRef> {
+        if self.should_broadcast_right() {
+            let right = self.right().to_distributed_with_required(
+                Order::any(),
+                &RequiredDist::PhysicalDist(Distribution::Broadcast),
+            )?;
+            let left = self
+                .left()
+                .to_distributed_with_required(Order::any(), &RequiredDist::Any)?;
+            return Ok(self.clone_with_left_right(left, right).into());
+        }
+
         let right = self.right().to_distributed_with_required(
             Order::any(),
             &RequiredDist::shard_by_key(
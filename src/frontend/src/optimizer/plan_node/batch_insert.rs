@@ -78,6 +78,12 @@ impl ToBatchProst for BatchInsert {
             }
             .into(),
             column_ids: vec![], // unused
+            unique_indexes: self
+                .logical
+                .unique_indexes()
+                .iter()
+                .map(|index| index.to_protobuf(self.input().schema()))
+                .collect(),
         })
     }
 }
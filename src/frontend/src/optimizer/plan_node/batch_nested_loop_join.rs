@@ -48,6 +48,11 @@ impl BatchNestedLoopJoin {
             (_, _) => unreachable!(),
         }
     }
+
+    /// Get a reference to the batch nested loop join's logical.
+    pub fn logical(&self) -> &LogicalJoin {
+        &self.logical
+    }
 }
 
 impl fmt::Display for BatchNestedLoopJoin {
@@ -0,0 +1,109 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::RecursiveUnionNode;
+
+use super::{
+    LogicalRecursiveUnion, PlanBase, PlanRef, PlanTreeNodeBinary, ToBatchProst, ToDistributedBatch,
+};
+use crate::optimizer::plan_node::ToLocalBatch;
+use crate::optimizer::property::{Distribution, Order, RequiredDist};
+
+/// `BatchRecursiveUnion` implements [`LogicalRecursiveUnion`] in a single task, where the
+/// recursive term is evaluated in each iteration over the work table held by the task.
+#[derive(Debug, Clone)]
+pub struct BatchRecursiveUnion {
+    pub base: PlanBase,
+    logical: LogicalRecursiveUnion,
+}
+
+impl BatchRecursiveUnion {
+    pub fn new(logical: LogicalRecursiveUnion) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let base = PlanBase::new_batch(
+            ctx,
+            logical.schema().clone(),
+            Distribution::Single,
+            Order::any().clone(),
+        );
+        BatchRecursiveUnion { base, logical }
+    }
+
+    /// The recursive term is kept in the same task as the recursive union, as it reads the work
+    /// table from the task. So no exchange is inserted into it.
+    fn with_non_recursive(&self, non_recursive: PlanRef) -> PlanRef {
+        self.clone_with_left_right(non_recursive, self.right())
+            .into()
+    }
+}
+
+impl fmt::Display for BatchRecursiveUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BatchRecursiveUnion {{ all: {}, work_table_id: {} }}",
+            self.logical.all(),
+            self.logical.work_table_id()
+        )
+    }
+}
+
+impl PlanTreeNodeBinary for BatchRecursiveUnion {
+    fn left(&self) -> PlanRef {
+        self.logical.left()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_left_right(left, right))
+    }
+}
+
+impl_plan_tree_node_for_binary! { BatchRecursiveUnion }
+
+impl ToDistributedBatch for BatchRecursiveUnion {
+    fn to_distributed(&self) -> Result<PlanRef> {
+        let non_recursive = self
+            .left()
+            .to_distributed_with_required(Order::any(), &RequiredDist::single())?;
+        Ok(self.with_non_recursive(non_recursive))
+    }
+}
+
+impl ToBatchProst for BatchRecursiveUnion {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        let config = self.base.ctx.inner().session_ctx.config();
+        NodeBody::RecursiveUnion(RecursiveUnionNode {
+            all: self.logical.all(),
+            work_table_id: self.logical.work_table_id(),
+            max_iterations: config.max_recursive_iterations(),
+            max_memory_bytes: config.max_recursive_memory_bytes(),
+        })
+    }
+}
+
+impl ToLocalBatch for BatchRecursiveUnion {
+    fn to_local(&self) -> Result<PlanRef> {
+        let non_recursive = RequiredDist::single()
+            .enforce_if_not_satisfies(self.left().to_local()?, Order::any())?;
+        Ok(self.with_non_recursive(non_recursive))
+    }
+}
@@ -13,38 +13,57 @@
 // limitations under the License.
 
 use std::fmt;
+use std::ops::Bound;
 
+use itertools::Itertools;
 use risingwave_common::error::Result;
+use risingwave_common::types::ScalarImpl;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::RowSeqScanNode;
 use risingwave_pb::plan_common::{CellBasedTableDesc, ColumnDesc as ProstColumnDesc};
 
 use super::{PlanBase, PlanRef, ToBatchProst, ToDistributedBatch};
+use crate::expr::Literal;
 use crate::optimizer::plan_node::{LogicalScan, ToLocalBatch};
 use crate::optimizer::property::{Distribution, Order};
+use crate::utils::ScanRange;
 
 /// `BatchSeqScan` implements [`super::LogicalScan`] to scan from a row-oriented table
 #[derive(Debug, Clone)]
 pub struct BatchSeqScan {
     pub base: PlanBase,
     logical: LogicalScan,
+    /// The ranges of the order key of the table to scan. The whole table is scanned if empty.
+    scan_ranges: Vec<ScanRange>,
 }
 
 impl BatchSeqScan {
-    pub fn new_inner(logical: LogicalScan, dist: Distribution) -> Self {
+    pub fn new_inner(
+        logical: LogicalScan,
+        dist: Distribution,
+        scan_ranges: Vec<ScanRange>,
+    ) -> Self {
         let ctx = logical.base.ctx.clone();
         // TODO: derive from input
         let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
 
-        Self { base, logical }
+        Self {
+            base,
+            logical,
+            scan_ranges,
+        }
     }
 
     pub fn new(logical: LogicalScan) -> Self {
-        Self::new_inner(logical, Distribution::Single)
+        Self::new_with_scan_ranges(logical, vec![])
     }
 
-    pub fn with_dist(logical: LogicalScan) -> Self {
-        Self::new_inner(logical, Distribution::SomeShard)
+    pub fn new_with_scan_ranges(logical: LogicalScan, scan_ranges: Vec<ScanRange>) -> Self {
+        Self::new_inner(logical, Distribution::Single, scan_ranges)
+    }
+
+    pub fn with_dist(logical: LogicalScan, scan_ranges: Vec<ScanRange>) -> Self {
+        Self::new_inner(logical, Distribution::SomeShard, scan_ranges)
     }
 
     /// Get a reference to the batch seq scan's logical.
@@ -52,24 +71,73 @@ impl BatchSeqScan {
     pub fn logical(&self) -> &LogicalScan {
         &self.logical
     }
+
+    /// Get a reference to the batch seq scan's scan ranges.
+    #[must_use]
+    pub fn scan_ranges(&self) -> &[ScanRange] {
+        &self.scan_ranges
+    }
+
+    /// Formats a scan range like `v1 = 1:Int32 AND v2 > 2:Int32` with the order key columns.
+    fn fmt_scan_range(&self, scan_range: &ScanRange) -> String {
+        let order_key = &self.logical.table_desc().order_desc;
+        let fmt_cond = |idx: usize, op: &str, value: &ScalarImpl| {
+            let column = &order_key[idx].column_desc;
+            let literal = Literal::new(Some(value.clone()), column.data_type.clone());
+            format!("{} {} {:?}", column.name, op, literal)
+        };
+
+        let mut conds = scan_range
+            .eq_conds
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| fmt_cond(idx, "=", value))
+            .collect_vec();
+        let idx = scan_range.eq_conds.len();
+        match &scan_range.range.0 {
+            Bound::Included(value) => conds.push(fmt_cond(idx, ">=", value)),
+            Bound::Excluded(value) => conds.push(fmt_cond(idx, ">", value)),
+            Bound::Unbounded => {}
+        }
+        match &scan_range.range.1 {
+            Bound::Included(value) => conds.push(fmt_cond(idx, "<=", value)),
+            Bound::Excluded(value) => conds.push(fmt_cond(idx, "<", value)),
+            Bound::Unbounded => {}
+        }
+
+        conds.join(" AND ")
+    }
 }
 
 impl_plan_tree_node_for_leaf! { BatchSeqScan }
 
 impl fmt::Display for BatchSeqScan {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "BatchScan {{ table: {}, columns: [{}] }}",
-            self.logical.table_name(),
-            self.logical.column_names().join(", ")
-        )
+        if self.scan_ranges.is_empty() {
+            write!(
+                f,
+                "BatchScan {{ table: {}, columns: [{}] }}",
+                self.logical.table_name(),
+                self.logical.column_names().join(", ")
+            )
+        } else {
+            write!(
+                f,
+                "BatchScan {{ table: {}, columns: [{}], scan_ranges: [{}] }}",
+                self.logical.table_name(),
+                self.logical.column_names().join(", "),
+                self.scan_ranges
+                    .iter()
+                    .map(|scan_range| self.fmt_scan_range(scan_range))
+                    .join(", ")
+            )
+        }
     }
 }
 
 impl ToDistributedBatch for BatchSeqScan {
     fn to_distributed(&self) -> Result<PlanRef> {
-        Ok(Self::with_dist(self.logical.clone()).into())
+        Ok(Self::with_dist(self.logical.clone(), self.scan_ranges.clone()).into())
     }
 }
 
@@ -85,15 +153,26 @@ impl ToBatchProst for BatchSeqScan {
         NodeBody::RowSeqScan(RowSeqScanNode {
             table_desc: Some(CellBasedTableDesc {
                 table_id: self.logical.table_desc().table_id.into(),
-                pk: vec![], // TODO:
+                pk: self
+                    .logical
+                    .table_desc()
+                    .order_desc
+                    .iter()
+                    .map(Into::into)
+                    .collect(),
             }),
             column_descs,
+            scan_ranges: self
+                .scan_ranges
+                .iter()
+                .map(ScanRange::to_protobuf)
+                .collect(),
         })
     }
 }
 
 impl ToLocalBatch for BatchSeqScan {
     fn to_local(&self) -> Result<PlanRef> {
-        Ok(Self::with_dist(self.logical.clone()).into())
+        Ok(Self::with_dist(self.logical.clone(), self.scan_ranges.clone()).into())
     }
 }
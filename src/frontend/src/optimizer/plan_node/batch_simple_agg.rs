@@ -36,6 +36,8 @@ impl BatchSimpleAgg {
         let input_dist = input.distribution();
         match input_dist {
             Distribution::Single | Distribution::SomeShard | Distribution::HashShard(_) => {}
+            // Every instance aggregates all the rows, so the results are the same.
+            Distribution::Broadcast => {}
         };
         let base = PlanBase::new_batch(
             ctx,
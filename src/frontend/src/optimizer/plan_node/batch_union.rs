@@ -0,0 +1,95 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::UnionNode;
+
+use super::{LogicalUnion, PlanBase, PlanRef, PlanTreeNode, ToBatchProst, ToDistributedBatch};
+use crate::optimizer::plan_node::ToLocalBatch;
+use crate::optimizer::property::{Distribution, Order, RequiredDist};
+
+/// `BatchUnion` implements [`super::LogicalUnion`] by returning the rows of its inputs one after
+/// another.
+#[derive(Debug, Clone)]
+pub struct BatchUnion {
+    pub base: PlanBase,
+    logical: LogicalUnion,
+}
+
+impl BatchUnion {
+    pub fn new(logical: LogicalUnion) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let inputs = logical.inputs();
+        let dist = match inputs.iter().map(|input| input.distribution()).all_equal() {
+            true => inputs[0].distribution().clone(),
+            false => Distribution::SomeShard,
+        };
+        let base = PlanBase::new_batch(ctx, logical.schema().clone(), dist, Order::any().clone());
+        BatchUnion { base, logical }
+    }
+}
+
+impl fmt::Display for BatchUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BatchUnion {{ all: true }}")
+    }
+}
+
+impl PlanTreeNode for BatchUnion {
+    fn inputs(&self) -> smallvec::SmallVec<[crate::optimizer::PlanRef; 2]> {
+        self.logical.inputs()
+    }
+
+    fn clone_with_inputs(&self, inputs: &[crate::optimizer::PlanRef]) -> crate::optimizer::PlanRef {
+        Self::new(self.logical.clone_with_input_list(inputs.to_vec())).into()
+    }
+}
+
+impl ToDistributedBatch for BatchUnion {
+    fn to_distributed(&self) -> Result<PlanRef> {
+        // The inputs are shuffled by all the columns, so that the same rows are in the same
+        // partition, which is likely to be required by the aggregation of a distinct union.
+        let dist =
+            RequiredDist::PhysicalDist(Distribution::HashShard((0..self.schema().len()).collect()));
+        let inputs = self
+            .inputs()
+            .into_iter()
+            .map(|input| input.to_distributed_with_required(Order::any(), &dist))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.clone_with_inputs(&inputs))
+    }
+}
+
+impl ToBatchProst for BatchUnion {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::Union(UnionNode {})
+    }
+}
+
+impl ToLocalBatch for BatchUnion {
+    fn to_local(&self) -> Result<PlanRef> {
+        let inputs = self
+            .inputs()
+            .into_iter()
+            .map(|input| {
+                RequiredDist::single().enforce_if_not_satisfies(input.to_local()?, Order::any())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(self.clone_with_inputs(&inputs))
+    }
+}
@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::UpdateNode;
@@ -83,10 +84,19 @@ impl ToBatchProst for BatchUpdate {
             .iter()
             .map(Expr::to_expr_proto)
             .collect();
+        // The keys of the unique indexes refer to the columns without the row id.
+        let schema = Schema::new(self.input().schema().fields()[1..].to_vec());
+        let unique_indexes = self
+            .logical
+            .unique_indexes()
+            .iter()
+            .map(|index| index.to_protobuf(&schema))
+            .collect();
 
         NodeBody::Update(UpdateNode {
             table_source_ref_id: Some(table_id),
             exprs,
+            unique_indexes,
         })
     }
 }
@@ -0,0 +1,80 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::error::Result;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+use risingwave_pb::batch_plan::WorkTableScanNode;
+
+use super::{
+    LogicalWorkTableScan, PlanBase, PlanRef, PlanTreeNodeLeaf, ToBatchProst, ToDistributedBatch,
+};
+use crate::optimizer::plan_node::ToLocalBatch;
+use crate::optimizer::property::{Distribution, Order};
+
+/// `BatchWorkTableScan` implements [`LogicalWorkTableScan`] by reading the work table held by
+/// the enclosing [`super::BatchRecursiveUnion`].
+#[derive(Debug, Clone)]
+pub struct BatchWorkTableScan {
+    pub base: PlanBase,
+    logical: LogicalWorkTableScan,
+}
+
+impl PlanTreeNodeLeaf for BatchWorkTableScan {}
+impl_plan_tree_node_for_leaf!(BatchWorkTableScan);
+
+impl BatchWorkTableScan {
+    pub fn new(logical: LogicalWorkTableScan) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let base = PlanBase::new_batch(
+            ctx,
+            logical.schema().clone(),
+            Distribution::Single,
+            Order::any().clone(),
+        );
+        BatchWorkTableScan { base, logical }
+    }
+}
+
+impl fmt::Display for BatchWorkTableScan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "BatchWorkTableScan {{ work_table_id: {} }}",
+            self.logical.work_table_id()
+        )
+    }
+}
+
+impl ToDistributedBatch for BatchWorkTableScan {
+    fn to_distributed(&self) -> Result<PlanRef> {
+        Ok(self.clone().into())
+    }
+}
+
+impl ToBatchProst for BatchWorkTableScan {
+    fn to_batch_prost_body(&self) -> NodeBody {
+        NodeBody::WorkTableScan(WorkTableScanNode {
+            work_table_id: self.logical.work_table_id(),
+            fields: self.schema().to_prost(),
+        })
+    }
+}
+
+impl ToLocalBatch for BatchWorkTableScan {
+    fn to_local(&self) -> Result<PlanRef> {
+        Ok(self.clone().into())
+    }
+}
@@ -27,7 +27,11 @@ use super::{
     BatchHashAgg, BatchSimpleAgg, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary,
     PredicatePushdown, StreamHashAgg, StreamSimpleAgg, ToBatch, ToStream,
 };
-use crate::expr::{AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef};
+use crate::binder::{BoundQuery, BoundSetExpr};
+use crate::expr::{
+    AggCall, CorrelatedInputRef, Expr, ExprImpl, ExprRewriter, ExprType, ExprVisitor, FunctionCall,
+    InputRef, Subquery, SubqueryKind,
+};
 use crate::optimizer::plan_node::{gen_filter_and_pushdown, LogicalProject};
 use crate::optimizer::property::RequiredDist;
 use crate::utils::{ColIndexMapping, Condition, Substitute};
@@ -181,6 +185,13 @@ impl ExprRewriter for ExprHandler {
                 ));
                 return AggCall::new(agg_kind, inputs, distinct).unwrap().into();
             }
+            if i.has_subquery() {
+                self.error = Some(ErrorCode::NotImplemented(
+                    "subquery inside aggregate function".into(),
+                    None.into(),
+                ));
+                return AggCall::new(agg_kind, inputs, distinct).unwrap().into();
+            }
         }
 
         let mut index = self.project.len();
@@ -278,17 +289,188 @@ impl ExprRewriter for ExprHandler {
         }
     }
 
-    fn rewrite_subquery(&mut self, subquery: crate::expr::Subquery) -> ExprImpl {
+    /// The left operand of `IN`, `ANY` and `ALL` is rewritten like any other expression. Inside a
+    /// correlated subquery, references to the aggregated input must refer to a group column too,
+    /// and agg calls whose arguments only refer to the aggregated input are calculated by this agg,
+    /// e.g. `max(v1)` in `select (select max(v1)) from t`.
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.kind = match subquery.kind {
+            SubqueryKind::In(expr) => SubqueryKind::In(self.rewrite_expr(expr)),
+            SubqueryKind::Some(expr, cmp) => SubqueryKind::Some(self.rewrite_expr(expr), cmp),
+            SubqueryKind::All(expr, cmp) => SubqueryKind::All(self.rewrite_expr(expr), cmp),
+            kind => kind,
+        };
         if subquery.is_correlated() {
-            self.error = Some(ErrorCode::NotImplemented(
-                "correlated subquery in HAVING or SELECT with agg".into(),
-                2275.into(),
-            ));
+            let mut rewriter = CorrelatedRefRewriter {
+                handler: self,
+                depth: 1,
+            };
+            rewriter.rewrite_query(&mut subquery.query);
         }
         subquery.into()
     }
 }
 
+/// Rewrites the `CorrelatedInputRef`s in a subquery that refer to the input of [`ExprHandler`],
+/// see [`ExprHandler::rewrite_subquery`].
+struct CorrelatedRefRewriter<'a> {
+    handler: &'a mut ExprHandler,
+    /// The depth of a `CorrelatedInputRef` referring to the aggregated input, which increases as
+    /// the rewriter goes into nested subqueries.
+    depth: usize,
+}
+
+impl CorrelatedRefRewriter<'_> {
+    fn rewrite_query(&mut self, query: &mut BoundQuery) {
+        self.rewrite_set_expr(&mut query.body);
+        for expr in &mut query.extra_order_exprs {
+            *expr = self.rewrite_expr(std::mem::replace(expr, ExprImpl::literal_bool(true)));
+        }
+    }
+
+    fn rewrite_set_expr(&mut self, set_expr: &mut BoundSetExpr) {
+        match set_expr {
+            BoundSetExpr::Select(select) => {
+                let select = select.as_mut();
+                for expr in select
+                    .select_items
+                    .iter_mut()
+                    .chain(select.group_by.iter_mut())
+                    .chain(select.where_clause.iter_mut())
+                    .chain(select.having.iter_mut())
+                {
+                    *expr =
+                        self.rewrite_expr(std::mem::replace(expr, ExprImpl::literal_bool(true)));
+                }
+            }
+            BoundSetExpr::Values(_) => {}
+            BoundSetExpr::SetOperation { left, right, .. } => {
+                self.rewrite_set_expr(left);
+                self.rewrite_set_expr(right);
+            }
+            BoundSetExpr::RecursiveUnion {
+                base, recursive, ..
+            } => {
+                self.rewrite_set_expr(base);
+                self.rewrite_set_expr(recursive);
+            }
+        }
+    }
+
+    /// Whether all the column references in the arguments of `agg_call` refer to the aggregated
+    /// input. Such an agg call belongs to the outer query.
+    fn is_outer_agg_call(&self, agg_call: &AggCall) -> bool {
+        struct Collector {
+            depth: usize,
+            has_outer: bool,
+            has_other: bool,
+        }
+
+        impl ExprVisitor for Collector {
+            fn visit_input_ref(&mut self, _: &InputRef) {
+                self.has_other = true;
+            }
+
+            fn visit_correlated_input_ref(&mut self, input_ref: &CorrelatedInputRef) {
+                if input_ref.depth() == self.depth {
+                    self.has_outer = true;
+                } else {
+                    self.has_other = true;
+                }
+            }
+
+            fn visit_subquery(&mut self, _: &Subquery) {
+                self.has_other = true;
+            }
+        }
+
+        let mut collector = Collector {
+            depth: self.depth,
+            has_outer: false,
+            has_other: false,
+        };
+        agg_call
+            .inputs()
+            .iter()
+            .for_each(|expr| collector.visit_expr(expr));
+        collector.has_outer && !collector.has_other
+    }
+}
+
+impl ExprRewriter for CorrelatedRefRewriter<'_> {
+    fn rewrite_correlated_input_ref(&mut self, input_ref: CorrelatedInputRef) -> ExprImpl {
+        if input_ref.depth() != self.depth {
+            return input_ref.into();
+        }
+        let expr = self
+            .handler
+            .rewrite_input_ref(InputRef::new(input_ref.index(), input_ref.return_type()));
+        correlate(expr, self.depth)
+    }
+
+    fn rewrite_agg_call(&mut self, agg_call: AggCall) -> ExprImpl {
+        if !self.is_outer_agg_call(&agg_call) {
+            let (agg_kind, inputs, distinct) = agg_call.decompose();
+            let inputs = inputs
+                .into_iter()
+                .map(|expr| self.rewrite_expr(expr))
+                .collect();
+            return AggCall::new(agg_kind, inputs, distinct).unwrap().into();
+        }
+        let (agg_kind, inputs, distinct) = agg_call.decompose();
+        let inputs = inputs
+            .into_iter()
+            .map(|expr| decorrelate(expr, self.depth))
+            .collect();
+        let agg_call = AggCall::new(agg_kind, inputs, distinct).unwrap();
+        let expr = self.handler.rewrite_agg_call(agg_call);
+        correlate(expr, self.depth)
+    }
+
+    fn rewrite_subquery(&mut self, mut subquery: Subquery) -> ExprImpl {
+        subquery.kind = match subquery.kind {
+            SubqueryKind::In(expr) => SubqueryKind::In(self.rewrite_expr(expr)),
+            SubqueryKind::Some(expr, cmp) => SubqueryKind::Some(self.rewrite_expr(expr), cmp),
+            SubqueryKind::All(expr, cmp) => SubqueryKind::All(self.rewrite_expr(expr), cmp),
+            kind => kind,
+        };
+        self.depth += 1;
+        self.rewrite_query(&mut subquery.query);
+        self.depth -= 1;
+        subquery.into()
+    }
+}
+
+/// Converts the `InputRef`s in `expr` to `CorrelatedInputRef`s with `depth`.
+fn correlate(expr: ExprImpl, depth: usize) -> ExprImpl {
+    struct Correlate(usize);
+
+    impl ExprRewriter for Correlate {
+        fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
+            CorrelatedInputRef::new(input_ref.index(), input_ref.return_type(), self.0).into()
+        }
+    }
+
+    Correlate(depth).rewrite_expr(expr)
+}
+
+/// Converts the `CorrelatedInputRef`s with `depth` in `expr` to `InputRef`s.
+fn decorrelate(expr: ExprImpl, depth: usize) -> ExprImpl {
+    struct Decorrelate(usize);
+
+    impl ExprRewriter for Decorrelate {
+        fn rewrite_correlated_input_ref(&mut self, input_ref: CorrelatedInputRef) -> ExprImpl {
+            if input_ref.depth() == self.0 {
+                InputRef::new(input_ref.index(), input_ref.return_type()).into()
+            } else {
+                input_ref.into()
+            }
+        }
+    }
+
+    Decorrelate(depth).rewrite_expr(expr)
+}
+
 impl LogicalAgg {
     pub fn new(agg_calls: Vec<PlanAggCall>, group_keys: Vec<usize>, input: PlanRef) -> Self {
         let ctx = input.ctx();
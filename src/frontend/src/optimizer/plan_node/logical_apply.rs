@@ -50,7 +50,7 @@ impl LogicalApply {
         assert!(
             matches!(
                 join_type,
-                JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti
+                JoinType::Inner | JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti
             ),
             "Invalid join type {:?} for LogicalApply",
             join_type
@@ -93,6 +93,11 @@ impl LogicalApply {
         self.join_type
     }
 
+    /// Get the join condition of the logical apply.
+    pub fn on(&self) -> &Condition {
+        &self.on
+    }
+
     pub fn decompose(self) -> (PlanRef, PlanRef, Condition, JoinType) {
         (self.left, self.right, self.on, self.join_type)
     }
@@ -0,0 +1,215 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+use super::{
+    ColPrunable, LogicalProject, PlanBase, PlanRef, PlanTreeNodeBinary, PredicatePushdown,
+    StreamDynamicFilter, ToBatch, ToStream,
+};
+use crate::expr::ExprType;
+use crate::optimizer::property::{Distribution, RequiredDist};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalDynamicFilter` filters the rows of `left` by comparing a column with the value of
+/// `right`, which is a single row of a single column that changes over time, e.g. the value of
+/// `now()` in a streaming query. Unlike a join, only the rows whose result changes are updated when
+/// the value changes.
+#[derive(Debug, Clone)]
+pub struct LogicalDynamicFilter {
+    pub base: PlanBase,
+    /// The column of `left` compared with the value of `right`.
+    left_index: usize,
+    /// One of `<`, `<=`, `>` and `>=`, with the column of `left` on the left.
+    comparator: ExprType,
+    left: PlanRef,
+    right: PlanRef,
+}
+
+impl LogicalDynamicFilter {
+    pub fn new(left: PlanRef, left_index: usize, comparator: ExprType, right: PlanRef) -> Self {
+        assert!(matches!(
+            comparator,
+            ExprType::LessThan
+                | ExprType::LessThanOrEqual
+                | ExprType::GreaterThan
+                | ExprType::GreaterThanOrEqual
+        ));
+        assert_eq!(right.schema().len(), 1);
+        assert_eq!(
+            left.schema().fields()[left_index].data_type(),
+            right.schema().fields()[0].data_type()
+        );
+        let ctx = left.ctx();
+        let schema = left.schema().clone();
+        let pk_indices = left.pk_indices().to_vec();
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        Self {
+            base,
+            left_index,
+            comparator,
+            left,
+            right,
+        }
+    }
+
+    pub fn create(
+        left: PlanRef,
+        left_index: usize,
+        comparator: ExprType,
+        right: PlanRef,
+    ) -> PlanRef {
+        Self::new(left, left_index, comparator, right).into()
+    }
+
+    pub fn left_index(&self) -> usize {
+        self.left_index
+    }
+
+    pub fn comparator(&self) -> ExprType {
+        self.comparator
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        let comparator = match self.comparator {
+            ExprType::LessThan => "<",
+            ExprType::LessThanOrEqual => "<=",
+            ExprType::GreaterThan => ">",
+            ExprType::GreaterThanOrEqual => ">=",
+            _ => unreachable!(),
+        };
+        write!(
+            f,
+            "{} {{ predicate: ${} {} ${} }}",
+            name,
+            self.left_index,
+            comparator,
+            self.left.schema().len()
+        )
+    }
+}
+
+impl PlanTreeNodeBinary for LogicalDynamicFilter {
+    fn left(&self) -> PlanRef {
+        self.left.clone()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.right.clone()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(left, self.left_index, self.comparator, right)
+    }
+
+    fn rewrite_with_left_right(
+        &self,
+        left: PlanRef,
+        left_col_change: ColIndexMapping,
+        right: PlanRef,
+        _right_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        // The output columns are those of the left side.
+        let left_index = left_col_change.map(self.left_index);
+        (
+            Self::new(left, left_index, self.comparator, right),
+            left_col_change,
+        )
+    }
+}
+
+impl_plan_tree_node_for_binary! { LogicalDynamicFilter }
+
+impl fmt::Display for LogicalDynamicFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalDynamicFilter")
+    }
+}
+
+impl ColPrunable for LogicalDynamicFilter {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        // The compared column is always required by the filter itself.
+        let mut left_required_cols = required_cols.to_vec();
+        if !left_required_cols.contains(&self.left_index) {
+            left_required_cols.push(self.left_index);
+        }
+        let mapping =
+            ColIndexMapping::with_remaining_columns(&left_required_cols, self.left.schema().len());
+        let filter = Self::new(
+            self.left.prune_col(&left_required_cols),
+            mapping.map(self.left_index),
+            self.comparator,
+            self.right.prune_col(&[0]),
+        );
+        if left_required_cols.len() == required_cols.len() {
+            filter.into()
+        } else {
+            LogicalProject::with_mapping(
+                filter.into(),
+                ColIndexMapping::with_remaining_columns(
+                    &(0..required_cols.len()).collect_vec(),
+                    left_required_cols.len(),
+                ),
+            )
+            .into()
+        }
+    }
+}
+
+impl PredicatePushdown for LogicalDynamicFilter {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        // The output columns are those of the left side, so the predicate can be pushed to it.
+        let left = self.left.predicate_pushdown(predicate);
+        let right = self.right.predicate_pushdown(Condition::true_cond());
+        self.clone_with_left_right(left, right).into()
+    }
+}
+
+impl ToBatch for LogicalDynamicFilter {
+    fn to_batch(&self) -> Result<PlanRef> {
+        // The value of the right side is a constant in batch queries, so this is never planned.
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "there is no batch dynamic filter".to_string(),
+            None.into(),
+        )))
+    }
+}
+
+impl ToStream for LogicalDynamicFilter {
+    fn to_stream(&self) -> Result<PlanRef> {
+        // Shard the left side by its primary key, so that its state can be keyed by vnode, and
+        // broadcast the value of the right side to every parallel unit of the left side.
+        let left = self
+            .left
+            .to_stream_with_dist_required(&RequiredDist::shard_by_key(
+                self.left.schema().len(),
+                self.left.pk_indices(),
+            ))?;
+        let right = self
+            .right
+            .to_stream_with_dist_required(&RequiredDist::PhysicalDist(Distribution::Broadcast))?;
+        Ok(StreamDynamicFilter::new(self.clone_with_left_right(left, right)).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (left, left_col_change) = self.left.logical_rewrite_for_stream()?;
+        let (right, right_col_change) = self.right.logical_rewrite_for_stream()?;
+        let (filter, out_col_change) =
+            self.rewrite_with_left_right(left, left_col_change, right, right_col_change);
+        Ok((filter.into(), out_col_change))
+    }
+}
@@ -152,6 +152,16 @@ impl PredicatePushdown for LogicalFilter {
 
 impl ToBatch for LogicalFilter {
     fn to_batch(&self) -> Result<PlanRef> {
+        // Push the predicate into the scan as a range to scan, if possible.
+        if let Some(scan) = self.input().as_logical_scan() {
+            let (scan, predicate) = scan.to_batch_with_predicate(self.predicate.clone());
+            if predicate.always_true() {
+                return Ok(scan.into());
+            }
+            let new_logical = LogicalFilter::new(scan.into(), predicate);
+            return Ok(BatchFilter::new(new_logical).into());
+        }
+
         let new_input = self.input().to_batch()?;
         let new_logical = self.clone_with_input(new_input);
         Ok(BatchFilter::new(new_logical).into())
@@ -22,6 +22,7 @@ use super::{
     gen_filter_and_pushdown, BatchInsert, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary,
     PredicatePushdown, ToBatch, ToStream,
 };
+use crate::binder::BoundUniqueIndex;
 use crate::catalog::TableId;
 use crate::utils::Condition;
 
@@ -34,12 +35,18 @@ pub struct LogicalInsert {
     pub base: PlanBase,
     table_source_name: String, // explain-only
     source_id: TableId,        // TODO: use SourceId
+    unique_indexes: Vec<BoundUniqueIndex>,
     input: PlanRef,
 }
 
 impl LogicalInsert {
     /// Create a [`LogicalInsert`] node. Used internally by optimizer.
-    pub fn new(input: PlanRef, table_source_name: String, source_id: TableId) -> Self {
+    pub fn new(
+        input: PlanRef,
+        table_source_name: String,
+        source_id: TableId,
+        unique_indexes: Vec<BoundUniqueIndex>,
+    ) -> Self {
         let ctx = input.ctx();
         let schema = Schema::new(vec![Field::unnamed(DataType::Int64)]);
         let base = PlanBase::new_logical(ctx, schema, vec![]);
@@ -47,13 +54,24 @@ impl LogicalInsert {
             base,
             table_source_name,
             source_id,
+            unique_indexes,
             input,
         }
     }
 
     /// Create a [`LogicalInsert`] node. Used by planner.
-    pub fn create(input: PlanRef, table_source_name: String, source_id: TableId) -> Result<Self> {
-        Ok(Self::new(input, table_source_name, source_id))
+    pub fn create(
+        input: PlanRef,
+        table_source_name: String,
+        source_id: TableId,
+        unique_indexes: Vec<BoundUniqueIndex>,
+    ) -> Result<Self> {
+        Ok(Self::new(
+            input,
+            table_source_name,
+            source_id,
+            unique_indexes,
+        ))
     }
 
     pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
@@ -65,6 +83,11 @@ impl LogicalInsert {
     pub fn source_id(&self) -> TableId {
         self.source_id
     }
+
+    /// Get the unique indexes on the table, whose keys refer to the input columns.
+    pub fn unique_indexes(&self) -> &[BoundUniqueIndex] {
+        &self.unique_indexes
+    }
 }
 
 impl PlanTreeNodeUnary for LogicalInsert {
@@ -73,7 +96,12 @@ impl PlanTreeNodeUnary for LogicalInsert {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        Self::new(input, self.table_source_name.clone(), self.source_id)
+        Self::new(
+            input,
+            self.table_source_name.clone(),
+            self.source_id,
+            self.unique_indexes.clone(),
+        )
     }
 }
 
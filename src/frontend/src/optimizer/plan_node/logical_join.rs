@@ -25,11 +25,12 @@ use super::{
     PredicatePushdown, StreamHashJoin, ToBatch, ToStream,
 };
 use crate::expr::{ExprImpl, ExprType};
+use crate::optimizer::cost_model::{CostModel, RelationSize, StreamJoinStrategy};
 use crate::optimizer::plan_node::{
     BatchFilter, BatchHashJoin, BatchNestedLoopJoin, BatchProject, EqJoinPredicate, LogicalFilter,
-    StreamFilter, StreamProject,
+    StreamFilter, StreamNestedLoopJoin, StreamProject,
 };
-use crate::optimizer::property::RequiredDist;
+use crate::optimizer::property::{Distribution, RequiredDist};
 use crate::utils::{ColIndexMapping, Condition};
 
 /// `LogicalJoin` combines two relations according to some condition.
@@ -400,6 +401,30 @@ impl LogicalJoin {
         )
     }
 
+    /// Whether to join the streams by broadcasting the right side to a nested loop join, instead of
+    /// shuffling both sides to a hash join on the equal conditions in `predicate`, which is only
+    /// considered for inner joins of inputs with known sizes.
+    fn should_broadcast_right_to_stream(&self, predicate: &EqJoinPredicate) -> bool {
+        if self.join_type != JoinType::Inner {
+            return false;
+        }
+        let (left, right) = match (RelationSize::of(&self.left), RelationSize::of(&self.right)) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return false,
+        };
+        let arranged = |plan: &PlanRef, join_indices: &[usize]| {
+            plan.as_logical_scan().map_or(false, |scan| {
+                scan.index_for_join_keys(join_indices).is_some()
+            })
+        };
+        let arranged = (
+            arranged(&self.left, &predicate.left_eq_indexes()),
+            arranged(&self.right, &predicate.right_eq_indexes()),
+        );
+        CostModel::stream(&self.ctx()).choose_stream_join(left, right, arranged)
+            == StreamJoinStrategy::Broadcast
+    }
+
     /// Try to simplify the outer join with the predicate on the top of the join
     ///
     /// now it is just a naive implementation for comparison expression, we can give a more general
@@ -716,21 +741,49 @@ impl ToStream for LogicalJoin {
             self.on.clone(),
         );
 
-        let right = self
-            .right()
-            .to_stream_with_dist_required(&RequiredDist::shard_by_key(
-                self.right().schema().len(),
-                &predicate.right_eq_indexes(),
-            ))?;
+        let hash_join = predicate.has_eq() && !self.should_broadcast_right_to_stream(&predicate);
 
-        let r2l =
-            predicate.r2l_eq_columns_mapping(self.left().schema().len(), right.schema().len());
+        let (left, right) = if hash_join {
+            let right = self
+                .right()
+                .to_stream_with_dist_required(&RequiredDist::shard_by_key(
+                    self.right().schema().len(),
+                    &predicate.right_eq_indexes(),
+                ))?;
 
-        let left_dist = r2l.rewrite_required_distribution(&RequiredDist::PhysicalDist(
-            right.distribution().clone(),
-        ));
+            let r2l =
+                predicate.r2l_eq_columns_mapping(self.left().schema().len(), right.schema().len());
+
+            let left_dist = r2l.rewrite_required_distribution(&RequiredDist::PhysicalDist(
+                right.distribution().clone(),
+            ));
 
-        let left = self.left().to_stream_with_dist_required(&left_dist)?;
+            let left = self.left().to_stream_with_dist_required(&left_dist)?;
+            (left, right)
+        } else {
+            if self.join_type != JoinType::Inner {
+                return Err(RwError::from(ErrorCode::NotImplemented(
+                    format!("stream nested-loop join with {:?}", self.join_type),
+                    None.into(),
+                )));
+            }
+            // Shard the left side by its primary key, so that its state can be keyed by vnode, and
+            // broadcast the right side to every parallel unit of the left side. Unless it's an
+            // interval join, every chunk from either side rescans all the rows kept from the other
+            // side, so this is only fit for small inputs or rare updates.
+            let left = self
+                .left()
+                .to_stream_with_dist_required(&RequiredDist::shard_by_key(
+                    self.left().schema().len(),
+                    self.left().pk_indices(),
+                ))?;
+            let right = self
+                .right()
+                .to_stream_with_dist_required(&RequiredDist::PhysicalDist(
+                    Distribution::Broadcast,
+                ))?;
+            (left, right)
+        };
         let logical_join = self.clone_with_left_right(left, right);
         let new_output_indices = logical_join.output_indices.clone();
         let new_internal_column_num = logical_join.internal_column_num();
@@ -739,7 +792,7 @@ impl ToStream for LogicalJoin {
         // Temporarily remove output indices.
         let logical_join = logical_join.clone_with_output_indices(default_indices.clone());
 
-        let plan = if predicate.has_eq() {
+        let plan = if hash_join {
             // Convert to Hash Join for equal joins
             // For inner joins, pull non-equal conditions to a filter operator on top of it
             let pull_filter = self.join_type == JoinType::Inner && predicate.has_non_eq();
@@ -757,10 +810,7 @@ impl ToStream for LogicalJoin {
                 StreamHashJoin::new(logical_join, predicate).into()
             }
         } else {
-            return Err(RwError::from(ErrorCode::NotImplemented(
-                "stream nested-loop join".to_string(),
-                None.into(),
-            )));
+            StreamNestedLoopJoin::new(logical_join).into()
         };
 
         if self.output_indices != default_indices {
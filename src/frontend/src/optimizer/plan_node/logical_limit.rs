@@ -14,12 +14,13 @@
 
 use std::fmt;
 
-use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::error::Result;
 
 use super::{
-    gen_filter_and_pushdown, BatchLimit, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary,
-    PredicatePushdown, ToBatch, ToStream,
+    gen_filter_and_pushdown, BatchLimit, ColPrunable, LogicalTopN, PlanBase, PlanRef,
+    PlanTreeNodeUnary, PredicatePushdown, ToBatch, ToStream,
 };
+use crate::optimizer::property::Order;
 use crate::utils::{ColIndexMapping, Condition};
 
 /// `LogicalLimit` fetches up to `limit` rows from `offset`
@@ -111,10 +112,8 @@ impl ToBatch for LogicalLimit {
 
 impl ToStream for LogicalLimit {
     fn to_stream(&self) -> Result<PlanRef> {
-        Err(RwError::from(ErrorCode::NotImplemented(
-            "there is no limit stream operator".to_string(),
-            None.into(),
-        )))
+        // A limit without order-by is a Top-N whose order only depends on the primary key.
+        LogicalTopN::new(self.input(), self.limit, self.offset, Order::default()).to_stream()
     }
 
     fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
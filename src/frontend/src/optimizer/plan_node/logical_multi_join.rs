@@ -14,6 +14,7 @@
 
 use std::fmt;
 
+use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::plan_common::JoinType;
 
@@ -21,9 +22,18 @@ use super::{
     ColPrunable, LogicalFilter, LogicalJoin, LogicalProject, PlanBase, PlanRef, PlanTreeNodeBinary,
     PlanTreeNodeUnary, PredicatePushdown, ToBatch, ToStream,
 };
+use crate::optimizer::cost_model::{
+    estimate_join_row_count, estimate_row_count, CostModel, RelationSize,
+};
 use crate::optimizer::plan_node::PlanTreeNode;
 use crate::utils::{ColIndexMapping, Condition, ConnectedComponentLabeller};
 
+/// The maximum number of inputs to be reordered by dynamic programming. Joins of more inputs are
+/// reordered greedily.
+const DP_MAX_INPUTS: usize = 10;
+/// The maximum number of inputs to be reordered by cost.
+const COST_BASED_MAX_INPUTS: usize = 64;
+
 /// `LogicalMultiJoin` combines two or more relations according to some condition.
 ///
 /// Each output row has fields from one the inputs. The set of output rows is a subset
@@ -129,30 +139,55 @@ impl PlanTreeNode for LogicalMultiJoin {
     }
 }
 
+/// A binary tree joining the inputs of a [`LogicalMultiJoin`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum JoinTree {
+    Input(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+impl JoinTree {
+    /// A left deep tree joining the inputs in `join_ordering`.
+    pub fn left_deep(join_ordering: &[usize]) -> Self {
+        join_ordering[1..]
+            .iter()
+            .fold(Self::Input(join_ordering[0]), |tree, &index| {
+                Self::Join(Box::new(tree), Box::new(Self::Input(index)))
+            })
+    }
+
+    /// The inputs in the order their columns are output by the tree.
+    pub fn ordering(&self) -> Vec<usize> {
+        match self {
+            Self::Input(index) => vec![*index],
+            Self::Join(left, right) => {
+                let mut ordering = left.ordering();
+                ordering.extend(right.ordering());
+                ordering
+            }
+        }
+    }
+}
+
 impl LogicalMultiJoin {
     pub fn as_reordered_left_deep_join(&self, join_ordering: &[usize]) -> PlanRef {
         assert_eq!(join_ordering.len(), self.inputs.len());
         assert!(!join_ordering.is_empty());
 
-        let base_plan = self.inputs[join_ordering[0]].clone();
+        self.as_reordered_join_tree(&JoinTree::left_deep(join_ordering))
+    }
+
+    pub fn as_reordered_join_tree(&self, join_tree: &JoinTree) -> PlanRef {
+        let join_ordering = join_tree.ordering();
+        assert_eq!(join_ordering.len(), self.inputs.len());
 
         // Express as a cross join, we will rely on filter pushdown to push all of the join
         // conditions to convert into inner joins.
-        let mut output = join_ordering[1..]
-            .iter()
-            .fold(base_plan, |join_chain, &index| {
-                LogicalJoin::new(
-                    join_chain,
-                    self.inputs[index].clone(),
-                    JoinType::Inner,
-                    Condition::true_cond(),
-                )
-                .into()
-            });
+        let mut output = self.build_join_tree(join_tree);
 
         if join_ordering != (0..self.schema().len()).collect::<Vec<_>>() {
             output =
-                LogicalProject::with_mapping(output, self.mapping_from_ordering(join_ordering))
+                LogicalProject::with_mapping(output, self.mapping_from_ordering(&join_ordering))
                     .into();
         }
 
@@ -163,6 +198,140 @@ impl LogicalMultiJoin {
         output
     }
 
+    fn build_join_tree(&self, join_tree: &JoinTree) -> PlanRef {
+        match join_tree {
+            JoinTree::Input(index) => self.inputs[*index].clone(),
+            JoinTree::Join(left, right) => LogicalJoin::new(
+                self.build_join_tree(left),
+                self.build_join_tree(right),
+                JoinType::Inner,
+                Condition::true_cond(),
+            )
+            .into(),
+        }
+    }
+
+    /// Our cost-based join reordering chooses the join tree with the least cost under
+    /// `cost_model`, considering bushy trees and both sides of each join:
+    ///
+    /// 1. With at most [`DP_MAX_INPUTS`] inputs, the best tree of each subset of the inputs is
+    ///    found by dynamic programming from the best trees of its two-way partitions.
+    /// 2. With more inputs, the pair of trees with the least cost to join is joined greedily,
+    ///    starting from the inputs, until a single tree is left.
+    ///
+    /// In both cases, two trees are only cross joined if no pair of them is connected by an eq
+    /// join condition. The size of a join is estimated as for a [`LogicalJoin`].
+    ///
+    /// Returns `None` if the size of any input can't be estimated, in which case the
+    /// [`Self::heuristic_ordering`] should be used.
+    pub(crate) fn cost_based_join_tree(&self, cost_model: &CostModel) -> Option<JoinTree> {
+        if self.inputs.len() > COST_BASED_MAX_INPUTS {
+            return None;
+        }
+        let sizes = self
+            .inputs
+            .iter()
+            .map(RelationSize::of)
+            .collect::<Option<Vec<_>>>()?;
+
+        let (eq_join_conditions, _) = self.on.clone().split_by_input_col_nums(
+            &self.input_col_nums(),
+            // only_eq=
+            true,
+        );
+        let mut neighbors = vec![0u64; self.inputs.len()];
+        for &(left, right) in eq_join_conditions.keys() {
+            neighbors[left] |= 1 << right;
+            neighbors[right] |= 1 << left;
+        }
+
+        let leaves = sizes
+            .into_iter()
+            .zip_eq(neighbors)
+            .enumerate()
+            .map(|(index, (size, neighbors))| CostedJoinTree {
+                tree: JoinTree::Input(index),
+                inputs: 1 << index,
+                neighbors,
+                is_connected: true,
+                size,
+                cost: 0.0,
+            })
+            .collect_vec();
+
+        let best = if leaves.len() <= DP_MAX_INPUTS {
+            Self::dp_join_tree(leaves, cost_model)
+        } else {
+            Self::greedy_join_tree(leaves, cost_model)
+        };
+        Some(best.tree)
+    }
+
+    fn dp_join_tree(leaves: Vec<CostedJoinTree>, cost_model: &CostModel) -> CostedJoinTree {
+        let all_inputs = (1u64 << leaves.len()) - 1;
+        // The best tree of each subset of the inputs.
+        let mut best_trees: Vec<Option<CostedJoinTree>> = vec![None; all_inputs as usize + 1];
+        for leaf in leaves {
+            let inputs = leaf.inputs as usize;
+            best_trees[inputs] = Some(leaf);
+        }
+
+        // The partitions of a subset are smaller subsets, so they are visited first.
+        for inputs in 1..=all_inputs {
+            if inputs.count_ones() < 2 {
+                continue;
+            }
+            let mut best_connected: Option<CostedJoinTree> = None;
+            let mut best_cross: Option<CostedJoinTree> = None;
+            // Enumerate the non-empty proper subsets as the left side, in ascending order so that
+            // the original order is kept among trees of the same cost.
+            let mut left = inputs & inputs.wrapping_neg();
+            while left != inputs {
+                let right = inputs ^ left;
+                let tree = best_trees[left as usize]
+                    .as_ref()
+                    .unwrap()
+                    .join(best_trees[right as usize].as_ref().unwrap(), cost_model);
+                let best = if tree.is_connected {
+                    &mut best_connected
+                } else {
+                    &mut best_cross
+                };
+                if best.as_ref().map_or(true, |best| tree.cost < best.cost) {
+                    *best = Some(tree);
+                }
+                left = left.wrapping_sub(inputs) & inputs;
+            }
+            best_trees[inputs as usize] = best_connected.or(best_cross);
+        }
+
+        best_trees[all_inputs as usize].take().unwrap()
+    }
+
+    fn greedy_join_tree(mut trees: Vec<CostedJoinTree>, cost_model: &CostModel) -> CostedJoinTree {
+        while trees.len() > 1 {
+            let mut best: Option<(usize, usize, CostedJoinTree)> = None;
+            for (i, j) in (0..trees.len()).cartesian_product(0..trees.len()) {
+                if i == j {
+                    continue;
+                }
+                let tree = trees[i].join(&trees[j], cost_model);
+                // Prefer joins connected by eq join conditions to cross joins.
+                let is_better = best.as_ref().map_or(true, |(_, _, best)| {
+                    (!tree.is_connected, tree.cost) < (!best.is_connected, best.cost)
+                });
+                if is_better {
+                    best = Some((i, j, tree));
+                }
+            }
+            let (i, j, tree) = best.unwrap();
+            trees.remove(i.max(j));
+            trees.remove(i.min(j));
+            trees.push(tree);
+        }
+        trees.pop().unwrap()
+    }
+
     /// Our heuristic join reordering algorithm will try to perform a left-deep join.
     /// It will try to do the following:
     ///
@@ -174,7 +343,8 @@ impl LogicalMultiJoin {
     ///       a. eq joins between primary keys on both sides
     ///       b. eq joins with primary keys on one side
     ///       c. more equijoin conditions
-    ///    in that order. This forms our selectivity heuristic.
+    ///    in that order. This forms our selectivity heuristic. If all tables involved have been
+    ///    analyzed, joins between the smallest inputs, by estimated row count, are added first.
     /// 3. Thirdly, we will emit a left-deep cross-join of each of the left-deep joins of the
     ///    connected components. Depending on the type of plan, this may result in a planner failure
     ///    (e.g. for streaming). No cross-join will be emitted for a single connected component.
@@ -204,11 +374,25 @@ impl LogicalMultiJoin {
 
         let mut join_ordering = vec![];
 
+        let row_counts = self
+            .inputs
+            .iter()
+            .map(|input| estimate_row_count(input.clone()))
+            .collect_vec();
+        // Estimated size of the cross product of the two inputs of an edge. Joining the
+        // smallest inputs first keeps the intermediate results small.
+        let edge_cost = |edge: &(usize, usize)| match (row_counts[edge.0], row_counts[edge.1]) {
+            (Some(left), Some(right)) => left * right,
+            _ => f64::INFINITY,
+        };
+
         for component in edge_sets {
             let mut eq_cond_edges: Vec<(usize, usize)> = component.into_iter().collect();
 
-            // TODO(jon-chuang): add sorting of eq_cond_edges based on selectivity here
+            // Sort by estimated cost. Edges without statistics keep their original order, since
+            // the sort is stable.
             eq_cond_edges.sort();
+            eq_cond_edges.sort_by(|a, b| edge_cost(a).total_cmp(&edge_cost(b)));
 
             if eq_cond_edges.is_empty() {
                 // There is nothing to join in this connected component
@@ -290,6 +474,44 @@ impl LogicalMultiJoin {
     }
 }
 
+/// A join tree considered by [`LogicalMultiJoin::cost_based_join_tree`], with its estimated
+/// output size and cost.
+#[derive(Clone, Debug)]
+struct CostedJoinTree {
+    tree: JoinTree,
+    /// Bitset of the inputs joined by the tree.
+    inputs: u64,
+    /// Bitset of the inputs connected to any input of the tree by eq join conditions.
+    neighbors: u64,
+    /// Whether the two sides of the join at the root are connected by eq join conditions. Always
+    /// true for an input.
+    is_connected: bool,
+    size: RelationSize,
+    cost: f64,
+}
+
+impl CostedJoinTree {
+    fn join(&self, right: &Self, cost_model: &CostModel) -> Self {
+        let is_connected = self.neighbors & right.inputs != 0;
+        let size = RelationSize {
+            row_count: estimate_join_row_count(
+                self.size.row_count,
+                right.size.row_count,
+                is_connected,
+            ),
+            row_width: self.size.row_width + right.size.row_width,
+        };
+        Self {
+            tree: JoinTree::Join(Box::new(self.tree.clone()), Box::new(right.tree.clone())),
+            inputs: self.inputs | right.inputs,
+            neighbors: self.neighbors | right.neighbors,
+            is_connected,
+            cost: self.cost + right.cost + cost_model.join_cost(self.size, right.size, size),
+            size,
+        }
+    }
+}
+
 impl ToStream for LogicalMultiJoin {
     fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
         panic!(
@@ -0,0 +1,95 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+
+use super::{
+    ColPrunable, LogicalFilter, LogicalProject, PlanBase, PlanRef, PredicatePushdown, StreamNow,
+    ToBatch, ToStream,
+};
+use crate::session::OptimizerContextRef;
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalNow` returns a single row of the current time, which is the value of `now()` in a
+/// streaming query. The row is updated as time passes, and it's the right side of the
+/// [`super::LogicalDynamicFilter`]s comparing the input with `now()`.
+#[derive(Debug, Clone)]
+pub struct LogicalNow {
+    pub base: PlanBase,
+}
+
+impl LogicalNow {
+    pub fn new(ctx: OptimizerContextRef) -> Self {
+        let schema = Schema::new(vec![Field::with_name(DataType::Timestampz, "now")]);
+        let base = PlanBase::new_logical(ctx, schema, vec![]);
+        Self { base }
+    }
+
+    pub fn create(ctx: OptimizerContextRef) -> PlanRef {
+        Self::new(ctx).into()
+    }
+}
+
+impl_plan_tree_node_for_leaf! { LogicalNow }
+
+impl fmt::Display for LogicalNow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LogicalNow")
+    }
+}
+
+impl ColPrunable for LogicalNow {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        if required_cols == [0] {
+            return self.clone().into();
+        }
+        LogicalProject::with_mapping(
+            self.clone().into(),
+            ColIndexMapping::with_remaining_columns(required_cols, self.schema().len()),
+        )
+        .into()
+    }
+}
+
+impl PredicatePushdown for LogicalNow {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        LogicalFilter::create(self.clone().into(), predicate)
+    }
+}
+
+impl ToBatch for LogicalNow {
+    fn to_batch(&self) -> Result<PlanRef> {
+        // `now()` is a constant in batch queries, so this is never planned.
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "there is no batch now operator".to_string(),
+            None.into(),
+        )))
+    }
+}
+
+impl ToStream for LogicalNow {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Ok(StreamNow::new(self.clone()).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Ok((
+            self.clone().into(),
+            ColIndexMapping::identity(self.schema().len()),
+        ))
+    }
+}
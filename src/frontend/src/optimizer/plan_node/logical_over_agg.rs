@@ -0,0 +1,361 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::Field;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+
+use super::{
+    gen_filter_and_pushdown, ColPrunable, LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary,
+    PredicatePushdown, ToBatch, ToStream,
+};
+use crate::expr::{
+    Expr, ExprImpl, ExprRewriter, ExprVisitor, InputRef, InputRefDisplay, WindowFunction,
+    WindowFunctionType,
+};
+use crate::optimizer::property::{FieldOrder, Order};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// A window function in [`LogicalOverAgg`], whose partition and order keys are input columns.
+#[derive(Clone)]
+pub struct PlanWindowFunction {
+    pub function_type: WindowFunctionType,
+    pub return_type: DataType,
+    pub partition_by: Vec<usize>,
+    pub order_by: Order,
+}
+
+impl fmt::Debug for PlanWindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}() OVER(", self.function_type)?;
+        if !self.partition_by.is_empty() {
+            write!(
+                f,
+                "PARTITION BY {:?} ",
+                self.partition_by
+                    .iter()
+                    .copied()
+                    .map(InputRefDisplay)
+                    .collect_vec()
+            )?;
+        }
+        write!(f, "ORDER BY {})", self.order_by)
+    }
+}
+
+/// `LogicalOverAgg` appends the results of window functions to each row of its input.
+///
+/// It can't be executed by itself yet, and is only supported as the
+/// `row_number() OVER (PARTITION BY ... ORDER BY ...) <= N` pattern, which is converted into a
+/// [`super::LogicalTopN`] with group key by `OverAggToTopNRule`.
+#[derive(Debug, Clone)]
+pub struct LogicalOverAgg {
+    pub base: PlanBase,
+    window_functions: Vec<PlanWindowFunction>,
+    input: PlanRef,
+}
+
+impl LogicalOverAgg {
+    pub fn new(window_functions: Vec<PlanWindowFunction>, input: PlanRef) -> Self {
+        let ctx = input.ctx();
+        let mut schema = input.schema().clone();
+        window_functions.iter().for_each(|window_function| {
+            schema.fields.push(Field::with_name(
+                window_function.return_type.clone(),
+                window_function.function_type.name(),
+            ))
+        });
+        let pk_indices = input.pk_indices().to_vec();
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalOverAgg {
+            base,
+            window_functions,
+            input,
+        }
+    }
+
+    /// Plans the window functions in `select_exprs` as a [`LogicalOverAgg`], below a
+    /// [`LogicalProject`] computing their partition and order keys if they are not input columns.
+    /// Returns the [`LogicalOverAgg`] and `select_exprs` with the window functions replaced by
+    /// references to their results.
+    pub fn create(input: PlanRef, select_exprs: Vec<ExprImpl>) -> Result<(PlanRef, Vec<ExprImpl>)> {
+        let input_len = input.schema().len();
+        let mut collector = CollectWindowFunction::default();
+        select_exprs
+            .iter()
+            .for_each(|expr| collector.visit_expr(expr));
+
+        let mut input_exprs: Vec<ExprImpl> = input
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        let mut to_input_col = |expr: &ExprImpl| -> Result<usize> {
+            if expr.has_window_function() {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "window function calls cannot be nested".to_string(),
+                )
+                .into());
+            }
+            Ok(match expr {
+                ExprImpl::InputRef(input_ref) => input_ref.index(),
+                _ => match input_exprs.iter().position(|e| e == expr) {
+                    Some(idx) => idx,
+                    None => {
+                        input_exprs.push(expr.clone());
+                        input_exprs.len() - 1
+                    }
+                },
+            })
+        };
+        let window_functions = collector
+            .window_functions
+            .iter()
+            .map(|window_function| {
+                let partition_by = window_function
+                    .partition_by
+                    .iter()
+                    .map(&mut to_input_col)
+                    .try_collect()?;
+                let field_order = window_function
+                    .order_by
+                    .iter()
+                    .map(|(expr, direct)| {
+                        Ok::<_, RwError>(FieldOrder {
+                            index: to_input_col(expr)?,
+                            direct: *direct,
+                        })
+                    })
+                    .try_collect()?;
+                Ok::<_, RwError>(PlanWindowFunction {
+                    function_type: window_function.function_type,
+                    return_type: window_function.return_type.clone(),
+                    partition_by,
+                    order_by: Order::new(field_order),
+                })
+            })
+            .try_collect()?;
+
+        let input = if input_exprs.len() > input_len {
+            LogicalProject::create(input, input_exprs)
+        } else {
+            input
+        };
+        let mut replacer = ReplaceWindowFunction {
+            offset: input.schema().len(),
+            window_functions: collector.window_functions,
+        };
+        let exprs = select_exprs
+            .into_iter()
+            .map(|expr| replacer.rewrite_expr(expr))
+            .collect();
+        Ok((Self::new(window_functions, input).into(), exprs))
+    }
+
+    pub fn window_functions(&self) -> &[PlanWindowFunction] {
+        &self.window_functions
+    }
+}
+
+/// Collects the distinct window functions in the expressions, without visiting inside them.
+#[derive(Default)]
+struct CollectWindowFunction {
+    window_functions: Vec<WindowFunction>,
+}
+
+impl ExprVisitor for CollectWindowFunction {
+    fn visit_window_function(&mut self, window_function: &WindowFunction) {
+        if !self.window_functions.contains(window_function) {
+            self.window_functions.push(window_function.clone());
+        }
+    }
+}
+
+/// Replaces each window function with a reference to its result in the [`LogicalOverAgg`].
+struct ReplaceWindowFunction {
+    offset: usize,
+    window_functions: Vec<WindowFunction>,
+}
+
+impl ExprRewriter for ReplaceWindowFunction {
+    fn rewrite_window_function(&mut self, window_function: WindowFunction) -> ExprImpl {
+        let idx = self
+            .window_functions
+            .iter()
+            .position(|w| w == &window_function)
+            .unwrap();
+        InputRef::new(self.offset + idx, window_function.return_type()).into()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalOverAgg {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.window_functions.clone(), input)
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let window_functions = self
+            .window_functions
+            .iter()
+            .map(|window_function| PlanWindowFunction {
+                function_type: window_function.function_type,
+                return_type: window_function.return_type.clone(),
+                partition_by: window_function
+                    .partition_by
+                    .iter()
+                    .map(|idx| input_col_change.map(*idx))
+                    .collect(),
+                order_by: input_col_change
+                    .rewrite_required_order(&window_function.order_by)
+                    .unwrap(),
+            })
+            .collect_vec();
+        let new_input_len = input.schema().len();
+        let map = (0..self.input.schema().len())
+            .map(|idx| input_col_change.try_map(idx))
+            .chain((0..window_functions.len()).map(|idx| Some(new_input_len + idx)))
+            .collect();
+        let out_col_change =
+            ColIndexMapping::with_target_size(map, new_input_len + window_functions.len());
+        (Self::new(window_functions, input), out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalOverAgg}
+
+impl fmt::Display for LogicalOverAgg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LogicalOverAgg")
+            .field("window_functions", &self.window_functions)
+            .finish()
+    }
+}
+
+impl ColPrunable for LogicalOverAgg {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        let input_len = self.input.schema().len();
+        let kept_functions = (0..self.window_functions.len())
+            .filter(|i| required_cols.contains(&(input_len + i)))
+            .collect_vec();
+
+        let mut input_required_cols = FixedBitSet::with_capacity(input_len);
+        required_cols
+            .iter()
+            .filter(|&&col| col < input_len)
+            .for_each(|&col| input_required_cols.insert(col));
+        for &i in &kept_functions {
+            let window_function = &self.window_functions[i];
+            input_required_cols.extend(window_function.partition_by.iter().copied());
+            input_required_cols.extend(
+                window_function
+                    .order_by
+                    .field_order
+                    .iter()
+                    .map(|field_order| field_order.index),
+            );
+        }
+        let input_required_cols = input_required_cols.ones().collect_vec();
+        let new_input = self.input.prune_col(&input_required_cols);
+
+        let mapping = ColIndexMapping::with_remaining_columns(&input_required_cols, input_len);
+        let window_functions = kept_functions
+            .iter()
+            .map(|&i| {
+                let window_function = &self.window_functions[i];
+                PlanWindowFunction {
+                    function_type: window_function.function_type,
+                    return_type: window_function.return_type.clone(),
+                    partition_by: window_function
+                        .partition_by
+                        .iter()
+                        .map(|idx| mapping.map(*idx))
+                        .collect(),
+                    order_by: mapping
+                        .rewrite_required_order(&window_function.order_by)
+                        .unwrap(),
+                }
+            })
+            .collect();
+        // The window functions are all pruned if none of their results is required.
+        let new_over_agg: PlanRef = if kept_functions.is_empty() {
+            new_input
+        } else {
+            Self::new(window_functions, new_input).into()
+        };
+
+        let kept_cols = input_required_cols
+            .iter()
+            .copied()
+            .chain(kept_functions.iter().map(|i| input_len + i))
+            .collect_vec();
+        if kept_cols == required_cols {
+            return new_over_agg;
+        }
+        // Project the required columns out of the kept ones.
+        let map = kept_cols
+            .iter()
+            .map(|col| required_cols.iter().position(|required| required == col))
+            .collect();
+        let mapping = ColIndexMapping::with_target_size(map, required_cols.len());
+        LogicalProject::with_mapping(new_over_agg, mapping).into()
+    }
+}
+
+impl PredicatePushdown for LogicalOverAgg {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        gen_filter_and_pushdown(self, predicate, Condition::true_cond())
+    }
+}
+
+impl ToBatch for LogicalOverAgg {
+    fn to_batch(&self) -> Result<PlanRef> {
+        Err(
+            ErrorCode::NotImplemented("window functions in batch queries".to_string(), None.into())
+                .into(),
+        )
+    }
+}
+
+impl ToStream for LogicalOverAgg {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(ErrorCode::NotImplemented(
+            "window functions other than `row_number() OVER (PARTITION BY ... ORDER BY ...) <= N` \
+             in streaming queries"
+                .to_string(),
+            None.into(),
+        )
+        .into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (over_agg, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        Ok((over_agg.into(), out_col_change))
+    }
+}
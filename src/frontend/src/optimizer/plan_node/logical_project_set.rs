@@ -0,0 +1,397 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::project_set_select_item::SelectItem;
+use risingwave_pb::expr::ProjectSetSelectItem;
+
+use super::{
+    gen_filter_and_pushdown, BatchProjectSet, ColPrunable, LogicalProject, PlanBase, PlanRef,
+    PlanTreeNodeUnary, PredicatePushdown, StreamProjectSet, ToBatch, ToStream,
+};
+use crate::expr::{Expr, ExprImpl, ExprRewriter, ExprVisitor, InputRef, TableFunction};
+use crate::optimizer::plan_node::CollectInputRef;
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalProjectSet` projects one row into multiple rows with its select list, which may contain
+/// table functions, or set-returning functions, like `unnest`.
+///
+/// An input row is expanded into as many rows as the longest set returned for it, where the
+/// shorter sets are padded with nulls and the other expressions are repeated. The first output
+/// column is `projected_row_id`, the index of an output row among the rows expanded from the same
+/// input row, which identifies the output row together with the pk of the input.
+#[derive(Debug, Clone)]
+pub struct LogicalProjectSet {
+    pub base: PlanBase,
+    select_list: Vec<ExprImpl>,
+    input: PlanRef,
+}
+
+impl LogicalProjectSet {
+    pub fn new(input: PlanRef, select_list: Vec<ExprImpl>) -> Self {
+        let ctx = input.ctx();
+        let schema = Self::derive_schema(&select_list, input.schema());
+        let pk_indices = Self::derive_pk(input.schema(), input.pk_indices(), &select_list);
+        for expr in &select_list {
+            assert!(!expr.has_subquery());
+            assert!(!expr.has_agg_call());
+        }
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalProjectSet {
+            base,
+            select_list,
+            input,
+        }
+    }
+
+    /// Plans `select_list`, where table functions may appear anywhere but not inside another
+    /// table function, as a [`LogicalProjectSet`] computing the input columns and the table
+    /// functions, below a [`LogicalProject`] computing the rest of the expressions.
+    pub fn create(input: PlanRef, select_list: Vec<ExprImpl>) -> Result<PlanRef> {
+        let input_schema = input.schema();
+        let mut extractor = ExtractTableFunction {
+            input_len: input_schema.len(),
+            table_functions: vec![],
+            error: None,
+        };
+        let exprs = select_list
+            .into_iter()
+            .map(|expr| extractor.rewrite_expr(expr))
+            .collect();
+        if let Some(err) = extractor.error {
+            return Err(err);
+        }
+        let project_set_select_list = input_schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .chain(extractor.table_functions)
+            .collect();
+        let project_set = Self::new(input, project_set_select_list);
+        Ok(LogicalProject::create(project_set.into(), exprs))
+    }
+
+    /// Gets the mapping of column indices from output to input. `projected_row_id` and the items
+    /// other than input refs are mapped to none.
+    pub fn o2i_col_mapping(&self) -> ColIndexMapping {
+        Self::o2i_col_mapping_inner(self.input.schema().len(), &self.select_list)
+    }
+
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        self.o2i_col_mapping().inverse()
+    }
+
+    fn o2i_col_mapping_inner(input_len: usize, select_list: &[ExprImpl]) -> ColIndexMapping {
+        let map = std::iter::once(None)
+            .chain(select_list.iter().map(|expr| match expr {
+                ExprImpl::InputRef(input_ref) => Some(input_ref.index()),
+                _ => None,
+            }))
+            .collect();
+        ColIndexMapping::with_target_size(map, input_len)
+    }
+
+    fn derive_schema(select_list: &[ExprImpl], input_schema: &Schema) -> Schema {
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(select_list.iter().enumerate().map(|(id, expr)| match expr {
+                ExprImpl::InputRef(input_ref) => {
+                    let field = input_schema.fields()[input_ref.index()].clone();
+                    Field::with_struct(
+                        expr.return_type(),
+                        field.name,
+                        field.sub_fields,
+                        field.type_name,
+                    )
+                }
+                _ => Field::with_name(expr.return_type(), format!("expr#{}", id + 1)),
+            }))
+            .collect();
+        Schema { fields }
+    }
+
+    fn derive_pk(
+        input_schema: &Schema,
+        input_pk: &[usize],
+        select_list: &[ExprImpl],
+    ) -> Vec<usize> {
+        let i2o = Self::o2i_col_mapping_inner(input_schema.len(), select_list).inverse();
+        input_pk
+            .iter()
+            .map(|pk_col| i2o.try_map(*pk_col))
+            .collect::<Option<Vec<_>>>()
+            .map(|mut pk| {
+                pk.push(0);
+                pk
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn select_list(&self) -> &Vec<ExprImpl> {
+        &self.select_list
+    }
+
+    /// Serializes the select list, where the table functions are the only items that are not
+    /// expressions.
+    pub(super) fn select_list_to_protobuf(&self) -> Vec<ProjectSetSelectItem> {
+        self.select_list
+            .iter()
+            .map(|item| {
+                let select_item = match item {
+                    ExprImpl::TableFunction(table_function) => {
+                        SelectItem::TableFunction(table_function.to_protobuf())
+                    }
+                    expr => SelectItem::Expr(expr.to_expr_proto()),
+                };
+                ProjectSetSelectItem {
+                    select_item: Some(select_item),
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        f.debug_struct(name)
+            .field("select_list", self.select_list())
+            .finish()
+    }
+}
+
+/// Replaces each table function with a reference to the column it is computed in by the
+/// [`LogicalProjectSet`], and each input ref with the column it is copied to.
+struct ExtractTableFunction {
+    input_len: usize,
+    table_functions: Vec<ExprImpl>,
+    error: Option<RwError>,
+}
+
+impl ExprRewriter for ExtractTableFunction {
+    fn rewrite_table_function(&mut self, table_function: TableFunction) -> ExprImpl {
+        if table_function.args.iter().any(ExprImpl::has_table_function) {
+            self.error.get_or_insert_with(|| {
+                ErrorCode::NotImplemented("nested set-returning functions".to_string(), None.into())
+                    .into()
+            });
+        }
+        let index = 1 + self.input_len + self.table_functions.len();
+        let return_type = table_function.return_type();
+        self.table_functions.push(table_function.into());
+        InputRef::new(index, return_type).into()
+    }
+
+    fn rewrite_input_ref(&mut self, input_ref: InputRef) -> ExprImpl {
+        InputRef::new(input_ref.index() + 1, input_ref.return_type()).into()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalProjectSet {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.select_list.clone())
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        mut input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let select_list = self
+            .select_list
+            .clone()
+            .into_iter()
+            .map(|expr| input_col_change.rewrite_expr(expr))
+            .collect();
+        let project_set = Self::new(input, select_list);
+        // change the input columns index will not change the output column index
+        let out_col_change = ColIndexMapping::identity(self.schema().len());
+        (project_set, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalProjectSet}
+
+impl fmt::Display for LogicalProjectSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalProjectSet")
+    }
+}
+
+impl ColPrunable for LogicalProjectSet {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        // The set-returning functions are always kept, as they decide the number of output rows.
+        let kept_items = (0..self.select_list.len())
+            .filter(|&i| {
+                required_cols.contains(&(i + 1)) || self.select_list[i].has_table_function()
+            })
+            .collect_vec();
+
+        let input_col_num = self.input.schema().len();
+        let mut input_ref_collector = CollectInputRef::with_capacity(input_col_num);
+        kept_items
+            .iter()
+            .for_each(|&i| input_ref_collector.visit_expr(&self.select_list[i]));
+        let input_required_cols = FixedBitSet::from(input_ref_collector).ones().collect_vec();
+        let new_input = self.input.prune_col(&input_required_cols);
+        let mut mapping =
+            ColIndexMapping::with_remaining_columns(&input_required_cols, input_col_num);
+        let select_list = kept_items
+            .iter()
+            .map(|&i| mapping.rewrite_expr(self.select_list[i].clone()))
+            .collect();
+        let new_project_set = Self::new(new_input, select_list);
+
+        let kept_cols = std::iter::once(0)
+            .chain(kept_items.iter().map(|&i| i + 1))
+            .collect_vec();
+        if kept_cols == required_cols {
+            return new_project_set.into();
+        }
+        // Project the required columns out of the kept ones.
+        let map = kept_cols
+            .iter()
+            .map(|col| required_cols.iter().position(|required| required == col))
+            .collect();
+        let mapping = ColIndexMapping::with_target_size(map, required_cols.len());
+        LogicalProject::with_mapping(new_project_set.into(), mapping).into()
+    }
+}
+
+impl PredicatePushdown for LogicalProjectSet {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        gen_filter_and_pushdown(self, predicate, Condition::true_cond())
+    }
+}
+
+impl ToBatch for LogicalProjectSet {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_batch()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(BatchProjectSet::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalProjectSet {
+    fn to_stream(&self) -> Result<PlanRef> {
+        let new_input = self.input().to_stream()?;
+        let new_logical = self.clone_with_input(new_input);
+        Ok(StreamProjectSet::new(new_logical).into())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream()?;
+        let (project_set, out_col_change) =
+            self.rewrite_with_input(input.clone(), input_col_change);
+        let i2o = project_set.i2o_col_mapping();
+        let col_need_to_add = input
+            .pk_indices()
+            .iter()
+            .cloned()
+            .filter(|i| i2o.try_map(*i).is_none());
+        let input_schema = input.schema();
+        let select_list =
+            project_set
+                .select_list()
+                .iter()
+                .cloned()
+                .chain(col_need_to_add.map(|idx| {
+                    InputRef::new(idx, input_schema.fields[idx].data_type.clone()).into()
+                }))
+                .collect();
+        let project_set = Self::new(input, select_list);
+        // the added columns is at the end, so it will not change the exists column index
+        Ok((project_set.into(), out_col_change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::expr::{assert_eq_input_ref, TableFunctionType};
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Pruning
+    /// ```text
+    /// ProjectSet(input_ref(0), unnest(input_ref(1)), input_ref(2))
+    ///   TableScan(v1, v2, v3)
+    /// ```
+    /// with required columns `[2]` will result in
+    /// ```text
+    /// Project(input_ref(1))
+    ///   ProjectSet(unnest(input_ref(0)))
+    ///     TableScan(v2)
+    /// ```
+    async fn test_prune_project_set() {
+        let ctx = OptimizerContext::mock().await;
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let fields: Vec<Field> = vec![
+            Field::with_name(DataType::Int32, "v1"),
+            Field::with_name(list_type.clone(), "v2"),
+            Field::with_name(DataType::Int32, "v3"),
+        ];
+        let values = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields.clone(),
+            },
+            ctx,
+        );
+        let unnest = TableFunction::new(
+            TableFunctionType::Unnest,
+            vec![InputRef::new(1, list_type).into()],
+        )
+        .unwrap();
+        let project_set = LogicalProjectSet::new(
+            values.into(),
+            vec![
+                InputRef::new(0, DataType::Int32).into(),
+                unnest.into(),
+                InputRef::new(2, DataType::Int32).into(),
+            ],
+        );
+        assert_eq!(project_set.schema().len(), 4);
+
+        let plan = project_set.prune_col(&[2]);
+
+        let project = plan.as_logical_project().unwrap();
+        assert_eq!(project.exprs().len(), 1);
+        assert_eq_input_ref!(&project.exprs()[0], 1);
+
+        let input = project.input();
+        let project_set = input.as_logical_project_set().unwrap();
+        assert_eq!(project_set.select_list().len(), 1);
+        let unnest = project_set.select_list()[0].as_table_function().unwrap();
+        assert_eq_input_ref!(&unnest.args[0], 0);
+
+        let values = project_set.input();
+        let values = values.as_logical_values().unwrap();
+        assert_eq!(values.schema().fields(), &fields[1..2]);
+    }
+}
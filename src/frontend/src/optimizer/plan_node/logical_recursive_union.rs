@@ -0,0 +1,156 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+use super::{
+    BatchRecursiveUnion, ColPrunable, LogicalFilter, LogicalProject, PlanBase, PlanRef,
+    PlanTreeNodeBinary, PredicatePushdown, ToBatch, ToStream,
+};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalRecursiveUnion` implements `WITH RECURSIVE`. It returns the rows of the non-recursive
+/// term on the left, and then evaluates the recursive term on the right repeatedly, where each
+/// evaluation reads the rows produced by the previous one from a [`super::LogicalWorkTableScan`],
+/// until no new rows are produced.
+#[derive(Debug, Clone)]
+pub struct LogicalRecursiveUnion {
+    pub base: PlanBase,
+    non_recursive: PlanRef,
+    recursive: PlanRef,
+    all: bool,
+    work_table_id: u32,
+}
+
+impl LogicalRecursiveUnion {
+    pub fn new(all: bool, non_recursive: PlanRef, recursive: PlanRef, work_table_id: u32) -> Self {
+        assert_eq!(
+            non_recursive.schema().data_types(),
+            recursive.schema().data_types()
+        );
+        let base =
+            PlanBase::new_logical(non_recursive.ctx(), non_recursive.schema().clone(), vec![]);
+        LogicalRecursiveUnion {
+            base,
+            non_recursive,
+            recursive,
+            all,
+            work_table_id,
+        }
+    }
+
+    pub fn create(
+        all: bool,
+        non_recursive: PlanRef,
+        recursive: PlanRef,
+        work_table_id: u32,
+    ) -> PlanRef {
+        Self::new(all, non_recursive, recursive, work_table_id).into()
+    }
+
+    pub fn all(&self) -> bool {
+        self.all
+    }
+
+    pub fn work_table_id(&self) -> u32 {
+        self.work_table_id
+    }
+}
+
+impl PlanTreeNodeBinary for LogicalRecursiveUnion {
+    fn left(&self) -> PlanRef {
+        self.non_recursive.clone()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.recursive.clone()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.all, left, right, self.work_table_id)
+    }
+}
+
+impl_plan_tree_node_for_binary! { LogicalRecursiveUnion }
+
+impl fmt::Display for LogicalRecursiveUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LogicalRecursiveUnion {{ all: {}, work_table_id: {} }}",
+            self.all, self.work_table_id
+        )
+    }
+}
+
+impl ColPrunable for LogicalRecursiveUnion {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        // The output rows are fed back to the recursive term as the work table, so all the columns
+        // are required from both sides.
+        let all_cols = (0..self.schema().len()).collect_vec();
+        let union = self.clone_with_left_right(
+            self.non_recursive.prune_col(&all_cols),
+            self.recursive.prune_col(&all_cols),
+        );
+        if required_cols == all_cols {
+            union.into()
+        } else {
+            LogicalProject::with_mapping(
+                union.into(),
+                ColIndexMapping::with_remaining_columns(required_cols, all_cols.len()),
+            )
+            .into()
+        }
+    }
+}
+
+impl PredicatePushdown for LogicalRecursiveUnion {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        // The predicate can't be pushed into either side, as it would also filter out the rows
+        // that the following iterations are derived from.
+        let union = self.clone_with_left_right(
+            self.non_recursive
+                .predicate_pushdown(Condition::true_cond()),
+            self.recursive.predicate_pushdown(Condition::true_cond()),
+        );
+        LogicalFilter::create(union.into(), predicate)
+    }
+}
+
+impl ToBatch for LogicalRecursiveUnion {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let new_logical =
+            self.clone_with_left_right(self.non_recursive.to_batch()?, self.recursive.to_batch()?);
+        Ok(BatchRecursiveUnion::new(new_logical).into())
+    }
+}
+
+impl ToStream for LogicalRecursiveUnion {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "WITH RECURSIVE in streaming queries".to_string(),
+            None.into(),
+        )))
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "WITH RECURSIVE in streaming queries".to_string(),
+            None.into(),
+        )))
+    }
+}
@@ -12,21 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Reverse;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::ops::Bound;
 use std::rc::Rc;
 
 use itertools::Itertools;
-use risingwave_common::catalog::{ColumnDesc, Schema, TableDesc};
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema, TableDesc};
 use risingwave_common::error::Result;
 
 use super::{
     ColPrunable, LogicalFilter, PlanBase, PlanRef, PredicatePushdown, StreamTableScan, ToBatch,
     ToStream,
 };
+use crate::catalog::table_statistics::{ColumnStatistics, TableStatistics};
 use crate::optimizer::plan_node::BatchSeqScan;
 use crate::session::OptimizerContextRef;
-use crate::utils::{ColIndexMapping, Condition};
+use crate::utils::{ColIndexMapping, Condition, ScanRange};
+
+/// The max number of ranges to scan derived from a predicate, beyond which the whole table is
+/// scanned instead.
+const MAX_NUM_SCAN_RANGES: usize = 64;
 
 /// `LogicalScan` returns contents of a table or other equivalent object
 #[derive(Debug, Clone)]
@@ -38,6 +45,8 @@ pub struct LogicalScan {
     table_desc: Rc<TableDesc>,
     // Descriptors of all indexes on this table
     indexes: Vec<(String, Rc<TableDesc>)>,
+    // Statistics of the table collected by `ANALYZE`, if any
+    statistics: Option<Rc<TableStatistics>>,
 }
 
 impl LogicalScan {
@@ -47,6 +56,7 @@ impl LogicalScan {
         required_col_idx: Vec<usize>, // the column index in the table
         table_desc: Rc<TableDesc>,
         indexes: Vec<(String, Rc<TableDesc>)>,
+        statistics: Option<Rc<TableStatistics>>,
         ctx: OptimizerContextRef,
     ) -> Self {
         // here we have 3 concepts
@@ -84,6 +94,7 @@ impl LogicalScan {
             required_col_idx,
             table_desc,
             indexes,
+            statistics,
         }
     }
 
@@ -92,6 +103,7 @@ impl LogicalScan {
         table_name: String, // explain-only
         table_desc: Rc<TableDesc>,
         indexes: Vec<(String, Rc<TableDesc>)>,
+        statistics: Option<Rc<TableStatistics>>,
         ctx: OptimizerContextRef,
     ) -> Result<PlanRef> {
         Ok(Self::new(
@@ -99,6 +111,7 @@ impl LogicalScan {
             (0..table_desc.columns.len()).into_iter().collect(),
             table_desc,
             indexes,
+            statistics,
             ctx,
         )
         .into())
@@ -136,6 +149,18 @@ impl LogicalScan {
         &self.indexes
     }
 
+    /// Get the statistics of the table collected by `ANALYZE`, if any
+    #[must_use]
+    pub fn statistics(&self) -> Option<&TableStatistics> {
+        self.statistics.as_deref()
+    }
+
+    /// Get the statistics of the `col_idx`-th output column, if any
+    pub fn column_statistics(&self, col_idx: usize) -> Option<&ColumnStatistics> {
+        let column_id = self.table_desc.columns[self.required_col_idx[col_idx]].column_id;
+        self.statistics()?.column(column_id)
+    }
+
     /// distribution keys stored in catalog only contains column index of the table (`table_idx`),
     /// so we need to convert it to `operator_idx` when filling distributions.
     pub fn map_distribution_keys(&self) -> Vec<usize> {
@@ -152,18 +177,91 @@ impl LogicalScan {
             .collect()
     }
 
+    /// Whether `index` stores all the columns required by this scan. The columns of an index have
+    /// the same names as the corresponding columns of the table.
+    pub fn is_covered_by_index(&self, index: &TableDesc) -> bool {
+        let index_columns = index
+            .columns
+            .iter()
+            .map(|desc| desc.name.as_str())
+            .collect::<HashSet<_>>();
+        self.required_col_idx
+            .iter()
+            .all(|idx| index_columns.contains(self.table_desc.columns[*idx].name.as_str()))
+    }
+
+    /// Find an index covering this scan, which is distributed by exactly the columns at
+    /// `join_indices` in order and whose order key starts with them in any order, so that a delta
+    /// join on these columns can look up its arrangement.
+    pub fn index_for_join_keys(&self, join_indices: &[usize]) -> Option<(&str, &Rc<TableDesc>)> {
+        let column_descs = self.column_descs();
+        // The columns of create index's MV have the same names as the corresponding columns in
+        // the original table, while their column ids may differ if the index only includes
+        // some of the columns. We generate a list of column names, and match them against
+        // prefixes of indexes.
+        let columns_to_match = join_indices
+            .iter()
+            .map(|idx| column_descs[*idx].name.as_str())
+            .collect_vec();
+
+        self.indexes.iter().find_map(|(name, index)| {
+            // 0. Check if the index stores all the columns of the scan.
+            if !self.is_covered_by_index(index) {
+                return None;
+            }
+
+            // 1. Check if distribution keys are the same.
+            // We don't assume the hash function we are using satisfies commutativity
+            // `Hash(A, B) == Hash(B, A)`, so we consider order of each item in distribution
+            // keys here.
+            if index
+                .distribution_keys
+                .iter()
+                .map(|x| index.columns[*x].name.as_str())
+                .collect_vec()
+                != columns_to_match
+            {
+                return None;
+            }
+
+            // 2. Check if the join keys are prefix of order keys
+
+            // A HashSet containing remaining columns to match
+            let mut remaining_to_match = columns_to_match.iter().copied().collect::<HashSet<_>>();
+
+            // Begin match join columns with index prefix. e.g., if the join columns are `a, b,
+            // c`, and the index has `a, b, c` or `a, c, b` or any combination as prefix, then
+            // we can use this index.
+            for ordered_column in &index.order_desc {
+                let column_name = ordered_column.column_desc.name.as_str();
+
+                match remaining_to_match.remove(&column_name) {
+                    true => continue, // matched
+                    false => break,   // not matched
+                }
+            }
+
+            if remaining_to_match.is_empty() {
+                Some((name.as_str(), index))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Scan `index` instead of the table. The index must cover this scan.
     pub fn to_index_scan(&self, index_name: &str, index: &Rc<TableDesc>) -> LogicalScan {
         let mut new_required_col_idx = Vec::with_capacity(self.required_col_idx.len());
         let all_columns = index
             .columns
             .iter()
             .enumerate()
-            .map(|(idx, desc)| (desc.column_id, idx))
+            .map(|(idx, desc)| (desc.name.as_str(), idx))
             .collect::<HashMap<_, _>>();
 
         // create index scan plan to match the output order of the current table scan
         for &col_idx in &self.required_col_idx {
-            let column_idx_in_index = all_columns[&self.table_desc.columns[col_idx].column_id];
+            let column_idx_in_index = all_columns[self.table_desc.columns[col_idx].name.as_str()];
             new_required_col_idx.push(column_idx_in_index);
         }
 
@@ -172,9 +270,73 @@ impl LogicalScan {
             new_required_col_idx,
             index.clone(),
             vec![],
+            // The index holds exactly the rows of the table, so share its statistics.
+            self.statistics.clone(),
             self.ctx(),
         )
     }
+
+    /// The output indices of the longest prefix of the table's order key present in the output.
+    fn order_key_output_indices(&self) -> Vec<usize> {
+        let id_to_op_idx = self
+            .required_col_idx
+            .iter()
+            .enumerate()
+            .map(|(op_idx, tb_idx)| (self.table_desc.columns[*tb_idx].column_id, op_idx))
+            .collect::<HashMap<ColumnId, _>>();
+        self.table_desc
+            .order_desc
+            .iter()
+            .map_while(|desc| id_to_op_idx.get(&desc.column_desc.column_id).copied())
+            .collect()
+    }
+
+    /// Converts to a batch scan filtered by `predicate`, returning the part of `predicate` left to
+    /// be applied on the scanned rows. If `predicate` restricts the order key of the table or of a
+    /// covering index, only the derived ranges of the table or the index are scanned.
+    pub fn to_batch_with_predicate(&self, predicate: Condition) -> (BatchSeqScan, Condition) {
+        // Prefer fewer scan ranges, then more equality conditions on the key, then a range on the
+        // next key column. Having no scan range at all means a full scan and scores the lowest.
+        let score = |scan_ranges: &[ScanRange]| {
+            scan_ranges
+                .first()
+                .map_or((false, Reverse(0), 0, false), |scan_range| {
+                    (
+                        true,
+                        Reverse(scan_ranges.len()),
+                        scan_range.eq_conds.len(),
+                        scan_range.range != (Bound::Unbounded, Bound::Unbounded),
+                    )
+                })
+        };
+
+        let (scan_ranges, others) = predicate
+            .clone()
+            .split_to_scan_ranges(&self.order_key_output_indices(), MAX_NUM_SCAN_RANGES);
+        let mut best = (self.clone(), scan_ranges, others);
+        for (index_name, index) in &self.indexes {
+            if !self.is_covered_by_index(index) {
+                continue;
+            }
+            let index_scan = self.to_index_scan(index_name, index);
+            let (scan_ranges, others) = predicate
+                .clone()
+                .split_to_scan_ranges(&index_scan.order_key_output_indices(), MAX_NUM_SCAN_RANGES);
+            if score(&scan_ranges) > score(&best.1) {
+                best = (index_scan, scan_ranges, others);
+            }
+        }
+
+        let (scan, scan_ranges, others) = best;
+        if scan_ranges.is_empty() {
+            (BatchSeqScan::new(self.clone()), predicate)
+        } else {
+            (
+                BatchSeqScan::new_with_scan_ranges(scan, scan_ranges),
+                others,
+            )
+        }
+    }
 }
 
 impl_plan_tree_node_for_leaf! {LogicalScan}
@@ -202,6 +364,7 @@ impl ColPrunable for LogicalScan {
             required_col_idx,
             self.table_desc.clone(),
             self.indexes.clone(),
+            self.statistics.clone(),
             self.base.ctx.clone(),
         )
         .into()
@@ -254,6 +417,7 @@ impl ToStream for LogicalScan {
                         required_col_idx,
                         self.table_desc.clone(),
                         self.indexes.clone(),
+                        self.statistics.clone(),
                         self.base.ctx.clone(),
                     )
                     .into(),
@@ -0,0 +1,102 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result};
+
+use super::{
+    BatchTableFunction, ColPrunable, LogicalFilter, LogicalProject, PlanBase, PlanRef,
+    PredicatePushdown, ToBatch, ToStream,
+};
+use crate::expr::TableFunction;
+use crate::session::OptimizerContextRef;
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalTableFunction` is a table function with constant arguments in the FROM clause, like
+/// `generate_series`. A set of structs is expanded into the fields of the structs as its columns.
+#[derive(Debug, Clone)]
+pub struct LogicalTableFunction {
+    pub base: PlanBase,
+    pub(super) table_function: TableFunction,
+}
+
+impl LogicalTableFunction {
+    /// Create a [`LogicalTableFunction`] node. Used by planner.
+    pub fn new(table_function: TableFunction, ctx: OptimizerContextRef) -> Self {
+        let schema = Schema::new(table_function.output_fields());
+        let base = PlanBase::new_logical(ctx, schema, vec![]);
+        Self {
+            base,
+            table_function,
+        }
+    }
+
+    pub fn table_function(&self) -> &TableFunction {
+        &self.table_function
+    }
+
+    pub fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(f, "{} {{ {:?} }}", name, self.table_function)
+    }
+}
+
+impl_plan_tree_node_for_leaf! { LogicalTableFunction }
+
+impl fmt::Display for LogicalTableFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalTableFunction")
+    }
+}
+
+impl ColPrunable for LogicalTableFunction {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        // The columns are all expanded from the same set, so they are pruned with a project.
+        if required_cols.iter().copied().eq(0..self.schema().len()) {
+            return self.clone().into();
+        }
+        let mapping = ColIndexMapping::with_remaining_columns(required_cols, self.schema().len());
+        LogicalProject::with_mapping(self.clone().into(), mapping).into()
+    }
+}
+
+impl PredicatePushdown for LogicalTableFunction {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        LogicalFilter::create(self.clone().into(), predicate)
+    }
+}
+
+impl ToBatch for LogicalTableFunction {
+    fn to_batch(&self) -> Result<PlanRef> {
+        Ok(BatchTableFunction::new(self.clone()).into())
+    }
+}
+
+impl ToStream for LogicalTableFunction {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(
+            ErrorCode::NotImplemented("LogicalTableFunction::to_stream".to_string(), None.into())
+                .into(),
+        )
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Err(ErrorCode::NotImplemented(
+            "LogicalTableFunction::logical_rewrite_for_stream".to_string(),
+            None.into(),
+        )
+        .into())
+    }
+}
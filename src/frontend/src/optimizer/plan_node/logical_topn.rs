@@ -16,13 +16,13 @@ use std::fmt;
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 
 use super::{
     gen_filter_and_pushdown, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary, PredicatePushdown,
     ToBatch, ToStream,
 };
-use crate::optimizer::plan_node::{BatchTopN, LogicalProject, StreamTopN};
+use crate::optimizer::plan_node::{BatchTopN, LogicalProject, StreamGroupTopN, StreamTopN};
 use crate::optimizer::property::{FieldOrder, Order, RequiredDist};
 use crate::utils::{ColIndexMapping, Condition};
 
@@ -34,10 +34,22 @@ pub struct LogicalTopN {
     limit: usize,
     offset: usize,
     order: Order,
+    /// Columns to group by. The top N rows are taken from each group if it's not empty.
+    group_key: Vec<usize>,
 }
 
 impl LogicalTopN {
     pub fn new(input: PlanRef, limit: usize, offset: usize, order: Order) -> Self {
+        Self::with_group(input, limit, offset, order, vec![])
+    }
+
+    pub fn with_group(
+        input: PlanRef,
+        limit: usize,
+        offset: usize,
+        order: Order,
+        group_key: Vec<usize>,
+    ) -> Self {
         let ctx = input.ctx();
         let schema = input.schema().clone();
         let pk_indices = input.pk_indices().to_vec();
@@ -48,6 +60,7 @@ impl LogicalTopN {
             limit,
             offset,
             order,
+            group_key,
         }
     }
 
@@ -73,6 +86,10 @@ impl LogicalTopN {
     pub fn topn_order(&self) -> &Order {
         &self.order
     }
+
+    pub fn group_key(&self) -> &[usize] {
+        &self.group_key
+    }
 }
 
 impl PlanTreeNodeUnary for LogicalTopN {
@@ -81,7 +98,13 @@ impl PlanTreeNodeUnary for LogicalTopN {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        Self::new(input, self.limit, self.offset, self.order.clone())
+        Self::with_group(
+            input,
+            self.limit,
+            self.offset,
+            self.order.clone(),
+            self.group_key.clone(),
+        )
     }
 
     #[must_use]
@@ -91,13 +114,17 @@ impl PlanTreeNodeUnary for LogicalTopN {
         input_col_change: ColIndexMapping,
     ) -> (Self, ColIndexMapping) {
         (
-            Self::new(
+            Self::with_group(
                 input,
                 self.limit,
                 self.offset,
                 input_col_change
                     .rewrite_required_order(&self.order)
                     .unwrap(),
+                self.group_key
+                    .iter()
+                    .map(|idx| input_col_change.map(*idx))
+                    .collect(),
             ),
             input_col_change,
         )
@@ -108,9 +135,13 @@ impl fmt::Display for LogicalTopN {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "LogicalTopN {{ order: {}, limit: {}, offset: {} }}",
+            "LogicalTopN {{ order: {}, limit: {}, offset: {}",
             &self.order, &self.limit, &self.offset,
-        )
+        )?;
+        if !self.group_key.is_empty() {
+            write!(f, ", group_key: {:?}", &self.group_key)?;
+        }
+        write!(f, " }}")
     }
 }
 
@@ -123,6 +154,9 @@ impl ColPrunable for LogicalTopN {
                 .field_order
                 .iter()
                 .for_each(|fo| order_required_cols.insert(fo.index));
+            self.group_key
+                .iter()
+                .for_each(|idx| order_required_cols.insert(*idx));
             order_required_cols
         };
 
@@ -146,8 +180,10 @@ impl ColPrunable for LogicalTopN {
                 })
                 .collect(),
         };
+        let new_group_key = self.group_key.iter().map(|idx| mapping.map(*idx)).collect();
         let new_input = self.input.prune_col(&input_required_cols);
-        let top_n = Self::new(new_input, self.limit, self.offset, new_order).into();
+        let top_n =
+            Self::with_group(new_input, self.limit, self.offset, new_order, new_group_key).into();
 
         if input_required_cols == required_cols {
             top_n
@@ -174,6 +210,13 @@ impl ToBatch for LogicalTopN {
     }
 
     fn to_batch_with_order_required(&self, required_order: &Order) -> Result<PlanRef> {
+        if !self.group_key.is_empty() {
+            return Err(ErrorCode::NotImplemented(
+                "group top-n in batch queries".to_string(),
+                None.into(),
+            )
+            .into());
+        }
         let new_input = self.input().to_batch()?;
         let new_logical = self.clone_with_input(new_input);
         let ret = BatchTopN::new(new_logical).into();
@@ -188,11 +231,28 @@ impl ToBatch for LogicalTopN {
 
 impl ToStream for LogicalTopN {
     fn to_stream(&self) -> Result<PlanRef> {
+        if self.limit == 0 {
+            return Err(ErrorCode::NotImplemented(
+                "LIMIT 0 in streaming queries".to_string(),
+                None.into(),
+            )
+            .into());
+        }
         // Unlike `BatchTopN`, `StreamTopN` cannot guarantee the output order
-        let input = self
-            .input()
-            .to_stream_with_dist_required(&RequiredDist::single())?;
-        Ok(StreamTopN::new(self.clone_with_input(input)).into())
+        if self.group_key.is_empty() {
+            let input = self
+                .input()
+                .to_stream_with_dist_required(&RequiredDist::single())?;
+            Ok(StreamTopN::new(self.clone_with_input(input)).into())
+        } else {
+            let input = self
+                .input()
+                .to_stream_with_dist_required(&RequiredDist::shard_by_key(
+                    self.input().schema().len(),
+                    &self.group_key,
+                ))?;
+            Ok(StreamGroupTopN::new(self.clone_with_input(input)).into())
+        }
     }
 
     fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
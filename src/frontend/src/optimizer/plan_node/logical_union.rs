@@ -0,0 +1,180 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_common::error::Result;
+
+use super::{
+    BatchUnion, ColPrunable, LogicalProject, PlanBase, PlanRef, PlanTreeNode, PredicatePushdown,
+    StreamUnion, ToBatch, ToStream,
+};
+use crate::expr::{ExprImpl, InputRef, Literal};
+use crate::optimizer::property::{Distribution, RequiredDist};
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalUnion` returns all the rows of its inputs, which have the same schema, keeping the
+/// duplicates, i.e. `UNION ALL`. The other set operations are planned on top of it.
+#[derive(Debug, Clone)]
+pub struct LogicalUnion {
+    pub base: PlanBase,
+    inputs: Vec<PlanRef>,
+}
+
+impl LogicalUnion {
+    /// A union has no pk, as it can't tell apart the same rows from different inputs.
+    pub fn new(inputs: Vec<PlanRef>) -> Self {
+        Self::new_with_pk(inputs, vec![])
+    }
+
+    fn new_with_pk(inputs: Vec<PlanRef>, pk_indices: Vec<usize>) -> Self {
+        assert!(!inputs.is_empty());
+        let first = &inputs[0];
+        for input in &inputs[1..] {
+            assert_eq!(input.schema().data_types(), first.schema().data_types());
+        }
+        let base = PlanBase::new_logical(first.ctx(), first.schema().clone(), pk_indices);
+        LogicalUnion { base, inputs }
+    }
+
+    pub fn create(inputs: Vec<PlanRef>) -> PlanRef {
+        Self::new(inputs).into()
+    }
+
+    /// Clone with new inputs, keeping the pk.
+    pub fn clone_with_input_list(&self, inputs: Vec<PlanRef>) -> Self {
+        Self::new_with_pk(inputs, self.base.pk_indices.clone())
+    }
+}
+
+impl PlanTreeNode for LogicalUnion {
+    fn inputs(&self) -> smallvec::SmallVec<[crate::optimizer::PlanRef; 2]> {
+        self.inputs.iter().cloned().collect()
+    }
+
+    fn clone_with_inputs(&self, inputs: &[crate::optimizer::PlanRef]) -> crate::optimizer::PlanRef {
+        self.clone_with_input_list(inputs.to_vec()).into()
+    }
+}
+
+impl fmt::Display for LogicalUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LogicalUnion {{ all: true }}")
+    }
+}
+
+impl ColPrunable for LogicalUnion {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.prune_col(required_cols))
+            .collect();
+        Self::new(inputs).into()
+    }
+}
+
+impl PredicatePushdown for LogicalUnion {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        // The inputs have the same columns as the union.
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.predicate_pushdown(predicate.clone()))
+            .collect();
+        self.clone_with_input_list(inputs).into()
+    }
+}
+
+impl ToBatch for LogicalUnion {
+    fn to_batch(&self) -> Result<PlanRef> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.to_batch())
+            .try_collect()?;
+        Ok(BatchUnion::new(self.clone_with_input_list(inputs)).into())
+    }
+}
+
+impl ToStream for LogicalUnion {
+    fn to_stream_with_dist_required(&self, required_dist: &RequiredDist) -> Result<PlanRef> {
+        // All the inputs must be partitioned in the same way, so that the same rows from different
+        // inputs end up in the same partition of the union.
+        let dist = match required_dist {
+            RequiredDist::PhysicalDist(
+                dist @ (Distribution::Single | Distribution::HashShard(_)),
+            ) => dist.clone(),
+            RequiredDist::ShardByKey(keys) => Distribution::HashShard(keys.ones().collect()),
+            _ if self.pk_indices().is_empty() => Distribution::Single,
+            _ => Distribution::HashShard(self.pk_indices().to_vec()),
+        };
+        let input_required = RequiredDist::PhysicalDist(dist);
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| input.to_stream_with_dist_required(&input_required))
+            .try_collect()?;
+        Ok(StreamUnion::new(self.clone_with_input_list(inputs)).into())
+    }
+
+    fn to_stream(&self) -> Result<PlanRef> {
+        self.to_stream_with_dist_required(&RequiredDist::Any)
+    }
+
+    /// The rows from different inputs are told apart by the pk of the input they come from, and
+    /// the index of the input. They are appended as hidden columns to each input, where the pk
+    /// columns of the other inputs are filled with nulls, and make up the pk of the union.
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        let column_num = self.schema().len();
+        let rewritten: Vec<(PlanRef, ColIndexMapping)> = self
+            .inputs
+            .iter()
+            .map(|input| input.logical_rewrite_for_stream())
+            .try_collect()?;
+
+        let inputs = rewritten
+            .iter()
+            .enumerate()
+            .map(|(i, (input, col_change))| {
+                let input_schema = input.schema();
+                let columns = (0..column_num).map(|idx| {
+                    let idx = col_change.map(idx);
+                    ExprImpl::from(InputRef::new(idx, input_schema.fields()[idx].data_type()))
+                });
+                let pk_columns = rewritten.iter().enumerate().flat_map(|(j, (other, _))| {
+                    other.pk_indices().iter().map(move |&idx| {
+                        let data_type = other.schema().fields()[idx].data_type();
+                        match i == j {
+                            true => ExprImpl::from(InputRef::new(idx, data_type)),
+                            false => Literal::new(None, data_type).into(),
+                        }
+                    })
+                });
+                let exprs = columns
+                    .chain(pk_columns)
+                    .chain(std::iter::once(ExprImpl::literal_int(i as i32)))
+                    .collect();
+                LogicalProject::create(input.clone(), exprs)
+            })
+            .collect_vec();
+
+        let new_column_num = inputs[0].schema().len();
+        let union = Self::new_with_pk(inputs, (column_num..new_column_num).collect());
+        let out_col_change =
+            ColIndexMapping::with_target_size((0..column_num).map(Some).collect(), new_column_num);
+        Ok((union.into(), out_col_change))
+    }
+}
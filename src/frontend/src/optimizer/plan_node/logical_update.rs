@@ -22,6 +22,7 @@ use super::{
     gen_filter_and_pushdown, BatchUpdate, ColPrunable, PlanBase, PlanRef, PlanTreeNodeUnary,
     PredicatePushdown, ToBatch, ToStream,
 };
+use crate::binder::BoundUniqueIndex;
 use crate::catalog::TableId;
 use crate::expr::ExprImpl;
 use crate::utils::Condition;
@@ -35,6 +36,7 @@ pub struct LogicalUpdate {
     pub base: PlanBase,
     table_source_name: String, // explain-only
     source_id: TableId,        // TODO: use SourceId
+    unique_indexes: Vec<BoundUniqueIndex>,
     input: PlanRef,
     exprs: Vec<ExprImpl>,
 }
@@ -45,6 +47,7 @@ impl LogicalUpdate {
         input: PlanRef,
         table_source_name: String,
         source_id: TableId,
+        unique_indexes: Vec<BoundUniqueIndex>,
         exprs: Vec<ExprImpl>,
     ) -> Self {
         let ctx = input.ctx();
@@ -55,6 +58,7 @@ impl LogicalUpdate {
             base,
             table_source_name,
             source_id,
+            unique_indexes,
             input,
             exprs,
         }
@@ -65,9 +69,16 @@ impl LogicalUpdate {
         input: PlanRef,
         table_source_name: String,
         source_id: TableId,
+        unique_indexes: Vec<BoundUniqueIndex>,
         exprs: Vec<ExprImpl>,
     ) -> Result<Self> {
-        Ok(Self::new(input, table_source_name, source_id, exprs))
+        Ok(Self::new(
+            input,
+            table_source_name,
+            source_id,
+            unique_indexes,
+            exprs,
+        ))
     }
 
     pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
@@ -84,6 +95,12 @@ impl LogicalUpdate {
         self.source_id
     }
 
+    /// Get the unique indexes on the table, whose keys refer to the input columns without the row
+    /// id.
+    pub fn unique_indexes(&self) -> &[BoundUniqueIndex] {
+        &self.unique_indexes
+    }
+
     pub fn exprs(&self) -> &[ExprImpl] {
         self.exprs.as_ref()
     }
@@ -99,6 +116,7 @@ impl PlanTreeNodeUnary for LogicalUpdate {
             input,
             self.table_source_name.clone(),
             self.source_id,
+            self.unique_indexes.clone(),
             self.exprs.clone(),
         )
     }
@@ -0,0 +1,108 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+
+use super::{
+    BatchWorkTableScan, ColPrunable, LogicalFilter, LogicalProject, PlanBase, PlanRef,
+    PredicatePushdown, ToBatch, ToStream,
+};
+use crate::session::OptimizerContextRef;
+use crate::utils::{ColIndexMapping, Condition};
+
+/// `LogicalWorkTableScan` returns the rows produced by the previous evaluation of the recursive
+/// term of the enclosing [`super::LogicalRecursiveUnion`] with the same `work_table_id`.
+#[derive(Debug, Clone)]
+pub struct LogicalWorkTableScan {
+    pub base: PlanBase,
+    work_table_id: u32,
+}
+
+impl LogicalWorkTableScan {
+    pub fn new(work_table_id: u32, schema: Schema, ctx: OptimizerContextRef) -> Self {
+        let base = PlanBase::new_logical(ctx, schema, vec![]);
+        Self {
+            base,
+            work_table_id,
+        }
+    }
+
+    pub fn create(work_table_id: u32, schema: Schema, ctx: OptimizerContextRef) -> PlanRef {
+        Self::new(work_table_id, schema, ctx).into()
+    }
+
+    pub fn work_table_id(&self) -> u32 {
+        self.work_table_id
+    }
+}
+
+impl_plan_tree_node_for_leaf! { LogicalWorkTableScan }
+
+impl fmt::Display for LogicalWorkTableScan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "LogicalWorkTableScan {{ work_table_id: {} }}",
+            self.work_table_id
+        )
+    }
+}
+
+impl ColPrunable for LogicalWorkTableScan {
+    fn prune_col(&self, required_cols: &[usize]) -> PlanRef {
+        // The work table always has all the columns of the recursive union.
+        let column_num = self.schema().len();
+        if required_cols.len() == column_num
+            && required_cols.iter().enumerate().all(|(i, &col)| i == col)
+        {
+            return self.clone().into();
+        }
+        LogicalProject::with_mapping(
+            self.clone().into(),
+            ColIndexMapping::with_remaining_columns(required_cols, column_num),
+        )
+        .into()
+    }
+}
+
+impl PredicatePushdown for LogicalWorkTableScan {
+    fn predicate_pushdown(&self, predicate: Condition) -> PlanRef {
+        LogicalFilter::create(self.clone().into(), predicate)
+    }
+}
+
+impl ToBatch for LogicalWorkTableScan {
+    fn to_batch(&self) -> Result<PlanRef> {
+        Ok(BatchWorkTableScan::new(self.clone()).into())
+    }
+}
+
+impl ToStream for LogicalWorkTableScan {
+    fn to_stream(&self) -> Result<PlanRef> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "WITH RECURSIVE in streaming queries".to_string(),
+            None.into(),
+        )))
+    }
+
+    fn logical_rewrite_for_stream(&self) -> Result<(PlanRef, ColIndexMapping)> {
+        Err(RwError::from(ErrorCode::NotImplemented(
+            "WITH RECURSIVE in streaming queries".to_string(),
+            None.into(),
+        )))
+    }
+}
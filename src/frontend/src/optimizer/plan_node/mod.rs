@@ -208,7 +208,6 @@ pub use predicate_pushdown::*;
 mod batch_delete;
 mod batch_exchange;
 mod batch_filter;
-mod batch_generate_series;
 mod batch_hash_agg;
 mod batch_hash_join;
 mod batch_hop_window;
@@ -216,46 +215,63 @@ mod batch_insert;
 mod batch_limit;
 mod batch_nested_loop_join;
 mod batch_project;
+mod batch_project_set;
+mod batch_recursive_union;
 mod batch_seq_scan;
 mod batch_simple_agg;
 mod batch_sort;
+mod batch_table_function;
 mod batch_topn;
+mod batch_union;
 mod batch_update;
 mod batch_values;
+mod batch_work_table_scan;
 mod logical_agg;
 mod logical_apply;
 mod logical_delete;
+mod logical_dynamic_filter;
 mod logical_filter;
-mod logical_generate_series;
 mod logical_hop_window;
 mod logical_insert;
 mod logical_join;
 mod logical_limit;
 mod logical_multi_join;
+mod logical_now;
+mod logical_over_agg;
 mod logical_project;
+mod logical_project_set;
+mod logical_recursive_union;
 mod logical_scan;
 mod logical_source;
+mod logical_table_function;
 mod logical_topn;
+mod logical_union;
 mod logical_update;
 mod logical_values;
+mod logical_work_table_scan;
 mod stream_delta_join;
+mod stream_dynamic_filter;
 mod stream_exchange;
 mod stream_filter;
+mod stream_group_topn;
 mod stream_hash_agg;
 mod stream_hash_join;
 mod stream_hop_window;
 mod stream_index_scan;
 mod stream_materialize;
+mod stream_nested_loop_join;
+mod stream_now;
 mod stream_project;
+mod stream_project_set;
 mod stream_simple_agg;
 mod stream_source;
 mod stream_table_scan;
 mod stream_topn;
+mod stream_union;
 
 pub use batch_delete::BatchDelete;
 pub use batch_exchange::BatchExchange;
 pub use batch_filter::BatchFilter;
-pub use batch_generate_series::BatchGenerateSeries;
 pub use batch_hash_agg::BatchHashAgg;
 pub use batch_hash_join::BatchHashJoin;
 pub use batch_hop_window::BatchHopWindow;
@@ -263,41 +279,59 @@ pub use batch_insert::BatchInsert;
 pub use batch_limit::BatchLimit;
 pub use batch_nested_loop_join::BatchNestedLoopJoin;
 pub use batch_project::BatchProject;
+pub use batch_project_set::BatchProjectSet;
+pub use batch_recursive_union::BatchRecursiveUnion;
 pub use batch_seq_scan::BatchSeqScan;
 pub use batch_simple_agg::BatchSimpleAgg;
 pub use batch_sort::BatchSort;
+pub use batch_table_function::BatchTableFunction;
 pub use batch_topn::BatchTopN;
+pub use batch_union::BatchUnion;
 pub use batch_update::BatchUpdate;
 pub use batch_values::BatchValues;
+pub use batch_work_table_scan::BatchWorkTableScan;
 pub use logical_agg::{LogicalAgg, PlanAggCall};
 pub use logical_apply::LogicalApply;
 pub use logical_delete::LogicalDelete;
+pub use logical_dynamic_filter::LogicalDynamicFilter;
 pub use logical_filter::LogicalFilter;
-pub use logical_generate_series::LogicalGenerateSeries;
 pub use logical_hop_window::LogicalHopWindow;
 pub use logical_insert::LogicalInsert;
 pub use logical_join::LogicalJoin;
 pub use logical_limit::LogicalLimit;
-pub use logical_multi_join::LogicalMultiJoin;
+pub use logical_multi_join::{JoinTree, LogicalMultiJoin};
+pub use logical_now::LogicalNow;
+pub use logical_over_agg::{LogicalOverAgg, PlanWindowFunction};
 pub use logical_project::LogicalProject;
+pub use logical_project_set::LogicalProjectSet;
+pub use logical_recursive_union::LogicalRecursiveUnion;
 pub use logical_scan::LogicalScan;
 pub use logical_source::LogicalSource;
+pub use logical_table_function::LogicalTableFunction;
 pub use logical_topn::LogicalTopN;
+pub use logical_union::LogicalUnion;
 pub use logical_update::LogicalUpdate;
 pub use logical_values::LogicalValues;
+pub use logical_work_table_scan::LogicalWorkTableScan;
 pub use stream_delta_join::StreamDeltaJoin;
+pub use stream_dynamic_filter::StreamDynamicFilter;
 pub use stream_exchange::StreamExchange;
 pub use stream_filter::StreamFilter;
+pub use stream_group_topn::StreamGroupTopN;
 pub use stream_hash_agg::StreamHashAgg;
 pub use stream_hash_join::StreamHashJoin;
 pub use stream_hop_window::StreamHopWindow;
 pub use stream_index_scan::StreamIndexScan;
 pub use stream_materialize::StreamMaterialize;
+pub use stream_nested_loop_join::{IntervalJoinBound, StreamNestedLoopJoin};
+pub use stream_now::StreamNow;
 pub use stream_project::StreamProject;
+pub use stream_project_set::StreamProjectSet;
 pub use stream_simple_agg::StreamSimpleAgg;
 pub use stream_source::StreamSource;
 pub use stream_table_scan::StreamTableScan;
 pub use stream_topn::StreamTopN;
+pub use stream_union::StreamUnion;
 
 use crate::session::OptimizerContextRef;
 
@@ -332,8 +366,15 @@ macro_rules! for_all_plan_nodes {
             , { Logical, Limit }
             , { Logical, TopN }
             , { Logical, HopWindow }
-            , { Logical, GenerateSeries }
+            , { Logical, TableFunction }
             , { Logical, MultiJoin }
+            , { Logical, ProjectSet }
+            , { Logical, Now }
+            , { Logical, DynamicFilter }
+            , { Logical, Union }
+            , { Logical, OverAgg }
+            , { Logical, RecursiveUnion }
+            , { Logical, WorkTableScan }
             // , { Logical, Sort } we don't need a LogicalSort, just require the Order
             , { Batch, SimpleAgg }
             , { Batch, HashAgg }
@@ -351,7 +392,11 @@ macro_rules! for_all_plan_nodes {
             , { Batch, Limit }
             , { Batch, TopN }
             , { Batch, HopWindow }
-            , { Batch, GenerateSeries }
+            , { Batch, TableFunction }
+            , { Batch, ProjectSet }
+            , { Batch, Union }
+            , { Batch, RecursiveUnion }
+            , { Batch, WorkTableScan }
             , { Stream, Project }
             , { Stream, Filter }
             , { Stream, TableScan }
@@ -365,6 +410,12 @@ macro_rules! for_all_plan_nodes {
             , { Stream, HopWindow }
             , { Stream, DeltaJoin }
             , { Stream, IndexScan }
+            , { Stream, ProjectSet }
+            , { Stream, Now }
+            , { Stream, DynamicFilter }
+            , { Stream, Union }
+            , { Stream, GroupTopN }
+            , { Stream, NestedLoopJoin }
         }
     };
 }
@@ -389,8 +440,15 @@ macro_rules! for_logical_plan_nodes {
             , { Logical, Limit }
             , { Logical, TopN }
             , { Logical, HopWindow }
-            , { Logical, GenerateSeries }
+            , { Logical, TableFunction }
             , { Logical, MultiJoin }
+            , { Logical, ProjectSet }
+            , { Logical, Now }
+            , { Logical, DynamicFilter }
+            , { Logical, Union }
+            , { Logical, OverAgg }
+            , { Logical, RecursiveUnion }
+            , { Logical, WorkTableScan }
             // , { Logical, Sort} not sure if we will support Order by clause in subquery/view/MV
             // if we dont support thatk, we don't need LogicalSort, just require the Order at the top of query
         }
@@ -419,7 +477,11 @@ macro_rules! for_batch_plan_nodes {
             , { Batch, Delete }
             , { Batch, Update }
             , { Batch, HopWindow }
-            , { Batch, GenerateSeries }
+            , { Batch, TableFunction }
+            , { Batch, ProjectSet }
+            , { Batch, Union }
+            , { Batch, RecursiveUnion }
+            , { Batch, WorkTableScan }
         }
     };
 }
@@ -443,6 +505,12 @@ macro_rules! for_stream_plan_nodes {
             , { Stream, HopWindow }
             , { Stream, DeltaJoin }
             , { Stream, IndexScan }
+            , { Stream, ProjectSet }
+            , { Stream, Now }
+            , { Stream, DynamicFilter }
+            , { Stream, Union }
+            , { Stream, GroupTopN }
+            , { Stream, NestedLoopJoin }
         }
     };
 }
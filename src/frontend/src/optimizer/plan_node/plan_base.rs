@@ -17,6 +17,7 @@ use risingwave_common::catalog::Schema;
 
 use super::*;
 use crate::for_all_plan_nodes;
+use crate::optimizer::cost_model::estimate_row_width;
 use crate::optimizer::property::{Distribution, Order};
 use crate::session::OptimizerContextRef;
 
@@ -93,6 +94,11 @@ impl PlanBase {
             append_only: true,
         }
     }
+
+    /// Estimated average size in bytes of an output row, used by the cost model.
+    pub fn row_width(&self) -> f64 {
+        estimate_row_width(&self.schema)
+    }
 }
 macro_rules! impl_base_delegate {
     ([], $( { $convention:ident, $name:ident }),*) => {
@@ -0,0 +1,84 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::DynamicFilterNode;
+
+use super::{LogicalDynamicFilter, PlanBase, PlanRef, PlanTreeNodeBinary, ToStreamProst};
+
+/// [`StreamDynamicFilter`] implements [`LogicalDynamicFilter`]. The left side keeps its
+/// distribution and the right side is broadcast to every parallel unit of it. The left rows are
+/// kept in the state ordered by the compared column, so a change of the right value only scans and
+/// updates the rows between the old and the new values.
+#[derive(Debug, Clone)]
+pub struct StreamDynamicFilter {
+    pub base: PlanBase,
+    logical: LogicalDynamicFilter,
+}
+
+impl StreamDynamicFilter {
+    pub fn new(logical: LogicalDynamicFilter) -> Self {
+        let ctx = logical.base.ctx.clone();
+        // Rows are deleted when they no longer pass the filter, so the output is never append-only.
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            logical.base.pk_indices.to_vec(),
+            logical.left().distribution().clone(),
+            false,
+        );
+        Self { base, logical }
+    }
+}
+
+impl fmt::Display for StreamDynamicFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "StreamDynamicFilter")
+    }
+}
+
+impl PlanTreeNodeBinary for StreamDynamicFilter {
+    fn left(&self) -> PlanRef {
+        self.logical.left()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_left_right(left, right))
+    }
+}
+
+impl_plan_tree_node_for_binary! { StreamDynamicFilter }
+
+impl ToStreamProst for StreamDynamicFilter {
+    fn to_stream_prost_body(&self) -> NodeBody {
+        NodeBody::DynamicFilter(DynamicFilterNode {
+            left_key: self.logical.left_index() as u32,
+            comparator: self.logical.comparator() as i32,
+            left_distribution_keys: self
+                .left()
+                .distribution()
+                .dist_column_indices()
+                .iter()
+                .map(|idx| *idx as u32)
+                .collect(),
+            ..Default::default()
+        })
+    }
+}
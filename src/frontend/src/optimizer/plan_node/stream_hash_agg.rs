@@ -40,6 +40,8 @@ impl StreamHashAgg {
                 .i2o_col_mapping()
                 .rewrite_provided_distribution(input_dist),
             Distribution::SomeShard => Distribution::SomeShard,
+            // Every instance aggregates all the rows, so the results are the same.
+            Distribution::Broadcast => Distribution::Broadcast,
         };
         // Hash agg executor might change the append-only behavior of the stream.
         let base = PlanBase::new_stream(ctx, logical.schema().clone(), pk_indices, dist, false);
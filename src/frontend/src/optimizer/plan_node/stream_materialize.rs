@@ -113,6 +113,7 @@ impl StreamMaterialize {
                 let mut c = ColumnCatalog {
                     column_desc: ColumnDesc::from_field_without_column_id(field),
                     is_hidden: !user_cols.contains(i),
+                    description: None,
                 };
                 c.column_desc.name = if !c.is_hidden {
                     out_name_iter.next().unwrap()
@@ -165,7 +166,10 @@ impl StreamMaterialize {
             order_desc,
             pks: pk_indices.clone(),
             is_index_on,
+            is_unique_index: false,
             distribution_keys: base.dist.dist_column_indices().to_vec(),
+            statistics: None,
+            description: None,
         };
 
         Ok(Self { base, input, table })
@@ -0,0 +1,345 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_common::types::{DataType, IntervalUnit, ScalarImpl};
+use risingwave_pb::plan_common::JoinType;
+use risingwave_pb::stream_plan::stream_node::NodeBody;
+use risingwave_pb::stream_plan::{IntervalJoinBound as IntervalJoinBoundProst, NestedLoopJoinNode};
+
+use super::{LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, ToStreamProst};
+use crate::expr::{Expr, ExprImpl, ExprType};
+use crate::utils::Condition;
+
+/// [`StreamNestedLoopJoin`] implements [`super::LogicalJoin`] without equal join keys. The left
+/// side is sharded by its primary key and the right side is broadcast to every parallel unit of the
+/// left side, and each row is checked against all the rows kept from the other side. So each chunk
+/// costs a scan of the whole state of the other side, and the state is never cleaned.
+///
+/// If both inputs are append-only and the condition bounds the distance of two time columns, e.g.
+/// `l.ts BETWEEN r.ts - INTERVAL '5' MINUTE AND r.ts`, the rows that can no longer be joined are
+/// removed from the state, which makes it an interval join. The rows falling behind the others on
+/// their side by more than `rw_interval_join_allowed_lateness_ms` are dropped. Each chunk then only
+/// scans the rows of the other side within the bound of its times.
+#[derive(Debug, Clone)]
+pub struct StreamNestedLoopJoin {
+    pub base: PlanBase,
+    logical: LogicalJoin,
+    interval_bound: Option<IntervalJoinBound>,
+    allowed_lateness: IntervalUnit,
+}
+
+impl StreamNestedLoopJoin {
+    pub fn new(logical: LogicalJoin) -> Self {
+        assert_eq!(logical.join_type(), JoinType::Inner);
+        let ctx = logical.base.ctx.clone();
+        let append_only = logical.left().append_only() && logical.right().append_only();
+        let dist = logical
+            .l2i_col_mapping()
+            .composite(&logical.i2o_col_mapping())
+            .rewrite_provided_distribution(logical.left().distribution());
+        let interval_bound = if append_only {
+            IntervalJoinBound::extract(logical.on(), logical.left().schema().len())
+        } else {
+            None
+        };
+        let allowed_lateness = IntervalUnit::from_millis(
+            ctx.inner()
+                .session_ctx
+                .config()
+                .interval_join_allowed_lateness_ms() as i64,
+        );
+
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            logical.base.pk_indices.to_vec(),
+            dist,
+            append_only,
+        );
+
+        Self {
+            base,
+            logical,
+            interval_bound,
+            allowed_lateness,
+        }
+    }
+
+    /// Get join type
+    pub fn join_type(&self) -> JoinType {
+        self.logical.join_type()
+    }
+
+    pub fn interval_bound(&self) -> Option<&IntervalJoinBound> {
+        self.interval_bound.as_ref()
+    }
+}
+
+impl fmt::Display for StreamNestedLoopJoin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StreamNestedLoopJoin {{ type: {:?}, predicate: {}",
+            self.logical.join_type(),
+            self.logical.on()
+        )?;
+        if let Some(bound) = &self.interval_bound {
+            write!(
+                f,
+                ", interval_join: {{ left_time: ${}, right_time: ${} }}",
+                bound.left_time_col,
+                self.logical.left().schema().len() + bound.right_time_col
+            )?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl PlanTreeNodeBinary for StreamNestedLoopJoin {
+    fn left(&self) -> PlanRef {
+        self.logical.left()
+    }
+
+    fn right(&self) -> PlanRef {
+        self.logical.right()
+    }
+
+    fn clone_with_left_right(&self, left: PlanRef, right: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_left_right(left, right))
+    }
+}
+
+impl_plan_tree_node_for_binary! { StreamNestedLoopJoin }
+
+impl ToStreamProst for StreamNestedLoopJoin {
+    fn to_stream_prost_body(&self) -> NodeBody {
+        NodeBody::NestedLoopJoin(NestedLoopJoinNode {
+            join_type: self.logical.join_type() as i32,
+            condition: Some(ExprImpl::from(self.logical.on().clone()).to_expr_proto()),
+            interval_bound: self
+                .interval_bound
+                .as_ref()
+                .map(|bound| bound.to_protobuf(self.allowed_lateness)),
+            left_distribution_keys: self
+                .left()
+                .distribution()
+                .dist_column_indices()
+                .iter()
+                .map(|idx| *idx as u32)
+                .collect(),
+            ..Default::default()
+        })
+    }
+}
+
+/// The bounds of `left_time - right_time` implied by a join condition.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalJoinBound {
+    /// Index of the time column in the left input.
+    pub left_time_col: usize,
+    /// Index of the time column in the right input.
+    pub right_time_col: usize,
+    /// `left_time >= right_time + lower`
+    pub lower: Option<IntervalUnit>,
+    /// `left_time <= right_time + upper`
+    pub upper: Option<IntervalUnit>,
+}
+
+impl IntervalJoinBound {
+    /// Finds the conjunctions comparing a timestamp column of each side, which may be shifted by
+    /// interval constants. Only the first pair of columns found is used.
+    pub fn extract(on: &Condition, left_col_num: usize) -> Option<Self> {
+        let mut bound: Option<Self> = None;
+        for expr in &on.conjunctions {
+            let Some((cmp, left_time_col, right_time_col, offset)) =
+                Self::extract_one(expr, left_col_num) else {
+                continue;
+            };
+            let bound = bound.get_or_insert(Self {
+                left_time_col,
+                right_time_col,
+                lower: None,
+                upper: None,
+            });
+            if (bound.left_time_col, bound.right_time_col) != (left_time_col, right_time_col) {
+                continue;
+            }
+            match cmp {
+                ExprType::GreaterThan | ExprType::GreaterThanOrEqual => {
+                    bound.lower.get_or_insert(offset);
+                }
+                ExprType::LessThan | ExprType::LessThanOrEqual => {
+                    bound.upper.get_or_insert(offset);
+                }
+                _ => unreachable!(),
+            }
+        }
+        bound
+    }
+
+    /// Matches `left_time + a <cmp> right_time + b`, with the sides in either order, and returns
+    /// it as `left_time <cmp> right_time + (b - a)`.
+    fn extract_one(
+        expr: &ExprImpl,
+        left_col_num: usize,
+    ) -> Option<(ExprType, usize, usize, IntervalUnit)> {
+        let func_call = expr.as_function_call()?;
+        let cmp = func_call.get_expr_type();
+        let flipped = match cmp {
+            ExprType::LessThan => ExprType::GreaterThan,
+            ExprType::LessThanOrEqual => ExprType::GreaterThanOrEqual,
+            ExprType::GreaterThan => ExprType::LessThan,
+            ExprType::GreaterThanOrEqual => ExprType::LessThanOrEqual,
+            _ => return None,
+        };
+        let [lhs, rhs] = func_call.inputs() else {
+            return None;
+        };
+        let (lhs_col, lhs_offset) = time_col_with_offset(lhs)?;
+        let (rhs_col, rhs_offset) = time_col_with_offset(rhs)?;
+        if lhs_col < left_col_num && rhs_col >= left_col_num {
+            Some((
+                cmp,
+                lhs_col,
+                rhs_col - left_col_num,
+                rhs_offset - lhs_offset,
+            ))
+        } else if rhs_col < left_col_num && lhs_col >= left_col_num {
+            Some((
+                flipped,
+                rhs_col,
+                lhs_col - left_col_num,
+                lhs_offset - rhs_offset,
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_protobuf(&self, allowed_lateness: IntervalUnit) -> IntervalJoinBoundProst {
+        IntervalJoinBoundProst {
+            left_time_col: self.left_time_col as u32,
+            right_time_col: self.right_time_col as u32,
+            lower: self.lower.map(Into::into),
+            upper: self.upper.map(Into::into),
+            allowed_lateness: Some(allowed_lateness.into()),
+        }
+    }
+}
+
+/// Matches a timestamp column, optionally shifted by adding or subtracting an interval constant.
+fn time_col_with_offset(expr: &ExprImpl) -> Option<(usize, IntervalUnit)> {
+    match expr {
+        ExprImpl::InputRef(input_ref) if input_ref.return_type() == DataType::Timestamp => {
+            Some((input_ref.index(), IntervalUnit::default()))
+        }
+        ExprImpl::FunctionCall(func_call) => {
+            let (input_ref, literal) = match (func_call.get_expr_type(), func_call.inputs()) {
+                (
+                    ExprType::Add | ExprType::Subtract,
+                    [ExprImpl::InputRef(input_ref), ExprImpl::Literal(literal)],
+                ) => (input_ref, literal),
+                (ExprType::Add, [ExprImpl::Literal(literal), ExprImpl::InputRef(input_ref)]) => {
+                    (input_ref, literal)
+                }
+                _ => return None,
+            };
+            if input_ref.return_type() != DataType::Timestamp {
+                return None;
+            }
+            let Some(ScalarImpl::Interval(interval)) = literal.get_data() else {
+                return None;
+            };
+            match func_call.get_expr_type() {
+                ExprType::Add => Some((input_ref.index(), *interval)),
+                _ => Some((input_ref.index(), IntervalUnit::default() - *interval)),
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::{FunctionCall, InputRef, Literal};
+
+    fn ts(index: usize) -> ExprImpl {
+        InputRef::new(index, DataType::Timestamp).into()
+    }
+
+    fn shift(expr: ExprImpl, ty: ExprType, minutes: i64) -> ExprImpl {
+        let interval = Literal::new(
+            Some(ScalarImpl::Interval(IntervalUnit::from_minutes(minutes))),
+            DataType::Interval,
+        );
+        FunctionCall::new(ty, vec![expr, interval.into()])
+            .unwrap()
+            .into()
+    }
+
+    fn cmp(ty: ExprType, lhs: ExprImpl, rhs: ExprImpl) -> ExprImpl {
+        FunctionCall::new(ty, vec![lhs, rhs]).unwrap().into()
+    }
+
+    #[test]
+    fn test_extract_interval_bound() {
+        // $1 BETWEEN $3 - '5 minutes' AND $3, where $3 is the 2nd column of the right side.
+        let on = Condition {
+            conjunctions: vec![
+                cmp(
+                    ExprType::GreaterThanOrEqual,
+                    ts(1),
+                    shift(ts(3), ExprType::Subtract, 5),
+                ),
+                cmp(ExprType::LessThanOrEqual, ts(1), ts(3)),
+            ],
+        };
+        assert_eq!(
+            IntervalJoinBound::extract(&on, 2),
+            Some(IntervalJoinBound {
+                left_time_col: 1,
+                right_time_col: 1,
+                lower: Some(IntervalUnit::from_minutes(-5)),
+                upper: Some(IntervalUnit::default()),
+            })
+        );
+
+        // $3 + '10 minutes' > $1, i.e. $1 < $3 + '10 minutes'.
+        let on = Condition {
+            conjunctions: vec![cmp(
+                ExprType::GreaterThan,
+                shift(ts(3), ExprType::Add, 10),
+                ts(1),
+            )],
+        };
+        assert_eq!(
+            IntervalJoinBound::extract(&on, 2),
+            Some(IntervalJoinBound {
+                left_time_col: 1,
+                right_time_col: 1,
+                lower: None,
+                upper: Some(IntervalUnit::from_minutes(10)),
+            })
+        );
+
+        // Columns of the same side are not a bound.
+        let on = Condition {
+            conjunctions: vec![cmp(ExprType::LessThan, ts(0), ts(1))],
+        };
+        assert_eq!(IntervalJoinBound::extract(&on, 2), None);
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
+use risingwave_pb::stream_plan::NowNode;
+
+use super::{LogicalNow, PlanBase, ToStreamProst};
+use crate::optimizer::property::Distribution;
+
+/// [`StreamNow`] produces the value of `now()`, updated on every barrier. Like a source, it's at
+/// the very beginning of the graph.
+#[derive(Debug, Clone)]
+pub struct StreamNow {
+    pub base: PlanBase,
+}
+
+impl StreamNow {
+    pub fn new(logical: LogicalNow) -> Self {
+        let base = PlanBase::new_stream(
+            logical.ctx(),
+            logical.schema().clone(),
+            logical.pk_indices().to_vec(),
+            Distribution::Single,
+            false,
+        );
+        Self { base }
+    }
+}
+
+impl_plan_tree_node_for_leaf! { StreamNow }
+
+impl fmt::Display for StreamNow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StreamNow")
+    }
+}
+
+impl ToStreamProst for StreamNow {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::Now(NowNode {})
+    }
+}
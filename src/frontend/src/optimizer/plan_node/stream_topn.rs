@@ -20,6 +20,7 @@ use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
 
 use super::{LogicalTopN, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
 use crate::optimizer::property::Distribution;
+use crate::planner::LIMIT_ALL_COUNT;
 
 /// `StreamTopN` implements [`super::LogicalTopN`] to find the top N elements with a heap
 #[derive(Debug, Clone)]
@@ -87,12 +88,26 @@ impl ToStreamProst for StreamTopN {
                 return_type: Some(self.input().schema()[f.index].data_type().to_protobuf()),
             })
             .collect();
-        ProstStreamNode::TopN(TopNNode {
+        let top_n_node = TopNNode {
             column_orders,
-            limit: self.logical.limit() as u64,
+            limit: top_n_limit_to_prost(self.logical.limit()),
             offset: self.logical.offset() as u64,
             distribution_keys: vec![], // TODO: seems unnecessary
             ..Default::default()
-        })
+        };
+        if self.input().append_only() {
+            ProstStreamNode::AppendOnlyTopN(top_n_node)
+        } else {
+            ProstStreamNode::TopN(top_n_node)
+        }
+    }
+}
+
+/// The limit of Top-N nodes in protobuf, where 0 means no limit.
+pub(super) fn top_n_limit_to_prost(limit: usize) -> u64 {
+    if limit == LIMIT_ALL_COUNT {
+        0
+    } else {
+        limit as u64
     }
 }
@@ -0,0 +1,68 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::NodeBody as ProstStreamNode;
+use risingwave_pb::stream_plan::UnionNode;
+
+use super::{LogicalUnion, PlanBase, PlanRef, PlanTreeNode, ToStreamProst};
+
+/// `StreamUnion` implements [`super::LogicalUnion`] by merging the streams of its inputs, which
+/// are partitioned in the same way.
+#[derive(Debug, Clone)]
+pub struct StreamUnion {
+    pub base: PlanBase,
+    logical: LogicalUnion,
+}
+
+impl StreamUnion {
+    pub fn new(logical: LogicalUnion) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let inputs = logical.inputs();
+        let dist = inputs[0].distribution().clone();
+        assert!(inputs.iter().all(|input| *input.distribution() == dist));
+        let append_only = inputs.iter().all(|input| input.append_only());
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            logical.pk_indices().to_vec(),
+            dist,
+            append_only,
+        );
+        StreamUnion { base, logical }
+    }
+}
+
+impl fmt::Display for StreamUnion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StreamUnion {{ all: true }}")
+    }
+}
+
+impl PlanTreeNode for StreamUnion {
+    fn inputs(&self) -> smallvec::SmallVec<[PlanRef; 2]> {
+        self.logical.inputs()
+    }
+
+    fn clone_with_inputs(&self, inputs: &[PlanRef]) -> PlanRef {
+        Self::new(self.logical.clone_with_input_list(inputs.to_vec())).into()
+    }
+}
+
+impl ToStreamProst for StreamUnion {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::Union(UnionNode {})
+    }
+}
@@ -45,7 +45,7 @@
 use fixedbitset::FixedBitSet;
 use risingwave_common::error::Result;
 use risingwave_pb::batch_plan::exchange_info::{
-    Distribution as DistributionProst, DistributionMode, HashInfo,
+    BroadcastInfo, Distribution as DistributionProst, DistributionMode, HashInfo,
 };
 use risingwave_pb::batch_plan::ExchangeInfo;
 
@@ -64,6 +64,9 @@ pub enum Distribution {
     /// records are shard on partitions based on hash value of some keys, which means the records
     /// with same hash values must be on the same partition.
     HashShard(Vec<usize>),
+    /// every partition holds a full copy of the records. Only used in batch queries, to send
+    /// the small side of a join to every partition of the other side.
+    Broadcast,
 }
 
 /// the distribution property requirement.
@@ -87,6 +90,7 @@ impl Distribution {
             mode: match self {
                 Distribution::Single => DistributionMode::Single,
                 Distribution::HashShard(_) => DistributionMode::Hash,
+                Distribution::Broadcast => DistributionMode::Broadcast,
                 // TODO: add round robin DistributionMode
                 Distribution::SomeShard => DistributionMode::Single,
             } as i32,
@@ -96,6 +100,9 @@ impl Distribution {
                     output_count,
                     keys: keys.iter().map(|num| *num as u32).collect(),
                 })),
+                Distribution::Broadcast => Some(DistributionProst::BroadcastInfo(BroadcastInfo {
+                    count: output_count,
+                })),
                 // TODO: add round robin distribution
                 Distribution::SomeShard => None,
             },
@@ -106,7 +106,7 @@ impl fmt::Display for FieldOrder {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum Direction {
     Asc,
     Desc,
@@ -123,6 +123,15 @@ impl From<Direction> for OrderType {
     }
 }
 
+impl From<OrderType> for Direction {
+    fn from(order_type: OrderType) -> Self {
+        match order_type {
+            OrderType::Ascending => Direction::Asc,
+            OrderType::Descending => Direction::Desc,
+        }
+    }
+}
+
 impl fmt::Display for Direction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
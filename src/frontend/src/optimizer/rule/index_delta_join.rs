@@ -12,12 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashSet;
 use std::rc::Rc;
 
-use itertools::Itertools;
 use risingwave_pb::plan_common::JoinType;
 
+use super::super::cost_model::{CostModel, RelationSize, StreamJoinStrategy};
 use super::super::plan_node::*;
 use super::{BoxedRule, Rule};
 
@@ -52,75 +51,36 @@ impl Rule for IndexDeltaJoinRule {
         let right_indices = join.eq_join_predicate().right_eq_indexes();
 
         fn match_indexes(join_indices: &[usize], table_scan: &StreamTableScan) -> Option<PlanRef> {
-            if table_scan.logical().indexes().is_empty() {
-                return None;
-            }
-            let column_descs = table_scan.logical().column_descs();
-            // We assume column id of create index's MV is exactly the same as corresponding columns
-            // in the original table. We generate a list of column ids, and match them against
-            // prefixes of indexes.
-            let columns_to_match = join_indices
-                .iter()
-                .map(|idx| column_descs[*idx].column_id)
-                .collect_vec();
-
-            for (name, index) in table_scan.logical().indexes() {
-                // 1. Check if distribution keys are the same.
-                // We don't assume the hash function we are using satisfies commutativity
-                // `Hash(A, B) == Hash(B, A)`, so we consider order of each item in distribution
-                // keys here.
-                if index
-                    .distribution_keys
-                    .iter()
-                    .map(|x| index.columns[*x].column_id)
-                    .collect_vec()
-                    != columns_to_match
-                {
-                    continue;
-                }
-
-                // 2. Check if the join keys are prefix of order keys
-
-                // A HashSet containing remaining columns to match
-                let mut remaining_to_match =
-                    columns_to_match.iter().copied().collect::<HashSet<_>>();
-
-                // Begin match join columns with index prefix. e.g., if the join columns are `a, b,
-                // c`, and the index has `a, b, c` or `a, c, b` or any combination as prefix, then
-                // we can use this index.
-                for ordered_column in &index.order_desc {
-                    let column_id = ordered_column.column_desc.column_id;
-
-                    match remaining_to_match.remove(&column_id) {
-                        true => continue, // matched
-                        false => break,   // not matched
-                    }
-                }
-
-                if remaining_to_match.is_empty() {
-                    return Some(table_scan.to_index_scan(name, index).into());
-                }
-            }
-
-            None
+            let (name, index) = table_scan.logical().index_for_join_keys(join_indices)?;
+            Some(table_scan.to_index_scan(name, index).into())
         }
 
-        if let Some(left) = match_indexes(&left_indices, input_left) {
-            if let Some(right) = match_indexes(&right_indices, input_right) {
-                // We already ensured that index and join use the same distribution, so we directly
-                // replace the children with stream index scan without inserting any exchanges.
-
-                Some(
-                    join.to_delta_join()
-                        .clone_with_left_right(left, right)
-                        .into(),
-                )
-            } else {
-                Some(plan)
-            }
-        } else {
-            Some(plan)
+        let (left, right) = match (
+            match_indexes(&left_indices, input_left),
+            match_indexes(&right_indices, input_right),
+        ) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Some(plan),
+        };
+
+        // With both sides arranged by the indexes, convert to a delta join only if the cost model
+        // chooses it. Without the sizes of the inputs, the delta join is always preferred.
+        let size_of =
+            |table_scan: &StreamTableScan| RelationSize::of(&table_scan.logical().clone().into());
+        if let (Some(left_size), Some(right_size)) = (size_of(input_left), size_of(input_right))
+            && CostModel::stream(&plan.ctx()).choose_stream_join(left_size, right_size, (true, true))
+                != StreamJoinStrategy::Delta
+        {
+            return Some(plan);
         }
+
+        // We already ensured that index and join use the same distribution, so we directly
+        // replace the children with stream index scan without inserting any exchanges.
+        Some(
+            join.to_delta_join()
+                .clone_with_left_right(left, right)
+                .into(),
+        )
     }
 }
 
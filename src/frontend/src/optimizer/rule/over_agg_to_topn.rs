@@ -0,0 +1,135 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::ScalarImpl;
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::{ExprImpl, ExprType};
+use crate::utils::Condition;
+
+/// Converts a `row_number() OVER (PARTITION BY ... ORDER BY ...)` compared with constants into a
+/// [`LogicalTopN`] grouped by the partition keys.
+///
+/// The pattern is Project->Filter->OverAgg, where the project doesn't refer to the row number,
+/// as it's not computed by [`LogicalTopN`].
+pub struct OverAggToTopNRule {}
+impl Rule for OverAggToTopNRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let project = plan.as_logical_project()?;
+        let input = project.input();
+        let filter = input.as_logical_filter()?;
+        let input = filter.input();
+        let over_agg = input.as_logical_over_agg()?;
+        let [window_function] = over_agg.window_functions() else {
+            return None;
+        };
+        let rank_col = over_agg.input().schema().len();
+        if project
+            .exprs()
+            .iter()
+            .any(|expr| expr.collect_input_refs(rank_col + 1).contains(rank_col))
+        {
+            return None;
+        }
+
+        // The rows ranked in `(offset, upper]` are kept.
+        let mut offset = 0;
+        let mut upper = None;
+        let mut others = vec![];
+        for expr in &filter.predicate().conjunctions {
+            if !expr.collect_input_refs(rank_col + 1).contains(rank_col) {
+                others.push(expr.clone());
+                continue;
+            }
+            let (cmp, bound) = rank_bound(expr, rank_col)?;
+            let (lower_bound, upper_bound) = match cmp {
+                ExprType::LessThan => (None, Some(bound - 1)),
+                ExprType::LessThanOrEqual => (None, Some(bound)),
+                ExprType::Equal => (Some(bound - 1), Some(bound)),
+                ExprType::GreaterThan => (Some(bound), None),
+                ExprType::GreaterThanOrEqual => (Some(bound - 1), None),
+                _ => unreachable!(),
+            };
+            if let Some(lower_bound) = lower_bound {
+                offset = offset.max(lower_bound);
+            }
+            if let Some(upper_bound) = upper_bound {
+                upper = Some(upper.map_or(upper_bound, |upper: i64| upper.min(upper_bound)));
+            }
+        }
+        let limit = upper? - offset;
+        if limit <= 0 {
+            return None;
+        }
+
+        let top_n = LogicalTopN::with_group(
+            over_agg.input(),
+            limit as usize,
+            offset as usize,
+            window_function.order_by.clone(),
+            window_function.partition_by.clone(),
+        )
+        .into();
+        let filter = LogicalFilter::create(
+            top_n,
+            Condition {
+                conjunctions: others,
+            },
+        );
+        Some(LogicalProject::new(filter, project.exprs().clone()).into())
+    }
+}
+
+impl OverAggToTopNRule {
+    pub fn create() -> BoxedRule {
+        Box::new(OverAggToTopNRule {})
+    }
+}
+
+/// Matches `rank_col <cmp> constant` or `constant <cmp> rank_col`, and returns the comparison
+/// with the rank on the left.
+fn rank_bound(expr: &ExprImpl, rank_col: usize) -> Option<(ExprType, i64)> {
+    let func_call = expr.as_function_call()?;
+    let cmp = func_call.get_expr_type();
+    let flipped = match cmp {
+        ExprType::LessThan => ExprType::GreaterThan,
+        ExprType::LessThanOrEqual => ExprType::GreaterThanOrEqual,
+        ExprType::Equal => ExprType::Equal,
+        ExprType::GreaterThan => ExprType::LessThan,
+        ExprType::GreaterThanOrEqual => ExprType::LessThanOrEqual,
+        _ => return None,
+    };
+    let is_rank = |expr: &ExprImpl| matches!(expr, ExprImpl::InputRef(input_ref) if input_ref.index() == rank_col);
+    match func_call.inputs() {
+        [left, right] if is_rank(left) => Some((cmp, as_int_constant(right)?)),
+        [left, right] if is_rank(right) => Some((flipped, as_int_constant(left)?)),
+        _ => None,
+    }
+}
+
+fn as_int_constant(expr: &ExprImpl) -> Option<i64> {
+    match expr {
+        ExprImpl::Literal(literal) => match literal.get_data() {
+            Some(ScalarImpl::Int16(v)) => Some(*v as i64),
+            Some(ScalarImpl::Int32(v)) => Some(*v as i64),
+            Some(ScalarImpl::Int64(v)) => Some(*v),
+            _ => None,
+        },
+        ExprImpl::FunctionCall(func_call) if func_call.get_expr_type() == ExprType::Cast => {
+            as_int_constant(&func_call.inputs()[0])
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_pb::plan_common::JoinType;
+
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::{CorrelatedInputRef, Expr, ExprImpl, ExprRewriter, InputRef};
+use crate::optimizer::PlanRef;
+use crate::utils::Substitute;
+
+/// This rule is for pattern: Apply->Project->Values, where Values has exactly one row, e.g. the
+/// subquery `(select t.v1 + 1)` in `select (select t.v1 + 1) from t`.
+///
+/// As the right side always produces one row for each row from the left, the `Inner` or
+/// `LeftOuter` Apply can be replaced by a Project over its left child, which evaluates the exprs of
+/// the right Project with the correlated variables converted to `InputRef`s.
+pub struct PullUpCorrelatedProject {}
+impl Rule for PullUpCorrelatedProject {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let apply = plan.as_logical_apply()?;
+        let (apply_left, apply_right, apply_on, join_type) = apply.clone().decompose();
+        if !matches!(join_type, JoinType::Inner | JoinType::LeftOuter) || !apply_on.always_true() {
+            return None;
+        }
+
+        let project = apply_right.as_logical_project()?;
+        let input = project.input();
+        let values = input.as_logical_values()?;
+        if values.rows().len() != 1 {
+            return None;
+        }
+
+        let mut substitute = Substitute {
+            mapping: values.rows()[0].clone(),
+        };
+        let mut exprs: Vec<ExprImpl> = apply_left
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        exprs.extend(project.exprs().iter().map(|expr| {
+            let expr = substitute.rewrite_expr(expr.clone());
+            Rewriter {}.rewrite_expr(expr)
+        }));
+
+        Some(LogicalProject::create(apply_left, exprs))
+    }
+}
+
+/// Rewrites the `CorrelatedInputRef`s referring to the left side of the Apply to `InputRef`s, and
+/// decreases the depth of the others, as the expression is pulled up by one level.
+pub(super) struct Rewriter {}
+
+impl ExprRewriter for Rewriter {
+    fn rewrite_correlated_input_ref(
+        &mut self,
+        correlated_input_ref: CorrelatedInputRef,
+    ) -> ExprImpl {
+        if correlated_input_ref.depth() == 1 {
+            InputRef::new(
+                correlated_input_ref.index(),
+                correlated_input_ref.return_type(),
+            )
+            .into()
+        } else {
+            CorrelatedInputRef::new(
+                correlated_input_ref.index(),
+                correlated_input_ref.return_type(),
+                correlated_input_ref.depth() - 1,
+            )
+            .into()
+        }
+    }
+}
+
+impl PullUpCorrelatedProject {
+    pub fn create() -> BoxedRule {
+        Box::new(PullUpCorrelatedProject {})
+    }
+}
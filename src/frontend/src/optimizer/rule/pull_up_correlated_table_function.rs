@@ -0,0 +1,93 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::types::{DataType, Scalar};
+use risingwave_pb::plan_common::JoinType;
+
+use super::super::plan_node::*;
+use super::pull_up_correlated_project::Rewriter;
+use super::{BoxedRule, Rule};
+use crate::expr::{ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal};
+use crate::optimizer::PlanRef;
+
+/// This rule is for pattern: Apply->TableFunction, where the arguments of the table function refer
+/// to the left side, e.g. `generate_series(1, t.v1)` in `select * from t, generate_series(1,
+/// t.v1)`.
+///
+/// The `Inner` Apply is replaced by a `ProjectSet` over its left child, which evaluates the table
+/// function for each row from the left, with the correlated variables converted to `InputRef`s. A
+/// Project on top removes `projected_row_id` and expands a set of structs into the fields, and a
+/// Filter evaluates the `on` condition of the Apply.
+pub struct PullUpCorrelatedTableFunction {}
+impl Rule for PullUpCorrelatedTableFunction {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let apply = plan.as_logical_apply()?;
+        let (apply_left, apply_right, apply_on, join_type) = apply.clone().decompose();
+        if join_type != JoinType::Inner {
+            return None;
+        }
+        let mut table_function = apply_right
+            .as_logical_table_function()?
+            .table_function()
+            .clone();
+        table_function.args = table_function
+            .args
+            .into_iter()
+            .map(|arg| Rewriter {}.rewrite_expr(arg))
+            .collect();
+        let output_fields = table_function.output_fields();
+        let return_type = table_function.return_type.clone();
+
+        let left_exprs = apply_left
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect::<Vec<ExprImpl>>();
+        let left_len = left_exprs.len();
+        let mut select_list = left_exprs;
+        select_list.push(table_function.into());
+        let project_set = LogicalProjectSet::new(apply_left, select_list);
+
+        // The first column of the ProjectSet is `projected_row_id`.
+        let mut exprs: Vec<ExprImpl> = (1..=left_len)
+            .map(|i| InputRef::new(i, project_set.schema().fields()[i].data_type()).into())
+            .collect();
+        let set = InputRef::new(left_len + 1, return_type);
+        if output_fields.len() == 1 {
+            exprs.push(set.into());
+        } else {
+            exprs.extend(output_fields.into_iter().enumerate().map(|(i, field)| {
+                FunctionCall::new_unchecked(
+                    ExprType::Field,
+                    vec![
+                        set.clone().into(),
+                        Literal::new(Some((i as i32).to_scalar_value()), DataType::Int32).into(),
+                    ],
+                    field.data_type,
+                )
+                .into()
+            }));
+        }
+        let project = LogicalProject::create(project_set.into(), exprs);
+        Some(LogicalFilter::create(project, apply_on))
+    }
+}
+
+impl PullUpCorrelatedTableFunction {
+    pub fn create() -> BoxedRule {
+        Box::new(PullUpCorrelatedTableFunction {})
+    }
+}
@@ -14,14 +14,21 @@
 
 use super::super::plan_node::*;
 use super::Rule;
+use crate::optimizer::cost_model::CostModel;
 use crate::optimizer::rule::BoxedRule;
 
-/// Reorders a multi join into a left deep join via the heuristic ordering
-pub struct ReorderMultiJoinRule {}
+/// Reorders a multi join into the join tree with the least cost under the cost model, or into a
+/// left deep join via the heuristic ordering if the sizes of its inputs can't be estimated.
+pub struct ReorderMultiJoinRule {
+    cost_model: CostModel,
+}
 
 impl Rule for ReorderMultiJoinRule {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let join = plan.as_logical_multi_join()?;
+        if let Some(join_tree) = join.cost_based_join_tree(&self.cost_model) {
+            return Some(join.as_reordered_join_tree(&join_tree));
+        }
         // check if join is inner and can be merged into multijoin
         let join_ordering = join.heuristic_ordering().ok()?; // maybe panic here instead?
         let left_deep_join = join.as_reordered_left_deep_join(&join_ordering);
@@ -30,8 +37,8 @@ impl Rule for ReorderMultiJoinRule {
 }
 
 impl ReorderMultiJoinRule {
-    pub fn create() -> BoxedRule {
-        Box::new(ReorderMultiJoinRule {})
+    pub fn create(cost_model: CostModel) -> BoxedRule {
+        Box::new(ReorderMultiJoinRule { cost_model })
     }
 }
 
@@ -39,13 +46,13 @@ impl ReorderMultiJoinRule {
 mod tests {
     use itertools::Itertools;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_pb::expr::expr_node::Type;
     use risingwave_pb::plan_common::JoinType;
 
     use super::*;
-    use crate::expr::{ExprImpl, FunctionCall, InputRef};
-    use crate::session::OptimizerContext;
+    use crate::expr::{ExprImpl, FunctionCall, InputRef, Literal};
+    use crate::session::{OptimizerContext, OptimizerContextRef};
     use crate::utils::Condition;
 
     #[tokio::test]
@@ -131,4 +138,97 @@ mod tests {
 
         assert_eq!(multi_join.heuristic_ordering().unwrap(), vec![0, 2, 1]);
     }
+
+    fn values_with_rows(row_count: usize, fields: &[Field], ctx: OptimizerContextRef) -> PlanRef {
+        let rows = (0..row_count)
+            .map(|i| {
+                fields
+                    .iter()
+                    .map(|field| {
+                        Literal::new(Some(ScalarImpl::Int32(i as i32)), field.data_type()).into()
+                    })
+                    .collect()
+            })
+            .collect();
+        LogicalValues::new(
+            rows,
+            Schema {
+                fields: fields.to_vec(),
+            },
+            ctx,
+        )
+        .into()
+    }
+
+    fn eq(left: usize, right: usize) -> ExprImpl {
+        FunctionCall::new(
+            Type::Equal,
+            vec![
+                InputRef::new(left, DataType::Int32).into(),
+                InputRef::new(right, DataType::Int32).into(),
+            ],
+        )
+        .unwrap()
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_cost_based_join_reorder_from_multijoin() {
+        // Joins a chain
+        // A(100)-B(10)-C(1)
+        //
+        // For batch, the smaller side of each join should be built, and A should never be cross
+        // joined with C.
+
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = (1..4)
+            .map(|i| Field::with_name(ty.clone(), format!("v{}", i)))
+            .collect();
+        let relation_a = values_with_rows(100, &fields[0..1], ctx.clone());
+        let relation_b = values_with_rows(10, &fields[1..2], ctx.clone());
+        let relation_c = values_with_rows(1, &fields[2..3], ctx.clone());
+
+        let join_0 = LogicalJoin::new(
+            relation_a,
+            relation_b,
+            JoinType::Inner,
+            Condition::with_expr(eq(0, 1)),
+        );
+        let join_1 = LogicalJoin::new(
+            LogicalMultiJoin::from_join(&join_0.into()).unwrap().into(),
+            relation_c,
+            JoinType::Inner,
+            Condition::with_expr(eq(1, 2)),
+        );
+        let multi_join = LogicalMultiJoin::from_join(&join_1.into()).unwrap();
+
+        let join_tree = multi_join
+            .cost_based_join_tree(&CostModel::Batch { parallelism: 1 })
+            .unwrap();
+        assert_eq!(
+            join_tree,
+            JoinTree::Join(
+                Box::new(JoinTree::Input(0)),
+                Box::new(JoinTree::Join(
+                    Box::new(JoinTree::Input(1)),
+                    Box::new(JoinTree::Input(2))
+                ))
+            )
+        );
+        assert_eq!(join_tree.ordering(), vec![0, 1, 2]);
+
+        // Without eq join conditions, a multi join of inputs without rows keeps its order.
+        let join_2 = LogicalJoin::new(
+            values_with_rows(0, &fields[0..1], ctx.clone()),
+            values_with_rows(0, &fields[1..2], ctx),
+            JoinType::Inner,
+            Condition::true_cond(),
+        );
+        let multi_join = LogicalMultiJoin::from_join(&join_2.into()).unwrap();
+        assert_eq!(
+            multi_join.cost_based_join_tree(&CostModel::Stream { parallelism: 1 }),
+            Some(JoinTree::left_deep(&[0, 1]))
+        );
+    }
 }
@@ -19,7 +19,7 @@ use risingwave_pb::plan_common::JoinType;
 use super::{BoxedRule, Rule};
 use crate::expr::{ExprImpl, ExprRewriter, InputRef};
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalProject, PlanTreeNodeBinary, PlanTreeNodeUnary,
+    LogicalAgg, LogicalApply, LogicalProject, PlanTreeNodeBinary, PlanTreeNodeUnary,
 };
 use crate::optimizer::PlanRef;
 use crate::utils::ColIndexMapping;
@@ -41,7 +41,11 @@ pub struct UnnestAggForLOJ {}
 impl Rule for UnnestAggForLOJ {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let apply = plan.as_logical_apply()?;
-        if apply.join_type() != JoinType::LeftOuter {
+        // The `on` condition refers to the output of Project p1, so it can't be kept on the new
+        // Apply below the Agg.
+        if !matches!(apply.join_type(), JoinType::Inner | JoinType::LeftOuter)
+            || !apply.on().always_true()
+        {
             return None;
         }
         let apply_left_len = apply.left().schema().fields().len();
@@ -61,7 +65,15 @@ impl Rule for UnnestAggForLOJ {
         let idx_of_constant = exprs.len() - 1;
         let new_project = LogicalProject::new(input, exprs);
 
-        let new_apply = apply.clone_with_left_right(apply.left(), new_project.into());
+        // As the scalar agg produces one row for each row from the left, an `Inner` Apply is the
+        // same as a `LeftOuter` one, which keeps the rows from the left without a match below the
+        // Agg.
+        let new_apply = LogicalApply::new(
+            apply.left(),
+            new_project.into(),
+            JoinType::LeftOuter,
+            apply.on().clone(),
+        );
 
         // To pull LogicalAgg up on top of LogicalApply, we need to convert scalar agg to group agg
         // using pks of Apply.left as its group keys and convert count(*) to count(pk).
@@ -16,7 +16,8 @@ use fixedbitset::FixedBitSet;
 use risingwave_common::error::Result;
 
 use super::Planner;
-use crate::binder::BoundDelete;
+use crate::binder::{BoundBaseTable, BoundDelete};
+use crate::expr::ExprImpl;
 use crate::optimizer::plan_node::{LogicalDelete, LogicalFilter};
 use crate::optimizer::property::{Order, RequiredDist};
 use crate::optimizer::{PlanRef, PlanRoot};
@@ -25,12 +26,7 @@ impl Planner {
     pub(super) fn plan_delete(&mut self, delete: BoundDelete) -> Result<PlanRoot> {
         let name = delete.table_source.name.clone();
         let source_id = delete.table_source.source_id;
-        let scan = self.plan_base_table(delete.table)?;
-        let input = if let Some(expr) = delete.selection {
-            LogicalFilter::create_with_expr(scan, expr)
-        } else {
-            scan
-        };
+        let input = self.plan_delete_input(delete.table, delete.selection)?;
         let plan: PlanRef = LogicalDelete::create(input, name, source_id)?.into();
 
         let order = Order::any().clone();
@@ -43,4 +39,18 @@ impl Planner {
         let root = PlanRoot::new(plan, dist, order, out_fields, out_names);
         Ok(root)
     }
+
+    /// Plan the rows to delete, i.e. the row id followed by the columns of the table.
+    pub(super) fn plan_delete_input(
+        &mut self,
+        table: BoundBaseTable,
+        selection: Option<ExprImpl>,
+    ) -> Result<PlanRef> {
+        let scan = self.plan_base_table(table)?;
+        Ok(if let Some(expr) = selection {
+            LogicalFilter::create_with_expr(scan, expr)
+        } else {
+            scan
+        })
+    }
 }
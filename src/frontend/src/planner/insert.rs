@@ -15,7 +15,8 @@
 use fixedbitset::FixedBitSet;
 use risingwave_common::error::Result;
 
-use crate::binder::BoundInsert;
+use crate::binder::{BoundInsert, BoundQuery};
+use crate::expr::ExprImpl;
 use crate::optimizer::plan_node::{LogicalInsert, LogicalProject, PlanRef};
 use crate::optimizer::property::{Order, RequiredDist};
 use crate::optimizer::PlanRoot;
@@ -23,15 +24,13 @@ use crate::planner::Planner;
 
 impl Planner {
     pub(super) fn plan_insert(&mut self, insert: BoundInsert) -> Result<PlanRoot> {
-        let mut input = self.plan_query(insert.source)?.as_subplan();
-        if !insert.cast_exprs.is_empty() {
-            input = LogicalProject::create(input, insert.cast_exprs);
-        }
+        let input = self.plan_insert_input(insert.source, insert.cast_exprs)?;
         // `columns` not used by backend yet.
         let plan: PlanRef = LogicalInsert::create(
             input,
             insert.table_source.name,
             insert.table_source.source_id,
+            insert.table_source.unique_indexes,
         )?
         .into();
         let order = Order::any().clone();
@@ -43,4 +42,17 @@ impl Planner {
         let root = PlanRoot::new(plan, dist, order, out_fields, out_names);
         Ok(root)
     }
+
+    /// Plan the rows to insert, i.e. the columns of the table except the row id.
+    pub(super) fn plan_insert_input(
+        &mut self,
+        source: BoundQuery,
+        cast_exprs: Vec<ExprImpl>,
+    ) -> Result<PlanRef> {
+        let mut input = self.plan_query(source)?.as_subplan();
+        if !cast_exprs.is_empty() {
+            input = LogicalProject::create(input, cast_exprs);
+        }
+        Ok(input)
+    }
 }
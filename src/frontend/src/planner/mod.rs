@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use risingwave_common::error::Result;
 
-use crate::binder::BoundStatement;
+use crate::binder::{BoundQuery, BoundStatement};
 use crate::optimizer::PlanRoot;
 use crate::session::OptimizerContextRef;
 
@@ -28,6 +30,8 @@ mod statement;
 mod update;
 mod values;
 
+pub use query::LIMIT_ALL_COUNT;
+
 /// `Planner` converts a bound statement to a [`crate::optimizer::plan_node::PlanNode`] tree
 pub struct Planner {
     ctx: OptimizerContextRef,
@@ -40,9 +44,35 @@ impl Planner {
 
     /// Plan a [`BoundStatement`]. Need to bind a statement before plan.
     pub fn plan(&mut self, stmt: BoundStatement) -> Result<PlanRoot> {
+        let stmt = stmt.bind_session_time(Some(Self::now()), &self.time_zone());
         self.plan_statement(stmt)
     }
 
+    /// Plan the rows an INSERT, DELETE or UPDATE writes, without writing them. They are buffered
+    /// by the transaction until `COMMIT`.
+    pub fn plan_changes(&mut self, stmt: BoundStatement) -> Result<PlanRoot> {
+        let stmt = stmt.bind_session_time(Some(Self::now()), &self.time_zone());
+        self.plan_statement_changes(stmt)
+    }
+
+    /// Plan the query of a materialized view, where `now()` is updated as time passes.
+    pub fn plan_streaming_query(&mut self, query: BoundQuery) -> Result<PlanRoot> {
+        let stmt = BoundStatement::Query(Box::new(query));
+        let stmt = stmt.bind_session_time(None, &self.time_zone());
+        self.plan_statement(stmt)
+    }
+
+    /// Microseconds since the Unix epoch, as the value of `now()`.
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since_epoch| since_epoch.as_micros() as i64)
+    }
+
+    fn time_zone(&self) -> String {
+        self.ctx.inner().session_ctx.config().timezone().to_string()
+    }
+
     pub fn ctx(&self) -> OptimizerContextRef {
         self.ctx.clone()
     }
@@ -15,18 +15,18 @@
 use std::rc::Rc;
 
 use itertools::Itertools;
-use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::ScalarImpl;
 
 use crate::binder::{
-    BoundBaseTable, BoundGenerateSeriesFunction, BoundJoin, BoundSource, BoundWindowTableFunction,
-    Relation, WindowTableFunctionKind,
+    BoundBaseTable, BoundJoin, BoundSource, BoundSystemTable, BoundWindowTableFunction, Relation,
+    WindowTableFunctionKind,
 };
-use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, Literal, TableFunction};
 use crate::optimizer::plan_node::{
-    LogicalGenerateSeries, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan,
-    LogicalSource, PlanRef,
+    LogicalApply, LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalSource,
+    LogicalTableFunction, LogicalValues, LogicalWorkTableScan, PlanRef,
 };
 use crate::planner::Planner;
 
@@ -39,10 +39,33 @@ impl Planner {
             Relation::Join(join) => self.plan_join(*join),
             Relation::WindowTableFunction(tf) => self.plan_window_table_function(*tf),
             Relation::Source(s) => self.plan_source(*s),
-            Relation::GenerateSeriesFunction(gs) => self.plan_generate_series_function(*gs),
+            Relation::TableFunction(tf) => self.plan_table_function(*tf),
+            Relation::SystemTable(st) => self.plan_system_table(*st),
+            Relation::WorkTable(wt) => {
+                Ok(LogicalWorkTableScan::create(wt.id, wt.schema, self.ctx()))
+            }
         }
     }
 
+    pub(super) fn plan_system_table(&mut self, system_table: BoundSystemTable) -> Result<PlanRef> {
+        let rows = system_table
+            .rows
+            .into_iter()
+            .map(|row| {
+                row.0
+                    .into_iter()
+                    .zip_eq(&system_table.fields)
+                    .map(|(datum, field)| Literal::new(datum, field.data_type()).into())
+                    .collect()
+            })
+            .collect();
+        Ok(LogicalValues::create(
+            rows,
+            Schema::new(system_table.fields),
+            self.ctx(),
+        ))
+    }
+
     pub(super) fn plan_base_table(&mut self, base_table: BoundBaseTable) -> Result<PlanRef> {
         LogicalScan::create(
             base_table.name,
@@ -52,6 +75,7 @@ impl Planner {
                 .iter()
                 .map(|x| (x.name.clone(), Rc::new(x.table_desc())))
                 .collect(),
+            base_table.table_catalog.statistics().cloned().map(Rc::new),
             self.ctx(),
         )
     }
@@ -61,11 +85,25 @@ impl Planner {
     }
 
     pub(super) fn plan_join(&mut self, join: BoundJoin) -> Result<PlanRef> {
+        // A `LATERAL` item on the right is evaluated for each row on the left, which is
+        // decorrelated later.
+        let is_lateral = join.right.is_lateral();
         let left = self.plan_relation(join.left)?;
         let right = self.plan_relation(join.right)?;
         let join_type = join.join_type;
         let on_clause = join.cond;
-        Ok(LogicalJoin::create(left, right, join_type, on_clause))
+        if on_clause.has_now() {
+            return Err(ErrorCode::NotImplemented(
+                "now() in the ON clause of a streaming query".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        if is_lateral {
+            Ok(LogicalApply::create(left, right, join_type, on_clause))
+        } else {
+            Ok(LogicalJoin::create(left, right, join_type, on_clause))
+        }
     }
 
     pub(super) fn plan_window_table_function(
@@ -87,28 +125,8 @@ impl Planner {
         }
     }
 
-    pub(super) fn plan_generate_series_function(
-        &mut self,
-        table_function: BoundGenerateSeriesFunction,
-    ) -> Result<PlanRef> {
-        let schema = Schema::new(vec![Field::with_name(
-            table_function.data_type,
-            "generate_series",
-        )]);
-
-        let mut args = table_function.args.into_iter();
-
-        let Some((start,stop,step)) = args.next_tuple() else {
-            return Err(ErrorCode::BindError("Invalid arguments for Generate series function".to_string()).into());
-        };
-
-        Ok(LogicalGenerateSeries::create(
-            start,
-            stop,
-            step,
-            schema,
-            self.ctx(),
-        ))
+    pub(super) fn plan_table_function(&mut self, table_function: TableFunction) -> Result<PlanRef> {
+        Ok(LogicalTableFunction::new(table_function, self.ctx()).into())
     }
 
     fn plan_tumble_window(
@@ -14,26 +14,28 @@
 
 use itertools::Itertools;
 use risingwave_common::catalog::Schema;
-use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
+use risingwave_expr::expr::AggKind;
 use risingwave_pb::plan_common::JoinType;
 
 use crate::binder::BoundSelect;
 use crate::expr::{
-    Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Subquery, SubqueryKind,
+    Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal, Subquery, SubqueryKind,
 };
 pub use crate::optimizer::plan_node::LogicalFilter;
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalApply, LogicalJoin, LogicalProject, LogicalValues, PlanAggCall, PlanRef,
+    LogicalAgg, LogicalApply, LogicalDynamicFilter, LogicalJoin, LogicalNow, LogicalOverAgg,
+    LogicalProject, LogicalProjectSet, LogicalValues, PlanAggCall, PlanRef,
 };
 use crate::planner::Planner;
-use crate::utils::Condition;
+use crate::utils::{ColIndexMapping, Condition};
 impl Planner {
     pub(super) fn plan_select(
         &mut self,
         BoundSelect {
             from,
-            where_clause,
+            mut where_clause,
             mut select_items,
             group_by,
             mut having,
@@ -50,16 +52,69 @@ impl Planner {
             .into());
         }
         select_items.extend(extra_order_exprs);
+        if group_by
+            .iter()
+            .chain(having.iter())
+            .any(ExprImpl::has_table_function)
+        {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "set-returning functions are not allowed in GROUP BY or HAVING".to_string(),
+            )
+            .into());
+        }
+        if group_by
+            .iter()
+            .chain(having.iter())
+            .any(ExprImpl::has_window_function)
+        {
+            return Err(ErrorCode::InvalidInputSyntax(
+                "window functions are not allowed in GROUP BY or HAVING".to_string(),
+            )
+            .into());
+        }
 
         // Plan the FROM clause.
         let mut root = match from {
             None => self.create_dummy_values(),
             Some(t) => self.plan_relation(t)?,
         };
+        // `now()` is left in streaming queries, where it can only be compared with the input in
+        // the WHERE clause. Such comparisons are planned as dynamic filters, which only update the
+        // rows crossing the bound as time passes.
+        let mut dynamic_filters = vec![];
+        if where_clause.as_ref().map_or(false, ExprImpl::has_now) {
+            let where_clause_with_now = Condition::with_expr(where_clause.take().unwrap());
+            let (now_conjunctions, others): (Vec<_>, Vec<_>) = where_clause_with_now
+                .conjunctions
+                .into_iter()
+                .partition(ExprImpl::has_now);
+            for expr in now_conjunctions {
+                dynamic_filters.push(Self::extract_dynamic_filter(expr)?);
+            }
+            if !others.is_empty() {
+                where_clause = Some(
+                    Condition {
+                        conjunctions: others,
+                    }
+                    .into(),
+                );
+            }
+        }
+        if select_items
+            .iter()
+            .chain(group_by.iter())
+            .chain(having.iter())
+            .any(ExprImpl::has_now)
+        {
+            return Err(Self::now_not_supported());
+        }
         // Plan the WHERE clause.
         if let Some(where_clause) = where_clause {
             root = self.plan_where(root, where_clause)?;
         }
+        for (left, comparator, right) in dynamic_filters {
+            root = self.plan_dynamic_filter(root, left, comparator, right);
+        }
         // Plan the SELECT clause.
         // TODO: select-agg, group-by, having can also contain subquery exprs.
         let has_agg_call = select_items.iter().any(|expr| expr.has_agg_call());
@@ -75,7 +130,14 @@ impl Planner {
         if select_items.iter().any(|e| e.has_subquery()) {
             (root, select_items) = self.substitute_subqueries(root, select_items)?;
         }
-        root = LogicalProject::create(root, select_items);
+        if select_items.iter().any(ExprImpl::has_window_function) {
+            (root, select_items) = LogicalOverAgg::create(root, select_items)?;
+        }
+        if select_items.iter().any(ExprImpl::has_table_function) {
+            root = LogicalProjectSet::create(root, select_items)?;
+        } else {
+            root = LogicalProject::create(root, select_items);
+        }
 
         if distinct {
             let group_keys = (0..root.schema().fields().len()).collect();
@@ -156,44 +218,286 @@ impl Planner {
         }
     }
 
-    /// Handle (NOT) EXISTS and (NOT) IN in WHERE clause.
+    /// Handle (NOT) EXISTS, (NOT) IN, ANY and ALL in WHERE clause.
     ///
-    /// We will use a = b to replace a in (select b from ....) for (NOT) IN thus avoiding adding a
-    /// `LogicalFilter` on `LogicalApply`.
+    /// `x op ANY (subquery)` is planned as a `LeftSemi` join on `x op y`, and its negation as a
+    /// `LeftAnti` join on `(x op y) IS NOT FALSE`, as the row should also be filtered out when
+    /// `x op y` is NULL. IN and ALL are normalized to ANY by [`Self::normalize_quantified`].
+    ///
+    /// We will use `x op y` as the join condition thus avoiding adding a `LogicalFilter` on
+    /// `LogicalApply`. The uncorrelated `x NOT IN (subquery)` is planned by
+    /// [`Self::create_not_in`] instead, to keep `x = y` as the equal key of the join.
     fn handle_exists_and_in(
         &mut self,
         expr: ExprImpl,
         negated: bool,
         input: &mut PlanRef,
     ) -> Result<()> {
-        let join_type = if negated {
-            JoinType::LeftAnti
-        } else {
-            JoinType::LeftSemi
-        };
         let subquery = expr.into_subquery().unwrap();
         let is_correlated = subquery.is_correlated();
         let output_column_type = subquery.query.data_types()[0].clone();
         let right_plan = self.plan_query(subquery.query)?.as_subplan();
-        let on = match subquery.kind {
-            SubqueryKind::Existential => ExprImpl::literal_bool(true),
-            SubqueryKind::In(left_expr) => {
-                let right_expr = InputRef::new(input.schema().fields().len(), output_column_type);
-                FunctionCall::new(ExprType::Equal, vec![left_expr, right_expr.into()])?.into()
-            }
-            kind => {
-                return Err(ErrorCode::NotImplemented(
-                    format!("Not supported subquery kind: {:?}", kind),
-                    1343.into(),
-                )
-                .into())
+        let (left_expr, cmp, negated) = match subquery.kind {
+            SubqueryKind::Existential => {
+                let join_type = if negated {
+                    JoinType::LeftAnti
+                } else {
+                    JoinType::LeftSemi
+                };
+                *input = Self::create_apply_or_join(
+                    is_correlated,
+                    input.clone(),
+                    right_plan,
+                    ExprImpl::literal_bool(true),
+                    join_type,
+                );
+                return Ok(());
             }
+            kind => Self::normalize_quantified(kind, negated)?,
+        };
+        if negated && cmp == ExprType::Equal && !is_correlated {
+            *input = Self::create_not_in(input.clone(), right_plan, left_expr, output_column_type)?;
+            return Ok(());
+        }
+        let right_expr = InputRef::new(input.schema().len(), output_column_type);
+        let mut on: ExprImpl = FunctionCall::new(cmp, vec![left_expr, right_expr.into()])?.into();
+        let join_type = if negated {
+            on = FunctionCall::new(ExprType::IsNotFalse, vec![on])?.into();
+            JoinType::LeftAnti
+        } else {
+            JoinType::LeftSemi
         };
         *input =
             Self::create_apply_or_join(is_correlated, input.clone(), right_plan, on, join_type);
         Ok(())
     }
 
+    /// Plans the uncorrelated `x NOT IN (subquery)`. The `LeftAnti` join on `x = y` leaves the rows
+    /// not equal to any `y`, which are then filtered out if `x` is NULL or the subquery contains a
+    /// NULL, unless the subquery is empty:
+    ///
+    /// ```text
+    /// Project([input.*])
+    ///   Join(type: Inner, on: count = 0 OR (x IS NOT NULL AND count = count_y))
+    ///     Join(type: LeftAnti, on: x = y)
+    ///       input
+    ///       subquery
+    ///     Agg(group_keys: [], agg_calls: [count(*), count(y)])
+    ///       subquery
+    /// ```
+    fn create_not_in(
+        input: PlanRef,
+        right: PlanRef,
+        left_expr: ExprImpl,
+        right_type: DataType,
+    ) -> Result<PlanRef> {
+        let input_len = input.schema().len();
+        let on = FunctionCall::new(
+            ExprType::Equal,
+            vec![
+                left_expr.clone(),
+                InputRef::new(input_len, right_type.clone()).into(),
+            ],
+        )?
+        .into();
+        let anti_join = LogicalJoin::create(input.clone(), right.clone(), JoinType::LeftAnti, on);
+
+        let count_y = PlanAggCall {
+            agg_kind: AggKind::Count,
+            return_type: DataType::Int64,
+            inputs: vec![InputRef::new(0, right_type)],
+            distinct: false,
+        };
+        let counts = LogicalAgg::new(vec![PlanAggCall::count_star(), count_y], vec![], right);
+
+        let count: ExprImpl = InputRef::new(input_len, DataType::Int64).into();
+        let count_y: ExprImpl = InputRef::new(input_len + 1, DataType::Int64).into();
+        let is_empty = FunctionCall::new(
+            ExprType::Equal,
+            vec![count.clone(), ExprImpl::literal_bigint(0)],
+        )?;
+        let no_null = FunctionCall::new(
+            ExprType::And,
+            vec![
+                FunctionCall::new(ExprType::IsNotNull, vec![left_expr])?.into(),
+                FunctionCall::new(ExprType::Equal, vec![count, count_y])?.into(),
+            ],
+        )?;
+        let on = FunctionCall::new(ExprType::Or, vec![is_empty.into(), no_null.into()])?.into();
+        let join = LogicalJoin::create(anti_join, counts.into(), JoinType::Inner, on);
+
+        let exprs = input
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        Ok(LogicalProject::create(join, exprs))
+    }
+
+    /// Normalizes `[NOT] x IN (subquery)`, `[NOT] x op ANY (subquery)` and
+    /// `[NOT] x op ALL (subquery)` to `[NOT] x op ANY (subquery)`, and returns `(x, op, negated)`.
+    ///
+    /// `x IN` is the same as `x = ANY`, and `x op ALL` is the same as `NOT x negated_op ANY`.
+    fn normalize_quantified(
+        kind: SubqueryKind,
+        negated: bool,
+    ) -> Result<(ExprImpl, ExprType, bool)> {
+        let (left_expr, cmp, negated) = match kind {
+            SubqueryKind::In(left_expr) => (left_expr, ExprType::Equal, negated),
+            SubqueryKind::Some(left_expr, cmp) => (left_expr, cmp, negated),
+            SubqueryKind::All(left_expr, cmp) => {
+                let cmp = match cmp {
+                    ExprType::Equal => ExprType::NotEqual,
+                    ExprType::NotEqual => ExprType::Equal,
+                    ExprType::LessThan => ExprType::GreaterThanOrEqual,
+                    ExprType::LessThanOrEqual => ExprType::GreaterThan,
+                    ExprType::GreaterThan => ExprType::LessThanOrEqual,
+                    ExprType::GreaterThanOrEqual => ExprType::LessThan,
+                    _ => unreachable!("invalid comparison for ALL subquery: {:?}", cmp),
+                };
+                (left_expr, cmp, !negated)
+            }
+            SubqueryKind::Scalar | SubqueryKind::Existential => {
+                unreachable!("not a quantified subquery: {:?}", kind)
+            }
+        };
+        if left_expr.has_subquery() {
+            return Err(ErrorCode::NotImplemented(
+                "subquery on the left side of IN, ANY or ALL subquery".into(),
+                None.into(),
+            )
+            .into());
+        }
+        Ok((left_expr, cmp, negated))
+    }
+
+    /// Plans `x op ANY (subquery)` as a boolean column appended to `root`, or its negation when
+    /// `negated`. The value is TRUE if `x op y` is TRUE for any `y`, otherwise NULL if `x op y` is
+    /// NULL for any `y`, otherwise FALSE (including when the subquery is empty).
+    ///
+    /// The subquery is `LeftOuter` joined on `(x op y) IS NOT FALSE`, and then we count the rows
+    /// where `x op y` is TRUE and the matched rows for each row of `root`:
+    ///
+    /// ```text
+    /// Project([root.*, CASE WHEN count_true > 0 THEN TRUE WHEN count_matched > 0 THEN NULL ELSE FALSE END])
+    ///   Agg(group_keys: [root.*], agg_calls: [count(CASE WHEN x op y THEN 1 END), count(matched)])
+    ///     Apply/Join(type: LeftOuter, on: (x op y) IS NOT FALSE)
+    ///       root
+    ///       Project([y, 1 as matched])
+    ///         subquery
+    /// ```
+    ///
+    /// The group keys include the primary key of `root`, so that its duplicate rows are not merged.
+    fn create_quantified(
+        is_correlated: bool,
+        root: PlanRef,
+        right: PlanRef,
+        left_expr: ExprImpl,
+        cmp: ExprType,
+        negated: bool,
+    ) -> Result<PlanRef> {
+        let root_len = root.schema().len();
+        let right_type = right.schema().fields()[0].data_type();
+        let is_single_row = root
+            .as_logical_values()
+            .map_or(false, |values| values.rows().len() <= 1);
+        if root.pk_indices().is_empty() && !is_single_row {
+            return Err(ErrorCode::NotImplemented(
+                "IN, ANY or ALL subquery in the select list of a relation without a primary key"
+                    .into(),
+                None.into(),
+            )
+            .into());
+        }
+
+        // Append the `matched` column to the subquery.
+        let right = match right.as_logical_project() {
+            Some(project) => {
+                let (mut exprs, input) = project.clone().decompose();
+                exprs.push(ExprImpl::literal_int(1));
+                LogicalProject::create(input, exprs)
+            }
+            None => LogicalProject::create(
+                right,
+                vec![
+                    InputRef::new(0, right_type.clone()).into(),
+                    ExprImpl::literal_int(1),
+                ],
+            ),
+        };
+
+        let cmp_expr: ExprImpl = FunctionCall::new(
+            cmp,
+            vec![left_expr, InputRef::new(root_len, right_type).into()],
+        )?
+        .into();
+        let on = FunctionCall::new(ExprType::IsNotFalse, vec![cmp_expr.clone()])?.into();
+        let join = Self::create_apply_or_join(is_correlated, root, right, on, JoinType::LeftOuter);
+
+        let mut exprs: Vec<ExprImpl> = join
+            .schema()
+            .fields()
+            .iter()
+            .take(root_len)
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        exprs.push(
+            FunctionCall::new(ExprType::Case, vec![cmp_expr, ExprImpl::literal_int(1)])?.into(),
+        );
+        exprs.push(InputRef::new(root_len + 1, DataType::Int32).into());
+        let project = LogicalProject::create(join, exprs);
+
+        let count = |index| PlanAggCall {
+            agg_kind: AggKind::Count,
+            return_type: DataType::Int64,
+            inputs: vec![InputRef::new(index, DataType::Int32)],
+            distinct: false,
+        };
+        let agg = LogicalAgg::new(
+            vec![count(root_len), count(root_len + 1)],
+            (0..root_len).collect(),
+            project,
+        );
+
+        let positive = |index| {
+            FunctionCall::new(
+                ExprType::GreaterThan,
+                vec![
+                    InputRef::new(index, DataType::Int64).into(),
+                    ExprImpl::literal_bigint(0),
+                ],
+            )
+            .map(ExprImpl::from)
+        };
+        let mut result: ExprImpl = FunctionCall::new(
+            ExprType::Case,
+            vec![
+                positive(root_len)?,
+                ExprImpl::literal_bool(true),
+                positive(root_len + 1)?,
+                Literal::new(None, DataType::Boolean).into(),
+                ExprImpl::literal_bool(false),
+            ],
+        )?
+        .into();
+        if negated {
+            result = FunctionCall::new(ExprType::Not, vec![result])?.into();
+        }
+        let mut exprs: Vec<ExprImpl> = agg
+            .schema()
+            .fields()
+            .iter()
+            .take(root_len)
+            .enumerate()
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        exprs.push(result);
+        Ok(LogicalProject::create(agg.into(), exprs))
+    }
+
     /// Substitutes all [`Subquery`] in `exprs`.
     ///
     /// Each time a [`Subquery`] is found, it is replaced by a new [`InputRef`]. And `root` is
@@ -239,12 +543,17 @@ impl Planner {
                 SubqueryKind::Existential => {
                     right = self.create_exists(right)?;
                 }
-                _ => {
-                    return Err(ErrorCode::NotImplemented(
-                        format!("{:?}", subquery.kind),
-                        1343.into(),
-                    )
-                    .into())
+                kind => {
+                    let (left_expr, cmp, negated) = Self::normalize_quantified(kind, false)?;
+                    root = Self::create_quantified(
+                        is_correlated,
+                        root,
+                        right,
+                        left_expr,
+                        cmp,
+                        negated,
+                    )?;
+                    continue;
                 }
             }
 
@@ -272,4 +581,102 @@ impl Planner {
             LogicalJoin::create(left, right, join_type, on)
         }
     }
+
+    fn now_not_supported() -> RwError {
+        ErrorCode::NotImplemented(
+            "now() in a streaming query, except comparing it with the input in WHERE".to_string(),
+            None.into(),
+        )
+        .into()
+    }
+
+    /// Matches `<expr> <cmp> <expr of now()>`, with the sides in either order, and returns it as
+    /// `(input expr, cmp, expr of now())`, where the input expr doesn't call `now()`, and the other
+    /// one only depends on `now()` and constants.
+    fn extract_dynamic_filter(expr: ExprImpl) -> Result<(ExprImpl, ExprType, ExprImpl)> {
+        let Some(func_call) = expr.as_function_call() else {
+            return Err(Self::now_not_supported());
+        };
+        let cmp = func_call.get_expr_type();
+        let flipped = match cmp {
+            ExprType::LessThan => ExprType::GreaterThan,
+            ExprType::LessThanOrEqual => ExprType::GreaterThanOrEqual,
+            ExprType::GreaterThan => ExprType::LessThan,
+            ExprType::GreaterThanOrEqual => ExprType::LessThanOrEqual,
+            _ => return Err(Self::now_not_supported()),
+        };
+        let is_input = |expr: &ExprImpl| !expr.has_now() && !expr.has_subquery();
+        let is_now = |expr: &ExprImpl| expr.has_now() && expr.is_const();
+        match func_call.inputs() {
+            [lhs, rhs] if is_input(lhs) && is_now(rhs) => Ok((lhs.clone(), cmp, rhs.clone())),
+            [lhs, rhs] if is_now(lhs) && is_input(rhs) => Ok((rhs.clone(), flipped, lhs.clone())),
+            _ => Err(Self::now_not_supported()),
+        }
+    }
+
+    /// Filters `input` by `left <comparator> right`, where `right` only depends on `now()`. The
+    /// value of `right` is computed from a [`LogicalNow`], and `left` is appended to the input
+    /// before the filter if it's not a column.
+    fn plan_dynamic_filter(
+        &self,
+        input: PlanRef,
+        left: ExprImpl,
+        comparator: ExprType,
+        right: ExprImpl,
+    ) -> PlanRef {
+        let input_col_num = input.schema().len();
+        let (input, left_index) = match left {
+            ExprImpl::InputRef(input_ref) => (input, input_ref.index()),
+            left => {
+                let exprs = input
+                    .schema()
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+                    .chain(std::iter::once(left))
+                    .collect();
+                (LogicalProject::create(input, exprs), input_col_num)
+            }
+        };
+        let mut rewriter = SubstituteNow {
+            now: InputRef::new(0, DataType::Timestampz),
+        };
+        let right = LogicalProject::create(
+            LogicalNow::create(self.ctx()),
+            vec![rewriter.rewrite_expr(right)],
+        );
+        let filter = LogicalDynamicFilter::create(input, left_index, comparator, right);
+        if filter.schema().len() == input_col_num {
+            filter
+        } else {
+            LogicalProject::with_mapping(
+                filter,
+                ColIndexMapping::with_remaining_columns(
+                    &(0..input_col_num).collect_vec(),
+                    input_col_num + 1,
+                ),
+            )
+            .into()
+        }
+    }
+}
+
+/// Replaces the calls of `now()` with the column of a [`LogicalNow`].
+struct SubstituteNow {
+    now: InputRef,
+}
+
+impl ExprRewriter for SubstituteNow {
+    fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        let (func_type, inputs, ret) = func_call.decompose();
+        if func_type == ExprType::Now {
+            return self.now.clone().into();
+        }
+        let inputs = inputs
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        FunctionCall::new_unchecked(func_type, inputs, ret).into()
+    }
 }
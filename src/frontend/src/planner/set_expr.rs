@@ -12,12 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
+use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_expr::expr::AggKind;
+use risingwave_sqlparser::ast::SetOperator;
 
 use crate::binder::BoundSetExpr;
-use crate::expr::ExprImpl;
-use crate::optimizer::plan_node::PlanRef;
+use crate::expr::{
+    ExprImpl, ExprType, FunctionCall, InputRef, Literal, TableFunction, TableFunctionType,
+};
+use crate::optimizer::plan_node::{
+    LogicalAgg, LogicalFilter, LogicalProject, LogicalProjectSet, LogicalRecursiveUnion,
+    LogicalUnion, PlanAggCall, PlanRef,
+};
 use crate::planner::Planner;
+use crate::utils::Condition;
 
 impl Planner {
     pub(super) fn plan_set_expr(
@@ -28,6 +39,150 @@ impl Planner {
         match set_expr {
             BoundSetExpr::Select(s) => self.plan_select(*s, extra_order_exprs),
             BoundSetExpr::Values(v) => self.plan_values(*v),
+            BoundSetExpr::SetOperation {
+                op,
+                all,
+                left,
+                right,
+                schema,
+            } => self.plan_set_operation(op, all, *left, *right, &schema),
+            BoundSetExpr::RecursiveUnion {
+                all,
+                base,
+                recursive,
+                work_table_id,
+            } => {
+                let base = self.plan_set_expr(*base, vec![])?;
+                let recursive =
+                    self.plan_set_operation_input(*recursive, &base.schema().data_types())?;
+                Ok(LogicalRecursiveUnion::create(
+                    all,
+                    base,
+                    recursive,
+                    work_table_id,
+                ))
+            }
         }
     }
+
+    /// Plans a set operation on top of a [`LogicalUnion`] of both sides. `UNION` removes the
+    /// duplicates by grouping on all the columns. `INTERSECT` and `EXCEPT` tag the rows with the
+    /// side they come from, and count the rows of each side per group to decide how many copies of
+    /// a row to keep.
+    fn plan_set_operation(
+        &mut self,
+        op: SetOperator,
+        all: bool,
+        left: BoundSetExpr,
+        right: BoundSetExpr,
+        schema: &Schema,
+    ) -> Result<PlanRef> {
+        let data_types = schema.data_types();
+        let column_num = data_types.len();
+        let left = self.plan_set_operation_input(left, &data_types)?;
+        let right = self.plan_set_operation_input(right, &data_types)?;
+        let columns = || {
+            data_types
+                .iter()
+                .enumerate()
+                .map(|(i, data_type)| ExprImpl::from(InputRef::new(i, data_type.clone())))
+        };
+
+        if op == SetOperator::Union {
+            let union = LogicalUnion::create(vec![left, right]);
+            return Ok(match all {
+                true => union,
+                false => LogicalAgg::new(vec![], (0..column_num).collect(), union).into(),
+            });
+        }
+
+        // Each row is tagged with a pair of flags, which are summed up to the count of its copies
+        // on the left and the right side.
+        let tag = |input: PlanRef, is_left: bool| {
+            let exprs = columns()
+                .chain([
+                    ExprImpl::literal_int(is_left as i32),
+                    ExprImpl::literal_int(!is_left as i32),
+                ])
+                .collect();
+            LogicalProject::create(input, exprs)
+        };
+        let union = LogicalUnion::create(vec![tag(left, true), tag(right, false)]);
+        let sum = |index| PlanAggCall {
+            agg_kind: AggKind::Sum,
+            return_type: DataType::Int64,
+            inputs: vec![InputRef::new(index, DataType::Int32)],
+            distinct: false,
+        };
+        let agg: PlanRef = LogicalAgg::new(
+            vec![sum(column_num), sum(column_num + 1)],
+            (0..column_num).collect(),
+            union,
+        )
+        .into();
+        let left_count: ExprImpl = InputRef::new(column_num, DataType::Int64).into();
+        let right_count: ExprImpl = InputRef::new(column_num + 1, DataType::Int64).into();
+
+        let plan = if all {
+            // `INTERSECT ALL` keeps as many copies of a row as the side with fewer of them, and
+            // `EXCEPT ALL` keeps the copies on the left beyond those on the right. The copies are
+            // expanded with `generate_series`.
+            let copies: ExprImpl = match op {
+                SetOperator::Intersect => {
+                    FunctionCall::new(ExprType::Least, vec![left_count, right_count])?
+                }
+                _ => FunctionCall::new(ExprType::Subtract, vec![left_count, right_count])?,
+            }
+            .into();
+            let series = TableFunction::new(
+                TableFunctionType::GenerateSeries,
+                vec![
+                    ExprImpl::literal_int(1),
+                    copies.cast_explicit(DataType::Int32)?,
+                ],
+            )?;
+            let select_list = columns()
+                .chain(std::iter::once(series.into()))
+                .collect_vec();
+            LogicalProjectSet::create(agg, select_list)?
+        } else {
+            let zero: ExprImpl = Literal::new(Some(ScalarImpl::Int64(0)), DataType::Int64).into();
+            let right_cmp = match op {
+                SetOperator::Intersect => ExprType::GreaterThan,
+                _ => ExprType::Equal,
+            };
+            let predicate = Condition {
+                conjunctions: vec![
+                    FunctionCall::new(ExprType::GreaterThan, vec![left_count, zero.clone()])?
+                        .into(),
+                    FunctionCall::new(right_cmp, vec![right_count, zero])?.into(),
+                ],
+            };
+            LogicalFilter::create(agg, predicate)
+        };
+        Ok(LogicalProject::create(plan, columns().collect()))
+    }
+
+    /// Plans a side of a set operation, casting its columns to the aligned types if needed.
+    fn plan_set_operation_input(
+        &mut self,
+        set_expr: BoundSetExpr,
+        data_types: &[DataType],
+    ) -> Result<PlanRef> {
+        let plan = self.plan_set_expr(set_expr, vec![])?;
+        if plan.schema().data_types() == data_types {
+            return Ok(plan);
+        }
+        let exprs: Vec<ExprImpl> = plan
+            .schema()
+            .fields()
+            .iter()
+            .zip_eq(data_types)
+            .enumerate()
+            .map(|(i, (field, data_type))| {
+                ExprImpl::from(InputRef::new(i, field.data_type())).cast_implicit(data_type.clone())
+            })
+            .try_collect()?;
+        Ok(LogicalProject::create(plan, exprs))
+    }
 }
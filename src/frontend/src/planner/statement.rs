@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::error::Result;
+use fixedbitset::FixedBitSet;
+use risingwave_common::error::{ErrorCode, Result};
 
 use crate::binder::BoundStatement;
+use crate::expr::{ExprImpl, InputRef};
+use crate::optimizer::plan_node::LogicalProject;
+use crate::optimizer::property::{Order, RequiredDist};
 use crate::optimizer::PlanRoot;
 use crate::planner::Planner;
 
 impl Planner {
-    pub(super) fn plan_statement(&mut self, stmt: BoundStatement) -> Result<PlanRoot> {
+    pub(super) fn plan_statement(&mut self, mut stmt: BoundStatement) -> Result<PlanRoot> {
+        check_no_params(&mut stmt)?;
         match stmt {
             BoundStatement::Insert(i) => self.plan_insert(*i),
             BoundStatement::Delete(d) => self.plan_delete(*d),
@@ -27,4 +32,49 @@ impl Planner {
             BoundStatement::Query(q) => self.plan_query(*q),
         }
     }
+
+    /// Plan the rows an INSERT, DELETE or UPDATE writes. They are the columns of the table except
+    /// the row id for INSERT, the row id followed by the columns for DELETE, and the row id and the
+    /// columns before the update followed by those after it for UPDATE.
+    pub(super) fn plan_statement_changes(&mut self, mut stmt: BoundStatement) -> Result<PlanRoot> {
+        check_no_params(&mut stmt)?;
+        let plan = match stmt {
+            BoundStatement::Insert(i) => self.plan_insert_input(i.source, i.cast_exprs)?,
+            BoundStatement::Delete(d) => self.plan_delete_input(d.table, d.selection)?,
+            BoundStatement::Update(u) => {
+                let input = self.plan_update_input(u.table, u.selection)?;
+                let mut exprs: Vec<ExprImpl> = input
+                    .schema()
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+                    .collect();
+                exprs.extend(u.exprs);
+                LogicalProject::create(input, exprs)
+            }
+            BoundStatement::Query(_) => unreachable!(),
+        };
+
+        let order = Order::any().clone();
+        // Like the statement itself, the rows are computed by one task.
+        let dist = RequiredDist::Any;
+        let mut out_fields = FixedBitSet::with_capacity(plan.schema().len());
+        out_fields.insert_range(..);
+        let out_names = plan.schema().names();
+        Ok(PlanRoot::new(plan, dist, order, out_fields, out_names))
+    }
+}
+
+/// Parameters can't be serialized, so they must have been substituted by `EXECUTE`.
+fn check_no_params(stmt: &mut BoundStatement) -> Result<()> {
+    let param_count = stmt.param_count();
+    if param_count > 0 {
+        return Err(ErrorCode::BindError(format!(
+            "no value supplied for parameter ${}",
+            param_count
+        ))
+        .into());
+    }
+    Ok(())
 }
@@ -17,7 +17,8 @@ use risingwave_common::error::Result;
 
 use super::select::LogicalFilter;
 use super::Planner;
-use crate::binder::BoundUpdate;
+use crate::binder::{BoundUpdate, Relation};
+use crate::expr::ExprImpl;
 use crate::optimizer::plan_node::LogicalUpdate;
 use crate::optimizer::property::{Order, RequiredDist};
 use crate::optimizer::{PlanRef, PlanRoot};
@@ -26,13 +27,10 @@ impl Planner {
     pub(super) fn plan_update(&mut self, update: BoundUpdate) -> Result<PlanRoot> {
         let name = update.table_source.name.clone();
         let source_id = update.table_source.source_id;
-        let scan = self.plan_relation(update.table)?;
-        let input = if let Some(expr) = update.selection {
-            LogicalFilter::create_with_expr(scan, expr)
-        } else {
-            scan
-        };
-        let plan: PlanRef = LogicalUpdate::create(input, name, source_id, update.exprs)?.into();
+        let unique_indexes = update.table_source.unique_indexes;
+        let input = self.plan_update_input(update.table, update.selection)?;
+        let plan: PlanRef =
+            LogicalUpdate::create(input, name, source_id, unique_indexes, update.exprs)?.into();
 
         let order = Order::any().clone();
         // For update, frontend will only schedule one task so do not need this to be single.
@@ -44,4 +42,19 @@ impl Planner {
         let root = PlanRoot::new(plan, dist, order, out_fields, out_names);
         Ok(root)
     }
+
+    /// Plan the rows to update, i.e. the row id followed by the columns of the table, before the
+    /// update.
+    pub(super) fn plan_update_input(
+        &mut self,
+        table: Relation,
+        selection: Option<ExprImpl>,
+    ) -> Result<PlanRef> {
+        let scan = self.plan_relation(table)?;
+        Ok(if let Some(expr) = selection {
+            LogicalFilter::create_with_expr(scan, expr)
+        } else {
+            scan
+        })
+    }
 }
@@ -17,7 +17,7 @@ use std::mem::swap;
 use std::sync::Arc;
 
 use risingwave_common::error::ErrorCode::InternalError;
-use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::batch_plan::{TaskId as TaskIdProst, TaskOutputId as TaskOutputIdProst};
 use risingwave_rpc_client::ComputeClientPoolRef;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
@@ -30,7 +30,9 @@ use crate::scheduler::distributed::query::QueryMessage::Stage;
 use crate::scheduler::distributed::query::QueryState::{Failed, Pending};
 use crate::scheduler::distributed::StageEvent::Scheduled;
 use crate::scheduler::distributed::StageExecution;
-use crate::scheduler::plan_fragmenter::{Query, StageId, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID};
+use crate::scheduler::plan_fragmenter::{
+    Query, QueryId, StageId, ROOT_TASK_ID, ROOT_TASK_OUTPUT_ID,
+};
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
 use crate::scheduler::HummockSnapshotManagerRef;
 
@@ -74,7 +76,7 @@ enum QueryState {
 pub struct QueryExecution {
     query: Arc<Query>,
     state: Arc<RwLock<QueryState>>,
-    _stage_executions: Arc<HashMap<StageId, Arc<StageExecution>>>,
+    stage_executions: Arc<HashMap<StageId, Arc<StageExecution>>>,
 }
 
 struct QueryRunner {
@@ -153,10 +155,14 @@ impl QueryExecution {
         Self {
             query,
             state: Arc::new(RwLock::new(state)),
-            _stage_executions: stage_executions,
+            stage_executions,
         }
     }
 
+    pub fn query_id(&self) -> &QueryId {
+        self.query.query_id()
+    }
+
     /// Start execution of this query.
     pub async fn start(&self) -> Result<QueryResultFetcher> {
         let mut state = self.state.write().await;
@@ -201,9 +207,27 @@ impl QueryExecution {
         }
     }
 
-    /// Cancel execution of this query.
-    pub async fn abort(&mut self) -> Result<()> {
-        todo!()
+    /// Cancel execution of this query, aborting all of its tasks.
+    pub async fn abort(&self) -> Result<()> {
+        let mut state = self.state.write().await;
+        let mut cur_state = Failed;
+        swap(&mut *state, &mut cur_state);
+
+        match cur_state {
+            QueryState::Running { .. } => {
+                info!("Aborting query {:?}.", self.query.query_id);
+                for stage_execution in self.stage_executions.values() {
+                    stage_execution.stop().await?;
+                }
+            }
+            // No task has been scheduled yet.
+            QueryState::Pending { .. } => {}
+            s => {
+                // Restore old state
+                *state = s;
+            }
+        }
+        Ok(())
     }
 }
 
@@ -212,21 +236,15 @@ impl QueryRunner {
         // Start leaf stages.
         let leaf_stages = self.query.leaf_stages();
         for stage_id in &leaf_stages {
-            // TODO: We should not return error here, we should abort query.
             info!(
                 "Starting query stage: {:?}-{:?}",
                 self.query.query_id, stage_id
             );
-            self.stage_executions
-                .get(stage_id)
-                .as_ref()
-                .unwrap()
-                .start()
-                .await
-                .map_err(|e| {
-                    error!("Failed to start stage: {}, reason: {:?}", stage_id, e);
-                    e
-                })?;
+            if let Err(e) = self.get_stage_execution_unchecked(stage_id).start().await {
+                error!("Failed to start stage: {}, reason: {:?}", stage_id, e);
+                self.fail(e.clone()).await;
+                return Err(e);
+            }
             info!(
                 "Query stage {:?}-{:?} started.",
                 self.query.query_id, stage_id
@@ -237,6 +255,12 @@ impl QueryRunner {
             .stage_has_table_scan()
             .into_iter()
             .collect::<HashSet<_>>();
+        if stages_has_table_scan.is_empty() {
+            // No stage reads the snapshot.
+            self.hummock_snapshot_manager
+                .unpin_snapshot(self.epoch, self.query.query_id())
+                .await?;
+        }
 
         // Schedule stages when leaf stages all scheduled
         while let Some(msg) = self.msg_receiver.recv().await {
@@ -247,8 +271,7 @@ impl QueryRunner {
                         self.query.query_id, stage_id
                     );
                     self.scheduled_stages_count += 1;
-                    stages_has_table_scan.remove(&stage_id);
-                    if stages_has_table_scan.is_empty() {
+                    if stages_has_table_scan.remove(&stage_id) && stages_has_table_scan.is_empty() {
                         // Since all the iterators are created during building the leaf tasks in the
                         // backend, we can be sure here that all the
                         // iterator have been created, thus they all successfully pinned a
@@ -261,21 +284,21 @@ impl QueryRunner {
                     }
 
                     if self.scheduled_stages_count == self.stage_executions.len() {
-                        // Now all stages have been scheduled, send root stage info.
+                        // Now all stages have been scheduled, send root stage info. The results
+                        // are then fetched from the root stage by `QueryResultFetcher`.
                         self.send_root_stage_info().await;
-                    } else {
-                        for parent in self.query.get_parents(&stage_id) {
-                            if self.all_children_scheduled(parent).await {
-                                self.get_stage_execution_unchecked(parent)
-                                    .start()
-                                    .await
-                                    .map_err(|e| {
-                                        error!(
-                                            "Failed to start stage: {}, reason: {:?}",
-                                            stage_id, e
-                                        );
-                                        e
-                                    })?;
+                        break;
+                    }
+
+                    // Start the parent stages whose inputs are all ready.
+                    let parents = self.query.get_parents(&stage_id).clone();
+                    for parent in &parents {
+                        if self.all_children_scheduled(parent).await {
+                            if let Err(e) = self.get_stage_execution_unchecked(parent).start().await
+                            {
+                                error!("Failed to start stage: {}, reason: {:?}", parent, e);
+                                self.fail(e.clone()).await;
+                                return Err(e);
                             }
                         }
                     }
@@ -286,22 +309,8 @@ impl QueryRunner {
                         self.query.query_id, id, reason
                     );
 
-                    // Consume sender here.
-                    let mut tmp_sender = None;
-                    swap(&mut self.root_stage_sender, &mut tmp_sender);
-                    // It's possible we receive stage failed event message multi times and the
-                    // sender has been consumed in first failed event.
-                    if let Some(sender) = tmp_sender {
-                        if let Err(e) = sender.send(Err(reason)) {
-                            warn!("Query execution dropped: {:?}", e);
-                        } else {
-                            debug!(
-                                "Root stage failure event for {:?} sent.",
-                                self.query.query_id
-                            );
-                        }
-                    }
-                    // TODO: We should can cancel all scheduled stages here.
+                    self.fail(reason.clone()).await;
+                    return Err(reason);
                 }
                 _ => {
                     return Err(ErrorCode::NotImplemented(
@@ -317,6 +326,33 @@ impl QueryRunner {
         Ok(())
     }
 
+    /// Stops all stages, aborting their scheduled tasks, and reports the failure to the
+    /// `QueryExecution` waiting for the root stage.
+    async fn fail(&mut self, reason: RwError) {
+        for (stage_id, stage_execution) in self.stage_executions.iter() {
+            if let Err(e) = stage_execution.stop().await {
+                warn!(
+                    "Failed to stop stage {:?}-{:?}: {:?}",
+                    self.query.query_id, stage_id, e
+                );
+            }
+        }
+
+        // Consume sender here.
+        let mut tmp_sender = None;
+        swap(&mut self.root_stage_sender, &mut tmp_sender);
+        if let Some(sender) = tmp_sender {
+            if let Err(e) = sender.send(Err(reason)) {
+                warn!("Query execution dropped: {:?}", e);
+            } else {
+                debug!(
+                    "Root stage failure event for {:?} sent.",
+                    self.query.query_id
+                );
+            }
+        }
+    }
+
     async fn send_root_stage_info(&mut self) {
         let root_task_status = self.stage_executions[&self.query.root_stage_id()]
             .get_task_status_unchecked(ROOT_TASK_ID);
@@ -451,6 +487,7 @@ mod tests {
                 distribution_keys: vec![],
             }),
             vec![],
+            None,
             ctx,
         ))
         .into();
@@ -541,7 +578,7 @@ mod tests {
         let workers = vec![worker1, worker2, worker3];
         let worker_node_manager = Arc::new(WorkerNodeManager::mock(workers));
         // Break the plan node into fragments.
-        let fragmenter = BatchPlanFragmenter::new(worker_node_manager);
+        let fragmenter = BatchPlanFragmenter::new(worker_node_manager, None);
         fragmenter.split(batch_exchange_node3.clone()).unwrap()
     }
 
@@ -13,12 +13,13 @@
 // limitations under the License.
 
 use std::fmt::{Debug, Formatter};
+use std::time::Duration;
 
 use futures::Stream;
 use futures_async_stream::try_stream;
-use log::debug;
+use log::{debug, error, warn};
 use risingwave_common::array::DataChunk;
-use risingwave_common::error::{Result, RwError};
+use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_pb::batch_plan::{PlanNode as BatchPlanProst, TaskId, TaskOutputId};
 use risingwave_pb::common::HostAddress;
 use risingwave_rpc_client::{ComputeClientPoolRef, ExchangeSource};
@@ -29,6 +30,12 @@ use crate::scheduler::plan_fragmenter::{Query, QueryId};
 use crate::scheduler::worker_node_manager::WorkerNodeManagerRef;
 use crate::scheduler::{DataChunkStream, ExecutionContextRef, HummockSnapshotManagerRef};
 
+/// Max number of times a query is retried when it fails to reach a compute node.
+const QUERY_MAX_RETRIES: usize = 3;
+/// Time to wait before retrying a query, e.g. for the failed compute node to be removed from
+/// the cluster.
+const QUERY_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
 pub struct QueryResultFetcher {
     // TODO: Remove these after implemented worker node level snapshot pinnning
     epoch: u64,
@@ -65,7 +72,7 @@ impl QueryManager {
     /// This is kept for dml only.
     pub async fn schedule_single(
         &self,
-        _context: ExecutionContextRef,
+        context: ExecutionContextRef,
         plan: BatchPlanProst,
     ) -> Result<impl Stream<Item = Result<DataChunk>>> {
         let worker_node_addr = self.worker_node_manager.next_random()?.host.unwrap();
@@ -91,7 +98,7 @@ impl QueryManager {
 
         let epoch = self
             .hummock_snapshot_manager
-            .get_epoch(query_id.clone())
+            .acquire_epoch(query_id.clone(), context.session().txn_epoch())
             .await?;
 
         if let Err(e) = compute_client
@@ -115,16 +122,53 @@ impl QueryManager {
         Ok(query_result_fetcher.run())
     }
 
+    /// Schedule a query with its stages distributed on compute nodes, and stream its results
+    /// back from the root stage.
+    ///
+    /// If the query fails to be scheduled because a compute node is unreachable, the tasks already
+    /// scheduled are aborted and the query is retried up to [`QUERY_MAX_RETRIES`] times. Any other
+    /// error, e.g. one returned by the compute node when it builds a task, would only happen again,
+    /// so it's returned at once.
     pub async fn schedule(
         &self,
-        _context: ExecutionContextRef,
+        context: ExecutionContextRef,
         query: Query,
     ) -> Result<impl DataChunkStream> {
+        let mut query = query;
+        let mut retries = 0;
+        loop {
+            let query_id = query.query_id().clone();
+            // Tasks of the retried query must not conflict with the failed ones.
+            let retry_query = query.clone_with_new_id();
+            match self.start_query(&context, query).await {
+                Ok((query_execution, query_result_fetcher)) => {
+                    return Ok(query_result_fetcher.run_query(query_execution));
+                }
+                Err(e) if retries < QUERY_MAX_RETRIES && is_transient(&e) => {
+                    retries += 1;
+                    warn!(
+                        "Failed to schedule query {:?}, retrying ({}/{}): {}",
+                        query_id, retries, QUERY_MAX_RETRIES, e
+                    );
+                    tokio::time::sleep(QUERY_RETRY_INTERVAL).await;
+                    query = retry_query;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Schedule all stages of a query. Returns the execution of the query, and the fetcher of the
+    /// results of its root stage.
+    async fn start_query(
+        &self,
+        context: &ExecutionContextRef,
+        query: Query,
+    ) -> Result<(QueryExecution, QueryResultFetcher)> {
         let query_id = query.query_id().clone();
-        // Cheat compiler to resolve type
         let epoch = self
             .hummock_snapshot_manager
-            .get_epoch(query_id.clone())
+            .acquire_epoch(query_id.clone(), context.session().txn_epoch())
             .await?;
 
         let query_execution = QueryExecution::new(
@@ -135,20 +179,23 @@ impl QueryManager {
             self.compute_client_pool.clone(),
         );
 
-        let query_result_fetcher = match query_execution.start().await {
-            Ok(query_result_fetcher) => query_result_fetcher,
+        match query_execution.start().await {
+            Ok(query_result_fetcher) => Ok((query_execution, query_result_fetcher)),
             Err(e) => {
                 self.hummock_snapshot_manager
                     .unpin_snapshot(epoch, &query_id)
                     .await?;
-                return Err(e);
+                Err(e)
             }
-        };
-
-        Ok(query_result_fetcher.run())
+        }
     }
 }
 
+/// Whether a query that failed to be scheduled with the error may succeed when it's retried.
+fn is_transient(e: &RwError) -> bool {
+    matches!(e.inner(), ErrorCode::RpcError(_))
+}
+
 impl QueryResultFetcher {
     pub fn new(
         epoch: u64,
@@ -184,6 +231,29 @@ impl QueryResultFetcher {
     }
 }
 
+impl QueryResultFetcher {
+    /// Fetches the results of a distributed query. Once a task fails, the error is returned and
+    /// all the other tasks of the query are aborted.
+    #[try_stream(ok = DataChunk, error = RwError)]
+    async fn run_query(self, query_execution: QueryExecution) {
+        #[for_await]
+        for chunk in self.run() {
+            match chunk {
+                Ok(chunk) => yield chunk,
+                Err(e) => {
+                    error!(
+                        "Query {:?} failed while fetching results: {}",
+                        query_execution.query_id(),
+                        e
+                    );
+                    query_execution.abort().await?;
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
+
 impl Debug for QueryResultFetcher {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("QueryResultFetcher")
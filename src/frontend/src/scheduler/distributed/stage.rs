@@ -31,7 +31,7 @@ use tokio::spawn;
 use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tokio::sync::RwLock;
 use tokio::task::JoinHandle;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 use uuid::Uuid;
 use StageEvent::Failed;
 
@@ -195,8 +195,66 @@ impl StageExecution {
         }
     }
 
+    /// Stops execution of this stage. Scheduling is interrupted if still in progress, and all tasks
+    /// that have been scheduled are aborted on their compute nodes.
     pub async fn stop(&self) -> Result<()> {
-        todo!()
+        let mut s = self.state.write().await;
+        let mut cur_state = StageState::Failed;
+        swap(&mut *s, &mut cur_state);
+
+        match cur_state {
+            StageState::Started { handle, .. } => handle.abort(),
+            StageState::Running {
+                _handle: handle, ..
+            } => handle.abort(),
+            StageState::Pending => {
+                // No task has been scheduled yet.
+                return Ok(());
+            }
+            StageState::Completed | StageState::Failed => {
+                *s = cur_state;
+                return Ok(());
+            }
+        }
+
+        info!(
+            "Stopping stage {:?}-{:?}, aborting its tasks.",
+            &self.stage.query_id, &self.stage.id
+        );
+        let futures = self
+            .tasks
+            .iter()
+            .filter_map(|(task_id, status_holder)| {
+                let location = status_holder.get_status().location.clone()?;
+                let task_id = TaskIdProst {
+                    query_id: self.stage.query_id.id.clone(),
+                    stage_id: self.stage.id,
+                    task_id: *task_id,
+                };
+                Some(self.abort_task(task_id, location))
+            })
+            .collect::<Vec<_>>();
+        stream::iter(futures)
+            .buffer_unordered(TASK_SCHEDULING_PARALLELISM)
+            .for_each(|_| async {})
+            .await;
+        Ok(())
+    }
+
+    /// Aborts a scheduled task. Failures are only logged since the task may have finished or
+    /// failed by itself.
+    async fn abort_task(&self, task_id: TaskIdProst, location: HostAddress) {
+        let result = async {
+            self.compute_client_pool
+                .get_client_for_addr((&location).into())
+                .await?
+                .abort_task(task_id.clone())
+                .await
+        }
+        .await;
+        if let Err(e) = result {
+            warn!("Failed to abort task {:?}, reason: {}", task_id, e);
+        }
     }
 
     pub async fn is_scheduled(&self) -> bool {
@@ -263,7 +321,11 @@ impl StageRunner {
                         _handle: handle,
                     };
                 }
-                _ => unreachable!(),
+                state => {
+                    // The stage has been stopped while scheduling its tasks.
+                    *s = state;
+                    return Ok(());
+                }
             }
         }
 
@@ -16,7 +16,7 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use log::error;
-use risingwave_common::error::Result;
+use risingwave_common::error::{internal_error, Result};
 use tokio::sync::Mutex;
 
 use crate::meta_client::FrontendMetaClient;
@@ -46,7 +46,8 @@ impl HummockSnapshotManager {
                 .await?;
             core_guard.is_outdated = false;
             core_guard.last_pinned = epoch;
-            core_guard.epoch_to_query_ids.insert(epoch, HashSet::new());
+            // The epoch may be unchanged and still pinned by a transaction.
+            core_guard.epoch_to_query_ids.entry(epoch).or_default();
         }
         let last_pinned = core_guard.last_pinned;
         core_guard
@@ -57,6 +58,23 @@ impl HummockSnapshotManager {
         Ok(core_guard.last_pinned)
     }
 
+    /// Get the epoch for `query_id` to read. A query in a transaction reads the epoch pinned at the
+    /// start of the transaction, given by `txn_epoch`, and a query outside of any transaction
+    /// reads the latest snapshot.
+    pub async fn acquire_epoch(&self, query_id: QueryId, txn_epoch: Option<u64>) -> Result<u64> {
+        let epoch = match txn_epoch {
+            Some(epoch) => epoch,
+            None => return self.get_epoch(query_id).await,
+        };
+        let mut core_guard = self.core.lock().await;
+        let query_ids = core_guard
+            .epoch_to_query_ids
+            .get_mut(&epoch)
+            .ok_or_else(|| internal_error(format!("epoch {} is not pinned", epoch)))?;
+        query_ids.insert(query_id);
+        Ok(epoch)
+    }
+
     pub async fn unpin_snapshot(&self, epoch: u64, query_id: &QueryId) -> Result<()> {
         let local_count = async {
             let mut core_guard = self.core.lock().await;
@@ -87,6 +105,12 @@ impl HummockSnapshotManager {
         Ok(())
     }
 
+    /// Pins the latest snapshot for the next query, e.g. after a flush, without waiting for the
+    /// notification of it.
+    pub async fn mark_outdated(&self) {
+        self.core.lock().await.is_outdated = true;
+    }
+
     /// Used in `ObserverManager`.
     pub async fn update_snapshot_status(&self, epoch: u64) {
         let mut core_guard = self.core.lock().await;
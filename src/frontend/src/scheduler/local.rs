@@ -32,18 +32,22 @@ pub struct LocalQueryExecution {
     sql: String,
     query: Query,
     hummock_snapshot_manager: HummockSnapshotManagerRef,
+    /// The epoch pinned by the transaction the query runs in, if any.
+    txn_epoch: Option<u64>,
 }
 
 impl LocalQueryExecution {
     pub fn new<S: Into<String>>(
         query: Query,
         hummock_snapshot_manager: HummockSnapshotManagerRef,
+        txn_epoch: Option<u64>,
         sql: S,
     ) -> Self {
         Self {
             sql: sql.into(),
             query,
             hummock_snapshot_manager,
+            txn_epoch,
         }
     }
 
@@ -66,7 +70,10 @@ impl LocalQueryExecution {
             task_id: 0,
         };
 
-        let epoch = self.hummock_snapshot_manager.get_epoch(query_id).await?;
+        let epoch = self
+            .hummock_snapshot_manager
+            .acquire_epoch(query_id, self.txn_epoch)
+            .await?;
         let plan_node = plan_fragment.root.unwrap();
         let executor = ExecutorBuilder::new(&plan_node, &task_id, context, epoch);
         let executor = executor.build().await?;
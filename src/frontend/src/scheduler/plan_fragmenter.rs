@@ -82,6 +82,8 @@ pub struct BatchPlanFragmenter {
     stage_graph_builder: StageGraphBuilder,
     next_stage_id: u32,
     worker_node_manager: WorkerNodeManagerRef,
+    /// Parallelism of non-root stages. Defaults to the number of worker nodes.
+    batch_parallelism: Option<u32>,
 }
 
 impl Default for QueryId {
@@ -93,12 +95,13 @@ impl Default for QueryId {
 }
 
 impl BatchPlanFragmenter {
-    pub fn new(worker_node_manager: WorkerNodeManagerRef) -> Self {
+    pub fn new(worker_node_manager: WorkerNodeManagerRef, batch_parallelism: Option<u32>) -> Self {
         Self {
             query_id: Default::default(),
             stage_graph_builder: StageGraphBuilder::new(),
             next_stage_id: 0,
             worker_node_manager,
+            batch_parallelism,
         }
     }
 }
@@ -138,6 +141,37 @@ impl Query {
         &self.query_id
     }
 
+    /// Returns a copy of this query under a new query id, so that it can be retried without its
+    /// tasks conflicting with those of the failed attempt.
+    pub fn clone_with_new_id(&self) -> Query {
+        let query_id = QueryId::default();
+        let stages = self
+            .stage_graph
+            .stages
+            .iter()
+            .map(|(stage_id, stage)| {
+                let stage = QueryStage {
+                    query_id: query_id.clone(),
+                    id: stage.id,
+                    root: stage.root.clone(),
+                    exchange_info: stage.exchange_info.clone(),
+                    parallelism: stage.parallelism,
+                    has_table_scan: stage.has_table_scan,
+                };
+                (*stage_id, Arc::new(stage))
+            })
+            .collect();
+        Query {
+            query_id,
+            stage_graph: StageGraph {
+                root_stage_id: self.stage_graph.root_stage_id,
+                stages,
+                child_edges: self.stage_graph.child_edges.clone(),
+                parent_edges: self.stage_graph.parent_edges.clone(),
+            },
+        }
+    }
+
     pub fn stage_has_table_scan(&self) -> Vec<StageId> {
         self.stage_graph
             .stages
@@ -317,7 +351,10 @@ impl BatchPlanFragmenter {
         self.next_stage_id += 1;
         let parallelism = match parent_parallelism {
             // Non-root node
-            Some(_) => self.worker_node_manager.worker_node_count(),
+            Some(_) => self.batch_parallelism.map_or_else(
+                || self.worker_node_manager.worker_node_count(),
+                |p| p as usize,
+            ),
             // Root node.
             None => 1,
         };
@@ -363,7 +400,7 @@ impl BatchPlanFragmenter {
                     builder.root = Some(Arc::new(execution_plan_node));
                 }
                 // Check out the comments for `has_table_scan` in `QueryStage`.
-                builder.has_table_scan = node.node_type() == PlanNodeType::BatchSeqScan;
+                builder.has_table_scan |= node.node_type() == PlanNodeType::BatchSeqScan;
             }
         }
     }
@@ -456,6 +493,7 @@ mod tests {
                 distribution_keys: vec![],
             }),
             vec![],
+            None,
             ctx,
         ))
         .into();
@@ -546,7 +584,7 @@ mod tests {
         let workers = vec![worker1, worker2, worker3];
         let worker_node_manager = Arc::new(WorkerNodeManager::mock(workers));
         // Break the plan node into fragments.
-        let fragmenter = BatchPlanFragmenter::new(worker_node_manager);
+        let fragmenter = BatchPlanFragmenter::new(worker_node_manager, None);
         let query = fragmenter.split(batch_exchange_node3.clone()).unwrap();
 
         assert_eq!(query.stage_graph.root_stage_id, 0);
@@ -619,6 +657,20 @@ mod tests {
         assert_eq!(scan_node2.root.node_type(), PlanNodeType::BatchSeqScan);
         assert_eq!(scan_node2.root.stage_id, None);
         assert_eq!(0, scan_node2.root.children.len());
+
+        // Only the leaf stages scan tables.
+        let mut stages_has_table_scan = query.stage_has_table_scan();
+        stages_has_table_scan.sort_unstable();
+        assert_eq!(stages_has_table_scan, vec![2, 3]);
+
+        // A retried query keeps the stages under a new query id.
+        let retried_query = query.clone_with_new_id();
+        assert_ne!(retried_query.query_id, query.query_id);
+        assert_eq!(retried_query.stage_graph.stages.len(), 4);
+        assert_eq!(retried_query.stage_graph.child_edges[&1], [2, 3].into());
+        for stage in retried_query.stage_graph.stages.values() {
+            assert_eq!(stage.query_id, retried_query.query_id);
+        }
     }
 
     fn generate_parallel_units(start_id: u32, node_id: u32) -> Vec<ParallelUnit> {
@@ -16,32 +16,51 @@ use std::sync::Arc;
 
 use risingwave_batch::executor::BatchMetrics;
 use risingwave_batch::task::{BatchTaskContext, TaskOutput, TaskOutputId};
+use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_source::SourceManagerRef;
 
 /// Batch task execution context in frontend.
-#[derive(Clone, Default)]
-pub struct FrontendBatchTaskContext {}
+///
+/// The frontend only runs the root fragment of a local query, which reads from compute nodes
+/// through exchanges, so no task outputs, sources or state stores are available here.
+#[derive(Clone)]
+pub struct FrontendBatchTaskContext {
+    stats: Arc<BatchMetrics>,
+}
+
+impl Default for FrontendBatchTaskContext {
+    fn default() -> Self {
+        Self {
+            stats: Arc::new(BatchMetrics::unused()),
+        }
+    }
+}
 
 impl BatchTaskContext for FrontendBatchTaskContext {
-    fn get_task_output(&self, _task_output_id: TaskOutputId) -> Result<TaskOutput> {
-        todo!()
+    fn get_task_output(&self, task_output_id: TaskOutputId) -> Result<TaskOutput> {
+        Err(InternalError(format!(
+            "Task output {:?} is not available in frontend",
+            task_output_id
+        ))
+        .into())
     }
 
     fn is_local_addr(&self, _peer_addr: &HostAddr) -> bool {
-        todo!()
+        // No task runs in frontend, so exchange sources are always remote.
+        false
     }
 
     fn source_manager_ref(&self) -> Option<SourceManagerRef> {
-        todo!()
+        None
     }
 
     fn state_store(&self) -> Option<risingwave_storage::store_impl::StateStoreImpl> {
-        todo!()
+        None
     }
 
     fn stats(&self) -> Arc<BatchMetrics> {
-        todo!()
+        self.stats.clone()
     }
 }
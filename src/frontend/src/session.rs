@@ -20,28 +20,33 @@ use std::sync::atomic::{AtomicI32, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
-use parking_lot::RwLock;
-use pgwire::pg_response::PgResponse;
+use parking_lot::{Mutex, RwLock, RwLockReadGuard};
+use pgwire::pg_response::{PgResponse, StatementType};
 use pgwire::pg_server::{BoxedError, Session, SessionManager};
+use risingwave_common::array::{Op, Row};
+use risingwave_common::catalog::{CatalogVersion, TableId};
 use risingwave_common::config::FrontendConfig;
-use risingwave_common::error::{Result, RwError};
+use risingwave_common::error::{internal_error, ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::common::WorkerType;
 use risingwave_rpc_client::{ComputeClientPool, MetaClient};
+use risingwave_sqlparser::ast::Statement;
 use risingwave_sqlparser::parser::Parser;
 use tokio::sync::oneshot::Sender;
-use tokio::sync::watch;
+use tokio::sync::{watch, OwnedMutexGuard};
 use tokio::task::JoinHandle;
 
+use crate::binder::{BoundStatement, BoundUniqueIndex};
 use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWriterImpl};
 use crate::catalog::root_catalog::Catalog;
-use crate::handler::dml::IMPLICIT_FLUSH;
+use crate::config::SessionConfig;
 use crate::handler::handle;
 use crate::meta_client::{FrontendMetaClient, FrontendMetaClientImpl};
 use crate::observer::observer_manager::ObserverManager;
 use crate::optimizer::plan_node::PlanNodeId;
 use crate::scheduler::worker_node_manager::{WorkerNodeManager, WorkerNodeManagerRef};
-use crate::scheduler::{HummockSnapshotManager, HummockSnapshotManagerRef, QueryManager};
+use crate::scheduler::{HummockSnapshotManager, HummockSnapshotManagerRef, QueryId, QueryManager};
 use crate::test_utils::MockUserInfoWriter;
 use crate::user::user_manager::UserInfoManager;
 use crate::user::user_service::{UserInfoReader, UserInfoWriter, UserInfoWriterImpl};
@@ -132,6 +137,8 @@ pub struct FrontendEnv {
     worker_node_manager: WorkerNodeManagerRef,
     query_manager: QueryManager,
     hummock_snapshot_manager: HummockSnapshotManagerRef,
+    /// The locks to serialize the DML on each table with unique indexes.
+    unique_dml_locks: Arc<Mutex<HashMap<TableId, Arc<tokio::sync::Mutex<()>>>>>,
 }
 
 impl FrontendEnv {
@@ -169,6 +176,7 @@ impl FrontendEnv {
             worker_node_manager,
             query_manager,
             hummock_snapshot_manager,
+            unique_dml_locks: Default::default(),
         }
     }
 
@@ -248,6 +256,7 @@ impl FrontendEnv {
                 meta_client: frontend_meta_client,
                 query_manager,
                 hummock_snapshot_manager,
+                unique_dml_locks: Default::default(),
             },
             observer_join_handle,
             heartbeat_join_handle,
@@ -298,36 +307,75 @@ impl FrontendEnv {
     pub fn hummock_snapshot_manager(&self) -> &HummockSnapshotManagerRef {
         &self.hummock_snapshot_manager
     }
+
+    /// Locks the table for a DML that checks its unique indexes, after the other DML that holds the
+    /// lock in this frontend finishes.
+    pub async fn lock_unique_dml(&self, table_id: TableId) -> OwnedMutexGuard<()> {
+        let lock = self
+            .unique_dml_locks
+            .lock()
+            .entry(table_id)
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
 }
 
 pub struct SessionImpl {
     env: FrontendEnv,
     database: String,
     /// Stores the value of configurations.
-    config_map: RwLock<HashMap<String, ConfigEntry>>,
+    config: RwLock<SessionConfig>,
+    /// Statements created by `PREPARE` in this session, keyed by their names.
+    prepared_statements: RwLock<HashMap<String, PreparedStatement>>,
+    /// The transaction started by `BEGIN`, if any.
+    transaction: RwLock<Option<Transaction>>,
 }
 
-#[derive(Clone)]
-pub struct ConfigEntry {
-    str_val: String,
+/// A transaction started by `BEGIN`. All reads in the transaction see the snapshot pinned at the
+/// start of it, and all writes are buffered until `COMMIT`.
+pub struct Transaction {
+    /// Identifies the transaction as the holder of the pinned snapshot.
+    pub id: QueryId,
+    /// The epoch of the pinned snapshot.
+    pub epoch: u64,
+    /// Rows written by INSERT/DELETE/UPDATE statements to apply at `COMMIT`, by table.
+    pub writes: HashMap<TableId, BufferedWrites>,
 }
 
-impl ConfigEntry {
-    pub fn new(str_val: String) -> Self {
-        ConfigEntry { str_val }
-    }
+/// Rows written to a table in a transaction, in the order they are written. A row is the row id
+/// followed by the columns of the table, where the row id of an inserted row is null until the
+/// row is applied.
+pub struct BufferedWrites {
+    pub data_types: Vec<DataType>,
+    pub rows: Vec<(Op, Row)>,
+    /// The unique indexes on the table, which are checked when the rows are applied.
+    pub unique_indexes: Vec<BoundUniqueIndex>,
+}
 
-    /// Only used for boolean configurations.
-    pub fn is_set(&self, default: bool) -> bool {
-        self.str_val.parse().unwrap_or(default)
-    }
+/// A statement created by `PREPARE`, which is executed many times with different parameters.
+///
+/// `EXECUTE` reuses the bound statement until the catalog or the `search_path` changes. Then the
+/// statement is bound again, so that it always refers to the current catalog even if the relations
+/// it reads are dropped or recreated after `PREPARE`.
+#[derive(Clone)]
+pub struct PreparedStatement {
+    pub statement: Statement,
+    /// Types of the parameters declared in `PREPARE`.
+    pub param_types: Vec<DataType>,
+    pub stmt_type: StatementType,
+    /// Number of parameters `EXECUTE` must supply.
+    pub param_count: usize,
+    /// The statement bound by `PREPARE` or the last `EXECUTE` that bound it again.
+    pub bound: BoundPreparedStatement,
+}
 
-    pub fn get_val<V>(&self, default: V) -> V
-    where
-        for<'a> V: TryFrom<&'a str, Error = RwError>,
-    {
-        V::try_from(&self.str_val).unwrap_or(default)
-    }
+/// A prepared statement bound with its parameters left as `$n`, and what it was bound against.
+#[derive(Clone)]
+pub struct BoundPreparedStatement {
+    pub catalog_version: CatalogVersion,
+    pub search_path: Vec<String>,
+    pub statement: BoundStatement,
 }
 
 impl SessionImpl {
@@ -335,7 +383,9 @@ impl SessionImpl {
         Self {
             env,
             database,
-            config_map: Self::init_config_map(),
+            config: RwLock::new(SessionConfig::default()),
+            prepared_statements: RwLock::new(HashMap::new()),
+            transaction: RwLock::new(None),
         }
     }
 
@@ -344,7 +394,9 @@ impl SessionImpl {
         Self {
             env: FrontendEnv::mock(),
             database: "dev".to_string(),
-            config_map: Self::init_config_map(),
+            config: RwLock::new(SessionConfig::default()),
+            prepared_statements: RwLock::new(HashMap::new()),
+            transaction: RwLock::new(None),
         }
     }
 
@@ -356,31 +408,153 @@ impl SessionImpl {
         &self.database
     }
 
+    /// Get the configurations of this session.
+    pub fn config(&self) -> RwLockReadGuard<'_, SessionConfig> {
+        self.config.read()
+    }
+
     /// Set configuration values in this session.
-    /// For example, `set_config("RW_IMPLICIT_FLUSH", true)` will implicit flush for every inserts.
-    pub fn set_config(&self, key: &str, val: &str) {
-        self.config_map
+    /// For example, `set_config("implicit_flush", "true")` will implicit flush for every inserts.
+    pub fn set_config(&self, key: &str, val: &str) -> Result<()> {
+        self.config.write().set(key, val)
+    }
+
+    /// Restore the configuration `key` to its default value.
+    pub fn reset_config(&self, key: &str) -> Result<()> {
+        self.config.write().reset(key)
+    }
+
+    /// Restore all configurations to their default values.
+    pub fn reset_all_config(&self) {
+        *self.config.write() = SessionConfig::default();
+    }
+
+    /// Store a prepared statement in this session. Fails if the name is already in use.
+    pub fn add_prepared_statement(&self, name: &str, stmt: PreparedStatement) -> Result<()> {
+        let mut prepared_statements = self.prepared_statements.write();
+        if prepared_statements.contains_key(name) {
+            return Err(ErrorCode::InvalidInputSyntax(format!(
+                "prepared statement \"{}\" already exists",
+                name
+            ))
+            .into());
+        }
+        prepared_statements.insert(name.to_string(), stmt);
+        Ok(())
+    }
+
+    /// Get a prepared statement of this session by name.
+    pub fn get_prepared_statement(&self, name: &str) -> Result<PreparedStatement> {
+        self.prepared_statements
+            .read()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| prepared_statement_not_found(name))
+    }
+
+    /// Replace the bound statement of a prepared statement, after it is bound again by `EXECUTE`.
+    pub fn update_bound_prepared_statement(&self, name: &str, bound: BoundPreparedStatement) {
+        if let Some(stmt) = self.prepared_statements.write().get_mut(name) {
+            stmt.bound = bound;
+        }
+    }
+
+    /// Remove a prepared statement of this session by name.
+    pub fn remove_prepared_statement(&self, name: &str) -> Result<()> {
+        self.prepared_statements
             .write()
-            .insert(key.to_string(), ConfigEntry::new(val.to_string()));
+            .remove(name)
+            .map(|_| ())
+            .ok_or_else(|| prepared_statement_not_found(name))
+    }
+
+    /// Remove all prepared statements of this session.
+    pub fn clear_prepared_statements(&self) {
+        self.prepared_statements.write().clear();
+    }
+
+    /// Start a transaction. The caller should have pinned the snapshot of `txn.epoch`.
+    pub fn start_transaction(&self, txn: Transaction) {
+        *self.transaction.write() = Some(txn);
+    }
+
+    /// End the current transaction and return it, so that the caller can apply or discard its
+    /// writes and unpin its snapshot.
+    pub fn end_transaction(&self) -> Option<Transaction> {
+        self.transaction.write().take()
+    }
+
+    pub fn in_transaction(&self) -> bool {
+        self.transaction.read().is_some()
+    }
+
+    /// The epoch pinned by the current transaction, if any.
+    pub fn txn_epoch(&self) -> Option<u64> {
+        self.transaction.read().as_ref().map(|txn| txn.epoch)
+    }
+
+    /// Buffer rows written to the table `table_id` in the current transaction.
+    pub fn buffer_writes(
+        &self,
+        table_id: TableId,
+        data_types: Vec<DataType>,
+        rows: Vec<(Op, Row)>,
+        unique_indexes: Vec<BoundUniqueIndex>,
+    ) -> Result<()> {
+        match &mut *self.transaction.write() {
+            Some(txn) => {
+                txn.writes
+                    .entry(table_id)
+                    .or_insert_with(|| BufferedWrites {
+                        data_types,
+                        rows: vec![],
+                        unique_indexes,
+                    })
+                    .rows
+                    .extend(rows);
+                Ok(())
+            }
+            None => Err(internal_error("no transaction in progress")),
+        }
     }
 
-    /// Get configuration values in this session.
-    pub fn get_config(&self, key: &str) -> Option<ConfigEntry> {
-        let reader = self.config_map.read();
-        reader.get(key).cloned()
+    /// The tables with writes buffered in the current transaction.
+    pub fn buffered_table_ids(&self) -> Vec<TableId> {
+        self.transaction
+            .read()
+            .as_ref()
+            .map(|txn| txn.writes.keys().copied().collect())
+            .unwrap_or_default()
     }
 
-    fn init_config_map() -> RwLock<HashMap<String, ConfigEntry>> {
-        let mut map = HashMap::new();
-        // FIXME: May need better init way + default config.
-        map.insert(
-            IMPLICIT_FLUSH.to_string(),
-            ConfigEntry::new("false".to_string()),
-        );
-        RwLock::new(map)
+    /// Take the writes buffered in the current transaction, or `None` if there is no transaction
+    /// in progress. The transaction itself is kept.
+    pub fn take_buffered_writes(&self) -> Option<HashMap<TableId, BufferedWrites>> {
+        self.transaction
+            .write()
+            .as_mut()
+            .map(|txn| std::mem::take(&mut txn.writes))
     }
 }
 
+impl Drop for SessionImpl {
+    /// Discards the transaction left open when the connection is closed, and unpins its snapshot.
+    fn drop(&mut self) {
+        if let Some(Transaction { id, epoch, .. }) = self.end_transaction() {
+            let snapshot_manager = self.env.hummock_snapshot_manager().clone();
+            tokio::spawn(async move {
+                if let Err(err) = snapshot_manager.unpin_snapshot(epoch, &id).await {
+                    tracing::warn!("failed to unpin the snapshot of a transaction: {}", err);
+                }
+            });
+        }
+    }
+}
+
+fn prepared_statement_not_found(name: &str) -> RwError {
+    ErrorCode::InvalidInputSyntax(format!("prepared statement \"{}\" does not exist", name)).into()
+}
+
 pub struct SessionManagerImpl {
     env: FrontendEnv,
     observer_join_handle: JoinHandle<()>,
@@ -99,6 +99,8 @@ impl StreamFragmenter {
                 // add an exchange.
                 NodeBody::HashAgg(_)
                 | NodeBody::HashJoin(_)
+                | NodeBody::NestedLoopJoin(_)
+                | NodeBody::DynamicFilter(_)
                 | NodeBody::DeltaIndexJoin(_)
                 | NodeBody::Chain(_) => {
                     // We didn't make `fields` available on Java frontend yet, so we check if schema
@@ -185,10 +187,17 @@ impl StreamFragmenter {
         match stream_node.get_node_body()? {
             NodeBody::Source(_) => current_fragment.fragment_type = FragmentType::Source,
 
+            // `Now` has no input either, so it receives barriers like a source. There's only one
+            // value of `now()`, so it runs in a single actor.
+            NodeBody::Now(_) => {
+                current_fragment.fragment_type = FragmentType::Source;
+                current_fragment.is_singleton = true;
+            }
+
             NodeBody::Materialize(_) => current_fragment.fragment_type = FragmentType::Sink,
 
             // TODO: Force singleton for TopN as a workaround. We should implement two phase TopN.
-            NodeBody::TopN(_) => current_fragment.is_singleton = true,
+            NodeBody::TopN(_) | NodeBody::AppendOnlyTopN(_) => current_fragment.is_singleton = true,
 
             NodeBody::Chain(ref node) => {
                 // memorize table id for later use
@@ -329,6 +338,16 @@ impl StreamFragmenter {
                 hash_join_node.right_table_id = state.gen_table_id();
             }
 
+            NodeBody::NestedLoopJoin(nested_loop_join_node) => {
+                nested_loop_join_node.left_table_id = state.gen_table_id();
+                nested_loop_join_node.right_table_id = state.gen_table_id();
+            }
+
+            NodeBody::DynamicFilter(dynamic_filter_node) => {
+                dynamic_filter_node.left_table_id = state.gen_table_id();
+                dynamic_filter_node.right_table_id = state.gen_table_id();
+            }
+
             NodeBody::GlobalSimpleAgg(node) | NodeBody::LocalSimpleAgg(node) => {
                 for _ in &node.agg_calls {
                     node.table_ids.push(state.gen_table_id());
@@ -346,6 +365,10 @@ impl StreamFragmenter {
                 top_n_node.table_id = state.gen_table_id();
             }
 
+            NodeBody::GroupTopN(group_top_n_node) => {
+                group_top_n_node.table_id = state.gen_table_id();
+            }
+
             _ => {}
         }
     }
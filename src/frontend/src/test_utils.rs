@@ -25,10 +25,14 @@ use risingwave_common::catalog::{
     DEFAULT_SUPPER_USER_PASSWORD,
 };
 use risingwave_common::error::Result;
+use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    TableStatistics as ProstTableStatistics,
 };
+use risingwave_pb::ddl_service::comment_on_request;
+use risingwave_pb::plan_common::ColumnCatalog as ProstColumnCatalog;
 use risingwave_pb::stream_plan::StreamFragmentGraph;
 use risingwave_pb::user::auth_info::EncryptionType;
 use risingwave_pb::user::{AuthInfo, GrantPrivilege, UserInfo};
@@ -93,10 +97,7 @@ impl LocalFrontend {
             let session = self.session_ref();
 
             let bound = {
-                let mut binder = Binder::new(
-                    session.env().catalog_reader().read_guard(),
-                    session.database().to_string(),
-                );
+                let mut binder = Binder::new_for_session(&session);
                 binder.bind(Statement::Query(query.clone()))?
             };
             Planner::new(OptimizerContext::new(session).into())
@@ -121,6 +122,8 @@ pub struct MockCatalogWriter {
     id: AtomicU32,
     table_id_to_schema_id: RwLock<HashMap<u32, SchemaId>>,
     schema_id_to_database_id: RwLock<HashMap<u32, DatabaseId>>,
+    /// The frontend catalog can't be converted back to a [`ProstSource`], so keep them here.
+    sources: RwLock<HashMap<u32, ProstSource>>,
 }
 
 #[async_trait::async_trait]
@@ -130,6 +133,7 @@ impl CatalogWriter for MockCatalogWriter {
             name: db_name.to_string(),
             id: self.gen_id(),
         });
+        self.bump_version();
         Ok(())
     }
 
@@ -141,6 +145,7 @@ impl CatalogWriter for MockCatalogWriter {
             database_id: db_id,
         });
         self.add_schema_id(id, db_id);
+        self.bump_version();
         Ok(())
     }
 
@@ -152,6 +157,7 @@ impl CatalogWriter for MockCatalogWriter {
         table.id = self.gen_id();
         self.catalog.write().create_table(&table);
         self.add_table_or_source_id(table.id, table.schema_id, table.database_id);
+        self.bump_version();
         Ok(())
     }
 
@@ -165,6 +171,7 @@ impl CatalogWriter for MockCatalogWriter {
         table.optional_associated_source_id =
             Some(OptionalAssociatedSourceId::AssociatedSourceId(source_id));
         self.create_materialized_view(table, graph).await?;
+        self.bump_version();
         Ok(())
     }
 
@@ -181,6 +188,7 @@ impl CatalogWriter for MockCatalogWriter {
         self.catalog
             .write()
             .drop_source(database_id, schema_id, source_id);
+        self.bump_version();
         Ok(())
     }
 
@@ -189,6 +197,7 @@ impl CatalogWriter for MockCatalogWriter {
         self.catalog
             .write()
             .drop_table(database_id, schema_id, table_id);
+        self.bump_version();
         Ok(())
     }
 
@@ -197,17 +206,88 @@ impl CatalogWriter for MockCatalogWriter {
         self.catalog
             .write()
             .drop_source(database_id, schema_id, source_id);
+        self.bump_version();
         Ok(())
     }
 
     async fn drop_database(&self, database_id: u32) -> Result<()> {
         self.catalog.write().drop_database(database_id);
+        self.bump_version();
         Ok(())
     }
 
     async fn drop_schema(&self, schema_id: u32) -> Result<()> {
         let database_id = self.drop_schema_id(schema_id);
         self.catalog.write().drop_schema(database_id, schema_id);
+        self.bump_version();
+        Ok(())
+    }
+
+    async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<()> {
+        let schema_id = self.get_schema_id_by_table(table_id.table_id);
+        let database_id = self.get_database_id_by_schema(schema_id);
+        let mut table = self
+            .catalog
+            .read()
+            .get_table_by_id(database_id, schema_id, &table_id)
+            .unwrap()
+            .to_prost(schema_id, database_id)?;
+        table.statistics = Some(statistics);
+        self.catalog.write().update_table(&table);
+        self.bump_version();
+        Ok(())
+    }
+
+    async fn comment_on(
+        &self,
+        object: comment_on_request::Object,
+        column_name: String,
+        comment: String,
+    ) -> Result<()> {
+        let set_description = |columns: &mut [ProstColumnCatalog], comment: String| {
+            columns
+                .iter_mut()
+                .find(|c| c.column_desc.as_ref().unwrap().name == column_name)
+                .unwrap()
+                .description = comment;
+        };
+        match object {
+            comment_on_request::Object::TableId(table_id) => {
+                let schema_id = self.get_schema_id_by_table(table_id);
+                let database_id = self.get_database_id_by_schema(schema_id);
+                let mut table = self
+                    .catalog
+                    .read()
+                    .get_table_by_id(database_id, schema_id, &table_id.into())
+                    .unwrap()
+                    .to_prost(schema_id, database_id)?;
+                if column_name.is_empty() {
+                    table.description = comment;
+                } else {
+                    set_description(&mut table.columns, comment);
+                }
+                self.catalog.write().update_table(&table);
+            }
+            comment_on_request::Object::SourceId(source_id) => {
+                let mut sources = self.sources.write();
+                let source = sources.get_mut(&source_id).unwrap();
+                if column_name.is_empty() {
+                    source.description = comment;
+                } else {
+                    let columns = match source.info.as_mut().unwrap() {
+                        Info::StreamSource(info) => &mut info.columns,
+                        Info::TableSource(info) => &mut info.columns,
+                    };
+                    set_description(columns, comment);
+                }
+                self.catalog.write().update_source(source);
+            }
+        }
+        self.bump_version();
         Ok(())
     }
 }
@@ -230,9 +310,17 @@ impl MockCatalogWriter {
             id: AtomicU32::new(0),
             table_id_to_schema_id: Default::default(),
             schema_id_to_database_id: RwLock::new(map),
+            sources: Default::default(),
         }
     }
 
+    /// Advance the catalog version after a change, like the meta service does.
+    fn bump_version(&self) {
+        let mut catalog = self.catalog.write();
+        let version = catalog.version();
+        catalog.set_version(version + 1);
+    }
+
     fn gen_id(&self) -> u32 {
         // Since the 0 value is `dev` schema and database, so jump out the 0 value.
         self.id.fetch_add(1, Ordering::SeqCst) + 1
@@ -250,6 +338,7 @@ impl MockCatalogWriter {
             .write()
             .remove(&table_id)
             .unwrap();
+        self.sources.write().remove(&table_id);
         (self.get_database_id_by_schema(schema_id), schema_id)
     }
 
@@ -270,9 +359,15 @@ impl MockCatalogWriter {
         source.id = self.gen_id();
         self.catalog.write().create_source(source.clone());
         self.add_table_or_source_id(source.id, source.schema_id, source.database_id);
+        self.sources.write().insert(source.id, source.clone());
+        self.bump_version();
         Ok(source.id)
     }
 
+    fn get_schema_id_by_table(&self, table_id: u32) -> SchemaId {
+        *self.table_id_to_schema_id.read().get(&table_id).unwrap()
+    }
+
     fn get_database_id_by_schema(&self, schema_id: u32) -> DatabaseId {
         *self
             .schema_id_to_database_id
@@ -43,6 +43,10 @@ impl UserInfoManager {
         self.users.get(user_name)
     }
 
+    pub fn iter_users(&self) -> impl Iterator<Item = &UserInfo> {
+        self.users.values()
+    }
+
     pub fn create_user(&mut self, user_info: UserInfo) {
         self.users
             .try_insert(user_info.name.clone(), user_info)
@@ -14,14 +14,17 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::ops::Bound;
 
 use fixedbitset::FixedBitSet;
 use itertools::Itertools;
+use risingwave_common::types::ScalarImpl;
 
 use crate::expr::{
-    factorization_expr, fold_boolean_constant, push_down_not, to_conjunctions,
-    try_get_bool_constant, ExprImpl, ExprRewriter, ExprType, ExprVisitor, InputRef,
+    factorization_expr, fold_boolean_constant, push_down_not, to_conjunctions, to_disjunctions,
+    try_get_bool_constant, Expr, ExprImpl, ExprRewriter, ExprType, ExprVisitor, InputRef,
 };
+use crate::utils::ScanRange;
 
 #[derive(Debug, Clone)]
 pub struct Condition {
@@ -186,6 +189,191 @@ impl Condition {
         }
     }
 
+    /// Returns the column index, the comparison type and the value of a comparison between a column
+    /// and a non-null literal of the same type, normalized in the form of `column op value`.
+    fn as_comparison_const(expr: &ExprImpl) -> Option<(usize, ExprType, ScalarImpl)> {
+        if let ExprImpl::FunctionCall(function_call) = expr
+            && let [left, right] = function_call.inputs()
+        {
+            let expr_type = function_call.get_expr_type();
+            let (input_ref, literal, expr_type) = match (left, right) {
+                (ExprImpl::InputRef(x), ExprImpl::Literal(y)) => (x, y, expr_type),
+                (ExprImpl::Literal(x), ExprImpl::InputRef(y)) => {
+                    let reversed = match expr_type {
+                        ExprType::Equal => ExprType::Equal,
+                        ExprType::LessThan => ExprType::GreaterThan,
+                        ExprType::LessThanOrEqual => ExprType::GreaterThanOrEqual,
+                        ExprType::GreaterThan => ExprType::LessThan,
+                        ExprType::GreaterThanOrEqual => ExprType::LessThanOrEqual,
+                        _ => return None,
+                    };
+                    (y, x, reversed)
+                }
+                _ => return None,
+            };
+            if input_ref.return_type() != literal.return_type() {
+                return None;
+            }
+            let value = literal.get_data().clone()?;
+            match expr_type {
+                ExprType::Equal
+                | ExprType::LessThan
+                | ExprType::LessThanOrEqual
+                | ExprType::GreaterThan
+                | ExprType::GreaterThanOrEqual => Some((input_ref.index(), expr_type, value)),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the distinct values of the column `col_idx` in an `IN` list of literals, or in a
+    /// disjunction of equality conditions between the column and literals, like `v IN (1, 2)` or
+    /// `v = 1 OR v = 2`. Nulls in the `IN` list are skipped since they never match.
+    fn as_in_const_list(expr: &ExprImpl, col_idx: usize) -> Option<Vec<ScalarImpl>> {
+        let mut values: Vec<ScalarImpl> = vec![];
+        if let ExprImpl::FunctionCall(function_call) = expr
+            && function_call.get_expr_type() == ExprType::In
+        {
+            let (input_ref, list) = match function_call.inputs() {
+                [ExprImpl::InputRef(input_ref), list @ ..] if input_ref.index() == col_idx => {
+                    (input_ref, list)
+                }
+                _ => return None,
+            };
+            for item in list {
+                match item {
+                    ExprImpl::Literal(literal)
+                        if literal.return_type() == input_ref.return_type() =>
+                    {
+                        if let Some(value) = literal.get_data() && !values.contains(value) {
+                            values.push(value.clone());
+                        }
+                    }
+                    _ => return None,
+                }
+            }
+        } else {
+            let disjunctions = to_disjunctions(expr.clone());
+            if disjunctions.len() < 2 {
+                return None;
+            }
+            for expr in &disjunctions {
+                match Self::as_comparison_const(expr) {
+                    Some((idx, ExprType::Equal, value)) if idx == col_idx => {
+                        if !values.contains(&value) {
+                            values.push(value);
+                        }
+                    }
+                    _ => return None,
+                }
+            }
+        }
+
+        if values.is_empty() {
+            None
+        } else {
+            Some(values)
+        }
+    }
+
+    /// Split the condition into the ranges to scan on the order key `order_key` of a table, and
+    /// the remaining conditions. No range is returned if the whole table has to be scanned.
+    ///
+    /// Equality conditions on a prefix of the order key are consumed into the ranges. An `IN` list
+    /// or a disjunction of equality conditions on a column of the prefix results in a range for
+    /// each of its values, as long as there are at most `max_num_ranges` ranges. Bounds on the
+    /// next column of the order key are also derived into the ranges, but they are kept in the
+    /// remaining conditions, since a range open at one side can include nulls.
+    #[must_use]
+    pub fn split_to_scan_ranges(
+        self,
+        order_key: &[usize],
+        max_num_ranges: usize,
+    ) -> (Vec<ScanRange>, Self) {
+        let mut eq_conds: Vec<Vec<ScalarImpl>> = vec![vec![]];
+        let mut range = (Bound::Unbounded, Bound::Unbounded);
+        let mut others = self.conjunctions;
+
+        for &col_idx in order_key {
+            let eq_cond = others.iter().position(|expr| {
+                matches!(
+                    Self::as_comparison_const(expr),
+                    Some((idx, ExprType::Equal, _)) if idx == col_idx
+                )
+            });
+            if let Some(pos) = eq_cond {
+                let (_, _, value) = Self::as_comparison_const(&others.remove(pos)).unwrap();
+                for prefix in &mut eq_conds {
+                    prefix.push(value.clone());
+                }
+                continue;
+            }
+
+            let in_list = others.iter().enumerate().find_map(|(pos, expr)| {
+                Self::as_in_const_list(expr, col_idx).map(|values| (pos, values))
+            });
+            if let Some((pos, values)) = in_list
+                && eq_conds.len() * values.len() <= max_num_ranges
+            {
+                others.remove(pos);
+                eq_conds = eq_conds
+                    .into_iter()
+                    .cartesian_product(values)
+                    .map(|(mut prefix, value)| {
+                        prefix.push(value);
+                        prefix
+                    })
+                    .collect();
+                continue;
+            }
+
+            let (lower, upper) = &mut range;
+            for (idx, expr_type, value) in others.iter().filter_map(Self::as_comparison_const) {
+                if idx != col_idx {
+                    continue;
+                }
+                match expr_type {
+                    ExprType::GreaterThan if *lower == Bound::Unbounded => {
+                        *lower = Bound::Excluded(value)
+                    }
+                    ExprType::GreaterThanOrEqual if *lower == Bound::Unbounded => {
+                        *lower = Bound::Included(value)
+                    }
+                    ExprType::LessThan if *upper == Bound::Unbounded => {
+                        *upper = Bound::Excluded(value)
+                    }
+                    ExprType::LessThanOrEqual if *upper == Bound::Unbounded => {
+                        *upper = Bound::Included(value)
+                    }
+                    _ => {}
+                }
+            }
+            break;
+        }
+
+        let scan_ranges = if eq_conds[0].is_empty() && range == (Bound::Unbounded, Bound::Unbounded)
+        {
+            vec![]
+        } else {
+            eq_conds
+                .into_iter()
+                .map(|eq_conds| ScanRange {
+                    eq_conds,
+                    range: range.clone(),
+                })
+                .collect()
+        };
+
+        (
+            scan_ranges,
+            Condition {
+                conjunctions: others,
+            },
+        )
+    }
+
     #[must_use]
     /// For [`EqJoinPredicate`], separate equality conditions which connect left columns and right
     /// columns from other conditions.
@@ -338,7 +526,7 @@ mod tests {
     use risingwave_common::types::DataType;
 
     use super::*;
-    use crate::expr::{FunctionCall, InputRef};
+    use crate::expr::{FunctionCall, InputRef, Literal};
 
     #[test]
     fn test_split() {
@@ -396,4 +584,86 @@ mod tests {
         assert_eq!(res.1.conjunctions, vec![right]);
         assert_eq!(res.2.conjunctions, vec![other]);
     }
+
+    #[test]
+    fn test_split_to_scan_ranges() {
+        let ty = DataType::Int32;
+        let cmp = |expr_type, left: ExprImpl, right: ExprImpl| -> ExprImpl {
+            FunctionCall::new(expr_type, vec![left, right])
+                .unwrap()
+                .into()
+        };
+        let col = |idx| -> ExprImpl { InputRef::new(idx, ty.clone()).into() };
+        let lit = |v: i32| -> ExprImpl { Literal::new(Some(v.into()), DataType::Int32).into() };
+        let int = ScalarImpl::Int32;
+
+        // `v0 = 1 AND 2 < v1 AND v1 <= 5 AND v2 = 3` on the order key `(v0, v1, v2)`
+        let eq_v0 = cmp(ExprType::Equal, col(0), lit(1));
+        let gt_v1 = cmp(ExprType::LessThan, lit(2), col(1));
+        let le_v1 = cmp(ExprType::LessThanOrEqual, col(1), lit(5));
+        let eq_v2 = cmp(ExprType::Equal, col(2), lit(3));
+        let cond = Condition {
+            conjunctions: vec![eq_v0, gt_v1.clone(), le_v1.clone(), eq_v2.clone()],
+        };
+
+        let (scan_ranges, others) = cond.clone().split_to_scan_ranges(&[0, 1, 2], usize::MAX);
+        assert_eq!(
+            scan_ranges,
+            vec![ScanRange {
+                eq_conds: vec![int(1)],
+                range: (Bound::Excluded(int(2)), Bound::Included(int(5))),
+            }]
+        );
+        assert_eq!(others.conjunctions, vec![gt_v1, le_v1, eq_v2]);
+
+        // All the columns of the order key `(v2, v0)` are fixed.
+        let (scan_ranges, others) = cond.clone().split_to_scan_ranges(&[2, 0], usize::MAX);
+        assert_eq!(
+            scan_ranges,
+            vec![ScanRange {
+                eq_conds: vec![int(3), int(1)],
+                range: (Bound::Unbounded, Bound::Unbounded),
+            }]
+        );
+        assert_eq!(others.conjunctions.len(), 2);
+
+        // Nothing to derive from the conditions on the order key `(v3)`.
+        let (scan_ranges, others) = cond.split_to_scan_ranges(&[3], usize::MAX);
+        assert!(scan_ranges.is_empty());
+        assert_eq!(others.conjunctions.len(), 4);
+
+        // `v0 IN (1, 2, 1) AND (v1 = 3 OR v1 = 4)` on the order key `(v0, v1)`
+        let in_v0: ExprImpl = FunctionCall::new(ExprType::In, vec![col(0), lit(1), lit(2), lit(1)])
+            .unwrap()
+            .into();
+        let or_v1 = cmp(
+            ExprType::Or,
+            cmp(ExprType::Equal, col(1), lit(3)),
+            cmp(ExprType::Equal, lit(4), col(1)),
+        );
+        let cond = Condition {
+            conjunctions: vec![in_v0, or_v1.clone()],
+        };
+
+        let (scan_ranges, others) = cond.clone().split_to_scan_ranges(&[0, 1], usize::MAX);
+        let eq_conds = scan_ranges
+            .into_iter()
+            .map(|scan_range| scan_range.eq_conds)
+            .collect_vec();
+        assert_eq!(
+            eq_conds,
+            vec![
+                vec![int(1), int(3)],
+                vec![int(1), int(4)],
+                vec![int(2), int(3)],
+                vec![int(2), int(4)],
+            ]
+        );
+        assert!(others.always_true());
+
+        // Too many ranges to expand `v1`.
+        let (scan_ranges, others) = cond.split_to_scan_ranges(&[0, 1], 3);
+        assert_eq!(scan_ranges.len(), 2);
+        assert_eq!(others.conjunctions, vec![or_v1]);
+    }
 }
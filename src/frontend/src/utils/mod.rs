@@ -18,6 +18,10 @@ mod condition;
 pub use condition::*;
 mod connected_components;
 pub(crate) use connected_components::*;
+mod hyper_log_log;
+pub use hyper_log_log::*;
+mod scan_range;
+pub use scan_range::*;
 
 use crate::expr::{Expr, ExprImpl, ExprRewriter, InputRef};
 
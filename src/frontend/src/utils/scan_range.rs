@@ -0,0 +1,53 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Bound;
+
+use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::value_encoding::serialize_datum;
+use risingwave_pb::batch_plan::scan_range::Bound as BoundProst;
+use risingwave_pb::batch_plan::ScanRange as ScanRangeProst;
+
+/// The range of the order key of a table to scan, which is a prefix of the order key columns with
+/// fixed values in `eq_conds`, followed by a column bounded by `range`. Note that nulls are out of
+/// a range bounded at both sides, but they may be in a range open at one side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanRange {
+    /// The values of the leading order key columns.
+    pub eq_conds: Vec<ScalarImpl>,
+    /// The range of the next order key column after `eq_conds`.
+    pub range: (Bound<ScalarImpl>, Bound<ScalarImpl>),
+}
+
+impl ScanRange {
+    pub fn to_protobuf(&self) -> ScanRangeProst {
+        let encode = |value: &ScalarImpl| serialize_datum(&Some(value.clone())).unwrap();
+        let encode_bound = |bound: &Bound<ScalarImpl>| match bound {
+            Bound::Included(value) => Some(BoundProst {
+                value: encode(value),
+                inclusive: true,
+            }),
+            Bound::Excluded(value) => Some(BoundProst {
+                value: encode(value),
+                inclusive: false,
+            }),
+            Bound::Unbounded => None,
+        };
+        ScanRangeProst {
+            eq_conds: self.eq_conds.iter().map(encode).collect(),
+            lower_bound: encode_bound(&self.range.0),
+            upper_bound: encode_bound(&self.range.1),
+        }
+    }
+}
@@ -178,7 +178,7 @@ impl TestCase {
 
         if let Some(ref config_map) = self.with_config_map {
             for (key, val) in config_map {
-                session.set_config(key, val);
+                session.set_config(key, val)?;
             }
         }
 
@@ -276,10 +276,15 @@ impl TestCase {
                     name,
                     table_name,
                     columns,
-                    // TODO: support unique and if_not_exist in planner test
+                    include,
+                    unique,
+                    // TODO: support if_not_exist in planner test
                     ..
                 } => {
-                    create_index::handle_create_index(context, name, table_name, columns).await?;
+                    create_index::handle_create_index(
+                        context, name, table_name, columns, include, unique,
+                    )
+                    .await?;
                 }
                 Statement::CreateView {
                     materialized: true,
@@ -308,10 +313,7 @@ impl TestCase {
         let mut ret = TestCaseResult::default();
 
         let bound = {
-            let mut binder = Binder::new(
-                session.env().catalog_reader().read_guard(),
-                session.database().to_string(),
-            );
+            let mut binder = Binder::new_for_session(&session);
             match binder.bind(stmt.clone()) {
                 Ok(bound) => bound,
                 Err(err) => {
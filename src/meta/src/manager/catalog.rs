@@ -22,9 +22,11 @@ use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
 use risingwave_common::ensure;
 use risingwave_common::error::ErrorCode::{CatalogError, InternalError};
 use risingwave_common::error::{Result, RwError};
+use risingwave_pb::catalog::source::Info as SourceInfo;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Schema, Source, Table, TableStatistics};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
+use risingwave_pb::plan_common::ColumnCatalog;
 use tokio::sync::{Mutex, MutexGuard};
 
 use super::IdCategory;
@@ -307,6 +309,106 @@ where
         }
     }
 
+    /// Replace the statistics of a table collected by `ANALYZE` and notify frontends with the
+    /// updated table catalog.
+    pub async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: TableStatistics,
+    ) -> Result<NotificationVersion> {
+        let _core = self.core.lock().await;
+        let table = Table::select(self.env.meta_store(), &table_id).await?;
+        if let Some(mut table) = table {
+            table.statistics = Some(statistics);
+            table.insert(self.env.meta_store()).await?;
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::Table(table))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "table doesn't exist".to_string(),
+            )))
+        }
+    }
+
+    /// Set the comment of a table or one of its columns, and notify frontends with the updated
+    /// table catalog. An empty `column_name` refers to the table itself.
+    pub async fn comment_on_table(
+        &self,
+        table_id: TableId,
+        column_name: &str,
+        comment: String,
+    ) -> Result<NotificationVersion> {
+        let _core = self.core.lock().await;
+        let table = Table::select(self.env.meta_store(), &table_id).await?;
+        if let Some(mut table) = table {
+            if column_name.is_empty() {
+                table.description = comment;
+            } else {
+                set_column_description(&mut table.columns, column_name, comment)?;
+            }
+            table.insert(self.env.meta_store()).await?;
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::Table(table))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "table doesn't exist".to_string(),
+            )))
+        }
+    }
+
+    /// Set the comment of a source or one of its columns, and notify frontends with the updated
+    /// source catalog. An empty `column_name` refers to the source itself.
+    pub async fn comment_on_source(
+        &self,
+        source_id: SourceId,
+        column_name: &str,
+        comment: String,
+    ) -> Result<NotificationVersion> {
+        let _core = self.core.lock().await;
+        let source = Source::select(self.env.meta_store(), &source_id).await?;
+        if let Some(mut source) = source {
+            if column_name.is_empty() {
+                source.description = comment;
+            } else {
+                let columns = match source.info.as_mut() {
+                    Some(SourceInfo::StreamSource(info)) => &mut info.columns,
+                    Some(SourceInfo::TableSource(info)) => &mut info.columns,
+                    None => {
+                        return Err(RwError::from(CatalogError(
+                            anyhow!("source {} has no columns", source.name).into(),
+                        )))
+                    }
+                };
+                set_column_description(columns, column_name, comment)?;
+            }
+            source.insert(self.env.meta_store()).await?;
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Update, Info::Source(source))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "source doesn't exist".to_string(),
+            )))
+        }
+    }
+
     pub async fn start_create_source_procedure(&self, source: &Source) -> Result<()> {
         let mut core = self.core.lock().await;
         let key = (source.database_id, source.schema_id, source.name.clone());
@@ -606,6 +708,27 @@ where
     }
 }
 
+fn set_column_description(
+    columns: &mut [ColumnCatalog],
+    column_name: &str,
+    description: String,
+) -> Result<()> {
+    let column = columns
+        .iter_mut()
+        .find(|c| {
+            c.column_desc
+                .as_ref()
+                .map_or(false, |desc| desc.name == column_name)
+        })
+        .ok_or_else(|| {
+            RwError::from(CatalogError(
+                anyhow!("column \"{}\" doesn't exist", column_name).into(),
+            ))
+        })?;
+    column.description = description;
+    Ok(())
+}
+
 type DatabaseKey = String;
 type SchemaKey = (DatabaseId, String);
 type TableKey = (DatabaseId, SchemaId, String);
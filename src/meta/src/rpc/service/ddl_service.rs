@@ -376,6 +376,52 @@ where
             version,
         }))
     }
+
+    async fn update_table_statistics(
+        &self,
+        request: Request<UpdateTableStatisticsRequest>,
+    ) -> Result<Response<UpdateTableStatisticsResponse>, Status> {
+        let req = request.into_inner();
+        let statistics = req.get_statistics().map_err(tonic_err)?.clone();
+        let version = self
+            .catalog_manager
+            .update_table_statistics(req.table_id, statistics)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(UpdateTableStatisticsResponse {
+            status: None,
+            version,
+        }))
+    }
+
+    async fn comment_on(
+        &self,
+        request: Request<CommentOnRequest>,
+    ) -> Result<Response<CommentOnResponse>, Status> {
+        let req = request.into_inner();
+        let object = req
+            .object
+            .ok_or_else(|| Status::invalid_argument("the object to comment on is not set"))?;
+        let version = match object {
+            comment_on_request::Object::TableId(table_id) => {
+                self.catalog_manager
+                    .comment_on_table(table_id, &req.column_name, req.comment)
+                    .await
+            }
+            comment_on_request::Object::SourceId(source_id) => {
+                self.catalog_manager
+                    .comment_on_source(source_id, &req.column_name, req.comment)
+                    .await
+            }
+        }
+        .map_err(tonic_err)?;
+
+        Ok(Response::new(CommentOnResponse {
+            status: None,
+            version,
+        }))
+    }
 }
 
 impl<S> DdlServiceImpl<S>
@@ -418,10 +464,13 @@ where
         );
 
         // Resolve fragments.
-        let parallel_degree = self
+        let mut parallel_degree = self
             .cluster_manager
             .get_parallel_unit_count(Some(ParallelUnitType::Hash))
             .await;
+        if fragment_graph.parallelism > 0 {
+            parallel_degree = parallel_degree.min(fragment_graph.parallelism as usize);
+        }
         let mut ctx = CreateMaterializedViewContext {
             affiliated_source,
             ..Default::default()
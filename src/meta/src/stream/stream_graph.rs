@@ -551,6 +551,17 @@ impl StreamGraphBuilder {
                     node.right_table_id = right_table_id;
                 }
 
+                if let NodeBody::NestedLoopJoin(node) = new_stream_node.node_body.as_mut().unwrap()
+                {
+                    node.left_table_id += table_id_offset;
+                    node.right_table_id += table_id_offset;
+                }
+
+                if let NodeBody::DynamicFilter(node) = new_stream_node.node_body.as_mut().unwrap() {
+                    node.left_table_id += table_id_offset;
+                    node.right_table_id += table_id_offset;
+                }
+
                 if let NodeBody::Lookup(node) = new_stream_node.node_body.as_mut().unwrap() {
                     if let Some(ArrangementTableId::TableId(table_id)) =
                         &mut node.arrangement_table_id
@@ -575,6 +586,10 @@ impl StreamGraphBuilder {
                     node.table_id += table_id_offset;
                 }
 
+                if let NodeBody::GroupTopN(node) = new_stream_node.node_body.as_mut().unwrap() {
+                    node.table_id += table_id_offset;
+                }
+
                 match new_stream_node.node_body.as_mut().unwrap() {
                     NodeBody::GlobalSimpleAgg(node) | NodeBody::LocalSimpleAgg(node) => {
                         assert_eq!(node.table_ids.len(), node.agg_calls.len());
@@ -18,7 +18,7 @@ use std::time::Duration;
 use futures::StreamExt;
 use log::trace;
 use risingwave_common::array::DataChunk;
-use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::ErrorCode::{InternalError, RpcError};
 use risingwave_common::error::{Result, ToRwResult};
 use risingwave_common::util::addr::HostAddr;
 use risingwave_pb::batch_plan::exchange_info::DistributionMode;
@@ -26,11 +26,11 @@ use risingwave_pb::batch_plan::{ExchangeInfo, PlanFragment, PlanNode, TaskId, Ta
 use risingwave_pb::task_service::exchange_service_client::ExchangeServiceClient;
 use risingwave_pb::task_service::task_service_client::TaskServiceClient;
 use risingwave_pb::task_service::{
-    CreateTaskRequest, CreateTaskResponse, GetDataRequest, GetDataResponse, GetStreamRequest,
-    GetStreamResponse,
+    AbortTaskRequest, CreateTaskRequest, CreateTaskResponse, GetDataRequest, GetDataResponse,
+    GetStreamRequest, GetStreamResponse,
 };
 use tonic::transport::{Channel, Endpoint};
-use tonic::Streaming;
+use tonic::{Code, Streaming};
 
 #[derive(Clone)]
 pub struct ComputeClient {
@@ -134,13 +134,30 @@ impl ComputeClient {
         Ok(())
     }
 
+    pub async fn abort_task(&self, task_id: TaskId) -> Result<()> {
+        let _ = self
+            .task_client
+            .to_owned()
+            .abort_task(AbortTaskRequest {
+                task_id: Some(task_id),
+            })
+            .await
+            .to_rw_result()?;
+        Ok(())
+    }
+
+    /// The errors of the compute node are returned with `Code::Internal`, and any other status
+    /// means that the RPC itself failed, e.g. because the compute node is down.
     async fn create_task_inner(&self, req: CreateTaskRequest) -> Result<CreateTaskResponse> {
         Ok(self
             .task_client
             .to_owned()
             .create_task(req)
             .await
-            .to_rw_result()?
+            .map_err(|status| match status.code() {
+                Code::Internal => InternalError(status.message().to_string()),
+                _ => RpcError(status.to_string()),
+            })?
             .into_inner())
     }
 }
@@ -24,16 +24,19 @@ use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::{HummockEpoch, HummockSSTableId, HummockVersionId};
 use risingwave_pb::catalog::{
     Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    TableStatistics as ProstTableStatistics,
 };
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
 use risingwave_pb::ddl_service::{
-    CreateDatabaseRequest, CreateDatabaseResponse, CreateMaterializedSourceRequest,
-    CreateMaterializedSourceResponse, CreateMaterializedViewRequest,
-    CreateMaterializedViewResponse, CreateSchemaRequest, CreateSchemaResponse, CreateSourceRequest,
-    CreateSourceResponse, DropDatabaseRequest, DropDatabaseResponse, DropMaterializedSourceRequest,
-    DropMaterializedSourceResponse, DropMaterializedViewRequest, DropMaterializedViewResponse,
-    DropSchemaRequest, DropSchemaResponse, DropSourceRequest, DropSourceResponse,
+    comment_on_request, CommentOnRequest, CommentOnResponse, CreateDatabaseRequest,
+    CreateDatabaseResponse, CreateMaterializedSourceRequest, CreateMaterializedSourceResponse,
+    CreateMaterializedViewRequest, CreateMaterializedViewResponse, CreateSchemaRequest,
+    CreateSchemaResponse, CreateSourceRequest, CreateSourceResponse, DropDatabaseRequest,
+    DropDatabaseResponse, DropMaterializedSourceRequest, DropMaterializedSourceResponse,
+    DropMaterializedViewRequest, DropMaterializedViewResponse, DropSchemaRequest,
+    DropSchemaResponse, DropSourceRequest, DropSourceResponse, UpdateTableStatisticsRequest,
+    UpdateTableStatisticsResponse,
 };
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
 use risingwave_pb::hummock::{
@@ -237,6 +240,34 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn update_table_statistics(
+        &self,
+        table_id: TableId,
+        statistics: ProstTableStatistics,
+    ) -> Result<CatalogVersion> {
+        let request = UpdateTableStatisticsRequest {
+            table_id: table_id.table_id(),
+            statistics: Some(statistics),
+        };
+        let resp = self.inner.update_table_statistics(request).await?;
+        Ok(resp.version)
+    }
+
+    pub async fn comment_on(
+        &self,
+        object: comment_on_request::Object,
+        column_name: String,
+        comment: String,
+    ) -> Result<CatalogVersion> {
+        let request = CommentOnRequest {
+            object: Some(object),
+            column_name,
+            comment,
+        };
+        let resp = self.inner.comment_on(request).await?;
+        Ok(resp.version)
+    }
+
     // TODO: using UserInfoVersion instead as return type.
     pub async fn create_user(&self, user: UserInfo) -> Result<u64> {
         let request = CreateUserRequest { user: Some(user) };
@@ -513,6 +544,8 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_source, DropSourceRequest, DropSourceResponse }
             ,{ ddl_client, drop_database, DropDatabaseRequest, DropDatabaseResponse }
             ,{ ddl_client, drop_schema, DropSchemaRequest, DropSchemaResponse }
+            ,{ ddl_client, update_table_statistics, UpdateTableStatisticsRequest, UpdateTableStatisticsResponse }
+            ,{ ddl_client, comment_on, CommentOnRequest, CommentOnResponse }
             ,{ hummock_client, pin_version, PinVersionRequest, PinVersionResponse }
             ,{ hummock_client, unpin_version, UnpinVersionRequest, UnpinVersionResponse }
             ,{ hummock_client, pin_snapshot, PinSnapshotRequest, PinSnapshotResponse }
@@ -273,6 +273,7 @@ mod tests {
             .map(|c| ColumnCatalog {
                 column_desc: Some(c.to_owned()),
                 is_hidden: false,
+                description: String::new(),
             })
             .collect();
         let info = StreamSourceInfo {
@@ -15,7 +15,7 @@
 use num_traits::FromPrimitive;
 use risingwave_common::error::ErrorCode::{self, InternalError};
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::{DataType, Decimal, ScalarImpl, ScalarRef};
+use risingwave_common::types::{DataType, Decimal, JsonbVal, ScalarImpl, ScalarRef};
 use risingwave_expr::vector_op::cast::{str_to_date, str_to_timestamp};
 use serde_json::Value;
 
@@ -94,6 +94,15 @@ pub(crate) fn json_parse_value(
                 Err(e) => Err(e),
             },
         },
+        DataType::Uuid => match value.and_then(|v| v.as_str()) {
+            None => Err(RwError::from(InternalError("parse error".to_string()))),
+            Some(uuid_str) => uuid_str.parse().map(ScalarImpl::Uuid),
+        },
+        DataType::Jsonb => {
+            make_ScalarImpl!(value, |v: &Value| ScalarImpl::Jsonb(JsonbVal::from_value(
+                v.clone()
+            )))
+        }
         _ => Err(ErrorCode::NotImplemented(
             "unsupported type for json_parse_value".to_string(),
             None.into(),
@@ -15,15 +15,28 @@
 use risingwave_common::array::Op;
 use risingwave_common::error::ErrorCode::ProtocolError;
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::Datum;
+use risingwave_common::types::{Datum, JsonbVal, ScalarImpl};
 use serde_json::Value;
 
 use crate::parser::common::json_parse_value;
 use crate::{Event, SourceColumnDesc, SourceParser};
 
+/// The `WITH` option naming a `jsonb` column that takes the whole message.
+pub const JSON_PAYLOAD_COLUMN_KEY: &str = "json.payload_column";
+
 /// Parser for JSON format
-#[derive(Debug)]
-pub struct JSONParser;
+#[derive(Debug, Default)]
+pub struct JSONParser {
+    /// The column that takes the whole message as `jsonb`. Other columns still take the fields of
+    /// the message with the same names.
+    payload_column: Option<String>,
+}
+
+impl JSONParser {
+    pub fn new(payload_column: Option<String>) -> Self {
+        Self { payload_column }
+    }
+}
 
 impl SourceParser for JSONParser {
     fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
@@ -37,6 +50,8 @@ impl SourceParser for JSONParser {
                 .map(|column| {
                     if column.skip_parse {
                         None
+                    } else if self.payload_column.as_ref() == Some(&column.name) {
+                        Some(ScalarImpl::Jsonb(JsonbVal::from_value(value.clone())))
                     } else {
                         json_parse_value(column, value.get(&column.name)).ok()
                     }
@@ -56,7 +71,7 @@ mod tests {
 
     #[test]
     fn test_json_parser() {
-        let parser = JSONParser {};
+        let parser = JSONParser::default();
         let payload = r#"{"i32":1,"bool":true,"i16":1,"i64":12345678,"f32":1.23,"f64":1.2345,"varchar":"varchar","date":"2021-01-01","timestamp":"2021-01-01 16:06:12.269"}"#.as_bytes();
         let descs = vec![
             SourceColumnDesc {
@@ -147,4 +162,48 @@ mod tests {
         let result = parser.parse(payload, &descs);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_json_parser_jsonb() {
+        let payload = r#"{"id":1,"data":{"a":[1,"x"]}}"#.as_bytes();
+        let descs = vec![
+            SourceColumnDesc {
+                name: "id".to_string(),
+                data_type: DataType::Int32,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "data".to_string(),
+                data_type: DataType::Jsonb,
+                column_id: ColumnId::from(1),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "payload".to_string(),
+                data_type: DataType::Jsonb,
+                column_id: ColumnId::from(2),
+                skip_parse: false,
+            },
+        ];
+        let jsonb = |s: &str| Some(ScalarImpl::Jsonb(s.parse().unwrap()));
+
+        let parser = JSONParser::default();
+        let row = parser.parse(payload, &descs).unwrap().rows.pop().unwrap();
+        assert_eq!(
+            row,
+            vec![Some(ScalarImpl::Int32(1)), jsonb(r#"{"a":[1,"x"]}"#), None]
+        );
+
+        let parser = JSONParser::new(Some("payload".to_string()));
+        let row = parser.parse(payload, &descs).unwrap().rows.pop().unwrap();
+        assert_eq!(
+            row,
+            vec![
+                Some(ScalarImpl::Int32(1)),
+                jsonb(r#"{"a":[1,"x"]}"#),
+                jsonb(r#"{"id":1,"data":{"a":[1,"x"]}}"#)
+            ]
+        );
+    }
 }
@@ -74,7 +74,9 @@ impl SourceParserImpl {
     ) -> Result<Arc<Self>> {
         const PROTOBUF_MESSAGE_KEY: &str = "proto.message";
         let parser = match format {
-            SourceFormat::Json => SourceParserImpl::Json(JSONParser {}),
+            SourceFormat::Json => SourceParserImpl::Json(JSONParser::new(
+                properties.get(JSON_PAYLOAD_COLUMN_KEY).cloned(),
+            )),
             SourceFormat::Protobuf => {
                 let message_name = properties.get(PROTOBUF_MESSAGE_KEY).ok_or_else(|| {
                     RwError::from(ProtocolError(format!(
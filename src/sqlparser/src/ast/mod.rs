@@ -237,12 +237,24 @@ pub enum Expr {
         field: DateTimeField,
         expr: Box<Expr>,
     },
+    /// `<timestamp> AT TIME ZONE <time_zone>`
+    AtTimeZone {
+        timestamp: Box<Expr>,
+        time_zone: Box<Expr>,
+    },
     /// SUBSTRING(<expr> [FROM <expr>] [FOR <expr>])
     Substring {
         expr: Box<Expr>,
         substring_from: Option<Box<Expr>>,
         substring_for: Option<Box<Expr>>,
     },
+    /// OVERLAY(<expr> PLACING <expr> FROM <expr> [FOR <expr>])
+    Overlay {
+        expr: Box<Expr>,
+        new_substring: Box<Expr>,
+        start: Box<Expr>,
+        count: Option<Box<Expr>>,
+    },
     /// TRIM([BOTH | LEADING | TRAILING] <expr> [FROM <expr>])\
     /// Or\
     /// TRIM(<expr>)
@@ -283,6 +295,12 @@ pub enum Expr {
     /// A parenthesized subquery `(SELECT ...)`, used in expression like
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
     Subquery(Box<Query>),
+    /// A quantified comparison operand `ANY (SELECT ...)` or `SOME (SELECT ...)`, used as the
+    /// right side of a comparison like `x = ANY (SELECT ...)`
+    AnySubquery(Box<Query>),
+    /// A quantified comparison operand `ALL (SELECT ...)`, used as the right side of a comparison
+    /// like `x > ALL (SELECT ...)`
+    AllSubquery(Box<Query>),
     /// The `GROUPING SETS` expr.
     GroupingSets(Vec<Vec<Expr>>),
     /// The `CUBE` expr.
@@ -296,12 +314,21 @@ pub enum Expr {
     Array(Vec<Expr>),
     /// An array index expression e.g. `(ARRAY[1, 2])[1]` or `(current_schemas(FALSE))[1]`
     ArrayIndex { obj: Box<Expr>, indexs: Vec<Expr> },
+    /// An array slice expression e.g. `arr[1:3]`, where either bound may be omitted
+    ArraySlice {
+        obj: Box<Expr>,
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
+    },
+    /// A positional parameter of a prepared statement, e.g. `$1`
+    Parameter { index: u64 },
 }
 
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Expr::Identifier(s) => write!(f, "{}", s),
+            Expr::Parameter { index } => write!(f, "${}", index),
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
             Expr::FieldIdentifier(ast, s) => write!(f, "{}.{}", ast, display_separated(s, ".")),
             Expr::IsNull(ast) => write!(f, "{} IS NULL", ast),
@@ -356,6 +383,10 @@ impl fmt::Display for Expr {
             Expr::Cast { expr, data_type } => write!(f, "CAST({} AS {})", expr, data_type),
             Expr::TryCast { expr, data_type } => write!(f, "TRY_CAST({} AS {})", expr, data_type),
             Expr::Extract { field, expr } => write!(f, "EXTRACT({} FROM {})", field, expr),
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => write!(f, "{} AT TIME ZONE {}", timestamp, time_zone),
             Expr::Collate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
             Expr::Nested(ast) => write!(f, "({})", ast),
             Expr::Value(v) => write!(f, "{}", v),
@@ -385,6 +416,8 @@ impl fmt::Display for Expr {
             }
             Expr::Exists(s) => write!(f, "EXISTS ({})", s),
             Expr::Subquery(s) => write!(f, "({})", s),
+            Expr::AnySubquery(s) => write!(f, "ANY ({})", s),
+            Expr::AllSubquery(s) => write!(f, "ALL ({})", s),
             Expr::GroupingSets(sets) => {
                 write!(f, "GROUPING SETS (")?;
                 let mut sep = "";
@@ -438,6 +471,23 @@ impl fmt::Display for Expr {
 
                 write!(f, ")")
             }
+            Expr::Overlay {
+                expr,
+                new_substring,
+                start,
+                count,
+            } => {
+                write!(
+                    f,
+                    "OVERLAY({} PLACING {} FROM {}",
+                    expr, new_substring, start
+                )?;
+                if let Some(count_expr) = count {
+                    write!(f, " FOR {}", count_expr)?;
+                }
+
+                write!(f, ")")
+            }
             Expr::IsDistinctFrom(a, b) => write!(f, "{} IS DISTINCT FROM {}", a, b),
             Expr::IsNotDistinctFrom(a, b) => write!(f, "{} IS NOT DISTINCT FROM {}", a, b),
             Expr::Trim { expr, trim_where } => {
@@ -467,6 +517,17 @@ impl fmt::Display for Expr {
                 }
                 Ok(())
             }
+            Expr::ArraySlice { obj, start, end } => {
+                write!(f, "{}[", obj)?;
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
+                }
+                write!(f, ":")?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                write!(f, "]")
+            }
             Expr::Array(exprs) => write!(
                 f,
                 "ARRAY[{}]",
@@ -657,6 +718,8 @@ impl fmt::Display for ShowObject {
 pub enum CommentObject {
     Column,
     Table,
+    MaterializedView,
+    Source,
 }
 
 impl fmt::Display for CommentObject {
@@ -664,6 +727,8 @@ impl fmt::Display for CommentObject {
         match self {
             CommentObject::Column => f.write_str("COLUMN"),
             CommentObject::Table => f.write_str("TABLE"),
+            CommentObject::MaterializedView => f.write_str("MATERIALIZED VIEW"),
+            CommentObject::Source => f.write_str("SOURCE"),
         }
     }
 }
@@ -743,6 +808,8 @@ pub enum Statement {
         name: ObjectName,
         table_name: ObjectName,
         columns: Vec<OrderByExpr>,
+        /// non-key columns stored in the index by `INCLUDE`
+        include: Vec<Ident>,
         unique: bool,
         if_not_exists: bool,
     },
@@ -780,6 +847,10 @@ pub enum Statement {
     ///
     /// Note: this is a PostgreSQL-specific statement.
     ShowVariable { variable: Vec<Ident> },
+    /// `RESET { <variable> | ALL }`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    ResetVariable { variable: Ident },
     /// `{ BEGIN [ TRANSACTION | WORK ] | START TRANSACTION } ...`
     StartTransaction { modes: Vec<TransactionMode> },
     /// ABORT
@@ -1065,17 +1136,24 @@ impl fmt::Display for Statement {
                 name,
                 table_name,
                 columns,
+                include,
                 unique,
                 if_not_exists,
-            } => write!(
-                f,
-                "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
-                unique = if *unique { "UNIQUE " } else { "" },
-                if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
-                name = name,
-                table_name = table_name,
-                columns = display_separated(columns, ",")
-            ),
+            } => {
+                write!(
+                    f,
+                    "CREATE {unique}INDEX {if_not_exists}{name} ON {table_name}({columns})",
+                    unique = if *unique { "UNIQUE " } else { "" },
+                    if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
+                    name = name,
+                    table_name = table_name,
+                    columns = display_separated(columns, ",")
+                )?;
+                if !include.is_empty() {
+                    write!(f, " INCLUDE({})", display_separated(include, ","))?;
+                }
+                Ok(())
+            }
             Statement::CreateSource {
                 is_materialized,
                 stmt,
@@ -1116,6 +1194,7 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ResetVariable { variable } => write!(f, "RESET {}", variable),
             Statement::StartTransaction { modes } => {
                 write!(f, "START TRANSACTION")?;
                 if !modes.is_empty() {
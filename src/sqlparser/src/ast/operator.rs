@@ -76,6 +76,8 @@ pub enum BinaryOperator {
     NotLike,
     ILike,
     NotILike,
+    SimilarTo,
+    NotSimilarTo,
     BitwiseOr,
     BitwiseAnd,
     BitwiseXor,
@@ -86,6 +88,22 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    /// `->` in PostgreSQL
+    Arrow,
+    /// `->>` in PostgreSQL
+    LongArrow,
+    /// `#>` in PostgreSQL
+    HashArrow,
+    /// `#>>` in PostgreSQL
+    HashLongArrow,
+    /// `@>` in PostgreSQL
+    AtArrow,
+    /// `<@` in PostgreSQL
+    ArrowAt,
+    /// `&&` in PostgreSQL
+    PGOverlap,
+    /// `?` in PostgreSQL
+    Question,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -111,6 +129,8 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::NotLike => "NOT LIKE",
             BinaryOperator::ILike => "ILIKE",
             BinaryOperator::NotILike => "NOT ILIKE",
+            BinaryOperator::SimilarTo => "SIMILAR TO",
+            BinaryOperator::NotSimilarTo => "NOT SIMILAR TO",
             BinaryOperator::BitwiseOr => "|",
             BinaryOperator::BitwiseAnd => "&",
             BinaryOperator::BitwiseXor => "^",
@@ -121,6 +141,14 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::Arrow => "->",
+            BinaryOperator::LongArrow => "->>",
+            BinaryOperator::HashArrow => "#>",
+            BinaryOperator::HashLongArrow => "#>>",
+            BinaryOperator::AtArrow => "@>",
+            BinaryOperator::ArrowAt => "<@",
+            BinaryOperator::PGOverlap => "&&",
+            BinaryOperator::Question => "?",
         })
     }
 }
@@ -112,6 +112,7 @@ define_keywords!(
     CAST,
     CEIL,
     CEILING,
+    CENTURY,
     CHAIN,
     CHAR,
     CHARACTER,
@@ -167,6 +168,7 @@ define_keywords!(
     DAY,
     DEALLOCATE,
     DEC,
+    DECADE,
     DECIMAL,
     DECLARE,
     DEFAULT,
@@ -180,6 +182,8 @@ define_keywords!(
     DISCONNECT,
     DISTINCT,
     DOUBLE,
+    DOW,
+    DOY,
     DROP,
     DYNAMIC,
     EACH,
@@ -190,6 +194,7 @@ define_keywords!(
     END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
+    EPOCH,
     EQUALS,
     ERROR,
     ESCAPE,
@@ -237,6 +242,7 @@ define_keywords!(
     IGNORE,
     ILIKE,
     IN,
+    INCLUDE,
     INDEX,
     INDICATOR,
     INNER,
@@ -250,9 +256,11 @@ define_keywords!(
     INTERVAL,
     INTO,
     IS,
+    ISODOW,
     ISOLATION,
     JOIN,
     JSON,
+    JSONB,
     KEY,
     LANGUAGE,
     LARGE,
@@ -277,6 +285,9 @@ define_keywords!(
     MERGE,
     MESSAGE,
     METHOD,
+    MICROSECONDS,
+    MILLENNIUM,
+    MILLISECONDS,
     MIN,
     MINUTE,
     MOD,
@@ -333,6 +344,7 @@ define_keywords!(
     PERCENTILE_DISC,
     PERCENT_RANK,
     PERIOD,
+    PLACING,
     PORTION,
     POSITION,
     POSITION_REGEX,
@@ -346,6 +358,7 @@ define_keywords!(
     PROCEDURE,
     PROTOBUF,
     PURGE,
+    QUARTER,
     RANGE,
     RANK,
     RCFILE,
@@ -371,6 +384,7 @@ define_keywords!(
     REPAIR,
     REPEATABLE,
     REPLACE,
+    RESET,
     RESTRICT,
     RESULT,
     RETURN,
@@ -492,6 +506,7 @@ define_keywords!(
     VIEW,
     VIEWS,
     VIRTUAL,
+    WEEK,
     WHEN,
     WHENEVER,
     WHERE,
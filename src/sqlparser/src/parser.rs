@@ -119,6 +119,8 @@ pub struct Parser {
 }
 
 impl Parser {
+    /// `AT TIME ZONE` binds tighter than the arithmetic operators, as in PG.
+    const AT_TIME_ZONE_PREC: u8 = 45;
     const BETWEEN_PREC: u8 = 20;
     const PLUS_MINUS_PREC: u8 = 30;
     const UNARY_NOT_PREC: u8 = 15;
@@ -181,6 +183,9 @@ impl Parser {
                 Keyword::COPY => Ok(self.parse_copy()?),
                 Keyword::SET => Ok(self.parse_set()?),
                 Keyword::SHOW => Ok(self.parse_show()?),
+                Keyword::RESET => Ok(Statement::ResetVariable {
+                    variable: self.parse_identifier()?,
+                }),
                 Keyword::DESCRIBE => Ok(Statement::Describe {
                     name: self.parse_object_name()?,
                 }),
@@ -392,7 +397,22 @@ impl Parser {
                 Keyword::TRY_CAST => self.parse_try_cast_expr(),
                 Keyword::EXISTS => self.parse_exists_expr(),
                 Keyword::EXTRACT => self.parse_extract_expr(),
+                Keyword::CURRENT_TIMESTAMP
+                | Keyword::CURRENT_TIME
+                | Keyword::CURRENT_DATE
+                | Keyword::LOCALTIMESTAMP
+                | Keyword::LOCALTIME
+                    if self.peek_token() != Token::LParen =>
+                {
+                    Ok(Expr::Function(Function {
+                        name: ObjectName(vec![w.to_ident()]),
+                        args: vec![],
+                        over: None,
+                        distinct: false,
+                    }))
+                }
                 Keyword::SUBSTRING => self.parse_substring_expr(),
+                Keyword::OVERLAY => self.parse_overlay_expr(),
                 Keyword::TRIM => self.parse_trim_expr(),
                 Keyword::INTERVAL => self.parse_literal_interval(),
                 Keyword::NOT => Ok(Expr::UnaryOp {
@@ -466,6 +486,10 @@ impl Parser {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
+            Token::Parameter(s) => match s.parse::<u64>() {
+                Ok(index) if index > 0 => Ok(Expr::Parameter { index }),
+                _ => parser_err!(format!("Invalid parameter ${}", s)),
+            },
 
             Token::LParen => {
                 let expr =
@@ -807,6 +831,28 @@ impl Parser {
         })
     }
 
+    /// OVERLAY('text' PLACING 'text' FROM 1 [FOR 3])
+    pub fn parse_overlay_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let expr = self.parse_expr()?;
+        self.expect_keyword(Keyword::PLACING)?;
+        let new_substring = self.parse_expr()?;
+        self.expect_keyword(Keyword::FROM)?;
+        let start = self.parse_expr()?;
+        let mut count = None;
+        if self.parse_keyword(Keyword::FOR) {
+            count = Some(Box::new(self.parse_expr()?));
+        }
+        self.expect_token(&Token::RParen)?;
+
+        Ok(Expr::Overlay {
+            expr: Box::new(expr),
+            new_substring: Box::new(new_substring),
+            start: Box::new(start),
+            count,
+        })
+    }
+
     /// TRIM (WHERE 'text' FROM 'text')\
     /// TRIM ('text')
     pub fn parse_trim_expr(&mut self) -> Result<Expr, ParserError> {
@@ -857,6 +903,17 @@ impl Parser {
                 Keyword::HOUR => Ok(DateTimeField::Hour),
                 Keyword::MINUTE => Ok(DateTimeField::Minute),
                 Keyword::SECOND => Ok(DateTimeField::Second),
+                Keyword::CENTURY => Ok(DateTimeField::Century),
+                Keyword::DECADE => Ok(DateTimeField::Decade),
+                Keyword::DOW => Ok(DateTimeField::Dow),
+                Keyword::DOY => Ok(DateTimeField::Doy),
+                Keyword::EPOCH => Ok(DateTimeField::Epoch),
+                Keyword::ISODOW => Ok(DateTimeField::Isodow),
+                Keyword::MICROSECONDS => Ok(DateTimeField::Microseconds),
+                Keyword::MILLENNIUM => Ok(DateTimeField::Millennium),
+                Keyword::MILLISECONDS => Ok(DateTimeField::Milliseconds),
+                Keyword::QUARTER => Ok(DateTimeField::Quarter),
+                Keyword::WEEK => Ok(DateTimeField::Week),
                 _ => self.expected("date/time field", Token::Word(w))?,
             },
             unexpected => self.expected("date/time field", unexpected),
@@ -970,16 +1027,30 @@ impl Parser {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::Arrow => Some(BinaryOperator::Arrow),
+            Token::LongArrow => Some(BinaryOperator::LongArrow),
+            Token::HashArrow => Some(BinaryOperator::HashArrow),
+            Token::HashLongArrow => Some(BinaryOperator::HashLongArrow),
+            Token::AtArrow => Some(BinaryOperator::AtArrow),
+            Token::ArrowAt => Some(BinaryOperator::ArrowAt),
+            Token::DoubleAmpersand => Some(BinaryOperator::PGOverlap),
+            Token::QuestionMark => Some(BinaryOperator::Question),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
                 Keyword::LIKE => Some(BinaryOperator::Like),
                 Keyword::ILIKE => Some(BinaryOperator::ILike),
+                Keyword::SIMILAR => {
+                    self.expect_keyword(Keyword::TO)?;
+                    Some(BinaryOperator::SimilarTo)
+                }
                 Keyword::NOT => {
                     if self.parse_keyword(Keyword::LIKE) {
                         Some(BinaryOperator::NotLike)
                     } else if self.parse_keyword(Keyword::ILIKE) {
                         Some(BinaryOperator::NotILike)
+                    } else if self.parse_keywords(&[Keyword::SIMILAR, Keyword::TO]) {
+                        Some(BinaryOperator::NotSimilarTo)
                     } else {
                         None
                     }
@@ -991,10 +1062,23 @@ impl Parser {
         };
 
         if let Some(op) = regular_binary_operator {
+            let right = match op {
+                BinaryOperator::Eq
+                | BinaryOperator::NotEq
+                | BinaryOperator::Gt
+                | BinaryOperator::GtEq
+                | BinaryOperator::Lt
+                | BinaryOperator::LtEq => self.parse_quantified_subquery()?,
+                _ => None,
+            };
+            let right = match right {
+                Some(right) => right,
+                None => self.parse_subexpr(precedence)?,
+            };
             Ok(Expr::BinaryOp {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(right),
             })
         } else if let Token::Word(w) = &tok {
             match w.keyword {
@@ -1025,6 +1109,13 @@ impl Parser {
                         )
                     }
                 }
+                Keyword::AT => {
+                    self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+                    Ok(Expr::AtTimeZone {
+                        timestamp: Box::new(expr),
+                        time_zone: Box::new(self.parse_subexpr(precedence)?),
+                    })
+                }
                 Keyword::NOT | Keyword::IN | Keyword::BETWEEN => {
                     self.prev_token();
                     let negated = self.parse_keyword(Keyword::NOT);
@@ -1056,7 +1147,26 @@ impl Parser {
     }
 
     pub fn parse_array_index(&mut self, expr: Expr) -> Result<Expr, ParserError> {
-        let index = self.parse_expr()?;
+        let start = match self.peek_token() {
+            Token::Colon => None,
+            _ => Some(self.parse_expr()?),
+        };
+        if self.consume_token(&Token::Colon) {
+            let end = match self.peek_token() {
+                Token::RBracket => None,
+                _ => Some(Box::new(self.parse_expr()?)),
+            };
+            self.expect_token(&Token::RBracket)?;
+            return Ok(Expr::ArraySlice {
+                obj: Box::new(expr),
+                start: start.map(Box::new),
+                end,
+            });
+        }
+        let index = match start {
+            Some(index) => index,
+            None => return self.expected("an array index", self.peek_token()),
+        };
         self.expect_token(&Token::RBracket)?;
         let mut indexs: Vec<Expr> = vec![index];
         while self.consume_token(&Token::LBracket) {
@@ -1070,6 +1180,33 @@ impl Parser {
         })
     }
 
+    /// Parses `ANY | SOME | ALL (SELECT ...)` following a comparison operator. Returns `None` and
+    /// consumes nothing if the next tokens are not a quantified subquery.
+    fn parse_quantified_subquery(&mut self) -> Result<Option<Expr>, ParserError> {
+        let quantifier = match self.peek_token() {
+            Token::Word(w) if matches!(w.keyword, Keyword::ANY | Keyword::SOME | Keyword::ALL) => {
+                w.keyword
+            }
+            _ => return Ok(None),
+        };
+        let is_subquery = self.peek_nth_token(1) == Token::LParen
+            && matches!(
+                self.peek_nth_token(2),
+                Token::Word(w) if w.keyword == Keyword::SELECT || w.keyword == Keyword::WITH
+            );
+        if !is_subquery {
+            return Ok(None);
+        }
+        self.next_token();
+        self.expect_token(&Token::LParen)?;
+        let query = Box::new(self.parse_query()?);
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(match quantifier {
+            Keyword::ALL => Expr::AllSubquery(query),
+            _ => Expr::AnySubquery(query),
+        }))
+    }
+
     /// Parses the parens following the `[ NOT ] IN` operator
     pub fn parse_in(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
@@ -1132,13 +1269,25 @@ impl Parser {
                 Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
                 _ => Ok(0),
             },
             Token::Word(w) if w.keyword == Keyword::IS => Ok(17),
+            Token::Word(w) if w.keyword == Keyword::AT => {
+                match (self.peek_nth_token(1), self.peek_nth_token(2)) {
+                    (Token::Word(w), Token::Word(w2))
+                        if w.keyword == Keyword::TIME && w2.keyword == Keyword::ZONE =>
+                    {
+                        Ok(Self::AT_TIME_ZONE_PREC)
+                    }
+                    _ => Ok(0),
+                }
+            }
             Token::Word(w) if w.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
             Token::Eq
             | Token::Lt
             | Token::LtEq
@@ -1154,6 +1303,14 @@ impl Parser {
             Token::Pipe => Ok(21),
             Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
             Token::Ampersand => Ok(23),
+            Token::Arrow
+            | Token::LongArrow
+            | Token::HashArrow
+            | Token::HashLongArrow
+            | Token::AtArrow
+            | Token::ArrowAt
+            | Token::DoubleAmpersand
+            | Token::QuestionMark => Ok(25),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
             Token::Mul | Token::Div | Token::Mod | Token::StringConcat => Ok(40),
             Token::DoubleColon => Ok(50),
@@ -1486,10 +1643,19 @@ impl Parser {
         self.expect_token(&Token::LParen)?;
         let columns = self.parse_comma_separated(Parser::parse_order_by_expr)?;
         self.expect_token(&Token::RParen)?;
+        let include = if self.parse_keyword(Keyword::INCLUDE) {
+            self.expect_token(&Token::LParen)?;
+            let include = self.parse_comma_separated(Parser::parse_identifier)?;
+            self.expect_token(&Token::RParen)?;
+            include
+        } else {
+            vec![]
+        };
         Ok(Statement::CreateIndex {
             name: index_name,
             table_name,
             columns,
+            include,
             unique,
             if_not_exists,
         })
@@ -2054,6 +2220,7 @@ impl Parser {
                 }
                 Keyword::STRUCT => Ok(DataType::Struct(self.parse_struct_data_type()?)),
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::JSONB => Ok(DataType::Jsonb),
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(DataType::Decimal(precision, scale))
@@ -2514,6 +2681,20 @@ impl Parser {
 
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
         let modifier = self.parse_one_of_keywords(&[Keyword::SESSION, Keyword::LOCAL]);
+        if self.parse_keywords(&[Keyword::TIME, Keyword::ZONE]) {
+            // `SET TIME ZONE <value>` is an alias of `SET timezone = <value>`.
+            let token = self.peek_token();
+            let value = match (self.parse_value(), token) {
+                (Ok(value), _) => SetVariableValue::Literal(value),
+                (Err(_), Token::Word(ident)) => SetVariableValue::Ident(ident.to_ident()),
+                (Err(_), unexpected) => self.expected("time zone value", unexpected)?,
+            };
+            return Ok(Statement::SetVariable {
+                local: modifier == Some(Keyword::LOCAL),
+                variable: Ident::new("timezone"),
+                value: vec![value],
+            });
+        }
         let variable = self.parse_identifier()?;
         if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
             let mut values = vec![];
@@ -2691,11 +2872,19 @@ impl Parser {
     /// A table name or a parenthesized subquery, followed by optional `[AS] alias`
     pub fn parse_table_factor(&mut self) -> Result<TableFactor, ParserError> {
         if self.parse_keyword(Keyword::LATERAL) {
-            // LATERAL must always be followed by a subquery.
+            // LATERAL must always be followed by a subquery or a table function.
+            if self.consume_token(&Token::LParen) {
+                return self.parse_derived_table_factor(Lateral);
+            }
+            let name = self.parse_object_name()?;
             if !self.consume_token(&Token::LParen) {
-                self.expected("subquery after LATERAL", self.peek_token())?;
+                self.expected("subquery or function after LATERAL", self.peek_token())?;
             }
-            self.parse_derived_table_factor(Lateral)
+            // Table functions can always refer to the items on their left, so `LATERAL` is
+            // implied for them.
+            let args = self.parse_optional_args()?;
+            let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
+            Ok(TableFactor::Table { name, alias, args })
         } else if self.consume_token(&Token::LParen) {
             // A left paren introduces either a derived table (i.e., a subquery)
             // or a nested join. It's nearly impossible to determine ahead of
@@ -3219,6 +3408,15 @@ impl Parser {
                 let object_name = self.parse_object_name()?;
                 (CommentObject::Table, object_name)
             }
+            Token::Word(w) if w.keyword == Keyword::MATERIALIZED => {
+                self.expect_keyword(Keyword::VIEW)?;
+                let object_name = self.parse_object_name()?;
+                (CommentObject::MaterializedView, object_name)
+            }
+            Token::Word(w) if w.keyword == Keyword::SOURCE => {
+                let object_name = self.parse_object_name()?;
+                (CommentObject::Source, object_name)
+            }
             _ => self.expected("comment object_type", token)?,
         };
 
@@ -138,6 +138,24 @@ pub enum Token {
     PGSquareRoot,
     /// `||/` , a cube root math operator in PostgreSQL
     PGCubeRoot,
+    /// A positional parameter `$n` of a prepared statement, holding the digits after `$`
+    Parameter(String),
+    /// `->`, access a field of JSON as JSON in PostgreSQL
+    Arrow,
+    /// `->>`, access a field of JSON as text in PostgreSQL
+    LongArrow,
+    /// `#>`, access a JSON value at a path as JSON in PostgreSQL
+    HashArrow,
+    /// `#>>`, access a JSON value at a path as text in PostgreSQL
+    HashLongArrow,
+    /// `@>`, whether the left JSON value or array contains the right one in PostgreSQL
+    AtArrow,
+    /// `<@`, whether the left array is contained by the right one in PostgreSQL
+    ArrowAt,
+    /// `&&`, whether two arrays overlap in PostgreSQL
+    DoubleAmpersand,
+    /// `?`, whether a key exists in JSON in PostgreSQL
+    QuestionMark,
 }
 
 impl fmt::Display for Token {
@@ -193,6 +211,15 @@ impl fmt::Display for Token {
             Token::ShiftRight => f.write_str(">>"),
             Token::PGSquareRoot => f.write_str("|/"),
             Token::PGCubeRoot => f.write_str("||/"),
+            Token::Parameter(ref s) => write!(f, "${}", s),
+            Token::Arrow => f.write_str("->"),
+            Token::LongArrow => f.write_str("->>"),
+            Token::HashArrow => f.write_str("#>"),
+            Token::HashLongArrow => f.write_str("#>>"),
+            Token::AtArrow => f.write_str("@>"),
+            Token::ArrowAt => f.write_str("<@"),
+            Token::DoubleAmpersand => f.write_str("&&"),
+            Token::QuestionMark => f.write_str("?"),
         }
     }
 }
@@ -475,6 +502,13 @@ impl<'a> Tokenizer<'a> {
                                 comment,
                             })))
                         }
+                        Some('>') => {
+                            chars.next(); // consume the '>'
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::LongArrow),
+                                _ => Ok(Some(Token::Arrow)),
+                            }
+                        }
                         // a regular '-' operator
                         _ => Ok(Some(Token::Minus)),
                     }
@@ -543,6 +577,7 @@ impl<'a> Tokenizer<'a> {
                         }
                         Some('>') => self.consume_and_return(chars, Token::Neq),
                         Some('<') => self.consume_and_return(chars, Token::ShiftLeft),
+                        Some('@') => self.consume_and_return(chars, Token::ArrowAt),
                         _ => Ok(Some(Token::Lt)),
                     }
                 }
@@ -565,7 +600,13 @@ impl<'a> Tokenizer<'a> {
                 '\\' => self.consume_and_return(chars, Token::Backslash),
                 '[' => self.consume_and_return(chars, Token::LBracket),
                 ']' => self.consume_and_return(chars, Token::RBracket),
-                '&' => self.consume_and_return(chars, Token::Ampersand),
+                '&' => {
+                    chars.next(); // consume the '&'
+                    match chars.peek() {
+                        Some('&') => self.consume_and_return(chars, Token::DoubleAmpersand),
+                        _ => Ok(Some(Token::Ampersand)),
+                    }
+                }
                 '^' => self.consume_and_return(chars, Token::Caret),
                 '{' => self.consume_and_return(chars, Token::LBrace),
                 '}' => self.consume_and_return(chars, Token::RBrace),
@@ -576,8 +617,36 @@ impl<'a> Tokenizer<'a> {
                         _ => Ok(Some(Token::Tilde)),
                     }
                 }
-                '#' => self.consume_and_return(chars, Token::Sharp),
-                '@' => self.consume_and_return(chars, Token::AtSign),
+                '#' => {
+                    chars.next(); // consume the '#'
+                    match chars.peek() {
+                        Some('>') => {
+                            chars.next(); // consume the '>'
+                            match chars.peek() {
+                                Some('>') => self.consume_and_return(chars, Token::HashLongArrow),
+                                _ => Ok(Some(Token::HashArrow)),
+                            }
+                        }
+                        _ => Ok(Some(Token::Sharp)),
+                    }
+                }
+                '@' => {
+                    chars.next(); // consume the '@'
+                    match chars.peek() {
+                        Some('>') => self.consume_and_return(chars, Token::AtArrow),
+                        _ => Ok(Some(Token::AtSign)),
+                    }
+                }
+                '?' => self.consume_and_return(chars, Token::QuestionMark),
+                '$' => {
+                    chars.next(); // consume the '$'
+                    let s = peeking_take_while(chars, |ch| ch.is_ascii_digit());
+                    if s.is_empty() {
+                        Ok(Some(Token::Char('$')))
+                    } else {
+                        Ok(Some(Token::Parameter(s)))
+                    }
+                }
                 other => self.consume_and_return(chars, Token::Char(other)),
             },
             None => Ok(None),
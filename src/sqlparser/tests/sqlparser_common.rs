@@ -760,6 +760,31 @@ fn parse_ilike() {
     chk(true);
 }
 
+#[test]
+fn parse_similar_to() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%(a|b)' IS NULL",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::IsNull(Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("name"))),
+                op: if negated {
+                    BinaryOperator::NotSimilarTo
+                } else {
+                    BinaryOperator::SimilarTo
+                },
+                right: Box::new(Expr::Value(Value::SingleQuotedString("%(a|b)".to_string()))),
+            })),
+            select.selection.unwrap()
+        );
+    }
+    chk(false);
+    chk(true);
+}
+
 #[test]
 fn parse_in_list() {
     fn chk(negated: bool) {
@@ -798,6 +823,40 @@ fn parse_in_subquery() {
     );
 }
 
+#[test]
+fn parse_quantified_subquery() {
+    let sql = "SELECT * FROM customers WHERE segment = ANY (SELECT segm FROM bar)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("segment"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::AnySubquery(Box::new(verified_query(
+                "SELECT segm FROM bar"
+            )))),
+        },
+        select.selection.unwrap()
+    );
+
+    let sql = "SELECT * FROM customers WHERE segment > ALL (SELECT segm FROM bar)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("segment"))),
+            op: BinaryOperator::Gt,
+            right: Box::new(Expr::AllSubquery(Box::new(verified_query(
+                "SELECT segm FROM bar"
+            )))),
+        },
+        select.selection.unwrap()
+    );
+
+    one_statement_parses_to(
+        "SELECT a <> SOME (SELECT b FROM t)",
+        "SELECT a <> ANY (SELECT b FROM t)",
+    );
+}
+
 #[test]
 fn parse_string_agg() {
     let sql = "SELECT a || b";
@@ -1201,6 +1260,10 @@ fn parse_extract() {
     verified_stmt("SELECT EXTRACT(HOUR FROM d)");
     verified_stmt("SELECT EXTRACT(MINUTE FROM d)");
     verified_stmt("SELECT EXTRACT(SECOND FROM d)");
+    verified_stmt("SELECT EXTRACT(EPOCH FROM d)");
+    verified_stmt("SELECT EXTRACT(DOW FROM d)");
+    verified_stmt("SELECT EXTRACT(MILLISECONDS FROM d)");
+    verified_stmt("SELECT EXTRACT(WEEK FROM d)");
 
     let res = parse_sql_statements("SELECT EXTRACT(MILLISECOND FROM d)");
     assert_eq!(
@@ -1209,6 +1272,52 @@ fn parse_extract() {
     );
 }
 
+#[test]
+fn parse_at_time_zone() {
+    let sql = "SELECT ts AT TIME ZONE 'UTC' + INTERVAL '1' HOUR";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+                time_zone: Box::new(Expr::Value(Value::SingleQuotedString("UTC".to_string()))),
+            }),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(Value::Interval {
+                value: "1".to_string(),
+                leading_field: Some(DateTimeField::Hour),
+                leading_precision: None,
+                last_field: None,
+                fractional_seconds_precision: None,
+            })),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    verified_stmt("SELECT a AT TIME ZONE b AT TIME ZONE 'UTC' FROM t");
+}
+
+#[test]
+fn parse_current_timestamp() {
+    let sql = "SELECT CURRENT_TIMESTAMP, CURRENT_DATE()";
+    let select = match one_statement_parses_to(sql, "SELECT CURRENT_TIMESTAMP(), CURRENT_DATE()") {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => select,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        &Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("CURRENT_TIMESTAMP")]),
+            args: vec![],
+            over: None,
+            distinct: false,
+        }),
+        expr_from_projection(&select.projection[0]),
+    );
+}
+
 #[test]
 fn parse_create_table() {
     let sql = "CREATE TABLE uk_cities (\
@@ -2686,6 +2795,24 @@ fn parse_substring() {
     one_statement_parses_to("SELECT SUBSTRING('1' FOR 3)", "SELECT SUBSTRING('1' FOR 3)");
 }
 
+#[test]
+fn parse_overlay() {
+    one_statement_parses_to(
+        "SELECT OVERLAY('abc' PLACING 'xyz' FROM 1)",
+        "SELECT OVERLAY('abc' PLACING 'xyz' FROM 1)",
+    );
+
+    one_statement_parses_to(
+        "SELECT OVERLAY('abc' PLACING 'xyz' FROM 1 FOR 2)",
+        "SELECT OVERLAY('abc' PLACING 'xyz' FROM 1 FOR 2)",
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected PLACING, found: ,".to_owned()),
+        parse_sql_statements("SELECT OVERLAY('abc', 'xyz', 1)").unwrap_err()
+    );
+}
+
 #[test]
 fn parse_trim() {
     one_statement_parses_to(
@@ -3190,11 +3317,17 @@ fn lateral_derived() {
     chk(false);
     chk(true);
 
-    let sql = "SELECT * FROM customer LEFT JOIN LATERAL generate_series(1, customer.id)";
+    // `LATERAL` is implied for table functions.
+    one_statement_parses_to(
+        "SELECT * FROM customer LEFT JOIN LATERAL generate_series(1, customer.id) ON true",
+        "SELECT * FROM customer LEFT JOIN generate_series(1, customer.id) ON true",
+    );
+
+    let sql = "SELECT * FROM customer LEFT JOIN LATERAL customer_orders ON true";
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected subquery after LATERAL, found: generate_series".to_string()
+            "Expected subquery or function after LATERAL, found: ON".to_string()
         ),
         res.unwrap_err()
     );
@@ -3368,12 +3501,14 @@ fn parse_create_index() {
             name,
             table_name,
             columns,
+            include,
             unique,
             if_not_exists,
         } => {
             assert_eq!("idx_name", name.to_string());
             assert_eq!("test", table_name.to_string());
             assert_eq!(indexed_columns, columns);
+            assert!(include.is_empty());
             assert!(unique);
             assert!(if_not_exists)
         }
@@ -3381,6 +3516,24 @@ fn parse_create_index() {
     }
 }
 
+#[test]
+fn parse_create_index_include() {
+    let sql = "CREATE INDEX idx_name ON test(name) INCLUDE(age,city)";
+    match verified_stmt(sql) {
+        Statement::CreateIndex {
+            columns,
+            include,
+            unique,
+            ..
+        } => {
+            assert_eq!(1, columns.len());
+            assert_eq!(vec![Ident::new("age"), Ident::new("city")], include);
+            assert!(!unique);
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_grant() {
     let sql = "GRANT SELECT, INSERT, UPDATE (shape, size), USAGE, DELETE, TRUNCATE, REFERENCES, TRIGGER ON abc, def TO xyz, m WITH GRANT OPTION GRANTED BY jj";
@@ -439,6 +439,8 @@ fn parse_set() {
 
     one_statement_parses_to("SET a TO b", "SET a = b");
     one_statement_parses_to("SET SESSION a = b", "SET a = b");
+    one_statement_parses_to("SET TIME ZONE 'UTC'", "SET timezone = 'UTC'");
+    one_statement_parses_to("SET TIME ZONE LOCAL", "SET timezone = LOCAL");
 
     assert_eq!(
         parse_sql_statements("SET"),
@@ -462,6 +464,19 @@ fn parse_set() {
     );
 }
 
+#[test]
+fn parse_reset() {
+    let stmt = verified_stmt("RESET a");
+    assert_eq!(
+        stmt,
+        Statement::ResetVariable {
+            variable: "a".into()
+        }
+    );
+    verified_stmt("RESET ALL");
+    assert!(parse_sql_statements("RESET").is_err());
+}
+
 #[test]
 fn parse_show() {
     let stmt = verified_stmt("SHOW a a");
@@ -608,6 +623,31 @@ fn parse_prepare() {
     );
 }
 
+#[test]
+fn parse_prepare_with_parameters() {
+    let stmt = verified_stmt("PREPARE a (INT) AS SELECT * FROM customers WHERE id = $1");
+    let sub_stmt = match stmt {
+        Statement::Prepare { statement, .. } => statement,
+        _ => unreachable!(),
+    };
+    match *sub_stmt {
+        Statement::Query(query) => match query.body {
+            SetExpr::Select(select) => assert_eq!(
+                select.selection,
+                Some(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier("id".into())),
+                    op: BinaryOperator::Eq,
+                    right: Box::new(Expr::Parameter { index: 1 }),
+                })
+            ),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    assert!(parse_sql_statements("SELECT $0").is_err());
+}
+
 #[test]
 fn parse_pg_bitwise_binary_ops() {
     let bitwise_ops = &[
@@ -689,6 +729,32 @@ fn parse_pg_regex_match_ops() {
     }
 }
 
+#[test]
+fn parse_pg_json_ops() {
+    let pg_json_ops = &[
+        ("->", BinaryOperator::Arrow),
+        ("->>", BinaryOperator::LongArrow),
+        ("#>", BinaryOperator::HashArrow),
+        ("#>>", BinaryOperator::HashLongArrow),
+        ("@>", BinaryOperator::AtArrow),
+        ("?", BinaryOperator::Question),
+    ];
+
+    for (str_op, op) in pg_json_ops {
+        let select = verified_only_select(&format!("SELECT a {} 'b'", &str_op));
+        assert_eq!(
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("a"))),
+                op: op.clone(),
+                right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+            }),
+            select.projection[0]
+        );
+    }
+
+    verified_only_select("SELECT CAST('{}' AS JSONB)");
+}
+
 #[test]
 fn test_transaction_statement() {
     let statement = verified_stmt("SET TRANSACTION SNAPSHOT '000003A1-1'");
@@ -754,4 +820,24 @@ fn parse_comments() {
         }
         _ => unreachable!(),
     }
+
+    match verified_stmt("COMMENT ON MATERIALIZED VIEW mv IS 'comment'") {
+        Statement::Comment {
+            object_type,
+            object_name,
+            comment: Some(comment),
+        } => {
+            assert_eq!("comment", comment);
+            assert_eq!("mv", object_name.to_string());
+            assert_eq!(CommentObject::MaterializedView, object_type);
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("COMMENT ON SOURCE s IS 'comment'") {
+        Statement::Comment { object_type, .. } => {
+            assert_eq!(CommentObject::Source, object_type);
+        }
+        _ => unreachable!(),
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::future::Future;
+use std::ops::Bound::{self, *};
 
 use bytes::{BufMut, Bytes, BytesMut};
 use risingwave_common::catalog::TableId;
@@ -165,6 +166,32 @@ impl<S: StateStore> Keyspace<S> {
         Ok(strip_prefix_iterator)
     }
 
+    /// Gets an iterator of the keys of the keyspace within `range`, where the bounds of `range`
+    /// don't include the prefix of this keyspace.
+    /// The returned iterator will iterate data from a snapshot corresponding to the given `epoch`
+    pub async fn iter_with_range(
+        &self,
+        range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
+        epoch: u64,
+    ) -> StorageResult<StripPrefixIterator<S::Iter>> {
+        let start = match range.0 {
+            Included(key) => Included(self.prefixed_key(key)),
+            Excluded(key) => Excluded(self.prefixed_key(key)),
+            Unbounded => Included(self.prefix.to_owned()),
+        };
+        let end = match range.1 {
+            Included(key) => Included(self.prefixed_key(key)),
+            Excluded(key) => Excluded(self.prefixed_key(key)),
+            Unbounded => Excluded(next_key(self.prefix.as_slice())),
+        };
+        let iter = self.store.iter((start, end), epoch).await?;
+        let strip_prefix_iterator = StripPrefixIterator {
+            iter,
+            prefix_len: self.prefix.len(),
+        };
+        Ok(strip_prefix_iterator)
+    }
+
     /// Gets the underlying state store.
     pub fn state_store(&self) -> S {
         self.store.clone()
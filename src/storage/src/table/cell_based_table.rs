@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::ops::Bound::{self, *};
 use std::sync::Arc;
 
 use bytes::Bytes;
@@ -22,6 +23,7 @@ use risingwave_common::array::column::Column;
 use risingwave_common::array::{DataChunk, Row};
 use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema};
 use risingwave_common::error::RwError;
+use risingwave_common::types::Datum;
 use risingwave_common::util::hash_util::CRC32FastBuilder;
 use risingwave_common::util::ordered::*;
 use risingwave_common::util::sort_util::OrderType;
@@ -306,17 +308,81 @@ impl<S: StateStore> CellBasedTable<S> {
         self.batch_write_rows_inner::<false>(buffer, epoch).await
     }
 
+    /// Gets the row with `pk` for batch queries, which waits for `epoch` to be readable first.
+    pub async fn batch_get_row(&self, pk: &Row, epoch: u64) -> StorageResult<Option<Row>> {
+        self.keyspace.state_store().wait_epoch(epoch).await?;
+        self.get_row(pk, epoch).await
+    }
+
     // The returned iterator will iterate data from a snapshot corresponding to the given `epoch`
     pub async fn iter(&self, epoch: u64) -> StorageResult<CellBasedTableRowIter<S>> {
         CellBasedTableRowIter::new(
             self.keyspace.clone(),
             self.column_descs.clone(),
+            (Unbounded, Unbounded),
             epoch,
             self.stats.clone(),
         )
         .await
     }
 
+    /// Iterates the rows whose pk starts with `pk_prefix`, and whose next pk column after the
+    /// prefix is within `next_col_bounds`. The bounds are compared by the values of the column
+    /// rather than by its order type, and must not be null.
+    pub async fn iter_with_pk_bounds(
+        &self,
+        epoch: u64,
+        pk_prefix: &Row,
+        next_col_bounds: (Bound<Datum>, Bound<Datum>),
+    ) -> StorageResult<CellBasedTableRowIter<S>> {
+        let (start_key, end_key) = self.pk_bounds_to_key_range(pk_prefix, next_col_bounds)?;
+        CellBasedTableRowIter::new(
+            self.keyspace.clone(),
+            self.column_descs.clone(),
+            (start_key, end_key),
+            epoch,
+            self.stats.clone(),
+        )
+        .await
+    }
+
+    /// The range of the serialized pks of the rows selected by [`Self::iter_with_pk_bounds`].
+    pub fn pk_bounds_to_key_range(
+        &self,
+        pk_prefix: &Row,
+        next_col_bounds: (Bound<Datum>, Bound<Datum>),
+    ) -> StorageResult<(Bound<Vec<u8>>, Bound<Vec<u8>>)> {
+        let pk_serializer = self.pk_serializer.as_ref().expect("pk_serializer is None");
+        let prefix_len = pk_prefix.size();
+        let serialize_with_next_col = |datum: Datum| {
+            let mut values = pk_prefix.0.clone();
+            values.push(datum);
+            serialize_pk(&Row(values), &pk_serializer.prefix(prefix_len + 1)).map_err(err)
+        };
+
+        // The bounds of the column are reversed in the keys if it's in descending order.
+        let (lower, upper) = match pk_serializer.order_types().get(prefix_len) {
+            Some(OrderType::Descending) => (next_col_bounds.1, next_col_bounds.0),
+            _ => next_col_bounds,
+        };
+        let start_key = match lower {
+            Included(datum) => Included(serialize_with_next_col(datum)?),
+            // Skip all keys starting with the bound.
+            Excluded(datum) => Included(next_key(&serialize_with_next_col(datum)?)),
+            Unbounded => {
+                Included(serialize_pk(pk_prefix, &pk_serializer.prefix(prefix_len)).map_err(err)?)
+            }
+        };
+        let end_key = match upper {
+            Included(datum) => end_bound_of_prefix(&serialize_with_next_col(datum)?),
+            Excluded(datum) => Excluded(serialize_with_next_col(datum)?),
+            Unbounded => end_bound_of_prefix(
+                &serialize_pk(pk_prefix, &pk_serializer.prefix(prefix_len)).map_err(err)?,
+            ),
+        };
+        Ok((start_key, end_key))
+    }
+
     // streaming_iter is uesed for streaming executors, which is regarded as a short-term iterator
     // and will not wait for epoch.
     pub async fn streaming_iter(
@@ -326,11 +392,37 @@ impl<S: StateStore> CellBasedTable<S> {
         CellBasedTableStreamingIter::new(&self.keyspace, self.column_descs.clone(), epoch).await
     }
 
+    /// Like [`Self::streaming_iter`], but only iterates the rows whose serialized pks are within
+    /// `key_range`.
+    pub async fn streaming_iter_with_range(
+        &self,
+        key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
+        epoch: u64,
+    ) -> StorageResult<CellBasedTableStreamingIter<S>> {
+        CellBasedTableStreamingIter::new_with_range(
+            &self.keyspace,
+            self.column_descs.clone(),
+            key_range,
+            epoch,
+        )
+        .await
+    }
+
     pub fn schema(&self) -> &Schema {
         &self.schema
     }
 }
 
+/// The end bound of the keys starting with `prefix`.
+fn end_bound_of_prefix(prefix: &[u8]) -> Bound<Vec<u8>> {
+    let end_key = next_key(prefix);
+    if end_key.is_empty() {
+        Unbounded
+    } else {
+        Excluded(end_key)
+    }
+}
+
 fn generate_column_id(column_descs: &[ColumnDesc]) -> Vec<ColumnId> {
     column_descs.iter().map(|d| d.column_id).collect()
 }
@@ -350,6 +442,7 @@ impl<S: StateStore> CellBasedTableRowIter<S> {
     pub async fn new(
         keyspace: Keyspace<S>,
         table_descs: Vec<ColumnDesc>,
+        key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
         epoch: u64,
         _stats: Arc<StateStoreMetrics>,
     ) -> StorageResult<Self> {
@@ -357,7 +450,7 @@ impl<S: StateStore> CellBasedTableRowIter<S> {
 
         let cell_based_row_deserializer = CellBasedRowDeserializer::new(table_descs);
 
-        let iter = keyspace.iter(epoch).await?;
+        let iter = keyspace.iter_with_range(key_range, epoch).await?;
 
         let iter = Self {
             iter,
@@ -449,9 +542,18 @@ impl<S: StateStore> CellBasedTableStreamingIter<S> {
         keyspace: &Keyspace<S>,
         table_descs: Vec<ColumnDesc>,
         epoch: u64,
+    ) -> StorageResult<Self> {
+        Self::new_with_range(keyspace, table_descs, (Unbounded, Unbounded), epoch).await
+    }
+
+    pub async fn new_with_range(
+        keyspace: &Keyspace<S>,
+        table_descs: Vec<ColumnDesc>,
+        key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
+        epoch: u64,
     ) -> StorageResult<Self> {
         let cell_based_row_deserializer = CellBasedRowDeserializer::new(table_descs);
-        let iter = keyspace.iter(epoch).await?;
+        let iter = keyspace.iter_with_range(key_range, epoch).await?;
         let iter = Self {
             iter,
             cell_based_row_deserializer,
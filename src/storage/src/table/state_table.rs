@@ -15,6 +15,7 @@ use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::btree_map;
 use std::marker::PhantomData;
+use std::ops::Bound::{self, Unbounded};
 use std::sync::Arc;
 
 use futures::{pin_mut, Stream, StreamExt};
@@ -22,6 +23,7 @@ use futures_async_stream::try_stream;
 use risingwave_common::array::Row;
 use risingwave_common::catalog::ColumnDesc;
 use risingwave_common::error::RwError;
+use risingwave_common::types::Datum;
 use risingwave_common::util::ordered::{serialize_pk, OrderedRowSerializer};
 use risingwave_common::util::sort_util::OrderType;
 
@@ -127,11 +129,34 @@ impl<S: StateStore> StateTable<S> {
     }
 
     pub async fn iter(&self, epoch: u64) -> StorageResult<impl RowStream<'_>> {
-        let mem_table_iter = self.mem_table.buffer.iter();
+        let mem_table_iter = self.mem_table.buffer.range::<Vec<u8>, _>(..);
         Ok(StateTableRowIter::into_stream(
             &self.keyspace,
             self.column_descs.clone(),
             mem_table_iter,
+            (Unbounded, Unbounded),
+            epoch,
+        ))
+    }
+
+    /// Iterates the rows whose pk starts with `pk_prefix`, and whose next pk column after the
+    /// prefix is within `next_col_bounds`, in the order of the pk. The bounds are compared by the
+    /// values of the column rather than by its order type, and must not be null.
+    pub async fn iter_with_pk_bounds(
+        &self,
+        epoch: u64,
+        pk_prefix: &Row,
+        next_col_bounds: (Bound<Datum>, Bound<Datum>),
+    ) -> StorageResult<impl RowStream<'_>> {
+        let key_range = self
+            .cell_based_table
+            .pk_bounds_to_key_range(pk_prefix, next_col_bounds)?;
+        let mem_table_iter = self.mem_table.buffer.range(key_range.clone());
+        Ok(StateTableRowIter::into_stream(
+            &self.keyspace,
+            self.column_descs.clone(),
+            mem_table_iter,
+            key_range,
             epoch,
         ))
     }
@@ -139,7 +164,7 @@ impl<S: StateStore> StateTable<S> {
 
 pub trait RowStream<'a> = Stream<Item = StorageResult<Cow<'a, Row>>> + 'a;
 
-type MemTableIter<'a> = btree_map::Iter<'a, Vec<u8>, RowOp>;
+type MemTableIter<'a> = btree_map::Range<'a, Vec<u8>, RowOp>;
 
 struct StateTableRowIter<S: StateStore> {
     _phantom: PhantomData<S>,
@@ -156,18 +181,17 @@ impl<S: StateStore> StateTableRowIter<S> {
         keyspace: &'a Keyspace<S>,
         table_descs: Vec<ColumnDesc>,
         mem_table_iter: MemTableIter<'a>,
+        key_range: (Bound<Vec<u8>>, Bound<Vec<u8>>),
         epoch: u64,
     ) {
         let cell_based_table_iter: futures::stream::Peekable<_> =
-            CellBasedTableStreamingIter::new(keyspace, table_descs, epoch)
+            CellBasedTableStreamingIter::new_with_range(keyspace, table_descs, key_range, epoch)
                 .await?
                 .into_stream()
                 .peekable();
         pin_mut!(cell_based_table_iter);
 
-        let mut mem_table_iter = mem_table_iter
-            .map(|(k, v)| Ok::<_, StorageError>((k, v)))
-            .peekable();
+        let mut mem_table_iter = mem_table_iter.peekable();
 
         loop {
             match (
@@ -180,7 +204,7 @@ impl<S: StateStore> StateTableRowIter<S> {
                     yield Cow::Owned(row);
                 }
                 (None, Some(_)) => {
-                    let row_op = mem_table_iter.next().unwrap()?.1;
+                    let row_op = mem_table_iter.next().unwrap().1;
                     match row_op {
                         RowOp::Insert(row) | RowOp::Update((_, row)) => {
                             yield Cow::Borrowed(row);
@@ -191,7 +215,7 @@ impl<S: StateStore> StateTableRowIter<S> {
 
                 (
                     Some(Ok((cell_based_pk, cell_based_row))),
-                    Some(Ok((mem_table_pk, _mem_table_row_op))),
+                    Some((mem_table_pk, _mem_table_row_op)),
                 ) => {
                     match cell_based_pk.cmp(mem_table_pk) {
                         Ordering::Less => {
@@ -203,7 +227,7 @@ impl<S: StateStore> StateTableRowIter<S> {
                             // mem_table_item will be return, while both cell_based_streaming_iter
                             // and mem_table_iter need to execute next()
                             // once.
-                            let row_op = mem_table_iter.next().unwrap()?.1;
+                            let row_op = mem_table_iter.next().unwrap().1;
                             match row_op {
                                 RowOp::Insert(row) => yield Cow::Borrowed(row),
                                 RowOp::Delete(_) => {}
@@ -216,7 +240,7 @@ impl<S: StateStore> StateTableRowIter<S> {
                         }
                         Ordering::Greater => {
                             // mem_table_item will be return
-                            let row_op = mem_table_iter.next().unwrap()?.1;
+                            let row_op = mem_table_iter.next().unwrap().1;
                             match row_op {
                                 RowOp::Insert(row) => yield Cow::Borrowed(row),
                                 RowOp::Delete(_) => {}
@@ -228,7 +252,6 @@ impl<S: StateStore> StateTableRowIter<S> {
                 (Some(_), Some(_)) => {
                     // Throw the error.
                     cell_based_table_iter.next().await.unwrap()?;
-                    mem_table_iter.next().unwrap()?;
 
                     unreachable!()
                 }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::Bound::*;
+
 use futures::pin_mut;
 use futures::stream::StreamExt;
 use risingwave_common::array::Row;
@@ -1451,3 +1453,135 @@ async fn test_cell_based_scan_empty_column_ids_cardinality() {
     };
     assert_eq!(chunk.cardinality(), 2);
 }
+
+#[tokio::test]
+async fn test_cell_based_table_iter_with_pk_bounds() {
+    let state_store = MemoryStateStore::new();
+    let order_types = vec![OrderType::Ascending, OrderType::Descending];
+    let keyspace = Keyspace::executor_root(state_store.clone(), 0x42);
+    let column_ids = vec![ColumnId::from(0), ColumnId::from(1), ColumnId::from(2)];
+    let column_descs = vec![
+        ColumnDesc::unnamed(column_ids[0], DataType::Int32),
+        ColumnDesc::unnamed(column_ids[1], DataType::Int32),
+        ColumnDesc::unnamed(column_ids[2], DataType::Int32),
+    ];
+    let pk_index = vec![0_usize, 1_usize];
+    let mut state = StateTable::new(
+        keyspace.clone(),
+        column_descs.clone(),
+        order_types.clone(),
+        None,
+        pk_index,
+    );
+    let table = CellBasedTable::new_for_test(keyspace.clone(), column_descs, order_types);
+    let epoch: u64 = 0;
+
+    let rows = [(1, 11), (1, 12), (1, 13), (2, 21)];
+    for (a, b) in rows {
+        state
+            .insert(
+                &Row(vec![Some(a.into()), Some(b.into())]),
+                Row(vec![Some(a.into()), Some(b.into()), Some((b * 10).into())]),
+            )
+            .unwrap();
+    }
+    state.commit(epoch).await.unwrap();
+
+    let epoch = u64::MAX;
+    async fn collect(mut iter: impl TableIter) -> Vec<Row> {
+        let mut rows = vec![];
+        while let Some(row) = iter.next().await.unwrap() {
+            rows.push(row);
+        }
+        rows
+    }
+    let row = |a: i32, b: i32| Row(vec![Some(a.into()), Some(b.into()), Some((b * 10).into())]);
+
+    // `a = 1 AND b >= 12`, where `b` is in descending order.
+    let iter = table
+        .iter_with_pk_bounds(
+            epoch,
+            &Row(vec![Some(1_i32.into())]),
+            (Included(Some(12_i32.into())), Unbounded),
+        )
+        .await
+        .unwrap();
+    assert_eq!(collect(iter).await, vec![row(1, 13), row(1, 12)]);
+
+    // `a = 1 AND b < 13`
+    let iter = table
+        .iter_with_pk_bounds(
+            epoch,
+            &Row(vec![Some(1_i32.into())]),
+            (Unbounded, Excluded(Some(13_i32.into()))),
+        )
+        .await
+        .unwrap();
+    assert_eq!(collect(iter).await, vec![row(1, 12), row(1, 11)]);
+
+    // `a > 1`
+    let iter = table
+        .iter_with_pk_bounds(
+            epoch,
+            &Row(vec![]),
+            (Excluded(Some(1_i32.into())), Unbounded),
+        )
+        .await
+        .unwrap();
+    assert_eq!(collect(iter).await, vec![row(2, 21)]);
+}
+
+#[tokio::test]
+async fn test_state_table_iter_with_pk_bounds() {
+    let state_store = MemoryStateStore::new();
+    let order_types = vec![OrderType::Ascending, OrderType::Ascending];
+    let keyspace = Keyspace::executor_root(state_store, 0x42);
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32),
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int32),
+    ];
+    let mut state = StateTable::new(keyspace, column_descs, order_types, None, vec![0, 1]);
+    let row = |a: i32, b: i32| Row(vec![Some(a.into()), Some(b.into())]);
+
+    for (a, b) in [(1, 11), (2, 21), (3, 31), (4, 41)] {
+        state.insert(&row(a, b), row(a, b)).unwrap();
+    }
+    state.commit(0).await.unwrap();
+
+    // The uncommitted changes are merged with the committed rows within the bounds.
+    state.delete(&row(2, 21), row(2, 21)).unwrap();
+    state.insert(&row(3, 32), row(3, 32)).unwrap();
+    state.insert(&row(5, 51), row(5, 51)).unwrap();
+
+    // `a >= 2 AND a <= 4`
+    let iter = state
+        .iter_with_pk_bounds(
+            u64::MAX,
+            &Row(vec![]),
+            (Included(Some(2_i32.into())), Included(Some(4_i32.into()))),
+        )
+        .await
+        .unwrap();
+    pin_mut!(iter);
+    let mut rows = vec![];
+    while let Some(res) = iter.next().await {
+        rows.push(res.unwrap().into_owned());
+    }
+    assert_eq!(rows, vec![row(3, 31), row(3, 32), row(4, 41)]);
+
+    // `a < 3`
+    let iter = state
+        .iter_with_pk_bounds(
+            u64::MAX,
+            &Row(vec![]),
+            (Unbounded, Excluded(Some(3_i32.into()))),
+        )
+        .await
+        .unwrap();
+    pin_mut!(iter);
+    let mut rows = vec![];
+    while let Some(res) = iter.next().await {
+        rows.push(res.unwrap().into_owned());
+    }
+    assert_eq!(rows, vec![row(1, 11)]);
+}
@@ -0,0 +1,563 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::ops::Bound::*;
+
+use futures::{pin_mut, StreamExt};
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Op, Row, StreamChunk};
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema};
+use risingwave_common::error::Result;
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::types::{Datum, ToOwnedDatum};
+use risingwave_common::util::hash_util::CRC32FastBuilder;
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_pb::expr::expr_node::Type as ExprNodeType;
+use risingwave_storage::table::state_table::StateTable;
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::barrier_align::*;
+use super::error::StreamExecutorError;
+use super::{BoxedExecutor, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef};
+use crate::executor::PROCESSING_WINDOW_SIZE;
+
+/// `DynamicFilterExecutor` filters the rows of the left input by comparing a column with the value
+/// of the right input, which is a single row that changes over time, e.g. `ts > now() - INTERVAL
+/// '1' HOUR`.
+///
+/// The left rows are kept in the state, ordered by the compared column. When the value of the
+/// right side changes, only the rows between the old and the new value are scanned, and those
+/// whose result changes are inserted or deleted. So the cost of a change is proportional to the
+/// rows crossing the bound rather than to all the rows kept.
+///
+/// The left side is sharded by its distribution keys and its rows are stored under their vnodes,
+/// while the right side is expected to be broadcast to every actor. The value of the right side is
+/// kept under the first vnode of each actor, so that it's known right after recovery.
+pub struct DynamicFilterExecutor<S: StateStore> {
+    input_l: Option<BoxedExecutor>,
+    input_r: Option<BoxedExecutor>,
+    schema: Schema,
+    pk_indices: PkIndices,
+    identity: String,
+    /// The column of the left side compared with the value of the right side.
+    key_l: usize,
+    /// One of `<`, `<=`, `>` and `>=`, with the left column on the left.
+    comparator: ExprNodeType,
+
+    /// The root of the state of the left side, which is followed by the vnode.
+    keyspace_l: Keyspace<S>,
+    column_descs_l: Vec<ColumnDesc>,
+    /// The pk of the state of the left side, which is the compared column followed by the pk of
+    /// the input.
+    state_pk_indices_l: Vec<usize>,
+    dist_key_indices_l: Vec<usize>,
+    /// The vnodes owned by the actor.
+    vnodes: Vec<VirtualNode>,
+    /// The state tables of the vnodes that may have rows, found at the first access.
+    state_tables_l: Option<BTreeMap<VirtualNode, StateTable<S>>>,
+
+    /// Keeps the value of the right side, as a single row with an empty pk.
+    state_table_r: StateTable<S>,
+    /// The value of the right side. The comparison is never true if it's null or not known yet.
+    bound: Datum,
+
+    epoch: u64,
+}
+
+impl<S: StateStore> std::fmt::Debug for DynamicFilterExecutor<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DynamicFilterExecutor")
+            .field("input_left", &self.input_l.as_ref().unwrap().identity())
+            .field("input_right", &self.input_r.as_ref().unwrap().identity())
+            .field("key_left", &self.key_l)
+            .field("comparator", &self.comparator)
+            .field("pk_indices", &self.pk_indices)
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+impl<S: StateStore> Executor for DynamicFilterExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.into_stream().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        self.identity.as_str()
+    }
+}
+
+impl<S: StateStore> DynamicFilterExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_l: BoxedExecutor,
+        input_r: BoxedExecutor,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        key_l: usize,
+        comparator: ExprNodeType,
+        ks_l: Keyspace<S>,
+        ks_r: Keyspace<S>,
+        dist_key_indices_l: Vec<usize>,
+        vnodes: Vec<VirtualNode>,
+    ) -> Self {
+        assert!(matches!(
+            comparator,
+            ExprNodeType::LessThan
+                | ExprNodeType::LessThanOrEqual
+                | ExprNodeType::GreaterThan
+                | ExprNodeType::GreaterThanOrEqual
+        ));
+        let schema = input_l.schema().clone();
+        let column_descs_l = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| ColumnDesc::unnamed(ColumnId::new(i as i32), field.data_type()))
+            .collect();
+        let state_pk_indices_l = std::iter::once(key_l)
+            .chain(input_l.pk_indices().iter().copied())
+            .collect();
+        let data_type_r = input_r.schema().fields()[0].data_type();
+        let state_table_r = StateTable::new(
+            ks_r.append_u16(vnodes[0]),
+            vec![ColumnDesc::unnamed(ColumnId::new(0), data_type_r)],
+            vec![],
+            None,
+            vec![],
+        );
+
+        Self {
+            input_l: Some(input_l),
+            input_r: Some(input_r),
+            schema,
+            pk_indices,
+            identity: format!("DynamicFilterExecutor {:X}", executor_id),
+            key_l,
+            comparator,
+            keyspace_l: ks_l,
+            column_descs_l,
+            state_pk_indices_l,
+            dist_key_indices_l,
+            vnodes,
+            state_tables_l: None,
+            state_table_r,
+            bound: None,
+            epoch: 0,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn into_stream(mut self) {
+        let input_l = self.input_l.take().unwrap();
+        let input_r = self.input_r.take().unwrap();
+        let aligned_stream = barrier_align(input_l.execute(), input_r.execute());
+        let mut is_first_barrier = true;
+
+        #[for_await]
+        for msg in aligned_stream {
+            match msg? {
+                AlignedMessage::Left(chunk) => {
+                    if let Some(chunk) = self
+                        .apply_left(chunk)
+                        .await
+                        .map_err(StreamExecutorError::dynamic_filter_error)?
+                    {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                AlignedMessage::Right(chunk) => {
+                    let chunks = self
+                        .apply_right(chunk)
+                        .await
+                        .map_err(StreamExecutorError::dynamic_filter_error)?;
+                    for chunk in chunks {
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                AlignedMessage::Barrier(barrier) => {
+                    if is_first_barrier {
+                        // Recover the value of the right side, which is committed at the epoch
+                        // before the first barrier.
+                        self.bound = self
+                            .state_table_r
+                            .get_row(&Row(vec![]), barrier.epoch.prev)
+                            .await
+                            .map_err(StreamExecutorError::storage)?
+                            .and_then(|row| row[0].clone());
+                        is_first_barrier = false;
+                    } else {
+                        self.commit(barrier.epoch.prev)
+                            .await
+                            .map_err(StreamExecutorError::dynamic_filter_error)?;
+                    }
+                    self.epoch = barrier.epoch.curr;
+                    yield Message::Barrier(barrier);
+                }
+            }
+        }
+    }
+
+    /// Whether a left row whose compared column is `key` passes the filter with `bound`.
+    fn passes(&self, key: &Datum, bound: &Datum) -> bool {
+        let (Some(key), Some(bound)) = (key, bound) else {
+            return false;
+        };
+        match self.comparator {
+            ExprNodeType::LessThan => key < bound,
+            ExprNodeType::LessThanOrEqual => key <= bound,
+            ExprNodeType::GreaterThan => key > bound,
+            ExprNodeType::GreaterThanOrEqual => key >= bound,
+            _ => unreachable!(),
+        }
+    }
+
+    fn new_state_table_l(&self, vnode: VirtualNode) -> StateTable<S> {
+        StateTable::new(
+            self.keyspace_l.append_u16(vnode),
+            self.column_descs_l.clone(),
+            vec![OrderType::Ascending; self.state_pk_indices_l.len()],
+            None,
+            self.state_pk_indices_l.clone(),
+        )
+    }
+
+    /// Finds the vnodes with rows of the left side.
+    async fn state_tables_l(&mut self) -> Result<&mut BTreeMap<VirtualNode, StateTable<S>>> {
+        if self.state_tables_l.is_none() {
+            let mut state_tables = BTreeMap::new();
+            for &vnode in &self.vnodes {
+                if !self
+                    .keyspace_l
+                    .append_u16(vnode)
+                    .scan(Some(1), self.epoch)
+                    .await?
+                    .is_empty()
+                {
+                    state_tables.insert(vnode, self.new_state_table_l(vnode));
+                }
+            }
+            self.state_tables_l = Some(state_tables);
+        }
+        Ok(self.state_tables_l.as_mut().unwrap())
+    }
+
+    /// The vnodes to store the rows of `data_chunk` under.
+    fn vnodes_of(&self, data_chunk: &DataChunk) -> Result<Vec<VirtualNode>> {
+        if self.dist_key_indices_l.is_empty() {
+            return Ok(vec![self.vnodes[0]; data_chunk.capacity()]);
+        }
+        Ok(data_chunk
+            .get_hash_values(&self.dist_key_indices_l, CRC32FastBuilder)?
+            .into_iter()
+            .map(|hash| hash.to_vnode())
+            .collect())
+    }
+
+    /// Keeps the rows of `chunk` in the state, and returns those passing the filter with the
+    /// current value of the right side.
+    async fn apply_left(&mut self, chunk: StreamChunk) -> Result<Option<StreamChunk>> {
+        let chunk = chunk.compact()?;
+        let (data_chunk, ops) = chunk.into_parts();
+        let vnodes = self.vnodes_of(&data_chunk)?;
+        self.state_tables_l().await?;
+
+        let mut output = vec![];
+        for ((row, op), vnode) in data_chunk.rows().zip_eq(ops).zip_eq(vnodes) {
+            let row = row.to_owned_row();
+            let key = row[self.key_l].clone();
+            // Updates are split into deletes and inserts, as only one of the old and the new rows
+            // may pass the filter.
+            let op = match op {
+                Op::Insert | Op::UpdateInsert => Op::Insert,
+                Op::Delete | Op::UpdateDelete => Op::Delete,
+            };
+            if self.passes(&key, &self.bound) {
+                output.push((op, row.clone()));
+            }
+            // The rows with a null key never pass the filter, so they're not kept.
+            if key.is_none() {
+                continue;
+            }
+            let pk = Row(self
+                .state_pk_indices_l
+                .iter()
+                .map(|&idx| row[idx].clone())
+                .collect());
+            if !self.state_tables_l.as_ref().unwrap().contains_key(&vnode) {
+                let state_table = self.new_state_table_l(vnode);
+                self.state_tables_l
+                    .as_mut()
+                    .unwrap()
+                    .insert(vnode, state_table);
+            }
+            let state_table = self
+                .state_tables_l
+                .as_mut()
+                .unwrap()
+                .get_mut(&vnode)
+                .unwrap();
+            match op {
+                Op::Insert => state_table.insert(&pk, row)?,
+                _ => state_table.delete(&pk, row)?,
+            }
+        }
+
+        if output.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(StreamChunk::from_rows(
+            &output,
+            &self.schema.data_types(),
+        )?))
+    }
+
+    /// Updates the value of the right side with `chunk`, and returns the changes of the rows whose
+    /// result changes, which are between the old and the new values.
+    async fn apply_right(&mut self, chunk: StreamChunk) -> Result<Vec<StreamChunk>> {
+        let mut new_bound = self.bound.clone();
+        for (op, row) in chunk.rows() {
+            match op {
+                Op::Insert | Op::UpdateInsert => new_bound = row.value_at(0).to_owned_datum(),
+                Op::Delete | Op::UpdateDelete => new_bound = None,
+            }
+        }
+        let old_bound = std::mem::replace(&mut self.bound, new_bound.clone());
+        if old_bound == new_bound {
+            return Ok(vec![]);
+        }
+
+        if let Some(old_row) = self.bound_row(&old_bound) {
+            self.state_table_r.delete(&Row(vec![]), old_row)?;
+        }
+        if let Some(new_row) = self.bound_row(&new_bound) {
+            self.state_table_r.insert(&Row(vec![]), new_row)?;
+        }
+
+        // Only the rows between the old and the new values may change. If one of them is null, all
+        // the rows are scanned.
+        let key_bounds = match (&old_bound, &new_bound) {
+            (Some(old), Some(new)) => (
+                Included(Some(old.min(new).clone())),
+                Included(Some(old.max(new).clone())),
+            ),
+            _ => (Unbounded, Unbounded),
+        };
+        let mut output = vec![];
+        self.state_tables_l().await?;
+        for state_table in self.state_tables_l.as_ref().unwrap().values() {
+            let iter = state_table
+                .iter_with_pk_bounds(self.epoch, &Row(vec![]), key_bounds.clone())
+                .await?;
+            pin_mut!(iter);
+            while let Some(row) = iter.next().await {
+                let row = row?.into_owned();
+                let key = &row[self.key_l];
+                match (self.passes(key, &old_bound), self.passes(key, &new_bound)) {
+                    (false, true) => output.push((Op::Insert, row)),
+                    (true, false) => output.push((Op::Delete, row)),
+                    _ => {}
+                }
+            }
+        }
+
+        let data_types = self.schema.data_types();
+        output
+            .chunks(PROCESSING_WINDOW_SIZE)
+            .map(|rows| StreamChunk::from_rows(rows, &data_types))
+            .collect()
+    }
+
+    fn bound_row(&self, bound: &Datum) -> Option<Row> {
+        bound.clone().map(|bound| Row(vec![Some(bound)]))
+    }
+
+    async fn commit(&mut self, epoch: u64) -> Result<()> {
+        if let Some(state_tables) = &mut self.state_tables_l {
+            for state_table in state_tables.values_mut() {
+                state_table.commit(epoch).await?;
+            }
+        }
+        self.state_table_r.commit(epoch).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::{Field, TableId};
+    use risingwave_common::hash::VIRTUAL_NODE_COUNT;
+    use risingwave_common::types::DataType;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor::test_utils::{MessageSender, MockSource};
+
+    fn create_executor(
+        mem_state: MemoryStateStore,
+        comparator: ExprNodeType,
+    ) -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let schema_l = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let schema_r = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let (tx_l, source_l) = MockSource::channel(schema_l, vec![0]);
+        let (tx_r, source_r) = MockSource::channel(schema_r, vec![]);
+        let executor = DynamicFilterExecutor::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            vec![0],
+            1,
+            1,
+            comparator,
+            Keyspace::table_root(mem_state.clone(), &TableId::new(0)),
+            Keyspace::table_root(mem_state, &TableId::new(1)),
+            vec![0],
+            (0..VIRTUAL_NODE_COUNT as VirtualNode).collect(),
+        );
+        (tx_l, tx_r, Box::new(executor).execute())
+    }
+
+    /// The order of the rows found by scanning depends on their vnodes, so they are sorted before
+    /// comparing.
+    fn sorted_rows(chunk: StreamChunk) -> Vec<(Op, Row)> {
+        chunk
+            .rows()
+            .map(|(op, row)| (op, row.to_owned_row()))
+            .sorted()
+            .collect()
+    }
+
+    async fn next_rows(filter: &mut BoxedMessageStream) -> Vec<(Op, Row)> {
+        sorted_rows(filter.next().await.unwrap().unwrap().into_chunk().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_filter() {
+        let mem_state = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut filter) =
+            create_executor(mem_state.clone(), ExprNodeType::GreaterThan);
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        filter.next().await.unwrap().unwrap();
+
+        // Nothing passes before the value of the right side is known.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 1
+             + 2 5
+             + 3 10",
+        ));
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I
+             + 3",
+        ));
+        assert_eq!(
+            next_rows(&mut filter).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I
+                + 2 5
+                + 3 10"
+            ))
+        );
+
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        filter.next().await.unwrap().unwrap();
+
+        // The new left rows are filtered with the current value.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 4 2
+             + 5 7
+             - 2 5",
+        ));
+        assert_eq!(
+            next_rows(&mut filter).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I
+                + 5 7
+                - 2 5"
+            ))
+        );
+
+        // Only the rows crossing the bound are changed.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I
+             U- 3
+             U+ 8",
+        ));
+        assert_eq!(
+            next_rows(&mut filter).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I
+                - 5 7"
+            ))
+        );
+
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+        filter.next().await.unwrap().unwrap();
+
+        // A new executor on the same state store recovers the rows and the value of the right
+        // side.
+        let (mut tx_l, mut tx_r, mut filter) =
+            create_executor(mem_state, ExprNodeType::GreaterThan);
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+        filter.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 6 9",
+        ));
+        assert_eq!(
+            next_rows(&mut filter).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I
+                + 6 9"
+            ))
+        );
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I
+             U- 8
+             U+ 1",
+        ));
+        assert_eq!(
+            next_rows(&mut filter).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I
+                + 4 2
+                + 5 7"
+            ))
+        );
+    }
+}
@@ -50,6 +50,12 @@ enum StreamExecutorErrorInner {
     #[error("Hash join error: {0}")]
     HashJoinError(RwError),
 
+    #[error("Nested-loop join error: {0}")]
+    NestedLoopJoinError(RwError),
+
+    #[error("Dynamic filter error: {0}")]
+    DynamicFilterError(RwError),
+
     #[error("Source error: {0}")]
     SourceError(RwError),
 
@@ -89,6 +95,14 @@ impl StreamExecutorError {
         StreamExecutorErrorInner::HashJoinError(error.into()).into()
     }
 
+    pub fn nested_loop_join_error(error: impl Into<RwError>) -> Self {
+        StreamExecutorErrorInner::NestedLoopJoinError(error.into()).into()
+    }
+
+    pub fn dynamic_filter_error(error: impl Into<RwError>) -> Self {
+        StreamExecutorErrorInner::DynamicFilterError(error.into()).into()
+    }
+
     pub fn source_error(error: impl Into<RwError>) -> Self {
         StreamExecutorErrorInner::SourceError(error.into()).into()
     }
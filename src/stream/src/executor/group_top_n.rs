@@ -0,0 +1,360 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use async_trait::async_trait;
+use madsim::collections::{HashMap, HashSet};
+use risingwave_common::array::{Op, Row, StreamChunk};
+use risingwave_common::catalog::Schema;
+use risingwave_common::collection::evictable::EvictableHashMap;
+use risingwave_common::error::Result;
+use risingwave_common::util::sort_util::OrderPair;
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::error::{StreamExecutorError, StreamExecutorResult};
+use super::top_n::InnerTopNExecutor;
+use super::top_n_executor::{generate_output, TopNExecutorBase, TopNExecutorWrapper};
+use super::{BoxedMessageStream, Executor, ExecutorInfo, PkIndices, PkIndicesRef};
+
+/// The number of groups whose states are kept in memory after a barrier.
+pub const GROUP_TOP_N_CACHE_SIZE: usize = 1 << 16;
+
+/// `GroupTopNExecutor` works like [`super::TopNExecutor`], but keeps the top N records of each
+/// group separately, where the groups are decided by the `group_by` columns.
+pub type GroupTopNExecutor<S> = TopNExecutorWrapper<InnerGroupTopNExecutor<S>>;
+
+impl<S: StateStore> GroupTopNExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input: Box<dyn Executor>,
+        order_pairs: Vec<OrderPair>,
+        offset_and_limit: (usize, Option<usize>),
+        pk_indices: PkIndices,
+        keyspace: Keyspace<S>,
+        cache_size: Option<usize>,
+        executor_id: u64,
+        group_by: Vec<usize>,
+    ) -> Result<Self> {
+        let info = input.info();
+        let schema = input.schema().clone();
+
+        Ok(TopNExecutorWrapper {
+            input,
+            inner: InnerGroupTopNExecutor::new(
+                info,
+                schema,
+                order_pairs,
+                offset_and_limit,
+                pk_indices,
+                keyspace,
+                cache_size,
+                executor_id,
+                group_by,
+            ),
+        })
+    }
+}
+
+pub struct InnerGroupTopNExecutor<S: StateStore> {
+    info: ExecutorInfo,
+
+    /// Schema of the executor.
+    schema: Schema,
+
+    order_pairs: Vec<OrderPair>,
+
+    /// `OFFSET XXX` and `LIMIT XXX` of each group.
+    offset_and_limit: (usize, Option<usize>),
+
+    /// The primary key indices of the `GroupTopNExecutor`
+    pk_indices: PkIndices,
+
+    /// The keyspace of all the groups. The state of a group is stored under
+    /// `table_id/[group_key]`.
+    keyspace: Keyspace<S>,
+
+    cache_size: Option<usize>,
+
+    executor_id: u64,
+
+    /// Indices of the columns to group by.
+    group_by: Vec<usize>,
+
+    /// The managed top N states of the recently used groups. The state of a group is loaded from
+    /// the storage when the group is not in the cache, and the least recently used groups are
+    /// evicted after each flush.
+    groups: EvictableHashMap<Row, InnerTopNExecutor<S>>,
+
+    /// The groups changed since the last flush, which are the only ones to flush.
+    dirty_groups: HashSet<Row>,
+}
+
+impl<S: StateStore> InnerGroupTopNExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_info: ExecutorInfo,
+        schema: Schema,
+        order_pairs: Vec<OrderPair>,
+        offset_and_limit: (usize, Option<usize>),
+        pk_indices: PkIndices,
+        keyspace: Keyspace<S>,
+        cache_size: Option<usize>,
+        executor_id: u64,
+        group_by: Vec<usize>,
+    ) -> Self {
+        Self {
+            info: ExecutorInfo {
+                schema: input_info.schema,
+                pk_indices: input_info.pk_indices,
+                identity: format!("GroupTopNExecutor {:X}", executor_id),
+            },
+            schema,
+            order_pairs,
+            offset_and_limit,
+            pk_indices,
+            keyspace,
+            cache_size,
+            executor_id,
+            group_by,
+            groups: EvictableHashMap::new(GROUP_TOP_N_CACHE_SIZE),
+            dirty_groups: HashSet::new(),
+        }
+    }
+
+    fn new_group_state(&self, group_key: &Row) -> Result<InnerTopNExecutor<S>> {
+        let keyspace = self.keyspace.append(group_key.serialize()?);
+        InnerTopNExecutor::new(
+            ExecutorInfo {
+                schema: self.info.schema.clone(),
+                pk_indices: self.info.pk_indices.clone(),
+                identity: self.info.identity.clone(),
+            },
+            self.schema.clone(),
+            self.order_pairs.clone(),
+            self.offset_and_limit,
+            self.pk_indices.clone(),
+            keyspace,
+            self.cache_size,
+            (0, 0, 0),
+            self.executor_id,
+            self.group_by.clone(),
+        )
+    }
+}
+
+impl<S: StateStore> Executor for InnerGroupTopNExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        panic!("Should execute by wrapper");
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[async_trait]
+impl<S: StateStore> TopNExecutorBase for InnerGroupTopNExecutor<S> {
+    async fn apply_chunk(
+        &mut self,
+        chunk: StreamChunk,
+        epoch: u64,
+    ) -> StreamExecutorResult<StreamChunk> {
+        // Split the chunk by groups, keeping the order of the rows within each group.
+        let mut group_indices = HashMap::new();
+        let mut group_rows: Vec<(Row, Vec<Op>, Vec<Row>)> = vec![];
+        for (op, row_ref) in chunk.rows() {
+            let group_key = row_ref.row_by_indices(&self.group_by);
+            let idx = *group_indices.entry(group_key.clone()).or_insert_with(|| {
+                group_rows.push((group_key, vec![], vec![]));
+                group_rows.len() - 1
+            });
+            group_rows[idx].1.push(op);
+            group_rows[idx].2.push(row_ref.to_owned_row());
+        }
+
+        let mut new_ops = vec![];
+        let mut new_rows = vec![];
+        for (group_key, ops, rows) in group_rows {
+            if !self.groups.contains(&group_key) {
+                let state = self
+                    .new_group_state(&group_key)
+                    .map_err(StreamExecutorError::top_n_state_error)?;
+                self.groups.put(group_key.clone(), state);
+            }
+            let state = self.groups.get_mut(&group_key).unwrap();
+            self.dirty_groups.insert(group_key);
+            let group_chunk = generate_output(rows, ops, &self.schema)?;
+            let output = state.apply_chunk(group_chunk, epoch).await?;
+            for (op, row_ref) in output.rows() {
+                new_ops.push(op);
+                new_rows.push(row_ref.to_owned_row());
+            }
+        }
+        generate_output(new_rows, new_ops, &self.schema)
+    }
+
+    async fn flush_data(&mut self, epoch: u64) -> StreamExecutorResult<()> {
+        for group_key in self.dirty_groups.drain() {
+            // Groups are only evicted below, after all the dirty ones are flushed.
+            let state = self.groups.peek_mut(&group_key).unwrap();
+            state.flush_data(epoch).await?;
+        }
+        self.groups.evict_to_target_cap();
+        Ok(())
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use futures::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::DataType;
+    use risingwave_common::util::sort_util::OrderType;
+
+    use super::*;
+    use crate::executor::test_utils::{create_in_memory_keyspace, MockSource};
+    use crate::executor::{Barrier, Message};
+
+    fn create_source() -> Box<MockSource> {
+        let chunk1 = StreamChunk::from_pretty(
+            "  I I I
+            +  1 1 0
+            +  2 2 1
+            +  3 1 2
+            + 10 2 3
+            +  9 1 4
+            +  8 2 5",
+        );
+        let chunk2 = StreamChunk::from_pretty(
+            "  I I I
+            -  1 1 0
+            +  7 2 6
+            -  2 2 1",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        Box::new(MockSource::with_messages(
+            schema,
+            PkIndices::new(),
+            vec![
+                Message::Barrier(Barrier::new_test_barrier(1)),
+                Message::Chunk(chunk1),
+                Message::Barrier(Barrier::new_test_barrier(2)),
+                Message::Chunk(chunk2),
+                Message::Barrier(Barrier::new_test_barrier(3)),
+            ],
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_group_top_n_executor() {
+        test_group_top_n_executor_with_cache_size(GROUP_TOP_N_CACHE_SIZE).await;
+    }
+
+    /// With only one group cached, the states of the evicted groups are reloaded from the storage
+    /// and the results are the same.
+    #[tokio::test]
+    async fn test_group_top_n_executor_with_eviction() {
+        test_group_top_n_executor_with_cache_size(1).await;
+    }
+
+    async fn test_group_top_n_executor_with_cache_size(group_cache_size: usize) {
+        let order_pairs = vec![
+            OrderPair::new(0, OrderType::Ascending),
+            OrderPair::new(2, OrderType::Ascending),
+        ];
+        let source = create_source();
+        let keyspace = create_in_memory_keyspace();
+        let mut top_n_executor = Box::new(
+            GroupTopNExecutor::new(
+                source as Box<dyn Executor>,
+                order_pairs,
+                (0, Some(2)),
+                vec![2],
+                keyspace,
+                Some(2),
+                1,
+                vec![1],
+            )
+            .unwrap(),
+        );
+        top_n_executor.inner.groups = EvictableHashMap::new(group_cache_size);
+        let mut top_n_executor = top_n_executor.execute();
+
+        // consume the init barrier
+        top_n_executor.next().await.unwrap().unwrap();
+        let res = top_n_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            *res.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I I
+                +  1 1 0
+                +  3 1 2
+                +  2 2 1
+                + 10 2 3
+                - 10 2 3
+                +  8 2 5"
+            )
+        );
+        // Group 1: (1, 3), group 2: (2, 8)
+        assert_matches!(
+            top_n_executor.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        );
+        let res = top_n_executor.next().await.unwrap().unwrap();
+        assert_eq!(
+            *res.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I I
+                -  1 1 0
+                +  9 1 4
+                -  8 2 5
+                +  7 2 6
+                -  2 2 1
+                +  8 2 5"
+            )
+        );
+        // Group 1: (3, 9), group 2: (7, 8)
+        assert_matches!(
+            top_n_executor.next().await.unwrap().unwrap(),
+            Message::Barrier(_)
+        );
+    }
+}
@@ -274,6 +274,7 @@ impl<S: StateStore> ManagedTopNBottomNState<S> {
         while let Some((pk, row)) = pk_and_row_iter.next().await? {
             self.bottom_n.insert(pk, row);
         }
+        self.total_count = self.bottom_n.len();
         // We don't retain `n` elements as we have a all-or-nothing policy for now.
         Ok(())
     }
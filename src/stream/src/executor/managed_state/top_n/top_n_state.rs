@@ -309,15 +309,19 @@ impl<S: StateStore, const TOP_N_TYPE: usize> ManagedTopNState<S, TOP_N_TYPE> {
             &mut self.ordered_row_deserializer,
             &mut self.cell_based_row_deserializer,
         );
+        // All the rows are scanned to recover the total count, but only the top n are cached.
+        let mut total_count = 0;
         while let Some((pk, row)) = pk_and_row_iter.next().await? {
+            total_count += 1;
+            if let Some(top_n_count) = self.top_n_count && top_n_count == self.top_n.len() {
+                continue;
+            }
             let prev_row = self.top_n.insert(pk, row.clone());
             if let Some(prev_row) = prev_row {
                 debug_assert_eq!(prev_row, row);
             }
-            if let Some(top_n_count) = self.top_n_count && top_n_count == self.top_n.len() {
-                break;
-            }
         }
+        self.total_count = total_count;
         Ok(())
     }
 
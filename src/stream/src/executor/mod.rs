@@ -47,9 +47,11 @@ mod batch_query;
 mod chain;
 mod debug;
 pub mod dispatch;
+mod dynamic_filter;
 mod error;
 mod filter;
 mod global_simple_agg;
+mod group_top_n;
 mod hash_agg;
 pub mod hash_join;
 mod hop_window;
@@ -60,7 +62,10 @@ mod managed_state;
 pub mod merge;
 pub mod monitor;
 mod mview;
+mod nested_loop_join;
+mod now;
 mod project;
+mod project_set;
 mod rearranged_chain;
 pub mod receiver;
 mod simple;
@@ -80,8 +85,10 @@ pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
 pub use debug::DebugExecutor;
 pub use dispatch::DispatchExecutor;
+pub use dynamic_filter::DynamicFilterExecutor;
 pub use filter::FilterExecutor;
 pub use global_simple_agg::SimpleAggExecutor;
+pub use group_top_n::GroupTopNExecutor;
 pub use hash_agg::HashAggExecutor;
 pub use hash_join::*;
 pub use hop_window::HopWindowExecutor;
@@ -90,7 +97,10 @@ pub use lookup::*;
 pub use lookup_union::LookupUnionExecutor;
 pub use merge::MergeExecutor;
 pub use mview::*;
+pub use nested_loop_join::{IntervalJoinBound, NestedLoopJoinExecutor};
+pub use now::NowExecutor;
 pub use project::ProjectExecutor;
+pub use project_set::ProjectSetExecutor;
 pub use rearranged_chain::RearrangedChainExecutor;
 use simple::{SimpleExecutor, SimpleExecutorWrapper};
 pub use source::*;
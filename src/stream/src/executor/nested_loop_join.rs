@@ -0,0 +1,988 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::ops::Bound::{self, *};
+
+use futures::{pin_mut, StreamExt, TryStreamExt};
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{Array, DataChunk, Op, Row, RowRef, StreamChunk};
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::hash::VirtualNode;
+use risingwave_common::types::{
+    CheckedAdd, DataType, Datum, IntervalUnit, NaiveDateTimeWrapper, ScalarImpl, ScalarRefImpl,
+    ToOwnedDatum,
+};
+use risingwave_common::util::hash_util::CRC32FastBuilder;
+use risingwave_common::util::sort_util::OrderType;
+use risingwave_expr::expr::RowExpression;
+use risingwave_storage::table::state_table::StateTable;
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::barrier_align::*;
+use super::error::StreamExecutorError;
+use super::{BoxedExecutor, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef};
+use crate::common::StreamChunkBuilder;
+use crate::executor::PROCESSING_WINDOW_SIZE;
+
+/// The bounds of `left_time - right_time` implied by the join condition of an interval join.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalJoinBound {
+    pub left_time_col: usize,
+    pub right_time_col: usize,
+    /// `left_time >= right_time + lower`
+    pub lower: Option<IntervalUnit>,
+    /// `left_time <= right_time + upper`
+    pub upper: Option<IntervalUnit>,
+    /// How far the time of a row may fall behind the largest time seen on its side before the
+    /// row is dropped as late.
+    pub allowed_lateness: IntervalUnit,
+}
+
+/// One side of the [`NestedLoopJoinExecutor`].
+///
+/// The rows are stored under the vnode computed from `dist_key_indices`, or under the first vnode
+/// owned by the actor if there are no distribution keys, e.g. for the broadcast side. Nothing is
+/// cached in memory, so the state is scanned for every chunk from the other side.
+///
+/// In an interval join, the rows are keyed by their time first, so only the rows within the bound
+/// of a chunk from the other side are scanned, and the rows out of the bound are removed by
+/// scanning the range of times below it.
+struct JoinSide<S: StateStore> {
+    /// The root of the rows of this side, which is followed by the vnode.
+    keyspace: Keyspace<S>,
+    column_descs: Vec<ColumnDesc>,
+    /// The pk of the state tables, which is the time column followed by the pk of the input in an
+    /// interval join, or the pk of the input otherwise.
+    state_pk_indices: Vec<usize>,
+    dist_key_indices: Vec<usize>,
+    /// The vnodes to store the rows of this side under.
+    vnodes: Vec<VirtualNode>,
+    /// The state tables of the vnodes that may have rows, found at the first access.
+    state_tables: Option<BTreeMap<VirtualNode, StateTable<S>>>,
+    /// The start position of the columns of this side in the output.
+    start_pos: usize,
+    /// The time column, if it's an interval join.
+    time_col: Option<usize>,
+    /// The offsets from the time of a row of this side to the earliest and the latest times of
+    /// the rows of the other side it can be joined with, if bounded.
+    match_time_offsets: (Option<IntervalUnit>, Option<IntervalUnit>),
+    /// The largest time seen on this side.
+    max_time: Option<NaiveDateTimeWrapper>,
+    /// The rows earlier than the watermark are late. It's advanced on each barrier to `max_time`
+    /// minus the allowed lateness.
+    watermark: Option<NaiveDateTimeWrapper>,
+    /// Keeps the watermark, as a single row with an empty pk, so that it's recovered as is.
+    watermark_table: StateTable<S>,
+    /// The number of late rows dropped since the last barrier.
+    late_rows: usize,
+}
+
+impl<S: StateStore> JoinSide<S> {
+    fn new(
+        keyspace: Keyspace<S>,
+        schema: &Schema,
+        pk_indices: Vec<usize>,
+        dist_key_indices: Vec<usize>,
+        vnodes: Vec<VirtualNode>,
+        start_pos: usize,
+        time_col: Option<usize>,
+        match_time_offsets: (Option<IntervalUnit>, Option<IntervalUnit>),
+    ) -> Self {
+        let state_pk_indices = time_col.into_iter().chain(pk_indices).collect();
+        let column_descs = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| ColumnDesc::unnamed(ColumnId::new(i as i32), field.data_type()))
+            .collect();
+        // The watermark of each actor is kept under the first vnode of it.
+        let watermark_table = StateTable::new(
+            keyspace.append_u8(b'w').append_u16(vnodes[0]),
+            vec![ColumnDesc::unnamed(ColumnId::new(0), DataType::Timestamp)],
+            vec![],
+            None,
+            vec![],
+        );
+        Self {
+            keyspace: keyspace.append_u8(b'r'),
+            column_descs,
+            state_pk_indices,
+            dist_key_indices,
+            vnodes,
+            state_tables: None,
+            start_pos,
+            time_col,
+            match_time_offsets,
+            max_time: None,
+            watermark: None,
+            watermark_table,
+            late_rows: 0,
+        }
+    }
+
+    fn new_state_table(&self, vnode: VirtualNode) -> StateTable<S> {
+        StateTable::new(
+            self.keyspace.append_u16(vnode),
+            self.column_descs.clone(),
+            vec![OrderType::Ascending; self.state_pk_indices.len()],
+            None,
+            self.state_pk_indices.clone(),
+        )
+    }
+
+    /// Finds the vnodes with rows, and recovers the largest time from the rows kept.
+    async fn state_tables(
+        &mut self,
+        epoch: u64,
+    ) -> Result<&mut BTreeMap<VirtualNode, StateTable<S>>> {
+        if self.state_tables.is_none() {
+            let mut state_tables = BTreeMap::new();
+            for &vnode in &self.vnodes {
+                if !self
+                    .keyspace
+                    .append_u16(vnode)
+                    .scan(Some(1), epoch)
+                    .await?
+                    .is_empty()
+                {
+                    state_tables.insert(vnode, self.new_state_table(vnode));
+                }
+            }
+            if let Some(time_col) = self.time_col {
+                for state_table in state_tables.values() {
+                    let iter = state_table.iter(epoch).await?;
+                    pin_mut!(iter);
+                    while let Some(row) = iter.next().await {
+                        self.max_time = self.max_time.max(time_of(&*row?, time_col));
+                    }
+                }
+            }
+            self.state_tables = Some(state_tables);
+        }
+        Ok(self.state_tables.as_mut().unwrap())
+    }
+
+    /// The vnodes to store the rows of `data_chunk` under.
+    fn vnodes_of(&self, data_chunk: &DataChunk) -> Result<Vec<VirtualNode>> {
+        if self.dist_key_indices.is_empty() {
+            return Ok(vec![self.vnodes[0]; data_chunk.capacity()]);
+        }
+        Ok(data_chunk
+            .get_hash_values(&self.dist_key_indices, CRC32FastBuilder)?
+            .into_iter()
+            .map(|hash| hash.to_vnode())
+            .collect())
+    }
+
+    /// Whether the row is earlier than the watermark of this side.
+    fn is_late(&self, row: &RowRef<'_>) -> bool {
+        match (self.time_col, self.watermark) {
+            (Some(time_col), Some(watermark)) => match row.value_at(time_col) {
+                Some(ScalarRefImpl::NaiveDateTime(time)) => time < watermark,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    async fn insert(&mut self, vnode: VirtualNode, row: Row, epoch: u64) -> Result<()> {
+        let time = self.time_col.and_then(|time_col| time_of(&row, time_col));
+        let pk = row_by_indices(&row, &self.state_pk_indices);
+        self.state_tables(epoch).await?;
+        if !self.state_tables.as_ref().unwrap().contains_key(&vnode) {
+            let state_table = self.new_state_table(vnode);
+            self.state_tables
+                .as_mut()
+                .unwrap()
+                .insert(vnode, state_table);
+        }
+        let state_table = self.state_tables.as_mut().unwrap().get_mut(&vnode).unwrap();
+        state_table.insert(&pk, row)?;
+        if time.is_some() {
+            self.max_time = self.max_time.max(time);
+        }
+        Ok(())
+    }
+
+    async fn delete(&mut self, vnode: VirtualNode, row: Row, epoch: u64) -> Result<()> {
+        let pk = row_by_indices(&row, &self.state_pk_indices);
+        // The row must have been inserted, so the state table of its vnode exists.
+        let state_table = self.state_tables(epoch).await?.get_mut(&vnode).unwrap();
+        state_table.delete(&pk, row)?;
+        Ok(())
+    }
+
+    /// Recovers the watermark kept in the state.
+    async fn recover_watermark(&mut self, epoch: u64) -> Result<()> {
+        self.watermark = self
+            .watermark_table
+            .get_row(&Row(vec![]), epoch)
+            .await?
+            .and_then(|row| time_of(&row, 0));
+        Ok(())
+    }
+
+    /// Advances the watermark with the largest time seen so far.
+    fn advance_watermark(&mut self, allowed_lateness: IntervalUnit) -> Result<()> {
+        if let Some(max_time) = self.max_time {
+            let watermark = max_time.checked_add(allowed_lateness.negative())?;
+            if Some(watermark) > self.watermark {
+                if let Some(old_watermark) = self.watermark {
+                    self.watermark_table
+                        .delete(&Row(vec![]), watermark_row(old_watermark))?;
+                }
+                self.watermark_table
+                    .insert(&Row(vec![]), watermark_row(watermark))?;
+                self.watermark = Some(watermark);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes the rows whose time is earlier than `bound`, as well as the rows without a time,
+    /// which can't be joined either.
+    async fn remove_earlier_than(&mut self, bound: NaiveDateTimeWrapper, epoch: u64) -> Result<()> {
+        let state_pk_indices = self.state_pk_indices.clone();
+        let time_bounds = (Unbounded, Excluded(Some(ScalarImpl::NaiveDateTime(bound))));
+        for state_table in self.state_tables(epoch).await?.values_mut() {
+            let expired: Vec<Row> = state_table
+                .iter_with_pk_bounds(epoch, &Row(vec![]), time_bounds.clone())
+                .await?
+                .map_ok(Cow::into_owned)
+                .try_collect()
+                .await?;
+            for row in expired {
+                state_table.delete(&row_by_indices(&row, &state_pk_indices), row)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The bounds of the times of the rows of the other side that can be joined with `rows` of
+    /// this side, or `None` if none of them can be joined. The times are unbounded if it's not an
+    /// interval join.
+    fn match_time_bounds(
+        &self,
+        rows: &[(RowRef<'_>, Op, VirtualNode)],
+    ) -> Result<Option<(Bound<Datum>, Bound<Datum>)>> {
+        let Some(time_col) = self.time_col else {
+            return Ok(Some((Unbounded, Unbounded)));
+        };
+        // The rows without a time can't be joined.
+        let Some((min_time, max_time)) = rows
+            .iter()
+            .filter_map(|(row, _, _)| match row.value_at(time_col) {
+                Some(ScalarRefImpl::NaiveDateTime(time)) => Some(time),
+                _ => None,
+            })
+            .minmax()
+            .into_option() else {
+            return Ok(None);
+        };
+        let (lower_offset, upper_offset) = self.match_time_offsets;
+        let lower = lower_offset
+            .map(|offset| min_time.checked_add(offset))
+            .transpose()?;
+        let upper = upper_offset
+            .map(|offset| max_time.checked_add(offset))
+            .transpose()?;
+        if let (Some(lower), Some(upper)) = (lower, upper) && lower > upper {
+            return Ok(None);
+        }
+        let bound = |time: Option<NaiveDateTimeWrapper>| match time {
+            Some(time) => Included(Some(ScalarImpl::NaiveDateTime(time))),
+            None => Unbounded,
+        };
+        Ok(Some((bound(lower), bound(upper))))
+    }
+
+    async fn commit(&mut self, epoch: u64) -> Result<()> {
+        if let Some(state_tables) = &mut self.state_tables {
+            for state_table in state_tables.values_mut() {
+                state_table.commit(epoch).await?;
+            }
+        }
+        self.watermark_table.commit(epoch).await?;
+        Ok(())
+    }
+}
+
+fn watermark_row(watermark: NaiveDateTimeWrapper) -> Row {
+    Row(vec![Some(ScalarImpl::NaiveDateTime(watermark))])
+}
+
+fn row_by_indices(row: &Row, indices: &[usize]) -> Row {
+    Row(indices.iter().map(|&idx| row[idx].clone()).collect())
+}
+
+fn time_of(row: &Row, time_col: usize) -> Option<NaiveDateTimeWrapper> {
+    match &row[time_col] {
+        Some(ScalarImpl::NaiveDateTime(time)) => Some(*time),
+        _ => None,
+    }
+}
+
+/// `NestedLoopJoinExecutor` takes two input streams and joins them by checking the condition
+/// against every pair of rows. The output columns are the concatenation of left and right columns.
+///
+/// Only inner join is supported. The left side is sharded by its distribution keys, and the right
+/// side is expected to be broadcast to every actor, so each actor keeps its share of the left rows
+/// and a copy of all the right rows in the state tables.
+///
+/// With an [`IntervalJoinBound`], both inputs must be append-only. The watermark of each side is
+/// the largest time seen on it minus the allowed lateness, and the rows earlier than it are
+/// dropped as late, with a warning at the next barrier. A row is removed from the state once the
+/// watermark of the other side goes beyond the bound, since it can no longer be joined. The
+/// watermarks are kept in the state, so that the same rows are late after recovery.
+pub struct NestedLoopJoinExecutor<S: StateStore> {
+    input_l: Option<BoxedExecutor>,
+    input_r: Option<BoxedExecutor>,
+    schema: Schema,
+    output_data_types: Vec<DataType>,
+    pk_indices: PkIndices,
+    side_l: JoinSide<S>,
+    side_r: JoinSide<S>,
+    cond: Option<RowExpression>,
+    interval_bound: Option<IntervalJoinBound>,
+    identity: String,
+    epoch: u64,
+}
+
+impl<S: StateStore> std::fmt::Debug for NestedLoopJoinExecutor<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NestedLoopJoinExecutor")
+            .field("input_left", &self.input_l.as_ref().unwrap().identity())
+            .field("input_right", &self.input_r.as_ref().unwrap().identity())
+            .field("pk_indices", &self.pk_indices)
+            .field("schema", &self.schema)
+            .field("interval_bound", &self.interval_bound)
+            .finish()
+    }
+}
+
+impl<S: StateStore> Executor for NestedLoopJoinExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.into_stream().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        self.identity.as_str()
+    }
+}
+
+impl<S: StateStore> NestedLoopJoinExecutor<S> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_l: BoxedExecutor,
+        input_r: BoxedExecutor,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        cond: Option<RowExpression>,
+        interval_bound: Option<IntervalJoinBound>,
+        ks_l: Keyspace<S>,
+        ks_r: Keyspace<S>,
+        left_dist_key_indices: Vec<usize>,
+        vnodes: Vec<VirtualNode>,
+    ) -> Self {
+        let schema = Schema {
+            fields: [
+                input_l.schema().fields.clone(),
+                input_r.schema().fields.clone(),
+            ]
+            .concat(),
+        };
+        let output_data_types = schema.data_types();
+        let side_l = JoinSide::new(
+            ks_l,
+            input_l.schema(),
+            input_l.pk_indices().to_vec(),
+            left_dist_key_indices,
+            vnodes.clone(),
+            0,
+            interval_bound.as_ref().map(|bound| bound.left_time_col),
+            // `left_time - upper <= right_time <= left_time - lower`
+            interval_bound.as_ref().map_or((None, None), |bound| {
+                (
+                    bound.upper.map(|upper| upper.negative()),
+                    bound.lower.map(|lower| lower.negative()),
+                )
+            }),
+        );
+        // The copies of the broadcast right side in different actors are told apart by the first
+        // vnode of each actor.
+        let side_r = JoinSide::new(
+            ks_r,
+            input_r.schema(),
+            input_r.pk_indices().to_vec(),
+            vec![],
+            vnodes[..1].to_vec(),
+            input_l.schema().len(),
+            interval_bound.as_ref().map(|bound| bound.right_time_col),
+            // `right_time + lower <= left_time <= right_time + upper`
+            interval_bound
+                .as_ref()
+                .map_or((None, None), |bound| (bound.lower, bound.upper)),
+        );
+
+        Self {
+            input_l: Some(input_l),
+            input_r: Some(input_r),
+            schema,
+            output_data_types,
+            pk_indices,
+            side_l,
+            side_r,
+            cond,
+            interval_bound,
+            identity: format!("NestedLoopJoinExecutor {:X}", executor_id),
+            epoch: 0,
+        }
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn into_stream(mut self) {
+        let input_l = self.input_l.take().unwrap();
+        let input_r = self.input_r.take().unwrap();
+        let aligned_stream = barrier_align(input_l.execute(), input_r.execute());
+        let mut is_first_barrier = true;
+        #[for_await]
+        for msg in aligned_stream {
+            match msg? {
+                AlignedMessage::Left(chunk) => {
+                    #[for_await]
+                    for chunk in Self::join_oneside(
+                        self.epoch,
+                        &mut self.side_l,
+                        &mut self.side_r,
+                        &self.output_data_types,
+                        &mut self.cond,
+                        chunk,
+                    ) {
+                        yield chunk.map_err(StreamExecutorError::nested_loop_join_error)?;
+                    }
+                }
+                AlignedMessage::Right(chunk) => {
+                    #[for_await]
+                    for chunk in Self::join_oneside(
+                        self.epoch,
+                        &mut self.side_r,
+                        &mut self.side_l,
+                        &self.output_data_types,
+                        &mut self.cond,
+                        chunk,
+                    ) {
+                        yield chunk.map_err(StreamExecutorError::nested_loop_join_error)?;
+                    }
+                }
+                AlignedMessage::Barrier(barrier) => {
+                    if is_first_barrier {
+                        self.side_l
+                            .recover_watermark(barrier.epoch.prev)
+                            .await
+                            .map_err(StreamExecutorError::nested_loop_join_error)?;
+                        self.side_r
+                            .recover_watermark(barrier.epoch.prev)
+                            .await
+                            .map_err(StreamExecutorError::nested_loop_join_error)?;
+                        is_first_barrier = false;
+                    }
+                    self.flush_data(barrier.epoch.prev)
+                        .await
+                        .map_err(StreamExecutorError::nested_loop_join_error)?;
+                    self.epoch = barrier.epoch.curr;
+                    yield Message::Barrier(barrier);
+                }
+            }
+        }
+    }
+
+    /// Advances the watermarks, removes the rows out of the interval bound, and commits the changes
+    /// of both sides.
+    async fn flush_data(&mut self, epoch: u64) -> Result<()> {
+        if let Some(bound) = &self.interval_bound {
+            for (side, name) in [(&mut self.side_l, "left"), (&mut self.side_r, "right")] {
+                if side.late_rows > 0 {
+                    tracing::warn!(
+                        "{} dropped {} late rows of the {} side, which are earlier than the \
+                         watermark {:?}",
+                        self.identity,
+                        side.late_rows,
+                        name,
+                        side.watermark
+                    );
+                    side.late_rows = 0;
+                }
+                side.advance_watermark(bound.allowed_lateness)?;
+            }
+            // A left row can be joined with the right rows whose time is no later than
+            // `left_time - lower`.
+            if let (Some(lower), Some(watermark)) = (bound.lower, self.side_r.watermark) {
+                let left_bound = watermark.checked_add(lower)?;
+                self.side_l.remove_earlier_than(left_bound, epoch).await?;
+            }
+            // A right row can be joined with the left rows whose time is no later than
+            // `right_time + upper`.
+            if let (Some(upper), Some(watermark)) = (bound.upper, self.side_l.watermark) {
+                let right_bound = watermark.checked_add(upper.negative())?;
+                self.side_r.remove_earlier_than(right_bound, epoch).await?;
+            }
+        }
+        self.side_l.commit(epoch).await?;
+        self.side_r.commit(epoch).await?;
+        Ok(())
+    }
+
+    fn row_concat(
+        row_update: &RowRef<'_>,
+        update_start_pos: usize,
+        row_matched: &Row,
+        matched_start_pos: usize,
+    ) -> Row {
+        let mut new_row = vec![None; row_update.size() + row_matched.size()];
+
+        for (i, datum_ref) in row_update.values().enumerate() {
+            new_row[i + update_start_pos] = datum_ref.to_owned_datum();
+        }
+        for i in 0..row_matched.size() {
+            new_row[i + matched_start_pos] = row_matched[i].clone();
+        }
+        Row(new_row)
+    }
+
+    #[try_stream(ok = Message, error = RwError)]
+    async fn join_oneside<'a>(
+        epoch: u64,
+        side_update: &'a mut JoinSide<S>,
+        side_match: &'a mut JoinSide<S>,
+        output_data_types: &'a [DataType],
+        cond: &'a mut Option<RowExpression>,
+        chunk: StreamChunk,
+    ) {
+        let chunk = chunk.compact()?;
+        let (data_chunk, ops) = chunk.into_parts();
+        let update_start_pos = side_update.start_pos;
+        let matched_start_pos = side_match.start_pos;
+
+        let mut builder = StreamChunkBuilder::new(
+            PROCESSING_WINDOW_SIZE,
+            output_data_types,
+            update_start_pos,
+            matched_start_pos,
+        )?;
+
+        let vnodes = side_update.vnodes_of(&data_chunk)?;
+        let rows = data_chunk
+            .rows()
+            .zip_eq(ops.iter())
+            .zip_eq(vnodes)
+            .filter(|((row, _), _)| !side_update.is_late(row))
+            .map(|((row, op), vnode)| {
+                // Updates are split into deletes and inserts, as the matched rows may differ.
+                let op = match *op {
+                    Op::Insert | Op::UpdateInsert => Op::Insert,
+                    Op::Delete | Op::UpdateDelete => Op::Delete,
+                };
+                (row, op, vnode)
+            })
+            .collect_vec();
+        side_update.late_rows += data_chunk.cardinality() - rows.len();
+
+        // The rows of the other side are scanned once for the whole chunk, within the bound of the
+        // times of the chunk in an interval join.
+        if let Some(time_bounds) = side_update.match_time_bounds(&rows)? {
+            for state_table in side_match.state_tables(epoch).await?.values() {
+                let iter = state_table
+                    .iter_with_pk_bounds(epoch, &Row(vec![]), time_bounds.clone())
+                    .await?;
+                pin_mut!(iter);
+                while let Some(matched_row) = iter.next().await {
+                    let matched_row = matched_row?;
+                    for (row, op, _) in &rows {
+                        let matched = match cond {
+                            Some(cond) => {
+                                let new_row = Self::row_concat(
+                                    row,
+                                    update_start_pos,
+                                    &matched_row,
+                                    matched_start_pos,
+                                );
+                                cond.eval(&new_row, output_data_types)?
+                                    .as_bool()
+                                    .value_at(0)
+                                    .unwrap_or(false)
+                            }
+                            None => true,
+                        };
+                        if matched {
+                            if let Some(chunk) = builder.append_row(*op, row, &matched_row)? {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        for (row, op, vnode) in rows {
+            match op {
+                Op::Insert => side_update.insert(vnode, row.to_owned_row(), epoch).await?,
+                _ => side_update.delete(vnode, row.to_owned_row(), epoch).await?,
+            }
+        }
+        if let Some(chunk) = builder.take()? {
+            yield Message::Chunk(chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::{Field, TableId};
+    use risingwave_common::hash::VIRTUAL_NODE_COUNT;
+    use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
+    use risingwave_expr::expr::InputRefExpression;
+    use risingwave_pb::expr::expr_node::Type;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor::test_utils::{MessageSender, MockSource};
+
+    fn create_executor(
+        mem_state: MemoryStateStore,
+        data_types: Vec<DataType>,
+        cond: Option<RowExpression>,
+        interval_bound: Option<IntervalJoinBound>,
+    ) -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let schema = Schema {
+            fields: data_types.into_iter().map(Field::unnamed).collect(),
+        };
+        let (tx_l, source_l) = MockSource::channel(schema.clone(), vec![0]);
+        let (tx_r, source_r) = MockSource::channel(schema, vec![0]);
+        let executor = NestedLoopJoinExecutor::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            vec![0, 2],
+            1,
+            cond,
+            interval_bound,
+            Keyspace::table_root(mem_state.clone(), &TableId::new(0)),
+            Keyspace::table_root(mem_state, &TableId::new(1)),
+            vec![0],
+            (0..VIRTUAL_NODE_COUNT as VirtualNode).collect(),
+        );
+        (tx_l, tx_r, Box::new(executor).execute())
+    }
+
+    /// The order of the output rows depends on the vnodes of the left rows, so they are sorted
+    /// before comparing.
+    fn sorted_rows(chunk: StreamChunk) -> Vec<(Op, Row)> {
+        chunk
+            .rows()
+            .map(|(op, row)| (op, row.to_owned_row()))
+            .sorted()
+            .collect()
+    }
+
+    async fn next_rows(join: &mut BoxedMessageStream) -> Vec<(Op, Row)> {
+        sorted_rows(join.next().await.unwrap().unwrap().into_chunk().unwrap())
+    }
+
+    /// $1 < $3
+    fn less_than_cond() -> RowExpression {
+        RowExpression::new(new_binary_expr(
+            Type::LessThan,
+            DataType::Boolean,
+            Box::new(InputRefExpression::new(DataType::Int64, 1)),
+            Box::new(InputRefExpression::new(DataType::Int64, 3)),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_streaming_nested_loop_inner_join() {
+        let (mut tx_l, mut tx_r, mut join) = create_executor(
+            MemoryStateStore::new(),
+            vec![DataType::Int64, DataType::Int64],
+            Some(less_than_cond()),
+            None,
+        );
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        join.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 4
+             + 2 5",
+        ));
+        assert_eq!(next_rows(&mut join).await, vec![]);
+
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 6
+             + 2 5",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I I I
+                + 1 4 1 6
+                + 2 5 1 6
+                + 1 4 2 5"
+            ))
+        );
+
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        join.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             - 1 4
+             + 3 1",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I I I
+                - 1 4 1 6
+                - 1 4 2 5
+                + 3 1 1 6
+                + 3 1 2 5"
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streaming_nested_loop_join_recovery() {
+        let mem_state = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut join) = create_executor(
+            mem_state.clone(),
+            vec![DataType::Int64, DataType::Int64],
+            Some(less_than_cond()),
+            None,
+        );
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        join.next().await.unwrap().unwrap();
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 4
+             + 2 5
+             + 3 6",
+        ));
+        join.next().await.unwrap().unwrap();
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 5",
+        ));
+        join.next().await.unwrap().unwrap();
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        join.next().await.unwrap().unwrap();
+
+        // A new executor on the same state store sees the rows committed by the old one.
+        let (mut tx_l, mut tx_r, mut join) = create_executor(
+            mem_state,
+            vec![DataType::Int64, DataType::Int64],
+            Some(less_than_cond()),
+            None,
+        );
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        join.next().await.unwrap().unwrap();
+
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 2 6",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I I I
+                + 1 4 2 6
+                + 2 5 2 6"
+            ))
+        );
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 4 0",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I I I I
+                + 4 0 1 5
+                + 4 0 2 6"
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streaming_interval_join() {
+        // $1 BETWEEN $3 - '5 minutes' AND $3, which is not checked by the executor itself. Only the
+        // rows within the bound of the times of a chunk are scanned, and they are all joined with
+        // every row of the chunk.
+        let interval_bound = IntervalJoinBound {
+            left_time_col: 1,
+            right_time_col: 1,
+            lower: Some(IntervalUnit::from_minutes(-5)),
+            upper: Some(IntervalUnit::default()),
+            allowed_lateness: IntervalUnit::from_minutes(1),
+        };
+        let mem_state = MemoryStateStore::new();
+        let (mut tx_l, mut tx_r, mut join) = create_executor(
+            mem_state.clone(),
+            vec![DataType::Int64, DataType::Timestamp],
+            None,
+            Some(interval_bound.clone()),
+        );
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        join.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-01-01T00:00:00",
+        ));
+        join.next().await.unwrap().unwrap();
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-01-01T00:03:00
+             + 2 2022-01-01T00:10:00",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 1 2022-01-01T00:00:00 1 2022-01-01T00:03:00
+                + 1 2022-01-01T00:00:00 2 2022-01-01T00:10:00"
+            ))
+        );
+
+        // The watermark of the right side is 00:09, so the left row at 00:00 can't be joined any
+        // more.
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        join.next().await.unwrap().unwrap();
+
+        // The right row at 00:03 is out of the bound of the left row at 00:06.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 2 2022-01-01T00:06:00",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 2 2022-01-01T00:06:00 2 2022-01-01T00:10:00"
+            ))
+        );
+
+        // The watermark of the left side is 00:05, so the right row at 00:03 can't be joined any
+        // more.
+        tx_l.push_barrier(3, false);
+        tx_r.push_barrier(3, false);
+        join.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 3 2022-01-01T00:07:00",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 3 2022-01-01T00:07:00 2 2022-01-01T00:10:00"
+            ))
+        );
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 3 2022-01-01T00:11:00",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 2 2022-01-01T00:06:00 3 2022-01-01T00:11:00
+                + 3 2022-01-01T00:07:00 3 2022-01-01T00:11:00"
+            ))
+        );
+
+        // The left row at 00:04 is earlier than the watermark of the left side, so it's dropped,
+        // and the right row at 00:11 is out of the bound of the left row at 00:05:30.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 4 2022-01-01T00:04:00
+             + 5 2022-01-01T00:05:30",
+        ));
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 5 2022-01-01T00:05:30 2 2022-01-01T00:10:00"
+            ))
+        );
+
+        // The watermark of the left side is advanced to 00:06, and kept in the state.
+        tx_l.push_barrier(4, false);
+        tx_r.push_barrier(4, false);
+        join.next().await.unwrap().unwrap();
+
+        let (mut tx_l, mut tx_r, mut join) = create_executor(
+            mem_state,
+            vec![DataType::Int64, DataType::Timestamp],
+            None,
+            Some(interval_bound),
+        );
+        tx_l.push_barrier(4, false);
+        tx_r.push_barrier(4, false);
+        join.next().await.unwrap().unwrap();
+
+        // The left row at 00:05:45 is still late after recovery, so only the row at 00:08 is
+        // joined.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 6 2022-01-01T00:05:45",
+        ));
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 7 2022-01-01T00:08:00",
+        ));
+        assert_eq!(next_rows(&mut join).await, vec![]);
+        assert_eq!(
+            next_rows(&mut join).await,
+            sorted_rows(StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 7 2022-01-01T00:08:00 2 2022-01-01T00:10:00
+                + 7 2022-01-01T00:08:00 3 2022-01-01T00:11:00"
+            ))
+        );
+    }
+}
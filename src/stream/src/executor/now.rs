@@ -0,0 +1,213 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::UNIX_EPOCH;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{Op, Row, StreamChunk};
+use risingwave_common::catalog::{ColumnDesc, ColumnId, Field, Schema};
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::epoch::Epoch;
+use risingwave_storage::table::state_table::StateTable;
+use risingwave_storage::{Keyspace, StateStore};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::error::StreamExecutorError;
+use super::{Barrier, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef};
+
+/// [`NowExecutor`] produces the value of `now()` in a streaming query, as a single row of
+/// `timestamp with time zone` that's updated on every barrier.
+///
+/// After each barrier, the row is set to the time of the epoch the barrier starts, by an insert
+/// after the first barrier and by a pair of `U-` and `U+` after the others. So the downstream
+/// retracts the results computed with the old time when it changes. The last value is kept in the
+/// state table, so that it can be retracted after recovery.
+pub struct NowExecutor<S: StateStore> {
+    barrier_receiver: UnboundedReceiver<Barrier>,
+    state_table: StateTable<S>,
+    schema: Schema,
+    pk_indices: PkIndices,
+    identity: String,
+}
+
+impl<S: StateStore> std::fmt::Debug for NowExecutor<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NowExecutor")
+            .field("schema", &self.schema)
+            .finish()
+    }
+}
+
+impl<S: StateStore> NowExecutor<S> {
+    pub fn new(
+        barrier_receiver: UnboundedReceiver<Barrier>,
+        keyspace: Keyspace<S>,
+        executor_id: u64,
+    ) -> Self {
+        // The single row is stored with an empty pk.
+        let state_table = StateTable::new(
+            keyspace,
+            vec![ColumnDesc::unnamed(ColumnId::new(0), DataType::Timestampz)],
+            vec![],
+            None,
+            vec![],
+        );
+        Self {
+            barrier_receiver,
+            state_table,
+            schema: Schema::new(vec![Field::with_name(DataType::Timestampz, "now")]),
+            pk_indices: vec![],
+            identity: format!("NowExecutor {:X}", executor_id),
+        }
+    }
+
+    /// The time of `epoch`, in microseconds since the Unix epoch.
+    fn epoch_time(epoch: u64) -> ScalarImpl {
+        let micros = Epoch::from(epoch)
+            .as_system_time()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_micros();
+        ScalarImpl::Int64(micros as i64)
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn into_stream(mut self) {
+        let mut last_row: Option<Row> = None;
+        let mut is_first_barrier = true;
+
+        while let Some(barrier) = self.barrier_receiver.recv().await {
+            if is_first_barrier {
+                // Recover the last value, which is committed at the epoch before the first
+                // barrier.
+                last_row = self
+                    .state_table
+                    .get_row(&Row(vec![]), barrier.epoch.prev)
+                    .await
+                    .map_err(StreamExecutorError::storage)?;
+                is_first_barrier = false;
+            } else {
+                self.state_table
+                    .commit(barrier.epoch.prev)
+                    .await
+                    .map_err(StreamExecutorError::storage)?;
+            }
+
+            let row = Row(vec![Some(Self::epoch_time(barrier.epoch.curr))]);
+            let (ops, rows) = match last_row.take() {
+                Some(last_row) => {
+                    self.state_table
+                        .delete(&Row(vec![]), last_row.clone())
+                        .map_err(StreamExecutorError::storage)?;
+                    (
+                        vec![Op::UpdateDelete, Op::UpdateInsert],
+                        vec![last_row, row.clone()],
+                    )
+                }
+                None => (vec![Op::Insert], vec![row.clone()]),
+            };
+            self.state_table
+                .insert(&Row(vec![]), row.clone())
+                .map_err(StreamExecutorError::storage)?;
+            last_row = Some(row);
+
+            yield Message::Barrier(barrier);
+
+            let rows = ops.into_iter().zip_eq(rows).collect_vec();
+            let chunk = StreamChunk::from_rows(&rows, &[DataType::Timestampz])
+                .map_err(StreamExecutorError::eval_error)?;
+            yield Message::Chunk(chunk);
+        }
+    }
+}
+
+impl<S: StateStore> Executor for NowExecutor<S> {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.into_stream().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        self.identity.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_storage::memory::MemoryStateStore;
+    use tokio::sync::mpsc::unbounded_channel;
+
+    use super::*;
+
+    type Now = NowExecutor<MemoryStateStore>;
+
+    fn chunk(rows: &[(Op, u64)]) -> StreamChunk {
+        let rows = rows
+            .iter()
+            .map(|(op, epoch)| (*op, Row(vec![Some(Now::epoch_time(*epoch))])))
+            .collect_vec();
+        StreamChunk::from_rows(&rows, &[DataType::Timestampz]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_now_executor() {
+        let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x42);
+        let epoch1 = Epoch::now().0;
+        let epoch2 = epoch1 + (1 << 16);
+        let epoch3 = epoch2 + (1 << 16);
+
+        let (tx, rx) = unbounded_channel();
+        let mut now = Box::new(Now::new(rx, keyspace.clone(), 1)).execute();
+        tx.send(Barrier::new_test_barrier(epoch1)).unwrap();
+        tx.send(Barrier::new_test_barrier(epoch2)).unwrap();
+        tx.send(Barrier::new_test_barrier(epoch3)).unwrap();
+
+        // The row is inserted after the first barrier, and updated after the others.
+        assert!(now.next().await.unwrap().unwrap().is_barrier());
+        let msg = now.next().await.unwrap().unwrap();
+        assert_eq!(msg.into_chunk().unwrap(), chunk(&[(Op::Insert, epoch1)]));
+
+        assert!(now.next().await.unwrap().unwrap().is_barrier());
+        let msg = now.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            chunk(&[(Op::UpdateDelete, epoch1), (Op::UpdateInsert, epoch2)])
+        );
+
+        // The value emitted after the second barrier is committed by the third one.
+        assert!(now.next().await.unwrap().unwrap().is_barrier());
+        drop(now);
+
+        // A recovered executor retracts the last committed value.
+        let (tx, rx) = unbounded_channel();
+        let mut now = Box::new(Now::new(rx, keyspace, 1)).execute();
+        let epoch4 = epoch3 + (1 << 16);
+        tx.send(Barrier::new_test_barrier(epoch4)).unwrap();
+        assert!(now.next().await.unwrap().unwrap().is_barrier());
+        let msg = now.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            chunk(&[(Op::UpdateDelete, epoch2), (Op::UpdateInsert, epoch4)])
+        );
+    }
+}
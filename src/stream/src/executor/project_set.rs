@@ -0,0 +1,186 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::{project_set, ProjectSetSelectItem};
+
+use super::error::StreamExecutorError;
+use super::{BoxedExecutor, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef};
+
+/// `ProjectSetExecutor` expands each row of the input stream into a set of rows with set-returning
+/// functions like `unnest`, where each output row takes the operation of its input row. See
+/// [`project_set`] for the details.
+pub struct ProjectSetExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+    select_list: Vec<ProjectSetSelectItem>,
+}
+
+impl ProjectSetExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: PkIndices,
+        select_list: Vec<ProjectSetSelectItem>,
+        executor_id: u64,
+    ) -> Self {
+        let fields = std::iter::once(Field::unnamed(DataType::Int64))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+        let info = ExecutorInfo {
+            schema: Schema { fields },
+            pk_indices,
+            identity: format!("ProjectSetExecutor {:X}", executor_id),
+        };
+        Self {
+            input,
+            info,
+            select_list,
+        }
+    }
+}
+
+impl Executor for ProjectSetExecutor {
+    fn execute(self: Box<Self>) -> super::BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+impl ProjectSetExecutor {
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(self: Box<Self>) {
+        let data_types = self.info.schema.data_types();
+        #[for_await]
+        for msg in self.input.execute() {
+            match msg? {
+                Message::Chunk(chunk) => {
+                    let (data_chunk, ops) = chunk.into_parts();
+                    let rows = project_set(&self.select_list, &data_chunk)
+                        .map_err(StreamExecutorError::eval_error)?;
+                    if rows.is_empty() {
+                        continue;
+                    }
+                    let rows = rows
+                        .into_iter()
+                        .map(|(row_idx, row)| {
+                            // The rows expanded from an update may not pair up, so updates are
+                            // emitted as deletes and inserts.
+                            let op = match ops[row_idx] {
+                                Op::UpdateDelete => Op::Delete,
+                                Op::UpdateInsert => Op::Insert,
+                                op => op,
+                            };
+                            (op, row)
+                        })
+                        .collect_vec();
+                    let chunk = StreamChunk::from_rows(&rows, &data_types)
+                        .map_err(StreamExecutorError::executor_v1)?;
+                    yield Message::Chunk(chunk);
+                }
+                msg => yield msg,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::array::ListValue;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_expr::expr::{Expression, InputRefExpression, LiteralExpression};
+    use risingwave_expr::table_function::{TableFunction, Unnest};
+
+    use super::*;
+    use crate::executor::test_utils::MockSource;
+
+    #[tokio::test]
+    async fn test_project_set() {
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            + 1 4
+            U- 2 5
+            U+ 2 6
+            - 3 7",
+        );
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::unnamed(DataType::Int64),
+        ]);
+        let source = MockSource::with_chunks(schema, vec![0], vec![chunk]);
+
+        let list_type = DataType::List {
+            datatype: Box::new(DataType::Int64),
+        };
+        let array = ListValue::new(vec![
+            Some(ScalarImpl::Int64(10)),
+            Some(ScalarImpl::Int64(20)),
+        ]);
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(InputRefExpression::new(DataType::Int64, 1).boxed()),
+            ProjectSetSelectItem::TableFunction(
+                Unnest::new(
+                    LiteralExpression::new(list_type, Some(ScalarImpl::List(array))).boxed(),
+                    DataType::Int64,
+                )
+                .boxed(),
+            ),
+        ];
+        let project_set = Box::new(ProjectSetExecutor::new(
+            Box::new(source),
+            vec![0],
+            select_list,
+            1,
+        ));
+
+        let mut stream = project_set.execute();
+        let msg = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            *msg.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 0 4 10
+                + 1 4 20
+                - 0 5 10
+                - 1 5 20
+                + 0 6 10
+                + 1 6 20
+                - 0 7 10
+                - 1 7 20"
+            )
+        );
+        assert!(stream.next().await.unwrap().unwrap().is_stop());
+    }
+}
@@ -16,8 +16,10 @@
 
 mod batch_query;
 mod chain;
+mod dynamic_filter;
 mod filter;
 mod global_simple_agg;
+mod group_top_n;
 mod hash_agg;
 mod hash_join;
 mod hop_window;
@@ -26,7 +28,10 @@ mod lookup;
 mod lookup_union;
 mod merge;
 mod mview;
+mod nested_loop_join;
+mod now;
 mod project;
+mod project_set;
 mod source;
 mod top_n;
 mod top_n_appendonly;
@@ -42,8 +47,10 @@ use risingwave_storage::{Keyspace, StateStore};
 
 use self::batch_query::*;
 use self::chain::*;
+use self::dynamic_filter::*;
 use self::filter::*;
 use self::global_simple_agg::*;
+use self::group_top_n::*;
 use self::hash_agg::*;
 use self::hash_join::*;
 use self::hop_window::*;
@@ -52,7 +59,10 @@ use self::lookup::*;
 use self::lookup_union::*;
 use self::merge::*;
 use self::mview::*;
+use self::nested_loop_join::*;
+use self::now::*;
 use self::project::*;
+use self::project_set::*;
 use self::source::*;
 use self::top_n::*;
 use self::top_n_appendonly::*;
@@ -102,12 +112,15 @@ pub fn create_executor(
         stream,
         NodeBody::Source => SourceExecutorBuilder,
         NodeBody::Project => ProjectExecutorBuilder,
+        NodeBody::ProjectSet => ProjectSetExecutorBuilder,
         NodeBody::TopN => TopNExecutorBuilder,
         NodeBody::AppendOnlyTopN => AppendOnlyTopNExecutorBuilder,
+        NodeBody::GroupTopN => GroupTopNExecutorBuilder,
         NodeBody::LocalSimpleAgg => LocalSimpleAggExecutorBuilder,
         NodeBody::GlobalSimpleAgg => SimpleAggExecutorBuilder,
         NodeBody::HashAgg => HashAggExecutorBuilder,
         NodeBody::HashJoin => HashJoinExecutorBuilder,
+        NodeBody::NestedLoopJoin => NestedLoopJoinExecutorBuilder,
         NodeBody::HopWindow => HopWindowExecutorBuilder,
         NodeBody::Chain => ChainExecutorBuilder,
         NodeBody::BatchPlan => BatchQueryExecutorBuilder,
@@ -118,5 +131,7 @@ pub fn create_executor(
         NodeBody::Lookup => LookupExecutorBuilder,
         NodeBody::Union => UnionExecutorBuilder,
         NodeBody::LookupUnion => LookupUnionExecutorBuilder,
+        NodeBody::Now => NowExecutorBuilder,
+        NodeBody::DynamicFilter => DynamicFilterExecutorBuilder,
     }
 }
@@ -0,0 +1,90 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::catalog::TableId;
+use risingwave_common::hash::{VirtualNode, VIRTUAL_NODE_COUNT};
+use risingwave_common::types::IntervalUnit;
+use risingwave_expr::expr::{build_from_prost, RowExpression};
+use risingwave_pb::plan_common::JoinType as JoinTypeProto;
+
+use super::*;
+use crate::executor::{IntervalJoinBound, NestedLoopJoinExecutor};
+
+pub struct NestedLoopJoinExecutorBuilder;
+
+impl ExecutorBuilder for NestedLoopJoinExecutorBuilder {
+    fn new_boxed_executor(
+        mut params: ExecutorParams,
+        node: &StreamNode,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<BoxedExecutor> {
+        let node = try_match_expand!(node.get_node_body().unwrap(), NodeBody::NestedLoopJoin)?;
+        let join_type = node.get_join_type()?;
+        if join_type != JoinTypeProto::Inner {
+            return Err(RwError::from(ErrorCode::NotImplemented(
+                format!("stream nested-loop join with {:?}", join_type),
+                None.into(),
+            )));
+        }
+        let source_r = params.input.remove(1);
+        let source_l = params.input.remove(0);
+
+        let condition = match node.get_condition() {
+            Ok(cond_prost) => Some(RowExpression::new(build_from_prost(cond_prost)?)),
+            Err(_) => None,
+        };
+        let interval_bound = node.interval_bound.as_ref().map(|bound| IntervalJoinBound {
+            left_time_col: bound.left_time_col as usize,
+            right_time_col: bound.right_time_col as usize,
+            lower: bound.lower.as_ref().map(IntervalUnit::from),
+            upper: bound.upper.as_ref().map(IntervalUnit::from),
+            allowed_lateness: bound
+                .allowed_lateness
+                .as_ref()
+                .map(IntervalUnit::from)
+                .unwrap_or_default(),
+        });
+        let left_dist_key_indices = node
+            .get_left_distribution_keys()
+            .iter()
+            .map(|key| *key as usize)
+            .collect::<Vec<_>>();
+
+        // The states are keyed by vnode, and an actor of a singleton fragment owns all the vnodes.
+        let vnodes = if params.vnode_bitmap.is_empty() {
+            (0..VIRTUAL_NODE_COUNT as VirtualNode).collect()
+        } else {
+            (0..VIRTUAL_NODE_COUNT)
+                .filter(|vnode| params.vnode_bitmap[vnode >> 3] & (1 << (vnode & 0b111)) != 0)
+                .map(|vnode| vnode as VirtualNode)
+                .collect()
+        };
+        let keyspace_l = Keyspace::table_root(store.clone(), &TableId::from(node.left_table_id));
+        let keyspace_r = Keyspace::table_root(store, &TableId::from(node.right_table_id));
+
+        Ok(Box::new(NestedLoopJoinExecutor::new(
+            source_l,
+            source_r,
+            params.pk_indices,
+            params.executor_id,
+            condition,
+            interval_bound,
+            keyspace_l,
+            keyspace_r,
+            left_dist_key_indices,
+            vnodes,
+        )))
+    }
+}
@@ -609,6 +609,18 @@ impl<B: Buf> Deserializer<B> {
         let nsecs = self.input.get_u32();
         Ok((secs, nsecs))
     }
+
+    /// Deserialize a bytea value.
+    pub fn deserialize_bytea(&mut self) -> Result<Vec<u8>> {
+        self.read_bytes()
+    }
+
+    /// Deserialize a uuid value. Returns its 16 bytes.
+    pub fn deserialize_uuid(&mut self) -> Result<[u8; 16]> {
+        let mut bytes = [0; 16];
+        self.input.copy_to_slice(&mut bytes);
+        Ok(bytes)
+    }
 }
 
 #[cfg(test)]
@@ -857,4 +869,25 @@ mod tests {
         let mut deserializer = Deserializer::new(bytes);
         deserializer.deserialize_naivedatetime().unwrap()
     }
+
+    #[test]
+    fn test_bytea() {
+        for bytes in [&b""[..], b"\x00", b"\x00\xff\x01\x02\x03\x04\x05\x06\x07"] {
+            let mut serializer = crate::Serializer::new(vec![]);
+            serializer.serialize_bytea(bytes).unwrap();
+            let encoded = serializer.into_inner();
+            let mut deserializer = Deserializer::new(encoded.as_slice());
+            assert_eq!(deserializer.deserialize_bytea().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn test_uuid() {
+        let bytes = [0, 0xff, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
+        let mut serializer = crate::Serializer::new(vec![]);
+        serializer.serialize_uuid(&bytes).unwrap();
+        let encoded = serializer.into_inner();
+        let mut deserializer = Deserializer::new(encoded.as_slice());
+        assert_eq!(deserializer.deserialize_uuid().unwrap(), bytes);
+    }
 }
@@ -583,6 +583,17 @@ impl<B: BufMut> Serializer<B> {
         self.output.put_u32(nsecs);
         Ok(())
     }
+
+    /// Serialize a bytea value, in the same format as a string.
+    pub fn serialize_bytea(&mut self, bytes: &[u8]) -> Result<()> {
+        serde::Serializer::serialize_bytes(self, bytes)
+    }
+
+    /// Serialize a uuid value as its 16 bytes, which keeps the byte-wise order.
+    pub fn serialize_uuid(&mut self, bytes: &[u8; 16]) -> Result<()> {
+        self.output.put_slice(bytes);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -876,6 +887,37 @@ mod tests {
         serializer.into_inner()
     }
 
+    #[test]
+    fn test_bytea() {
+        let a = serialize_bytea(b"\xff");
+        let b = serialize_bytea(b"\x00\x01");
+        let c = serialize_bytea(b"\x00");
+        let d = serialize_bytea(b"");
+        assert!(a > b && b > c && c > d);
+    }
+
+    fn serialize_bytea(bytes: &[u8]) -> Vec<u8> {
+        let mut serializer = Serializer::new(vec![]);
+        serializer.serialize_bytea(bytes).unwrap();
+        serializer.into_inner()
+    }
+
+    #[test]
+    fn test_uuid() {
+        let a = serialize_uuid(&[0xff; 16]);
+        let b = serialize_uuid(&[0x01, 0xff, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        let c = serialize_uuid(&[0x01; 16]);
+        let d = serialize_uuid(&[0; 16]);
+        assert!(a > b && b > c && c > d);
+        assert_eq!(a.len(), 16);
+    }
+
+    fn serialize_uuid(bytes: &[u8; 16]) -> Vec<u8> {
+        let mut serializer = Serializer::new(vec![]);
+        serializer.serialize_uuid(bytes).unwrap();
+        serializer.into_inner()
+    }
+
     #[test]
     fn test_reverse_order() {
         // Order: (ASC, DESC)
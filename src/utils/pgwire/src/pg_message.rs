@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::ffi::CStr;
-use std::io::{Error, ErrorKind, IoSlice, Result, Write};
+use std::io::{Error, ErrorKind, Result};
 
 use byteorder::{BigEndian, ByteOrder};
 /// Part of code learned from https://github.com/zenithdb/zenith/blob/main/zenith_utils/src/pq_proto.rs.
@@ -239,6 +239,11 @@ pub enum BeParameterStatusMessage<'a> {
     ClientEncoding(&'a str),
     StandardConformingString(&'a str),
     ServerVersion(&'a str),
+    /// A session parameter reported to the client when it's changed.
+    Other {
+        name: &'a str,
+        value: &'a str,
+    },
 }
 
 #[derive(Debug)]
@@ -285,24 +290,18 @@ impl<'a> BeMessage<'a> {
             BeMessage::ParameterStatus(param) => {
                 use BeParameterStatusMessage::*;
                 let [name, value] = match param {
-                    ClientEncoding(val) => [b"client_encoding", val.as_bytes()],
-                    StandardConformingString(val) => {
-                        [b"standard_conforming_strings", val.as_bytes()]
-                    }
-                    ServerVersion(val) => [b"server_version", val.as_bytes()],
+                    ClientEncoding(val) => ["client_encoding", *val],
+                    StandardConformingString(val) => ["standard_conforming_strings", *val],
+                    ServerVersion(val) => ["server_version", *val],
+                    Other { name, value } => [*name, *value],
                 };
 
                 // Parameter names and values are passed as null-terminated strings
-                let iov = &mut [name, b"\0", value, b"\0"].map(IoSlice::new);
-                let mut buffer = [0u8; 64]; // this should be enough
-                let cnt = buffer.as_mut().write_vectored(iov).unwrap();
-
                 buf.put_u8(b'S');
                 write_body(buf, |stream| {
-                    stream.put_slice(&buffer[..cnt]);
-                    Ok(())
-                })
-                .unwrap();
+                    write_cstr(stream, name.as_bytes())?;
+                    write_cstr(stream, value.as_bytes())
+                })?;
             }
 
             // CommandComplete
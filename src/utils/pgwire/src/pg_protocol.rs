@@ -202,6 +202,11 @@ where
                         } else if res.is_query() {
                             self.process_query_with_results(res, extended).await?;
                         } else {
+                            for (name, value) in res.get_parameter_status() {
+                                self.write_message_no_flush(&BeMessage::ParameterStatus(
+                                    BeParameterStatusMessage::Other { name, value },
+                                ))?;
+                            }
                             self.write_message_no_flush(&BeMessage::CommandComplete(
                                 BeCommandCompleteMessage {
                                     stmt_type: res.get_stmt_type(),
@@ -51,7 +51,13 @@ pub enum StatementType {
     SHOW_COMMAND,
     START_TRANSACTION,
     ABORT,
+    COMMIT,
+    ROLLBACK,
     FLUSH,
+    ANALYZE,
+    PREPARE,
+    DEALLOCATE,
+    COMMENT,
     OTHER,
     // EMPTY is used when query statement is empty (e.g. ";").
     EMPTY,
@@ -70,6 +76,8 @@ pub struct PgResponse {
     notice: Option<String>,
     values: Vec<Row>,
     row_desc: Vec<PgFieldDescriptor>,
+    /// Session parameters changed by the statement, to be reported to the client.
+    parameter_status: Vec<(String, String)>,
 }
 
 impl StatementType {
@@ -100,6 +108,7 @@ impl PgResponse {
             values,
             row_desc,
             notice: None,
+            parameter_status: vec![],
         }
     }
 
@@ -114,9 +123,16 @@ impl PgResponse {
             values: vec![],
             row_desc: vec![],
             notice: Some(notice),
+            parameter_status: vec![],
         }
     }
 
+    /// Report the new value of a session parameter to the client along with the response.
+    pub fn with_parameter_status(mut self, name: String, value: String) -> Self {
+        self.parameter_status.push((name, value));
+        self
+    }
+
     pub fn get_stmt_type(&self) -> StatementType {
         self.stmt_type
     }
@@ -143,6 +159,10 @@ impl PgResponse {
         self.stmt_type == StatementType::EMPTY
     }
 
+    pub fn get_parameter_status(&self) -> &[(String, String)] {
+        &self.parameter_status
+    }
+
     pub fn get_row_desc(&self) -> Vec<PgFieldDescriptor> {
         self.row_desc.clone()
     }